@@ -36,7 +36,11 @@ async fn app() -> Router {
 
     let llm_config =
         hypr_llm_proxy::LlmProxyConfig::new(&env.llm).with_analytics(analytics.clone());
-    let stt_config = hypr_transcribe_proxy::SttProxyConfig::new(&env.stt).with_analytics(analytics);
+    let mut stt_config =
+        hypr_transcribe_proxy::SttProxyConfig::new(&env.stt).with_analytics(analytics);
+    if let Some(storage) = env.stt.storage() {
+        stt_config = stt_config.with_storage(storage.build().await);
+    }
     let auth_state_pro =
         AuthState::new(&env.supabase.supabase_url).with_required_entitlement("hyprnote_pro");
     let auth_state_basic = AuthState::new(&env.supabase.supabase_url);
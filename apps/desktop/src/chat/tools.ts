@@ -1,6 +1,8 @@
 import { tool } from "ai";
 import { z } from "zod";
 
+import { commands as db2Commands } from "@hypr/plugin-db2";
+
 import { searchFiltersSchema } from "../contexts/search/engine/types";
 import type { SearchFilters, SearchHit } from "../contexts/search/engine/types";
 import type { SupportMcpTools } from "./support-mcp-tools";
@@ -10,6 +12,7 @@ export interface ToolDependencies {
     query: string,
     filters?: SearchFilters | null,
   ) => Promise<SearchHit[]>;
+  getSessionTitle: (sessionId: string) => string | null;
 }
 
 const buildSearchSessionsTool = (deps: ToolDependencies) =>
@@ -39,8 +42,46 @@ const buildSearchSessionsTool = (deps: ToolDependencies) =>
     },
   });
 
+const buildListOpenActionItemsTool = (deps: ToolDependencies) =>
+  tool({
+    description: `
+  List action items extracted from past sessions that are not done yet,
+  newest first. Use this for questions about open tasks or follow-ups.
+  `.trim(),
+    inputSchema: z.object({
+      limit: z
+        .number()
+        .int()
+        .min(1)
+        .max(100)
+        .optional()
+        .describe("Maximum number of items to return (default 20)"),
+    }),
+    execute: async (params: { limit?: number }) => {
+      const result = await db2Commands.listOpenActionItems({
+        owner_id: null,
+        session_id: null,
+        limit: params.limit ?? 20,
+      });
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+
+      const items = result.data.map((item) => ({
+        text: item.text,
+        owner: item.owner_name,
+        due: item.due,
+        session_id: item.session_id,
+        session_title: deps.getSessionTitle(item.session_id),
+      }));
+
+      return { items };
+    },
+  });
+
 export const buildChatTools = (deps: ToolDependencies) => ({
   search_sessions: buildSearchSessionsTool(deps),
+  list_open_action_items: buildListOpenActionItemsTool(deps),
 });
 
 type LocalTools = {
@@ -56,6 +97,18 @@ type LocalTools = {
       }>;
     };
   };
+  list_open_action_items: {
+    input: { limit?: number };
+    output: {
+      items: Array<{
+        text: string;
+        owner: string | null;
+        due: string | null;
+        session_id: string;
+        session_title: string | null;
+      }>;
+    };
+  };
 };
 
 export type Tools = LocalTools & SupportMcpTools;
@@ -17,6 +17,7 @@ import { useContextCollection } from "../../hooks/useContextCollection";
 import { useCreateChatMessage } from "../../hooks/useCreateChatMessage";
 import { useLanguageModel } from "../../hooks/useLLMConnection";
 import * as main from "../../store/tinybase/store/main";
+import { getPreviousMeeting } from "../../store/tinybase/store/sessions";
 import { id } from "../../utils";
 import { buildSegments, SegmentKey, type WordLike } from "../../utils/segment";
 import {
@@ -336,24 +337,29 @@ function useTransport(
 
     let stale = false;
 
-    templateCommands
-      .render({
+    const render = async () => {
+      const previousMeeting =
+        store && attachedSessionId
+          ? await getPreviousMeeting(store, attachedSessionId)
+          : null;
+
+      const result = await templateCommands.render({
         chatSystem: {
           language,
-          context: chatContext,
+          context: chatContext ? { ...chatContext, previousMeeting } : null,
         },
-      })
-      .then((result) => {
-        if (!stale && result.status === "ok") {
-          setSystemPrompt(result.data);
-        }
-      })
-      .catch(console.error);
+      });
+      if (!stale && result.status === "ok") {
+        setSystemPrompt(result.data);
+      }
+    };
+
+    render().catch(console.error);
 
     return () => {
       stale = true;
     };
-  }, [language, chatContext, systemPromptOverride]);
+  }, [language, chatContext, systemPromptOverride, store, attachedSessionId]);
 
   const effectiveSystemPrompt = systemPromptOverride ?? systemPrompt;
 
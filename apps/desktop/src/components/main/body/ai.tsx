@@ -23,6 +23,7 @@ import { type Tab, useTabs } from "../../../store/zustand/tabs";
 import { LLM } from "../../settings/ai/llm";
 import { STT } from "../../settings/ai/stt";
 import { StandardTabWrapper } from "./index";
+import { UserPromptTemplates } from "./prompts/user-templates";
 import { useWebResources } from "./resource-list";
 import { type TabItem, TabItemBase } from "./shared";
 import { useUserTemplates } from "./templates/index";
@@ -127,7 +128,6 @@ function AIView({ tab }: { tab: Extract<Tab, { type: "ai" }> }) {
       key: "prompts",
       label: "Prompts",
       icon: <SparklesIcon size={14} />,
-      disabled: true,
     },
   ];
 
@@ -713,10 +713,5 @@ function ShortcutCardItem({
 }
 
 function PromptsContent() {
-  return (
-    <div className="flex flex-col items-center justify-center h-full min-h-[300px] gap-3">
-      <SparklesIcon size={48} className="text-neutral-300" />
-      <p className="text-sm text-neutral-400 font-medium">Coming soon</p>
-    </div>
-  );
+  return <UserPromptTemplates />;
 }
@@ -43,6 +43,7 @@ import { useTabs } from "../../../../store/zustand/tabs";
 import { AppleCalendarSelection } from "../../../settings/calendar/configure/apple/calendar-selection";
 import { SyncProvider } from "../../../settings/calendar/configure/apple/context";
 import { AccessPermissionRow } from "../../../settings/calendar/configure/apple/permission";
+import { CalDavCalendarSelection } from "../../../settings/calendar/configure/caldav/calendar-selection";
 import { PROVIDERS } from "../../../settings/calendar/shared";
 import { EventDisplay } from "../sessions/outer-header/metadata";
 
@@ -759,6 +760,11 @@ function CalendarSidebarContent() {
                   )}
                 </div>
               )}
+              {provider.id === "caldav" && (
+                <SyncProvider>
+                  <CalDavCalendarSelection />
+                </SyncProvider>
+              )}
             </AccordionContent>
           </AccordionItem>
         ),
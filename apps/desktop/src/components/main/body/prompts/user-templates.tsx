@@ -0,0 +1,319 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Plus, Trash2 } from "lucide-react";
+import { useState } from "react";
+
+import {
+  commands as templateCommands,
+  type RenderedPrompts,
+  type UserTemplate,
+} from "@hypr/plugin-template";
+import { PromptEditor } from "@hypr/tiptap/prompt";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { cn } from "@hypr/utils";
+
+import { AVAILABLE_FILTERS } from "../../../../store/tinybase/store/prompts";
+
+export const USER_TEMPLATES_QUERY_KEY = "user-templates";
+
+const SYSTEM_VARIABLES = ["language"];
+const USER_VARIABLES = [
+  "session",
+  "participants",
+  "template",
+  "transcripts",
+  "previous_meeting",
+];
+
+const EMPTY_TEMPLATE: UserTemplate = {
+  id: "",
+  name: "",
+  description: null,
+  system: null,
+  user: null,
+};
+
+// Prompt templates stored as files in the vault, picked per session in the
+// note header. Both prompts are optional and fall back to the built-in ones.
+export function UserPromptTemplates() {
+  const [editing, setEditing] = useState<{
+    template: UserTemplate;
+    isNew: boolean;
+  } | null>(null);
+
+  const { data: templates = [], error } = useQuery({
+    queryKey: [USER_TEMPLATES_QUERY_KEY],
+    queryFn: async () => {
+      const result = await templateCommands.listUserTemplates();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+
+  if (editing) {
+    return (
+      <UserTemplateEditor
+        key={editing.template.id || "new"}
+        initial={editing.template}
+        isNew={editing.isNew}
+        onClose={() => setEditing(null)}
+      />
+    );
+  }
+
+  return (
+    <div className="flex flex-col gap-3">
+      <div className="flex items-center justify-between">
+        <div>
+          <h3 className="text-sm font-medium">Prompt Templates</h3>
+          <p className="text-xs text-neutral-500">
+            Jinja prompts for enhancing notes. Import shared macros with{" "}
+            <code>{'{% import "_macros.jinja" as m %}'}</code>.
+          </p>
+        </div>
+        <Button
+          variant="outline"
+          size="sm"
+          className="gap-1"
+          onClick={() => setEditing({ template: EMPTY_TEMPLATE, isNew: true })}
+        >
+          <Plus size={14} />
+          New
+        </Button>
+      </div>
+
+      {error && <p className="text-xs text-red-500">{error.message}</p>}
+
+      {templates.length === 0 ? (
+        <p className="text-sm text-neutral-400 py-6 text-center">
+          No prompt templates yet
+        </p>
+      ) : (
+        <div className="flex flex-col gap-1">
+          {templates.map((template) => (
+            <button
+              key={template.id}
+              onClick={() => setEditing({ template, isNew: false })}
+              className="w-full text-left px-3 py-2 rounded-md text-sm border border-neutral-200 hover:bg-neutral-50 transition-colors"
+            >
+              <div className="font-medium truncate">{template.name}</div>
+              <div className="text-xs text-neutral-500 truncate">
+                {template.description || template.id}
+              </div>
+            </button>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
+
+function UserTemplateEditor({
+  initial,
+  isNew,
+  onClose,
+}: {
+  initial: UserTemplate;
+  isNew: boolean;
+  onClose: () => void;
+}) {
+  const queryClient = useQueryClient();
+  const [template, setTemplate] = useState(initial);
+  const [preview, setPreview] = useState<RenderedPrompts | null>(null);
+
+  const update = (patch: Partial<UserTemplate>) => {
+    setTemplate((prev) => ({ ...prev, ...patch }));
+  };
+
+  // Empty prompts are stored as null so the built-in prompt is used.
+  const normalized: UserTemplate = {
+    ...template,
+    id: template.id.trim(),
+    description: template.description?.trim() || null,
+    system: template.system?.trim() ? template.system : null,
+    user: template.user?.trim() ? template.user : null,
+  };
+
+  const previewTemplate = useMutation({
+    mutationFn: async () => {
+      const result = await templateCommands.previewUserTemplate(
+        normalized,
+        null,
+        null,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+    onSuccess: setPreview,
+  });
+
+  const saveTemplate = useMutation({
+    mutationFn: async () => {
+      const result = await templateCommands.saveUserTemplate(normalized);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+    },
+    onSuccess: () => {
+      void queryClient.invalidateQueries({
+        queryKey: [USER_TEMPLATES_QUERY_KEY],
+      });
+      onClose();
+    },
+  });
+
+  const deleteTemplate = useMutation({
+    mutationFn: async () => {
+      const result = await templateCommands.deleteUserTemplate(initial.id);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+    },
+    onSuccess: () => {
+      void queryClient.invalidateQueries({
+        queryKey: [USER_TEMPLATES_QUERY_KEY],
+      });
+      onClose();
+    },
+  });
+
+  const error =
+    saveTemplate.error ?? previewTemplate.error ?? deleteTemplate.error;
+
+  return (
+    <div className="flex flex-col gap-4">
+      <div className="flex items-center justify-between">
+        <h3 className="text-sm font-medium">
+          {isNew ? "New Prompt Template" : initial.name}
+        </h3>
+        <div className="flex gap-2">
+          {!isNew && (
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => deleteTemplate.mutate()}
+              disabled={deleteTemplate.isPending}
+            >
+              <Trash2 size={14} />
+            </Button>
+          )}
+          <Button variant="outline" size="sm" onClick={onClose}>
+            Cancel
+          </Button>
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={() => previewTemplate.mutate()}
+            disabled={previewTemplate.isPending}
+          >
+            Preview
+          </Button>
+          <Button
+            size="sm"
+            onClick={() => saveTemplate.mutate()}
+            disabled={
+              saveTemplate.isPending || !normalized.id || !template.name.trim()
+            }
+          >
+            Save
+          </Button>
+        </div>
+      </div>
+
+      <div className="grid grid-cols-2 gap-2">
+        <Input
+          placeholder="id (file name)"
+          value={template.id}
+          disabled={!isNew}
+          onChange={(e) => update({ id: e.target.value })}
+        />
+        <Input
+          placeholder="Name"
+          value={template.name}
+          onChange={(e) => update({ name: e.target.value })}
+        />
+      </div>
+      <Input
+        placeholder="Description"
+        value={template.description ?? ""}
+        onChange={(e) => update({ description: e.target.value })}
+      />
+
+      <PromptField
+        label="System prompt"
+        value={template.system ?? ""}
+        onChange={(system) => update({ system })}
+        variables={SYSTEM_VARIABLES}
+      />
+      <PromptField
+        label="User prompt"
+        value={template.user ?? ""}
+        onChange={(user) => update({ user })}
+        variables={USER_VARIABLES}
+      />
+
+      {error && (
+        <pre className="text-xs text-red-500 whitespace-pre-wrap">
+          {error.message}
+        </pre>
+      )}
+
+      {preview && (
+        <div className="flex flex-col gap-2">
+          <h4 className="text-xs font-medium text-neutral-600">
+            Preview with a sample session
+          </h4>
+          <PreviewBlock label="System" content={preview.system} />
+          <PreviewBlock label="User" content={preview.user} />
+        </div>
+      )}
+    </div>
+  );
+}
+
+function PromptField({
+  label,
+  value,
+  onChange,
+  variables,
+}: {
+  label: string;
+  value: string;
+  onChange: (value: string) => void;
+  variables: string[];
+}) {
+  return (
+    <div className="flex flex-col gap-1">
+      <span className="text-xs font-medium text-neutral-600">{label}</span>
+      <div className="h-48 border border-neutral-200 rounded-lg overflow-hidden">
+        <PromptEditor
+          value={value}
+          onChange={onChange}
+          placeholder="Leave empty to use the built-in prompt"
+          variables={variables}
+          filters={[...AVAILABLE_FILTERS]}
+        />
+      </div>
+    </div>
+  );
+}
+
+function PreviewBlock({ label, content }: { label: string; content: string }) {
+  return (
+    <div>
+      <span className="text-xs text-neutral-500">{label}</span>
+      <pre
+        className={cn([
+          "text-xs bg-neutral-50 p-3 rounded-lg border border-neutral-200",
+          "whitespace-pre-wrap font-mono text-neutral-600 max-h-64 overflow-auto",
+        ])}
+      >
+        {content}
+      </pre>
+    </div>
+  );
+}
@@ -7,12 +7,20 @@ import {
   type AttachmentInfo,
   commands as fsSyncCommands,
 } from "@hypr/plugin-fs-sync";
+import { commands as templateCommands } from "@hypr/plugin-template";
 import { md2json } from "@hypr/tiptap/shared";
 import {
   Popover,
   PopoverContent,
   PopoverTrigger,
 } from "@hypr/ui/components/ui/popover";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@hypr/ui/components/ui/select";
 import {
   ScrollFadeOverlay,
   useScrollFade,
@@ -37,6 +45,7 @@ import { createTaskId } from "../../../../../store/zustand/ai-task/task-configs"
 import { type TaskStepInfo } from "../../../../../store/zustand/ai-task/tasks";
 import { useTabs } from "../../../../../store/zustand/tabs";
 import { type EditorView } from "../../../../../store/zustand/tabs/schema";
+import { USER_TEMPLATES_QUERY_KEY } from "../../prompts/user-templates";
 import { useHasTranscript } from "../shared";
 import { EditingControls } from "./transcript/editing-controls";
 import { TranscriptionProgress } from "./transcript/progress";
@@ -465,6 +474,7 @@ function CreateOtherFormatButton({
               >
                 Manage templates
               </TemplateButton>
+              <PromptTemplateSelect sessionId={sessionId} />
             </>
           ) : (
             <>
@@ -488,6 +498,70 @@ function CreateOtherFormatButton({
   );
 }
 
+const DEFAULT_PROMPT_TEMPLATE = "default";
+
+// Picks the user-defined prompt template the enhance task renders with.
+function PromptTemplateSelect({ sessionId }: { sessionId: string }) {
+  const { data: userTemplates = [] } = useQuery({
+    queryKey: [USER_TEMPLATES_QUERY_KEY],
+    queryFn: async () => {
+      const result = await templateCommands.listUserTemplates();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+
+  const value = main.UI.useCell(
+    "sessions",
+    sessionId,
+    "enhance_template_id",
+    main.STORE_ID,
+  );
+  const handleChange = main.UI.useSetCellCallback(
+    "sessions",
+    sessionId,
+    "enhance_template_id",
+    (templateId: string) =>
+      templateId === DEFAULT_PROMPT_TEMPLATE ? "" : templateId,
+    [],
+    main.STORE_ID,
+  );
+
+  if (userTemplates.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className="flex items-center justify-between gap-2 border-t border-neutral-100 pt-2">
+      <span className="text-xs text-neutral-500">Prompt</span>
+      <Select
+        value={value || DEFAULT_PROMPT_TEMPLATE}
+        onValueChange={handleChange}
+      >
+        <SelectTrigger className="h-7 w-36 text-xs">
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          <SelectItem value={DEFAULT_PROMPT_TEMPLATE} className="text-xs">
+            Default
+          </SelectItem>
+          {userTemplates.map((template) => (
+            <SelectItem
+              key={template.id}
+              value={template.id}
+              className="text-xs"
+            >
+              {template.name}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+    </div>
+  );
+}
+
 export function Header({
   sessionId,
   editorTabs,
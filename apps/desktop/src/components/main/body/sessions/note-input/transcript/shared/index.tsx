@@ -169,6 +169,8 @@ export function TranscriptContainer({
           </div>
         ))}
 
+        {currentActive && <LiveTranslation />}
+
         {editable && (
           <SelectionMenu
             containerRef={containerRef}
@@ -195,6 +197,47 @@ export function TranscriptContainer({
   );
 }
 
+const VISIBLE_TRANSLATION_LINES = 3;
+
+function LiveTranslation() {
+  const finalTranslations = useListener((state) => state.finalTranslations);
+  const partialTranslationByChannel = useListener(
+    (state) => state.partialTranslationByChannel,
+  );
+
+  const recent = finalTranslations.slice(-VISIBLE_TRANSLATION_LINES);
+  const partials = Object.entries(partialTranslationByChannel)
+    .sort(([a], [b]) => Number(a) - Number(b))
+    .map(([, text]) => text);
+
+  if (recent.length === 0 && partials.length === 0) {
+    return null;
+  }
+
+  return (
+    <div
+      className={cn([
+        "flex flex-col gap-1 px-3 py-2 rounded-lg",
+        "bg-neutral-50 border border-neutral-200 text-sm",
+      ])}
+    >
+      <span className="text-[11px] uppercase tracking-wide text-neutral-400">
+        Translation
+      </span>
+      {recent.map((line, index) => (
+        <p key={index} className="text-neutral-700">
+          {line.text}
+        </p>
+      ))}
+      {partials.map((text, index) => (
+        <p key={`partial-${index}`} className="text-neutral-400">
+          {text}
+        </p>
+      ))}
+    </div>
+  );
+}
+
 function TranscriptSeparator() {
   return (
     <div
@@ -0,0 +1,27 @@
+import { Switch } from "@hypr/ui/components/ui/switch";
+
+import { useConfigValue } from "../../../../config/use-config";
+import * as settings from "../../../../store/tinybase/store/settings";
+
+export function ExtractActionItemsToggle() {
+  const value = useConfigValue("extract_action_items");
+  const setExtractActionItems = settings.UI.useSetValueCallback(
+    "extract_action_items",
+    (val: boolean) => val,
+    [],
+    settings.STORE_ID,
+  );
+
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div className="flex-1">
+        <h3 className="text-sm font-medium mb-1">Extract action items</h3>
+        <p className="text-xs text-neutral-600">
+          After a summary is generated, ask the model again for action items
+          and decisions. This runs a second request on every summary.
+        </p>
+      </div>
+      <Switch checked={value} onCheckedChange={setExtractActionItems} />
+    </div>
+  );
+}
@@ -1,3 +1,4 @@
+import { ExtractActionItemsToggle } from "./action-items";
 import { ConfigureProviders } from "./configure";
 import { LlmSettingsProvider } from "./context";
 import { SelectProviderAndModel } from "./select";
@@ -8,6 +9,7 @@ export function LLM() {
       <div className="flex flex-col gap-6 mt-4">
         <SelectProviderAndModel />
         <ConfigureProviders />
+        <ExtractActionItemsToggle />
       </div>
     </LlmSettingsProvider>
   );
@@ -3,7 +3,10 @@ import { useQueries, useQuery } from "@tanstack/react-query";
 import { arch } from "@tauri-apps/plugin-os";
 import { Check, Loader2 } from "lucide-react";
 
-import { commands as listenerCommands } from "@hypr/plugin-listener";
+import {
+  commands as listenerCommands,
+  type ProviderCapabilities,
+} from "@hypr/plugin-listener";
 import type { SupportedSttModel } from "@hypr/plugin-local-stt";
 import type { AIProviderStorage } from "@hypr/store";
 import { Input } from "@hypr/ui/components/ui/input";
@@ -69,6 +72,18 @@ export function SelectProviderAndModel() {
   const hasLanguageWarning =
     isConfigured && languageSupport.data === false && !hasError;
 
+  const capabilities = useQuery({
+    queryKey: ["stt-capabilities", current_stt_provider, current_stt_model],
+    queryFn: async () => {
+      const result = await listenerCommands.getProviderCapabilities(
+        current_stt_provider!,
+        current_stt_model ?? null,
+      );
+      return result.status === "ok" ? result.data : null;
+    },
+    enabled: !!current_stt_provider,
+  });
+
   const handleSelectProvider = settings.UI.useSetValueCallback(
     "current_stt_provider",
     (provider: string) => provider,
@@ -275,6 +290,9 @@ export function SelectProviderAndModel() {
             </span>
           </div>
         )}
+        {isConfigured && !hasError && capabilities.data && (
+          <CapabilitySummary capabilities={capabilities.data} />
+        )}
       </div>
     </div>
   );
@@ -383,6 +401,49 @@ function useConfiguredMapping(): Record<
   >;
 }
 
+function CapabilitySummary({
+  capabilities,
+}: {
+  capabilities: ProviderCapabilities;
+}) {
+  const features = [
+    { label: "Speaker labels", supported: capabilities.diarization },
+    {
+      label:
+        capabilities.max_keywords === null
+          ? "Vocabulary"
+          : `Vocabulary (up to ${capabilities.max_keywords})`,
+      supported: capabilities.keyword_boosting,
+    },
+    { label: "Translation", supported: capabilities.translation },
+    { label: "Punctuation", supported: capabilities.punctuation },
+    { label: "Word timings", supported: capabilities.word_timestamps },
+  ];
+
+  return (
+    <div className="flex flex-wrap items-center gap-1.5 pt-2 border-t border-neutral-200">
+      {features.map(({ label, supported }) => (
+        <span
+          key={label}
+          className={cn([
+            "text-[11px] rounded-full px-2 py-0.5 border",
+            supported
+              ? "border-neutral-300 text-neutral-700"
+              : "border-neutral-200 text-neutral-400 line-through",
+          ])}
+        >
+          {label}
+        </span>
+      ))}
+      {capabilities.max_session_secs !== null && (
+        <span className="text-[11px] text-neutral-500">
+          Up to {Math.round(capabilities.max_session_secs / 3600)}h per session
+        </span>
+      )}
+    </div>
+  );
+}
+
 function ModelSelectItem({
   model,
   onDownload,
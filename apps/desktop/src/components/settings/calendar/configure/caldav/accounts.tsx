@@ -0,0 +1,148 @@
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { useState } from "react";
+
+import {
+  commands as appleCalendarCommands,
+  type RemoteCalendar,
+} from "@hypr/plugin-apple-calendar";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Spinner } from "@hypr/ui/components/ui/spinner";
+
+import * as main from "../../../../../store/tinybase/store/main";
+import { Section } from "../apple";
+import { useSync } from "../apple/context";
+import { CALDAV_CALENDARS_QUERY_KEY } from "./calendar-selection";
+
+export function CalDavAccounts() {
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+  const queryClient = useQueryClient();
+  const { scheduleSync } = useSync();
+
+  const [serverUrl, setServerUrl] = useState("");
+  const [username, setUsername] = useState("");
+  const [password, setPassword] = useState("");
+  const [icsUrl, setIcsUrl] = useState("");
+
+  const onAdded = (calendars: RemoteCalendar[]) => {
+    queryClient.setQueryData([CALDAV_CALENDARS_QUERY_KEY, user_id], calendars);
+    scheduleSync();
+  };
+
+  const addAccount = useMutation({
+    mutationFn: async () => {
+      const result = await appleCalendarCommands.addCaldavAccount(
+        String(user_id),
+        { server_url: serverUrl.trim(), username, password },
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+    onSuccess: (calendars) => {
+      setServerUrl("");
+      setUsername("");
+      setPassword("");
+      onAdded(calendars);
+    },
+  });
+
+  const addSubscription = useMutation({
+    mutationFn: async () => {
+      const result = await appleCalendarCommands.addIcsSubscription(
+        String(user_id),
+        icsUrl.trim(),
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+    onSuccess: (calendars) => {
+      setIcsUrl("");
+      onAdded(calendars);
+    },
+  });
+
+  return (
+    <>
+      <Section title="CalDAV account">
+        <form
+          className="flex flex-col gap-2"
+          onSubmit={(e) => {
+            e.preventDefault();
+            addAccount.mutate();
+          }}
+        >
+          <Input
+            placeholder="https://caldav.example.com"
+            value={serverUrl}
+            onChange={(e) => setServerUrl(e.target.value)}
+          />
+          <div className="flex gap-2">
+            <Input
+              placeholder="Username"
+              value={username}
+              onChange={(e) => setUsername(e.target.value)}
+            />
+            <Input
+              type="password"
+              placeholder="Password"
+              value={password}
+              onChange={(e) => setPassword(e.target.value)}
+            />
+          </div>
+          <div className="flex items-center justify-between gap-2">
+            <p className="text-xs text-red-500 truncate">
+              {addAccount.error?.message}
+            </p>
+            <Button
+              type="submit"
+              size="sm"
+              disabled={
+                !user_id || !serverUrl.trim() || addAccount.isPending
+              }
+            >
+              {addAccount.isPending && <Spinner size={12} />}
+              Connect
+            </Button>
+          </div>
+        </form>
+      </Section>
+
+      <Section title="Subscription">
+        <form
+          className="flex flex-col gap-2"
+          onSubmit={(e) => {
+            e.preventDefault();
+            addSubscription.mutate();
+          }}
+        >
+          <div className="flex gap-2">
+            <Input
+              placeholder="webcal://example.com/calendar.ics"
+              value={icsUrl}
+              onChange={(e) => setIcsUrl(e.target.value)}
+            />
+            <Button
+              type="submit"
+              size="sm"
+              disabled={
+                !user_id || !icsUrl.trim() || addSubscription.isPending
+              }
+            >
+              {addSubscription.isPending && <Spinner size={12} />}
+              Subscribe
+            </Button>
+          </div>
+          {addSubscription.error && (
+            <p className="text-xs text-red-500 truncate">
+              {addSubscription.error.message}
+            </p>
+          )}
+        </form>
+      </Section>
+    </>
+  );
+}
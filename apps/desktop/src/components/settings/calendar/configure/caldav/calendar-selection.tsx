@@ -0,0 +1,181 @@
+import { useQuery } from "@tanstack/react-query";
+import { RefreshCwIcon } from "lucide-react";
+import { useCallback, useEffect, useMemo } from "react";
+
+import {
+  commands as appleCalendarCommands,
+  type RemoteCalendar,
+} from "@hypr/plugin-apple-calendar";
+import { Button } from "@hypr/ui/components/ui/button";
+import { cn } from "@hypr/utils";
+
+import * as main from "../../../../../store/tinybase/store/main";
+import { findCalendarByTrackingId } from "../../../../../utils/calendar";
+import { Section } from "../apple";
+import { useSync } from "../apple/context";
+import { SyncIndicator } from "../apple/sync";
+import {
+  type CalendarGroup,
+  type CalendarItem,
+  CalendarSelection,
+} from "../shared";
+
+export const CALDAV_CALENDARS_QUERY_KEY = "caldavCalendars";
+
+export function CalDavCalendarSelection() {
+  const { groups, handleToggle, handleRefresh, isLoading } =
+    useCalDavCalendarSelection();
+
+  return (
+    <Section
+      title="Calendars"
+      action={
+        <div className="flex items-center gap-2">
+          <SyncIndicator />
+
+          <Button
+            variant="ghost"
+            size="icon"
+            onClick={handleRefresh}
+            className="size-6"
+            disabled={isLoading}
+          >
+            <RefreshCwIcon
+              className={cn(["size-3.5", isLoading && "animate-spin"])}
+            />
+          </Button>
+        </div>
+      }
+    >
+      <div className="pt-0.5"></div>
+      <CalendarSelection groups={groups} onToggle={handleToggle} />
+    </Section>
+  );
+}
+
+function useCalDavCalendarSelection() {
+  const { scheduleSync, scheduleDebouncedSync, cancelDebouncedSync } =
+    useSync();
+
+  const store = main.UI.useStore(main.STORE_ID);
+  const calendars = main.UI.useTable("calendars", main.STORE_ID);
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+
+  const {
+    data: incomingCalendars,
+    refetch,
+    isFetching,
+  } = useQuery({
+    queryKey: [CALDAV_CALENDARS_QUERY_KEY, user_id],
+    enabled: !!user_id,
+    queryFn: async () => {
+      // Picks up calendars created on the server since the last sync.
+      const synced = await appleCalendarCommands.syncRemoteCalendars(
+        String(user_id),
+      );
+      if (synced.status === "error") {
+        console.error(synced.error);
+      }
+
+      const result = await appleCalendarCommands.listRemoteCalendars(
+        String(user_id),
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+
+  useEffect(() => {
+    if (!incomingCalendars || !store || !user_id) return;
+
+    store.transaction(() => {
+      for (const cal of incomingCalendars) {
+        const existingRowId = findCalendarByTrackingId(store, cal.tracking_id);
+        const rowId = existingRowId ?? crypto.randomUUID();
+        const existing = existingRowId
+          ? store.getRow("calendars", existingRowId)
+          : null;
+
+        // Selection lives in the plugin, which only syncs selected calendars.
+        store.setRow("calendars", rowId, {
+          user_id,
+          created_at: existing?.created_at || new Date().toISOString(),
+          tracking_id_calendar: cal.tracking_id,
+          name: cal.name,
+          enabled: cal.selected,
+          provider: "caldav",
+          source: getSourceName(cal),
+        });
+      }
+    });
+  }, [incomingCalendars, store, user_id]);
+
+  const groups = useMemo((): CalendarGroup[] => {
+    const caldavCalendars = Object.entries(calendars).filter(
+      ([_, cal]) => cal.provider === "caldav",
+    );
+
+    const grouped = new Map<string, CalendarItem[]>();
+    for (const [id, cal] of caldavCalendars) {
+      const source = cal.source || "CalDAV";
+      if (!grouped.has(source)) grouped.set(source, []);
+      grouped.get(source)!.push({
+        id,
+        title: cal.name || "Untitled",
+        color: cal.color ?? "#888",
+        enabled: cal.enabled ?? false,
+      });
+    }
+
+    return Array.from(grouped.entries()).map(([sourceName, calendars]) => ({
+      sourceName,
+      calendars,
+    }));
+  }, [calendars]);
+
+  const handleToggle = useCallback(
+    async (calendar: CalendarItem, enabled: boolean) => {
+      const trackingId = store?.getCell(
+        "calendars",
+        calendar.id,
+        "tracking_id_calendar",
+      );
+      if (!trackingId) return;
+
+      const result = await appleCalendarCommands.setRemoteCalendarSelected(
+        String(trackingId),
+        enabled,
+      );
+      if (result.status === "error") {
+        console.error(result.error);
+        return;
+      }
+
+      store?.setPartialRow("calendars", calendar.id, { enabled });
+      scheduleDebouncedSync();
+    },
+    [store, scheduleDebouncedSync],
+  );
+
+  const handleRefresh = useCallback(async () => {
+    cancelDebouncedSync();
+    await refetch();
+    scheduleSync();
+  }, [refetch, scheduleSync, cancelDebouncedSync]);
+
+  return {
+    groups,
+    handleToggle,
+    handleRefresh,
+    isLoading: isFetching,
+  };
+}
+
+function getSourceName(calendar: RemoteCalendar): string {
+  if (calendar.kind === "Ics") {
+    return "Subscriptions";
+  }
+  return calendar.source ?? "CalDAV";
+}
@@ -0,0 +1,54 @@
+import { commands as openerCommands } from "@hypr/plugin-opener2";
+import {
+  AccordionContent,
+  AccordionItem,
+  AccordionTrigger,
+} from "@hypr/ui/components/ui/accordion";
+
+import { StyledStreamdown } from "../../../ai/shared";
+import { PROVIDERS } from "../../shared";
+import { SyncProvider } from "../apple/context";
+import { CalDavAccounts } from "./accounts";
+import { CalDavCalendarSelection } from "./calendar-selection";
+
+export function CalDavProviderCard() {
+  const config = PROVIDERS.find((p) => p.id === "caldav")!;
+
+  return (
+    <AccordionItem
+      value={config.id}
+      className="rounded-xl border-2 border-dashed bg-neutral-50"
+    >
+      <AccordionTrigger className="gap-2 px-4">
+        <div className="flex items-center gap-2">
+          {config.icon}
+          <span>{config.displayName}</span>
+          {config.badge && (
+            <span className="text-xs text-neutral-500 font-light border border-neutral-300 rounded-full px-2">
+              {config.badge}
+            </span>
+          )}
+        </div>
+      </AccordionTrigger>
+      <AccordionContent className="px-4 flex flex-col gap-5">
+        <div className="flex items-center justify-between">
+          <StyledStreamdown>
+            Sync events from a **CalDAV** server such as Fastmail, Nextcloud or
+            iCloud, or subscribe to a read-only **.ics** feed.
+          </StyledStreamdown>
+          <button
+            onClick={() => openerCommands.openUrl(config.docsPath, null)}
+            className="text-xs text-neutral-400 hover:text-neutral-600 transition-colors"
+          >
+            Docs ↗
+          </button>
+        </div>
+
+        <SyncProvider>
+          <CalDavAccounts />
+          <CalDavCalendarSelection />
+        </SyncProvider>
+      </AccordionContent>
+    </AccordionItem>
+  );
+}
@@ -0,0 +1,209 @@
+import { useMutation } from "@tanstack/react-query";
+import { save as selectSavePath } from "@tauri-apps/plugin-dialog";
+
+import { commands as analyticsCommands } from "@hypr/plugin-analytics";
+import { commands as exportCommands } from "@hypr/plugin-export";
+import { commands as openerCommands } from "@hypr/plugin-opener2";
+import { json2md } from "@hypr/tiptap/shared";
+import { Button } from "@hypr/ui/components/ui/button";
+
+import * as main from "../../../store/tinybase/store/main";
+import {
+  parseTranscriptHints,
+  parseTranscriptWords,
+} from "../../../store/transcript/utils";
+import { buildSegments, SegmentKey } from "../../../utils/segment";
+import {
+  defaultRenderLabelContext,
+  SpeakerLabelManager,
+} from "../../../utils/segment/shared";
+import { convertStorageHintsToRuntime } from "../../../utils/speaker-hints";
+
+function toMarkdown(content: unknown): string {
+  if (typeof content !== "string" || !content) {
+    return "";
+  }
+  try {
+    return json2md(JSON.parse(content));
+  } catch {
+    return content;
+  }
+}
+
+function optional(value: unknown): string | null {
+  return typeof value === "string" && value ? value : null;
+}
+
+function transcriptWords(store: main.Store, transcriptId: string) {
+  const words = parseTranscriptWords(store, transcriptId).filter(
+    (word) => word.text !== undefined && word.start_ms !== undefined,
+  );
+
+  const wordIdToIndex = new Map<string, number>();
+  words.forEach((w, i) => wordIdToIndex.set(w.id, i));
+
+  const speakerHints = convertStorageHintsToRuntime(
+    parseTranscriptHints(store, transcriptId),
+    wordIdToIndex,
+  );
+
+  const segments = buildSegments(
+    words.map((w) => ({ ...w, channel: w.channel ?? 0 })),
+    [],
+    speakerHints,
+  );
+  const ctx = defaultRenderLabelContext(store);
+  const manager = SpeakerLabelManager.fromSegments(segments, ctx);
+
+  const speakers = new Map<string, string>();
+  for (const segment of segments) {
+    const label = SegmentKey.renderLabel(segment.key, ctx, manager);
+    for (const word of segment.words) {
+      if (word.id) {
+        speakers.set(word.id, label);
+      }
+    }
+  }
+
+  return words.map((word) => ({
+    id: word.id,
+    text: word.text,
+    start_ms: word.start_ms,
+    end_ms: word.end_ms ?? word.start_ms,
+    channel: word.channel ?? 0,
+    speaker: speakers.get(word.id) ?? null,
+  }));
+}
+
+// Same shape as the `AsIs` import, so the archive's manifest can be read back.
+function buildExportData(store: main.Store) {
+  const rows = (tableId: Parameters<main.Store["getTable"]>[0]) =>
+    Object.entries(store.getTable(tableId));
+
+  return {
+    sessions: rows("sessions").map(([id, row]) => ({
+      id,
+      created_at: row.created_at ?? "",
+      title: row.title ?? "",
+      raw_md: toMarkdown(row.raw_md),
+      folder_id: optional(row.folder_id),
+      event_id: optional(row.event_id),
+    })),
+    transcripts: rows("transcripts").map(([id, row]) => ({
+      id,
+      created_at: row.created_at ?? "",
+      session_id: row.session_id ?? "",
+      started_at: typeof row.started_at === "number" ? row.started_at : 0,
+      ended_at: typeof row.ended_at === "number" ? row.ended_at : null,
+      words: transcriptWords(store, id),
+    })),
+    humans: rows("humans").map(([id, row]) => ({
+      id,
+      created_at: row.created_at ?? "",
+      name: row.name ?? "",
+      email: optional(row.email),
+      org_id: optional(row.org_id),
+      job_title: optional(row.job_title),
+      linkedin_username: optional(row.linkedin_username),
+    })),
+    organizations: rows("organizations").map(([id, row]) => ({
+      id,
+      created_at: row.created_at ?? "",
+      name: row.name ?? "",
+    })),
+    participants: rows("mapping_session_participant").map(([id, row]) => ({
+      id,
+      session_id: row.session_id ?? "",
+      human_id: row.human_id ?? "",
+      source: row.source ?? "",
+    })),
+    enhanced_notes: rows("enhanced_notes").map(([id, row]) => ({
+      id,
+      session_id: row.session_id ?? "",
+      content: toMarkdown(row.content),
+      template_id: optional(row.template_id),
+      position: typeof row.position === "number" ? row.position : 0,
+      title: row.title ?? "",
+    })),
+    tags: rows("tags").map(([id, row]) => ({ id, name: row.name ?? "" })),
+    tag_mappings: rows("mapping_tag_session").map(([id, row]) => ({
+      id,
+      tag_id: row.tag_id ?? "",
+      session_id: row.session_id ?? "",
+    })),
+  };
+}
+
+export function ExportSessions({ disabled }: { disabled: boolean }) {
+  const store = main.UI.useStore(main.STORE_ID);
+
+  const exportMutation = useMutation({
+    mutationFn: async () => {
+      if (!store) {
+        throw new Error("Store not available");
+      }
+
+      const date = new Date().toISOString().slice(0, 10);
+      const path = await selectSavePath({
+        title: "Export sessions",
+        defaultPath: `Hyprnote export ${date}.zip`,
+        filters: [{ name: "Zip archive", extensions: ["zip"] }],
+      });
+      if (!path) {
+        return null;
+      }
+
+      const result = await exportCommands.exportSessions(path, {
+        data: buildExportData(store as main.Store),
+        sessionIds: null,
+        transcriptFormats: ["json", "srt", "vtt", "txt", "docx"],
+        includeAudio: true,
+        zip: true,
+      });
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+
+      return result.data;
+    },
+    onSuccess: (summary) => {
+      if (summary) {
+        void analyticsCommands.event({
+          event: "session_exported",
+          format: "archive",
+          sessions_count: summary.sessionsCount,
+        });
+        void openerCommands.revealItemInDir(summary.path);
+      }
+    },
+  });
+
+  return (
+    <div className="flex flex-col gap-1">
+      <div className="flex items-center justify-between gap-4">
+        <div className="flex-1 min-w-0">
+          <h3 className="text-sm font-medium mb-1">Export all sessions</h3>
+          <p className="text-xs text-neutral-600">
+            {exportMutation.data
+              ? `Exported ${exportMutation.data.sessionsCount} sessions`
+              : "Markdown notes, transcripts and audio in a zip archive"}
+          </p>
+        </div>
+        <Button
+          size="sm"
+          variant="outline"
+          className="shrink-0"
+          onClick={() => exportMutation.mutate()}
+          disabled={disabled || exportMutation.isPending}
+        >
+          {exportMutation.isPending ? "Exporting..." : "Export"}
+        </Button>
+      </div>
+      {exportMutation.isError && (
+        <p className="text-xs text-red-600">
+          Export failed: {exportMutation.error.message}
+        </p>
+      )}
+    </div>
+  );
+}
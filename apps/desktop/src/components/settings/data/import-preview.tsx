@@ -81,6 +81,11 @@ export function ImportPreview({
         ) : (
           <p className="text-xs text-neutral-500">No data found to import.</p>
         )}
+        {stats.skippedCount > 0 && (
+          <p className="text-xs text-amber-600 mt-1">
+            {stats.skippedCount} could not be read and will be skipped.
+          </p>
+        )}
       </div>
       <div className="flex items-center gap-2 shrink-0">
         <Button
@@ -1,24 +1,39 @@
 import { useMutation, useQuery } from "@tanstack/react-query";
+import { open as selectFile } from "@tauri-apps/plugin-dialog";
 import { XCircleIcon } from "lucide-react";
 import { useState } from "react";
 
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import {
   commands,
+  type ImportFilesResult,
   type ImportSourceInfo,
   type ImportSourceKind,
   type ImportStats,
 } from "@hypr/plugin-importer";
 
-import { importData } from "../../../store/tinybase/store/importer";
+import { Button } from "@hypr/ui/components/ui/button";
+
+import {
+  getExistingHumans,
+  importData,
+} from "../../../store/tinybase/store/importer";
 import * as main from "../../../store/tinybase/store/main";
 import { save } from "../../../store/tinybase/store/save";
 import { StyledStreamdown } from "../ai/shared";
+import { ExportSessions } from "./export";
 import { ImportPreview } from "./import-preview";
+import { ObsidianSync } from "./obsidian";
 import { SourceItem } from "./source-item";
 
-type DryRunResult = {
+type ImportRequest = {
   source: ImportSourceKind;
+  name: string;
+  // Where to read the source from when the user picked it, e.g. on Linux.
+  path: string | null;
+};
+
+type DryRunResult = ImportRequest & {
   stats: ImportStats;
 };
 
@@ -41,8 +56,8 @@ export function Data() {
   });
 
   const importMutation = useMutation({
-    mutationFn: async (source: ImportSourceKind) => {
-      const result = await commands.runImport(source, user_id ?? "");
+    mutationFn: async ({ source, path }: ImportRequest) => {
+      const result = await commands.runImport(source, user_id ?? "", path);
       if (result.status === "error") {
         throw new Error(result.error);
       }
@@ -76,18 +91,111 @@ export function Data() {
   });
 
   const dryImportMutation = useMutation({
-    mutationFn: async (source: ImportSourceKind) => {
-      const result = await commands.runImportDry(source);
+    mutationFn: async (request: ImportRequest) => {
+      const result = await commands.runImportDry(request.source, request.path);
       if (result.status === "error") {
         throw new Error(result.error);
       }
-      return { source, stats: result.data };
+      return { ...request, stats: result.data };
     },
     onSuccess: (result) => {
       setDryRunResult(result);
     },
   });
 
+  const pickAppleNotesStore = async () => {
+    const path = await selectFile({
+      title: "Choose NoteStore.sqlite",
+      multiple: false,
+      directory: false,
+      filters: [{ name: "Apple Notes store", extensions: ["sqlite"] }],
+    });
+    if (!path) {
+      return;
+    }
+
+    setSuccessfulSource(null);
+    dryImportMutation.mutate({
+      source: "apple_notes",
+      name: "Apple Notes",
+      path,
+    });
+  };
+
+  const hasAppleNotes = sources?.some((s) => s.kind === "apple_notes");
+
+  // Archives written by "Export sessions", kept as a folder or a zip.
+  const pickExport = async (directory: boolean) => {
+    const path = await selectFile({
+      title: directory ? "Choose export folder" : "Choose export zip",
+      multiple: false,
+      directory,
+      filters: directory ? undefined : [{ name: "Zip", extensions: ["zip"] }],
+    });
+    if (!path) {
+      return;
+    }
+
+    setSuccessfulSource(null);
+    dryImportMutation.mutate({
+      source: "as_is",
+      name: "Hyprnote export",
+      path,
+    });
+  };
+
+  const transcriptImportMutation = useMutation({
+    mutationFn: async () => {
+      const selection = await selectFile({
+        title: "Import transcripts",
+        multiple: true,
+        directory: false,
+        filters: [
+          {
+            name: "Transcripts",
+            extensions: ["txt", "docx", "srt", "vtt", "json"],
+          },
+        ],
+      });
+      if (!selection || selection.length === 0) {
+        return null;
+      }
+
+      if (!store) {
+        throw new Error("Store not available");
+      }
+
+      const result = await commands.runImportFiles(
+        selection,
+        user_id ?? "",
+        store.getRowIds("sessions"),
+        getExistingHumans(store as main.Store),
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+
+      const importResult = await importData(
+        store as main.Store,
+        result.data.data,
+        save,
+      );
+      if (importResult.status === "error") {
+        throw new Error(importResult.error);
+      }
+
+      return result.data;
+    },
+    onSuccess: (result) => {
+      if (result) {
+        void analyticsCommands.event({
+          event: "data_imported",
+          source: "transcript_files",
+        });
+      }
+    },
+  });
+
   const handleCancel = () => {
     setDryRunResult(null);
     dryImportMutation.reset();
@@ -95,7 +203,10 @@ export function Data() {
     setSuccessfulSource(null);
   };
 
-  const isPending = importMutation.isPending || dryImportMutation.isPending;
+  const isPending =
+    importMutation.isPending ||
+    dryImportMutation.isPending ||
+    transcriptImportMutation.isPending;
 
   return (
     <div>
@@ -109,11 +220,8 @@ export function Data() {
         {dryRunResult ? (
           <ImportPreview
             stats={dryRunResult.stats}
-            sourceName={
-              sources?.find((s) => s.kind === dryRunResult.source)?.name ??
-              "Unknown"
-            }
-            onConfirm={() => importMutation.mutate(dryRunResult.source)}
+            sourceName={dryRunResult.name}
+            onConfirm={() => importMutation.mutate(dryRunResult)}
             onCancel={handleCancel}
             isPending={importMutation.isPending}
           />
@@ -131,25 +239,121 @@ export function Data() {
                 source={source}
                 onScan={() => {
                   setSuccessfulSource(null);
-                  dryImportMutation.mutate(source.kind);
+                  dryImportMutation.mutate({
+                    source: source.kind,
+                    name: source.name,
+                    path: null,
+                  });
                 }}
                 disabled={isPending}
                 isScanning={
                   dryImportMutation.isPending &&
-                  dryImportMutation.variables === source.kind
+                  dryImportMutation.variables?.source === source.kind
                 }
                 isSuccess={successfulSource === source.kind}
               />
             ))
         )}
 
-        {(importMutation.isError || dryImportMutation.isError) && (
+        {!dryRunResult && !hasAppleNotes && (
+          <div className="flex items-center justify-between gap-4">
+            <div className="flex-1 min-w-0">
+              <h3 className="text-sm font-medium mb-1">Apple Notes</h3>
+              <p className="text-xs text-neutral-600">
+                {successfulSource === "apple_notes"
+                  ? "Imported notes from the chosen store"
+                  : "Choose a NoteStore.sqlite copied from a Mac"}
+              </p>
+            </div>
+            <Button
+              size="sm"
+              variant="outline"
+              className="shrink-0"
+              onClick={pickAppleNotesStore}
+              disabled={isPending}
+            >
+              {dryImportMutation.isPending &&
+              dryImportMutation.variables?.source === "apple_notes"
+                ? "Scanning..."
+                : "Choose"}
+            </Button>
+          </div>
+        )}
+
+        {!dryRunResult && (
+          <div className="flex items-center justify-between gap-4">
+            <div className="flex-1 min-w-0">
+              <h3 className="text-sm font-medium mb-1">Hyprnote export</h3>
+              <p className="text-xs text-neutral-600">
+                {successfulSource === "as_is"
+                  ? "Imported sessions from the chosen export"
+                  : "A folder or zip from Export all sessions"}
+              </p>
+            </div>
+            {dryImportMutation.isPending &&
+            dryImportMutation.variables?.source === "as_is" ? (
+              <Button size="sm" variant="outline" disabled>
+                Scanning...
+              </Button>
+            ) : (
+              <div className="flex items-center gap-2 shrink-0">
+                <Button
+                  size="sm"
+                  variant="outline"
+                  onClick={() => pickExport(true)}
+                  disabled={isPending}
+                >
+                  Folder
+                </Button>
+                <Button
+                  size="sm"
+                  variant="outline"
+                  onClick={() => pickExport(false)}
+                  disabled={isPending}
+                >
+                  Zip
+                </Button>
+              </div>
+            )}
+          </div>
+        )}
+
+        {!dryRunResult && (
+          <div className="flex items-center justify-between gap-4">
+            <div className="flex-1 min-w-0">
+              <h3 className="text-sm font-medium mb-1">Transcript files</h3>
+              <p className="text-xs text-neutral-600">
+                {transcriptImportMutation.data
+                  ? formatTranscriptImport(transcriptImportMutation.data)
+                  : "Otter.ai, Fireflies and other TXT, DOCX, SRT, VTT or JSON exports"}
+              </p>
+            </div>
+            <Button
+              size="sm"
+              variant="outline"
+              className="shrink-0"
+              onClick={() => transcriptImportMutation.mutate()}
+              disabled={isPending}
+            >
+              {transcriptImportMutation.isPending ? "Importing..." : "Choose"}
+            </Button>
+          </div>
+        )}
+
+        {!dryRunResult && <ExportSessions disabled={isPending} />}
+        {!dryRunResult && <ObsidianSync disabled={isPending} />}
+
+        {(importMutation.isError ||
+          dryImportMutation.isError ||
+          transcriptImportMutation.isError) && (
           <div className="flex items-center gap-2 text-xs text-red-600">
             <XCircleIcon size={14} />
             <span>
               {importMutation.isError
                 ? `Import failed: ${importMutation.error.message}`
-                : `Scan failed: ${dryImportMutation.error?.message}`}
+                : transcriptImportMutation.isError
+                  ? `Import failed: ${transcriptImportMutation.error.message}`
+                  : `Scan failed: ${dryImportMutation.error?.message}`}
             </span>
           </div>
         )}
@@ -157,3 +361,15 @@ export function Data() {
     </div>
   );
 }
+
+function formatTranscriptImport({ stats, errors }: ImportFilesResult) {
+  const imported = `Imported ${stats.sessionsCount} new meetings`;
+  if (errors.length === 0) {
+    return imported;
+  }
+
+  const failed = errors
+    .map((e) => e.path.split(/[\\/]/).pop() ?? e.path)
+    .join(", ");
+  return `${imported}. Could not read ${failed}`;
+}
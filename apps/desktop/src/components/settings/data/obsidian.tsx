@@ -0,0 +1,106 @@
+import { useMutation, useQuery } from "@tanstack/react-query";
+import { open as selectFolder } from "@tauri-apps/plugin-dialog";
+
+import { commands as settingsCommands } from "@hypr/plugin-settings";
+import { Button } from "@hypr/ui/components/ui/button";
+
+import { useConfigValue } from "../../../config/use-config";
+import { runObsidianSync } from "../../../hooks/useObsidianSync";
+import * as settings from "../../../store/tinybase/store/settings";
+
+export function ObsidianSync({ disabled }: { disabled: boolean }) {
+  const folder = useConfigValue("obsidian_sync_folder");
+  const setFolder = settings.UI.useSetValueCallback(
+    "obsidian_sync_folder",
+    (val: string) => val,
+    [],
+    settings.STORE_ID,
+  );
+
+  const { data: vaults } = useQuery({
+    queryKey: ["obsidian-vaults"],
+    queryFn: async () => {
+      const result = await settingsCommands.obsidianVaults();
+      return result.status === "ok" ? result.data : [];
+    },
+  });
+
+  const syncMutation = useMutation({
+    mutationFn: (target: string) => runObsidianSync(target),
+  });
+
+  const handleChoose = async () => {
+    const selected = await selectFolder({
+      title: "Choose a folder in your Obsidian vault",
+      directory: true,
+      defaultPath: folder || vaults?.[0]?.path,
+    });
+    if (typeof selected === "string") {
+      setFolder(selected);
+      syncMutation.mutate(selected);
+    }
+  };
+
+  const conflicts = syncMutation.data?.conflicts ?? [];
+
+  return (
+    <div className="flex flex-col gap-1">
+      <div className="flex items-center justify-between gap-4">
+        <div className="flex-1 min-w-0">
+          <h3 className="text-sm font-medium mb-1">Sync to Obsidian</h3>
+          <p className="text-xs text-neutral-600 truncate">
+            {folder
+              ? folder
+              : vaults && vaults.length > 0
+                ? "Write each session as a note in a vault folder, and bring memo edits back"
+                : "No Obsidian vault found on this device"}
+          </p>
+        </div>
+        <div className="flex gap-2 shrink-0">
+          {folder && (
+            <>
+              <Button
+                size="sm"
+                variant="outline"
+                onClick={() => syncMutation.mutate(folder)}
+                disabled={disabled || syncMutation.isPending}
+              >
+                {syncMutation.isPending ? "Syncing..." : "Sync now"}
+              </Button>
+              <Button
+                size="sm"
+                variant="ghost"
+                onClick={() => setFolder("")}
+                disabled={disabled}
+              >
+                Stop
+              </Button>
+            </>
+          )}
+          {!folder && (
+            <Button
+              size="sm"
+              variant="outline"
+              onClick={handleChoose}
+              disabled={disabled}
+            >
+              Choose folder
+            </Button>
+          )}
+        </div>
+      </div>
+      {conflicts.length > 0 && (
+        <p className="text-xs text-amber-700">
+          {conflicts.length === 1
+            ? `A note was edited in both apps. Obsidian's version was kept as "${conflicts[0].conflict_path}".`
+            : `${conflicts.length} notes were edited in both apps. Obsidian's versions were kept as "(conflict)" copies.`}
+        </p>
+      )}
+      {syncMutation.isError && (
+        <p className="text-xs text-red-600">
+          Sync failed: {syncMutation.error.message}
+        </p>
+      )}
+    </div>
+  );
+}
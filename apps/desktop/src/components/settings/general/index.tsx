@@ -18,6 +18,9 @@ import { Permissions } from "./permissions";
 import { SpokenLanguagesView } from "./spoken-languages";
 import { StorageSettingsView } from "./storage";
 import { TimezoneSelector } from "./timezone";
+import { TranscriptFormattingToggle } from "./transcript-formatting";
+import { TranslationLanguageSelector } from "./translation-language";
+import { VocabularyView } from "./vocabulary";
 import { WeekStartSelector } from "./week-start";
 
 function useSettingsForm() {
@@ -44,6 +47,7 @@ function useSettingsForm() {
         ignored_recurring_series: row.ignored_recurring_series
           ? JSON.stringify(row.ignored_recurring_series)
           : undefined,
+        vocabulary: row.vocabulary ? JSON.stringify(row.vocabulary) : undefined,
       }) satisfies Partial<GeneralStorage>,
     [],
     settings.STORE_ID,
@@ -205,6 +209,11 @@ export function SettingsApp() {
               />
             )}
           </form.Field>
+          <TranslationLanguageSelector
+            supportedLanguages={supportedLanguages}
+          />
+          <VocabularyView />
+          <TranscriptFormattingToggle />
         </div>
       </div>
 
@@ -0,0 +1,27 @@
+import { Switch } from "@hypr/ui/components/ui/switch";
+
+import { useConfigValue } from "../../../config/use-config";
+import * as settings from "../../../store/tinybase/store/settings";
+
+export function TranscriptFormattingToggle() {
+  const value = useConfigValue("transcript_formatting");
+  const setTranscriptFormatting = settings.UI.useSetValueCallback(
+    "transcript_formatting",
+    (val: boolean) => val,
+    [],
+    settings.STORE_ID,
+  );
+
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div className="flex-1">
+        <h3 className="text-sm font-medium mb-1">Format local transcripts</h3>
+        <p className="text-xs text-neutral-600">
+          Capitalize and end sentences for models that return raw text.
+          Numbers are written as digits in English only.
+        </p>
+      </div>
+      <Switch checked={value} onCheckedChange={setTranscriptFormatting} />
+    </div>
+  );
+}
@@ -0,0 +1,92 @@
+import { useQuery } from "@tanstack/react-query";
+import { useMemo } from "react";
+
+import { commands as listenerCommands } from "@hypr/plugin-listener";
+
+import { useConfigValues } from "../../../config/use-config";
+import * as settings from "../../../store/tinybase/store/settings";
+import {
+  getBaseLanguageDisplayName,
+  parseLocale,
+} from "../../../utils/language";
+import {
+  SearchableSelect,
+  type SearchableSelectOption,
+} from "./searchable-select";
+
+const OFF = "off";
+
+export function TranslationLanguageSelector({
+  supportedLanguages,
+}: {
+  supportedLanguages: readonly string[];
+}) {
+  const { translation_language, current_stt_provider, current_stt_model } =
+    useConfigValues([
+      "translation_language",
+      "current_stt_provider",
+      "current_stt_model",
+    ] as const);
+  const setTranslationLanguage = settings.UI.useSetValueCallback(
+    "translation_language",
+    (val: string) => val,
+    [],
+    settings.STORE_ID,
+  );
+
+  const capabilities = useQuery({
+    queryKey: ["stt-capabilities", current_stt_provider, current_stt_model],
+    queryFn: async () => {
+      const result = await listenerCommands.getProviderCapabilities(
+        current_stt_provider!,
+        current_stt_model ?? null,
+      );
+      return result.status === "ok" ? result.data : null;
+    },
+    enabled: !!current_stt_provider,
+  });
+
+  const options: SearchableSelectOption[] = useMemo(() => {
+    const seen = new Set<string>();
+    const languages: SearchableSelectOption[] = [];
+    for (const code of supportedLanguages) {
+      const { language } = parseLocale(code);
+      if (!seen.has(language)) {
+        seen.add(language);
+        languages.push({
+          value: language,
+          label: getBaseLanguageDisplayName(language),
+        });
+      }
+    }
+    return [{ value: OFF, label: "Off" }, ...languages];
+  }, [supportedLanguages]);
+
+  const handleChange = (val: string) => {
+    setTranslationLanguage(val === OFF ? "" : val);
+  };
+
+  const unsupported =
+    !!translation_language && capabilities.data?.translation === false;
+
+  return (
+    <div className="flex flex-row items-center justify-between">
+      <div>
+        <h3 className="text-sm font-medium mb-1">Live translation</h3>
+        <p className="text-xs text-neutral-600">
+          {unsupported
+            ? "The selected transcription model can't translate"
+            : "Show a translation alongside the live transcript"}
+        </p>
+      </div>
+      <SearchableSelect
+        value={translation_language || OFF}
+        onChange={handleChange}
+        options={options}
+        placeholder="Select language"
+        searchPlaceholder="Search language..."
+        className="w-40"
+      />
+    </div>
+  );
+}
@@ -0,0 +1,120 @@
+import { X } from "lucide-react";
+import { useState } from "react";
+
+import type { VocabularyTerm } from "@hypr/store";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+
+import { useConfigValue } from "../../../config/use-config";
+import * as settings from "../../../store/tinybase/store/settings";
+
+function parseSoundsLike(value: string): string[] {
+  return value
+    .split(",")
+    .map((s) => s.trim())
+    .filter(Boolean);
+}
+
+export function VocabularyView() {
+  const terms = useConfigValue("vocabulary");
+  const setTerms = settings.UI.useSetValueCallback(
+    "vocabulary",
+    (val: VocabularyTerm[]) => JSON.stringify(val),
+    [],
+    settings.STORE_ID,
+  );
+
+  const [text, setText] = useState("");
+  const [soundsLike, setSoundsLike] = useState("");
+
+  const handleAdd = () => {
+    const trimmed = text.trim();
+    if (!trimmed) {
+      return;
+    }
+
+    const term: VocabularyTerm = { text: trimmed };
+    const sounds_like = parseSoundsLike(soundsLike);
+    if (sounds_like.length > 0) {
+      term.sounds_like = sounds_like;
+    }
+
+    // Re-adding a term replaces it rather than listing it twice.
+    const rest = terms.filter(
+      (t) => t.text.toLowerCase() !== trimmed.toLowerCase(),
+    );
+    setTerms([...rest, term]);
+    setText("");
+    setSoundsLike("");
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent<HTMLInputElement>) => {
+    if (e.key === "Enter") {
+      e.preventDefault();
+      handleAdd();
+    }
+  };
+
+  return (
+    <div>
+      <h3 className="text-sm font-medium mb-1">Vocabulary</h3>
+      <p className="text-xs text-neutral-600 mb-3">
+        Names and terms the transcription should spell the way you do
+      </p>
+      {terms.length > 0 && (
+        <div className="flex flex-col mb-2 rounded-lg border border-neutral-200 divide-y divide-neutral-200">
+          {terms.map((term) => (
+            <div
+              key={term.text}
+              className="flex items-center justify-between px-3 py-1.5"
+            >
+              <div className="text-sm truncate">
+                {term.text}
+                {term.sounds_like && term.sounds_like.length > 0 && (
+                  <span className="ml-2 text-xs text-neutral-500">
+                    sounds like {term.sounds_like.join(", ")}
+                  </span>
+                )}
+              </div>
+              <Button
+                type="button"
+                variant="ghost"
+                size="sm"
+                className="h-6 w-6 p-0"
+                onClick={() =>
+                  setTerms(terms.filter((t) => t.text !== term.text))
+                }
+              >
+                <X className="h-3 w-3" />
+              </Button>
+            </div>
+          ))}
+        </div>
+      )}
+      <div className="flex gap-2">
+        <Input
+          value={text}
+          onChange={(e) => setText(e.target.value)}
+          onKeyDown={handleKeyDown}
+          placeholder="Term, e.g. Hyprnote"
+          className="flex-1"
+        />
+        <Input
+          value={soundsLike}
+          onChange={(e) => setSoundsLike(e.target.value)}
+          onKeyDown={handleKeyDown}
+          placeholder="Sounds like (optional)"
+          className="flex-1"
+        />
+        <Button
+          type="button"
+          variant="outline"
+          onClick={handleAdd}
+          disabled={!text.trim()}
+        >
+          Add
+        </Button>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,97 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { CheckCircle2Icon, XCircleIcon } from "lucide-react";
+
+import { commands as hooksCommands, type HookRun } from "@hypr/plugin-hooks";
+import { Button } from "@hypr/ui/components/ui/button";
+import { cn } from "@hypr/utils";
+
+const HOOK_RUNS_QUERY_KEY = "hook-runs";
+const HOOK_RUNS_LIMIT = 50;
+
+// Recent runs of the hooks configured in settings, kept across restarts.
+export function HookRuns() {
+  const queryClient = useQueryClient();
+
+  const { data: runs = [], error } = useQuery({
+    queryKey: [HOOK_RUNS_QUERY_KEY],
+    queryFn: async () => {
+      const result = await hooksCommands.listHookRuns(null, HOOK_RUNS_LIMIT);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+
+  const clearRuns = useMutation({
+    mutationFn: async () => {
+      const result = await hooksCommands.clearHookRuns();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+    },
+    onSuccess: () => {
+      void queryClient.invalidateQueries({ queryKey: [HOOK_RUNS_QUERY_KEY] });
+    },
+  });
+
+  return (
+    <div className="flex flex-col gap-2">
+      <div className="flex items-center justify-between gap-4">
+        <div className="flex-1">
+          <h3 className="text-sm font-medium mb-1">Hook Runs</h3>
+          <p className="text-xs text-neutral-600">
+            The latest commands run by your hooks and how they exited.
+          </p>
+        </div>
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={() => clearRuns.mutate()}
+          disabled={runs.length === 0 || clearRuns.isPending}
+        >
+          Clear
+        </Button>
+      </div>
+
+      {error && <p className="text-xs text-red-500">{error.message}</p>}
+
+      {runs.length > 0 && (
+        <div className="flex flex-col gap-1 max-h-64 overflow-auto">
+          {runs.map((run, index) => (
+            <HookRunItem key={`${run.started_at}-${index}`} run={run} />
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
+
+function HookRunItem({ run }: { run: HookRun }) {
+  const { result } = run;
+  const Icon = result.success ? CheckCircle2Icon : XCircleIcon;
+
+  return (
+    <div className="px-3 py-2 rounded-md border border-neutral-200 text-xs">
+      <div className="flex items-center gap-2">
+        <Icon
+          size={12}
+          className={cn([
+            "shrink-0",
+            result.success ? "text-emerald-600" : "text-red-600",
+          ])}
+        />
+        <span className="font-mono truncate flex-1">{result.command}</span>
+        <span className="text-neutral-500 shrink-0">
+          {run.event} · {new Date(run.started_at).toLocaleString()} ·{" "}
+          {result.duration_ms}ms
+        </span>
+      </div>
+      {!result.success && result.stderr && (
+        <pre className="mt-1 text-red-600 whitespace-pre-wrap line-clamp-3">
+          {result.stderr}
+        </pre>
+      )}
+    </div>
+  );
+}
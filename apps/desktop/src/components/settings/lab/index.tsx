@@ -10,6 +10,7 @@ import { cn } from "@hypr/utils";
 
 import { useConfigValue } from "../../../config/use-config";
 import * as settings from "../../../store/tinybase/store/settings";
+import { HookRuns } from "./hook-runs";
 
 export function SettingsLab() {
   const handleOpenControlWindow = async () => {
@@ -32,6 +33,8 @@ export function SettingsLab() {
 
       <MeetingReminderToggle />
 
+      <HookRuns />
+
       <DownloadButtons />
     </div>
   );
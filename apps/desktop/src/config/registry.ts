@@ -5,6 +5,7 @@ import {
   commands as localSttCommands,
   type SupportedSttModel,
 } from "@hypr/plugin-local-stt";
+import type { VocabularyTerm } from "@hypr/store";
 
 export type ConfigKey =
   | "autostart"
@@ -16,13 +17,18 @@ export type ConfigKey =
   | "current_stt_model"
   | "ai_language"
   | "spoken_languages"
+  | "translation_language"
+  | "vocabulary"
+  | "transcript_formatting"
   | "save_recordings"
   | "telemetry_consent"
   | "current_llm_provider"
   | "current_llm_model"
+  | "extract_action_items"
   | "timezone"
   | "week_start"
-  | "notification_in_meeting_reminder";
+  | "notification_in_meeting_reminder"
+  | "obsidian_sync_folder";
 
 type ConfigValueType<K extends ConfigKey> =
   (typeof CONFIG_REGISTRY)[K]["default"];
@@ -123,6 +129,21 @@ export const CONFIG_REGISTRY = {
     default: ["en"] as string[],
   },
 
+  translation_language: {
+    key: "translation_language",
+    default: undefined as string | undefined,
+  },
+
+  vocabulary: {
+    key: "vocabulary",
+    default: [] as VocabularyTerm[],
+  },
+
+  transcript_formatting: {
+    key: "transcript_formatting",
+    default: true,
+  },
+
   save_recordings: {
     key: "save_recordings",
     default: true,
@@ -143,6 +164,11 @@ export const CONFIG_REGISTRY = {
     default: undefined,
   },
 
+  extract_action_items: {
+    key: "extract_action_items",
+    default: false,
+  },
+
   timezone: {
     key: "timezone",
     default: undefined as string | undefined,
@@ -157,4 +183,9 @@ export const CONFIG_REGISTRY = {
     key: "notification_in_meeting_reminder",
     default: true,
   },
+
+  obsidian_sync_folder: {
+    key: "obsidian_sync_folder",
+    default: undefined as string | undefined,
+  },
 } satisfies Record<ConfigKey, ConfigDefinition>;
@@ -28,6 +28,15 @@ interface AudioPlayerContextValue {
 
 const AudioPlayerContext = createContext<AudioPlayerContextValue | null>(null);
 
+// Seeks requested before a session's player exists, e.g. from a deep link.
+const pendingSeeks = new Map<string, number>();
+const pendingSeekListeners = new Set<() => void>();
+
+export function requestSeek(sessionId: string, sec: number) {
+  pendingSeeks.set(sessionId, sec);
+  pendingSeekListeners.forEach((listener) => listener());
+}
+
 export function useAudioPlayer() {
   const context = useContext(AudioPlayerContext);
   if (!context) {
@@ -62,6 +71,28 @@ export function AudioPlayerProvider({
     },
   });
 
+  const [isReady, setIsReady] = useState(false);
+
+  useEffect(() => {
+    if (!wavesurfer || !isReady) {
+      return;
+    }
+
+    const applyPendingSeek = () => {
+      const sec = pendingSeeks.get(sessionId);
+      if (sec !== undefined) {
+        pendingSeeks.delete(sessionId);
+        wavesurfer.setTime(sec);
+      }
+    };
+
+    applyPendingSeek();
+    pendingSeekListeners.add(applyPendingSeek);
+    return () => {
+      pendingSeekListeners.delete(applyPendingSeek);
+    };
+  }, [wavesurfer, isReady, sessionId]);
+
   const registerContainer = useCallback((el: HTMLDivElement | null) => {
     setContainer((prev) => (prev === el ? prev : el));
   }, []);
@@ -102,6 +133,7 @@ export function AudioPlayerProvider({
       }
 
       setDuration(media.duration);
+      setIsReady(true);
 
       audioContext = new AudioContext();
       if (audioContext.state === "suspended") {
@@ -144,6 +176,7 @@ export function AudioPlayerProvider({
     return () => {
       ws.destroy();
       setWavesurfer(null);
+      setIsReady(false);
       audio.pause();
       audio.src = "";
       audio.load();
@@ -0,0 +1,127 @@
+import { useEffect, useRef } from "react";
+
+import { events as deeplink2Events } from "@hypr/plugin-deeplink2";
+import { commands as importerCommands } from "@hypr/plugin-importer";
+
+import { requestSeek } from "../contexts/audio-player/provider";
+import { useListener } from "../contexts/listener";
+import {
+  getExistingHumans,
+  importData,
+} from "../store/tinybase/store/importer";
+import * as main from "../store/tinybase/store/main";
+import { save } from "../store/tinybase/store/save";
+import {
+  createSession,
+  getOrCreateSessionForEventId,
+} from "../store/tinybase/store/sessions";
+import { useTabs } from "../store/zustand/tabs";
+
+// Links that drive the app from launchers and scripts. State-changing ones
+// have already been confirmed by the user in a native prompt.
+export function useAutomationDeeplinks() {
+  const store = main.UI.useStore(main.STORE_ID);
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+  const openNew = useTabs((state) => state.openNew);
+  const liveStatus = useListener((state) => state.live.status);
+  const stop = useListener((state) => state.stop);
+
+  const latest = useRef({ store, user_id, openNew, liveStatus, stop });
+  latest.current = { store, user_id, openNew, liveStatus, stop };
+
+  useEffect(() => {
+    const unlisten = deeplink2Events.deepLinkEvent.listen(({ payload }) => {
+      const { store, user_id, openNew, liveStatus, stop } = latest.current;
+      if (!store) {
+        return;
+      }
+
+      switch (payload.to) {
+        case "/listen/start": {
+          if (liveStatus !== "inactive") {
+            console.warn("[deeplink] already listening");
+            return;
+          }
+          const { title, event_id } = payload.search;
+          const sessionId = event_id
+            ? getOrCreateSessionForEventId(store, event_id, title ?? undefined)
+            : createSession(store, title ?? undefined);
+          openNew({
+            type: "sessions",
+            id: sessionId,
+            state: { view: null, autoStart: true },
+          });
+          return;
+        }
+        case "/listen/stop": {
+          if (liveStatus === "active") {
+            stop();
+          }
+          return;
+        }
+        case "/session/open": {
+          const { id, t } = payload.search;
+          if (!store.hasRow("sessions", id)) {
+            console.warn(`[deeplink] unknown session ${id}`);
+            return;
+          }
+          openNew({
+            type: "sessions",
+            id,
+            state: {
+              view: t !== null ? { type: "transcript" } : null,
+              autoStart: null,
+            },
+          });
+          if (t !== null) {
+            requestSeek(id, t);
+          }
+          return;
+        }
+        case "/search": {
+          openNew({
+            type: "search",
+            state: { selectedTypes: null, initialQuery: payload.search.q },
+          });
+          return;
+        }
+        case "/import": {
+          void importTranscript(
+            store as main.Store,
+            user_id ?? "",
+            payload.search.path,
+          ).catch((error) => console.error("[deeplink] import failed", error));
+          return;
+        }
+      }
+    });
+
+    return () => {
+      void unlisten.then((fn) => fn());
+    };
+  }, []);
+}
+
+async function importTranscript(
+  store: main.Store,
+  userId: string,
+  path: string,
+) {
+  const result = await importerCommands.runImportFiles(
+    [path],
+    userId,
+    store.getRowIds("sessions"),
+    getExistingHumans(store),
+  );
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  if (result.data.errors[0]) {
+    throw new Error(result.data.errors[0].error);
+  }
+
+  const importResult = await importData(store, result.data.data, save);
+  if (importResult.status === "error") {
+    throw new Error(importResult.error);
+  }
+}
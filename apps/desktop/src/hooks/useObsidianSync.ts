@@ -0,0 +1,79 @@
+import { useEffect } from "react";
+
+import { commands as fsSyncCommands } from "@hypr/plugin-fs-sync";
+import { events as notifyEvents } from "@hypr/plugin-notify";
+
+import { useConfigValue } from "../config/use-config";
+import * as main from "../store/tinybase/store/main";
+import { save } from "../store/tinybase/store/save";
+
+const OBSIDIAN_EDIT_DEBOUNCE_MS = 1500;
+const STORE_CHANGE_DEBOUNCE_MS = 10000;
+
+export async function runObsidianSync(folder: string) {
+  // The sync reads the vault from disk, so flush pending edits first.
+  await save();
+
+  const result = await fsSyncCommands.obsidianSync(folder, null);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  for (const conflict of result.data.conflicts) {
+    console.warn(
+      `[obsidian] conflicting edits in ${conflict.note_path}, Obsidian version saved as ${conflict.conflict_path}`,
+    );
+  }
+
+  return result.data;
+}
+
+export function useObsidianSync() {
+  const folder = useConfigValue("obsidian_sync_folder");
+  const store = main.UI.useStore(main.STORE_ID);
+
+  useEffect(() => {
+    if (!folder || !store) {
+      return;
+    }
+
+    let cancelled = false;
+    let running: Promise<unknown> = Promise.resolve();
+    let timeout: ReturnType<typeof setTimeout> | null = null;
+
+    const sync = () => {
+      running = running
+        .then(() => (cancelled ? undefined : runObsidianSync(folder)))
+        .catch((error) => console.error("[obsidian] sync failed", error));
+    };
+
+    const schedule = (delay: number) => {
+      if (timeout) clearTimeout(timeout);
+      timeout = setTimeout(sync, delay);
+    };
+
+    sync();
+    void fsSyncCommands.obsidianWatch(folder);
+
+    const unlistenPromise = notifyEvents.externalFileChanged.listen(
+      (event) => {
+        const { root, path } = event.payload;
+        if (root === folder && path.endsWith(".md")) {
+          schedule(OBSIDIAN_EDIT_DEBOUNCE_MS);
+        }
+      },
+    );
+
+    const listenerId = store.addDidFinishTransactionListener(() => {
+      schedule(STORE_CHANGE_DEBOUNCE_MS);
+    });
+
+    return () => {
+      cancelled = true;
+      if (timeout) clearTimeout(timeout);
+      store.delListener(listenerId);
+      void unlistenPromise.then((unlisten) => unlisten());
+      void fsSyncCommands.obsidianUnwatch(folder);
+    };
+  }, [folder, store]);
+}
@@ -2,8 +2,11 @@ import { useCallback, useRef } from "react";
 
 import type { BatchParams } from "@hypr/plugin-listener2";
 
+import { useAuth } from "../auth";
+import { useBillingAccess } from "../billing";
 import { useConfigValue } from "../config/use-config";
 import { useListener } from "../contexts/listener";
+import { env } from "../env";
 import * as main from "../store/tinybase/store/main";
 import type { SpeakerHintWithId, WordWithId } from "../store/transcript/types";
 import {
@@ -15,6 +18,7 @@ import {
 import type { HandlePersistCallback } from "../store/zustand/listener/transcript";
 import { type Tab, useTabs } from "../store/zustand/tabs";
 import { id } from "../utils";
+import { fireEventHooks, sessionResourceDir } from "../utils/event-hooks";
 import { useKeywords } from "./useKeywords";
 import { useSTTConnection } from "./useSTTConnection";
 
@@ -60,6 +64,8 @@ export const useRunBatch = (sessionId: string) => {
   const sessionTabRef = useRef(sessionTab);
   sessionTabRef.current = sessionTab;
 
+  const auth = useAuth();
+  const { isPro } = useBillingAccess();
   const { conn } = useSTTConnection();
   const keywords = useKeywords(sessionId);
   const languages = useConfigValue("spoken_languages");
@@ -173,12 +179,31 @@ export const useRunBatch = (sessionId: string) => {
         api_key: options?.apiKey ?? conn.apiKey,
         keywords: options?.keywords ?? keywords ?? [],
         languages: options?.languages ?? languages ?? [],
+        // Large files go through cloud storage only for Hyprnote Pro, since
+        // the proxy's upload routes need the entitlement. Everything else is
+        // sent directly.
+        upload:
+          auth?.session && isPro && conn.provider === "hyprnote"
+            ? { api_base: new URL("/stt", env.VITE_API_URL).toString() }
+            : null,
       };
 
       await runBatch(params, { handlePersist: persist, sessionId });
+
+      fireEventHooks("TranscriptFinalized", async () => ({
+        transcriptFinalized: {
+          args: {
+            resource_dir: await sessionResourceDir(sessionId),
+            session_id: sessionId,
+            source: "batch",
+          },
+        },
+      }));
     },
     [
+      auth?.session,
       conn,
+      isPro,
       keywords,
       languages,
       runBatch,
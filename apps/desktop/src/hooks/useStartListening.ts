@@ -23,6 +23,8 @@ export function useStartListening(sessionId: string) {
 
   const record_enabled = useConfigValue("save_recordings");
   const languages = useConfigValue("spoken_languages");
+  const translation_language = useConfigValue("translation_language");
+  const transcript_formatting = useConfigValue("transcript_formatting");
 
   const start = useListener((state) => state.start);
   const { conn } = useSTTConnection();
@@ -119,6 +121,26 @@ export function useStartListening(sessionId: string) {
       });
     };
 
+    const participants = store
+      .getRowIds("mapping_session_participant")
+      .filter(
+        (rowId) =>
+          store.getCell("mapping_session_participant", rowId, "session_id") ===
+          sessionId,
+      )
+      .map((rowId) => {
+        const humanId = store.getCell(
+          "mapping_session_participant",
+          rowId,
+          "human_id",
+        );
+        return humanId ? store.getCell("humans", humanId, "name") : undefined;
+      })
+      .filter((name): name is string => !!name);
+    const eventTitle = eventId
+      ? store.getCell("events", eventId, "title")
+      : undefined;
+
     start(
       {
         session_id: sessionId,
@@ -129,6 +151,10 @@ export function useStartListening(sessionId: string) {
         base_url: conn.baseUrl,
         api_key: conn.apiKey,
         keywords,
+        translation_language: translation_language || null,
+        transcript_formatting,
+        participants,
+        event_title: eventTitle ?? null,
       },
       {
         handlePersist,
@@ -143,6 +169,8 @@ export function useStartListening(sessionId: string) {
     user_id,
     record_enabled,
     languages,
+    translation_language,
+    transcript_formatting,
   ]);
 
   return startListening;
@@ -14,7 +14,9 @@ import { SearchUIProvider } from "../../../contexts/search/ui";
 import { ShellProvider } from "../../../contexts/shell";
 import { useRegisterTools } from "../../../contexts/tool";
 import { ToolRegistryProvider } from "../../../contexts/tool";
+import { useAutomationDeeplinks } from "../../../hooks/useAutomationDeeplinks";
 import { useDeeplinkHandler } from "../../../hooks/useDeeplinkHandler";
+import { useObsidianSync } from "../../../hooks/useObsidianSync";
 import { deleteSessionCascade } from "../../../store/tinybase/store/deleteSession";
 import * as main from "../../../store/tinybase/store/main";
 import { isSessionEmpty } from "../../../store/tinybase/store/sessions";
@@ -45,6 +47,8 @@ function Component() {
   const indexes = main.UI.useIndexes(main.STORE_ID);
 
   useDeeplinkHandler();
+  useAutomationDeeplinks();
+  useObsidianSync();
 
   const openDefaultEmptyTab = useCallback(() => {
     openNew({ type: "empty" });
@@ -116,8 +120,21 @@ function Component() {
 
 function ToolRegistration() {
   const { search } = useSearchEngine();
+  const store = main.UI.useStore(main.STORE_ID);
+
+  const getSessionTitle = useCallback(
+    (sessionId: string) => {
+      const title = store?.getCell("sessions", sessionId, "title");
+      return typeof title === "string" && title ? title : null;
+    },
+    [store],
+  );
 
-  useRegisterTools("chat-general", () => buildChatTools({ search }), [search]);
+  useRegisterTools(
+    "chat-general",
+    () => buildChatTools({ search, getSessionTitle }),
+    [search, getSessionTitle],
+  );
 
   return null;
 }
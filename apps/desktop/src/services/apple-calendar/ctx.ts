@@ -15,21 +15,27 @@ export interface Ctx {
   to: Date;
   calendarIds: Set<string>;
   calendarTrackingIdToId: Map<string, string>;
+  calendarTrackingIdToProvider: Map<string, string>;
 }
 
 // ---
 
 export function createCtx(store: Store, queries: Queries<Schemas>): Ctx | null {
-  const resultTable = queries.getResultTable(QUERIES.enabledAppleCalendars);
+  const resultTable = queries.getResultTable(QUERIES.enabledCalendars);
 
   const calendarIds = new Set(Object.keys(resultTable));
   const calendarTrackingIdToId = new Map<string, string>();
+  const calendarTrackingIdToProvider = new Map<string, string>();
 
   for (const calendarId of calendarIds) {
     const calendar = store.getRow("calendars", calendarId);
     const trackingId = calendar?.tracking_id_calendar as string | undefined;
     if (trackingId) {
       calendarTrackingIdToId.set(trackingId, calendarId);
+      calendarTrackingIdToProvider.set(
+        trackingId,
+        resultTable[calendarId].provider,
+      );
     }
   }
 
@@ -51,6 +57,7 @@ export function createCtx(store: Store, queries: Queries<Schemas>): Ctx | null {
     to,
     calendarIds,
     calendarTrackingIdToId,
+    calendarTrackingIdToProvider,
   };
 }
 
@@ -1,4 +1,8 @@
-import type { AppleEvent, Participant } from "@hypr/plugin-apple-calendar";
+import type {
+  AppleEvent,
+  Participant,
+  RemoteEvent,
+} from "@hypr/plugin-apple-calendar";
 import { commands as appleCalendarCommands } from "@hypr/plugin-apple-calendar";
 import { commands as miscCommands } from "@hypr/plugin-misc";
 
@@ -26,29 +30,45 @@ export async function fetchIncomingEvents(ctx: Ctx): Promise<{
   participants: IncomingParticipants;
 }> {
   const trackingIds = Array.from(ctx.calendarTrackingIdToId.keys());
+  const isCaldav = (trackingId: string) =>
+    ctx.calendarTrackingIdToProvider.get(trackingId) === "caldav";
+
+  if (trackingIds.some(isCaldav)) {
+    // A failed sync still leaves the previously synced events to list below.
+    const synced = await appleCalendarCommands.syncRemoteCalendars(ctx.userId);
+    if (synced.status === "error") {
+      console.error(`[calendar-sync] CalDAV sync failed: ${synced.error}`);
+    }
+  }
 
   const results = await Promise.all(
     trackingIds.map(async (trackingId) => {
-      const result = await appleCalendarCommands.listEvents({
+      const filter = {
         calendar_tracking_id: trackingId,
         from: ctx.from.toISOString(),
         to: ctx.to.toISOString(),
-      });
+      };
+
+      if (isCaldav(trackingId)) {
+        const result = await appleCalendarCommands.listRemoteEvents(filter);
+        if (result.status === "error") {
+          throw new CalendarFetchError(trackingId, result.error);
+        }
+        return Promise.all(result.data.map(normalizeRemoteEvent));
+      }
 
+      const result = await appleCalendarCommands.listEvents(filter);
       if (result.status === "error") {
         throw new CalendarFetchError(trackingId, result.error);
       }
-
-      return result.data;
+      return Promise.all(result.data.map(normalizeAppleEvent));
     }),
   );
 
-  const appleEvents = results.flat();
   const events: IncomingEvent[] = [];
   const participants: IncomingParticipants = new Map();
 
-  for (const appleEvent of appleEvents) {
-    const { event, eventParticipants } = await normalizeAppleEvent(appleEvent);
+  for (const { event, eventParticipants } of results.flat()) {
     events.push(event);
     if (eventParticipants.length > 0) {
       participants.set(event.tracking_id_event, eventParticipants);
@@ -58,6 +78,36 @@ export async function fetchIncomingEvents(ctx: Ctx): Promise<{
   return { events, participants };
 }
 
+// Recurring CalDAV events arrive already expanded, one tracking id per instance.
+async function normalizeRemoteEvent(remoteEvent: RemoteEvent): Promise<{
+  event: IncomingEvent;
+  eventParticipants: EventParticipant[];
+}> {
+  const meetingLink =
+    remoteEvent.url ?? (await extractMeetingLink(remoteEvent.notes));
+
+  return {
+    event: {
+      tracking_id_event: remoteEvent.tracking_id,
+      tracking_id_calendar: remoteEvent.calendar_tracking_id,
+      title: remoteEvent.title,
+      started_at: remoteEvent.start_date,
+      ended_at: remoteEvent.end_date,
+      meeting_link: meetingLink ?? undefined,
+      description: remoteEvent.notes ?? undefined,
+      recurrence_series_id: remoteEvent.series_id ?? undefined,
+      has_recurrence_rules: false,
+      is_all_day: false,
+    },
+    eventParticipants: remoteEvent.participants.map((participant) => ({
+      name: participant.name ?? undefined,
+      email: participant.email ?? undefined,
+      is_organizer: false,
+      is_current_user: false,
+    })),
+  };
+}
+
 async function normalizeAppleEvent(appleEvent: AppleEvent): Promise<{
   event: IncomingEvent;
   eventParticipants: EventParticipant[];
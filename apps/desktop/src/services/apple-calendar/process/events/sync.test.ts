@@ -58,6 +58,9 @@ function createMockCtx(
     calendarTrackingIdToId:
       overrides.calendarTrackingIdToId ??
       new Map([["tracking-cal-1", "cal-1"]]),
+    calendarTrackingIdToProvider:
+      overrides.calendarTrackingIdToProvider ??
+      new Map([["tracking-cal-1", "apple"]]),
     store,
     ...overrides,
   };
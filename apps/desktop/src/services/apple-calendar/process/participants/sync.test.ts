@@ -35,6 +35,7 @@ function createMockCtx(store: Ctx["store"]): Ctx {
     to: new Date("2024-02-01"),
     calendarIds: new Set(["cal-1"]),
     calendarTrackingIdToId: new Map([["tracking-cal-1", "cal-1"]]),
+    calendarTrackingIdToProvider: new Map([["tracking-cal-1", "apple"]]),
   };
 }
 
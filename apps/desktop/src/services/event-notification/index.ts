@@ -6,6 +6,7 @@ import {
 
 import type * as main from "../../store/tinybase/store/main";
 import type * as settings from "../../store/tinybase/store/settings";
+import { fireEventHooks } from "../../utils/event-hooks";
 
 export const EVENT_NOTIFICATION_TASK_ID = "eventNotification";
 export const EVENT_NOTIFICATION_INTERVAL = 30 * 1000; // 30 sec
@@ -60,11 +61,12 @@ export function checkEventNotifications(
   settingsStore: settings.Store,
   notifiedEvents: NotifiedEventsMap,
 ) {
-  const notificationEnabled = settingsStore?.getValue("notification_event");
-  if (!notificationEnabled || !store) {
+  if (!store) {
     return;
   }
 
+  const notificationEnabled = settingsStore?.getValue("notification_event");
+
   const now = Date.now();
 
   for (const [key, timestamp] of notifiedEvents) {
@@ -81,8 +83,28 @@ export function checkEventNotifications(
     const timeUntilStart = startTime.getTime() - now;
     const notificationKey = `event-${eventId}-${startTime.getTime()}`;
 
+    const hookKey = `hook-${notificationKey}`;
+
     if (timeUntilStart > 0 && timeUntilStart <= NOTIFY_WINDOW_MS) {
-      if (notifiedEvents.has(notificationKey)) {
+      const sessionId = getSessionIdForEvent(store, eventId);
+
+      // Hooks fire regardless of whether notifications are enabled.
+      if (!notifiedEvents.has(hookKey)) {
+        notifiedEvents.set(hookKey, now);
+        fireEventHooks("CalendarEventStarting", () => ({
+          calendarEventStarting: {
+            args: {
+              event_id: eventId,
+              title: String(event.title ?? ""),
+              started_at: startTime.toISOString(),
+              meeting_link: (event.meeting_link as string) || null,
+              session_id: sessionId,
+            },
+          },
+        }));
+      }
+
+      if (!notificationEnabled || notifiedEvents.has(notificationKey)) {
         return;
       }
 
@@ -99,7 +121,6 @@ export function checkEventNotifications(
       };
 
       let participants: Participant[] | null = null;
-      const sessionId = getSessionIdForEvent(store, eventId);
       if (sessionId) {
         const sessionParticipants = getParticipantsForSession(store, sessionId);
         if (sessionParticipants.length > 0) {
@@ -120,6 +141,7 @@ export function checkEventNotifications(
       });
     } else if (timeUntilStart <= 0) {
       notifiedEvents.delete(notificationKey);
+      notifiedEvents.delete(hookKey);
     }
   });
 }
@@ -38,6 +38,7 @@ export function processMetaFile(
       folder_id: folderPath,
       event_id: meta.event_id,
       raw_md: "",
+      enhance_template_id: meta.enhance_template_id,
     };
 
     for (const participant of meta.participants) {
@@ -41,6 +41,7 @@ export function tablesToSessionMetaMap(
         created_at: session.created_at ?? "",
         title: session.title ?? "",
         event_id: session.event_id || undefined,
+        enhance_template_id: session.enhance_template_id || undefined,
         participants: participantsBySession.get(session.id) ?? [],
         tags: tagsBySession.get(session.id),
       },
@@ -81,6 +82,7 @@ function collectSessionMetas(ctx: BuildContext): MetaItem[] {
         created_at: session.created_at ?? "",
         title: session.title ?? "",
         event_id: session.event_id || undefined,
+        enhance_template_id: session.enhance_template_id || undefined,
         participants: participantsBySession.get(session.id) ?? [],
         tags: tagsBySession.get(session.id),
       };
@@ -14,6 +14,7 @@ export type SessionMetaJson = Pick<
 > & {
   id: string;
   event_id?: string;
+  enhance_template_id?: string;
   participants: ParticipantData[];
   tags?: string[];
 };
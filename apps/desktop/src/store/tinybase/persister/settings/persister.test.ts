@@ -102,6 +102,10 @@ describe("settingsPersister roundtrip", () => {
       language: {
         ai_language: "en",
         spoken_languages: ["en", "ko"],
+        vocabulary: [
+          { text: "Hyprnote", sounds_like: ["hyper note"] },
+          { text: "Yujong" },
+        ],
       },
     };
 
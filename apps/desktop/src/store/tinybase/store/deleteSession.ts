@@ -1,5 +1,7 @@
+import { commands as db2Commands } from "@hypr/plugin-db2";
 import { commands as fsSyncCommands } from "@hypr/plugin-fs-sync";
 
+import { fireEventHooks } from "../../../utils/event-hooks";
 import type { DeletedSessionData } from "../../zustand/undo-delete";
 import * as main from "./main";
 
@@ -37,6 +39,7 @@ export function captureSessionData(
     event_id: sessionRow.event_id as string,
     title: sessionRow.title as string,
     raw_md: sessionRow.raw_md as string,
+    enhance_template_id: sessionRow.enhance_template_id as string,
   };
 
   const transcripts: DeletedSessionData["transcripts"] = [];
@@ -143,6 +146,7 @@ export function restoreSessionData(
       event_id: session.event_id,
       title: session.title,
       raw_md: session.raw_md,
+      enhance_template_id: session.enhance_template_id,
     });
 
     for (const transcript of transcripts) {
@@ -232,4 +236,8 @@ export function deleteSessionCascade(
   }
 
   void fsSyncCommands.audioDelete(sessionId);
+  void db2Commands.deleteExtraction(sessionId);
+  fireEventHooks("SessionDeleted", () => ({
+    sessionDeleted: { args: { session_id: sessionId } },
+  }));
 }
@@ -1,5 +1,6 @@
 import { createMergeableStore } from "tinybase/with-schemas";
 
+import type { ExistingHuman } from "@hypr/plugin-importer";
 import { SCHEMA } from "@hypr/store";
 import { isValidTiptapContent, md2json } from "@hypr/tiptap/shared";
 
@@ -140,3 +141,15 @@ export const importData = async (
     return { status: "error", error: errorMessage };
   }
 };
+
+// Lets imported transcripts link speakers to people already in the app.
+export const getExistingHumans = (store: Store): ExistingHuman[] => {
+  const humans: ExistingHuman[] = [];
+  store.forEachRow("humans", (id, _forEachCell) => {
+    const name = store.getCell("humans", id, "name");
+    if (name) {
+      humans.push({ id, name });
+    }
+  });
+  return humans;
+};
@@ -184,13 +184,15 @@ export const StoreComponent = () => {
           },
         )
         .setQueryDefinition(
-          QUERIES.enabledAppleCalendars,
+          QUERIES.enabledCalendars,
           "calendars",
           ({ select, where }) => {
             select("provider");
             where(
               (getCell) =>
-                getCell("enabled") === true && getCell("provider") === "apple",
+                getCell("enabled") === true &&
+                (getCell("provider") === "apple" ||
+                  getCell("provider") === "caldav"),
             );
           },
         )
@@ -357,7 +359,7 @@ export const QUERIES = {
   visibleChatShortcuts: "visibleChatShortcuts",
   sessionParticipantsWithDetails: "sessionParticipantsWithDetails",
   sessionRecordingTimes: "sessionRecordingTimes",
-  enabledAppleCalendars: "enabledAppleCalendars",
+  enabledCalendars: "enabledCalendars",
   userTemplates: "userTemplates",
 } as const;
 
@@ -445,7 +447,7 @@ interface _QueryResultRows {
     min_started_at: number;
     max_ended_at: number;
   };
-  enabledAppleCalendars: {
+  enabledCalendars: {
     provider: string;
   };
   userTemplates: {
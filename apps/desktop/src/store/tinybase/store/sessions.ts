@@ -1,8 +1,11 @@
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
+import { commands as db2Commands } from "@hypr/plugin-db2";
+import type { PreviousMeeting } from "@hypr/plugin-template";
 import { json2md } from "@hypr/tiptap/shared";
 
 import { DEFAULT_USER_ID } from "../../../utils";
 import { id } from "../../../utils";
+import { fireEventHooks, sessionResourceDir } from "../../../utils/event-hooks";
 import * as main from "./main";
 
 type Store = NonNullable<ReturnType<typeof main.UI.useStore>>;
@@ -19,6 +22,7 @@ export function createSession(store: Store, title?: string): string {
     event: "note_created",
     has_event_id: false,
   });
+  fireSessionCreated(sessionId, title, null);
   return sessionId;
 }
 
@@ -52,9 +56,110 @@ export function getOrCreateSessionForEventId(
     event: "note_created",
     has_event_id: true,
   });
+  fireSessionCreated(sessionId, title, eventId);
   return sessionId;
 }
 
+function fireSessionCreated(
+  sessionId: string,
+  title: string | undefined,
+  eventId: string | null,
+) {
+  fireEventHooks("SessionCreated", async () => ({
+    sessionCreated: {
+      args: {
+        resource_dir: await sessionResourceDir(sessionId),
+        session_id: sessionId,
+        title: title || null,
+        event_id: eventId,
+      },
+    },
+  }));
+}
+
+// Latest earlier session whose event belongs to the same recurring series,
+// with the action items still open from it.
+export async function getPreviousMeeting(
+  store: Store,
+  sessionId: string,
+): Promise<PreviousMeeting | null> {
+  const eventId = store.getCell("sessions", sessionId, "event_id");
+  if (!eventId) {
+    return null;
+  }
+
+  const seriesId = store.getCell("events", eventId, "recurrence_series_id");
+  const startedAt = store.getCell("events", eventId, "started_at");
+  if (!seriesId || !startedAt) {
+    return null;
+  }
+
+  const candidates: Array<{ sessionId: string; startedAt: string }> = [];
+  store.forEachRow("sessions", (candidateId, _forEachCell) => {
+    const candidateEventId = store.getCell("sessions", candidateId, "event_id");
+    if (candidateId === sessionId || !candidateEventId) {
+      return;
+    }
+
+    const row = store.getRow("events", candidateEventId);
+    if (
+      row?.recurrence_series_id === seriesId &&
+      row.started_at &&
+      Date.parse(row.started_at) < Date.parse(startedAt)
+    ) {
+      candidates.push({ sessionId: candidateId, startedAt: row.started_at });
+    }
+  });
+
+  const previous = candidates.sort(
+    (a, b) => Date.parse(b.startedAt) - Date.parse(a.startedAt),
+  )[0];
+  if (!previous) {
+    return null;
+  }
+
+  const notes: Array<{ position: number; content: string }> = [];
+  store.forEachRow("enhanced_notes", (rowId, _forEachCell) => {
+    const row = store.getRow("enhanced_notes", rowId);
+    if (row?.session_id === previous.sessionId && row.content) {
+      notes.push({ position: row.position ?? 0, content: row.content });
+    }
+  });
+  notes.sort((a, b) => a.position - b.position);
+
+  let summary: string | null = null;
+  if (notes[0]) {
+    try {
+      summary = json2md(JSON.parse(notes[0].content));
+    } catch {
+      summary = notes[0].content;
+    }
+  }
+
+  const actionItems = await db2Commands.listOpenActionItems({
+    owner_id: null,
+    session_id: previous.sessionId,
+    limit: null,
+  });
+  if (actionItems.status === "error") {
+    console.error(actionItems.error);
+  }
+
+  return {
+    title: store.getCell("sessions", previous.sessionId, "title") || null,
+    date: previous.startedAt,
+    summary: summary?.trim() || null,
+    actionItems:
+      actionItems.status === "ok"
+        ? actionItems.data.map((item) => ({
+            text: item.text,
+            owner: item.owner_name,
+            done: item.done,
+          }))
+        : [],
+  };
+}
+
 export function isSessionEmpty(store: Store, sessionId: string): boolean {
   const session = store.getRow("sessions", sessionId);
   if (!session) {
@@ -41,6 +41,18 @@ export const SETTINGS_MAPPING = {
       type: "string",
       path: ["language", "spoken_languages"],
     },
+    translation_language: {
+      type: "string",
+      path: ["language", "translation_language"],
+    },
+    vocabulary: {
+      type: "string",
+      path: ["language", "vocabulary"],
+    },
+    transcript_formatting: {
+      type: "boolean",
+      path: ["language", "transcript_formatting"],
+    },
     ignored_platforms: {
       type: "string",
       path: ["notification", "ignored_platforms"],
@@ -53,6 +65,10 @@ export const SETTINGS_MAPPING = {
       type: "string",
       path: ["ai", "current_llm_model"],
     },
+    extract_action_items: {
+      type: "boolean",
+      path: ["ai", "extract_action_items"],
+    },
     current_stt_provider: {
       type: "string",
       path: ["ai", "current_stt_provider"],
@@ -73,6 +89,10 @@ export const SETTINGS_MAPPING = {
       type: "boolean",
       path: ["notification", "in_meeting_reminder"],
     },
+    obsidian_sync_folder: {
+      type: "string",
+      path: ["obsidian", "sync_folder"],
+    },
   },
   tables: {
     ai_providers: {
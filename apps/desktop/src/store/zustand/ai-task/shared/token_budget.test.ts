@@ -0,0 +1,63 @@
+import { describe, expect, it, vi } from "vitest";
+
+import {
+  getTokenBudget,
+  hostedContextTokens,
+  inputTokensFor,
+  ollamaContextTokens,
+} from "./token_budget";
+
+vi.mock("@tauri-apps/plugin-http", () => ({ fetch: vi.fn() }));
+vi.mock("@lmstudio/sdk", () => ({ LMStudioClient: vi.fn() }));
+vi.mock("ollama/browser", () => ({ Ollama: vi.fn() }));
+
+describe("hostedContextTokens", () => {
+  it("matches by model prefix, ignoring the vendor", () => {
+    expect(hostedContextTokens("claude-sonnet-4-5")).toBe(200_000);
+    expect(hostedContextTokens("anthropic/claude-sonnet-4-5")).toBe(200_000);
+    expect(hostedContextTokens("gpt-4.1-mini")).toBe(1_047_576);
+  });
+
+  it("falls back for unknown models", () => {
+    expect(hostedContextTokens("some-new-model")).toBe(128_000);
+  });
+});
+
+describe("inputTokensFor", () => {
+  it("keeps room for the output", () => {
+    expect(inputTokensFor(128_000)).toBe(128_000 - 4_096);
+    expect(inputTokensFor(4_096)).toBe(3_072);
+  });
+});
+
+describe("ollamaContextTokens", () => {
+  it("uses num_ctx, capped by the trained context", () => {
+    expect(
+      ollamaContextTokens("stop <eos>\nnum_ctx 32768", {
+        "llama.context_length": 8_192,
+      }),
+    ).toBe(8_192);
+    expect(
+      ollamaContextTokens("num_ctx    16384", {
+        "qwen2.context_length": 32_768,
+      }),
+    ).toBe(16_384);
+  });
+
+  it("defaults to Ollama's runtime context", () => {
+    expect(ollamaContextTokens(undefined, undefined)).toBe(4_096);
+  });
+});
+
+describe("getTokenBudget", () => {
+  it("estimates for hosted providers", async () => {
+    const budget = await getTokenBudget({
+      providerId: "anthropic",
+      modelId: "claude-sonnet-4-5",
+      baseUrl: "https://api.anthropic.com/v1",
+    });
+
+    expect(budget.maxInputTokens).toBe(200_000 - 4_096);
+    expect(await budget.countTokens("abcdefgh")).toBe(2);
+  });
+});
@@ -0,0 +1,195 @@
+import { LMStudioClient } from "@lmstudio/sdk";
+import { fetch as tauriFetch } from "@tauri-apps/plugin-http";
+import { Ollama } from "ollama/browser";
+
+export type LLMTarget = {
+  providerId: string;
+  modelId: string;
+  baseUrl: string;
+};
+
+export type TokenBudget = {
+  // Tokens the system and user prompt may use together.
+  maxInputTokens: number;
+  countTokens: (text: string) => Promise<number>;
+};
+
+// Room kept for the generated note, capped at a quarter of small windows.
+const RESERVED_OUTPUT_TOKENS = 4_096;
+// Matches `DEFAULT_MAX_INPUT_TOKENS` of the bundled llama.cpp runtime.
+const DEFAULT_LOCAL_CONTEXT_TOKENS = 16_384;
+const DEFAULT_HOSTED_CONTEXT_TOKENS = 128_000;
+// What Ollama runs with when the model does not set `num_ctx`.
+const OLLAMA_DEFAULT_NUM_CTX = 4_096;
+// Rough average for English text, matching `estimate_tokens` in template-app.
+const CHARS_PER_TOKEN = 4;
+
+const HOSTED_PROVIDERS = new Set([
+  "hyprnote",
+  "openai",
+  "anthropic",
+  "google_generative_ai",
+  "mistral",
+  "openrouter",
+]);
+
+// Context windows of hosted models, matched by prefix of the model id with
+// any `vendor/` part removed. More specific prefixes come first.
+const HOSTED_CONTEXT_TOKENS: Array<[prefix: string, tokens: number]> = [
+  ["gpt-4.1", 1_047_576],
+  ["gpt-5", 400_000],
+  ["gpt-4o", 128_000],
+  ["o1", 200_000],
+  ["o3", 200_000],
+  ["o4", 200_000],
+  ["claude", 200_000],
+  ["gemini", 1_048_576],
+  ["mistral-large", 128_000],
+  ["mistral-medium", 128_000],
+  ["mistral-small", 32_000],
+];
+
+export function estimateTokens(text: string): number {
+  return Math.ceil(text.length / CHARS_PER_TOKEN);
+}
+
+export function hostedContextTokens(modelId: string): number {
+  const name = modelId.slice(modelId.lastIndexOf("/") + 1).toLowerCase();
+  const match = HOSTED_CONTEXT_TOKENS.find(([prefix]) =>
+    name.startsWith(prefix),
+  );
+  return match?.[1] ?? DEFAULT_HOSTED_CONTEXT_TOKENS;
+}
+
+export function inputTokensFor(contextTokens: number): number {
+  return (
+    contextTokens -
+    Math.min(RESERVED_OUTPUT_TOKENS, Math.floor(contextTokens / 4))
+  );
+}
+
+// `parameters` from `ollama show` is a newline-separated `key value` list.
+export function ollamaContextTokens(
+  parameters: string | undefined,
+  modelInfo: Record<string, unknown> | undefined,
+): number {
+  const numCtx = parameters
+    ?.split("\n")
+    .map((line) => line.trim().split(/\s+/))
+    .find(([key]) => key === "num_ctx")?.[1];
+  const trained = Object.entries(modelInfo ?? {}).find(([key]) =>
+    key.endsWith(".context_length"),
+  )?.[1];
+
+  const runtime = numCtx ? Number(numCtx) : OLLAMA_DEFAULT_NUM_CTX;
+  return typeof trained === "number" ? Math.min(runtime, trained) : runtime;
+}
+
+// Local servers are asked for their context window and tokenizer; hosted
+// models use a known window and an estimate, which their windows absorb.
+export async function getTokenBudget(
+  target: LLMTarget | null,
+): Promise<TokenBudget> {
+  const estimate: TokenBudget["countTokens"] = async (text) =>
+    estimateTokens(text);
+
+  if (!target) {
+    return {
+      maxInputTokens: inputTokensFor(DEFAULT_LOCAL_CONTEXT_TOKENS),
+      countTokens: estimate,
+    };
+  }
+
+  if (HOSTED_PROVIDERS.has(target.providerId)) {
+    return {
+      maxInputTokens: inputTokensFor(hostedContextTokens(target.modelId)),
+      countTokens: estimate,
+    };
+  }
+
+  try {
+    switch (target.providerId) {
+      case "lmstudio":
+        return await getLMStudioBudget(target);
+      case "ollama":
+        return await getOllamaBudget(target);
+      default:
+        return await getLlamaCppBudget(target);
+    }
+  } catch (error) {
+    console.warn("token_budget_fallback", error);
+    return {
+      maxInputTokens: inputTokensFor(DEFAULT_LOCAL_CONTEXT_TOKENS),
+      countTokens: estimate,
+    };
+  }
+}
+
+async function getLMStudioBudget(target: LLMTarget): Promise<TokenBudget> {
+  const port = new URL(target.baseUrl).port || "1234";
+  const client = new LMStudioClient({ baseUrl: `ws:127.0.0.1:${port}` });
+  const llm = await client.llm.model(target.modelId);
+
+  return {
+    maxInputTokens: inputTokensFor(await llm.getContextLength()),
+    countTokens: (text) => llm.countTokens(text),
+  };
+}
+
+async function getOllamaBudget(target: LLMTarget): Promise<TokenBudget> {
+  const host = target.baseUrl.replace(/\/v1\/?$/, "");
+  const origin = new URL(host).origin;
+  const ollama = new Ollama({
+    host,
+    fetch: (input, init) => {
+      const headers = new Headers(init?.headers);
+      headers.set("Origin", origin);
+      return tauriFetch(input as RequestInfo | URL, { ...init, headers });
+    },
+  });
+  const info = await ollama.show({ model: target.modelId });
+
+  return {
+    maxInputTokens: inputTokensFor(
+      ollamaContextTokens(
+        info.parameters,
+        info.model_info as unknown as Record<string, unknown>,
+      ),
+    ),
+    // Ollama has no tokenize endpoint.
+    countTokens: async (text) => estimateTokens(text),
+  };
+}
+
+// llama.cpp's server, and the bundled local-llm server that mirrors it.
+async function getLlamaCppBudget(target: LLMTarget): Promise<TokenBudget> {
+  const origin = new URL(target.baseUrl).origin;
+
+  const props = await tauriFetch(`${origin}/props`);
+  if (!props.ok) {
+    throw new Error(`props_failed: ${props.status}`);
+  }
+  const { default_generation_settings } = (await props.json()) as {
+    default_generation_settings?: { n_ctx?: number };
+  };
+  const contextTokens = default_generation_settings?.n_ctx;
+  if (!contextTokens) {
+    throw new Error("props_missing_n_ctx");
+  }
+
+  return {
+    maxInputTokens: inputTokensFor(contextTokens),
+    countTokens: async (text) => {
+      const response = await tauriFetch(`${origin}/tokenize`, {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ content: text }),
+      });
+      if (!response.ok) {
+        return estimateTokens(text);
+      }
+      const { tokens } = (await response.json()) as { tokens: unknown[] };
+      return tokens.length;
+    },
+  };
+}
@@ -8,6 +8,7 @@ import type {
 } from "@hypr/plugin-template";
 
 import type { TaskArgsMap, TaskArgsMapTransformed, TaskConfig } from ".";
+import { PROVIDERS } from "../../../../components/settings/ai/llm/shared";
 import {
   buildSegments,
   type RuntimeSpeakerHint,
@@ -18,9 +19,12 @@ import {
   defaultRenderLabelContext,
   SpeakerLabelManager,
 } from "../../../../utils/segment/shared";
+import { runTransformHooks } from "../../../../utils/event-hooks";
 import { convertStorageHintsToRuntime } from "../../../../utils/speaker-hints";
 import type { Store as MainStore } from "../../../tinybase/store/main";
+import { getPreviousMeeting } from "../../../tinybase/store/sessions";
 import type { Store as SettingsStore } from "../../../tinybase/store/settings";
+import type { LLMTarget } from "../shared/token_budget";
 
 type TranscriptMeta = {
   id: string;
@@ -73,14 +77,100 @@ async function transformArgs(
     session: sessionContext.session,
     participants: sessionContext.participants,
     template,
-    transcripts: formatTranscripts(
-      sessionContext.rawMd,
-      sessionContext.segments,
-      sessionContext.transcriptsMeta,
-    ),
+    transcripts: await getModelTranscripts(sessionId, sessionContext),
+    previousMeeting: await getPreviousMeeting(store, sessionId),
+    enhanceTemplateId:
+      getOptionalStringCell(
+        store,
+        "sessions",
+        sessionId,
+        "enhance_template_id",
+      ) ?? null,
+    llm: getLLMTarget(settingsStore),
   };
 }
 
+// The selected model, so the workflow can size prompts to its context window.
+function getLLMTarget(settingsStore: SettingsStore): LLMTarget | null {
+  const providerId = settingsStore.getValue("current_llm_provider");
+  const modelId = settingsStore.getValue("current_llm_model");
+  if (typeof providerId !== "string" || typeof modelId !== "string") {
+    return null;
+  }
+
+  const configured = settingsStore.getCell(
+    "ai_providers",
+    providerId,
+    "base_url",
+  );
+  const baseUrl =
+    (typeof configured === "string" && configured.trim()) ||
+    PROVIDERS.find((p) => p.id === providerId)?.baseUrl ||
+    "";
+
+  return { providerId, modelId, baseUrl };
+}
+
+// The transcripts the model sees, shared by every task that reads them.
+export async function getModelTranscripts(
+  sessionId: string,
+  sessionContext: ReturnType<typeof getSessionContext>,
+): Promise<Transcript[]> {
+  const transcripts = formatTranscripts(
+    sessionContext.rawMd,
+    sessionContext.segments,
+    sessionContext.transcriptsMeta,
+  );
+  return applyTranscriptTransforms(
+    sessionId,
+    transcripts,
+    sessionContext.segments,
+  );
+}
+
+// Lets `beforeEnhance` transform hooks (e.g. PII redaction) rewrite what the
+// model sees. The stored transcript is left untouched. Segments built from
+// `timed` carry its timestamps, so hooks are held to them.
+async function applyTranscriptTransforms(
+  sessionId: string,
+  transcripts: Transcript[],
+  timed: SegmentPayload[],
+): Promise<Transcript[]> {
+  const result: Transcript[] = [];
+
+  for (const transcript of transcripts) {
+    const hasTiming = transcript.segments.length === timed.length;
+    const transformed = await runTransformHooks({
+      beforeEnhance: {
+        args: { session_id: sessionId },
+        data: {
+          segments: transcript.segments.map((segment, i) =>
+            hasTiming
+              ? {
+                  ...segment,
+                  start_ms: timed[i].start_ms,
+                  end_ms: timed[i].end_ms,
+                }
+              : segment,
+          ),
+        },
+      },
+    });
+    result.push(
+      "beforeEnhance" in transformed
+        ? {
+            ...transcript,
+            segments: transformed.beforeEnhance.data.segments.map(
+              ({ speaker, text }): Segment => ({ speaker, text }),
+            ),
+          }
+        : transcript,
+    );
+  }
+
+  return result;
+}
+
 function formatTranscripts(
   rawMd: string,
   segments: SegmentPayload[],
@@ -123,12 +213,12 @@ function formatTranscripts(
   return [];
 }
 
-function getLanguage(settingsStore: SettingsStore): string | null {
+export function getLanguage(settingsStore: SettingsStore): string | null {
   const value = settingsStore.getValue("ai_language");
   return typeof value === "string" && value.length > 0 ? value : null;
 }
 
-function getSessionContext(sessionId: string, store: MainStore) {
+export function getSessionContext(sessionId: string, store: MainStore) {
   const transcriptsMeta = collectTranscripts(sessionId, store);
   return {
     rawMd: getStringCell(store, "sessions", sessionId, "raw_md"),
@@ -433,7 +523,7 @@ function toSegmentPayload(
   };
 }
 
-function getStringCell(
+export function getStringCell(
   store: MainStore,
   tableId: any,
   rowId: string,
@@ -443,7 +533,7 @@ function getStringCell(
   return typeof value === "string" ? value : "";
 }
 
-function getOptionalStringCell(
+export function getOptionalStringCell(
   store: MainStore,
   tableId: any,
   rowId: string,
@@ -9,14 +9,17 @@ import { z } from "zod";
 
 import {
   type EnhanceTemplate,
+  type RenderedPrompts,
   commands as templateCommands,
   type TemplateSection,
+  type Transcript,
 } from "@hypr/plugin-template";
 import { templateSectionSchema } from "@hypr/store";
 
 import type { TaskArgsMapTransformed, TaskConfig } from ".";
 import type { Store } from "../../../tinybase/store/main";
 import { getCustomPrompt } from "../../../tinybase/store/prompts";
+import { getTokenBudget, type TokenBudget } from "../shared/token_budget";
 import {
   normalizeBulletPoints,
   trimBeforeMarker,
@@ -26,6 +29,11 @@ import {
   withEarlyValidationRetry,
 } from "../shared/validate";
 
+// Below this, a chunk holds too little of the transcript to be worth a call.
+const MIN_CHUNK_TOKENS = 256;
+const CONTEXT_TOO_SMALL =
+  "The model's context window is too small to summarize.";
+
 export const enhanceWorkflow: Pick<
   TaskConfig<"enhance">,
   "executeWorkflow" | "transforms"
@@ -47,80 +55,351 @@ async function* executeWorkflow(params: {
 }) {
   const { model, args, onProgress, signal, store } = params;
 
-  const sections = await generateTemplateIfNeeded({
+  const budget = await getTokenBudget(args.llm);
+  const summaries = await summarizeIfTooLong({
     model,
     args,
+    budget,
+    onProgress,
+    signal,
+    store,
+  });
+  // From here on, a transcript that did not fit is replaced by its notes.
+  const condensed = summaries ? withSummaries(args, summaries) : args;
+
+  const sections = await generateTemplateIfNeeded({
+    model,
+    args: condensed,
     onProgress,
     signal,
     store,
   });
   const argsWithTemplate: TaskArgsMapTransformed["enhance"] = {
-    ...args,
+    ...condensed,
     template: sections ? { title: "", description: null, sections } : null,
   };
 
-  const system = await getSystemPrompt(argsWithTemplate);
-  const prompt = await getUserPrompt(argsWithTemplate, store);
+  let prompts = await getPrompts(argsWithTemplate, store);
+  if (summaries) {
+    // The generated sections make the prompt a little longer.
+    const reduced = await reduceWithinBudget({
+      model,
+      args: argsWithTemplate,
+      summaries,
+      budget,
+      onProgress,
+      signal,
+      store,
+    });
+    prompts = await getFinalPrompts(
+      withSummaries(argsWithTemplate, reduced),
+      reduced,
+      store,
+    );
+  }
 
   yield* generateSummary({
     model,
     args: argsWithTemplate,
-    system,
-    prompt,
+    system: prompts.system,
+    prompt: prompts.user,
     onProgress,
     signal,
   });
 }
 
-async function getSystemPrompt(args: TaskArgsMapTransformed["enhance"]) {
-  const result = await templateCommands.render({
-    enhanceSystem: {
-      language: args.language,
-    },
-  });
+// Renders through the session's user template when one is set, otherwise
+// through the built-in templates (or the custom enhance prompt, if any).
+async function getPrompts(
+  args: TaskArgsMapTransformed["enhance"],
+  store: Store,
+): Promise<RenderedPrompts> {
+  const {
+    language,
+    session,
+    participants,
+    template,
+    transcripts,
+    previousMeeting,
+    enhanceTemplateId,
+  } = args;
+
+  const rendered = await templateCommands.renderEnhance(
+    enhanceTemplateId,
+    { language },
+    { session, participants, template, transcripts, previousMeeting },
+  );
+  if (rendered.status === "error") {
+    throw new Error(rendered.error);
+  }
+
+  const customPrompt = enhanceTemplateId
+    ? null
+    : getCustomPrompt(store, "enhance");
+  if (!customPrompt) {
+    return rendered.data;
+  }
 
+  const result = await templateCommands.renderCustom(customPrompt, {
+    content: transcripts,
+    session,
+    participants,
+    template,
+  });
   if (result.status === "error") {
     throw new Error(result.error);
   }
 
-  return result.data;
+  return { system: rendered.data.system, user: result.data };
 }
 
-async function getUserPrompt(
+// Map-reduce for transcripts that do not fit in the model's context:
+// summarize each chunk separately, then merge the partial summaries until
+// the final prompt fits. Returns null when the transcript fits as it is.
+async function summarizeIfTooLong(params: {
+  model: LanguageModel;
+  args: TaskArgsMapTransformed["enhance"];
+  budget: TokenBudget;
+  onProgress: (step: any) => void;
+  signal: AbortSignal;
+  store: Store;
+}): Promise<string[] | null> {
+  const { model, args, budget, onProgress, signal, store } = params;
+  const { session, participants, template, transcripts, language } = args;
+
+  const prompts = await getPrompts(args, store);
+  if (await fits(budget, prompts)) {
+    return null;
+  }
+
+  const system = await render({ enhanceChunkSystem: { language } });
+  const renderChunk = (chunk: Transcript, index: number, total: number) =>
+    render({
+      enhanceChunkUser: {
+        session,
+        participants,
+        template,
+        chunk,
+        index,
+        total,
+      },
+    });
+
+  const chunks = await chunkWithinBudget(
+    transcripts,
+    budget.maxInputTokens,
+    budget,
+    async (chunk) => ({ system, user: await renderChunk(chunk, 0, 1) }),
+  );
+
+  const summaries: string[] = [];
+  for (const [index, chunk] of chunks.entries()) {
+    onProgress({ type: "analyzing" });
+
+    const result = await generateText({
+      model,
+      system,
+      prompt: await renderChunk(chunk, index, chunks.length),
+      abortSignal: signal,
+    });
+    summaries.push(result.text.trim());
+  }
+
+  return reduceWithinBudget({
+    model,
+    args,
+    summaries,
+    budget,
+    onProgress,
+    signal,
+    store,
+  });
+}
+
+// Chunks are sized by estimate first, then checked against the model's own
+// token count and split again where the estimate fell short.
+async function chunkWithinBudget(
+  transcripts: Transcript[],
+  maxTokens: number,
+  budget: TokenBudget,
+  renderChunk: (chunk: Transcript) => Promise<RenderedPrompts>,
+): Promise<Transcript[]> {
+  const chunks = await templateCommands.chunkTranscripts(
+    transcripts,
+    maxTokens,
+  );
+  if (chunks.status === "error") {
+    throw new Error(chunks.error);
+  }
+
+  const result: Transcript[] = [];
+  for (const chunk of chunks.data) {
+    const used = await countPromptTokens(budget, await renderChunk(chunk));
+    if (used <= budget.maxInputTokens) {
+      result.push(chunk);
+      continue;
+    }
+
+    // Aim a little under the budget so one more split is usually enough.
+    const smaller = Math.floor(
+      (maxTokens * budget.maxInputTokens * 0.9) / used,
+    );
+    if (smaller < MIN_CHUNK_TOKENS) {
+      throw new Error(CONTEXT_TOO_SMALL);
+    }
+    result.push(
+      ...(await chunkWithinBudget([chunk], smaller, budget, renderChunk)),
+    );
+  }
+
+  return result;
+}
+
+// Merges neighbouring summaries in groups that fit, round after round,
+// until the final prompt over all of them fits.
+async function reduceWithinBudget(params: {
+  model: LanguageModel;
+  args: TaskArgsMapTransformed["enhance"];
+  summaries: string[];
+  budget: TokenBudget;
+  onProgress: (step: any) => void;
+  signal: AbortSignal;
+  store: Store;
+}): Promise<string[]> {
+  const { model, args, budget, onProgress, signal, store } = params;
+  const { session, participants, template, language } = args;
+
+  const system = await render({ enhanceChunkSystem: { language } });
+  const renderGroup = (group: string[]) =>
+    render({
+      enhanceMergeUser: {
+        session,
+        participants,
+        template,
+        summaries: group,
+        previousMeeting: null,
+      },
+    });
+
+  const groupFits = async (group: string[]) =>
+    fits(budget, { system, user: await renderGroup(group) });
+  const finalFits = async (current: string[]) =>
+    fits(
+      budget,
+      await getFinalPrompts(withSummaries(args, current), current, store),
+    );
+
+  let summaries = params.summaries;
+  while (!(await finalFits(summaries))) {
+    if (summaries.length <= 1) {
+      throw new Error(CONTEXT_TOO_SMALL);
+    }
+
+    const groups: string[][] = [];
+    for (const summary of summaries) {
+      const last = groups.at(-1);
+      if (last && (await groupFits([...last, summary]))) {
+        last.push(summary);
+      } else {
+        groups.push([summary]);
+      }
+    }
+    if (groups.length === summaries.length) {
+      throw new Error(CONTEXT_TOO_SMALL);
+    }
+
+    const merged: string[] = [];
+    for (const group of groups) {
+      if (group.length === 1) {
+        merged.push(group[0]);
+        continue;
+      }
+
+      onProgress({ type: "analyzing" });
+      const result = await generateText({
+        model,
+        system,
+        prompt: await renderGroup(group),
+        abortSignal: signal,
+      });
+      merged.push(result.text.trim());
+    }
+    summaries = merged;
+  }
+
+  return summaries;
+}
+
+// The built-in prompt has a dedicated merge step. User templates and custom
+// prompts see the notes in place of the transcript.
+async function getFinalPrompts(
   args: TaskArgsMapTransformed["enhance"],
+  summaries: string[],
   store: Store,
-) {
-  const { session, participants, template, transcripts } = args;
+): Promise<RenderedPrompts> {
+  const prompts = await getPrompts(args, store);
+  if (args.enhanceTemplateId || getCustomPrompt(store, "enhance")) {
+    return prompts;
+  }
 
-  const ctx = {
-    content: transcripts,
-    session,
-    participants,
-    template,
+  const { session, participants, template, previousMeeting } = args;
+  return {
+    system: prompts.system,
+    user: await render({
+      enhanceMergeUser: {
+        session,
+        participants,
+        template,
+        summaries,
+        previousMeeting,
+      },
+    }),
+  };
+}
+
+function withSummaries(
+  args: TaskArgsMapTransformed["enhance"],
+  summaries: string[],
+): TaskArgsMapTransformed["enhance"] {
+  return {
+    ...args,
+    transcripts: [
+      {
+        segments: summaries.map((text, index) => ({
+          speaker: `Notes on part ${index + 1}`,
+          text,
+        })),
+        startedAt: args.transcripts[0]?.startedAt ?? null,
+        endedAt: args.transcripts.at(-1)?.endedAt ?? null,
+      },
+    ],
   };
+}
 
-  const customPrompt = getCustomPrompt(store, "enhance");
-  if (customPrompt) {
-    const result = await templateCommands.renderCustom(customPrompt, ctx);
-    if (result.status === "error") {
-      throw new Error(result.error);
-    }
-    return result.data;
-  }
+async function countPromptTokens(
+  budget: TokenBudget,
+  prompts: RenderedPrompts,
+): Promise<number> {
+  return (
+    (await budget.countTokens(prompts.system)) +
+    (await budget.countTokens(prompts.user))
+  );
+}
 
-  const result = await templateCommands.render({
-    enhanceUser: {
-      session,
-      participants,
-      template,
-      transcripts,
-    },
-  });
+async function fits(
+  budget: TokenBudget,
+  prompts: RenderedPrompts,
+): Promise<boolean> {
+  return (await countPromptTokens(budget, prompts)) <= budget.maxInputTokens;
+}
 
+async function render(
+  tpl: Parameters<typeof templateCommands.render>[0],
+): Promise<string> {
+  const result = await templateCommands.render(tpl);
   if (result.status === "error") {
     throw new Error(result.error);
   }
-
   return result.data;
 }
 
@@ -137,7 +416,7 @@ async function generateTemplateIfNeeded(params: {
     onProgress({ type: "analyzing" });
 
     const schema = z.object({ sections: z.array(templateSectionSchema) });
-    const userPrompt = await getUserPrompt(args, store);
+    const { user: userPrompt } = await getPrompts(args, store);
 
     const result = await generateStructuredOutput({
       model,
@@ -0,0 +1,122 @@
+import {
+  commands as db2Commands,
+  type ExtractionParticipant,
+} from "@hypr/plugin-db2";
+import type { TranscriptLine } from "@hypr/plugin-template";
+
+import type { TaskArgsMap, TaskArgsMapTransformed, TaskConfig } from ".";
+import type { Store as MainStore } from "../../../tinybase/store/main";
+import type { Store as SettingsStore } from "../../../tinybase/store/settings";
+import {
+  getLanguage,
+  getModelTranscripts,
+  getOptionalStringCell,
+  getSessionContext,
+  getStringCell,
+} from "./enhance-transform";
+
+export const extractTransform: Pick<TaskConfig<"extract">, "transformArgs"> = {
+  transformArgs,
+};
+
+async function transformArgs(
+  args: TaskArgsMap["extract"],
+  store: MainStore,
+  settingsStore: SettingsStore,
+): Promise<TaskArgsMapTransformed["extract"]> {
+  const { sessionId } = args;
+  const sessionContext = getSessionContext(sessionId, store);
+
+  // The same transcript enhance sees, after transform hooks. Timestamps are
+  // taken from the stored segments when the hooks kept them one to one.
+  const segments = (
+    await getModelTranscripts(sessionId, sessionContext)
+  ).flatMap((transcript) => transcript.segments);
+  const timed = segments.length === sessionContext.segments.length;
+
+  const lines: TranscriptLine[] = segments.map((segment, i) => ({
+    timestamp: timed
+      ? formatTimestamp(sessionContext.segments[i].start_ms)
+      : null,
+    speaker: segment.speaker,
+    text: segment.text,
+  }));
+
+  return {
+    language: getLanguage(settingsStore),
+    currentDate: new Date().toISOString().slice(0, 10),
+    session: sessionContext.session,
+    participants: sessionContext.participants,
+    lines,
+  };
+}
+
+// Replaces the session's stored action items and decisions with the model
+// output. Owners are linked to the session's participants by name or email.
+export async function saveExtraction(
+  store: MainStore,
+  sessionId: string,
+  text: string,
+): Promise<void> {
+  const result = await db2Commands.saveExtraction(
+    sessionId,
+    text,
+    getExtractionParticipants(sessionId, store),
+  );
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+}
+
+function getExtractionParticipants(
+  sessionId: string,
+  store: MainStore,
+): ExtractionParticipant[] {
+  const participants: ExtractionParticipant[] = [];
+
+  store.forEachRow("mapping_session_participant", (mappingId, _forEachCell) => {
+    const mappingSessionId = getOptionalStringCell(
+      store,
+      "mapping_session_participant",
+      mappingId,
+      "session_id",
+    );
+    if (mappingSessionId !== sessionId) {
+      return;
+    }
+
+    const humanId = getOptionalStringCell(
+      store,
+      "mapping_session_participant",
+      mappingId,
+      "human_id",
+    );
+    if (!humanId) {
+      return;
+    }
+
+    const name = getStringCell(store, "humans", humanId, "name");
+    if (!name) {
+      return;
+    }
+
+    participants.push({
+      id: humanId,
+      name,
+      email: getOptionalStringCell(store, "humans", humanId, "email") ?? null,
+    });
+  });
+
+  return participants;
+}
+
+// Offsets from the start of the session, e.g. "04:05" or "1:02:03".
+function formatTimestamp(ms: number): string {
+  const totalSeconds = Math.max(0, Math.floor(ms / 1000));
+  const hours = Math.floor(totalSeconds / 3600);
+  const minutes = Math.floor((totalSeconds % 3600) / 60);
+  const seconds = totalSeconds % 60;
+
+  const mmss = `${String(minutes).padStart(2, "0")}:${String(seconds).padStart(2, "0")}`;
+  return hours > 0 ? `${hours}:${mmss}` : mmss;
+}
@@ -0,0 +1,106 @@
+import { generateId, generateText, type LanguageModel, Output } from "ai";
+import { z } from "zod";
+
+import { commands as templateCommands } from "@hypr/plugin-template";
+
+import type { TaskArgsMapTransformed, TaskConfig } from ".";
+import type { Store } from "../../../tinybase/store/main";
+
+export const extractWorkflow: Pick<
+  TaskConfig<"extract">,
+  "executeWorkflow" | "transforms"
+> = {
+  executeWorkflow,
+  transforms: [],
+};
+
+async function* executeWorkflow(params: {
+  model: LanguageModel;
+  args: TaskArgsMapTransformed["extract"];
+  onProgress: (step: any) => void;
+  signal: AbortSignal;
+  store: Store;
+}) {
+  const { model, args, onProgress, signal } = params;
+  const { language, currentDate, session, participants, lines } = args;
+
+  const system = await render({ extractSystem: { language, currentDate } });
+  const prompt = await render({
+    extractUser: { session, participants, lines },
+  });
+
+  onProgress({ type: "generating" });
+
+  // The stored result is parsed by `Extraction::parse` in template-app.
+  yield {
+    type: "text-delta" as const,
+    id: generateId(),
+    text: await generateExtraction({ model, system, prompt, signal }),
+  };
+}
+
+// Mirrors `Extraction` in template-app.
+const extractionSchema = z.object({
+  action_items: z.array(
+    z.object({
+      text: z.string(),
+      owner: z.string().nullable(),
+      due: z.string().nullable(),
+      source_timestamp: z.string().nullable(),
+    }),
+  ),
+  decisions: z.array(
+    z.object({
+      text: z.string(),
+      source_timestamp: z.string().nullable(),
+    }),
+  ),
+});
+
+// Asks for structured output, and falls back to the JSON the prompt describes
+// for providers that do not support it.
+async function generateExtraction(params: {
+  model: LanguageModel;
+  system: string;
+  prompt: string;
+  signal: AbortSignal;
+}): Promise<string> {
+  const { model, system, prompt, signal } = params;
+
+  try {
+    const result = await generateText({
+      model,
+      temperature: 0,
+      system,
+      prompt,
+      output: Output.object({ schema: extractionSchema }),
+      abortSignal: signal,
+    });
+    if (result.output) {
+      return JSON.stringify(result.output);
+    }
+  } catch (error) {
+    if (signal.aborted) {
+      throw error;
+    }
+  }
+
+  const fallback = await generateText({
+    model,
+    temperature: 0,
+    system,
+    prompt,
+    abortSignal: signal,
+  });
+  return fallback.text;
+}
+
+async function render(
+  tpl: Parameters<typeof templateCommands.render>[0],
+): Promise<string> {
+  const result = await templateCommands.render(tpl);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return result.data;
+}
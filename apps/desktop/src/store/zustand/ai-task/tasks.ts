@@ -4,13 +4,20 @@ import type { StoreApi } from "zustand";
 
 import type { Store as MainStore } from "../../tinybase/store/main";
 import type { Store as SettingsStore } from "../../tinybase/store/settings";
+import {
+  fireEventHooks,
+  runTransformHooks,
+  sessionResourceDir,
+} from "../../../utils/event-hooks";
 import { applyTransforms } from "./shared/transform_infra";
 import {
+  createTaskId,
   TASK_CONFIGS,
   type TaskArgsMap,
   type TaskId,
   type TaskType,
 } from "./task-configs";
+import { saveExtraction } from "./task-configs/extract-transform";
 
 export type TasksState = {
   tasks: Record<string, TaskState>;
@@ -66,7 +73,7 @@ const initialState: TasksState = {
   tasks: {},
 };
 
-export const createTasksSlice = <T extends TasksState>(
+export const createTasksSlice = <T extends TasksState & TasksActions>(
   set: StoreApi<T>["setState"],
   get: StoreApi<T>["getState"],
   deps: { persistedStore: MainStore; settingsStore: SettingsStore },
@@ -200,6 +207,29 @@ export const createTasksSlice = <T extends TasksState>(
         }
       }
 
+      if (config.taskType === "enhance" && fullText) {
+        const { sessionId, enhancedNoteId, templateId } =
+          config.args as TaskArgsMap["enhance"];
+        const transformed = await runTransformHooks({
+          afterEnhance: {
+            args: {
+              session_id: sessionId,
+              note_id: enhancedNoteId,
+              template_id: templateId ?? null,
+            },
+            data: { markdown: fullText },
+          },
+        });
+        if ("afterEnhance" in transformed) {
+          fullText = transformed.afterEnhance.data.markdown;
+        }
+      }
+
+      if (config.taskType === "extract" && fullText) {
+        const { sessionId } = config.args as TaskArgsMap["extract"];
+        await saveExtraction(deps.persistedStore, sessionId, fullText);
+      }
+
       set((state) =>
         mutate(state, (draft) => {
           draft.tasks[taskId] = {
@@ -214,6 +244,31 @@ export const createTasksSlice = <T extends TasksState>(
       );
 
       config.onComplete?.(fullText);
+
+      if (config.taskType === "enhance") {
+        const { sessionId, enhancedNoteId, templateId } =
+          config.args as TaskArgsMap["enhance"];
+        fireEventHooks("EnhancementCompleted", async () => ({
+          enhancementCompleted: {
+            args: {
+              resource_dir: await sessionResourceDir(sessionId),
+              session_id: sessionId,
+              note_id: enhancedNoteId,
+              template_id: templateId ?? null,
+            },
+          },
+        }));
+
+        // Action items and decisions come from the same transcript, so they
+        // are re-extracted whenever the summary is regenerated, if enabled.
+        if (deps.settingsStore.getValue("extract_action_items") === true) {
+          void get().generate(createTaskId(sessionId, "extract"), {
+            model: config.model,
+            taskType: "extract",
+            args: { sessionId },
+          });
+        }
+      }
     } catch (err) {
       if (
         err instanceof Error &&
@@ -24,6 +24,7 @@ import {
 import { commands as settingsCommands } from "@hypr/plugin-settings";
 
 import { fromResult } from "../../../effect";
+import { fireEventHooks, sessionResourceDir } from "../../../utils/event-hooks";
 import { buildSessionPath } from "../../tinybase/persister/shared/paths";
 import type { BatchActions, BatchState } from "./batch";
 import type { HandlePersistCallback, TranscriptActions } from "./transcript";
@@ -437,6 +438,16 @@ export const createGeneralSlice = <
               .catch((error) => {
                 console.error("[hooks] AfterListeningStopped failed:", error);
               });
+
+            fireEventHooks("TranscriptFinalized", async () => ({
+              transcriptFinalized: {
+                args: {
+                  resource_dir: await sessionResourceDir(sessionId),
+                  session_id: sessionId,
+                  source: "live",
+                },
+              },
+            }));
           }
         },
       });
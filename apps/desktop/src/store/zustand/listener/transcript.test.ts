@@ -43,11 +43,13 @@ describe("transcript slice", () => {
     transcript,
     isFinal,
     channelIndex = 0,
+    translation,
   }: {
     words: StreamWord[];
     transcript: string;
     isFinal: boolean;
     channelIndex?: number;
+    translation?: string;
   }): StreamResponse => {
     return {
       type: "Results",
@@ -63,6 +65,9 @@ describe("transcript slice", () => {
             transcript,
             confidence: 1,
             words,
+            translation: translation
+              ? { language: "es", text: translation }
+              : null,
           },
         ],
       },
@@ -274,4 +279,34 @@ describe("transcript slice", () => {
     expect(hintedWord).toBeDefined();
     expect(hintedWord?.text).toBe(" test");
   });
+
+  test("keeps partial and final translations per channel", () => {
+    store.getState().handleTranscriptResponse(
+      createResponse({
+        words: defaultWords,
+        transcript: "Another problem",
+        isFinal: false,
+        translation: "Otro",
+      }),
+    );
+    expect(store.getState().partialTranslationByChannel).toEqual({
+      0: "Otro",
+    });
+
+    store.getState().handleTranscriptResponse(
+      createResponse({
+        words: [],
+        transcript: "",
+        isFinal: true,
+        translation: "Otro problema",
+      }),
+    );
+    expect(store.getState().finalTranslations).toEqual([
+      { channel: 0, text: "Otro problema" },
+    ]);
+    expect(store.getState().partialTranslationByChannel).toEqual({});
+
+    store.getState().resetTranscript();
+    expect(store.getState().finalTranslations).toEqual([]);
+  });
 });
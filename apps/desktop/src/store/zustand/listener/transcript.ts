@@ -8,6 +8,8 @@ import { transformWordEntries } from "./utils";
 
 type WordsByChannel = Record<number, WordLike[]>;
 
+export type TranslationLine = { channel: number; text: string };
+
 export type HandlePersistCallback = (
   words: WordLike[],
   hints: RuntimeSpeakerHint[],
@@ -17,6 +19,8 @@ export type TranscriptState = {
   finalWordsMaxEndMsByChannel: Record<number, number>;
   partialWordsByChannel: WordsByChannel;
   partialHintsByChannel: Record<number, RuntimeSpeakerHint[]>;
+  finalTranslations: TranslationLine[];
+  partialTranslationByChannel: Record<number, string>;
   handlePersist?: HandlePersistCallback;
 };
 
@@ -30,6 +34,8 @@ const initialState: TranscriptState = {
   finalWordsMaxEndMsByChannel: {},
   partialWordsByChannel: {},
   partialHintsByChannel: {},
+  finalTranslations: [],
+  partialTranslationByChannel: {},
   handlePersist: undefined,
 };
 
@@ -164,6 +170,23 @@ export const createTranscriptSlice = <
         return;
       }
 
+      const translation = alternative.translation?.text.trim();
+      if (translation) {
+        set((state) =>
+          mutate(state, (draft) => {
+            if (response.is_final) {
+              draft.finalTranslations.push({
+                channel: channelIndex,
+                text: translation,
+              });
+              delete draft.partialTranslationByChannel[channelIndex];
+            } else {
+              draft.partialTranslationByChannel[channelIndex] = translation;
+            }
+          }),
+        );
+      }
+
       const [words, hints] = transformWordEntries(
         alternative.words,
         alternative.transcript,
@@ -217,6 +240,8 @@ export const createTranscriptSlice = <
           draft.partialWordsByChannel = {};
           draft.partialHintsByChannel = {};
           draft.finalWordsMaxEndMsByChannel = {};
+          draft.finalTranslations = [];
+          draft.partialTranslationByChannel = {};
           draft.handlePersist = undefined;
         }),
       );
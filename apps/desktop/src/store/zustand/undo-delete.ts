@@ -8,6 +8,7 @@ type SessionRow = {
   event_id: string;
   title: string;
   raw_md: string;
+  enhance_template_id: string;
 };
 
 type TranscriptRow = {
@@ -0,0 +1,50 @@
+import {
+  type HookEvent,
+  commands as hooksCommands,
+  type TransformEvent,
+} from "@hypr/plugin-hooks";
+
+import {
+  buildSessionPath,
+  getDataDir,
+} from "../store/tinybase/persister/shared/paths";
+
+export async function sessionResourceDir(sessionId: string): Promise<string> {
+  return buildSessionPath(await getDataDir(), sessionId);
+}
+
+// Fire-and-forget: hooks never block or fail the action that triggered them.
+export function fireEventHooks(
+  name: string,
+  event: () => Promise<HookEvent> | HookEvent,
+) {
+  void Promise.resolve()
+    .then(event)
+    .then((e) => hooksCommands.runEventHooks(e))
+    .then((result) => {
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+    })
+    .catch((error) => {
+      console.error(`[hooks] ${name} failed:`, error);
+    });
+}
+
+// Unlike notify hooks, a transform chain that aborts fails the caller.
+export async function runTransformHooks(
+  event: TransformEvent,
+): Promise<TransformEvent> {
+  const result = await hooksCommands.runTransformHooks(event);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  for (const hook of result.data.results) {
+    if (hook.rejected) {
+      console.warn(`[hooks] transform "${hook.command}" skipped:`, hook.rejected);
+    }
+  }
+
+  return result.data.event;
+}
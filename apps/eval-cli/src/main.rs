@@ -5,17 +5,19 @@ use clap::{Parser, Subcommand};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 
 mod report;
+mod stt;
 mod submissions;
 
 use hypr_eval::{
-    DEFAULT_MODELS, EvalResult, Executor, ExecutorProgress, OpenRouterClient, parse_config,
+    DEFAULT_MODELS, EvalResult, Executor, ExecutorProgress, OpenRouterClient, SttMode, parse_config,
 };
 use report::{render_json, render_results};
+use stt::{SttArgs, run_stt};
 use submissions::{all_cases, filter_cases};
 
 #[derive(Parser)]
 #[command(name = "evals")]
-#[command(about = "LLM evaluation runner")]
+#[command(about = "LLM and speech-to-text evaluation runner")]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
@@ -39,6 +41,33 @@ enum Commands {
         #[arg(long)]
         cache_dir: Option<String>,
     },
+    /// Evaluate speech-to-text providers against the audio fixtures.
+    Stt {
+        #[arg(short, long, value_delimiter = ',')]
+        providers: Option<Vec<String>>,
+
+        /// Base URL of a local Deepgram-compatible server (whisper, moonshine).
+        #[arg(long)]
+        local: Option<String>,
+
+        #[arg(long, value_enum, default_value = "batch")]
+        mode: Mode,
+
+        #[arg(short, long, value_delimiter = ',')]
+        cases: Option<Vec<String>>,
+
+        #[arg(short, long, default_value_t = 1)]
+        samples: i32,
+
+        #[arg(short, long, default_value = "table")]
+        output: String,
+
+        #[arg(long)]
+        no_cache: bool,
+
+        #[arg(long)]
+        cache_dir: Option<String>,
+    },
     List,
     Completion {
         #[arg(value_enum)]
@@ -46,6 +75,12 @@ enum Commands {
     },
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Mode {
+    Batch,
+    Realtime,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 enum Shell {
     Bash,
@@ -71,6 +106,34 @@ fn main() -> ExitCode {
                 return ExitCode::FAILURE;
             }
         }
+        Commands::Stt {
+            providers,
+            local,
+            mode,
+            cases,
+            samples,
+            output,
+            no_cache,
+            cache_dir,
+        } => {
+            let args = SttArgs {
+                providers,
+                local,
+                mode: match mode {
+                    Mode::Batch => SttMode::Batch,
+                    Mode::Realtime => SttMode::Realtime,
+                },
+                cases,
+                samples,
+                output,
+                no_cache,
+                cache_dir,
+            };
+            if let Err(e) = run_stt(args) {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
         Commands::List => {
             list_cases();
         }
@@ -175,6 +238,17 @@ fn list_cases() {
             println!("  - {}: {}", rubric.name, rubric.description);
         }
     }
+    for case in hypr_eval::fixture_cases() {
+        println!("{} (stt)", case.case_id);
+        println!(
+            "  - wer: word error rate at most {:.0}%",
+            case.max_wer * 100.0
+        );
+        println!(
+            "  - der: diarization error rate at most {:.0}%",
+            case.max_der * 100.0
+        );
+    }
 }
 
 fn generate_completion(shell: Shell) {
@@ -1,6 +1,6 @@
 use comfy_table::{Cell, Color, ContentArrangement, Table, presets::UTF8_FULL_CONDENSED};
 
-use hypr_eval::EvalResult;
+use hypr_eval::{EvalResult, MetricStats, SttResult};
 
 pub fn render_json(results: &[EvalResult]) -> std::result::Result<(), String> {
     let json = serde_json::to_string_pretty(
@@ -152,3 +152,145 @@ fn format_cost(cost: f64) -> String {
         format!("{:.4}", cost)
     }
 }
+
+pub fn render_stt_json(results: &[SttResult]) -> std::result::Result<(), String> {
+    let json = serde_json::to_string_pretty(
+        &results
+            .iter()
+            .map(|r| {
+                let eval = r.to_eval_result();
+                serde_json::json!({
+                    "case_id": r.case_id,
+                    "transcriber": r.transcriber,
+                    "wer": metric_json(Some(&r.wer)),
+                    "cer": metric_json(Some(&r.cer)),
+                    "der": metric_json(r.der.as_ref()),
+                    "latency_to_final_ms": metric_json(r.latency_to_final_ms.as_ref()),
+                    "scores": eval.scores.iter().map(|s| {
+                        serde_json::json!({
+                            "rubric_name": s.rubric_name,
+                            "passed": s.passed,
+                            "reasoning": s.reasoning,
+                            "pass_rate": s.pass_rate,
+                            "samples": s.samples,
+                            "confidence_interval": {
+                                "lower": s.confidence_interval.lower,
+                                "upper": s.confidence_interval.upper,
+                                "level": s.confidence_interval.level,
+                            },
+                        })
+                    }).collect::<Vec<_>>(),
+                    "transcripts": r.transcriptions.iter().map(|t| &t.transcript).collect::<Vec<_>>(),
+                    "error": r.error,
+                })
+            })
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|e| format!("Failed to encode JSON: {}", e))?;
+
+    println!("{}", json);
+
+    if results.iter().any(|r| r.error.is_some()) {
+        return Err("evaluation failed".to_string());
+    }
+
+    Ok(())
+}
+
+pub fn render_stt_results(results: &[SttResult]) -> std::result::Result<(), String> {
+    let mut results: Vec<&SttResult> = results.iter().collect();
+    results.sort_by(|a, b| (&a.transcriber, &a.case_id).cmp(&(&b.transcriber, &b.case_id)));
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        "Transcriber",
+        "Case",
+        "WER",
+        "CER",
+        "DER",
+        "Latency (ms)",
+        "Total",
+    ]);
+
+    let mut error_details: Vec<String> = Vec::new();
+
+    for r in &results {
+        let mut row = vec![Cell::new(&r.transcriber), Cell::new(&r.case_id)];
+
+        if let Some(ref err) = r.error {
+            row.extend((0..4).map(|_| Cell::new("-")));
+            row.push(Cell::new("error").fg(Color::Red));
+            table.add_row(row);
+            error_details.push(format!("{} / {}: {}", r.transcriber, r.case_id, err));
+            continue;
+        }
+
+        row.push(Cell::new(format_rate(Some(&r.wer))));
+        row.push(Cell::new(format_rate(Some(&r.cer))));
+        row.push(Cell::new(format_rate(r.der.as_ref())));
+        row.push(Cell::new(match &r.latency_to_final_ms {
+            Some(stats) => format_interval(stats, 1.0, 0),
+            None => "-".to_string(),
+        }));
+
+        let (passed, total) = r.to_eval_result().tally_score();
+        let cell = Cell::new(format!("{}/{}", passed, total));
+        row.push(if passed == total {
+            cell
+        } else {
+            cell.fg(Color::Red)
+        });
+
+        table.add_row(row);
+    }
+
+    println!("{}", table);
+
+    if !error_details.is_empty() {
+        eprintln!();
+        eprintln!("\x1b[31mErrors:\x1b[0m");
+        for detail in &error_details {
+            eprintln!("\x1b[31m  - {}\x1b[0m", detail);
+        }
+        return Err("evaluation failed".to_string());
+    }
+
+    Ok(())
+}
+
+fn metric_json(stats: Option<&MetricStats>) -> serde_json::Value {
+    match stats {
+        Some(stats) => serde_json::json!({
+            "mean": stats.mean,
+            "samples": stats.samples,
+            "standard_deviation": stats.standard_deviation,
+            "confidence_interval": {
+                "lower": stats.confidence_interval.lower,
+                "upper": stats.confidence_interval.upper,
+                "level": stats.confidence_interval.level,
+            },
+        }),
+        None => serde_json::Value::Null,
+    }
+}
+
+fn format_rate(stats: Option<&MetricStats>) -> String {
+    match stats {
+        Some(stats) => format_interval(stats, 100.0, 1) + "%",
+        None => "-".to_string(),
+    }
+}
+
+// "12.3 ±1.5" when there are enough samples for an interval, else just the mean.
+fn format_interval(stats: &MetricStats, scale: f64, precision: usize) -> String {
+    let mean = stats.mean * scale;
+    let margin = (stats.confidence_interval.upper - stats.mean) * scale;
+    if stats.samples > 1 {
+        format!("{:.*} ±{:.*}", precision, mean, precision, margin)
+    } else {
+        format!("{:.*}", precision, mean)
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+use hypr_eval::{
+    BatchTranscriber, ExecutorProgress, RealtimeTranscriber, SttCase, SttExecutor, SttMode,
+    Transcriber, fixture_cases, provider_transcriber,
+};
+use owhisper_client::{DeepgramAdapter, Provider};
+
+use crate::report::{render_stt_json, render_stt_results};
+
+pub struct SttArgs {
+    pub providers: Option<Vec<String>>,
+    pub local: Option<String>,
+    pub mode: SttMode,
+    pub cases: Option<Vec<String>>,
+    pub samples: i32,
+    pub output: String,
+    pub no_cache: bool,
+    pub cache_dir: Option<String>,
+}
+
+pub fn run_stt(args: SttArgs) -> Result<(), String> {
+    let cases = select_cases(args.cases.as_deref(), args.samples);
+    if cases.is_empty() {
+        return Err("no cases matched the filter".to_string());
+    }
+
+    let transcribers = select_transcribers(args.providers.as_deref(), args.local, args.mode)?;
+
+    let mut executor = SttExecutor::new();
+    if args.no_cache {
+        executor = executor.without_cache();
+    } else if let Some(cache_dir) = args.cache_dir {
+        executor = executor.with_cache_dir(cache_dir);
+    }
+
+    if args.output == "json" {
+        let results = executor.execute(&cases, &transcribers);
+        return render_stt_json(&results);
+    }
+
+    let bar = ProgressBar::new(executor.total_transcriptions(&cases, &transcribers) as u64);
+    bar.set_style(
+        ProgressStyle::default_bar()
+            .template("{prefix:>14} [{bar:30.white}] {pos}/{len}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    bar.set_prefix("Transcriptions");
+
+    let bar_clone = bar.clone();
+    let executor = executor.with_on_progress(Box::new(move |info: ExecutorProgress| {
+        bar_clone.set_position(info.generations_complete as u64);
+    }));
+
+    let results = executor.execute(&cases, &transcribers);
+    bar.finish();
+
+    render_stt_results(&results)
+}
+
+fn select_cases(filter: Option<&[String]>, samples: i32) -> Vec<SttCase> {
+    fixture_cases()
+        .into_iter()
+        .filter(|case| filter.is_none_or(|ids| ids.contains(&case.case_id)))
+        .map(|case| SttCase { samples, ..case })
+        .collect()
+}
+
+// Without an explicit list, every provider whose API key is set is evaluated.
+fn select_transcribers(
+    providers: Option<&[String]>,
+    local: Option<String>,
+    mode: SttMode,
+) -> Result<Vec<Arc<dyn Transcriber>>, String> {
+    let mut transcribers: Vec<Arc<dyn Transcriber>> = Vec::new();
+
+    let providers = match providers {
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                name.parse::<Provider>()
+                    .map_err(|_| format!("unknown provider: {}", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None if local.is_some() => vec![],
+        None => Provider::ALL
+            .into_iter()
+            .filter(|p| std::env::var(p.env_key_name()).is_ok())
+            .collect(),
+    };
+
+    for provider in providers {
+        let api_key = std::env::var(provider.env_key_name()).map_err(|_| {
+            format!(
+                "{} environment variable is not set",
+                provider.env_key_name()
+            )
+        })?;
+        transcribers.push(provider_transcriber(provider, mode, api_key));
+    }
+
+    // Local whisper/moonshine servers expose a Deepgram-compatible API.
+    if let Some(api_base) = local {
+        transcribers.push(match mode {
+            SttMode::Batch => Arc::new(BatchTranscriber::<DeepgramAdapter>::new(
+                "local", api_base, "",
+            )),
+            SttMode::Realtime => Arc::new(RealtimeTranscriber::<DeepgramAdapter>::new(
+                "local", api_base, "",
+            )),
+        });
+    }
+
+    if transcribers.is_empty() {
+        return Err("no providers selected and no provider API keys are set".to_string());
+    }
+
+    Ok(transcribers)
+}
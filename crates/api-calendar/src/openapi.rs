@@ -11,6 +11,7 @@ use crate::routes::ListEventsResponse;
     ),
     components(
         schemas(
+            crate::routes::calendar::CalendarProvider,
             crate::routes::calendar::ListCalendarsRequest,
             crate::routes::calendar::ListCalendarsResponse,
             crate::routes::calendar::ListEventsRequest,
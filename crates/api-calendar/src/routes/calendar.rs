@@ -5,9 +5,28 @@ use utoipa::ToSchema;
 use crate::error::{CalendarError, Result};
 use crate::state::AppState;
 
+#[derive(Debug, Default, Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarProvider {
+    #[default]
+    Google,
+    Outlook,
+}
+
+impl CalendarProvider {
+    fn integration_id(self) -> &'static str {
+        match self {
+            Self::Google => "google-calendar",
+            Self::Outlook => "outlook",
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct ListCalendarsRequest {
     pub connection_id: String,
+    #[serde(default)]
+    pub provider: CalendarProvider,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -18,6 +37,8 @@ pub struct ListCalendarsResponse {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct ListEventsRequest {
     pub connection_id: String,
+    #[serde(default)]
+    pub provider: CalendarProvider,
     pub calendar_id: String,
     #[serde(default)]
     pub time_min: Option<String>,
@@ -43,6 +64,8 @@ pub struct ListEventsResponse {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateEventRequest {
     pub connection_id: String,
+    #[serde(default)]
+    pub provider: CalendarProvider,
     pub calendar_id: String,
     pub summary: String,
     pub start: EventDateTime,
@@ -94,23 +117,36 @@ pub async fn list_calendars(
     State(state): State<AppState>,
     Json(payload): Json<ListCalendarsRequest>,
 ) -> Result<Json<ListCalendarsResponse>> {
-    let proxy = state
-        .nango
-        .integration("google-calendar")
-        .connection(&payload.connection_id);
-    let http = crate::nango_http::NangoHttpClient::new(proxy);
-    let client = hypr_google_calendar::GoogleCalendarClient::new(http);
-
-    let response = client
-        .list_calendars()
-        .await
-        .map_err(|e| CalendarError::Internal(e.to_string()))?;
-
-    let calendars: Vec<serde_json::Value> = response
-        .items
-        .iter()
-        .map(|c| serde_json::to_value(c).unwrap_or_default())
-        .collect();
+    let http = http_client(&state, payload.provider, &payload.connection_id);
+
+    let calendars: Vec<serde_json::Value> = match payload.provider {
+        CalendarProvider::Google => {
+            let client = hypr_google_calendar::GoogleCalendarClient::new(http);
+            let response = client
+                .list_calendars()
+                .await
+                .map_err(|e| CalendarError::Internal(e.to_string()))?;
+
+            response
+                .items
+                .iter()
+                .map(|c| serde_json::to_value(c).unwrap_or_default())
+                .collect()
+        }
+        CalendarProvider::Outlook => {
+            let client = hypr_outlook_calendar::OutlookCalendarClient::new(http);
+            let response = client
+                .list_calendars()
+                .await
+                .map_err(|e| CalendarError::Internal(e.to_string()))?;
+
+            response
+                .value
+                .iter()
+                .map(|c| serde_json::to_value(c).unwrap_or_default())
+                .collect()
+        }
+    };
 
     Ok(Json(ListCalendarsResponse { calendars }))
 }
@@ -130,12 +166,7 @@ pub async fn list_events(
     State(state): State<AppState>,
     Json(payload): Json<ListEventsRequest>,
 ) -> Result<Json<ListEventsResponse>> {
-    let proxy = state
-        .nango
-        .integration("google-calendar")
-        .connection(&payload.connection_id);
-    let http = crate::nango_http::NangoHttpClient::new(proxy);
-    let client = hypr_google_calendar::GoogleCalendarClient::new(http);
+    let http = http_client(&state, payload.provider, &payload.connection_id);
 
     let time_min = payload
         .time_min
@@ -157,31 +188,78 @@ pub async fn list_events(
         })
         .transpose()?;
 
-    let req = hypr_google_calendar::ListEventsRequest {
-        calendar_id: payload.calendar_id,
-        time_min,
-        time_max,
-        max_results: payload.max_results,
-        page_token: payload.page_token,
-        single_events: payload.single_events,
-        order_by: payload.order_by,
-    };
+    match payload.provider {
+        CalendarProvider::Google => {
+            let client = hypr_google_calendar::GoogleCalendarClient::new(http);
+
+            let req = hypr_google_calendar::ListEventsRequest {
+                calendar_id: payload.calendar_id,
+                time_min,
+                time_max,
+                max_results: payload.max_results,
+                page_token: payload.page_token,
+                single_events: payload.single_events,
+                order_by: payload.order_by,
+            };
+
+            let response = client
+                .list_events(req)
+                .await
+                .map_err(|e| CalendarError::Internal(e.to_string()))?;
 
-    let response = client
-        .list_events(req)
-        .await
-        .map_err(|e| CalendarError::Internal(e.to_string()))?;
+            let events: Vec<serde_json::Value> = response
+                .items
+                .iter()
+                .map(|e| serde_json::to_value(e).unwrap_or_default())
+                .collect();
 
-    let events: Vec<serde_json::Value> = response
-        .items
-        .iter()
-        .map(|e| serde_json::to_value(e).unwrap_or_default())
-        .collect();
+            Ok(Json(ListEventsResponse {
+                events,
+                next_page_token: response.next_page_token,
+            }))
+        }
+        CalendarProvider::Outlook => {
+            let client = hypr_outlook_calendar::OutlookCalendarClient::new(http);
 
-    Ok(Json(ListEventsResponse {
-        events,
-        next_page_token: response.next_page_token,
-    }))
+            // Graph only expands recurring events through `calendarView`, which needs both bounds.
+            if payload.single_events == Some(true)
+                && payload.page_token.is_none()
+                && (time_min.is_none() || time_max.is_none())
+            {
+                return Err(CalendarError::BadRequest(
+                    "time_min and time_max are required for single_events".to_string(),
+                ));
+            }
+
+            let req = hypr_outlook_calendar::ListEventsRequest {
+                calendar_id: payload.calendar_id,
+                start_date_time: time_min,
+                end_date_time: time_max,
+                top: payload.max_results,
+                order_by: payload.order_by.map(|o| match o.as_str() {
+                    "startTime" => "start/dateTime".to_string(),
+                    _ => o,
+                }),
+                next_link: payload.page_token,
+            };
+
+            let response = client
+                .list_events(req)
+                .await
+                .map_err(|e| CalendarError::Internal(e.to_string()))?;
+
+            let events: Vec<serde_json::Value> = response
+                .value
+                .iter()
+                .map(|e| serde_json::to_value(e).unwrap_or_default())
+                .collect();
+
+            Ok(Json(ListEventsResponse {
+                events,
+                next_page_token: response.next_link,
+            }))
+        }
+    }
 }
 
 #[utoipa::path(
@@ -199,51 +277,224 @@ pub async fn create_event(
     State(state): State<AppState>,
     Json(payload): Json<CreateEventRequest>,
 ) -> Result<Json<CreateEventResponse>> {
+    let http = http_client(&state, payload.provider, &payload.connection_id);
+
+    let event = match payload.provider {
+        CalendarProvider::Google => {
+            let client = hypr_google_calendar::GoogleCalendarClient::new(http);
+
+            let req = hypr_google_calendar::CreateEventRequest {
+                calendar_id: payload.calendar_id,
+                event: hypr_google_calendar::CreateEventBody {
+                    summary: payload.summary,
+                    start: hypr_google_calendar::GoogleEventDateTime {
+                        date: payload.start.date,
+                        date_time: payload.start.date_time,
+                        time_zone: payload.start.time_zone,
+                    },
+                    end: hypr_google_calendar::GoogleEventDateTime {
+                        date: payload.end.date,
+                        date_time: payload.end.date_time,
+                        time_zone: payload.end.time_zone,
+                    },
+                    description: payload.description,
+                    location: payload.location,
+                    attendees: payload.attendees.map(|attendees| {
+                        attendees
+                            .into_iter()
+                            .map(|a| hypr_google_calendar::GoogleEventAttendee {
+                                email: Some(a.email),
+                                display_name: a.display_name,
+                                response_status: None,
+                                is_self: None,
+                                organizer: None,
+                                optional: a.optional,
+                            })
+                            .collect()
+                    }),
+                },
+            };
+
+            let event = client
+                .create_event(req)
+                .await
+                .map_err(|e| CalendarError::Internal(e.to_string()))?;
+
+            serde_json::to_value(event).unwrap_or_default()
+        }
+        CalendarProvider::Outlook => {
+            let client = hypr_outlook_calendar::OutlookCalendarClient::new(http);
+
+            let (start, start_all_day) = outlook_date_time(payload.start, "start")?;
+            let (end, end_all_day) = outlook_date_time(payload.end, "end")?;
+            if start_all_day != end_all_day {
+                return Err(CalendarError::BadRequest(
+                    "start and end must both be dates or both be date-times".to_string(),
+                ));
+            }
+
+            let req = hypr_outlook_calendar::CreateEventRequest {
+                calendar_id: payload.calendar_id,
+                event: hypr_outlook_calendar::CreateEventBody {
+                    subject: payload.summary,
+                    start,
+                    end,
+                    body: payload
+                        .description
+                        .map(|content| hypr_outlook_calendar::ItemBody {
+                            content_type: "text".to_string(),
+                            content,
+                        }),
+                    location: payload
+                        .location
+                        .map(|location| hypr_outlook_calendar::Location {
+                            display_name: Some(location),
+                        }),
+                    attendees: payload.attendees.map(|attendees| {
+                        attendees
+                            .into_iter()
+                            .map(|a| hypr_outlook_calendar::Attendee {
+                                email_address: hypr_outlook_calendar::EmailAddress {
+                                    name: a.display_name,
+                                    address: Some(a.email),
+                                },
+                                attendee_type: Some(
+                                    if a.optional == Some(true) {
+                                        "optional"
+                                    } else {
+                                        "required"
+                                    }
+                                    .to_string(),
+                                ),
+                                status: None,
+                            })
+                            .collect()
+                    }),
+                    is_all_day: start_all_day.then_some(true),
+                },
+            };
+
+            let event = client
+                .create_event(req)
+                .await
+                .map_err(|e| CalendarError::Internal(e.to_string()))?;
+
+            serde_json::to_value(event).unwrap_or_default()
+        }
+    };
+
+    Ok(Json(CreateEventResponse { event }))
+}
+
+fn http_client<'a>(
+    state: &'a AppState,
+    provider: CalendarProvider,
+    connection_id: &str,
+) -> crate::nango_http::NangoHttpClient<'a> {
     let proxy = state
         .nango
-        .integration("google-calendar")
-        .connection(&payload.connection_id);
-    let http = crate::nango_http::NangoHttpClient::new(proxy);
-    let client = hypr_google_calendar::GoogleCalendarClient::new(http);
-
-    let req = hypr_google_calendar::CreateEventRequest {
-        calendar_id: payload.calendar_id,
-        event: hypr_google_calendar::CreateEventBody {
-            summary: payload.summary,
-            start: hypr_google_calendar::GoogleEventDateTime {
-                date: payload.start.date,
-                date_time: payload.start.date_time,
-                time_zone: payload.start.time_zone,
+        .integration(provider.integration_id())
+        .connection(connection_id);
+    crate::nango_http::NangoHttpClient::new(proxy)
+}
+
+// Graph wants a wall-clock `dateTime` with a separate `timeZone`, so RFC 3339
+// inputs are sent as UTC. Returns whether the value was a whole day.
+fn outlook_date_time(
+    value: EventDateTime,
+    field: &str,
+) -> Result<(hypr_outlook_calendar::DateTimeTimeZone, bool)> {
+    let time_zone = value.time_zone.unwrap_or_else(|| "UTC".to_string());
+
+    if let Some(date_time) = value.date_time {
+        if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&date_time) {
+            return Ok((
+                hypr_outlook_calendar::DateTimeTimeZone {
+                    date_time: dt.to_utc().format("%Y-%m-%dT%H:%M:%S").to_string(),
+                    time_zone: "UTC".to_string(),
+                },
+                false,
+            ));
+        }
+
+        chrono::NaiveDateTime::parse_from_str(&date_time, "%Y-%m-%dT%H:%M:%S")
+            .map_err(|e| CalendarError::BadRequest(format!("Invalid {field}.dateTime: {e}")))?;
+        return Ok((
+            hypr_outlook_calendar::DateTimeTimeZone {
+                date_time,
+                time_zone,
             },
-            end: hypr_google_calendar::GoogleEventDateTime {
-                date: payload.end.date,
-                date_time: payload.end.date_time,
-                time_zone: payload.end.time_zone,
+            false,
+        ));
+    }
+
+    if let Some(date) = value.date {
+        let date = chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+            .map_err(|e| CalendarError::BadRequest(format!("Invalid {field}.date: {e}")))?;
+        return Ok((
+            hypr_outlook_calendar::DateTimeTimeZone {
+                date_time: format!("{}T00:00:00", date),
+                time_zone,
             },
-            description: payload.description,
-            location: payload.location,
-            attendees: payload.attendees.map(|attendees| {
-                attendees
-                    .into_iter()
-                    .map(|a| hypr_google_calendar::GoogleEventAttendee {
-                        email: Some(a.email),
-                        display_name: a.display_name,
-                        response_status: None,
-                        is_self: None,
-                        organizer: None,
-                        optional: a.optional,
-                    })
-                    .collect()
-            }),
-        },
-    };
+            true,
+        ));
+    }
 
-    let event = client
-        .create_event(req)
-        .await
-        .map_err(|e| CalendarError::Internal(e.to_string()))?;
+    Err(CalendarError::BadRequest(format!(
+        "{field} needs either date or dateTime"
+    )))
+}
 
-    let event = serde_json::to_value(event).unwrap_or_default();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(Json(CreateEventResponse { event }))
+    fn date_time(date: Option<&str>, date_time: Option<&str>, tz: Option<&str>) -> EventDateTime {
+        EventDateTime {
+            date: date.map(str::to_string),
+            date_time: date_time.map(str::to_string),
+            time_zone: tz.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_outlook_date_time() {
+        let (dt, all_day) = outlook_date_time(
+            date_time(
+                None,
+                Some("2025-03-10T09:00:00-07:00"),
+                Some("America/Los_Angeles"),
+            ),
+            "start",
+        )
+        .unwrap();
+        assert_eq!(
+            (dt.date_time.as_str(), dt.time_zone.as_str(), all_day),
+            ("2025-03-10T16:00:00", "UTC", false)
+        );
+
+        let (dt, _) = outlook_date_time(
+            date_time(
+                None,
+                Some("2025-03-10T09:00:00"),
+                Some("Pacific Standard Time"),
+            ),
+            "start",
+        )
+        .unwrap();
+        assert_eq!(
+            (dt.date_time.as_str(), dt.time_zone.as_str()),
+            ("2025-03-10T09:00:00", "Pacific Standard Time")
+        );
+
+        let (dt, all_day) =
+            outlook_date_time(date_time(Some("2025-03-10"), None, None), "start").unwrap();
+        assert_eq!(
+            (dt.date_time.as_str(), dt.time_zone.as_str(), all_day),
+            ("2025-03-10T00:00:00", "UTC", true)
+        );
+
+        assert!(outlook_date_time(date_time(None, None, None), "end").is_err());
+        assert!(outlook_date_time(date_time(None, Some("tomorrow"), None), "end").is_err());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     STYLE_TYPE_CHECKBOX, STYLE_TYPE_DASHED_LIST, STYLE_TYPE_DOTTED_LIST, STYLE_TYPE_HEADING,
     STYLE_TYPE_MONOSPACED, STYLE_TYPE_NUMBERED_LIST, STYLE_TYPE_SUBHEADING, STYLE_TYPE_TITLE,
-    extract::extract_text_spans, proto::Note,
+    extract::extract_text_spans, proto::Note, table::Table,
 };
 
 pub fn note_to_markdown(note: &Note) -> String {
@@ -165,3 +165,22 @@ pub fn note_to_markdown(note: &Note) -> String {
 
     markdown
 }
+
+/// The first row becomes the header, since Apple Notes tables have none of their own.
+pub fn table_to_markdown(table: &Table) -> String {
+    let Some((header, body)) = table.rows.split_first() else {
+        return String::new();
+    };
+
+    let row = |cells: &[String]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .map(|cell| cell.trim().replace('|', "\\|").replace('\n', "<br>"))
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut lines = vec![row(header), format!("|{}", " --- |".repeat(header.len()))];
+    lines.extend(body.iter().map(|cells| row(cells)));
+    lines.join("\n")
+}
@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use hypr_importer_core::ir::{Collection, Session};
+
+use crate::{
+    EmbeddedObjectType, Error, Note, Result, note_to_markdown, parse_mergable_data_proto,
+    parse_note_store_proto, parse_table, table_to_markdown, utils::core_time_to_unix,
+};
+
+const FOLDER_TYPE_TRASH: i64 = 1;
+
+// Newer schemas renamed the note timestamps; the first existing column wins.
+const CREATION_DATE_COLUMNS: &[&str] = &["ZCREATIONDATE3", "ZCREATIONDATE1", "ZCREATIONDATE"];
+
+pub fn default_note_store_path() -> PathBuf {
+    dirs::home_dir()
+        .map(|home| home.join("Library/Group Containers/group.com.apple.notes/NoteStore.sqlite"))
+        .unwrap_or_else(|| PathBuf::from("NoteStore.sqlite"))
+}
+
+#[derive(Debug, Default)]
+pub struct ImportedNotes {
+    pub collection: Collection,
+    /// Notes whose body could not be decoded, and so were left out.
+    pub skipped: usize,
+}
+
+/// Notes keeps the store open while running, so it is copied, along with its
+/// WAL, before being read.
+pub async fn import_all_from_path(path: &Path) -> Result<ImportedNotes> {
+    let dir = tempfile::tempdir().map_err(Error::Copy)?;
+    let copy = dir.path().join("NoteStore.sqlite");
+
+    std::fs::copy(path, &copy).map_err(Error::Copy)?;
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = PathBuf::from(format!("{}{}", path.display(), suffix));
+        if sidecar.exists() {
+            let target = PathBuf::from(format!("{}{}", copy.display(), suffix));
+            std::fs::copy(&sidecar, target).map_err(Error::Copy)?;
+        }
+    }
+
+    import_all_from_copy(&copy).await
+}
+
+pub async fn import_all_from_copy(path: &Path) -> Result<ImportedNotes> {
+    let db = libsql::Builder::new_local(path).build().await?;
+    let conn = db.connect()?;
+
+    let folders = list_folders(&conn).await?;
+    let created_column = find_column(&conn, CREATION_DATE_COLUMNS).await?;
+
+    let mut rows = conn
+        .query(
+            &format!(
+                "SELECT n.ZIDENTIFIER, n.ZTITLE1, n.ZFOLDER, {}, d.ZDATA
+                 FROM ZICCLOUDSYNCINGOBJECT n
+                 JOIN ZICNOTEDATA d ON d.Z_PK = n.ZNOTEDATA
+                 WHERE n.ZTITLE1 IS NOT NULL
+                   AND COALESCE(n.ZMARKEDFORDELETION, 0) = 0
+                   AND COALESCE(n.ZISPASSWORDPROTECTED, 0) = 0
+                 ORDER BY n.Z_PK",
+                created_column
+                    .map(|column| format!("n.{}", column))
+                    .unwrap_or_else(|| "NULL".to_string())
+            ),
+            (),
+        )
+        .await?;
+
+    let mut sessions = Vec::new();
+    let mut skipped = 0;
+
+    while let Some(row) = rows.next().await? {
+        let identifier: String = row.get(0)?;
+        let title: Option<String> = row.get(1)?;
+        let folder: Option<i64> = row.get(2)?;
+        let created: Option<f64> = row.get(3)?;
+        let data: Option<Vec<u8>> = row.get(4)?;
+
+        let folder = folder.and_then(|pk| folders.get(&pk));
+        if folder.is_some_and(|f| f.trashed) {
+            continue;
+        }
+
+        let Some(data) = data else {
+            continue;
+        };
+
+        let raw_md = match parse_note_store_proto(&data) {
+            Ok(proto) => note_markdown(&conn, &proto.document.note).await?,
+            Err(_) => {
+                skipped += 1;
+                continue;
+            }
+        };
+
+        sessions.push(Session {
+            id: uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, identifier.as_bytes()).to_string(),
+            user_id: String::new(),
+            created_at: created
+                .and_then(|t| chrono::DateTime::from_timestamp(core_time_to_unix(t as i64), 0))
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_default(),
+            title: title.unwrap_or_default(),
+            raw_md: Some(raw_md),
+            enhanced_content: None,
+            folder_id: folder.map(|f| f.path.clone()),
+            event_id: None,
+        });
+    }
+
+    Ok(ImportedNotes {
+        collection: Collection {
+            sessions,
+            ..Default::default()
+        },
+        skipped,
+    })
+}
+
+struct Folder {
+    // Slash-separated, like the folder ids fs-sync derives from directories.
+    path: String,
+    trashed: bool,
+}
+
+async fn list_folders(conn: &libsql::Connection) -> Result<HashMap<i64, Folder>> {
+    let mut rows = conn
+        .query(
+            "SELECT Z_PK, ZTITLE2, ZPARENT, ZFOLDERTYPE
+             FROM ZICCLOUDSYNCINGOBJECT
+             WHERE ZTITLE2 IS NOT NULL AND COALESCE(ZMARKEDFORDELETION, 0) = 0",
+            (),
+        )
+        .await?;
+
+    let mut raw: HashMap<i64, (String, Option<i64>, bool)> = HashMap::new();
+    while let Some(row) = rows.next().await? {
+        let pk: i64 = row.get(0)?;
+        let title: String = row.get(1)?;
+        let parent: Option<i64> = row.get(2)?;
+        let folder_type: Option<i64> = row.get(3)?;
+
+        raw.insert(
+            pk,
+            (
+                title.trim().replace('/', "-"),
+                parent,
+                folder_type == Some(FOLDER_TYPE_TRASH),
+            ),
+        );
+    }
+
+    let folders = raw
+        .keys()
+        .map(|&pk| {
+            let mut names = Vec::new();
+            let mut trashed = false;
+            let mut current = Some(pk);
+
+            // Bounded, so a corrupt parent cycle can't hang the import.
+            while let Some((name, parent, is_trash)) = current.and_then(|pk| raw.get(&pk)) {
+                if names.len() > raw.len() {
+                    break;
+                }
+                names.push(name.as_str());
+                trashed |= *is_trash;
+                current = *parent;
+            }
+
+            names.reverse();
+            (
+                pk,
+                Folder {
+                    path: names.join("/"),
+                    trashed,
+                },
+            )
+        })
+        .collect();
+
+    Ok(folders)
+}
+
+async fn find_column(conn: &libsql::Connection, candidates: &[&str]) -> Result<Option<String>> {
+    let mut rows = conn
+        .query("PRAGMA table_info(ZICCLOUDSYNCINGOBJECT)", ())
+        .await?;
+
+    let mut columns = Vec::new();
+    while let Some(row) = rows.next().await? {
+        columns.push(row.get::<String>(1)?);
+    }
+
+    Ok(candidates
+        .iter()
+        .find(|c| columns.iter().any(|column| column == *c))
+        .map(|c| c.to_string()))
+}
+
+// The text extractor skips attachment runs, so the note is converted in chunks
+// around each attachment to keep it in place.
+async fn note_markdown(conn: &libsql::Connection, note: &Note) -> Result<String> {
+    let chars: Vec<char> = note.note_text.chars().collect();
+    let mut markdown = String::new();
+    let mut chunk = Note::default();
+    let mut index = 0;
+
+    for run in &note.attribute_run {
+        let end = (index + run.length.max(0) as usize).min(chars.len());
+
+        match &run.attachment_info {
+            Some(info) => {
+                markdown.push_str(&note_to_markdown(&std::mem::take(&mut chunk)));
+
+                let uti = info.type_uti.as_deref().unwrap_or_default();
+                if let (EmbeddedObjectType::Table, Some(uuid)) = (
+                    EmbeddedObjectType::from_uti(uti),
+                    info.attachment_identifier.as_deref(),
+                ) && let Some(table) = table_markdown(conn, uuid).await?
+                {
+                    markdown.push_str(&table);
+                }
+            }
+            None => {
+                chunk.note_text.extend(&chars[index..end]);
+                chunk.attribute_run.push(run.clone());
+            }
+        }
+
+        index = end;
+    }
+
+    markdown.push_str(&note_to_markdown(&chunk));
+    Ok(markdown)
+}
+
+async fn table_markdown(conn: &libsql::Connection, uuid: &str) -> Result<Option<String>> {
+    let mut rows = conn
+        .query(
+            "SELECT ZMERGEABLEDATA1 FROM ZICCLOUDSYNCINGOBJECT WHERE ZIDENTIFIER = ?",
+            libsql::params![uuid],
+        )
+        .await?;
+
+    let Some(row) = rows.next().await? else {
+        return Ok(None);
+    };
+    let Some(data) = row.get::<Option<Vec<u8>>>(0)? else {
+        return Ok(None);
+    };
+
+    Ok(parse_mergable_data_proto(&data)
+        .ok()
+        .as_ref()
+        .and_then(parse_table)
+        .map(|table| table_to_markdown(&table)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AttachmentInfo, AttributeRun, Document, NoteStoreProto};
+    use flate2::{Compression, write::GzEncoder};
+    use prost::Message;
+    use std::io::Write;
+
+    const SCHEMA: &str = "
+        CREATE TABLE ZICCLOUDSYNCINGOBJECT (
+            Z_PK INTEGER PRIMARY KEY,
+            ZIDENTIFIER VARCHAR,
+            ZTITLE1 VARCHAR,
+            ZTITLE2 VARCHAR,
+            ZFOLDER INTEGER,
+            ZPARENT INTEGER,
+            ZFOLDERTYPE INTEGER,
+            ZNOTEDATA INTEGER,
+            ZMARKEDFORDELETION INTEGER,
+            ZISPASSWORDPROTECTED INTEGER,
+            ZCREATIONDATE3 TIMESTAMP,
+            ZMERGEABLEDATA1 BLOB
+        );
+        CREATE TABLE ZICNOTEDATA (Z_PK INTEGER PRIMARY KEY, ZNOTE INTEGER, ZDATA BLOB);
+    ";
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn note_data(text: &str, attachment: Option<&str>) -> Vec<u8> {
+        let mut attribute_run = vec![AttributeRun {
+            length: text.chars().count() as i32,
+            ..Default::default()
+        }];
+
+        if let Some(uuid) = attachment {
+            attribute_run.push(AttributeRun {
+                length: 1,
+                attachment_info: Some(AttachmentInfo {
+                    attachment_identifier: Some(uuid.to_string()),
+                    type_uti: Some("com.apple.notes.table".to_string()),
+                }),
+                ..Default::default()
+            });
+        }
+
+        let proto = NoteStoreProto {
+            document: Document {
+                version: 0,
+                note: Note {
+                    note_text: match attachment {
+                        Some(_) => format!("{}\u{FFFC}", text),
+                        None => text.to_string(),
+                    },
+                    attribute_run,
+                },
+            },
+        };
+
+        gzip(&proto.encode_to_vec())
+    }
+
+    async fn fixture(path: &Path) {
+        let db = libsql::Builder::new_local(path).build().await.unwrap();
+        let conn = db.connect().unwrap();
+        conn.execute_batch(SCHEMA).await.unwrap();
+
+        let folders: &[(i64, &str, Option<i64>, i64)] = &[
+            (1, "Notes", None, 0),
+            (2, "Work", None, 0),
+            (3, "1:1s / Staff", Some(2), 0),
+            (4, "Recently Deleted", None, 1),
+        ];
+        for (pk, title, parent, folder_type) in folders {
+            conn.execute(
+                "INSERT INTO ZICCLOUDSYNCINGOBJECT (Z_PK, ZTITLE2, ZPARENT, ZFOLDERTYPE) VALUES (?, ?, ?, ?)",
+                libsql::params![*pk, *title, *parent, *folder_type],
+            )
+            .await
+            .unwrap();
+        }
+
+        let table = std::fs::read("tests/data/table_gzipped.bin").unwrap();
+        conn.execute(
+            "INSERT INTO ZICCLOUDSYNCINGOBJECT (Z_PK, ZIDENTIFIER, ZMERGEABLEDATA1) VALUES (5, 'TABLE-1', ?)",
+            libsql::params![table],
+        )
+        .await
+        .unwrap();
+
+        let notes: &[(i64, &str, &str, i64, i64, Vec<u8>)] = &[
+            (
+                10,
+                "NOTE-1",
+                "Groceries",
+                1,
+                0,
+                note_data("Groceries\nMilk", None),
+            ),
+            (
+                11,
+                "NOTE-2",
+                "Weekly sync",
+                3,
+                0,
+                note_data("Weekly sync\n", Some("TABLE-1")),
+            ),
+            (12, "NOTE-3", "Old", 4, 0, note_data("Old", None)),
+            (13, "NOTE-4", "Secret", 1, 1, b"encrypted".to_vec()),
+            (14, "NOTE-5", "Corrupt", 1, 0, b"not a note".to_vec()),
+        ];
+        for (pk, identifier, title, folder, locked, data) in notes {
+            conn.execute(
+                "INSERT INTO ZICNOTEDATA (Z_PK, ZNOTE, ZDATA) VALUES (?, ?, ?)",
+                libsql::params![*pk + 100, *pk, data.clone()],
+            )
+            .await
+            .unwrap();
+            conn.execute(
+                "INSERT INTO ZICCLOUDSYNCINGOBJECT (Z_PK, ZIDENTIFIER, ZTITLE1, ZFOLDER, ZNOTEDATA, ZISPASSWORDPROTECTED, ZCREATIONDATE3) VALUES (?, ?, ?, ?, ?, ?, 700000000.5)",
+                libsql::params![*pk, *identifier, *title, *folder, *pk + 100, *locked],
+            )
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_fixture_note_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("NoteStore.sqlite");
+        fixture(&path).await;
+
+        let imported = import_all_from_path(&path).await.unwrap();
+        let sessions = &imported.collection.sessions;
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(imported.skipped, 1);
+
+        assert_eq!(sessions[0].title, "Groceries");
+        assert_eq!(sessions[0].folder_id.as_deref(), Some("Notes"));
+        assert_eq!(sessions[0].raw_md.as_deref(), Some("Groceries\nMilk"));
+        assert_eq!(sessions[0].created_at, "2023-03-08T20:26:40+00:00");
+        assert_eq!(
+            sessions[0].id,
+            uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, b"NOTE-1").to_string()
+        );
+
+        assert_eq!(sessions[1].folder_id.as_deref(), Some("Work/1:1s - Staff"));
+        assert_eq!(
+            sessions[1].raw_md.as_deref(),
+            Some(
+                "Weekly sync\n| Row 1 Column 1 | Row 1 Column 2 |\n| --- | --- |\n| Row 2 Column 1 | Row 2 Column 2 |"
+            )
+        );
+    }
+}
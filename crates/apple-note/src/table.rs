@@ -53,13 +53,18 @@ pub fn parse_table(proto: &MergableDataProto) -> Option<Table> {
 
     let mut table_direction = LEFT_TO_RIGHT_DIRECTION.to_string();
 
+    // Direction registers are keyed one past the key item, unlike the table's own map.
+    let direction_key = key_items
+        .iter()
+        .position(|k| k == "crTableColumnDirection")
+        .map(|i| i + 1);
+
     for entry in table_objects {
         if let Some(ref custom_map) = entry.custom_map
             && let Some(first_entry) = custom_map.map_entry.first()
-            && (first_entry.key as usize) == key_items.len()
-            && let Some(dir) = &first_entry.value.string_value.as_str().into()
+            && Some(first_entry.key as usize) == direction_key
         {
-            table_direction = (*dir).to_string();
+            table_direction = first_entry.value.string_value.clone();
         }
     }
 
@@ -98,12 +103,9 @@ fn parse_table_entry(
     let mut cell_columns_entry: Option<&MergeableDataObjectEntry> = None;
 
     for map_entry in &custom_map.map_entry {
-        let key_index = map_entry.key as usize;
-        if key_index == 0 || key_index > key_items.len() {
+        let Some(key_name) = key_items.get(map_entry.key as usize) else {
             continue;
-        }
-
-        let key_name = &key_items[key_index - 1];
+        };
         let object_index = map_entry.value.object_index as usize;
 
         if object_index >= table_objects.len() {
@@ -0,0 +1,9 @@
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Opus(#[from] opus::Error),
+    #[error("unsupported channel count {0}")]
+    UnsupportedChannelCount(u8),
+    #[error("unsupported sample rate {0}")]
+    UnsupportedSampleRate(u32),
+}
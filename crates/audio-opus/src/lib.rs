@@ -0,0 +1,217 @@
+// Raw Opus packets for streaming 16-bit PCM over a websocket. Every message
+// carries exactly one packet, so there is no container framing.
+// https://opus-codec.org/docs/opus_api-1.3.1/group__opus__encoder.html
+
+mod error;
+
+pub use error::*;
+
+use bytes::Bytes;
+
+pub const FRAME_DURATION_MS: u32 = 20;
+pub const SUPPORTED_SAMPLE_RATES: &[u32] = &[8000, 12000, 16000, 24000, 48000];
+
+// Recommended by libopus as a safe upper bound for a single packet.
+const MAX_PACKET_SIZE: usize = 4000;
+// The longest frame a single packet can carry.
+const MAX_FRAME_DURATION_MS: u32 = 120;
+const BITRATE_PER_CHANNEL: i32 = 24_000;
+
+fn opus_channels(channels: u8) -> Result<opus::Channels, Error> {
+    match channels {
+        1 => Ok(opus::Channels::Mono),
+        2 => Ok(opus::Channels::Stereo),
+        n => Err(Error::UnsupportedChannelCount(n)),
+    }
+}
+
+fn check_sample_rate(sample_rate: u32) -> Result<(), Error> {
+    if SUPPORTED_SAMPLE_RATES.contains(&sample_rate) {
+        Ok(())
+    } else {
+        Err(Error::UnsupportedSampleRate(sample_rate))
+    }
+}
+
+fn samples_per_channel(sample_rate: u32, duration_ms: u32) -> usize {
+    (sample_rate * duration_ms / 1000) as usize
+}
+
+pub struct OpusEncoder {
+    encoder: opus::Encoder,
+    frame_len: usize,
+    pending: Vec<i16>,
+}
+
+impl OpusEncoder {
+    pub fn new(sample_rate: u32, channels: u8) -> Result<Self, Error> {
+        check_sample_rate(sample_rate)?;
+        let mut encoder = opus::Encoder::new(
+            sample_rate,
+            opus_channels(channels)?,
+            opus::Application::Voip,
+        )?;
+        encoder.set_bitrate(opus::Bitrate::Bits(
+            BITRATE_PER_CHANNEL * i32::from(channels),
+        ))?;
+
+        let frame_len = samples_per_channel(sample_rate, FRAME_DURATION_MS) * channels as usize;
+
+        Ok(Self {
+            encoder,
+            frame_len,
+            pending: Vec::with_capacity(frame_len * 2),
+        })
+    }
+
+    /// Buffers interleaved 16-bit little-endian PCM and returns one packet per
+    /// complete frame. Leftover samples wait for the next call.
+    pub fn encode(&mut self, pcm: &[u8]) -> Result<Vec<Bytes>, Error> {
+        self.pending.extend(
+            pcm.chunks_exact(2)
+                .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]])),
+        );
+
+        let mut packets = Vec::with_capacity(self.pending.len() / self.frame_len);
+        let mut offset = 0;
+        while self.pending.len() - offset >= self.frame_len {
+            let frame = &self.pending[offset..offset + self.frame_len];
+            packets.push(encode_frame(&mut self.encoder, frame)?);
+            offset += self.frame_len;
+        }
+        self.pending.drain(..offset);
+
+        Ok(packets)
+    }
+
+    /// Pads whatever is buffered with silence and encodes it as a final frame.
+    pub fn flush(&mut self) -> Result<Option<Bytes>, Error> {
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+
+        self.pending.resize(self.frame_len, 0);
+        let packet = encode_frame(&mut self.encoder, &self.pending)?;
+        self.pending.clear();
+
+        Ok(Some(packet))
+    }
+}
+
+fn encode_frame(encoder: &mut opus::Encoder, frame: &[i16]) -> Result<Bytes, Error> {
+    let mut packet = vec![0u8; MAX_PACKET_SIZE];
+    let len = encoder.encode(frame, &mut packet)?;
+    packet.truncate(len);
+    Ok(packet.into())
+}
+
+pub struct OpusDecoder {
+    decoder: opus::Decoder,
+    channels: usize,
+    buffer: Vec<i16>,
+}
+
+impl OpusDecoder {
+    pub fn new(sample_rate: u32, channels: u8) -> Result<Self, Error> {
+        check_sample_rate(sample_rate)?;
+        let decoder = opus::Decoder::new(sample_rate, opus_channels(channels)?)?;
+        let buffer_len =
+            samples_per_channel(sample_rate, MAX_FRAME_DURATION_MS) * channels as usize;
+
+        Ok(Self {
+            decoder,
+            channels: channels as usize,
+            buffer: vec![0; buffer_len],
+        })
+    }
+
+    /// Decodes one packet into interleaved 16-bit little-endian PCM.
+    pub fn decode(&mut self, packet: &[u8]) -> Result<Bytes, Error> {
+        let samples = self.decoder.decode(packet, &mut self.buffer, false)?;
+
+        let mut pcm = Vec::with_capacity(samples * self.channels * 2);
+        for sample in &self.buffer[..samples * self.channels] {
+            pcm.extend_from_slice(&sample.to_le_bytes());
+        }
+
+        Ok(pcm.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine_pcm(sample_rate: u32, channels: u8, duration_ms: u32) -> Vec<u8> {
+        let frames = samples_per_channel(sample_rate, duration_ms);
+        let mut pcm = Vec::with_capacity(frames * channels as usize * 2);
+        for i in 0..frames {
+            let t = i as f32 / sample_rate as f32;
+            let sample = ((t * 440.0 * std::f32::consts::TAU).sin() * 8000.0) as i16;
+            for _ in 0..channels {
+                pcm.extend_from_slice(&sample.to_le_bytes());
+            }
+        }
+        pcm
+    }
+
+    #[test]
+    fn test_round_trip_mono() {
+        let pcm = sine_pcm(16000, 1, 100);
+        let mut encoder = OpusEncoder::new(16000, 1).unwrap();
+        let mut decoder = OpusDecoder::new(16000, 1).unwrap();
+
+        let packets = encoder.encode(&pcm).unwrap();
+        assert_eq!(packets.len(), 5);
+        assert!(encoder.flush().unwrap().is_none());
+
+        let compressed: usize = packets.iter().map(Bytes::len).sum();
+        assert!(compressed * 8 < pcm.len(), "{compressed} vs {}", pcm.len());
+
+        let decoded: usize = packets
+            .iter()
+            .map(|p| decoder.decode(p).unwrap().len())
+            .sum();
+        assert_eq!(decoded, pcm.len());
+    }
+
+    #[test]
+    fn test_round_trip_stereo() {
+        let pcm = sine_pcm(16000, 2, 40);
+        let mut encoder = OpusEncoder::new(16000, 2).unwrap();
+        let mut decoder = OpusDecoder::new(16000, 2).unwrap();
+
+        let packets = encoder.encode(&pcm).unwrap();
+        assert_eq!(packets.len(), 2);
+
+        let frame = decoder.decode(&packets[0]).unwrap();
+        assert_eq!(frame.len(), 320 * 2 * 2);
+    }
+
+    #[test]
+    fn test_partial_frames_are_buffered() {
+        let pcm = sine_pcm(16000, 1, 30);
+        let mut encoder = OpusEncoder::new(16000, 1).unwrap();
+
+        let (head, tail) = pcm.split_at(200);
+        assert!(encoder.encode(head).unwrap().is_empty());
+        assert_eq!(encoder.encode(tail).unwrap().len(), 1);
+
+        let mut decoder = OpusDecoder::new(16000, 1).unwrap();
+        let last = encoder.flush().unwrap().unwrap();
+        assert_eq!(decoder.decode(&last).unwrap().len(), 320 * 2);
+        assert!(encoder.flush().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rejects_unsupported_formats() {
+        assert!(matches!(
+            OpusEncoder::new(44100, 1),
+            Err(Error::UnsupportedSampleRate(44100))
+        ));
+        assert!(matches!(
+            OpusDecoder::new(16000, 3),
+            Err(Error::UnsupportedChannelCount(3))
+        ));
+    }
+}
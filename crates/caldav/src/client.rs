@@ -0,0 +1,481 @@
+use reqwest::{Method, StatusCode};
+use url::Url;
+
+use crate::Error;
+use crate::xml::{self, DavResponse, Multistatus};
+
+// Keeps `calendar-multiget` bodies within what servers typically accept.
+const MULTIGET_CHUNK: usize = 100;
+// Bounds how many truncated `sync-collection` pages are followed before a full sync.
+const MAX_SYNC_PAGES: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalDavCalendar {
+    pub url: String,
+    pub name: String,
+    pub color: Option<String>,
+    pub ctag: Option<String>,
+    pub sync_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarObject {
+    pub url: String,
+    pub etag: Option<String>,
+    pub data: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncDelta {
+    /// `changed` holds the whole calendar, so anything else stored for it is stale.
+    pub full: bool,
+    pub changed: Vec<CalendarObject>,
+    /// URLs of removed calendar objects. Always empty for a full sync.
+    pub deleted: Vec<String>,
+    /// Pass this to the next `sync` call. `None` if the server does not support `sync-collection`.
+    pub sync_token: Option<String>,
+}
+
+pub struct CalDavClient {
+    http: reqwest::Client,
+    base_url: Url,
+    credentials: Option<Credentials>,
+}
+
+impl CalDavClient {
+    pub fn new(base_url: impl AsRef<str>, credentials: Option<Credentials>) -> Result<Self, Error> {
+        Ok(Self {
+            http: reqwest::Client::new(),
+            base_url: Url::parse(base_url.as_ref())?,
+            credentials,
+        })
+    }
+
+    async fn request(
+        &self,
+        method: &'static str,
+        url: &Url,
+        depth: &'static str,
+        body: String,
+    ) -> Result<(StatusCode, String), Error> {
+        let method = Method::from_bytes(method.as_bytes()).unwrap();
+
+        let mut request = self
+            .http
+            .request(method, url.clone())
+            .header("Depth", depth)
+            .header("Content-Type", "application/xml; charset=utf-8")
+            .body(body);
+
+        if let Some(credentials) = &self.credentials {
+            request = request.basic_auth(&credentials.username, Some(&credentials.password));
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        Ok((status, response.text().await?))
+    }
+
+    async fn multistatus(
+        &self,
+        method: &'static str,
+        url: &Url,
+        depth: &'static str,
+        body: impl Into<String>,
+    ) -> Result<Multistatus, Error> {
+        let (status, text) = self.request(method, url, depth, body.into()).await?;
+
+        if !status.is_success() {
+            return Err(Error::Status {
+                status,
+                url: url.to_string(),
+            });
+        }
+        xml::parse_multistatus(&text)
+    }
+
+    async fn first_response(
+        &self,
+        url: &Url,
+        body: &'static str,
+    ) -> Result<Option<DavResponse>, Error> {
+        let multistatus = self.multistatus("PROPFIND", url, "0", body).await?;
+        Ok(multistatus.responses.into_iter().next())
+    }
+
+    async fn find_principal(&self) -> Result<Option<Url>, Error> {
+        let candidates = [
+            self.base_url.clone(),
+            self.base_url.join("/.well-known/caldav")?,
+        ];
+
+        for candidate in candidates {
+            match self
+                .first_response(&candidate, xml::PROPFIND_PRINCIPAL)
+                .await
+            {
+                Ok(Some(response)) => {
+                    if let Some(principal) = response.props.current_user_principal {
+                        return Ok(Some(candidate.join(&principal)?));
+                    }
+                }
+                Ok(None) => {}
+                Err(Error::Status { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Follows `current-user-principal` and `calendar-home-set` from the base URL, falling
+    /// back to `/.well-known/caldav`, and lists the calendars that can hold events.
+    /// If neither property is found, the base URL is treated as the calendar home.
+    pub async fn discover(&self) -> Result<Vec<CalDavCalendar>, Error> {
+        let mut home = self.base_url.clone();
+
+        if let Some(principal) = self.find_principal().await?
+            && let Some(response) = self
+                .first_response(&principal, xml::PROPFIND_HOME_SET)
+                .await?
+            && let Some(home_set) = response.props.calendar_home_set
+        {
+            home = principal.join(&home_set)?;
+        }
+
+        let multistatus = self
+            .multistatus("PROPFIND", &home, "1", xml::PROPFIND_CALENDARS)
+            .await?;
+
+        multistatus
+            .responses
+            .into_iter()
+            .filter(|r| r.props.is_calendar)
+            .filter(|r| {
+                r.props.components.is_empty() || r.props.components.iter().any(|c| c == "VEVENT")
+            })
+            .map(|r| {
+                let url = home.join(&r.href)?;
+                let name = r.props.display_name.clone().unwrap_or_else(|| {
+                    url.path_segments()
+                        .and_then(|mut s| s.rfind(|s| !s.is_empty()))
+                        .unwrap_or("Calendar")
+                        .to_string()
+                });
+
+                Ok(CalDavCalendar {
+                    url: url.to_string(),
+                    name,
+                    color: r.props.color,
+                    ctag: r.props.ctag,
+                    sync_token: r.props.sync_token,
+                })
+            })
+            .collect()
+    }
+
+    /// Incremental sync with `sync-collection` when `sync_token` is set, falling back
+    /// to a full `calendar-query` when there is no token or the server rejects it.
+    /// Truncated results (RFC 6578 section 3.6) are followed with the returned token.
+    pub async fn sync(
+        &self,
+        calendar_url: impl AsRef<str>,
+        sync_token: Option<&str>,
+    ) -> Result<SyncDelta, Error> {
+        let calendar_url = Url::parse(calendar_url.as_ref())?;
+
+        let Some(sync_token) = sync_token else {
+            return self.full_sync(&calendar_url).await;
+        };
+
+        let mut sync_token = sync_token.to_string();
+        // (url, href) pairs, as `calendar-multiget` takes the hrefs the server reported.
+        let mut changed: Vec<(String, String)> = vec![];
+        let mut deleted: Vec<String> = vec![];
+
+        for _ in 0..MAX_SYNC_PAGES {
+            let (status, text) = self
+                .request(
+                    "REPORT",
+                    &calendar_url,
+                    "0",
+                    xml::sync_collection(&sync_token),
+                )
+                .await?;
+
+            // 403/409 with `valid-sync-token` means the token expired; anything else
+            // unsuccessful means the server cannot do incremental sync at all.
+            if !status.is_success() {
+                tracing::info!("caldav_sync_token_rejected: {}", status);
+                return self.full_sync(&calendar_url).await;
+            }
+
+            let multistatus = xml::parse_multistatus(&text)?;
+            let mut truncated = false;
+
+            for response in multistatus.responses {
+                let url = calendar_url.join(&response.href)?;
+                if url == calendar_url {
+                    // The server reports the collection itself with 507 when more changes follow.
+                    truncated |= response.status == Some(507);
+                    continue;
+                }
+
+                // A later page wins over an earlier one for the same object.
+                let url = url.to_string();
+                changed.retain(|(u, _)| u != &url);
+                deleted.retain(|u| u != &url);
+
+                match response.status {
+                    Some(404) => deleted.push(url),
+                    _ => changed.push((url, response.href)),
+                }
+            }
+
+            match multistatus.sync_token {
+                Some(next) if truncated && next != sync_token => sync_token = next,
+                next => {
+                    let hrefs = changed
+                        .into_iter()
+                        .map(|(_, href)| href)
+                        .collect::<Vec<_>>();
+                    return Ok(SyncDelta {
+                        full: false,
+                        changed: self.multiget(&calendar_url, &hrefs).await?,
+                        deleted,
+                        sync_token: next,
+                    });
+                }
+            }
+        }
+
+        tracing::warn!("caldav_sync_too_many_pages: {}", calendar_url);
+        self.full_sync(&calendar_url).await
+    }
+
+    async fn full_sync(&self, calendar_url: &Url) -> Result<SyncDelta, Error> {
+        // Read the token first, so changes made during the query are picked up next time.
+        let sync_token = match self
+            .first_response(calendar_url, xml::PROPFIND_SYNC_TOKEN)
+            .await
+        {
+            Ok(response) => response.and_then(|r| r.props.sync_token),
+            Err(Error::Status { .. }) => None,
+            Err(e) => return Err(e),
+        };
+
+        let multistatus = self
+            .multistatus("REPORT", calendar_url, "1", xml::REPORT_CALENDAR_QUERY)
+            .await?;
+
+        Ok(SyncDelta {
+            full: true,
+            changed: objects(calendar_url, multistatus)?,
+            deleted: vec![],
+            sync_token,
+        })
+    }
+
+    async fn multiget(
+        &self,
+        calendar_url: &Url,
+        hrefs: &[String],
+    ) -> Result<Vec<CalendarObject>, Error> {
+        let mut out = vec![];
+
+        for chunk in hrefs.chunks(MULTIGET_CHUNK) {
+            let multistatus = self
+                .multistatus("REPORT", calendar_url, "1", xml::calendar_multiget(chunk))
+                .await?;
+            out.extend(objects(calendar_url, multistatus)?);
+        }
+
+        Ok(out)
+    }
+}
+
+fn objects(calendar_url: &Url, multistatus: Multistatus) -> Result<Vec<CalendarObject>, Error> {
+    multistatus
+        .responses
+        .into_iter()
+        .filter_map(|r| Some((r.href, r.props.etag, r.props.calendar_data?)))
+        .map(|(href, etag, data)| {
+            Ok(CalendarObject {
+                url: calendar_url.join(&href)?.to_string(),
+                etag,
+                data,
+            })
+        })
+        .collect()
+}
+
+/// Fetches a read-only `.ics` subscription. `webcal://` URLs are fetched over HTTPS.
+pub async fn fetch_ics(http: &reqwest::Client, url: impl AsRef<str>) -> Result<String, Error> {
+    let url = url.as_ref();
+    let url = match url.strip_prefix("webcal://") {
+        Some(rest) => format!("https://{}", rest),
+        None => url.to_string(),
+    };
+
+    let response = http.get(&url).send().await?;
+    if !response.status().is_success() {
+        return Err(Error::Status {
+            status: response.status(),
+            url,
+        });
+    }
+
+    Ok(response.text().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn multistatus(body: &str) -> ResponseTemplate {
+        ResponseTemplate::new(207).set_body_string(format!(
+            r#"<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">{}</d:multistatus>"#,
+            body
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_discover() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("PROPFIND"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("PROPFIND"))
+            .and(path("/.well-known/caldav"))
+            .and(body_string_contains("current-user-principal"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/.well-known/caldav</d:href><d:propstat><d:prop><d:current-user-principal><d:href>/principals/jane/</d:href></d:current-user-principal></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("PROPFIND"))
+            .and(path("/principals/jane/"))
+            .and(header("Depth", "0"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/principals/jane/</d:href><d:propstat><d:prop><c:calendar-home-set><d:href>/calendars/jane/</d:href></c:calendar-home-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("PROPFIND"))
+            .and(path("/calendars/jane/"))
+            .and(header("Depth", "1"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/calendars/jane/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+                <d:response><d:href>/calendars/jane/work/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/><c:calendar/></d:resourcetype><d:displayname>Work</d:displayname><d:sync-token>t1</d:sync-token><c:supported-calendar-component-set><c:comp name="VEVENT"/></c:supported-calendar-component-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+                <d:response><d:href>/calendars/jane/tasks/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/><c:calendar/></d:resourcetype><d:displayname>Tasks</d:displayname><c:supported-calendar-component-set><c:comp name="VTODO"/></c:supported-calendar-component-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = CalDavClient::new(server.uri(), None).unwrap();
+        let calendars = client.discover().await.unwrap();
+
+        assert_eq!(
+            calendars,
+            vec![CalDavCalendar {
+                url: format!("{}/calendars/jane/work/", server.uri()),
+                name: "Work".to_string(),
+                color: None,
+                ctag: None,
+                sync_token: Some("t1".to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_falls_back_to_full() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("REPORT"))
+            .and(body_string_contains("sync-collection"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+        Mock::given(method("PROPFIND"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/cal/</d:href><d:propstat><d:prop><d:sync-token>t9</d:sync-token></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("REPORT"))
+            .and(body_string_contains("calendar-query"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/cal/a.ics</d:href><d:propstat><d:prop><d:getetag>"1"</d:getetag><c:calendar-data>BEGIN:VCALENDAR&#13;
+END:VCALENDAR</c:calendar-data></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = CalDavClient::new(server.uri(), None).unwrap();
+        let delta = client
+            .sync(format!("{}/cal/", server.uri()), Some("expired"))
+            .await
+            .unwrap();
+
+        assert!(delta.full);
+        assert_eq!(delta.sync_token.as_deref(), Some("t9"));
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(delta.changed[0].url, format!("{}/cal/a.ics", server.uri()));
+        assert_eq!(delta.changed[0].etag.as_deref(), Some("\"1\""));
+    }
+
+    #[tokio::test]
+    async fn test_sync_follows_truncated_results() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("REPORT"))
+            .and(body_string_contains("<d:sync-token>t1</d:sync-token>"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/cal/a.ics</d:href><d:propstat><d:prop><d:getetag>"1"</d:getetag></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+                <d:response><d:href>/cal/b.ics</d:href><d:propstat><d:prop><d:getetag>"1"</d:getetag></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+                <d:response><d:href>/cal/</d:href><d:status>HTTP/1.1 507 Insufficient Storage</d:status></d:response>
+                <d:sync-token>t2</d:sync-token>"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("REPORT"))
+            .and(body_string_contains("<d:sync-token>t2</d:sync-token>"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/cal/b.ics</d:href><d:status>HTTP/1.1 404 Not Found</d:status></d:response>
+                <d:sync-token>t3</d:sync-token>"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("REPORT"))
+            .and(body_string_contains("calendar-multiget"))
+            .respond_with(multistatus(
+                r#"<d:response><d:href>/cal/a.ics</d:href><d:propstat><d:prop><d:getetag>"1"</d:getetag><c:calendar-data>BEGIN:VCALENDAR&#13;
+END:VCALENDAR</c:calendar-data></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = CalDavClient::new(server.uri(), None).unwrap();
+        let delta = client
+            .sync(format!("{}/cal/", server.uri()), Some("t1"))
+            .await
+            .unwrap();
+
+        assert!(!delta.full);
+        assert_eq!(delta.sync_token.as_deref(), Some("t3"));
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(delta.changed[0].url, format!("{}/cal/a.ics", server.uri()));
+        assert_eq!(delta.deleted, vec![format!("{}/cal/b.ics", server.uri())]);
+    }
+}
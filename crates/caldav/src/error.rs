@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+
+    #[error(transparent)]
+    Url(#[from] url::ParseError),
+
+    #[error(transparent)]
+    Xml(#[from] roxmltree::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Db(#[from] hypr_db_user::Error),
+
+    #[error("invalid iCalendar data: {0}")]
+    InvalidIcs(String),
+
+    #[error("unsupported RRULE: {0}")]
+    UnsupportedRRule(String),
+
+    #[error("CalDAV server returned {status} for {url}")]
+    Status {
+        status: reqwest::StatusCode,
+        url: String,
+    },
+}
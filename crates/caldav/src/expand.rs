@@ -0,0 +1,319 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::ics::{IcsAttendee, IcsCalendar, IcsEvent, IcsTime, resolve_tz};
+use crate::rrule::RRule;
+
+/// A single concrete instance of an event, after recurrence expansion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence {
+    pub uid: String,
+    /// Original start of this instance, set for every instance of a recurring event.
+    pub recurrence_id: Option<DateTime<Utc>>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub all_day: bool,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    /// Organizer first, then attendees who have not declined, without duplicates.
+    pub participants: Vec<IcsAttendee>,
+}
+
+impl Occurrence {
+    pub fn is_recurring(&self) -> bool {
+        self.recurrence_id.is_some()
+    }
+}
+
+/// Expands every event in `calendar` into the occurrences that overlap `from..to`,
+/// applying `RRULE`, `RDATE`, `EXDATE`, and `RECURRENCE-ID` overrides.
+pub fn expand(calendar: &IcsCalendar, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<Occurrence> {
+    let default_tz = calendar.timezone.as_deref().and_then(resolve_tz);
+
+    let mut groups: BTreeMap<&str, (Option<&IcsEvent>, Vec<&IcsEvent>)> = BTreeMap::new();
+    for event in &calendar.events {
+        let group = groups.entry(event.uid.as_str()).or_default();
+        match event.recurrence_id {
+            Some(_) => group.1.push(event),
+            None if group.0.is_none() => group.0 = Some(event),
+            None => tracing::warn!("duplicate_uid: {}", event.uid),
+        }
+    }
+
+    let mut occurrences = vec![];
+    for (master, overrides) in groups.into_values() {
+        expand_series(master, overrides, default_tz, from, to, &mut occurrences);
+    }
+
+    occurrences.retain(|o| o.start <= to && o.end >= from);
+    occurrences.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.uid.cmp(&b.uid)));
+    occurrences
+}
+
+fn expand_series(
+    master: Option<&IcsEvent>,
+    overrides: Vec<&IcsEvent>,
+    default_tz: Option<Tz>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    out: &mut Vec<Occurrence>,
+) {
+    let mut overrides: BTreeMap<DateTime<Utc>, &IcsEvent> = overrides
+        .into_iter()
+        .filter_map(|e| Some((e.recurrence_id.as_ref()?.to_utc(default_tz), e)))
+        .collect();
+
+    if let Some(master) = master {
+        if is_cancelled(master) {
+            return;
+        }
+
+        let is_recurring = master.rrule.is_some() || !master.rdates.is_empty();
+        let starts = instance_starts(master, default_tz, from, to);
+        let exdates: Vec<&IcsTime> = master.exdates.iter().collect();
+
+        for start in starts {
+            let start_utc = start.to_utc(default_tz);
+            if is_excluded(&start, start_utc, &exdates, default_tz) {
+                continue;
+            }
+
+            let recurrence_id = is_recurring.then_some(start_utc);
+            match overrides.remove(&start_utc) {
+                Some(instance) if is_cancelled(instance) => {}
+                Some(instance) => out.push(occurrence(instance, None, recurrence_id, default_tz)),
+                None => out.push(occurrence(master, Some(&start), recurrence_id, default_tz)),
+            }
+        }
+    }
+
+    // Overrides that moved an instance to a date the rule itself does not produce,
+    // or whose master we never received.
+    for (recurrence_id, instance) in overrides {
+        if !is_cancelled(instance) {
+            out.push(occurrence(instance, None, Some(recurrence_id), default_tz));
+        }
+    }
+}
+
+fn instance_starts(
+    master: &IcsEvent,
+    default_tz: Option<Tz>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<IcsTime> {
+    let mut starts = vec![];
+
+    match master.rrule.as_deref().map(RRule::parse) {
+        None => starts.push(master.start.clone()),
+        Some(Err(e)) => {
+            tracing::warn!("{}", e);
+            starts.push(master.start.clone());
+        }
+        Some(Ok(rule)) => {
+            // Rules run on wall-clock time; the margin covers any UTC offset.
+            let limit = to.naive_utc() + Duration::days(2);
+            let until = rule
+                .until
+                .as_ref()
+                .map(|until| until_local(until, &master.start, default_tz));
+
+            starts.extend(
+                rule.expand(master.start.local(), until, limit)
+                    .into_iter()
+                    .map(|local| master.start.with_local(local)),
+            );
+        }
+    }
+
+    starts.extend(master.rdates.iter().cloned());
+
+    // Instances that ended before the window are never needed, but the rule had to
+    // be walked from DTSTART for COUNT to be right.
+    let duration = duration(master, default_tz);
+    starts.retain(|s| s.to_utc(default_tz) + duration >= from);
+    starts
+}
+
+// UNTIL is UTC (or a date) while the rule runs on DTSTART's wall clock.
+fn until_local(until: &IcsTime, dtstart: &IcsTime, default_tz: Option<Tz>) -> NaiveDateTime {
+    match until {
+        IcsTime::Date(date) => date.and_hms_opt(23, 59, 59).unwrap(),
+        IcsTime::Floating(local) | IcsTime::Zoned { local, .. } => *local,
+        IcsTime::Utc(utc) => {
+            let tz = match dtstart {
+                IcsTime::Zoned { tzid, .. } => resolve_tz(tzid).or(default_tz),
+                IcsTime::Utc(_) => None,
+                _ => default_tz,
+            };
+            match tz {
+                Some(tz) => utc.with_timezone(&tz).naive_local(),
+                None => utc.naive_utc(),
+            }
+        }
+    }
+}
+
+fn is_excluded(
+    start: &IcsTime,
+    start_utc: DateTime<Utc>,
+    exdates: &[&IcsTime],
+    default_tz: Option<Tz>,
+) -> bool {
+    exdates.iter().any(|exdate| match exdate {
+        IcsTime::Date(date) => start.local().date() == *date,
+        _ => exdate.to_utc(default_tz) == start_utc,
+    })
+}
+
+fn is_cancelled(event: &IcsEvent) -> bool {
+    event.status.as_deref() == Some("CANCELLED")
+}
+
+fn duration(event: &IcsEvent, default_tz: Option<Tz>) -> Duration {
+    match (&event.end, event.duration) {
+        (Some(end), _) => end.to_utc(default_tz) - event.start.to_utc(default_tz),
+        (None, Some(duration)) => duration,
+        (None, None) if event.start.is_date() => Duration::days(1),
+        (None, None) => Duration::zero(),
+    }
+}
+
+fn occurrence(
+    event: &IcsEvent,
+    start: Option<&IcsTime>,
+    recurrence_id: Option<DateTime<Utc>>,
+    default_tz: Option<Tz>,
+) -> Occurrence {
+    let start = start.unwrap_or(&event.start);
+    let start_utc = start.to_utc(default_tz);
+
+    Occurrence {
+        uid: event.uid.clone(),
+        recurrence_id,
+        start: start_utc,
+        end: start_utc + duration(event, default_tz),
+        all_day: start.is_date(),
+        summary: event.summary.clone(),
+        description: event.description.clone(),
+        location: event.location.clone(),
+        url: event.url.clone(),
+        participants: participants(event),
+    }
+}
+
+fn participants(event: &IcsEvent) -> Vec<IcsAttendee> {
+    let mut out: Vec<IcsAttendee> = vec![];
+
+    for attendee in event.organizer.iter().chain(event.attendees.iter()) {
+        if attendee.declined {
+            continue;
+        }
+
+        let duplicate = out
+            .iter()
+            .any(|existing| match (&existing.email, &attendee.email) {
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                _ => false,
+            });
+        if !duplicate {
+            out.push(attendee.clone());
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics::parse;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_expand() {
+        let calendar = parse(indoc::indoc! {"
+            BEGIN:VCALENDAR
+            BEGIN:VEVENT
+            UID:standup
+            SUMMARY:Standup
+            DTSTART;TZID=America/New_York:20250303T093000
+            DTEND;TZID=America/New_York:20250303T094500
+            RRULE:FREQ=DAILY;COUNT=10
+            EXDATE;TZID=America/New_York:20250305T093000
+            ORGANIZER;CN=Alice:mailto:alice@example.com
+            ATTENDEE;CN=Alice:mailto:ALICE@example.com
+            ATTENDEE;CN=Bob:mailto:bob@example.com
+            END:VEVENT
+            BEGIN:VEVENT
+            UID:standup
+            RECURRENCE-ID;TZID=America/New_York:20250306T093000
+            SUMMARY:Standup (moved)
+            DTSTART;TZID=America/New_York:20250306T110000
+            DTEND;TZID=America/New_York:20250306T111500
+            END:VEVENT
+            BEGIN:VEVENT
+            UID:standup
+            RECURRENCE-ID;TZID=America/New_York:20250307T093000
+            STATUS:CANCELLED
+            DTSTART;TZID=America/New_York:20250307T093000
+            END:VEVENT
+            BEGIN:VEVENT
+            UID:offsite
+            SUMMARY:Offsite
+            DTSTART;VALUE=DATE:20250310
+            DTEND;VALUE=DATE:20250312
+            END:VEVENT
+            END:VCALENDAR
+        "})
+        .unwrap();
+
+        let occurrences = expand(
+            &calendar,
+            utc("2025-03-04T00:00:00Z"),
+            utc("2025-03-10T12:00:00Z"),
+        );
+
+        let summary: Vec<_> = occurrences
+            .iter()
+            .map(|o| {
+                format!(
+                    "{} {}",
+                    o.start.to_rfc3339(),
+                    o.summary.as_deref().unwrap_or_default()
+                )
+            })
+            .collect();
+
+        // DST starts in New York on 2025-03-09, moving the instance from 14:30 to 13:30 UTC.
+        assert_eq!(
+            summary,
+            vec![
+                "2025-03-04T14:30:00+00:00 Standup",
+                "2025-03-06T16:00:00+00:00 Standup (moved)",
+                "2025-03-08T14:30:00+00:00 Standup",
+                "2025-03-09T13:30:00+00:00 Standup",
+                "2025-03-10T00:00:00+00:00 Offsite",
+            ]
+        );
+
+        let moved = &occurrences[1];
+        assert_eq!(moved.recurrence_id, Some(utc("2025-03-06T14:30:00Z")));
+        assert_eq!(moved.end - moved.start, Duration::minutes(15));
+
+        let standup = &occurrences[0];
+        assert!(standup.is_recurring());
+        assert_eq!(standup.participants.len(), 2);
+
+        let offsite = &occurrences[4];
+        assert!(offsite.all_day && !offsite.is_recurring());
+        assert_eq!(offsite.end, utc("2025-03-12T00:00:00Z"));
+    }
+}
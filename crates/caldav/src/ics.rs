@@ -0,0 +1,571 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::Error;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IcsCalendar {
+    /// `X-WR-CALNAME`, which most feeds use as the display name.
+    pub name: Option<String>,
+    /// `X-WR-TIMEZONE`, used for floating times.
+    pub timezone: Option<String>,
+    pub events: Vec<IcsEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsEvent {
+    pub uid: String,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    pub status: Option<String>,
+    pub start: IcsTime,
+    pub end: Option<IcsTime>,
+    pub duration: Option<Duration>,
+    pub rrule: Option<String>,
+    pub rdates: Vec<IcsTime>,
+    pub exdates: Vec<IcsTime>,
+    /// Set on overrides of a single occurrence of a recurring event.
+    pub recurrence_id: Option<IcsTime>,
+    pub organizer: Option<IcsAttendee>,
+    pub attendees: Vec<IcsAttendee>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsAttendee {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub declined: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IcsTime {
+    Date(NaiveDate),
+    Floating(NaiveDateTime),
+    Utc(DateTime<Utc>),
+    Zoned { local: NaiveDateTime, tzid: String },
+}
+
+impl IcsTime {
+    pub fn is_date(&self) -> bool {
+        matches!(self, IcsTime::Date(_))
+    }
+
+    /// Wall-clock time, which is what recurrence rules are expanded in.
+    pub fn local(&self) -> NaiveDateTime {
+        match self {
+            IcsTime::Date(date) => date.and_hms_opt(0, 0, 0).unwrap(),
+            IcsTime::Floating(local) | IcsTime::Zoned { local, .. } => *local,
+            IcsTime::Utc(utc) => utc.naive_utc(),
+        }
+    }
+
+    /// Same zone, different wall-clock time.
+    pub fn with_local(&self, local: NaiveDateTime) -> IcsTime {
+        match self {
+            IcsTime::Date(_) => IcsTime::Date(local.date()),
+            IcsTime::Floating(_) => IcsTime::Floating(local),
+            IcsTime::Utc(_) => IcsTime::Utc(local.and_utc()),
+            IcsTime::Zoned { tzid, .. } => IcsTime::Zoned {
+                local,
+                tzid: tzid.clone(),
+            },
+        }
+    }
+
+    /// Dates and floating times are placed in `default_tz`, or UTC when there is none.
+    /// Unknown `TZID`s fall back the same way.
+    pub fn to_utc(&self, default_tz: Option<Tz>) -> DateTime<Utc> {
+        let (local, tz) = match self {
+            IcsTime::Utc(utc) => return *utc,
+            IcsTime::Date(_) | IcsTime::Floating(_) => (self.local(), default_tz),
+            IcsTime::Zoned { local, tzid } => {
+                let tz = resolve_tz(tzid);
+                if tz.is_none() {
+                    tracing::warn!("unknown_tzid: {}", tzid);
+                }
+                (*local, tz.or(default_tz))
+            }
+        };
+
+        match tz {
+            Some(tz) => local_to_utc(&tz, local),
+            None => local.and_utc(),
+        }
+    }
+
+    pub(crate) fn parse(value: &str, params: &[(String, String)]) -> Result<Self, Error> {
+        let value = value.trim();
+        let is_date = param(params, "VALUE").is_some_and(|v| v.eq_ignore_ascii_case("DATE"))
+            || (value.len() == 8 && !value.contains('T'));
+
+        if is_date {
+            return NaiveDate::parse_from_str(value, "%Y%m%d")
+                .map(IcsTime::Date)
+                .map_err(|_| Error::InvalidIcs(format!("invalid date {:?}", value)));
+        }
+
+        let (naive, is_utc) = match value.strip_suffix('Z') {
+            Some(naive) => (naive, true),
+            None => (value, false),
+        };
+        let local = NaiveDateTime::parse_from_str(naive, "%Y%m%dT%H%M%S")
+            .map_err(|_| Error::InvalidIcs(format!("invalid date-time {:?}", value)))?;
+
+        Ok(match (is_utc, param(params, "TZID")) {
+            (true, _) => IcsTime::Utc(local.and_utc()),
+            (false, Some(tzid)) => IcsTime::Zoned {
+                local,
+                tzid: tzid.to_string(),
+            },
+            (false, None) => IcsTime::Floating(local),
+        })
+    }
+}
+
+pub(crate) fn local_to_utc(tz: &Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    match tz.from_local_datetime(&local) {
+        chrono::LocalResult::Single(t) => t.with_timezone(&Utc),
+        chrono::LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+        // Wall-clock times skipped by a DST transition are pushed past the gap.
+        chrono::LocalResult::None => local_to_utc(tz, local + Duration::hours(1)),
+    }
+}
+
+/// Resolves IANA names, including the `/vendor/.../Area/City` form some clients emit,
+/// and the Windows names Outlook and Exchange use.
+pub fn resolve_tz(tzid: &str) -> Option<Tz> {
+    let tzid = tzid.trim().trim_matches('"');
+
+    if let Ok(tz) = Tz::from_str(tzid) {
+        return Some(tz);
+    }
+
+    let mut rest = tzid;
+    while let Some((_, tail)) = rest.split_once('/') {
+        if let Ok(tz) = Tz::from_str(tail) {
+            return Some(tz);
+        }
+        rest = tail;
+    }
+
+    let iana = match tzid {
+        "UTC" | "Coordinated Universal Time" => "UTC",
+        "GMT Standard Time" => "Europe/London",
+        "W. Europe Standard Time" => "Europe/Berlin",
+        "Romance Standard Time" => "Europe/Paris",
+        "Central Europe Standard Time" => "Europe/Budapest",
+        "Central European Standard Time" => "Europe/Warsaw",
+        "E. Europe Standard Time" => "Europe/Chisinau",
+        "FLE Standard Time" => "Europe/Kiev",
+        "Eastern Standard Time" => "America/New_York",
+        "Central Standard Time" => "America/Chicago",
+        "Mountain Standard Time" => "America/Denver",
+        "US Mountain Standard Time" => "America/Phoenix",
+        "Pacific Standard Time" => "America/Los_Angeles",
+        "Alaskan Standard Time" => "America/Anchorage",
+        "Hawaiian Standard Time" => "Pacific/Honolulu",
+        "E. South America Standard Time" => "America/Sao_Paulo",
+        "India Standard Time" => "Asia/Kolkata",
+        "China Standard Time" => "Asia/Shanghai",
+        "Singapore Standard Time" => "Asia/Singapore",
+        "Tokyo Standard Time" => "Asia/Tokyo",
+        "Korea Standard Time" => "Asia/Seoul",
+        "AUS Eastern Standard Time" => "Australia/Sydney",
+        "New Zealand Standard Time" => "Pacific/Auckland",
+        _ => return None,
+    };
+    Tz::from_str(iana).ok()
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+struct ContentLine {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for raw in text.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+
+        match raw.strip_prefix([' ', '\t']) {
+            Some(continuation) if !lines.is_empty() => {
+                lines.last_mut().unwrap().push_str(continuation)
+            }
+            _ if raw.is_empty() => {}
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    lines
+}
+
+fn parse_line(line: &str) -> Option<ContentLine> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut pos = 0;
+
+    let mut name = String::new();
+    while pos < chars.len() && chars[pos] != ';' && chars[pos] != ':' {
+        name.push(chars[pos]);
+        pos += 1;
+    }
+
+    let mut params = Vec::new();
+    while pos < chars.len() && chars[pos] == ';' {
+        pos += 1;
+
+        let mut key = String::new();
+        while pos < chars.len() && !matches!(chars[pos], '=' | ';' | ':') {
+            key.push(chars[pos]);
+            pos += 1;
+        }
+
+        let mut value = String::new();
+        if pos < chars.len() && chars[pos] == '=' {
+            pos += 1;
+            let mut quoted = false;
+            while pos < chars.len() {
+                match chars[pos] {
+                    '"' => quoted = !quoted,
+                    ';' | ':' if !quoted => break,
+                    c => value.push(c),
+                }
+                pos += 1;
+            }
+        }
+
+        params.push((key.to_ascii_uppercase(), value));
+    }
+
+    if pos >= chars.len() || chars[pos] != ':' {
+        return None;
+    }
+
+    Some(ContentLine {
+        name: name.to_ascii_uppercase(),
+        params,
+        value: chars[pos + 1..].iter().collect(),
+    })
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+
+    out
+}
+
+/// Parses an RFC 5545 duration such as `PT1H30M` or `-P1D`.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let value = value.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+
+    for c in value.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match (unit, in_time) {
+                    ('W', false) => Duration::weeks(n),
+                    ('D', false) => Duration::days(n),
+                    ('H', true) => Duration::hours(n),
+                    ('M', true) => Duration::minutes(n),
+                    ('S', true) => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+
+    if !number.is_empty() {
+        return None;
+    }
+    Some(total * sign)
+}
+
+fn parse_attendee(line: &ContentLine) -> IcsAttendee {
+    let value = line.value.trim();
+    let email = value
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map(|_| value[7..].to_string())
+        .or_else(|| param(&line.params, "EMAIL").map(str::to_string))
+        .filter(|email| !email.is_empty());
+
+    IcsAttendee {
+        name: param(&line.params, "CN")
+            .map(str::to_string)
+            .filter(|name| !name.is_empty()),
+        email,
+        declined: param(&line.params, "PARTSTAT")
+            .is_some_and(|v| v.eq_ignore_ascii_case("DECLINED")),
+    }
+}
+
+fn parse_time_list(line: &ContentLine) -> Result<Vec<IcsTime>, Error> {
+    line.value
+        .split(',')
+        .filter(|v| !v.trim().is_empty())
+        // RDATE periods (`start/end`) only contribute their start.
+        .map(|v| IcsTime::parse(v.split('/').next().unwrap_or(v), &line.params))
+        .collect()
+}
+
+#[derive(Default)]
+struct EventBuilder {
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    url: Option<String>,
+    status: Option<String>,
+    start: Option<IcsTime>,
+    end: Option<IcsTime>,
+    duration: Option<Duration>,
+    rrule: Option<String>,
+    rdates: Vec<IcsTime>,
+    exdates: Vec<IcsTime>,
+    recurrence_id: Option<IcsTime>,
+    organizer: Option<IcsAttendee>,
+    attendees: Vec<IcsAttendee>,
+}
+
+impl EventBuilder {
+    fn add(&mut self, line: &ContentLine) -> Result<(), Error> {
+        match line.name.as_str() {
+            "UID" => self.uid = Some(line.value.trim().to_string()),
+            "SUMMARY" => self.summary = Some(unescape(&line.value)),
+            "DESCRIPTION" => self.description = Some(unescape(&line.value)),
+            "LOCATION" => self.location = Some(unescape(&line.value)),
+            "URL" => self.url = Some(line.value.trim().to_string()),
+            "STATUS" => self.status = Some(line.value.trim().to_ascii_uppercase()),
+            "DTSTART" => self.start = Some(IcsTime::parse(&line.value, &line.params)?),
+            "DTEND" => self.end = Some(IcsTime::parse(&line.value, &line.params)?),
+            "DURATION" => self.duration = parse_duration(&line.value),
+            "RRULE" => self.rrule = Some(line.value.trim().to_string()),
+            "RDATE" => self.rdates.extend(parse_time_list(line)?),
+            "EXDATE" => self.exdates.extend(parse_time_list(line)?),
+            "RECURRENCE-ID" => {
+                self.recurrence_id = Some(IcsTime::parse(&line.value, &line.params)?)
+            }
+            "ORGANIZER" => self.organizer = Some(parse_attendee(line)),
+            "ATTENDEE" => self.attendees.push(parse_attendee(line)),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn build(self) -> Result<IcsEvent, Error> {
+        Ok(IcsEvent {
+            uid: self
+                .uid
+                .ok_or_else(|| Error::InvalidIcs("VEVENT without UID".to_string()))?,
+            start: self
+                .start
+                .ok_or_else(|| Error::InvalidIcs("VEVENT without DTSTART".to_string()))?,
+            summary: self.summary,
+            description: self.description,
+            location: self.location,
+            url: self.url,
+            status: self.status,
+            end: self.end,
+            duration: self.duration,
+            rrule: self.rrule,
+            rdates: self.rdates,
+            exdates: self.exdates,
+            recurrence_id: self.recurrence_id,
+            organizer: self.organizer,
+            attendees: self.attendees,
+        })
+    }
+}
+
+impl FromStr for IcsCalendar {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse(text)
+    }
+}
+
+pub fn parse(text: &str) -> Result<IcsCalendar, Error> {
+    let mut calendar = IcsCalendar::default();
+    let mut stack: Vec<String> = Vec::new();
+    let mut event: Option<EventBuilder> = None;
+    let mut seen_calendar = false;
+
+    for raw in unfold(text) {
+        let Some(line) = parse_line(&raw) else {
+            continue;
+        };
+
+        match line.name.as_str() {
+            "BEGIN" => {
+                let component = line.value.trim().to_ascii_uppercase();
+                match component.as_str() {
+                    "VCALENDAR" => seen_calendar = true,
+                    "VEVENT" => event = Some(EventBuilder::default()),
+                    _ => {}
+                }
+                stack.push(component);
+            }
+            "END" => {
+                if stack.pop().as_deref() == Some("VEVENT")
+                    && let Some(builder) = event.take()
+                {
+                    match builder.build() {
+                        Ok(e) => calendar.events.push(e),
+                        Err(e) => tracing::warn!("skipping_vevent: {}", e),
+                    }
+                }
+            }
+            _ => match stack.last().map(String::as_str) {
+                Some("VEVENT") => {
+                    if let Some(builder) = event.as_mut()
+                        && let Err(e) = builder.add(&line)
+                    {
+                        tracing::warn!("skipping_property: {}", e);
+                    }
+                }
+                Some("VCALENDAR") => match line.name.as_str() {
+                    "X-WR-CALNAME" => calendar.name = Some(unescape(&line.value)),
+                    "X-WR-TIMEZONE" => calendar.timezone = Some(line.value.trim().to_string()),
+                    _ => {}
+                },
+                _ => {}
+            },
+        }
+    }
+
+    if !seen_calendar {
+        return Err(Error::InvalidIcs("no VCALENDAR found".to_string()));
+    }
+
+    Ok(calendar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = indoc::indoc! {r#"
+            BEGIN:VCALENDAR
+            VERSION:2.0
+            X-WR-CALNAME:Team
+            BEGIN:VTIMEZONE
+            TZID:Europe/Berlin
+            BEGIN:STANDARD
+            DTSTART:19701025T030000
+            TZOFFSETFROM:+0200
+            TZOFFSETTO:+0100
+            END:STANDARD
+            END:VTIMEZONE
+            BEGIN:VEVENT
+            UID:weekly@example.com
+            SUMMARY:Weekly\, sync
+            DESCRIPTION:Agenda:\nitem one
+            DTSTART;TZID=Europe/Berlin:20250106T100000
+            DURATION:PT30M
+            RRULE:FREQ=WEEKLY;BYDAY=MO
+            EXDATE;TZID=Europe/Berlin:20250113T100000,20250120T100000
+            ORGANIZER;CN="Doe, Jane":mailto:jane@example.com
+            ATTENDEE;CN=Bob;PARTSTAT=DECLINED:MAILTO:bob@exa
+             mple.com
+            BEGIN:VALARM
+            DESCRIPTION:Reminder
+            END:VALARM
+            END:VEVENT
+            END:VCALENDAR
+        "#};
+
+        let calendar = parse(text).unwrap();
+        assert_eq!(calendar.name.as_deref(), Some("Team"));
+        assert_eq!(calendar.events.len(), 1);
+
+        let event = &calendar.events[0];
+        assert_eq!(event.summary.as_deref(), Some("Weekly, sync"));
+        assert_eq!(event.description.as_deref(), Some("Agenda:\nitem one"));
+        assert_eq!(event.duration, Some(Duration::minutes(30)));
+        assert_eq!(event.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=MO"));
+        assert_eq!(event.exdates.len(), 2);
+        assert_eq!(
+            event.start.to_utc(None).to_rfc3339(),
+            "2025-01-06T09:00:00+00:00"
+        );
+        assert_eq!(
+            event.organizer,
+            Some(IcsAttendee {
+                name: Some("Doe, Jane".to_string()),
+                email: Some("jane@example.com".to_string()),
+                declined: false,
+            })
+        );
+        assert_eq!(
+            event.attendees,
+            vec![IcsAttendee {
+                name: Some("Bob".to_string()),
+                email: Some("bob@example.com".to_string()),
+                declined: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1W"), Some(Duration::weeks(1)));
+        assert_eq!(parse_duration("-P1DT1S"), Some(-Duration::seconds(86401)));
+        assert_eq!(parse_duration("P1H"), None);
+    }
+
+    #[test]
+    fn test_resolve_tz() {
+        assert_eq!(resolve_tz("Asia/Seoul"), Some(chrono_tz::Asia::Seoul));
+        assert_eq!(
+            resolve_tz("/mozilla.org/20050126_1/America/New_York"),
+            Some(chrono_tz::America::New_York)
+        );
+        assert_eq!(
+            resolve_tz("Pacific Standard Time"),
+            Some(chrono_tz::America::Los_Angeles)
+        );
+        assert_eq!(resolve_tz("Mars/Olympus_Mons"), None);
+    }
+}
@@ -0,0 +1,14 @@
+mod client;
+mod error;
+mod expand;
+mod ics;
+mod rrule;
+mod sync;
+mod xml;
+
+pub use client::*;
+pub use error::Error;
+pub use expand::*;
+pub use ics::{IcsAttendee, IcsCalendar, IcsEvent, IcsTime, parse as parse_ics, resolve_tz};
+pub use rrule::{Frequency, RRule};
+pub use sync::*;
@@ -0,0 +1,534 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, Weekday};
+
+use crate::Error;
+use crate::ics::IcsTime;
+
+// Guards against rules that never produce an occurrence, e.g. `BYMONTHDAY=31;BYMONTH=2`.
+const MAX_PERIODS: usize = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<IcsTime>,
+    /// Weekdays with an optional ordinal, e.g. `-1FR` is `(Some(-1), Fri)`.
+    pub by_day: Vec<(Option<i32>, Weekday)>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub week_start: Weekday,
+}
+
+impl RRule {
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let unsupported = |reason: &str| Error::UnsupportedRRule(format!("{value}: {reason}"));
+
+        let mut freq = None;
+        let mut rule = RRule {
+            freq: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: vec![],
+            by_month_day: vec![],
+            by_month: vec![],
+            by_set_pos: vec![],
+            week_start: Weekday::Mon,
+        };
+
+        for part in value.trim().trim_start_matches("RRULE:").split(';') {
+            let Some((key, val)) = part.split_once('=') else {
+                continue;
+            };
+            let list = || val.split(',').map(str::trim).filter(|v| !v.is_empty());
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match val.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        other => return Err(unsupported(&format!("FREQ={other}"))),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = val
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| unsupported("INTERVAL"))?
+                }
+                "COUNT" => rule.count = Some(val.parse().map_err(|_| unsupported("COUNT"))?),
+                "UNTIL" => {
+                    let params = if val.len() == 8 {
+                        vec![("VALUE".to_string(), "DATE".to_string())]
+                    } else {
+                        vec![]
+                    };
+                    rule.until = Some(IcsTime::parse(val, &params)?);
+                }
+                "BYDAY" => {
+                    rule.by_day = list()
+                        .map(parse_by_day)
+                        .collect::<Option<_>>()
+                        .ok_or_else(|| unsupported("BYDAY"))?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = list()
+                        .map(|v| v.parse().ok().filter(|d: &i32| (1..=31).contains(&d.abs())))
+                        .collect::<Option<_>>()
+                        .ok_or_else(|| unsupported("BYMONTHDAY"))?
+                }
+                "BYMONTH" => {
+                    rule.by_month = list()
+                        .map(|v| v.parse().ok().filter(|m| (1..=12).contains(m)))
+                        .collect::<Option<_>>()
+                        .ok_or_else(|| unsupported("BYMONTH"))?
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos = list()
+                        .map(|v| v.parse().ok())
+                        .collect::<Option<_>>()
+                        .ok_or_else(|| unsupported("BYSETPOS"))?
+                }
+                "WKST" => {
+                    rule.week_start = parse_weekday(val).ok_or_else(|| unsupported("WKST"))?
+                }
+                // Sub-daily parts are rare for meetings; occurrences keep DTSTART's time.
+                "BYHOUR" | "BYMINUTE" | "BYSECOND" | "BYYEARDAY" | "BYWEEKNO" => {
+                    return Err(unsupported(key));
+                }
+                _ => {}
+            }
+        }
+
+        rule.freq = freq.ok_or_else(|| unsupported("missing FREQ"))?;
+        Ok(rule)
+    }
+
+    /// Wall-clock start times the rule produces from `dtstart` up to and including `limit`.
+    /// `dtstart` itself is only included when it matches the rule, and counts towards
+    /// `COUNT` only then. `until` must already be in the same wall clock as `dtstart`.
+    pub fn expand(
+        &self,
+        dtstart: NaiveDateTime,
+        until: Option<NaiveDateTime>,
+        limit: NaiveDateTime,
+    ) -> Vec<NaiveDateTime> {
+        let end = match until {
+            Some(until) => until.min(limit),
+            None => limit,
+        };
+
+        let mut out = vec![];
+        let mut emitted = 0u32;
+
+        for period in 0..MAX_PERIODS {
+            let Some(period_start) = self.period_start(dtstart.date(), period as u32) else {
+                break;
+            };
+            if period_start.and_time(dtstart.time()) > end && period > 0 {
+                break;
+            }
+
+            let mut dates = self.candidates(dtstart.date(), period_start);
+            dates.sort();
+            dates.dedup();
+            let dates = self.apply_set_pos(dates);
+
+            for date in dates {
+                let occurrence = date.and_time(dtstart.time());
+                if occurrence < dtstart {
+                    continue;
+                }
+                if occurrence > end || self.count.is_some_and(|c| emitted >= c) {
+                    return out;
+                }
+
+                emitted += 1;
+                out.push(occurrence);
+            }
+        }
+
+        out
+    }
+
+    fn period_start(&self, dtstart: NaiveDate, period: u32) -> Option<NaiveDate> {
+        let step = period.checked_mul(self.interval)?;
+
+        match self.freq {
+            Frequency::Daily => dtstart.checked_add_signed(Duration::days(step as i64)),
+            Frequency::Weekly => {
+                let offset = (7 + dtstart.weekday().num_days_from_monday()
+                    - self.week_start.num_days_from_monday())
+                    % 7;
+                let week = dtstart - Duration::days(offset as i64);
+                week.checked_add_signed(Duration::weeks(step as i64))
+            }
+            Frequency::Monthly => dtstart.with_day(1)?.checked_add_months(Months::new(step)),
+            Frequency::Yearly => NaiveDate::from_ymd_opt(dtstart.year() + step as i32, 1, 1),
+        }
+    }
+
+    fn candidates(&self, dtstart: NaiveDate, period_start: NaiveDate) -> Vec<NaiveDate> {
+        let months = |year: i32| -> Vec<(i32, u32)> {
+            if !self.by_month.is_empty() {
+                self.by_month.iter().map(|m| (year, *m)).collect()
+            } else if !self.by_month_day.is_empty() {
+                // BYMONTHDAY alone expands into every month of the year.
+                (1..=12).map(|m| (year, m)).collect()
+            } else {
+                vec![(year, dtstart.month())]
+            }
+        };
+
+        let dates = match self.freq {
+            Frequency::Daily => vec![period_start],
+            Frequency::Weekly => {
+                if self.by_day.is_empty() {
+                    vec![period_start + Duration::days(days_between(period_start, dtstart))]
+                } else {
+                    self.by_day
+                        .iter()
+                        .map(|(_, wd)| {
+                            let offset = (7 + wd.num_days_from_monday()
+                                - period_start.weekday().num_days_from_monday())
+                                % 7;
+                            period_start + Duration::days(offset as i64)
+                        })
+                        .collect()
+                }
+            }
+            Frequency::Monthly => {
+                self.month_candidates(period_start.year(), period_start.month(), dtstart)
+            }
+            Frequency::Yearly => {
+                let year = period_start.year();
+                // Ordinal weekdays without BYMONTH count through the whole year.
+                if self.by_month.is_empty()
+                    && self.by_month_day.is_empty()
+                    && !self.by_day.is_empty()
+                {
+                    weekdays_in_range(
+                        NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+                        NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
+                        &self.by_day,
+                    )
+                } else {
+                    months(year)
+                        .into_iter()
+                        .flat_map(|(y, m)| self.month_candidates(y, m, dtstart))
+                        .collect()
+                }
+            }
+        };
+
+        dates
+            .into_iter()
+            .filter(|d| self.by_month.is_empty() || self.by_month.contains(&d.month()))
+            .filter(|d| match self.freq {
+                // Expanding frequencies already applied these above.
+                Frequency::Monthly | Frequency::Yearly => true,
+                _ => {
+                    (self.by_month_day.is_empty() || month_day_matches(*d, &self.by_month_day))
+                        && (self.freq == Frequency::Weekly
+                            || self.by_day.is_empty()
+                            || self.by_day.iter().any(|(_, wd)| *wd == d.weekday()))
+                }
+            })
+            .collect()
+    }
+
+    fn month_candidates(&self, year: i32, month: u32, dtstart: NaiveDate) -> Vec<NaiveDate> {
+        let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return vec![];
+        };
+        let last = last_day_of_month(first);
+
+        match (self.by_month_day.is_empty(), self.by_day.is_empty()) {
+            (true, true) => NaiveDate::from_ymd_opt(year, month, dtstart.day())
+                .into_iter()
+                .collect(),
+            (false, true) => month_days(first, last, &self.by_month_day),
+            (true, false) => weekdays_in_range(first, last, &self.by_day),
+            (false, false) => weekdays_in_range(first, last, &self.by_day)
+                .into_iter()
+                .filter(|d| month_day_matches(*d, &self.by_month_day))
+                .collect(),
+        }
+    }
+
+    fn apply_set_pos(&self, dates: Vec<NaiveDate>) -> Vec<NaiveDate> {
+        if self.by_set_pos.is_empty() {
+            return dates;
+        }
+
+        let len = dates.len() as i32;
+        let mut picked: Vec<NaiveDate> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|pos| {
+                let index = if *pos > 0 { pos - 1 } else { len + pos };
+                (0..len).contains(&index).then(|| dates[index as usize])
+            })
+            .collect();
+        picked.sort();
+        picked.dedup();
+        picked
+    }
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    Some(match value.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_by_day(value: &str) -> Option<(Option<i32>, Weekday)> {
+    let split = value.len().checked_sub(2)?;
+    let (ordinal, weekday) = value.split_at(split);
+    let weekday = parse_weekday(weekday)?;
+
+    if ordinal.is_empty() {
+        return Some((None, weekday));
+    }
+    let ordinal: i32 = ordinal.trim_start_matches('+').parse().ok()?;
+    (ordinal != 0).then_some((Some(ordinal), weekday))
+}
+
+fn days_between(from: NaiveDate, to: NaiveDate) -> i64 {
+    (7 + to.weekday().num_days_from_monday() as i64 - from.weekday().num_days_from_monday() as i64)
+        % 7
+}
+
+fn last_day_of_month(first: NaiveDate) -> NaiveDate {
+    first
+        .checked_add_months(Months::new(1))
+        .and_then(|d| d.pred_opt())
+        .unwrap_or(first)
+}
+
+fn month_day_matches(date: NaiveDate, by_month_day: &[i32]) -> bool {
+    let last = last_day_of_month(date.with_day(1).unwrap()).day() as i32;
+    by_month_day.iter().any(|d| {
+        let day = if *d > 0 { *d } else { last + d + 1 };
+        day == date.day() as i32
+    })
+}
+
+fn month_days(first: NaiveDate, last: NaiveDate, by_month_day: &[i32]) -> Vec<NaiveDate> {
+    by_month_day
+        .iter()
+        .filter_map(|d| {
+            let day = if *d > 0 {
+                *d
+            } else {
+                last.day() as i32 + d + 1
+            };
+            (day >= 1).then(|| first.with_day(day as u32)).flatten()
+        })
+        .collect()
+}
+
+fn weekdays_in_range(
+    first: NaiveDate,
+    last: NaiveDate,
+    by_day: &[(Option<i32>, Weekday)],
+) -> Vec<NaiveDate> {
+    by_day
+        .iter()
+        .flat_map(|(ordinal, weekday)| {
+            let matching: Vec<NaiveDate> = first
+                .iter_days()
+                .take_while(|d| *d <= last)
+                .filter(|d| d.weekday() == *weekday)
+                .collect();
+
+            match ordinal {
+                None => matching,
+                Some(n) => {
+                    let len = matching.len() as i32;
+                    let index = if *n > 0 { n - 1 } else { len + n };
+                    if (0..len).contains(&index) {
+                        vec![matching[index as usize]]
+                    } else {
+                        vec![]
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn expand(rule: &str, dtstart: &str, limit: &str) -> Vec<String> {
+        let rule = RRule::parse(rule).unwrap();
+        let until = rule.until.as_ref().map(|u| u.local());
+        rule.expand(dt(dtstart), until, dt(limit))
+            .into_iter()
+            .map(|d| d.format("%Y-%m-%d %a").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_weekly() {
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH;COUNT=5",
+                "2025-01-07 10:00",
+                "2026-01-01 00:00"
+            ),
+            vec![
+                "2025-01-07 Tue",
+                "2025-01-09 Thu",
+                "2025-01-21 Tue",
+                "2025-01-23 Thu",
+                "2025-02-04 Tue",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekly_until() {
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;UNTIL=20250120T090000Z",
+                "2025-01-06 09:00",
+                "2026-01-01 00:00"
+            ),
+            vec!["2025-01-06 Mon", "2025-01-13 Mon", "2025-01-20 Mon"]
+        );
+    }
+
+    #[test]
+    fn test_daily_weekdays() {
+        assert_eq!(
+            expand(
+                "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR",
+                "2025-01-03 09:00",
+                "2025-01-08 00:00"
+            ),
+            vec!["2025-01-03 Fri", "2025-01-06 Mon", "2025-01-07 Tue"]
+        );
+    }
+
+    #[test]
+    fn test_monthly() {
+        assert_eq!(
+            expand(
+                "FREQ=MONTHLY;BYDAY=-1FR",
+                "2025-01-31 15:00",
+                "2025-04-30 00:00"
+            ),
+            vec![
+                "2025-01-31 Fri",
+                "2025-02-28 Fri",
+                "2025-03-28 Fri",
+                "2025-04-25 Fri"
+            ]
+        );
+        assert_eq!(
+            expand(
+                "FREQ=MONTHLY;COUNT=3",
+                "2025-01-31 15:00",
+                "2026-01-01 00:00"
+            ),
+            vec!["2025-01-31 Fri", "2025-03-31 Mon", "2025-05-31 Sat"]
+        );
+        assert_eq!(
+            expand(
+                "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=2",
+                "2025-05-30 15:00",
+                "2026-01-01 00:00"
+            ),
+            vec!["2025-05-30 Fri", "2025-06-30 Mon"]
+        );
+    }
+
+    #[test]
+    fn test_yearly() {
+        assert_eq!(
+            expand(
+                "FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;COUNT=2",
+                "2025-11-27 12:00",
+                "2030-01-01 00:00"
+            ),
+            vec!["2025-11-27 Thu", "2026-11-26 Thu"]
+        );
+    }
+
+    #[test]
+    fn test_yearly_by_month_day() {
+        assert_eq!(
+            expand(
+                "FREQ=YEARLY;BYMONTHDAY=1,-1;COUNT=5",
+                "2025-01-01 09:00",
+                "2026-01-01 00:00"
+            ),
+            vec![
+                "2025-01-01 Wed",
+                "2025-01-31 Fri",
+                "2025-02-01 Sat",
+                "2025-02-28 Fri",
+                "2025-03-01 Sat",
+            ]
+        );
+        assert_eq!(
+            expand(
+                "FREQ=YEARLY;BYMONTHDAY=13;BYDAY=FR",
+                "2025-06-13 09:00",
+                "2026-12-31 00:00"
+            ),
+            vec![
+                "2025-06-13 Fri",
+                "2026-02-13 Fri",
+                "2026-03-13 Fri",
+                "2026-11-13 Fri"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dtstart_not_matching_rule() {
+        // A DTSTART the rule does not produce is skipped rather than emitted first.
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;BYDAY=MO;COUNT=2",
+                "2025-01-08 10:00",
+                "2026-01-01 00:00"
+            ),
+            vec!["2025-01-13 Mon", "2025-01-20 Mon"]
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert!(RRule::parse("FREQ=HOURLY").is_err());
+        assert!(RRule::parse("INTERVAL=2").is_err());
+        assert!(RRule::parse("FREQ=DAILY;BYHOUR=9,17").is_err());
+    }
+}
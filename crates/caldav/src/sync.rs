@@ -0,0 +1,436 @@
+use chrono::{DateTime, Utc};
+use hypr_db_user::{Calendar, Event, EventParticipant, Platform, UserDatabase};
+use serde::{Deserialize, Serialize};
+
+use crate::{CalDavCalendar, CalDavClient, Error, Occurrence, expand, fetch_ics, ics};
+
+/// Occurrences of recurring events are only stored inside this range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SyncWindow {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+/// What to keep between two syncs of one CalDAV calendar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncState {
+    pub sync_token: String,
+    pub window: SyncWindow,
+}
+
+/// Stores `calendar`, and its events if the user selected it. Returns the state
+/// to pass next time, which is `None` while the calendar is not selected.
+pub async fn sync_caldav_calendar(
+    db: &UserDatabase,
+    user_id: impl Into<String>,
+    client: &CalDavClient,
+    calendar: &CalDavCalendar,
+    state: Option<&SyncState>,
+    window: SyncWindow,
+) -> Result<Option<SyncState>, Error> {
+    let user_id = user_id.into();
+
+    // Recurring events were expanded inside the previous window only, and an
+    // incremental sync would not return the unchanged ones again.
+    let sync_token = state
+        .filter(|state| state.window == window)
+        .map(|state| state.sync_token.as_str());
+
+    let stored = db
+        .upsert_calendar(Calendar {
+            id: uuid::Uuid::new_v4().to_string(),
+            tracking_id: calendar.url.clone(),
+            user_id: user_id.clone(),
+            platform: Platform::CalDav,
+            name: calendar.name.clone(),
+            selected: false,
+            source: host(&calendar.url),
+        })
+        .await?;
+
+    if !stored.selected {
+        return Ok(None);
+    }
+
+    let delta = client.sync(&calendar.url, sync_token).await?;
+    let mut kept = vec![];
+
+    for object in &delta.changed {
+        match ics::parse(&object.data) {
+            Ok(parsed) => {
+                kept.extend(
+                    store_resource(db, &stored, &user_id, &object.url, &parsed, window).await?,
+                );
+            }
+            Err(e) => tracing::warn!("skipping_calendar_object: {} {}", object.url, e),
+        }
+    }
+
+    for url in &delta.deleted {
+        db.prune_events(&stored.id, format!("{}#", url), &[])
+            .await?;
+    }
+
+    if delta.full {
+        db.prune_events(&stored.id, "", &kept).await?;
+    }
+
+    Ok(delta
+        .sync_token
+        .map(|sync_token| SyncState { sync_token, window }))
+}
+
+/// Fetches a read-only `.ics` feed and replaces the events stored for it.
+/// New subscriptions are selected, since adding one is an explicit choice.
+pub async fn sync_ics_subscription(
+    db: &UserDatabase,
+    user_id: impl Into<String>,
+    http: &reqwest::Client,
+    url: impl AsRef<str>,
+    window: SyncWindow,
+) -> Result<Calendar, Error> {
+    let user_id = user_id.into();
+    let url = url.as_ref();
+
+    let parsed = ics::parse(&fetch_ics(http, url).await?)?;
+
+    let stored = db
+        .upsert_calendar(Calendar {
+            id: uuid::Uuid::new_v4().to_string(),
+            tracking_id: url.to_string(),
+            user_id: user_id.clone(),
+            platform: Platform::Ics,
+            name: parsed
+                .name
+                .clone()
+                .or_else(|| host(url))
+                .unwrap_or_else(|| url.to_string()),
+            selected: true,
+            source: host(url),
+        })
+        .await?;
+
+    if !stored.selected {
+        return Ok(stored);
+    }
+
+    let kept = store_resource(db, &stored, &user_id, url, &parsed, window).await?;
+    db.prune_events(&stored.id, "", &kept).await?;
+
+    Ok(stored)
+}
+
+// Every event of one calendar resource shares the `{resource}#` tracking id prefix,
+// so a changed or deleted resource can be pruned on its own.
+async fn store_resource(
+    db: &UserDatabase,
+    calendar: &Calendar,
+    user_id: &str,
+    resource: &str,
+    parsed: &ics::IcsCalendar,
+    window: SyncWindow,
+) -> Result<Vec<String>, Error> {
+    let prefix = format!("{}#", resource);
+    let mut kept = vec![];
+
+    for occurrence in expand(parsed, window.from, window.to) {
+        let event = to_event(calendar, user_id, &prefix, occurrence)?;
+        kept.push(db.upsert_event(event).await?.tracking_id);
+    }
+
+    db.prune_events(&calendar.id, &prefix, &kept).await?;
+    Ok(kept)
+}
+
+fn to_event(
+    calendar: &Calendar,
+    user_id: &str,
+    prefix: &str,
+    occurrence: Occurrence,
+) -> Result<Event, Error> {
+    // Keyed by the original start, so moving a single instance keeps its event id.
+    let tracking_id = match occurrence.recurrence_id {
+        Some(recurrence_id) => format!(
+            "{}{}#{}",
+            prefix,
+            occurrence.uid,
+            recurrence_id.to_rfc3339()
+        ),
+        None => format!("{}{}", prefix, occurrence.uid),
+    };
+
+    let participants = occurrence
+        .participants
+        .iter()
+        .map(|p| EventParticipant {
+            name: p.name.clone(),
+            email: p.email.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Event {
+        id: uuid::Uuid::new_v4().to_string(),
+        user_id: user_id.to_string(),
+        tracking_id,
+        calendar_id: Some(calendar.id.clone()),
+        name: occurrence.summary.clone().unwrap_or_default(),
+        note: occurrence.description.clone().unwrap_or_default(),
+        start_date: occurrence.start,
+        end_date: occurrence.end,
+        google_event_url: occurrence.url.clone(),
+        participants: (!participants.is_empty())
+            .then(|| serde_json::to_string(&participants))
+            .transpose()?,
+        is_recurring: occurrence.is_recurring(),
+        series_id: occurrence.is_recurring().then(|| occurrence.uid.clone()),
+        recurrence_id: occurrence.recurrence_id,
+    })
+}
+
+fn host(url: &str) -> Option<String> {
+    let url = url.replacen("webcal://", "https://", 1);
+    url::Url::parse(&url).ok()?.host_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_db_user::{Human, ListEventFilter, ListEventFilterCommon, ListEventFilterSpecific};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup_db() -> (UserDatabase, String) {
+        let base_db = hypr_db_core::DatabaseBuilder::default()
+            .memory()
+            .build()
+            .await
+            .unwrap();
+        let db = UserDatabase::from(base_db);
+        hypr_db_user::migrate(&db).await.unwrap();
+
+        let human = db.upsert_human(Human::default()).await.unwrap();
+        (db, human.id)
+    }
+
+    async fn list_events(db: &UserDatabase, user_id: &str) -> Vec<Event> {
+        db.list_events(Some(ListEventFilter {
+            common: ListEventFilterCommon {
+                user_id: user_id.to_string(),
+                limit: None,
+            },
+            specific: ListEventFilterSpecific::DateRange {
+                start: "2025-01-01T00:00:00Z".parse().unwrap(),
+                end: "2026-01-01T00:00:00Z".parse().unwrap(),
+            },
+        }))
+        .await
+        .unwrap()
+    }
+
+    fn window() -> SyncWindow {
+        SyncWindow {
+            from: "2025-03-01T00:00:00Z".parse().unwrap(),
+            to: "2025-03-31T00:00:00Z".parse().unwrap(),
+        }
+    }
+
+    const FEED: &str = "BEGIN:VCALENDAR\r
+X-WR-CALNAME:Team\r
+BEGIN:VEVENT\r
+UID:review\r
+SUMMARY:Design review\r
+DTSTART:20250303T150000Z\r
+DTEND:20250303T160000Z\r
+RRULE:FREQ=WEEKLY;COUNT=3\r
+ATTENDEE;CN=Bob:mailto:bob@example.com\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[tokio::test]
+    async fn test_sync_ics_subscription() {
+        let (db, user_id) = setup_db().await;
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/team.ics"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(FEED))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let url = format!("{}/team.ics", server.uri());
+        let http = reqwest::Client::new();
+
+        let calendar = sync_ics_subscription(&db, &user_id, &http, &url, window())
+            .await
+            .unwrap();
+        assert_eq!(calendar.name, "Team");
+        assert_eq!(calendar.platform, Platform::Ics);
+
+        let events = list_events(&db, &user_id).await;
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| e.is_recurring));
+        assert!(
+            events
+                .iter()
+                .all(|e| e.series_id.as_deref() == Some("review"))
+        );
+        assert_eq!(
+            events[1].recurrence_id,
+            Some("2025-03-10T15:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            events[0].participants.as_deref(),
+            Some(r#"[{"name":"Bob","email":"bob@example.com"}]"#)
+        );
+
+        // The series was shortened upstream; the removed instance must disappear.
+        Mock::given(method("GET"))
+            .and(path("/team.ics"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(FEED.replace("COUNT=3", "COUNT=2")),
+            )
+            .mount(&server)
+            .await;
+
+        sync_ics_subscription(&db, &user_id, &http, &url, window())
+            .await
+            .unwrap();
+
+        let after = list_events(&db, &user_id).await;
+        assert_eq!(after.len(), 2);
+        assert_eq!(after[0].id, events[0].id);
+    }
+
+    #[tokio::test]
+    async fn test_sync_caldav_calendar() {
+        let (db, user_id) = setup_db().await;
+        let server = MockServer::start().await;
+        let client = CalDavClient::new(server.uri(), None).unwrap();
+
+        let calendar = CalDavCalendar {
+            url: format!("{}/cal/work/", server.uri()),
+            name: "Work".to_string(),
+            color: None,
+            ctag: None,
+            sync_token: None,
+        };
+
+        // Not selected yet, so nothing is fetched.
+        let state = sync_caldav_calendar(&db, &user_id, &client, &calendar, None, window())
+            .await
+            .unwrap();
+        assert_eq!(state, None);
+        db.toggle_calendar_selected(&calendar.url).await.unwrap();
+
+        Mock::given(method("PROPFIND"))
+            .and(path("/cal/work/"))
+            .respond_with(ResponseTemplate::new(207).set_body_string(
+                r#"<d:multistatus xmlns:d="DAV:"><d:response><d:href>/cal/work/</d:href><d:propstat><d:prop><d:sync-token>t1</d:sync-token></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response></d:multistatus>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("REPORT"))
+            .and(path("/cal/work/"))
+            .and(wiremock::matchers::body_string_contains("calendar-query"))
+            .respond_with(ResponseTemplate::new(207).set_body_string(format!(
+                r#"<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav"><d:response><d:href>/cal/work/review.ics</d:href><d:propstat><d:prop><d:getetag>"1"</d:getetag><c:calendar-data>{}</c:calendar-data></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response></d:multistatus>"#,
+                FEED
+            )))
+            .mount(&server)
+            .await;
+
+        let state = sync_caldav_calendar(&db, &user_id, &client, &calendar, None, window())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(state.sync_token, "t1");
+        assert_eq!(list_events(&db, &user_id).await.len(), 3);
+
+        Mock::given(method("REPORT"))
+            .and(path("/cal/work/"))
+            .and(wiremock::matchers::body_string_contains("sync-collection"))
+            .respond_with(ResponseTemplate::new(207).set_body_string(
+                r#"<d:multistatus xmlns:d="DAV:"><d:response><d:href>/cal/work/review.ics</d:href><d:status>HTTP/1.1 404 Not Found</d:status></d:response><d:sync-token>t2</d:sync-token></d:multistatus>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let state = sync_caldav_calendar(&db, &user_id, &client, &calendar, Some(&state), window())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(state.sync_token, "t2");
+        assert_eq!(list_events(&db, &user_id).await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sync_caldav_calendar_window_shift() {
+        let (db, user_id) = setup_db().await;
+        let server = MockServer::start().await;
+        let client = CalDavClient::new(server.uri(), None).unwrap();
+
+        let calendar = CalDavCalendar {
+            url: format!("{}/cal/work/", server.uri()),
+            name: "Work".to_string(),
+            color: None,
+            ctag: None,
+            sync_token: None,
+        };
+        sync_caldav_calendar(&db, &user_id, &client, &calendar, None, window())
+            .await
+            .unwrap();
+        db.toggle_calendar_selected(&calendar.url).await.unwrap();
+
+        Mock::given(method("PROPFIND"))
+            .and(path("/cal/work/"))
+            .respond_with(ResponseTemplate::new(207).set_body_string(
+                r#"<d:multistatus xmlns:d="DAV:"><d:response><d:href>/cal/work/</d:href><d:propstat><d:prop><d:sync-token>t1</d:sync-token></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response></d:multistatus>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("REPORT"))
+            .and(path("/cal/work/"))
+            .and(wiremock::matchers::body_string_contains("calendar-query"))
+            .respond_with(ResponseTemplate::new(207).set_body_string(format!(
+                r#"<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav"><d:response><d:href>/cal/work/review.ics</d:href><d:propstat><d:prop><d:getetag>"1"</d:getetag><c:calendar-data>{}</c:calendar-data></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response></d:multistatus>"#,
+                FEED.replace(";COUNT=3", "")
+            )))
+            .mount(&server)
+            .await;
+
+        // Nothing changed upstream since `t1`.
+        Mock::given(method("REPORT"))
+            .and(path("/cal/work/"))
+            .and(wiremock::matchers::body_string_contains("sync-collection"))
+            .respond_with(ResponseTemplate::new(207).set_body_string(
+                r#"<d:multistatus xmlns:d="DAV:"><d:sync-token>t1</d:sync-token></d:multistatus>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let state = sync_caldav_calendar(&db, &user_id, &client, &calendar, None, window())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(list_events(&db, &user_id).await.len(), 4);
+
+        let april = SyncWindow {
+            from: "2025-04-01T00:00:00Z".parse().unwrap(),
+            to: "2025-04-30T00:00:00Z".parse().unwrap(),
+        };
+        let state = sync_caldav_calendar(&db, &user_id, &client, &calendar, Some(&state), april)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(state.window, april);
+
+        // The weekly series is re-expanded into the new window, not left in the old one.
+        let events = list_events(&db, &user_id).await;
+        assert_eq!(events.len(), 4);
+        assert!(events.iter().all(|e| e.start_date >= april.from));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::Error;
+
+const DAV: &str = "DAV:";
+const CALDAV: &str = "urn:ietf:params:xml:ns:caldav";
+const CALENDARSERVER: &str = "http://calendarserver.org/ns/";
+const APPLE_ICAL: &str = "http://apple.com/ns/ical/";
+
+pub(crate) const PROPFIND_PRINCIPAL: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:">
+  <d:prop><d:current-user-principal/></d:prop>
+</d:propfind>"#;
+
+pub(crate) const PROPFIND_HOME_SET: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop><c:calendar-home-set/></d:prop>
+</d:propfind>"#;
+
+pub(crate) const PROPFIND_CALENDARS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav" xmlns:cs="http://calendarserver.org/ns/" xmlns:ic="http://apple.com/ns/ical/">
+  <d:prop>
+    <d:resourcetype/>
+    <d:displayname/>
+    <d:sync-token/>
+    <cs:getctag/>
+    <c:supported-calendar-component-set/>
+    <ic:calendar-color/>
+  </d:prop>
+</d:propfind>"#;
+
+pub(crate) const PROPFIND_SYNC_TOKEN: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:">
+  <d:prop><d:sync-token/></d:prop>
+</d:propfind>"#;
+
+pub(crate) const REPORT_CALENDAR_QUERY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop><d:getetag/><c:calendar-data/></d:prop>
+  <c:filter><c:comp-filter name="VCALENDAR"><c:comp-filter name="VEVENT"/></c:comp-filter></c:filter>
+</c:calendar-query>"#;
+
+pub(crate) fn sync_collection(sync_token: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<d:sync-collection xmlns:d="DAV:">
+  <d:sync-token>{}</d:sync-token>
+  <d:sync-level>1</d:sync-level>
+  <d:prop><d:getetag/></d:prop>
+</d:sync-collection>"#,
+        escape(sync_token)
+    )
+}
+
+pub(crate) fn calendar_multiget(hrefs: &[String]) -> String {
+    let hrefs = hrefs
+        .iter()
+        .map(|href| format!("  <d:href>{}</d:href>", escape(href)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-multiget xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop><d:getetag/><c:calendar-data/></d:prop>
+{}
+</c:calendar-multiget>"#,
+        hrefs
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Multistatus {
+    pub responses: Vec<DavResponse>,
+    /// Top-level token returned by `sync-collection`.
+    pub sync_token: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct DavResponse {
+    pub href: String,
+    /// Response-level status, which `sync-collection` uses to report deletions.
+    pub status: Option<u16>,
+    /// Properties from successful `propstat`s only.
+    pub props: DavProps,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct DavProps {
+    pub display_name: Option<String>,
+    pub is_calendar: bool,
+    pub components: Vec<String>,
+    pub current_user_principal: Option<String>,
+    pub calendar_home_set: Option<String>,
+    pub etag: Option<String>,
+    pub ctag: Option<String>,
+    pub sync_token: Option<String>,
+    pub calendar_data: Option<String>,
+    pub color: Option<String>,
+}
+
+fn is(node: &roxmltree::Node, ns: &str, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name && node.tag_name().namespace() == Some(ns)
+}
+
+fn child<'a, 'i>(
+    node: &roxmltree::Node<'a, 'i>,
+    ns: &str,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'i>> {
+    node.children().find(|c| is(c, ns, name))
+}
+
+fn text(node: &roxmltree::Node) -> Option<String> {
+    let text: String = node
+        .descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn href(node: &roxmltree::Node) -> Option<String> {
+    child(node, DAV, "href").and_then(|h| text(&h))
+}
+
+fn status(node: &roxmltree::Node) -> Option<u16> {
+    child(node, DAV, "status")
+        .and_then(|s| text(&s))
+        .and_then(|s| s.split_whitespace().nth(1)?.parse().ok())
+}
+
+pub(crate) fn parse_multistatus(xml: &str) -> Result<Multistatus, Error> {
+    let doc = roxmltree::Document::parse(xml)?;
+    let root = doc.root_element();
+
+    let mut multistatus = Multistatus {
+        sync_token: child(&root, DAV, "sync-token").and_then(|t| text(&t)),
+        ..Default::default()
+    };
+
+    for response in root.children().filter(|n| is(n, DAV, "response")) {
+        let Some(href) = href(&response) else {
+            continue;
+        };
+
+        let mut props = DavProps::default();
+        for propstat in response.children().filter(|n| is(n, DAV, "propstat")) {
+            if !status(&propstat).is_some_and(|s| (200..300).contains(&s)) {
+                continue;
+            }
+            if let Some(prop) = child(&propstat, DAV, "prop") {
+                read_props(&prop, &mut props);
+            }
+        }
+
+        multistatus.responses.push(DavResponse {
+            href,
+            status: status(&response),
+            props,
+        });
+    }
+
+    Ok(multistatus)
+}
+
+fn read_props(prop: &roxmltree::Node, props: &mut DavProps) {
+    for node in prop.children().filter(|n| n.is_element()) {
+        let ns = node.tag_name().namespace().unwrap_or_default();
+
+        match (ns, node.tag_name().name()) {
+            (DAV, "displayname") => props.display_name = text(&node),
+            (DAV, "resourcetype") => props.is_calendar = child(&node, CALDAV, "calendar").is_some(),
+            (DAV, "current-user-principal") => props.current_user_principal = href(&node),
+            (DAV, "getetag") => props.etag = text(&node),
+            (DAV, "sync-token") => props.sync_token = text(&node),
+            (CALDAV, "calendar-home-set") => props.calendar_home_set = href(&node),
+            (CALDAV, "calendar-data") => props.calendar_data = text(&node),
+            (CALDAV, "supported-calendar-component-set") => {
+                props.components = node
+                    .children()
+                    .filter(|c| is(c, CALDAV, "comp"))
+                    .filter_map(|c| c.attribute("name").map(str::to_ascii_uppercase))
+                    .collect()
+            }
+            (CALENDARSERVER, "getctag") => props.ctag = text(&node),
+            (APPLE_ICAL, "calendar-color") => props.color = text(&node),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multistatus() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav" xmlns:cs="http://calendarserver.org/ns/">
+  <d:response>
+    <d:href>/calendars/jane/work/</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:resourcetype><d:collection/><cal:calendar/></d:resourcetype>
+        <d:displayname>Work</d:displayname>
+        <cs:getctag>"42"</cs:getctag>
+        <cal:supported-calendar-component-set><cal:comp name="VEVENT"/><cal:comp name="VTODO"/></cal:supported-calendar-component-set>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+    <d:propstat>
+      <d:prop><d:sync-token/></d:prop>
+      <d:status>HTTP/1.1 404 Not Found</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/calendars/jane/work/gone.ics</d:href>
+    <d:status>HTTP/1.1 404 Not Found</d:status>
+  </d:response>
+  <d:sync-token>http://example.com/sync/2</d:sync-token>
+</d:multistatus>"#;
+
+        let multistatus = parse_multistatus(xml).unwrap();
+        assert_eq!(
+            multistatus.sync_token.as_deref(),
+            Some("http://example.com/sync/2")
+        );
+        assert_eq!(multistatus.responses.len(), 2);
+
+        let work = &multistatus.responses[0];
+        assert!(work.props.is_calendar);
+        assert_eq!(work.props.display_name.as_deref(), Some("Work"));
+        assert_eq!(work.props.ctag.as_deref(), Some("\"42\""));
+        assert_eq!(work.props.components, vec!["VEVENT", "VTODO"]);
+        assert_eq!(work.props.sync_token, None);
+
+        let gone = &multistatus.responses[1];
+        assert_eq!(gone.status, Some(404));
+    }
+}
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS action_items (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL,
+  text TEXT NOT NULL,
+  owner_id TEXT DEFAULT NULL,
+  owner_name TEXT DEFAULT NULL,
+  due TEXT DEFAULT NULL,
+  source_timestamp TEXT DEFAULT NULL,
+  done BOOLEAN NOT NULL DEFAULT FALSE,
+  created_at TEXT NOT NULL
+);
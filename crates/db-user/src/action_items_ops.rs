@@ -0,0 +1,329 @@
+use hypr_db_core::SqlTable;
+
+use super::{ActionItem, Human, ListActionItemFilter, UserDatabase};
+
+impl UserDatabase {
+    /// Replaces the extracted action items of a session.
+    ///
+    /// Sessions live outside this database, so callers link owners with [`match_owner`].
+    /// Items that were already marked as done stay done if they are extracted again.
+    pub async fn replace_session_action_items(
+        &self,
+        session_id: impl Into<String>,
+        items: Vec<ActionItem>,
+    ) -> Result<Vec<ActionItem>, crate::Error> {
+        let session_id = session_id.into();
+
+        let done_texts = self
+            .list_session_action_items(&session_id)
+            .await?
+            .into_iter()
+            .filter(|item| item.done)
+            .map(|item| item.text)
+            .collect::<Vec<_>>();
+
+        let conn = self.conn()?;
+
+        let sql = format!(
+            "DELETE FROM {} WHERE session_id = ?",
+            ActionItem::sql_table()
+        );
+        conn.execute(&sql, vec![session_id.clone()]).await?;
+
+        let sql = format!(
+            "INSERT INTO {} (
+                id,
+                session_id,
+                text,
+                owner_id,
+                owner_name,
+                due,
+                source_timestamp,
+                done,
+                created_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            ActionItem::sql_table()
+        );
+
+        for item in items {
+            let done = item.done || done_texts.contains(&item.text);
+
+            conn.execute(
+                &sql,
+                libsql::params![
+                    item.id,
+                    session_id.clone(),
+                    item.text,
+                    item.owner_id,
+                    item.owner_name,
+                    item.due,
+                    item.source_timestamp,
+                    done,
+                    item.created_at.to_rfc3339(),
+                ],
+            )
+            .await?;
+        }
+
+        self.list_session_action_items(session_id).await
+    }
+
+    pub async fn list_session_action_items(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<ActionItem>, crate::Error> {
+        let conn = self.conn()?;
+
+        let sql = format!(
+            "SELECT * FROM {} WHERE session_id = ? ORDER BY created_at ASC, rowid ASC",
+            ActionItem::sql_table()
+        );
+        let mut rows = conn.query(&sql, vec![session_id.into()]).await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: ActionItem = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    /// Action items that are not done yet, newest first, optionally narrowed to an owner or a session.
+    pub async fn list_open_action_items(
+        &self,
+        filter: Option<ListActionItemFilter>,
+    ) -> Result<Vec<ActionItem>, crate::Error> {
+        let conn = self.conn()?;
+        let ListActionItemFilter {
+            owner_id,
+            session_id,
+            limit,
+        } = filter.unwrap_or(ListActionItemFilter {
+            owner_id: None,
+            session_id: None,
+            limit: None,
+        });
+
+        let mut conditions = vec!["done = FALSE"];
+        let mut params = Vec::new();
+        if let Some(owner_id) = owner_id {
+            conditions.push("owner_id = ?");
+            params.push(owner_id);
+        }
+        if let Some(session_id) = session_id {
+            conditions.push("session_id = ?");
+            params.push(session_id);
+        }
+        params.push(limit.unwrap_or(100).to_string());
+
+        let sql = format!(
+            "SELECT * FROM {}
+            WHERE {}
+            ORDER BY created_at DESC, rowid ASC
+            LIMIT ?",
+            ActionItem::sql_table(),
+            conditions.join(" AND ")
+        );
+        let mut rows = conn.query(&sql, params).await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: ActionItem = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn set_action_item_done(
+        &self,
+        id: impl Into<String>,
+        done: bool,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        let sql = format!(
+            "UPDATE {} SET done = ? WHERE id = ?",
+            ActionItem::sql_table()
+        );
+        conn.execute(&sql, libsql::params![done, id.into()]).await?;
+        Ok(())
+    }
+
+    pub async fn set_action_item_owner(
+        &self,
+        id: impl Into<String>,
+        owner_id: Option<String>,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        let sql = format!(
+            "UPDATE {} SET owner_id = ? WHERE id = ?",
+            ActionItem::sql_table()
+        );
+        conn.execute(&sql, libsql::params![owner_id, id.into()])
+            .await?;
+        Ok(())
+    }
+}
+
+/// Links an owner name from a transcript to one of `participants`, by full name or email.
+/// Models usually refer to people by first name, so a unique first-name match is accepted too.
+pub fn match_owner(participants: &[Human], name: &str) -> Option<String> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+
+    let exact = participants.iter().find(|h| {
+        h.full_name.as_deref().map(str::to_lowercase).as_deref() == Some(name.as_str())
+            || h.email.as_deref().map(str::to_lowercase).as_deref() == Some(name.as_str())
+    });
+    if let Some(human) = exact {
+        return Some(human.id.clone());
+    }
+
+    let mut by_first_name = participants.iter().filter(|h| {
+        h.full_name
+            .as_deref()
+            .and_then(|n| n.split_whitespace().next())
+            .map(str::to_lowercase)
+            .as_deref()
+            == Some(name.as_str())
+    });
+
+    match (by_first_name.next(), by_first_name.next()) {
+        (Some(human), None) => Some(human.id.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::match_owner;
+    use crate::{ActionItem, Human, ListActionItemFilter, tests::setup_extraction_db};
+
+    fn action_item(session_id: &str, text: &str, owner_id: Option<&str>) -> ActionItem {
+        ActionItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session_id.to_string(),
+            text: text.to_string(),
+            owner_id: owner_id.map(String::from),
+            owner_name: None,
+            due: None,
+            source_timestamp: None,
+            done: false,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_match_owner() {
+        let humans = vec![
+            Human {
+                full_name: Some("Alice Kim".to_string()),
+                email: Some("alice@example.com".to_string()),
+                ..Human::default()
+            },
+            Human {
+                full_name: Some("Bob Lee".to_string()),
+                ..Human::default()
+            },
+            Human {
+                full_name: Some("Bob Park".to_string()),
+                ..Human::default()
+            },
+        ];
+
+        assert_eq!(
+            match_owner(&humans, "alice kim"),
+            Some(humans[0].id.clone())
+        );
+        assert_eq!(
+            match_owner(&humans, "alice@example.com"),
+            Some(humans[0].id.clone())
+        );
+        assert_eq!(match_owner(&humans, "Alice"), Some(humans[0].id.clone()));
+        assert_eq!(match_owner(&humans, "Bob"), None);
+        assert_eq!(match_owner(&humans, "Carol"), None);
+        assert_eq!(match_owner(&humans, " "), None);
+    }
+
+    #[tokio::test]
+    async fn test_action_items() {
+        let db = setup_extraction_db().await;
+        let session_id = uuid::Uuid::new_v4().to_string();
+
+        let items = db
+            .replace_session_action_items(
+                &session_id,
+                vec![
+                    action_item(&session_id, "Update the docs", Some("john")),
+                    action_item(&session_id, "Book a room", None),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].owner_id, Some("john".to_string()));
+        assert_eq!(items[1].owner_id, None);
+
+        db.set_action_item_done(&items[0].id, true).await.unwrap();
+
+        let open = db.list_open_action_items(None).await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].text, "Book a room");
+
+        // Extracting again keeps the done state of items that are still there.
+        let items = db
+            .replace_session_action_items(
+                &session_id,
+                vec![
+                    action_item(&session_id, "Update the docs", Some("john")),
+                    action_item(&session_id, "Send the invoice", Some("john")),
+                ],
+            )
+            .await
+            .unwrap();
+        assert!(items[0].done);
+        assert!(!items[1].done);
+
+        let open = db
+            .list_open_action_items(Some(ListActionItemFilter {
+                owner_id: Some("john".to_string()),
+                session_id: None,
+                limit: None,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].text, "Send the invoice");
+
+        let open = db
+            .list_open_action_items(Some(ListActionItemFilter {
+                owner_id: None,
+                session_id: Some(session_id.clone()),
+                limit: None,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            open.iter().map(|i| i.text.as_str()).collect::<Vec<_>>(),
+            vec!["Send the invoice"]
+        );
+
+        let open = db
+            .list_open_action_items(Some(ListActionItemFilter {
+                owner_id: None,
+                session_id: Some("other".to_string()),
+                limit: None,
+            }))
+            .await
+            .unwrap();
+        assert!(open.is_empty());
+
+        db.replace_session_action_items(&session_id, vec![])
+            .await
+            .unwrap();
+        assert!(db.list_open_action_items(None).await.unwrap().is_empty());
+    }
+}
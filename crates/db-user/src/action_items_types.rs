@@ -0,0 +1,25 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[sql_table("action_items")]
+    pub struct ActionItem {
+        pub id: String,
+        pub session_id: String,
+        pub text: String,
+        pub owner_id: Option<String>,
+        /// Owner as written in the transcript. Kept even when it could not be linked to a human.
+        pub owner_name: Option<String>,
+        pub due: Option<String>,
+        pub source_timestamp: Option<String>,
+        pub done: bool,
+        pub created_at: chrono::DateTime<chrono::Utc>,
+    }
+}
+
+user_common_derives! {
+    pub struct ListActionItemFilter {
+        pub owner_id: Option<String>,
+        pub session_id: Option<String>,
+        pub limit: Option<u8>,
+    }
+}
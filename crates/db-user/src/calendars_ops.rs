@@ -138,6 +138,32 @@ impl UserDatabase {
         let calendar: Calendar = libsql::de::from_row(&row)?;
         Ok(calendar)
     }
+
+    pub async fn set_calendar_selected(
+        &self,
+        tracking_id: impl AsRef<str>,
+        selected: bool,
+    ) -> Result<Option<Calendar>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "UPDATE calendars
+                SET selected = ?
+                WHERE tracking_id = ?
+                RETURNING *",
+                libsql::params![selected, tracking_id.as_ref()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            Some(row) => {
+                let calendar: Calendar = libsql::de::from_row(&row)?;
+                Ok(Some(calendar))
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -180,5 +206,12 @@ mod tests {
 
         let calendars = db.list_calendars(&human.id).await.unwrap();
         assert_eq!(calendars.len(), 1);
+
+        let selected = db.set_calendar_selected("test", true).await.unwrap();
+        assert!(selected.unwrap().selected);
+        assert_eq!(
+            db.set_calendar_selected("missing", true).await.unwrap(),
+            None
+        );
     }
 }
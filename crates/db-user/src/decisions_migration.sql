@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS decisions (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL,
+  text TEXT NOT NULL,
+  source_timestamp TEXT DEFAULT NULL,
+  created_at TEXT NOT NULL
+);
@@ -0,0 +1,64 @@
+use hypr_db_core::SqlTable;
+
+use super::{Decision, UserDatabase};
+
+impl UserDatabase {
+    pub async fn replace_session_decisions(
+        &self,
+        session_id: impl Into<String>,
+        decisions: Vec<Decision>,
+    ) -> Result<Vec<Decision>, crate::Error> {
+        let session_id = session_id.into();
+        let conn = self.conn()?;
+
+        let sql = format!("DELETE FROM {} WHERE session_id = ?", Decision::sql_table());
+        conn.execute(&sql, vec![session_id.clone()]).await?;
+
+        let sql = format!(
+            "INSERT INTO {} (
+                id,
+                session_id,
+                text,
+                source_timestamp,
+                created_at
+            ) VALUES (?, ?, ?, ?, ?)",
+            Decision::sql_table()
+        );
+
+        for decision in decisions {
+            conn.execute(
+                &sql,
+                libsql::params![
+                    decision.id,
+                    session_id.clone(),
+                    decision.text,
+                    decision.source_timestamp,
+                    decision.created_at.to_rfc3339(),
+                ],
+            )
+            .await?;
+        }
+
+        self.list_session_decisions(session_id).await
+    }
+
+    pub async fn list_session_decisions(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<Decision>, crate::Error> {
+        let conn = self.conn()?;
+
+        let sql = format!(
+            "SELECT * FROM {} WHERE session_id = ? ORDER BY created_at ASC, rowid ASC",
+            Decision::sql_table()
+        );
+        let mut rows = conn.query(&sql, vec![session_id.into()]).await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: Decision = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[sql_table("decisions")]
+    pub struct Decision {
+        pub id: String,
+        pub session_id: String,
+        pub text: String,
+        pub source_timestamp: Option<String>,
+        pub created_at: chrono::DateTime<chrono::Utc>,
+    }
+}
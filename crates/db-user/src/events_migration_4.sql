@@ -0,0 +1,4 @@
+ALTER TABLE
+  events
+ADD
+  COLUMN recurrence_id TEXT DEFAULT NULL;
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use super::{Event, ListEventFilter, ListEventFilterCommon, ListEventFilterSpecific, UserDatabase};
 
 impl UserDatabase {
@@ -26,6 +28,47 @@ impl UserDatabase {
         Ok(())
     }
 
+    /// Deletes the calendar's events whose `tracking_id` starts with `tracking_id_prefix`,
+    /// except the ones listed in `keep`. Returns the number of deleted events.
+    pub async fn prune_events(
+        &self,
+        calendar_id: impl AsRef<str>,
+        tracking_id_prefix: impl AsRef<str>,
+        keep: &[String],
+    ) -> Result<usize, crate::Error> {
+        let conn = self.conn()?;
+        let calendar_id = calendar_id.as_ref();
+        let prefix = tracking_id_prefix.as_ref();
+        let keep = keep.iter().map(String::as_str).collect::<HashSet<_>>();
+
+        let mut rows = conn
+            .query(
+                "SELECT tracking_id FROM events
+                WHERE calendar_id = ? AND substr(tracking_id, 1, length(?)) = ?",
+                vec![calendar_id, prefix, prefix],
+            )
+            .await?;
+
+        let mut stale = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let tracking_id: String = row.get(0)?;
+            if !keep.contains(tracking_id.as_str()) {
+                stale.push(tracking_id);
+            }
+        }
+
+        for tracking_id in &stale {
+            conn.execute(
+                "DELETE FROM events
+                WHERE calendar_id = ? AND substr(tracking_id, 1, length(?)) = ? AND tracking_id = ?",
+                vec![calendar_id, prefix, prefix, tracking_id.as_str()],
+            )
+            .await?;
+        }
+
+        Ok(stale.len())
+    }
+
     pub async fn update_event(&self, event: Event) -> Result<Event, crate::Error> {
         let conn = self.conn()?;
         let event_id = event.id.clone();
@@ -41,7 +84,9 @@ impl UserDatabase {
                     end_date = :end_date,
                     google_event_url = :google_event_url,
                     participants = :participants,
-                    is_recurring = :is_recurring
+                    is_recurring = :is_recurring,
+                    series_id = :series_id,
+                    recurrence_id = :recurrence_id
                 WHERE id = :id
                 RETURNING *",
                 libsql::named_params! {
@@ -55,6 +100,8 @@ impl UserDatabase {
                     ":google_event_url": event.google_event_url,
                     ":participants": event.participants,
                     ":is_recurring": event.is_recurring,
+                    ":series_id": event.series_id,
+                    ":recurrence_id": event.recurrence_id.map(|dt| dt.to_rfc3339()),
                 },
             )
             .await?;
@@ -87,7 +134,9 @@ impl UserDatabase {
                     end_date,
                     google_event_url,
                     participants,
-                    is_recurring
+                    is_recurring,
+                    series_id,
+                    recurrence_id
                 ) VALUES (
                     :id,
                     :user_id,
@@ -99,7 +148,9 @@ impl UserDatabase {
                     :end_date,
                     :google_event_url,
                     :participants,
-                    :is_recurring
+                    :is_recurring,
+                    :series_id,
+                    :recurrence_id
                 ) ON CONFLICT(tracking_id) DO UPDATE SET
                     name = :name,
                     note = :note,
@@ -107,7 +158,9 @@ impl UserDatabase {
                     end_date = :end_date,
                     google_event_url = :google_event_url,
                     participants = :participants,
-                    is_recurring = :is_recurring
+                    is_recurring = :is_recurring,
+                    series_id = :series_id,
+                    recurrence_id = :recurrence_id
                 RETURNING *",
                 libsql::named_params! {
                     ":id": event.id,
@@ -121,6 +174,8 @@ impl UserDatabase {
                     ":google_event_url": event.google_event_url,
                     ":participants": event.participants,
                     ":is_recurring": event.is_recurring,
+                    ":series_id": event.series_id,
+                    ":recurrence_id": event.recurrence_id.map(|dt| dt.to_rfc3339()),
                 },
             )
             .await?;
@@ -130,6 +185,38 @@ impl UserDatabase {
         Ok(event)
     }
 
+    /// Events of the calendar with `calendar_tracking_id` that overlap `start..end`,
+    /// earliest first.
+    pub async fn list_calendar_events(
+        &self,
+        calendar_tracking_id: impl AsRef<str>,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Event>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT events.* FROM events
+                JOIN calendars ON calendars.id = events.calendar_id
+                WHERE calendars.tracking_id = ? AND events.start_date < ? AND events.end_date > ?
+                ORDER BY events.start_date ASC",
+                vec![
+                    calendar_tracking_id.as_ref().to_string(),
+                    end.to_rfc3339(),
+                    start.to_rfc3339(),
+                ],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: Event = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
     pub async fn list_events(
         &self,
         filter: Option<ListEventFilter>,
@@ -248,6 +335,8 @@ mod tests {
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            series_id: None,
+            recurrence_id: None,
         };
 
         let event = db.upsert_event(event).await.unwrap();
@@ -256,5 +345,51 @@ mod tests {
 
         let events = db.list_events(None).await.unwrap();
         assert_eq!(events.len(), 1);
+
+        let in_range = db
+            .list_calendar_events(
+                "calendar_test",
+                event.start_date - chrono::Duration::hours(1),
+                event.end_date + chrono::Duration::hours(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(in_range.len(), 1);
+
+        let out_of_range = db
+            .list_calendar_events(
+                "calendar_test",
+                event.end_date + chrono::Duration::hours(1),
+                event.end_date + chrono::Duration::hours(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(out_of_range.len(), 0);
+
+        for tracking_id in ["sub#a#1", "sub#a#2", "sub#b#1"] {
+            db.upsert_event(Event {
+                id: uuid::Uuid::new_v4().to_string(),
+                tracking_id: tracking_id.to_string(),
+                ..event.clone()
+            })
+            .await
+            .unwrap();
+        }
+
+        // Another calendar's prune never touches these events.
+        let pruned = db.prune_events("other", "", &[]).await.unwrap();
+        assert_eq!(pruned, 0);
+
+        let pruned = db
+            .prune_events(&calendar.id, "sub#a#", &["sub#a#2".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(pruned, 1);
+
+        let pruned = db.prune_events(&calendar.id, "sub#", &[]).await.unwrap();
+        assert_eq!(pruned, 2);
+
+        let events = db.list_events(None).await.unwrap();
+        assert_eq!(events.len(), 1);
     }
 }
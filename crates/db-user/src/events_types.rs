@@ -15,6 +15,11 @@ user_common_derives! {
         pub google_event_url: Option<String>,
         pub participants: Option<String>,
         pub is_recurring: bool,
+        /// Shared by every instance of a recurring meeting, such as the provider's
+        /// recurring event id or the iCal UID.
+        pub series_id: Option<String>,
+        /// Original start of this instance within its series.
+        pub recurrence_id: Option<DateTime<Utc>>,
     }
 }
 
@@ -1,3 +1,5 @@
+mod action_items_ops;
+mod action_items_types;
 mod calendars_ops;
 mod calendars_types;
 mod chat_conversations_ops;
@@ -10,6 +12,8 @@ mod chat_messages_v2_ops;
 mod chat_messages_v2_types;
 mod config_ops;
 mod config_types;
+mod decisions_ops;
+mod decisions_types;
 mod events_ops;
 mod events_types;
 mod extensions_ops;
@@ -25,6 +29,10 @@ mod tags_types;
 mod templates_ops;
 mod templates_types;
 
+#[allow(unused)]
+pub use action_items_ops::*;
+#[allow(unused)]
+pub use action_items_types::*;
 #[allow(unused)]
 pub use calendars_ops::*;
 #[allow(unused)]
@@ -50,6 +58,10 @@ pub use config_ops::*;
 #[allow(unused)]
 pub use config_types::*;
 #[allow(unused)]
+pub use decisions_ops::*;
+#[allow(unused)]
+pub use decisions_types::*;
+#[allow(unused)]
 pub use events_ops::*;
 #[allow(unused)]
 pub use events_types::*;
@@ -139,7 +151,7 @@ impl std::ops::Deref for UserDatabase {
 }
 
 // Append only. Do not reorder.
-const MIGRATIONS: [&str; 27] = [
+const MIGRATIONS: [&str; 29] = [
     include_str!("./calendars_migration.sql"),
     include_str!("./configs_migration.sql"),
     include_str!("./events_migration.sql"),
@@ -167,6 +179,8 @@ const MIGRATIONS: [&str; 27] = [
     include_str!("./templates_migration_1.sql"),
     include_str!("./chat_conversations_migration.sql"),
     include_str!("./chat_messages_v2_migration.sql"),
+    include_str!("./events_migration_3.sql"),
+    include_str!("./events_migration_4.sql"),
 ];
 
 pub async fn migrate(db: &UserDatabase) -> Result<(), crate::Error> {
@@ -176,10 +190,24 @@ pub async fn migrate(db: &UserDatabase) -> Result<(), crate::Error> {
     Ok(())
 }
 
+// Action items and decisions live in the db2 database, which does not run the migrations above.
+// Append only. Do not reorder.
+const EXTRACTION_MIGRATIONS: [&str; 2] = [
+    include_str!("./action_items_migration.sql"),
+    include_str!("./decisions_migration.sql"),
+];
+
+pub async fn migrate_extraction(db: &UserDatabase) -> Result<(), crate::Error> {
+    let conn = db.conn()?;
+    hypr_db_core::migrate(&conn, EXTRACTION_MIGRATIONS.to_vec()).await?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::UserDatabase;
-    use crate::migrate;
+    use crate::{migrate, migrate_extraction};
     use hypr_db_core::DatabaseBuilder;
 
     pub async fn setup_db() -> UserDatabase {
@@ -189,8 +217,20 @@ mod tests {
         user_db
     }
 
+    pub async fn setup_extraction_db() -> UserDatabase {
+        let base_db = DatabaseBuilder::default().memory().build().await.unwrap();
+        let user_db = UserDatabase::from(base_db);
+        migrate_extraction(&user_db).await.unwrap();
+        user_db
+    }
+
     #[tokio::test]
     async fn test_migrate() {
         let _ = setup_db().await;
     }
+
+    #[tokio::test]
+    async fn test_migrate_extraction() {
+        let _ = setup_extraction_db().await;
+    }
 }
@@ -426,11 +426,64 @@ impl UserDatabase {
             }
         }
     }
+
+    /// Sessions attached to any instance of the series, oldest meeting first.
+    pub async fn list_series_sessions(
+        &self,
+        series_id: impl Into<String>,
+    ) -> Result<Vec<Session>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT s.* FROM sessions s
+                JOIN events e ON e.id = s.calendar_event_id
+                WHERE e.series_id = ?
+                ORDER BY e.start_date ASC",
+                vec![series_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item = Session::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    /// The session of the latest earlier meeting in the same series, if any.
+    pub async fn get_previous_series_session(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Option<Session>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT s.* FROM sessions s
+                JOIN events e ON e.id = s.calendar_event_id
+                JOIN events current_event ON current_event.series_id = e.series_id
+                JOIN sessions current ON current.calendar_event_id = current_event.id
+                WHERE current.id = ?
+                    AND s.id != current.id
+                    AND e.start_date < current_event.start_date
+                ORDER BY e.start_date DESC
+                LIMIT 1",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            None => Ok(None),
+            Some(row) => Ok(Some(Session::from_row(&row)?)),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Human, Session, tests::setup_db};
+    use crate::{Event, Human, Session, tests::setup_db};
 
     #[tokio::test]
     async fn test_sessions() {
@@ -498,4 +551,71 @@ mod tests {
 
         assert_eq!(db.session_get_event(&session.id).await.unwrap(), None);
     }
+
+    #[tokio::test]
+    async fn test_series_sessions() {
+        let db = setup_db().await;
+
+        let user = db.upsert_human(Human::default()).await.unwrap();
+        let now = chrono::Utc::now();
+
+        let mut ids = vec![];
+        for (weeks_ago, series_id) in [(2, "weekly"), (0, "weekly"), (1, "weekly"), (1, "other")] {
+            let start = now - chrono::Duration::weeks(weeks_ago);
+            let event = db
+                .upsert_event(Event {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    user_id: user.id.clone(),
+                    tracking_id: uuid::Uuid::new_v4().to_string(),
+                    calendar_id: None,
+                    name: format!("{} {}", series_id, weeks_ago),
+                    note: "".to_string(),
+                    start_date: start,
+                    end_date: start + chrono::Duration::minutes(30),
+                    google_event_url: None,
+                    participants: None,
+                    is_recurring: true,
+                    series_id: Some(series_id.to_string()),
+                    recurrence_id: Some(start),
+                })
+                .await
+                .unwrap();
+            assert_eq!(event.recurrence_id, Some(start));
+
+            let session = db
+                .upsert_session(Session {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    user_id: user.id.clone(),
+                    created_at: now,
+                    visited_at: now,
+                    calendar_event_id: Some(event.id.clone()),
+                    title: event.name.clone(),
+                    raw_memo_html: "".to_string(),
+                    enhanced_memo_html: None,
+                    conversations: vec![],
+                    words: vec![],
+                    record_start: None,
+                    record_end: None,
+                    pre_meeting_memo_html: None,
+                })
+                .await
+                .unwrap();
+            ids.push(session.id);
+        }
+
+        let series = db.list_series_sessions("weekly").await.unwrap();
+        assert_eq!(
+            series.iter().map(|s| s.title.as_str()).collect::<Vec<_>>(),
+            vec!["weekly 2", "weekly 1", "weekly 0"]
+        );
+
+        let previous = db.get_previous_series_session(&ids[1]).await.unwrap();
+        assert_eq!(previous.map(|s| s.id), Some(ids[2].clone()));
+
+        let previous = db.get_previous_series_session(&ids[0]).await.unwrap();
+        assert!(previous.is_none());
+
+        let previous = db.get_previous_series_session(&ids[3]).await.unwrap();
+        assert!(previous.is_none());
+    }
 }
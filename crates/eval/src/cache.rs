@@ -116,6 +116,79 @@ impl CachingClient {
     }
 }
 
+/// Disk cache for STT transcriptions, so re-scoring doesn't re-transcribe.
+/// Keyed by transcriber, case, sample index and the audio content itself.
+pub(crate) struct TranscriptionCache {
+    disk_cache: DiskCacheState,
+}
+
+impl TranscriptionCache {
+    pub(crate) fn new(cache_dir: Option<String>) -> Self {
+        let disk_cache = match cache_dir
+            .map(PathBuf::from)
+            .or_else(|| default_cache_dir().map(|p| p.join("stt")))
+        {
+            Some(dir) => match fs::create_dir_all(&dir) {
+                Ok(()) => DiskCacheState::Available(dir),
+                Err(_) => DiskCacheState::Disabled,
+            },
+            None => DiskCacheState::Disabled,
+        };
+
+        Self { disk_cache }
+    }
+
+    pub(crate) fn disabled() -> Self {
+        Self {
+            disk_cache: DiskCacheState::Disabled,
+        }
+    }
+
+    pub(crate) fn key(
+        &self,
+        transcriber: &str,
+        case_id: &str,
+        audio: &[u8],
+        sample: i32,
+    ) -> String {
+        let key_input = serde_json::json!({
+            "v": CACHE_KEY_VERSION,
+            "transcriber": transcriber,
+            "case_id": case_id,
+            "audio": hex::encode(Sha256::digest(audio)),
+            "sample": sample,
+        });
+
+        let canonical = serde_json::to_string(&key_input).unwrap_or_default();
+        hex::encode(Sha256::digest(canonical.as_bytes()))
+    }
+
+    fn path(&self, key: &str) -> Option<PathBuf> {
+        match &self.disk_cache {
+            DiskCacheState::Available(dir) => Some(dir.join(format!("{}.json", key))),
+            DiskCacheState::Disabled => None,
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<crate::Transcription> {
+        let content = fs::read_to_string(self.path(key)?).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub(crate) fn set(&self, key: &str, transcription: &crate::Transcription) {
+        let Some(path) = self.path(key) else {
+            return;
+        };
+
+        let written = serde_json::to_string(transcription)
+            .map_err(std::io::Error::other)
+            .and_then(|json| fs::write(&path, json));
+        if written.is_err() {
+            eprintln!("Warning: failed to write cache file: {}", path.display());
+        }
+    }
+}
+
 mod hex {
     pub fn encode(bytes: impl AsRef<[u8]>) -> String {
         bytes
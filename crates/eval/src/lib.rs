@@ -10,6 +10,7 @@
 //! - Response caching for reproducibility
 //! - Progress tracking
 //! - OpenRouter API integration
+//! - Speech-to-text evaluation (WER, CER, DER, latency) across STT adapters
 //!
 //! ## Quick Start
 //!
@@ -44,6 +45,7 @@ mod format;
 mod models;
 mod rubric;
 mod stats;
+mod stt;
 mod submission;
 mod testing;
 
@@ -69,8 +71,14 @@ pub use format::{
 pub use models::{fetch_openrouter_models, filter_models};
 pub use rubric::{Score, grade_with_func, grade_with_llm, is_non_empty};
 pub use stats::{
-    AggregatedGraderResponse, ConfidenceInterval, PassStats, aggregate_grader_responses,
-    calc_pass_stats,
+    AggregatedGraderResponse, ConfidenceInterval, MetricStats, PassStats,
+    aggregate_grader_responses, calc_metric_stats, calc_pass_stats,
+};
+pub use stt::{
+    BatchTranscriber, DiarizationError, ErrorCounts, RealtimeTranscriber, SpeakerTurn, SttCase,
+    SttError, SttExecutor, SttMode, SttResult, TimedWord, Transcriber, Transcription,
+    character_error_rate, diarization_error_rate, fixture_cases, normalize_text,
+    provider_transcriber, word_error_rate,
 };
 pub use submission::{
     EvalCase, EvalResult, Executor, ExecutorProgress, ExecutorProgressCallback, GraderSpec,
@@ -43,6 +43,43 @@ pub fn calc_pass_stats(pass_count: i32, total_count: i32) -> PassStats {
     }
 }
 
+/// Mean of a continuous metric (error rate, latency) across samples, with a
+/// normal-approximation confidence interval.
+#[derive(Debug, Clone, Default)]
+pub struct MetricStats {
+    pub mean: f64,
+    pub samples: i32,
+    pub standard_deviation: f64,
+    pub confidence_interval: ConfidenceInterval,
+}
+
+pub fn calc_metric_stats(values: &[f64]) -> MetricStats {
+    if values.is_empty() {
+        return MetricStats::default();
+    }
+
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = if values.len() > 1 {
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    } else {
+        0.0
+    };
+    let std_dev = variance.sqrt();
+    let margin = 1.96 * std_dev / n.sqrt();
+
+    MetricStats {
+        mean,
+        samples: values.len() as i32,
+        standard_deviation: std_dev,
+        confidence_interval: ConfidenceInterval {
+            lower: mean - margin,
+            upper: mean + margin,
+            level: 0.95,
+        },
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregatedGraderResponse {
     pub pass_stats: PassStats,
@@ -152,6 +189,19 @@ mod tests {
         assert_eq!(stats.fail_count, 5);
     }
 
+    #[test]
+    fn test_calc_metric_stats() {
+        let stats = calc_metric_stats(&[0.1, 0.2, 0.3]);
+        assert!((stats.mean - 0.2).abs() < 1e-9);
+        assert_eq!(stats.samples, 3);
+        assert!((stats.standard_deviation - 0.1).abs() < 1e-9);
+        assert!(stats.confidence_interval.lower < 0.2 && stats.confidence_interval.upper > 0.2);
+
+        let single = calc_metric_stats(&[0.5]);
+        assert_eq!(single.confidence_interval.lower, 0.5);
+        assert_eq!(single.confidence_interval.upper, 0.5);
+    }
+
     #[test]
     fn test_wilson_confidence_interval() {
         let (lower, upper) = calculate_wilson_confidence_interval(50, 100, 0.95);
@@ -0,0 +1,115 @@
+use hypr_language::Language;
+use serde::Deserialize;
+
+use super::{SpeakerTurn, SttCase};
+
+const DEFAULT_MAX_WER: f64 = 0.2;
+const DEFAULT_MAX_DER: f64 = 0.3;
+
+// Fixture JSON uses milliseconds.
+#[derive(Deserialize)]
+struct ReferenceWord {
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct ReferenceTurn {
+    start: u64,
+    end: u64,
+    speaker: String,
+}
+
+/// The `hypr_data` recordings that ship with a reference transcript and
+/// speaker turns.
+pub fn fixture_cases() -> Vec<SttCase> {
+    vec![
+        fixture_case(
+            "english_1",
+            "en",
+            hypr_data::english_1::AUDIO_PATH,
+            hypr_data::english_1::TRANSCRIPTION_JSON,
+            hypr_data::english_1::DIARIZATION_JSON,
+        ),
+        fixture_case(
+            "english_2",
+            "en",
+            hypr_data::english_2::AUDIO_PATH,
+            hypr_data::english_2::TRANSCRIPTION_JSON,
+            hypr_data::english_2::DIARIZATION_JSON,
+        ),
+        fixture_case(
+            "korean_1",
+            "ko",
+            hypr_data::korean_1::AUDIO_PATH,
+            hypr_data::korean_1::TRANSCRIPTION_JSON,
+            hypr_data::korean_1::DIARIZATION_JSON,
+        ),
+        fixture_case(
+            "korean_2",
+            "ko",
+            hypr_data::korean_2::AUDIO_PATH,
+            hypr_data::korean_2::TRANSCRIPTION_JSON,
+            hypr_data::korean_2::DIARIZATION_JSON,
+        ),
+    ]
+}
+
+fn fixture_case(
+    case_id: &str,
+    language: &str,
+    audio_path: &str,
+    transcription_json: &str,
+    diarization_json: &str,
+) -> SttCase {
+    SttCase {
+        case_id: case_id.to_string(),
+        audio_path: audio_path.into(),
+        languages: vec![language.parse::<Language>().unwrap()],
+        reference: parse_reference(transcription_json),
+        speakers: parse_speakers(diarization_json),
+        samples: 1,
+        max_wer: DEFAULT_MAX_WER,
+        max_der: DEFAULT_MAX_DER,
+    }
+}
+
+fn parse_reference(json: &str) -> String {
+    let words: Vec<ReferenceWord> = serde_json::from_str(json).unwrap();
+    words
+        .iter()
+        .map(|w| w.text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_speakers(json: &str) -> Vec<SpeakerTurn> {
+    let turns: Vec<ReferenceTurn> = serde_json::from_str(json).unwrap();
+    turns
+        .into_iter()
+        .map(|t| SpeakerTurn {
+            start: t.start as f64 / 1000.0,
+            end: t.end as f64 / 1000.0,
+            speaker: t.speaker,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixture_cases() {
+        let cases = fixture_cases();
+        assert_eq!(cases.len(), 4);
+
+        for case in &cases {
+            assert!(!case.reference.is_empty(), "{}", case.case_id);
+            assert!(!case.speakers.is_empty(), "{}", case.case_id);
+            assert!(case.speakers.iter().all(|t| t.end >= t.start));
+        }
+
+        assert!(cases[1].reference.starts_with("Hello? Hello? Oh, hello."));
+    }
+}
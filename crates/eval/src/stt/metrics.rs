@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::SpeakerTurn;
+
+// Diarization is scored on 10ms frames, without a forgiveness collar.
+const FRAME_SECS: f64 = 0.01;
+
+/// Lowercases, drops punctuation and collapses whitespace, so formatting
+/// differences between providers don't count as errors.
+pub fn normalize_text(text: &str) -> String {
+    let spaced: String = text
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '\'' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    spaced
+        .split_whitespace()
+        .map(|token| token.trim_matches('\''))
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Edit operations needed to turn the hypothesis into the reference.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorCounts {
+    pub substitutions: usize,
+    pub deletions: usize,
+    pub insertions: usize,
+    pub reference_len: usize,
+}
+
+impl ErrorCounts {
+    pub fn errors(&self) -> usize {
+        self.substitutions + self.deletions + self.insertions
+    }
+
+    pub fn rate(&self) -> f64 {
+        if self.reference_len == 0 {
+            return if self.errors() == 0 { 0.0 } else { 1.0 };
+        }
+        self.errors() as f64 / self.reference_len as f64
+    }
+}
+
+pub fn word_error_rate(reference: &str, hypothesis: &str) -> ErrorCounts {
+    let reference = normalize_text(reference);
+    let hypothesis = normalize_text(hypothesis);
+
+    let reference: Vec<&str> = reference.split_whitespace().collect();
+    let hypothesis: Vec<&str> = hypothesis.split_whitespace().collect();
+    align(&reference, &hypothesis)
+}
+
+/// Character error rate with whitespace removed, which keeps it meaningful for
+/// languages where word boundaries are ambiguous.
+pub fn character_error_rate(reference: &str, hypothesis: &str) -> ErrorCounts {
+    let chars = |text: &str| -> Vec<char> {
+        normalize_text(text)
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect()
+    };
+
+    align(&chars(reference), &chars(hypothesis))
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Cell {
+    cost: usize,
+    counts: ErrorCounts,
+}
+
+impl Cell {
+    fn step(self, substitutions: usize, deletions: usize, insertions: usize) -> Self {
+        Self {
+            cost: self.cost + substitutions + deletions + insertions,
+            counts: ErrorCounts {
+                substitutions: self.counts.substitutions + substitutions,
+                deletions: self.counts.deletions + deletions,
+                insertions: self.counts.insertions + insertions,
+                reference_len: 0,
+            },
+        }
+    }
+}
+
+// Levenshtein alignment keeping only two rows, since character sequences of a
+// long recording don't fit a full matrix.
+fn align<T: PartialEq>(reference: &[T], hypothesis: &[T]) -> ErrorCounts {
+    let mut prev: Vec<Cell> = (0..=hypothesis.len())
+        .map(|j| Cell::default().step(0, 0, j))
+        .collect();
+    let mut curr = vec![Cell::default(); hypothesis.len() + 1];
+
+    for (i, r) in reference.iter().enumerate() {
+        curr[0] = Cell::default().step(0, i + 1, 0);
+
+        for (j, h) in hypothesis.iter().enumerate() {
+            let substitute = prev[j].step(usize::from(r != h), 0, 0);
+            let delete = prev[j + 1].step(0, 1, 0);
+            let insert = curr[j].step(0, 0, 1);
+
+            curr[j + 1] = [substitute, delete, insert]
+                .into_iter()
+                .min_by_key(|cell| cell.cost)
+                .unwrap();
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    ErrorCounts {
+        reference_len: reference.len(),
+        ..prev[hypothesis.len()].counts
+    }
+}
+
+/// Diarization error broken down by cause, in seconds of speech.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiarizationError {
+    pub missed: f64,
+    pub false_alarm: f64,
+    pub confusion: f64,
+    /// Total reference speaker time.
+    pub total: f64,
+}
+
+impl DiarizationError {
+    pub fn rate(&self) -> f64 {
+        if self.total == 0.0 {
+            return if self.missed + self.false_alarm + self.confusion == 0.0 {
+                0.0
+            } else {
+                1.0
+            };
+        }
+        (self.missed + self.false_alarm + self.confusion) / self.total
+    }
+}
+
+/// NIST-style diarization error. Hypothesis speaker labels are mapped
+/// one-to-one onto reference speakers by greatest overlap before scoring.
+pub fn diarization_error_rate(
+    reference: &[SpeakerTurn],
+    hypothesis: &[SpeakerTurn],
+) -> DiarizationError {
+    let end = reference
+        .iter()
+        .chain(hypothesis)
+        .map(|turn| turn.end)
+        .fold(0.0, f64::max);
+    let frame_count = (end / FRAME_SECS).ceil() as usize;
+
+    let (reference_frames, reference_count) = speaker_frames(reference, frame_count);
+    let (hypothesis_frames, hypothesis_count) = speaker_frames(hypothesis, frame_count);
+
+    let mut overlap = vec![vec![0usize; hypothesis_count]; reference_count];
+    for (r, h) in reference_frames.iter().zip(&hypothesis_frames) {
+        for &ref_speaker in r {
+            for &hyp_speaker in h {
+                overlap[ref_speaker][hyp_speaker] += 1;
+            }
+        }
+    }
+
+    let mut pairs: Vec<(usize, usize, usize)> = overlap
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| row.iter().enumerate().map(move |(h, &n)| (n, r, h)))
+        .filter(|&(n, _, _)| n > 0)
+        .collect();
+    pairs.sort_by_key(|&(n, _, _)| std::cmp::Reverse(n));
+
+    let mut mapping: HashMap<usize, usize> = HashMap::new();
+    let mut mapped_hypothesis = vec![false; hypothesis_count];
+    for (_, r, h) in pairs {
+        if mapping.contains_key(&r) || mapped_hypothesis[h] {
+            continue;
+        }
+        mapping.insert(r, h);
+        mapped_hypothesis[h] = true;
+    }
+
+    let mut frames = DiarizationError::default();
+    for (r, h) in reference_frames.iter().zip(&hypothesis_frames) {
+        let correct = r
+            .iter()
+            .filter(|speaker| mapping.get(speaker).is_some_and(|m| h.contains(m)))
+            .count();
+
+        frames.total += r.len() as f64;
+        frames.missed += r.len().saturating_sub(h.len()) as f64;
+        frames.false_alarm += h.len().saturating_sub(r.len()) as f64;
+        frames.confusion += (r.len().min(h.len()) - correct) as f64;
+    }
+
+    DiarizationError {
+        missed: frames.missed * FRAME_SECS,
+        false_alarm: frames.false_alarm * FRAME_SECS,
+        confusion: frames.confusion * FRAME_SECS,
+        total: frames.total * FRAME_SECS,
+    }
+}
+
+// Speakers active in each frame, with labels replaced by dense indices.
+fn speaker_frames(turns: &[SpeakerTurn], frame_count: usize) -> (Vec<Vec<usize>>, usize) {
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    let mut frames = vec![Vec::new(); frame_count];
+
+    for turn in turns {
+        let next = labels.len();
+        let speaker = *labels.entry(turn.speaker.as_str()).or_insert(next);
+
+        let start = (turn.start / FRAME_SECS).round().max(0.0) as usize;
+        let end = ((turn.end / FRAME_SECS).round() as usize).min(frame_count);
+        for frame in frames.iter_mut().take(end).skip(start) {
+            if !frame.contains(&speaker) {
+                frame.push(speaker);
+            }
+        }
+    }
+
+    (frames, labels.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(start: f64, end: f64, speaker: &str) -> SpeakerTurn {
+        SpeakerTurn {
+            start,
+            end,
+            speaker: speaker.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        assert_eq!(
+            normalize_text("  Hello, World!  It's  'fine'."),
+            "hello world it's fine"
+        );
+        assert_eq!(normalize_text("안녕하세요."), "안녕하세요");
+    }
+
+    #[test]
+    fn test_word_error_rate() {
+        let counts = word_error_rate("the cat sat on the mat", "The cat sat on mat.");
+        assert_eq!(
+            counts,
+            ErrorCounts {
+                substitutions: 0,
+                deletions: 1,
+                insertions: 0,
+                reference_len: 6,
+            }
+        );
+        assert!((counts.rate() - 1.0 / 6.0).abs() < 1e-9);
+
+        assert_eq!(word_error_rate("a b", "a b c").insertions, 1);
+
+        let counts = word_error_rate("a b c", "a x c");
+        assert_eq!(counts.substitutions, 1);
+        assert_eq!(counts.errors(), 1);
+
+        assert_eq!(word_error_rate("", "").rate(), 0.0);
+        assert_eq!(word_error_rate("", "noise").rate(), 1.0);
+    }
+
+    #[test]
+    fn test_character_error_rate_ignores_spacing() {
+        assert_eq!(
+            character_error_rate("기관 스터디", "기관스터디").rate(),
+            0.0
+        );
+        assert_eq!(character_error_rate("abcd", "abed").substitutions, 1);
+    }
+
+    #[test]
+    fn test_diarization_error_rate() {
+        let reference = [turn(0.0, 2.0, "speaker0"), turn(2.0, 4.0, "speaker1")];
+
+        let perfect = [turn(0.0, 2.0, "B"), turn(2.0, 4.0, "A")];
+        assert!(diarization_error_rate(&reference, &perfect).rate() < 1e-9);
+
+        let hypothesis = [turn(0.0, 3.0, "B"), turn(3.0, 4.0, "A")];
+        let der = diarization_error_rate(&reference, &hypothesis);
+        assert!((der.total - 4.0).abs() < 1e-9);
+        assert!((der.confusion - 1.0).abs() < 1e-9);
+        assert!((der.rate() - 0.25).abs() < 1e-9);
+
+        let partial = [turn(0.0, 1.0, "A"), turn(4.0, 5.0, "A")];
+        let der = diarization_error_rate(&reference, &partial);
+        assert!((der.missed - 3.0).abs() < 1e-9);
+        assert!((der.false_alarm - 1.0).abs() < 1e-9);
+        assert!(der.confusion.abs() < 1e-9);
+    }
+}
@@ -0,0 +1,99 @@
+//! Speech-to-text evaluation: runs audio fixtures through a transcriber and
+//! scores the output against reference transcripts and speaker turns.
+
+mod fixtures;
+mod metrics;
+mod runner;
+mod transcriber;
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+pub use fixtures::fixture_cases;
+pub use metrics::{
+    DiarizationError, ErrorCounts, character_error_rate, diarization_error_rate, normalize_text,
+    word_error_rate,
+};
+pub use runner::{SttExecutor, SttResult};
+pub use transcriber::{
+    BatchTranscriber, RealtimeTranscriber, SttMode, Transcriber, provider_transcriber,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SttError {
+    #[error("failed to read audio: {0}")]
+    Audio(String),
+    #[error("transcription failed: {0}")]
+    Transcription(String),
+    #[error("timed out waiting for the final transcript")]
+    Timeout,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimedWord {
+    pub text: String,
+    /// Seconds from the start of the audio.
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeakerTurn {
+    pub start: f64,
+    pub end: f64,
+    pub speaker: String,
+}
+
+/// An audio fixture with the transcript and speaker turns it should produce.
+#[derive(Debug, Clone)]
+pub struct SttCase {
+    pub case_id: String,
+    pub audio_path: PathBuf,
+    pub languages: Vec<hypr_language::Language>,
+    pub reference: String,
+    /// Reference speaker turns. Empty skips diarization scoring.
+    pub speakers: Vec<SpeakerTurn>,
+    pub samples: i32,
+    /// A sample passes the "wer" rubric at or below this rate.
+    pub max_wer: f64,
+    /// A sample passes the "der" rubric at or below this rate.
+    pub max_der: f64,
+}
+
+/// What a transcriber produced for one pass over a case's audio.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Transcription {
+    pub transcript: String,
+    pub words: Vec<TimedWord>,
+    /// Mean delay between a stretch of audio being sent and its final result
+    /// arriving. Only set for realtime transcription.
+    pub latency_to_final_ms: Option<f64>,
+    pub elapsed_ms: f64,
+}
+
+impl Transcription {
+    /// Consecutive words from the same speaker merged into turns. Words
+    /// without a speaker label are skipped.
+    pub fn speaker_turns(&self) -> Vec<SpeakerTurn> {
+        let mut turns: Vec<SpeakerTurn> = Vec::new();
+
+        for word in &self.words {
+            let Some(speaker) = &word.speaker else {
+                continue;
+            };
+
+            match turns.last_mut() {
+                Some(turn) if &turn.speaker == speaker => turn.end = word.end,
+                _ => turns.push(SpeakerTurn {
+                    start: word.start,
+                    end: word.end,
+                    speaker: speaker.clone(),
+                }),
+            }
+        }
+
+        turns
+    }
+}
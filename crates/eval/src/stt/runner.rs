@@ -0,0 +1,347 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use rayon::prelude::*;
+
+use super::{
+    SttCase, Transcriber, Transcription, character_error_rate, diarization_error_rate,
+    word_error_rate,
+};
+use crate::cache::TranscriptionCache;
+use crate::{
+    EvalResult, ExecutorProgress, ExecutorProgressCallback, MetricStats, Score, calc_metric_stats,
+    calc_pass_stats, parse_config,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct SttResult {
+    pub case_id: String,
+    pub transcriber: String,
+    pub transcriptions: Vec<Transcription>,
+    pub wer: MetricStats,
+    pub cer: MetricStats,
+    /// `None` when the transcriber returned no speaker labels.
+    pub der: Option<MetricStats>,
+    /// `None` for batch transcription.
+    pub latency_to_final_ms: Option<MetricStats>,
+    pub scores: Vec<Score>,
+    pub error: Option<String>,
+}
+
+impl SttResult {
+    /// The result in the shape the LLM eval reports render.
+    pub fn to_eval_result(&self) -> EvalResult {
+        EvalResult {
+            case_id: self.case_id.clone(),
+            model: self.transcriber.clone(),
+            output: self
+                .transcriptions
+                .first()
+                .map(|t| t.transcript.clone())
+                .unwrap_or_default(),
+            scores: self.scores.clone(),
+            error: self.error.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+pub struct SttExecutor {
+    cache: TranscriptionCache,
+    concurrency: usize,
+    on_progress: Option<ExecutorProgressCallback>,
+}
+
+impl Default for SttExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SttExecutor {
+    pub fn new() -> Self {
+        let cfg = parse_config();
+        Self {
+            cache: TranscriptionCache::new(None),
+            concurrency: cfg.concurrency,
+            on_progress: None,
+        }
+    }
+
+    pub fn with_cache_dir(mut self, cache_dir: String) -> Self {
+        self.cache = TranscriptionCache::new(Some(cache_dir));
+        self
+    }
+
+    pub fn without_cache(mut self) -> Self {
+        self.cache = TranscriptionCache::disabled();
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets a progress callback. Generations count transcriptions and
+    /// evaluations count scored transcriptions.
+    pub fn with_on_progress(mut self, callback: ExecutorProgressCallback) -> Self {
+        self.on_progress = Some(callback);
+        self
+    }
+
+    pub fn total_transcriptions(
+        &self,
+        cases: &[SttCase],
+        transcribers: &[Arc<dyn Transcriber>],
+    ) -> usize {
+        let samples: usize = cases.iter().map(|c| c.samples.max(1) as usize).sum();
+        samples * transcribers.len()
+    }
+
+    pub fn execute(
+        &self,
+        cases: &[SttCase],
+        transcribers: &[Arc<dyn Transcriber>],
+    ) -> Vec<SttResult> {
+        let total = self.total_transcriptions(cases, transcribers);
+        let done = AtomicUsize::new(0);
+        let results = Mutex::new(Vec::new());
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.concurrency)
+            .build()
+            .unwrap();
+
+        let work_items: Vec<_> = transcribers
+            .iter()
+            .flat_map(|transcriber| cases.iter().map(move |case| (transcriber, case)))
+            .collect();
+
+        pool.install(|| {
+            work_items.par_iter().for_each(|&(transcriber, case)| {
+                let on_transcribed = || {
+                    let complete = done.fetch_add(1, Ordering::SeqCst) + 1;
+                    if let Some(ref cb) = self.on_progress {
+                        cb(ExecutorProgress {
+                            generations_complete: complete,
+                            generations_total: total,
+                            evaluations_complete: complete,
+                            evaluations_total: total,
+                        });
+                    }
+                };
+
+                let result = self.execute_single(transcriber.as_ref(), case, &on_transcribed);
+                if let Ok(mut results_guard) = results.lock() {
+                    results_guard.push(result);
+                }
+            });
+        });
+
+        results.into_inner().unwrap_or_default()
+    }
+
+    fn execute_single(
+        &self,
+        transcriber: &dyn Transcriber,
+        case: &SttCase,
+        on_transcribed: &dyn Fn(),
+    ) -> SttResult {
+        let name = transcriber.name();
+        let mut result = SttResult {
+            case_id: case.case_id.clone(),
+            transcriber: name.clone(),
+            ..Default::default()
+        };
+
+        let samples = case.samples.max(1);
+        let audio = match std::fs::read(&case.audio_path) {
+            Ok(audio) => audio,
+            Err(e) => {
+                result.error = Some(format!("{}: {}", case.audio_path.display(), e));
+                (0..samples).for_each(|_| on_transcribed());
+                return result;
+            }
+        };
+
+        for sample in 0..samples {
+            let key = self.cache.key(&name, &case.case_id, &audio, sample);
+
+            let transcription = match self.cache.get(&key) {
+                Some(cached) => Ok(cached),
+                None => transcriber
+                    .transcribe(case)
+                    .inspect(|t| self.cache.set(&key, t)),
+            };
+            on_transcribed();
+
+            match transcription {
+                Ok(transcription) => result.transcriptions.push(transcription),
+                Err(e) => {
+                    result.error = Some(e.to_string());
+                    (sample + 1..samples).for_each(|_| on_transcribed());
+                    return result;
+                }
+            }
+        }
+
+        score(case, &mut result);
+        result
+    }
+}
+
+fn score(case: &SttCase, result: &mut SttResult) {
+    let wer: Vec<f64> = result
+        .transcriptions
+        .iter()
+        .map(|t| word_error_rate(&case.reference, &t.transcript).rate())
+        .collect();
+    let cer: Vec<f64> = result
+        .transcriptions
+        .iter()
+        .map(|t| character_error_rate(&case.reference, &t.transcript).rate())
+        .collect();
+    let der: Vec<f64> = result
+        .transcriptions
+        .iter()
+        .map(|t| t.speaker_turns())
+        .filter(|turns| !turns.is_empty() && !case.speakers.is_empty())
+        .map(|turns| diarization_error_rate(&case.speakers, &turns).rate())
+        .collect();
+    let latency: Vec<f64> = result
+        .transcriptions
+        .iter()
+        .filter_map(|t| t.latency_to_final_ms)
+        .collect();
+
+    result
+        .scores
+        .push(threshold_score("wer", &wer, case.max_wer));
+    if !der.is_empty() {
+        result
+            .scores
+            .push(threshold_score("der", &der, case.max_der));
+    }
+
+    result.wer = calc_metric_stats(&wer);
+    result.cer = calc_metric_stats(&cer);
+    result.der = (!der.is_empty()).then(|| calc_metric_stats(&der));
+    result.latency_to_final_ms = (!latency.is_empty()).then(|| calc_metric_stats(&latency));
+}
+
+// Each sample passes when its rate is at or below `max`; the rubric passes when
+// most samples do, matching how multi-sample LLM rubrics are aggregated.
+fn threshold_score(name: &str, rates: &[f64], max: f64) -> Score {
+    let pass_count = rates.iter().filter(|&&rate| rate <= max).count() as i32;
+    let stats = calc_pass_stats(pass_count, rates.len() as i32);
+    let mean = calc_metric_stats(rates).mean;
+
+    Score {
+        rubric_name: name.to_string(),
+        passed: stats.pass_rate >= 0.5,
+        value: if stats.pass_rate >= 0.5 { 1 } else { 0 },
+        reasoning: format!(
+            "mean {} {:.1}% (max {:.1}%)",
+            name,
+            mean * 100.0,
+            max * 100.0
+        ),
+        grader_type: "func".to_string(),
+        grader_model: String::new(),
+        pass_rate: stats.pass_rate,
+        samples: stats.samples,
+        standard_deviation: stats.standard_deviation,
+        variance: stats.variance,
+        confidence_interval: stats.confidence_interval,
+        pass_count: stats.pass_count,
+        fail_count: stats.fail_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SpeakerTurn, SttError, TimedWord};
+
+    struct FixedTranscriber(&'static str);
+
+    impl Transcriber for FixedTranscriber {
+        fn name(&self) -> String {
+            "fixed".to_string()
+        }
+
+        fn transcribe(&self, _case: &SttCase) -> Result<Transcription, SttError> {
+            let words = self
+                .0
+                .split_whitespace()
+                .enumerate()
+                .map(|(i, text)| TimedWord {
+                    text: text.to_string(),
+                    start: i as f64,
+                    end: i as f64 + 1.0,
+                    speaker: Some(if i < 2 { "0" } else { "1" }.to_string()),
+                })
+                .collect();
+
+            Ok(Transcription {
+                transcript: self.0.to_string(),
+                words,
+                latency_to_final_ms: Some(250.0),
+                elapsed_ms: 1.0,
+            })
+        }
+    }
+
+    #[test]
+    fn test_execute_scores_transcriptions() {
+        let audio = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(audio.path(), b"audio").unwrap();
+
+        let case = SttCase {
+            case_id: "case".to_string(),
+            audio_path: audio.path().to_path_buf(),
+            languages: vec![],
+            reference: "hello there general kenobi".to_string(),
+            speakers: vec![
+                SpeakerTurn {
+                    start: 0.0,
+                    end: 2.0,
+                    speaker: "a".to_string(),
+                },
+                SpeakerTurn {
+                    start: 2.0,
+                    end: 4.0,
+                    speaker: "b".to_string(),
+                },
+            ],
+            samples: 2,
+            max_wer: 0.2,
+            max_der: 0.1,
+        };
+
+        let transcribers: Vec<Arc<dyn Transcriber>> =
+            vec![Arc::new(FixedTranscriber("Hello there, general Kenobi."))];
+        let executor = SttExecutor::new().without_cache().with_concurrency(1);
+        assert_eq!(
+            executor.total_transcriptions(std::slice::from_ref(&case), &transcribers),
+            2
+        );
+
+        let results = executor.execute(&[case], &transcribers);
+        assert_eq!(results.len(), 1);
+
+        let result = &results[0];
+        assert!(result.error.is_none());
+        assert_eq!(result.transcriptions.len(), 2);
+        assert_eq!(result.wer.mean, 0.0);
+        assert_eq!(result.wer.samples, 2);
+        assert!(result.der.as_ref().unwrap().mean < 1e-9);
+        assert_eq!(result.latency_to_final_ms.as_ref().unwrap().mean, 250.0);
+
+        let eval = result.to_eval_result();
+        assert_eq!(eval.model, "fixed");
+        assert!(eval.all_passed());
+        assert_eq!(eval.tally_score(), (2, 2));
+    }
+}
@@ -0,0 +1,350 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+use hypr_audio_utils::AudioFormatExt;
+use owhisper_client::{
+    AssemblyAIAdapter, BatchClient, BatchSttAdapter, DeepgramAdapter, ElevenLabsAdapter,
+    FinalizeHandle, FireworksAdapter, GladiaAdapter, ListenClient, OpenAIAdapter, Provider,
+    RealtimeSttAdapter, RevAiAdapter, SonioxAdapter, SpeechmaticsAdapter,
+};
+use owhisper_interface::stream::StreamResponse;
+use owhisper_interface::{ListenParams, MixedMessage};
+
+use super::{SttCase, SttError, TimedWord, Transcription};
+
+const CHUNK_MS: u64 = 100;
+// How long to wait for outstanding finals after the last chunk is sent.
+const FINALIZE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Something that turns a case's audio into a transcript. Implemented for
+/// every batch and realtime adapter; local whisper/moonshine servers speak the
+/// Deepgram protocol and go through [`DeepgramAdapter`] with their own
+/// `api_base`.
+pub trait Transcriber: Send + Sync {
+    /// Identifies the transcriber in reports and cache keys, so it should
+    /// change whenever the provider, model or mode does.
+    fn name(&self) -> String;
+
+    fn transcribe(&self, case: &SttCase) -> Result<Transcription, SttError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SttMode {
+    Batch,
+    Realtime,
+}
+
+impl std::fmt::Display for SttMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SttMode::Batch => write!(f, "batch"),
+            SttMode::Realtime => write!(f, "realtime"),
+        }
+    }
+}
+
+/// A transcriber for a hosted provider at its default endpoint and model.
+pub fn provider_transcriber(
+    provider: Provider,
+    mode: SttMode,
+    api_key: String,
+) -> Arc<dyn Transcriber> {
+    match provider {
+        Provider::Deepgram => build::<DeepgramAdapter>(provider, mode, api_key),
+        Provider::AssemblyAI => build::<AssemblyAIAdapter>(provider, mode, api_key),
+        Provider::Soniox => build::<SonioxAdapter>(provider, mode, api_key),
+        Provider::Fireworks => build::<FireworksAdapter>(provider, mode, api_key),
+        Provider::OpenAI => build::<OpenAIAdapter>(provider, mode, api_key),
+        Provider::Gladia => build::<GladiaAdapter>(provider, mode, api_key),
+        Provider::ElevenLabs => build::<ElevenLabsAdapter>(provider, mode, api_key),
+        Provider::Speechmatics => build::<SpeechmaticsAdapter>(provider, mode, api_key),
+        Provider::RevAi => build::<RevAiAdapter>(provider, mode, api_key),
+    }
+}
+
+fn build<A: RealtimeSttAdapter + BatchSttAdapter + 'static>(
+    provider: Provider,
+    mode: SttMode,
+    api_key: String,
+) -> Arc<dyn Transcriber> {
+    match mode {
+        SttMode::Batch => Arc::new(
+            BatchTranscriber::<A>::new(provider.to_string(), provider.default_api_base(), api_key)
+                .with_model(provider.default_batch_model()),
+        ),
+        SttMode::Realtime => Arc::new(
+            RealtimeTranscriber::<A>::new(
+                provider.to_string(),
+                provider.default_api_base(),
+                api_key,
+            )
+            .with_model(provider.default_live_model())
+            .with_sample_rate(provider.default_live_sample_rate()),
+        ),
+    }
+}
+
+fn runtime() -> Result<tokio::runtime::Runtime, SttError> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| SttError::Transcription(e.to_string()))
+}
+
+fn listen_params(model: &Option<String>, sample_rate: u32, case: &SttCase) -> ListenParams {
+    ListenParams {
+        model: model.clone(),
+        sample_rate,
+        languages: case.languages.clone(),
+        ..Default::default()
+    }
+}
+
+pub struct BatchTranscriber<A: BatchSttAdapter> {
+    label: String,
+    api_base: String,
+    api_key: String,
+    model: Option<String>,
+    _adapter: PhantomData<fn() -> A>,
+}
+
+impl<A: BatchSttAdapter> BatchTranscriber<A> {
+    pub fn new(
+        label: impl Into<String>,
+        api_base: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            api_base: api_base.into(),
+            api_key: api_key.into(),
+            model: None,
+            _adapter: PhantomData,
+        }
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+}
+
+impl<A: BatchSttAdapter> Transcriber for BatchTranscriber<A> {
+    fn name(&self) -> String {
+        match &self.model {
+            Some(model) => format!("{}/{} (batch)", self.label, model),
+            None => format!("{} (batch)", self.label),
+        }
+    }
+
+    fn transcribe(&self, case: &SttCase) -> Result<Transcription, SttError> {
+        let client = BatchClient::<A>::builder()
+            .api_base(&self.api_base)
+            .api_key(&self.api_key)
+            .params(listen_params(&self.model, 16000, case))
+            .build();
+
+        let started = Instant::now();
+        let response = runtime()?
+            .block_on(client.transcribe_file(&case.audio_path))
+            .map_err(|e| SttError::Transcription(e.to_string()))?;
+        let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let Some(alternative) = response
+            .results
+            .channels
+            .into_iter()
+            .next()
+            .and_then(|channel| channel.alternatives.into_iter().next())
+        else {
+            return Ok(Transcription {
+                elapsed_ms,
+                ..Default::default()
+            });
+        };
+
+        Ok(Transcription {
+            transcript: alternative.transcript,
+            words: alternative
+                .words
+                .into_iter()
+                .map(|w| TimedWord {
+                    text: w.punctuated_word.unwrap_or(w.word),
+                    start: w.start,
+                    end: w.end,
+                    speaker: w.speaker.map(|s| s.to_string()),
+                })
+                .collect(),
+            latency_to_final_ms: None,
+            elapsed_ms,
+        })
+    }
+}
+
+pub struct RealtimeTranscriber<A: RealtimeSttAdapter> {
+    label: String,
+    api_base: String,
+    api_key: String,
+    model: Option<String>,
+    sample_rate: u32,
+    _adapter: PhantomData<fn() -> A>,
+}
+
+impl<A: RealtimeSttAdapter> RealtimeTranscriber<A> {
+    pub fn new(
+        label: impl Into<String>,
+        api_base: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            api_base: api_base.into(),
+            api_key: api_key.into(),
+            model: None,
+            sample_rate: 16000,
+            _adapter: PhantomData,
+        }
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    // Streams the audio in real time, then finalizes and waits for the
+    // remaining results. Latency is measured per final result, from when the
+    // end of its audio was sent to when it arrived.
+    async fn stream(&self, case: &SttCase) -> Result<Transcription, SttError> {
+        let file = std::fs::File::open(&case.audio_path)
+            .map_err(|e| SttError::Audio(format!("{}: {}", case.audio_path.display(), e)))?;
+        let decoder = rodio::Decoder::new(std::io::BufReader::new(file))
+            .map_err(|e| SttError::Audio(e.to_string()))?;
+        let chunk_samples = (self.sample_rate as u64 * CHUNK_MS / 1000) as usize;
+        let chunks: Vec<_> = decoder
+            .to_i16_le_chunks(self.sample_rate, chunk_samples)
+            .collect()
+            .await;
+
+        let client = ListenClient::builder()
+            .adapter::<A>()
+            .api_base(&self.api_base)
+            .api_key(&self.api_key)
+            .params(listen_params(&self.model, self.sample_rate, case))
+            .build_single()
+            .await;
+
+        let (tx, rx) = tokio::sync::mpsc::channel(chunks.len().max(1));
+        let (responses, handle) = client
+            .from_realtime_audio(tokio_stream::wrappers::ReceiverStream::new(rx))
+            .await
+            .map_err(|e| SttError::Transcription(e.to_string()))?;
+        futures_util::pin_mut!(responses);
+
+        let started = Instant::now();
+        let feeder_tx = tx.clone();
+        let mut feeder = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(CHUNK_MS));
+            for chunk in chunks {
+                interval.tick().await;
+                if feeder_tx.send(MixedMessage::Audio(chunk)).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut transcript = Vec::new();
+        let mut words = Vec::new();
+        let mut latencies = Vec::new();
+        let mut deadline = None;
+
+        loop {
+            let timeout = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                _ = &mut feeder, if deadline.is_none() => {
+                    handle.finalize().await;
+                    deadline = Some(tokio::time::Instant::now() + FINALIZE_TIMEOUT);
+                }
+                _ = timeout => {
+                    if transcript.is_empty() {
+                        return Err(SttError::Timeout);
+                    }
+                    break;
+                }
+                response = responses.next() => {
+                    let Some(response) = response else { break };
+                    let response =
+                        response.map_err(|e| SttError::Transcription(e.to_string()))?;
+
+                    let StreamResponse::TranscriptResponse {
+                        start,
+                        duration,
+                        is_final,
+                        from_finalize,
+                        channel,
+                        ..
+                    } = response
+                    else {
+                        continue;
+                    };
+
+                    if is_final {
+                        if let Some(alternative) = channel.alternatives.into_iter().next() {
+                            if !alternative.transcript.trim().is_empty() {
+                                let sent_at = (start + duration) * 1000.0;
+                                let received_at = started.elapsed().as_secs_f64() * 1000.0;
+                                latencies.push((received_at - sent_at).max(0.0));
+
+                                transcript.push(alternative.transcript);
+                                words.extend(alternative.words.into_iter().map(|w| TimedWord {
+                                    text: w.punctuated_word.unwrap_or(w.word),
+                                    start: w.start,
+                                    end: w.end,
+                                    speaker: w.speaker.map(|s| s.to_string()),
+                                }));
+                            }
+                        }
+                    }
+
+                    if from_finalize {
+                        break;
+                    }
+                }
+            }
+        }
+        drop(tx);
+
+        Ok(Transcription {
+            transcript: transcript.join(" "),
+            words,
+            latency_to_final_ms: (!latencies.is_empty())
+                .then(|| latencies.iter().sum::<f64>() / latencies.len() as f64),
+            elapsed_ms: started.elapsed().as_secs_f64() * 1000.0,
+        })
+    }
+}
+
+impl<A: RealtimeSttAdapter> Transcriber for RealtimeTranscriber<A> {
+    fn name(&self) -> String {
+        match &self.model {
+            Some(model) => format!("{}/{} (realtime)", self.label, model),
+            None => format!("{} (realtime)", self.label),
+        }
+    }
+
+    fn transcribe(&self, case: &SttCase) -> Result<Transcription, SttError> {
+        runtime()?.block_on(self.stream(case))
+    }
+}
@@ -0,0 +1,401 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use hypr_importer_core::ir::{Collection, Session, Transcript};
+use serde::{Deserialize, Serialize};
+
+use crate::writer::Sink;
+use crate::{MANIFEST_FILE, Manifest, Result, TranscriptFormat, markdown, transcript};
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Sessions to export; `None` exports all of them.
+    pub session_ids: Option<Vec<String>>,
+    pub transcript_formats: Vec<TranscriptFormat>,
+    /// Recordings to copy next to each session, keyed by session id.
+    pub audio: HashMap<String, PathBuf>,
+    /// Write a single zip file at the destination instead of a directory.
+    pub zip: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    pub path: String,
+    pub sessions_count: usize,
+    pub transcripts_count: usize,
+    pub audio_count: usize,
+}
+
+/// Writes one directory per session, holding the memo and enhanced notes as
+/// Markdown with frontmatter, the transcript in each requested format, and the
+/// recording. `manifest.json` at the root keeps every row, for re-importing.
+pub fn export_sessions(
+    collection: &Collection,
+    options: &ExportOptions,
+    dest: &Path,
+) -> Result<ExportSummary> {
+    let collection = match &options.session_ids {
+        Some(ids) => select_sessions(collection, ids),
+        None => collection.clone(),
+    };
+
+    let mut sink = if options.zip {
+        Sink::zip(dest)?
+    } else {
+        Sink::directory(dest)?
+    };
+
+    let mut used = HashSet::new();
+    let mut audio_count = 0;
+
+    for session in &collection.sessions {
+        let dir = format!("sessions/{}", unique_name(&dir_name(session), &mut used));
+
+        sink.write(
+            &format!("{}/memo.md", dir),
+            markdown::memo(session, &collection)?.as_bytes(),
+        )?;
+
+        let mut note_names = HashSet::new();
+        for (title, content) in markdown::enhanced_notes(session, &collection)? {
+            let name = unique_name(&sanitize(&title), &mut note_names);
+            sink.write(&format!("{}/enhanced/{}.md", dir, name), content.as_bytes())?;
+        }
+
+        let transcripts: Vec<&Transcript> = collection
+            .transcripts
+            .iter()
+            .filter(|t| t.session_id == session.id)
+            .collect();
+
+        if transcripts.iter().any(|t| !t.words.is_empty()) {
+            for format in &options.transcript_formats {
+                sink.write(
+                    &format!("{}/transcript.{}", dir, format.extension()),
+                    &transcript::render(*format, session, &transcripts)?,
+                )?;
+            }
+        }
+
+        // A session recorded on another device has no local audio; that is not an error.
+        if let Some(audio) = options.audio.get(&session.id).filter(|p| p.is_file()) {
+            let extension = audio.extension().and_then(|e| e.to_str()).unwrap_or("wav");
+            sink.copy(&format!("{}/audio.{}", dir, extension), audio)?;
+            audio_count += 1;
+        }
+    }
+
+    let summary = ExportSummary {
+        path: dest.to_string_lossy().to_string(),
+        sessions_count: collection.sessions.len(),
+        transcripts_count: collection.transcripts.len(),
+        audio_count,
+    };
+
+    sink.write(
+        MANIFEST_FILE,
+        &serde_json::to_vec_pretty(&Manifest::new(collection))?,
+    )?;
+    sink.finish()?;
+
+    Ok(summary)
+}
+
+/// Keeps the given sessions and every row they refer to, so the result
+/// imports on its own.
+pub fn select_sessions(collection: &Collection, session_ids: &[String]) -> Collection {
+    let ids: HashSet<&str> = session_ids.iter().map(String::as_str).collect();
+    let in_selection = |session_id: &str| ids.contains(session_id);
+
+    let participants: Vec<_> = collection
+        .participants
+        .iter()
+        .filter(|p| in_selection(&p.session_id))
+        .cloned()
+        .collect();
+    let humans: Vec<_> = collection
+        .humans
+        .iter()
+        .filter(|h| participants.iter().any(|p| p.human_id == h.id))
+        .cloned()
+        .collect();
+    let enhanced_notes: Vec<_> = collection
+        .enhanced_notes
+        .iter()
+        .filter(|n| in_selection(&n.session_id))
+        .cloned()
+        .collect();
+    let tag_mappings: Vec<_> = collection
+        .tag_mappings
+        .iter()
+        .filter(|m| in_selection(&m.session_id))
+        .cloned()
+        .collect();
+
+    Collection {
+        sessions: collection
+            .sessions
+            .iter()
+            .filter(|s| in_selection(&s.id))
+            .cloned()
+            .collect(),
+        transcripts: collection
+            .transcripts
+            .iter()
+            .filter(|t| in_selection(&t.session_id))
+            .cloned()
+            .collect(),
+        organizations: collection
+            .organizations
+            .iter()
+            .filter(|o| humans.iter().any(|h| h.org_id.as_ref() == Some(&o.id)))
+            .cloned()
+            .collect(),
+        templates: collection
+            .templates
+            .iter()
+            .filter(|t| {
+                enhanced_notes
+                    .iter()
+                    .any(|n| n.template_id.as_ref() == Some(&t.id))
+            })
+            .cloned()
+            .collect(),
+        tags: collection
+            .tags
+            .iter()
+            .filter(|t| tag_mappings.iter().any(|m| m.tag_id == t.id))
+            .cloned()
+            .collect(),
+        humans,
+        participants,
+        enhanced_notes,
+        tag_mappings,
+    }
+}
+
+// `2025-03-04 Weekly sync`, so the directory listing reads like a timeline.
+fn dir_name(session: &Session) -> String {
+    let date = session
+        .created_at
+        .get(..10)
+        .filter(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").is_ok());
+    let title = sanitize(&session.title);
+
+    match date {
+        Some(date) => format!("{} {}", date, title),
+        None => title,
+    }
+}
+
+fn sanitize(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .take(80)
+        .collect();
+
+    let trimmed = cleaned.trim().trim_matches('.').trim();
+    if trimmed.is_empty() {
+        "Untitled".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn unique_name(name: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.to_string();
+    let mut n = 2;
+    while !used.insert(candidate.to_lowercase()) {
+        candidate = format!("{} ({})", name, n);
+        n += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_manifest;
+    use hypr_importer_core::ir::{EnhancedNote, Human, SessionParticipant, Word};
+
+    fn collection() -> Collection {
+        let session = |id: &str, title: &str| Session {
+            id: id.to_string(),
+            user_id: String::new(),
+            created_at: "2025-03-04T10:00:00Z".to_string(),
+            title: title.to_string(),
+            raw_md: Some("- agenda".to_string()),
+            enhanced_content: None,
+            folder_id: Some("work/planning".to_string()),
+            event_id: None,
+        };
+
+        let words = ["Let's", "start.", "Sounds", "good."]
+            .iter()
+            .enumerate()
+            .map(|(i, text)| Word {
+                id: format!("w{}", i),
+                text: text.to_string(),
+                start_ms: Some(i as f64 * 1_000.0),
+                end_ms: Some(i as f64 * 1_000.0 + 800.0),
+                channel: 0,
+                speaker: Some(if i < 2 { "Alice" } else { "Bob" }.to_string()),
+            })
+            .collect();
+
+        Collection {
+            sessions: vec![session("s1", "Weekly: sync"), session("s2", "Weekly: sync")],
+            transcripts: vec![Transcript {
+                id: "t1".to_string(),
+                user_id: String::new(),
+                created_at: String::new(),
+                session_id: "s1".to_string(),
+                title: String::new(),
+                started_at: 0.0,
+                ended_at: None,
+                start_ms: Some(0.0),
+                end_ms: Some(3_800.0),
+                words,
+                speaker_hints: vec![],
+            }],
+            humans: vec![Human {
+                id: "h1".to_string(),
+                user_id: String::new(),
+                created_at: String::new(),
+                name: "Alice".to_string(),
+                email: None,
+                org_id: None,
+                job_title: None,
+                linkedin_username: None,
+            }],
+            participants: vec![SessionParticipant {
+                id: "p1".to_string(),
+                user_id: String::new(),
+                session_id: "s1".to_string(),
+                human_id: "h1".to_string(),
+                source: "manual".to_string(),
+            }],
+            enhanced_notes: vec![EnhancedNote {
+                id: "e1".to_string(),
+                user_id: String::new(),
+                session_id: "s1".to_string(),
+                content: "## Decisions".to_string(),
+                template_id: None,
+                position: 0,
+                title: String::new(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_export_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let audio = dir.path().join("recording.ogg");
+        std::fs::write(&audio, b"OggS").unwrap();
+
+        let dest = dir.path().join("export");
+        let summary = export_sessions(
+            &collection(),
+            &ExportOptions {
+                transcript_formats: vec![TranscriptFormat::Txt, TranscriptFormat::Srt],
+                audio: HashMap::from([("s1".to_string(), audio)]),
+                ..Default::default()
+            },
+            &dest,
+        )
+        .unwrap();
+        assert_eq!(summary.sessions_count, 2);
+        assert_eq!(summary.audio_count, 1);
+
+        let session_dir = dest.join("sessions/2025-03-04 Weekly_ sync");
+        assert!(
+            dest.join("sessions/2025-03-04 Weekly_ sync (2)/memo.md")
+                .exists()
+        );
+        assert!(session_dir.join("audio.ogg").exists());
+        assert!(session_dir.join("enhanced/Summary.md").exists());
+
+        let memo = std::fs::read_to_string(session_dir.join("memo.md")).unwrap();
+        assert!(memo.starts_with("---\n"));
+        assert!(memo.contains("participants:\n- Alice"));
+        assert!(memo.contains("folder: work/planning"));
+        assert!(memo.ends_with("- agenda"));
+
+        // The TXT and SRT exports read back through the transcript importer.
+        for name in ["transcript.txt", "transcript.srt"] {
+            let parsed = hypr_transcript_import::parse_file(&session_dir.join(name)).unwrap();
+            let turns: Vec<_> = parsed
+                .segments
+                .iter()
+                .map(|s| (s.speaker.as_deref(), s.text.as_str()))
+                .collect();
+            assert_eq!(
+                turns,
+                vec![
+                    (Some("Alice"), "Let's start."),
+                    (Some("Bob"), "Sounds good.")
+                ]
+            );
+        }
+
+        let manifest = read_manifest(&dest).unwrap();
+        assert_eq!(manifest.version, 1);
+        assert_eq!(manifest.collection.sessions.len(), 2);
+        assert_eq!(manifest.collection.transcripts[0].words.len(), 4);
+    }
+
+    #[test]
+    fn test_export_zip_selection() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("export.zip");
+
+        export_sessions(
+            &collection(),
+            &ExportOptions {
+                session_ids: Some(vec!["s2".to_string()]),
+                transcript_formats: vec![TranscriptFormat::Docx],
+                zip: true,
+                ..Default::default()
+            },
+            &dest,
+        )
+        .unwrap();
+
+        let manifest = read_manifest(&dest).unwrap().collection;
+        assert_eq!(manifest.sessions.len(), 1);
+        assert!(manifest.transcripts.is_empty());
+        assert!(manifest.humans.is_empty());
+        assert!(manifest.enhanced_notes.is_empty());
+    }
+
+    #[test]
+    fn test_docx_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("export");
+
+        export_sessions(
+            &collection(),
+            &ExportOptions {
+                session_ids: Some(vec!["s1".to_string()]),
+                transcript_formats: vec![TranscriptFormat::Docx, TranscriptFormat::Json],
+                ..Default::default()
+            },
+            &dest,
+        )
+        .unwrap();
+
+        let session_dir = dest.join("sessions/2025-03-04 Weekly_ sync");
+        for name in ["transcript.docx", "transcript.json"] {
+            let parsed = hypr_transcript_import::parse_file(&session_dir.join(name)).unwrap();
+            assert_eq!(parsed.segments.len(), 2);
+            assert_eq!(parsed.segments[1].speaker.as_deref(), Some("Bob"));
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod archive;
+mod error;
+mod manifest;
+mod markdown;
+mod transcript;
+mod writer;
+
+pub use archive::*;
+pub use error::*;
+pub use manifest::*;
+pub use transcript::TranscriptFormat;
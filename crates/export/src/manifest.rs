@@ -0,0 +1,60 @@
+use std::io::Read;
+use std::path::Path;
+
+use hypr_importer_core::ir::Collection;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// The tables of every exported session, in the shape the `AsIs` importer reads.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: String,
+    #[serde(flatten)]
+    pub collection: Collection,
+}
+
+impl Manifest {
+    pub fn new(collection: Collection) -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            collection,
+        }
+    }
+}
+
+/// Accepts an exported directory, an exported zip, or the manifest file itself.
+pub fn read_manifest(path: &Path) -> Result<Manifest> {
+    let content = if path.is_dir() {
+        let manifest = path.join(MANIFEST_FILE);
+        if !manifest.exists() {
+            return Err(Error::ManifestNotFound(path.display().to_string()));
+        }
+        std::fs::read_to_string(manifest)?
+    } else if is_zip(path) {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(path)?)?;
+        let mut entry = archive
+            .by_name(MANIFEST_FILE)
+            .map_err(|_| Error::ManifestNotFound(path.display().to_string()))?;
+
+        let mut content = String::new();
+        entry.read_to_string(&mut content)?;
+        content
+    } else {
+        std::fs::read_to_string(path)?
+    };
+
+    Ok(serde_json::from_str(&content)?)
+}
+
+pub fn is_zip(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+}
@@ -0,0 +1,114 @@
+use hypr_frontmatter::Document;
+use hypr_importer_core::ir::{Collection, EnhancedNote, Session};
+use serde::Serialize;
+
+use crate::Result;
+
+#[derive(Serialize)]
+struct MemoFrontmatter<'a> {
+    id: &'a str,
+    title: &'a str,
+    created_at: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folder: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    participants: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct EnhancedFrontmatter<'a> {
+    id: &'a str,
+    session_id: &'a str,
+    title: &'a str,
+    position: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<&'a str>,
+}
+
+pub(crate) fn memo(session: &Session, collection: &Collection) -> Result<String> {
+    let participants = collection
+        .participants
+        .iter()
+        .filter(|p| p.session_id == session.id)
+        .filter_map(|p| collection.humans.iter().find(|h| h.id == p.human_id))
+        .map(|h| h.name.as_str())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    let tags = collection
+        .tag_mappings
+        .iter()
+        .filter(|m| m.session_id == session.id)
+        .filter_map(|m| collection.tags.iter().find(|t| t.id == m.tag_id))
+        .map(|t| t.name.as_str())
+        .collect();
+
+    let frontmatter = MemoFrontmatter {
+        id: &session.id,
+        title: &session.title,
+        created_at: &session.created_at,
+        folder: session.folder_id.as_deref().filter(|f| !f.is_empty()),
+        participants,
+        tags,
+    };
+
+    Ok(Document::new(frontmatter, session.raw_md.clone().unwrap_or_default()).render()?)
+}
+
+/// Returns `(title, markdown)` for every enhanced note of the session, in order.
+/// Sessions from older imports only carry a single `enhanced_content`.
+pub(crate) fn enhanced_notes(
+    session: &Session,
+    collection: &Collection,
+) -> Result<Vec<(String, String)>> {
+    let mut notes: Vec<&EnhancedNote> = collection
+        .enhanced_notes
+        .iter()
+        .filter(|n| n.session_id == session.id)
+        .collect();
+    notes.sort_by_key(|n| n.position);
+
+    if notes.is_empty() {
+        return Ok(session
+            .enhanced_content
+            .as_ref()
+            .filter(|c| !c.trim().is_empty())
+            .map(|content| ("Summary".to_string(), content.clone()))
+            .into_iter()
+            .collect());
+    }
+
+    notes
+        .into_iter()
+        .map(|note| {
+            let title = if note.title.trim().is_empty() {
+                "Summary"
+            } else {
+                note.title.trim()
+            };
+
+            let template = note.template_id.as_deref().and_then(|id| {
+                collection
+                    .templates
+                    .iter()
+                    .find(|t| t.id == id)
+                    .map(|t| t.title.as_str())
+            });
+
+            let frontmatter = EnhancedFrontmatter {
+                id: &note.id,
+                session_id: &note.session_id,
+                title,
+                position: note.position,
+                template,
+            };
+
+            Ok((
+                title.to_string(),
+                Document::new(frontmatter, note.content.clone()).render()?,
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,330 @@
+use std::io::Write;
+
+use aspasia::{Moment, SubRipSubtitle, WebVttSubtitle, webvtt::WebVttCue};
+use hypr_importer_core::ir::{Session, Transcript};
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+
+// Subtitle cues are kept short enough to read along while listening.
+const MAX_CUE_MS: f64 = 7_000.0;
+const MAX_CUE_GAP_MS: f64 = 1_500.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptFormat {
+    Json,
+    Srt,
+    Vtt,
+    Txt,
+    Docx,
+}
+
+impl TranscriptFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Txt => "txt",
+            Self::Docx => "docx",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Cue {
+    pub speaker: String,
+    pub start_ms: f64,
+    pub end_ms: f64,
+    pub text: String,
+}
+
+pub(crate) fn render(
+    format: TranscriptFormat,
+    session: &Session,
+    transcripts: &[&Transcript],
+) -> Result<Vec<u8>> {
+    let bytes = match format {
+        TranscriptFormat::Json => to_json(session, &cues(transcripts, None))?,
+        TranscriptFormat::Srt => to_srt(&cues(transcripts, Some(MAX_CUE_MS))).into_bytes(),
+        TranscriptFormat::Vtt => to_vtt(&cues(transcripts, Some(MAX_CUE_MS))).into_bytes(),
+        TranscriptFormat::Txt => to_txt(&cues(transcripts, None)).into_bytes(),
+        TranscriptFormat::Docx => to_docx(&cues(transcripts, None))?,
+    };
+    Ok(bytes)
+}
+
+/// Groups the words of every transcript of a session into cues, on the timeline
+/// of the first recording. Without `max_ms`, a cue runs for a whole speaker turn.
+pub(crate) fn cues(transcripts: &[&Transcript], max_ms: Option<f64>) -> Vec<Cue> {
+    let mut transcripts = transcripts.to_vec();
+    transcripts.sort_by(|a, b| a.started_at.total_cmp(&b.started_at));
+    let first_started_at = transcripts.first().map(|t| t.started_at).unwrap_or(0.0);
+
+    let mut words = vec![];
+    for transcript in transcripts {
+        let offset = if transcript.started_at > 0.0 && first_started_at > 0.0 {
+            transcript.started_at - first_started_at
+        } else {
+            0.0
+        };
+
+        let mut last_end = 0.0;
+        for word in &transcript.words {
+            let text = word.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            let start = word.start_ms.unwrap_or(last_end);
+            let end = word.end_ms.unwrap_or(start).max(start);
+            last_end = end;
+
+            let speaker = word
+                .speaker
+                .as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Speaker {}", word.channel + 1));
+
+            words.push((speaker, start + offset, end + offset, text));
+        }
+    }
+    words.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let mut cues: Vec<Cue> = vec![];
+    for (speaker, start, end, text) in words {
+        match cues.last_mut() {
+            Some(cue)
+                if cue.speaker == speaker
+                    && max_ms.is_none_or(|max| {
+                        end - cue.start_ms <= max && start - cue.end_ms <= MAX_CUE_GAP_MS
+                    }) =>
+            {
+                cue.text.push(' ');
+                cue.text.push_str(text);
+                cue.end_ms = cue.end_ms.max(end);
+            }
+            _ => cues.push(Cue {
+                speaker,
+                start_ms: start,
+                end_ms: end,
+                text: text.to_string(),
+            }),
+        }
+    }
+
+    cues
+}
+
+#[derive(Serialize)]
+struct JsonTranscript<'a> {
+    title: &'a str,
+    date: &'a str,
+    segments: &'a [Cue],
+}
+
+// Same field names as the transcript importer reads.
+fn to_json(session: &Session, cues: &[Cue]) -> Result<Vec<u8>> {
+    Ok(serde_json::to_vec_pretty(&JsonTranscript {
+        title: &session.title,
+        date: &session.created_at,
+        segments: cues,
+    })?)
+}
+
+fn to_vtt(cues: &[Cue]) -> String {
+    subtitles(cues, |cue| format!("<v {}>{}", cue.speaker, cue.text)).to_string()
+}
+
+// SubRip has no voice spans, so the speaker goes in front of the text.
+fn to_srt(cues: &[Cue]) -> String {
+    let vtt = subtitles(cues, |cue| format!("{}: {}", cue.speaker, cue.text));
+    SubRipSubtitle::from(&vtt).to_string()
+}
+
+fn subtitles(cues: &[Cue], text: impl Fn(&Cue) -> String) -> WebVttSubtitle {
+    let cues = cues
+        .iter()
+        .enumerate()
+        .map(|(i, cue)| WebVttCue {
+            identifier: Some((i + 1).to_string()),
+            text: text(cue),
+            settings: None,
+            start: Moment::from(cue.start_ms as i64),
+            end: Moment::from(cue.end_ms as i64),
+        })
+        .collect();
+
+    WebVttSubtitle::builder().cues(cues).build()
+}
+
+fn to_txt(cues: &[Cue]) -> String {
+    lines(cues).map(|line| line + "\n").collect()
+}
+
+fn lines(cues: &[Cue]) -> impl Iterator<Item = String> + '_ {
+    cues.iter().map(|cue| {
+        format!(
+            "[{}] {}: {}",
+            timestamp(cue.start_ms),
+            cue.speaker,
+            cue.text
+        )
+    })
+}
+
+fn timestamp(ms: f64) -> String {
+    let seconds = (ms / 1000.0) as u64;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/></Types>"#;
+
+const RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+/// The smallest package Word opens: one paragraph per line of the TXT export.
+fn to_docx(cues: &[Cue]) -> Result<Vec<u8>> {
+    let body: String = lines(cues)
+        .map(|line| {
+            format!(
+                r#"<w:p><w:r><w:t xml:space="preserve">{}</w:t></w:r></w:p>"#,
+                escape_xml(&line)
+            )
+        })
+        .collect();
+
+    let document = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}</w:body></w:document>"#,
+        body
+    );
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    for (name, content) in [
+        ("[Content_Types].xml", CONTENT_TYPES),
+        ("_rels/.rels", RELS),
+        ("word/document.xml", document.as_str()),
+    ] {
+        zip.start_file(name, options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_importer_core::ir::Word;
+
+    fn word(text: &str, start_ms: f64, speaker: Option<&str>, channel: i64) -> Word {
+        Word {
+            id: text.to_string(),
+            text: format!(" {}", text),
+            start_ms: Some(start_ms),
+            end_ms: Some(start_ms + 400.0),
+            channel,
+            speaker: speaker.map(str::to_string),
+        }
+    }
+
+    fn transcript(started_at: f64, words: Vec<Word>) -> Transcript {
+        Transcript {
+            id: started_at.to_string(),
+            user_id: String::new(),
+            created_at: String::new(),
+            session_id: "s".to_string(),
+            title: String::new(),
+            started_at,
+            ended_at: None,
+            start_ms: None,
+            end_ms: None,
+            words,
+            speaker_hints: vec![],
+        }
+    }
+
+    #[test]
+    fn test_cues() {
+        let first = transcript(
+            1_000_000.0,
+            vec![
+                word("Hello", 0.0, Some("Alice"), 0),
+                word("there.", 500.0, Some("Alice"), 0),
+                word("Hi.", 1_000.0, None, 1),
+            ],
+        );
+        // A second recording of the same session, started ten seconds later.
+        let second = transcript(1_010_000.0, vec![word("Back.", 0.0, Some("Alice"), 0)]);
+
+        let turns = cues(&[&second, &first], None);
+        assert_eq!(
+            turns
+                .iter()
+                .map(|c| (c.speaker.as_str(), c.text.as_str(), c.start_ms))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Alice", "Hello there.", 0.0),
+                ("Speaker 2", "Hi.", 1_000.0),
+                ("Alice", "Back.", 10_000.0),
+            ]
+        );
+
+        let long = transcript(
+            0.0,
+            (0..20)
+                .map(|i| word("word", i as f64 * 1_000.0, Some("Alice"), 0))
+                .collect(),
+        );
+        assert_eq!(cues(&[&long], None).len(), 1);
+        assert!(cues(&[&long], Some(MAX_CUE_MS)).len() > 1);
+    }
+
+    #[test]
+    fn test_text_formats() {
+        let cues = vec![
+            Cue {
+                speaker: "Alice".to_string(),
+                start_ms: 1_000.0,
+                end_ms: 2_500.0,
+                text: "Ship it & see.".to_string(),
+            },
+            Cue {
+                speaker: "Bob".to_string(),
+                start_ms: 3_723_000.0,
+                end_ms: 3_724_000.0,
+                text: "Agreed.".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            to_txt(&cues),
+            "[00:00:01] Alice: Ship it & see.\n[01:02:03] Bob: Agreed.\n"
+        );
+
+        let vtt = to_vtt(&cues);
+        assert!(vtt.starts_with("WEBVTT"));
+        assert!(vtt.contains("00:00:01.000 --> 00:00:02.500\n<v Alice>Ship it & see."));
+
+        let srt = to_srt(&cues);
+        assert!(srt.contains("1\n00:00:01,000 --> 00:00:02,500\nAlice: Ship it & see."));
+    }
+}
@@ -0,0 +1,75 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use zip::CompressionMethod;
+use zip::write::{SimpleFileOptions, ZipWriter};
+
+use crate::Result;
+
+/// Where archive entries go: a plain directory, or a single zip file.
+pub(crate) enum Sink {
+    Directory(PathBuf),
+    Zip(Box<ZipWriter<File>>),
+}
+
+impl Sink {
+    pub fn directory(root: &Path) -> Result<Self> {
+        std::fs::create_dir_all(root)?;
+        Ok(Self::Directory(root.to_path_buf()))
+    }
+
+    pub fn zip(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(Self::Zip(Box::new(ZipWriter::new(File::create(path)?))))
+    }
+
+    pub fn write(&mut self, name: &str, bytes: &[u8]) -> Result<()> {
+        match self {
+            Self::Directory(root) => {
+                let path = root.join(name);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(path, bytes)?;
+            }
+            Self::Zip(zip) => {
+                zip.start_file(name, options(CompressionMethod::Deflated))?;
+                std::io::Write::write_all(zip.as_mut(), bytes)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Audio is already compressed (or too large to be worth it), so it is stored as is.
+    pub fn copy(&mut self, name: &str, from: &Path) -> Result<()> {
+        match self {
+            Self::Directory(root) => {
+                let path = root.join(name);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::copy(from, path)?;
+            }
+            Self::Zip(zip) => {
+                zip.start_file(name, options(CompressionMethod::Stored))?;
+                std::io::copy(&mut File::open(from)?, zip.as_mut())?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<()> {
+        if let Self::Zip(zip) = self {
+            zip.finish()?;
+        }
+        Ok(())
+    }
+}
+
+fn options(method: CompressionMethod) -> SimpleFileOptions {
+    SimpleFileOptions::default()
+        .compression_method(method)
+        .large_file(true)
+}
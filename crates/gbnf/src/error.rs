@@ -0,0 +1,9 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("unsupported schema: {0}")]
+    UnsupportedSchema(String),
+    #[error("unresolved reference: {0}")]
+    UnresolvedRef(String),
+    #[error("unsupported pattern {pattern:?}: {reason}")]
+    UnsupportedPattern { pattern: String, reason: String },
+}
@@ -0,0 +1,602 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value;
+
+use crate::Error;
+
+const SPACE: &str = "ws";
+
+// Rules that generated grammars can refer to. Added to the output only when used.
+const BUILTINS: [(&str, &str); 10] = [
+    ("ws", r##"[ \t\n]*"##),
+    (
+        "char",
+        r##"[^"\\\x7F\x00-\x1F] | "\\" (["\\/bfnrt] | "u" [0-9a-fA-F]{4})"##,
+    ),
+    ("string", r##""\"" char* "\"" ws"##),
+    (
+        "number",
+        r##""-"? ([0-9] | [1-9] [0-9]{0,15}) ("." [0-9]+)? ([eE] [-+]? [0-9]+)? ws"##,
+    ),
+    ("integer", r##""-"? ([0-9] | [1-9] [0-9]{0,15}) ws"##),
+    ("boolean", r##"("true" | "false") ws"##),
+    ("null", r##""null" ws"##),
+    (
+        "value",
+        r##"object | array | string | number | boolean | null"##,
+    ),
+    (
+        "object",
+        r##""{" ws (string ":" ws value ("," ws string ":" ws value)*)? "}" ws"##,
+    ),
+    ("array", r##""[" ws (value ("," ws value)*)? "]" ws"##),
+];
+
+/// Compiles a JSON Schema into a llama.cpp GBNF grammar whose `root` matches conforming JSON.
+///
+/// Supports `type` (including type arrays), `properties`/`required`, `items`/`prefixItems`,
+/// `minItems`/`maxItems`, `enum`, `const`, `anyOf`/`oneOf`, single-element `allOf`, local
+/// `$ref`s, string `pattern`/`minLength`/`maxLength` and the `date`/`date-time` formats.
+/// Properties are emitted required-first, then by name, and no additional properties are allowed.
+pub fn from_json_schema(schema: &Value) -> Result<String, Error> {
+    let mut converter = Converter {
+        root_schema: schema,
+        rules: BTreeMap::new(),
+        refs: HashMap::new(),
+    };
+
+    let root = converter.visit(schema, "root")?;
+    if root != "root" {
+        converter.rules.insert("root".to_string(), root);
+    }
+
+    let mut lines = vec![format!("root ::= {}", converter.rules["root"])];
+    for (name, body) in &converter.rules {
+        if name != "root" {
+            lines.push(format!("{} ::= {}", name, body));
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+struct Converter<'a> {
+    root_schema: &'a Value,
+    rules: BTreeMap<String, String>,
+    refs: HashMap<String, String>,
+}
+
+impl Converter<'_> {
+    // Returns the name of a rule matching `schema`, followed by whitespace.
+    fn visit(&mut self, schema: &Value, name: &str) -> Result<String, Error> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return self.visit_ref(reference);
+        }
+
+        let body = self.body(schema, name)?;
+        Ok(self.add_rule(name, body))
+    }
+
+    fn visit_ref(&mut self, reference: &str) -> Result<String, Error> {
+        if let Some(name) = self.refs.get(reference) {
+            return Ok(name.clone());
+        }
+
+        let target = reference
+            .strip_prefix('#')
+            .and_then(|pointer| self.root_schema.pointer(pointer))
+            .ok_or_else(|| Error::UnresolvedRef(reference.to_string()))?;
+
+        // Reserve the name first so recursive schemas refer back to it.
+        let name = self.unique_name(reference.rsplit('/').next().unwrap_or("ref"));
+        self.refs.insert(reference.to_string(), name.clone());
+        self.rules.insert(name.clone(), String::new());
+
+        let body = self.body(target, &name)?;
+        self.rules.insert(name.clone(), body);
+        Ok(name)
+    }
+
+    fn body(&mut self, schema: &Value, name: &str) -> Result<String, Error> {
+        let schema = match schema {
+            Value::Bool(true) => return Ok(self.builtin("value")),
+            Value::Object(map) if map.is_empty() => return Ok(self.builtin("value")),
+            Value::Object(map) => map,
+            _ => return Err(Error::UnsupportedSchema(schema.to_string())),
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return self.visit_ref(reference);
+        }
+
+        if schema.contains_key("const") || schema.contains_key("enum") {
+            self.builtin(SPACE);
+        }
+
+        if let Some(value) = schema.get("const") {
+            return Ok(format!("{} {}", json_literal(value), SPACE));
+        }
+
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let alternatives = values.iter().map(json_literal).collect::<Vec<_>>();
+            return Ok(format!("({}) {}", alternatives.join(" | "), SPACE));
+        }
+
+        for key in ["anyOf", "oneOf"] {
+            if let Some(variants) = schema.get(key).and_then(Value::as_array) {
+                let alternatives = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| self.visit(v, &format!("{}-{}", name, i)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                return Ok(alternatives.join(" | "));
+            }
+        }
+
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            return match all_of.as_slice() {
+                [single] => self.visit(single, name),
+                _ => Err(Error::UnsupportedSchema(
+                    "allOf with more than one schema".to_string(),
+                )),
+            };
+        }
+
+        match schema.get("type") {
+            Some(Value::Array(types)) => {
+                let alternatives = types
+                    .iter()
+                    .map(|t| {
+                        let mut variant = schema.clone();
+                        variant.insert("type".to_string(), t.clone());
+                        self.visit(
+                            &Value::Object(variant),
+                            &format!("{}-{}", name, type_name(t)),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(alternatives.join(" | "))
+            }
+            Some(Value::String(t)) => self.typed_body(schema, t, name),
+            None if schema.contains_key("properties") => self.typed_body(schema, "object", name),
+            None if schema.contains_key("items") || schema.contains_key("prefixItems") => {
+                self.typed_body(schema, "array", name)
+            }
+            None => Ok(self.builtin("value")),
+            Some(other) => Err(Error::UnsupportedSchema(format!("type {}", other))),
+        }
+    }
+
+    fn typed_body(
+        &mut self,
+        schema: &serde_json::Map<String, Value>,
+        ty: &str,
+        name: &str,
+    ) -> Result<String, Error> {
+        match ty {
+            "object" => self.object_body(schema, name),
+            "array" => self.array_body(schema, name),
+            "string" => self.string_body(schema),
+            "number" => Ok(self.builtin("number")),
+            "integer" => Ok(self.builtin("integer")),
+            "boolean" => Ok(self.builtin("boolean")),
+            "null" => Ok(self.builtin("null")),
+            other => Err(Error::UnsupportedSchema(format!("type {:?}", other))),
+        }
+    }
+
+    fn object_body(
+        &mut self,
+        schema: &serde_json::Map<String, Value>,
+        name: &str,
+    ) -> Result<String, Error> {
+        let properties = match schema.get("properties").and_then(Value::as_object) {
+            Some(properties) if !properties.is_empty() => properties,
+            _ => return Ok(self.builtin("object")),
+        };
+        self.builtin(SPACE);
+
+        let required = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        // Sorted explicitly so the grammar doesn't depend on the map's iteration order,
+        // which changes with serde_json's `preserve_order` feature.
+        let mut properties = properties.iter().collect::<Vec<_>>();
+        properties.sort_by_key(|(key, _)| (!required.contains(&key.as_str()), key.as_str()));
+
+        let mut required_kvs = vec![];
+        let mut optional_kvs = vec![];
+        for (key, prop) in properties {
+            let rule = self.visit(prop, &format!("{}-{}", name, key))?;
+            let kv = format!(
+                "{} {} \":\" {} {}",
+                json_literal(&Value::String(key.clone())),
+                SPACE,
+                SPACE,
+                rule
+            );
+
+            if required.contains(&key.as_str()) {
+                required_kvs.push(kv);
+            } else {
+                optional_kvs.push(kv);
+            }
+        }
+
+        let mut body = format!("\"{{\" {}", SPACE);
+        if !required_kvs.is_empty() {
+            body.push(' ');
+            body.push_str(&required_kvs.join(&format!(" \",\" {} ", SPACE)));
+            for kv in &optional_kvs {
+                body.push_str(&format!(" (\",\" {} {})?", SPACE, kv));
+            }
+        } else if !optional_kvs.is_empty() {
+            // Any non-empty, in-order subset: pick the first present property, then each later one is optional.
+            let alternatives = (0..optional_kvs.len())
+                .map(|i| {
+                    let mut alt = optional_kvs[i].clone();
+                    for kv in &optional_kvs[i + 1..] {
+                        alt.push_str(&format!(" (\",\" {} {})?", SPACE, kv));
+                    }
+                    alt
+                })
+                .collect::<Vec<_>>();
+            body.push_str(&format!(" ({})?", alternatives.join(" | ")));
+        }
+        body.push_str(&format!(" \"}}\" {}", SPACE));
+
+        Ok(body)
+    }
+
+    fn array_body(
+        &mut self,
+        schema: &serde_json::Map<String, Value>,
+        name: &str,
+    ) -> Result<String, Error> {
+        self.builtin(SPACE);
+
+        if let Some(prefix_items) = schema.get("prefixItems").and_then(Value::as_array) {
+            let items = prefix_items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| self.visit(item, &format!("{}-{}", name, i)))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(format!(
+                "\"[\" {} {} \"]\" {}",
+                SPACE,
+                items.join(&format!(" \",\" {} ", SPACE)),
+                SPACE
+            ));
+        }
+
+        let min = schema.get("minItems").and_then(Value::as_u64).unwrap_or(0);
+        let max = schema.get("maxItems").and_then(Value::as_u64);
+        if max == Some(0) {
+            return Ok(format!("\"[\" {} \"]\" {}", SPACE, SPACE));
+        }
+
+        let item = match schema.get("items") {
+            Some(items) => self.visit(items, &format!("{}-item", name))?,
+            None => self.builtin("value"),
+        };
+
+        let rest = |min: u64, max: Option<u64>| {
+            format!(
+                "(\",\" {} {}){}",
+                SPACE,
+                item,
+                repetition(min, max.map(|m| m.saturating_sub(1)))
+            )
+        };
+
+        let inner = match (min, max) {
+            (0, Some(1)) => format!("{}? ", item),
+            (_, Some(1)) => format!("{} ", item),
+            (0, _) => format!("({} {})? ", item, rest(0, max)),
+            (min, _) => format!("{} {} ", item, rest(min - 1, max)),
+        };
+
+        Ok(format!("\"[\" {} {}\"]\" {}", SPACE, inner, SPACE))
+    }
+
+    fn string_body(&mut self, schema: &serde_json::Map<String, Value>) -> Result<String, Error> {
+        self.builtin(SPACE);
+
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            let expr = crate::pattern::to_gbnf(pattern)?;
+            return Ok(format!(r#""\"" {} "\"" {}"#, expr, SPACE));
+        }
+
+        match schema.get("format").and_then(Value::as_str) {
+            Some("date") => {
+                return Ok(format!(r#""\"" {} "\"" {}"#, DATE, SPACE));
+            }
+            Some("date-time") => {
+                return Ok(format!(r#""\"" {} "T" {} "\"" {}"#, DATE, TIME, SPACE));
+            }
+            _ => {}
+        }
+
+        let min = schema.get("minLength").and_then(Value::as_u64);
+        let max = schema.get("maxLength").and_then(Value::as_u64);
+        if min.is_some() || max.is_some() {
+            let ch = self.builtin("char");
+            return Ok(format!(
+                r#""\"" {}{} "\"" {}"#,
+                ch,
+                repetition(min.unwrap_or(0), max),
+                SPACE
+            ));
+        }
+
+        Ok(self.builtin("string"))
+    }
+
+    fn builtin(&mut self, name: &str) -> String {
+        if !self.rules.contains_key(name) {
+            let (_, body) = BUILTINS.iter().find(|(n, _)| *n == name).unwrap();
+            self.rules.insert(name.to_string(), body.to_string());
+
+            for (dep, _) in BUILTINS {
+                if dep != name && references(body, dep) {
+                    self.builtin(dep);
+                }
+            }
+        }
+        name.to_string()
+    }
+
+    fn add_rule(&mut self, name: &str, body: String) -> String {
+        // A body that is just another rule name needs no rule of its own.
+        if is_rule_name(&body) {
+            return body;
+        }
+
+        let base = sanitize(name);
+        if self.rules.get(&base) == Some(&body) {
+            return base;
+        }
+
+        let name = self.unique_name(&base);
+        self.rules.insert(name.clone(), body);
+        name
+    }
+
+    fn unique_name(&self, name: &str) -> String {
+        let base = sanitize(name);
+        let taken = |n: &str| {
+            self.rules.contains_key(n) || BUILTINS.iter().any(|(builtin, _)| *builtin == n)
+        };
+
+        if !taken(&base) {
+            return base;
+        }
+        (1..)
+            .map(|i| format!("{}{}", base, i))
+            .find(|n| !taken(n))
+            .unwrap()
+    }
+}
+
+const DATE: &str =
+    r##"[0-9]{4} "-" ("0" [1-9] | "1" [0-2]) "-" ("0" [1-9] | [1-2] [0-9] | "3" [0-1])"##;
+const TIME: &str = r##"([01] [0-9] | "2" [0-3]) ":" [0-5] [0-9] ":" [0-5] [0-9] ("." [0-9]+)? ("Z" | [+-] [0-9]{2} ":" [0-9]{2})"##;
+
+fn repetition(min: u64, max: Option<u64>) -> String {
+    match (min, max) {
+        (0, None) => "*".to_string(),
+        (1, None) => "+".to_string(),
+        (0, Some(1)) => "?".to_string(),
+        (min, None) => format!("{{{},}}", min),
+        (min, Some(max)) if min == max => format!("{{{}}}", min),
+        (min, Some(max)) => format!("{{{},{}}}", min, max),
+    }
+}
+
+// GBNF rule names only allow `[a-zA-Z0-9-]`.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn is_rule_name(body: &str) -> bool {
+    !body.is_empty() && body.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn references(body: &str, rule: &str) -> bool {
+    body.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .any(|word| word == rule)
+        && !body.contains(&format!("\"{}\"", rule))
+}
+
+fn type_name(ty: &Value) -> String {
+    ty.as_str().unwrap_or("value").to_string()
+}
+
+// A GBNF string literal matching the JSON encoding of `value`.
+pub(crate) fn json_literal(value: &Value) -> String {
+    gbnf_literal(&value.to_string())
+}
+
+pub(crate) fn gbnf_literal(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_object() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "age": { "type": "integer" },
+            },
+            "required": ["name"],
+        });
+
+        insta::assert_snapshot!(from_json_schema(&schema).unwrap(), @r#"
+        root ::= "{" ws "\"name\"" ws ":" ws string ("," ws "\"age\"" ws ":" ws integer)? "}" ws
+        char ::= [^"\\\x7F\x00-\x1F] | "\\" (["\\/bfnrt] | "u" [0-9a-fA-F]{4})
+        integer ::= "-"? ([0-9] | [1-9] [0-9]{0,15}) ws
+        string ::= "\"" char* "\"" ws
+        ws ::= [ \t\n]*
+        "#);
+    }
+
+    #[test]
+    fn test_optional_only_object() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "a": { "type": "boolean" },
+                "b": { "type": "null" },
+            },
+        });
+
+        insta::assert_snapshot!(from_json_schema(&schema).unwrap(), @r#"
+        root ::= "{" ws ("\"a\"" ws ":" ws boolean ("," ws "\"b\"" ws ":" ws null)? | "\"b\"" ws ":" ws null)? "}" ws
+        boolean ::= ("true" | "false") ws
+        null ::= "null" ws
+        ws ::= [ \t\n]*
+        "#);
+    }
+
+    #[test]
+    fn test_array_bounds() {
+        let schema = json!({
+            "type": "array",
+            "items": { "enum": ["a", "b", 1] },
+            "minItems": 1,
+            "maxItems": 3,
+        });
+
+        insta::assert_snapshot!(from_json_schema(&schema).unwrap(), @r#"
+        root ::= "[" ws root-item ("," ws root-item){0,2} "]" ws
+        root-item ::= ("\"a\"" | "\"b\"" | "1") ws
+        ws ::= [ \t\n]*
+        "#);
+
+        let schema = json!({ "type": "array", "items": { "type": "number" }, "maxItems": 0 });
+        insta::assert_snapshot!(from_json_schema(&schema).unwrap(), @r#"
+        root ::= "[" ws "]" ws
+        ws ::= [ \t\n]*
+        "#);
+    }
+
+    #[test]
+    fn test_nullable_and_refs() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "owner": { "type": ["string", "null"] },
+                "child": { "$ref": "#/$defs/Node" },
+                "alias": { "type": "string" },
+            },
+            "required": ["owner", "child"],
+            "$defs": {
+                "Node": {
+                    "type": "object",
+                    "properties": {
+                        "next": { "anyOf": [{ "$ref": "#/$defs/Node" }, { "type": "null" }] },
+                    },
+                    "required": ["next"],
+                },
+            },
+        });
+
+        insta::assert_snapshot!(from_json_schema(&schema).unwrap(), @r#"
+        root ::= "{" ws "\"child\"" ws ":" ws Node "," ws "\"owner\"" ws ":" ws root-owner ("," ws "\"alias\"" ws ":" ws string)? "}" ws
+        Node ::= "{" ws "\"next\"" ws ":" ws Node-next "}" ws
+        Node-next ::= Node | null
+        char ::= [^"\\\x7F\x00-\x1F] | "\\" (["\\/bfnrt] | "u" [0-9a-fA-F]{4})
+        null ::= "null" ws
+        root-owner ::= string | null
+        string ::= "\"" char* "\"" ws
+        ws ::= [ \t\n]*
+        "#);
+    }
+
+    #[test]
+    fn test_string_constraints() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "code": { "type": "string", "pattern": "^[A-Z]{3}-\\d+$" },
+                "short": { "type": "string", "minLength": 1, "maxLength": 5 },
+                "day": { "type": "string", "format": "date" },
+                "kind": { "const": "task" },
+            },
+            "required": ["code", "short", "day", "kind"],
+        });
+
+        insta::assert_snapshot!(from_json_schema(&schema).unwrap(), @r#"
+        root ::= "{" ws "\"code\"" ws ":" ws root-code "," ws "\"day\"" ws ":" ws root-day "," ws "\"kind\"" ws ":" ws root-kind "," ws "\"short\"" ws ":" ws root-short "}" ws
+        char ::= [^"\\\x7F\x00-\x1F] | "\\" (["\\/bfnrt] | "u" [0-9a-fA-F]{4})
+        root-code ::= "\"" [A-Z]{3} "-" [0-9]+ "\"" ws
+        root-day ::= "\"" [0-9]{4} "-" ("0" [1-9] | "1" [0-2]) "-" ("0" [1-9] | [1-2] [0-9] | "3" [0-1]) "\"" ws
+        root-kind ::= "\"task\"" ws
+        root-short ::= "\"" char{1,5} "\"" ws
+        ws ::= [ \t\n]*
+        "#);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            from_json_schema(&json!({ "$ref": "#/$defs/Missing" })),
+            Err(Error::UnresolvedRef(_))
+        ));
+        assert!(matches!(
+            from_json_schema(&json!({ "type": "string", "pattern": "^(?=a)$" })),
+            Err(Error::UnsupportedPattern { .. })
+        ));
+        assert!(matches!(
+            from_json_schema(&json!({ "type": "tuple" })),
+            Err(Error::UnsupportedSchema(_))
+        ));
+    }
+
+    #[test]
+    fn test_validates_against_schema() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 2 },
+                "score": { "type": "number" },
+            },
+            "required": ["tags", "score"],
+        });
+        let matcher = crate::matcher::Matcher::new(&from_json_schema(&schema).unwrap());
+
+        for (input, expected) in [
+            (r#"{"score": 1.5, "tags": ["a", "b"]}"#, true),
+            (r#"{"score": -2, "tags": []}"#, true),
+            (r#"{"score":1,"tags":[]}"#, true),
+            (r#"{"score": 1, "tags": ["a", "b", "c"]}"#, false),
+            (r#"{"tags": [], "score": 1}"#, false),
+            (r#"{"score": 1}"#, false),
+        ] {
+            assert_eq!(matcher.matches(input), expected, "failed: {}", input);
+        }
+    }
+}
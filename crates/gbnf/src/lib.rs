@@ -1,5 +1,15 @@
 // https://github.com/ggml-org/llama.cpp/blob/master/grammars/README.md
 
+mod error;
+mod json_schema;
+mod pattern;
+
+#[cfg(test)]
+mod matcher;
+
+pub use error::*;
+pub use json_schema::from_json_schema;
+
 #[derive(specta::Type, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "task")]
 pub enum Grammar {
@@ -11,16 +21,20 @@ pub enum Grammar {
     Tags,
     #[serde(rename = "email-to-name")]
     EmailToName,
+    #[serde(rename = "json-schema")]
+    JsonSchema { schema: serde_json::Value },
 }
 
 impl Grammar {
-    pub fn build(&self) -> String {
-        match self {
+    /// Fails only for a `JsonSchema` the converter can't compile.
+    pub fn build(&self) -> Result<String, Error> {
+        Ok(match self {
             Grammar::Enhance { sections } => build_enhance_other_grammar(sections),
             Grammar::Title => build_title_grammar(),
             Grammar::Tags => build_tags_grammar(),
             Grammar::EmailToName => build_email_to_name_grammar(),
-        }
+            Grammar::JsonSchema { schema } => from_json_schema(schema)?,
+        })
     }
 }
 
@@ -0,0 +1,300 @@
+// A small GBNF interpreter so grammar tests can run without loading a model.
+// Covers the syntax this crate emits: literals, character classes, groups,
+// alternation, rule references and the `* + ? {m} {m,} {m,n}` quantifiers.
+
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug)]
+enum Expr {
+    Literal(Vec<char>),
+    Class {
+        ranges: Vec<(char, char)>,
+        negated: bool,
+    },
+    Rule(String),
+    Sequence(Vec<Expr>),
+    Alternation(Vec<Expr>),
+    Repeat {
+        expr: Box<Expr>,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+pub(crate) struct Matcher {
+    rules: HashMap<String, Expr>,
+}
+
+impl Matcher {
+    pub(crate) fn new(grammar: &str) -> Self {
+        let mut sources: Vec<(String, String)> = vec![];
+        for line in grammar.lines() {
+            match line.split_once("::=") {
+                Some((name, body)) if is_rule_name(name.trim()) => {
+                    sources.push((name.trim().to_string(), body.to_string()));
+                }
+                _ => match sources.last_mut() {
+                    Some((_, body)) => {
+                        body.push(' ');
+                        body.push_str(line);
+                    }
+                    None if line.trim().is_empty() || line.trim_start().starts_with('#') => {}
+                    None => panic!("expected a rule: {}", line),
+                },
+            }
+        }
+
+        let rules = sources
+            .into_iter()
+            .map(|(name, body)| {
+                let mut parser = Parser {
+                    chars: body.chars().collect(),
+                    pos: 0,
+                };
+                let expr = parser.alternation();
+                parser.skip_space();
+                assert!(
+                    parser.pos == parser.chars.len(),
+                    "trailing input in rule {}",
+                    name
+                );
+                (name, expr)
+            })
+            .collect::<HashMap<_, _>>();
+
+        assert!(rules.contains_key("root"), "grammar has no root rule");
+        Self { rules }
+    }
+
+    pub(crate) fn matches(&self, input: &str) -> bool {
+        let input = input.chars().collect::<Vec<_>>();
+        self.ends(&Expr::Rule("root".to_string()), &input, 0)
+            .contains(&input.len())
+    }
+
+    // Every position where a match of `expr` starting at `pos` can end.
+    fn ends(&self, expr: &Expr, input: &[char], pos: usize) -> BTreeSet<usize> {
+        match expr {
+            Expr::Literal(chars) => {
+                if input[pos..].starts_with(chars) {
+                    BTreeSet::from([pos + chars.len()])
+                } else {
+                    BTreeSet::new()
+                }
+            }
+            Expr::Class { ranges, negated } => match input.get(pos) {
+                Some(c) if ranges.iter().any(|(lo, hi)| lo <= c && c <= hi) != *negated => {
+                    BTreeSet::from([pos + 1])
+                }
+                _ => BTreeSet::new(),
+            },
+            Expr::Rule(name) => {
+                let rule = self
+                    .rules
+                    .get(name)
+                    .unwrap_or_else(|| panic!("undefined rule {}", name));
+                self.ends(rule, input, pos)
+            }
+            Expr::Sequence(items) => items.iter().fold(BTreeSet::from([pos]), |starts, item| {
+                starts
+                    .into_iter()
+                    .flat_map(|start| self.ends(item, input, start))
+                    .collect()
+            }),
+            Expr::Alternation(alternatives) => alternatives
+                .iter()
+                .flat_map(|alternative| self.ends(alternative, input, pos))
+                .collect(),
+            Expr::Repeat { expr, min, max } => {
+                let mut out = BTreeSet::new();
+                let mut frontier = BTreeSet::from([pos]);
+
+                for count in 0.. {
+                    if count >= *min {
+                        // Reaching a position again can't lead anywhere new.
+                        frontier.retain(|p| !out.contains(p));
+                        out.extend(frontier.iter().copied());
+                    }
+                    if frontier.is_empty() || max.is_some_and(|max| count >= max) {
+                        break;
+                    }
+
+                    frontier = frontier
+                        .into_iter()
+                        .flat_map(|start| self.ends(expr, input, start))
+                        .collect();
+                }
+
+                out
+            }
+        }
+    }
+}
+
+fn is_rule_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> char {
+        let c = self.peek().expect("unexpected end of rule");
+        self.pos += 1;
+        c
+    }
+
+    fn skip_space(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn alternation(&mut self) -> Expr {
+        let mut alternatives = vec![self.sequence()];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            alternatives.push(self.sequence());
+        }
+
+        if alternatives.len() == 1 {
+            alternatives.pop().unwrap()
+        } else {
+            Expr::Alternation(alternatives)
+        }
+    }
+
+    fn sequence(&mut self) -> Expr {
+        let mut items = vec![];
+        loop {
+            self.skip_space();
+            match self.peek() {
+                None | Some('|') | Some(')') => break,
+                _ => {
+                    let atom = self.atom();
+                    items.push(self.quantified(atom));
+                }
+            }
+        }
+        Expr::Sequence(items)
+    }
+
+    fn atom(&mut self) -> Expr {
+        match self.next() {
+            '"' => {
+                let mut chars = vec![];
+                loop {
+                    match self.next() {
+                        '"' => break,
+                        '\\' => chars.push(self.escape()),
+                        c => chars.push(c),
+                    }
+                }
+                Expr::Literal(chars)
+            }
+            '[' => {
+                let negated = self.peek() == Some('^');
+                if negated {
+                    self.pos += 1;
+                }
+
+                let mut ranges = vec![];
+                loop {
+                    let lo = match self.next() {
+                        ']' => break,
+                        '\\' => self.escape(),
+                        c => c,
+                    };
+                    let hi = if self.peek() == Some('-')
+                        && self.chars.get(self.pos + 1).is_some_and(|&c| c != ']')
+                    {
+                        self.pos += 1;
+                        match self.next() {
+                            '\\' => self.escape(),
+                            c => c,
+                        }
+                    } else {
+                        lo
+                    };
+                    ranges.push((lo, hi));
+                }
+                Expr::Class { ranges, negated }
+            }
+            '(' => {
+                let expr = self.alternation();
+                self.skip_space();
+                assert_eq!(self.next(), ')', "unbalanced parenthesis");
+                expr
+            }
+            c if c.is_ascii_alphanumeric() || c == '-' => {
+                let mut name = c.to_string();
+                while let Some(c) = self
+                    .peek()
+                    .filter(|&c| c.is_ascii_alphanumeric() || c == '-')
+                {
+                    name.push(c);
+                    self.pos += 1;
+                }
+                Expr::Rule(name)
+            }
+            c => panic!("unexpected {:?}", c),
+        }
+    }
+
+    fn escape(&mut self) -> char {
+        match self.next() {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'x' => {
+                let hex = [self.next(), self.next()].iter().collect::<String>();
+                char::from(u8::from_str_radix(&hex, 16).expect("invalid \\x escape"))
+            }
+            c => c,
+        }
+    }
+
+    fn quantified(&mut self, expr: Expr) -> Expr {
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let end = self.chars[self.pos..]
+                    .iter()
+                    .position(|&c| c == '}')
+                    .expect("unterminated repetition")
+                    + self.pos;
+                let bounds = self.chars[self.pos + 1..end].iter().collect::<String>();
+                self.pos = end;
+
+                match bounds.split_once(',') {
+                    None => {
+                        let n = bounds.trim().parse().unwrap();
+                        (n, Some(n))
+                    }
+                    Some((min, "")) => (min.trim().parse().unwrap(), None),
+                    Some((min, max)) => (
+                        min.trim().parse().unwrap(),
+                        Some(max.trim().parse().unwrap()),
+                    ),
+                }
+            }
+            _ => return expr,
+        };
+        self.pos += 1;
+
+        Expr::Repeat {
+            expr: Box::new(expr),
+            min,
+            max,
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::Error;
+use crate::json_schema::gbnf_literal;
+
+// Any character that can appear unescaped inside a JSON string.
+const JSON_CHAR: &str = r##"[^"\\\x7F\x00-\x1F]"##;
+
+/// Translates a JSON Schema `pattern` into a GBNF expression for the contents of a JSON string.
+///
+/// Supports literals, `.`, character classes, `\d` `\w` `\s`, groups, alternation and the
+/// `* + ? {m} {m,} {m,n}` quantifiers. Lookarounds and backreferences are rejected.
+pub(crate) fn to_gbnf(pattern: &str) -> Result<String, Error> {
+    let body = pattern.strip_prefix('^').unwrap_or(pattern);
+    let body = match body.strip_suffix('$') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => body,
+    };
+
+    let mut parser = Parser {
+        pattern,
+        chars: body.chars().collect(),
+        pos: 0,
+    };
+
+    let (expr, is_alternation) = parser.alternation()?;
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("unbalanced parenthesis"));
+    }
+
+    // The expression sits between two quote literals, so a top-level `|` needs a group.
+    Ok(if is_alternation {
+        format!("({})", expr)
+    } else {
+        expr
+    })
+}
+
+struct Parser<'a> {
+    pattern: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn error(&self, reason: &str) -> Error {
+        Error::UnsupportedPattern {
+            pattern: self.pattern.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    fn alternation(&mut self) -> Result<(String, bool), Error> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            alternatives.push(self.sequence()?);
+        }
+
+        Ok((alternatives.join(" | "), alternatives.len() > 1))
+    }
+
+    fn sequence(&mut self) -> Result<String, Error> {
+        let mut items = vec![];
+
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+
+            let atom = self.atom()?;
+            let quantifier = self.quantifier()?;
+            items.push(format!("{}{}", atom, quantifier));
+        }
+
+        if items.is_empty() {
+            return Ok("\"\"".to_string());
+        }
+        Ok(items.join(" "))
+    }
+
+    fn atom(&mut self) -> Result<String, Error> {
+        match self.next() {
+            Some('(') => {
+                if self.peek() == Some('?') {
+                    self.pos += 1;
+                    if self.next() != Some(':') {
+                        return Err(self.error("lookarounds are not supported"));
+                    }
+                }
+
+                let (inner, _) = self.alternation()?;
+                if self.next() != Some(')') {
+                    return Err(self.error("unbalanced parenthesis"));
+                }
+                Ok(format!("({})", inner))
+            }
+            Some('[') => self.class(),
+            Some('.') => Ok(JSON_CHAR.to_string()),
+            Some('\\') => match self.next() {
+                Some('d') => Ok("[0-9]".to_string()),
+                Some('D') => Ok(r##"[^0-9"\\\x7F\x00-\x1F]"##.to_string()),
+                Some('w') => Ok("[a-zA-Z0-9_]".to_string()),
+                Some('W') => Ok(r##"[^a-zA-Z0-9_"\\\x7F\x00-\x1F]"##.to_string()),
+                Some('s') => Ok(r##"[ ]"##.to_string()),
+                Some(c) if c.is_ascii_digit() => {
+                    Err(self.error("backreferences are not supported"))
+                }
+                Some(c) => Ok(literal(escaped(c))),
+                None => Err(self.error("trailing backslash")),
+            },
+            Some(c @ ('^' | '$')) => {
+                Err(self.error(&format!("{} is only supported at the ends", c)))
+            }
+            Some(c @ ('*' | '+' | '?' | '{')) => {
+                Err(self.error(&format!("nothing to repeat before {}", c)))
+            }
+            Some(c) => Ok(literal(c)),
+            None => Err(self.error("unexpected end")),
+        }
+    }
+
+    fn class(&mut self) -> Result<String, Error> {
+        let mut out = String::from("[");
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.pos += 1;
+            out.push('^');
+        }
+
+        let mut first = true;
+        loop {
+            match self.next() {
+                None => return Err(self.error("unterminated character class")),
+                Some(']') if !first => break,
+                Some('\\') => match self.next() {
+                    Some('d') => out.push_str("0-9"),
+                    Some('w') => out.push_str("a-zA-Z0-9_"),
+                    Some('s') => out.push(' '),
+                    Some(c) => out.push_str(&class_char(escaped(c))),
+                    None => return Err(self.error("trailing backslash")),
+                },
+                Some(c) => out.push_str(&class_char(c)),
+            }
+            first = false;
+        }
+
+        // A negated class must still not match characters that would end the JSON string.
+        if negated {
+            out.push_str(r##""\\\x7F\x00-\x1F"##);
+        }
+        out.push(']');
+        Ok(out)
+    }
+
+    fn quantifier(&mut self) -> Result<String, Error> {
+        let quantifier = match self.peek() {
+            Some(c @ ('*' | '+' | '?')) => {
+                self.pos += 1;
+                c.to_string()
+            }
+            Some('{') => {
+                let start = self.pos;
+                let end = self.chars[start..]
+                    .iter()
+                    .position(|c| *c == '}')
+                    .map(|i| start + i)
+                    .ok_or_else(|| self.error("unterminated quantifier"))?;
+
+                let inner = self.chars[start + 1..end].iter().collect::<String>();
+                let valid = !inner.is_empty()
+                    && inner.chars().all(|c| c.is_ascii_digit() || c == ',')
+                    && inner.matches(',').count() <= 1
+                    && !inner.starts_with(',');
+                if !valid {
+                    return Err(self.error(&format!("invalid quantifier {{{}}}", inner)));
+                }
+
+                self.pos = end + 1;
+                format!("{{{}}}", inner)
+            }
+            _ => return Ok(String::new()),
+        };
+
+        // Lazy and possessive modifiers do not change what matches.
+        if matches!(self.peek(), Some('?' | '+')) {
+            self.pos += 1;
+        }
+        Ok(quantifier)
+    }
+}
+
+fn escaped(c: char) -> char {
+    match c {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        c => c,
+    }
+}
+
+// Characters are matched in their JSON-encoded form, e.g. `"` must appear as `\"`.
+fn literal(c: char) -> String {
+    let encoded = serde_json::to_string(&c.to_string()).unwrap();
+    gbnf_literal(&encoded[1..encoded.len() - 1])
+}
+
+fn class_char(c: char) -> String {
+    match c {
+        '\\' | ']' | '[' | '"' => format!("\\{}", c),
+        '\n' => "\\n".to_string(),
+        '\t' => "\\t".to_string(),
+        '\r' => "\\r".to_string(),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_gbnf() {
+        for (pattern, expected) in [
+            ("^abc$", r#""a" "b" "c""#),
+            (r"^\d{3}-\d{4}$", r#"[0-9]{3} "-" [0-9]{4}"#),
+            ("^(foo|bar)+$", r#"("f" "o" "o" | "b" "a" "r")+"#),
+            ("^[a-z_]*?$", "[a-z_]*"),
+            (r"^[^\s]+$", r##"[^ "\\\x7F\x00-\x1F]+"##),
+            (r#"^say "hi"$"#, r#""s" "a" "y" " " "\\\"" "h" "i" "\\\"""#),
+            ("^(?:a|)$", r#"("a" | "")"#),
+        ] {
+            assert_eq!(to_gbnf(pattern).unwrap(), expected, "pattern: {}", pattern);
+        }
+    }
+
+    #[test]
+    fn test_unsupported() {
+        for pattern in ["^(?=a)$", r"^(a)\1$", "^(a$", "^a{x}$", "^*$", "^a^b$"] {
+            assert!(to_gbnf(pattern).is_err(), "pattern: {}", pattern);
+        }
+    }
+}
@@ -5,7 +5,8 @@ macro_rules! common_derives {
     };
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct Collection {
     pub sessions: Vec<Session>,
     pub transcripts: Vec<Transcript>,
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use llama_cpp_2::{
+    context::params::LlamaContextParams,
+    llama_batch::LlamaBatch,
+    model::{AddBos, LlamaModel},
+};
+
+use crate::{Llama, ModelName};
+
+const MAX_EMBEDDING_CONTEXT: u32 = 1024 * 8;
+
+/// A GGUF embedding model, loaded with llama.cpp in embedding mode.
+pub struct LlamaEmbedding {
+    pub name: ModelName,
+    model: Arc<LlamaModel>,
+}
+
+pub struct Embeddings {
+    /// One L2-normalized vector per input, in input order.
+    pub data: Vec<Vec<f32>>,
+    pub prompt_tokens: usize,
+}
+
+impl LlamaEmbedding {
+    pub fn new(model_path: impl AsRef<std::path::Path>) -> Result<Self, crate::Error> {
+        Llama::setup_log();
+
+        let model = Arc::new(Llama::load_model(model_path)?);
+        let name = ModelName::from_model(&model);
+
+        Ok(Self { name, model })
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.model.n_embd() as usize
+    }
+
+    /// Inputs longer than the model's context are truncated. This blocks, so call it off the async runtime.
+    pub fn embed(&self, inputs: &[String]) -> Result<Embeddings, crate::Error> {
+        let backend = Llama::get_backend();
+        let n_ctx = self.model.n_ctx_train().clamp(1, MAX_EMBEDDING_CONTEXT);
+
+        let mut ctx = self.model.new_context(
+            &backend,
+            LlamaContextParams::default()
+                .with_n_ctx(std::num::NonZeroU32::new(n_ctx))
+                // Non-causal models need the whole input in a single ubatch.
+                .with_n_batch(n_ctx)
+                .with_n_ubatch(n_ctx)
+                .with_embeddings(true),
+        )?;
+
+        let mut batch = LlamaBatch::new(n_ctx as usize, 1);
+        let mut data = Vec::with_capacity(inputs.len());
+        let mut prompt_tokens = 0;
+
+        for input in inputs {
+            let mut tokens = self.model.str_to_token(input, AddBos::Always)?;
+            tokens.truncate(n_ctx as usize);
+            prompt_tokens += tokens.len();
+
+            if tokens.is_empty() {
+                data.push(vec![0.0; self.dimensions()]);
+                continue;
+            }
+
+            batch.clear();
+            batch.add_sequence(&tokens, 0, false)?;
+
+            ctx.clear_kv_cache();
+            ctx.decode(&mut batch)?;
+
+            data.push(normalize(ctx.embeddings_seq_ith(0)?));
+        }
+
+        Ok(Embeddings {
+            data,
+            prompt_tokens,
+        })
+    }
+}
+
+fn normalize(v: &[f32]) -> Vec<f32> {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return v.to_vec();
+    }
+    v.iter().map(|x| x / norm).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dot(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_normalize() {
+        let v = normalize(&[3.0, 4.0]);
+        assert_eq!(v, vec![0.6, 0.8]);
+        assert_eq!(normalize(&[0.0, 0.0]), vec![0.0, 0.0]);
+    }
+
+    // cargo test test_embed -p llama -- --nocapture --ignored
+    #[ignore]
+    #[test]
+    fn test_embed() {
+        let model_path = dirs::data_dir()
+            .unwrap()
+            .join("hyprnote")
+            .join("models/llm/embedding.gguf");
+        let model = LlamaEmbedding::new(model_path).unwrap();
+
+        let inputs = [
+            "The meeting moved to Friday.",
+            "We rescheduled the sync to the end of the week.",
+            "Bananas are rich in potassium.",
+        ]
+        .map(String::from);
+
+        let embeddings = model.embed(&inputs).unwrap();
+        assert_eq!(embeddings.data.len(), 3);
+        assert_eq!(embeddings.data[0].len(), model.dimensions());
+
+        let related = dot(&embeddings.data[0], &embeddings.data[1]);
+        let unrelated = dot(&embeddings.data[0], &embeddings.data[2]);
+        assert!(related > unrelated);
+    }
+}
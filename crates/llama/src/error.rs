@@ -19,6 +19,8 @@ pub enum Error {
     #[error(transparent)]
     DecodeError(#[from] llama_cpp_2::DecodeError),
     #[error(transparent)]
+    EmbeddingsError(#[from] llama_cpp_2::EmbeddingsError),
+    #[error(transparent)]
     TaskSendError(#[from] tokio::sync::mpsc::error::SendError<crate::Task>),
 }
 
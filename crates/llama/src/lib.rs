@@ -15,10 +15,12 @@ use tokio_util::sync::CancellationToken;
 
 use hypr_gguf::GgufExt;
 
+mod embedding;
 mod error;
 mod parser;
 mod types;
 
+pub use embedding::*;
 pub use error::*;
 pub use parser::{Response, StreamingParser};
 pub use types::*;
@@ -34,8 +36,19 @@ pub enum ModelName {
     Other(Option<String>),
 }
 
+impl ModelName {
+    fn from_model(model: &LlamaModel) -> Self {
+        match model.meta_val_str("general.name") {
+            Ok(name) if name == "hypr-llm" => ModelName::HyprLLM,
+            Ok(name) => ModelName::Other(Some(name.to_string())),
+            Err(_) => ModelName::Other(None),
+        }
+    }
+}
+
 pub struct Llama {
     pub name: ModelName,
+    model: Arc<LlamaModel>,
     task_sender: tokio::sync::mpsc::UnboundedSender<Task>,
 }
 
@@ -318,16 +331,13 @@ impl Llama {
         let template = model_path.chat_format()?.unwrap();
 
         let backend = Self::get_backend();
-        let model = Self::load_model(model_path)?;
-        let name = match model.meta_val_str("general.name") {
-            Ok(name) if name == "hypr-llm" => ModelName::HyprLLM,
-            Ok(name) => ModelName::Other(Some(name.to_string())),
-            Err(_) => ModelName::Other(None),
-        };
+        let model = Arc::new(Self::load_model(model_path)?);
+        let name = ModelName::from_model(&model);
 
         let (task_sender, mut task_receiver) = tokio::sync::mpsc::unbounded_channel::<Task>();
 
         std::thread::spawn({
+            let model = model.clone();
             move || {
                 while let Some(task) = task_receiver.blocking_recv() {
                     match task {
@@ -375,7 +385,24 @@ impl Llama {
             }
         });
 
-        Ok(Self { name, task_sender })
+        Ok(Self {
+            name,
+            model,
+            task_sender,
+        })
+    }
+
+    pub fn max_input_tokens(&self) -> usize {
+        DEFAULT_MAX_INPUT_TOKENS as usize
+    }
+
+    pub fn tokenize(&self, text: impl AsRef<str>) -> Result<Vec<i32>, crate::Error> {
+        let tokens = self.model.str_to_token(text.as_ref(), AddBos::Never)?;
+        Ok(tokens.into_iter().map(|token| token.0).collect())
+    }
+
+    pub fn count_tokens(&self, text: impl AsRef<str>) -> Result<usize, crate::Error> {
+        Ok(self.tokenize(text)?.len())
     }
 
     pub fn generate_stream(
@@ -451,7 +478,11 @@ mod tests {
 
     fn get_request() -> LlamaRequest {
         LlamaRequest {
-            grammar: Some(hypr_gbnf::Grammar::Enhance { sections: None }.build()),
+            grammar: Some(
+                hypr_gbnf::Grammar::Enhance { sections: None }
+                    .build()
+                    .unwrap(),
+            ),
             messages: vec![
                 LlamaMessage {
                     role: "system".into(),
@@ -10,6 +10,8 @@ pub enum Error {
     HyprLlamaError(#[from] hypr_llama::Error),
     #[error("Model not downloaded")]
     ModelNotDownloaded,
+    #[error("Embedding model not configured")]
+    EmbeddingModelNotConfigured,
 }
 
 impl Serialize for Error {
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -7,6 +7,7 @@ use tokio::sync::{Mutex, watch};
 #[derive(Default)]
 pub struct ModelManagerBuilder {
     model_path: Option<PathBuf>,
+    embedding_model_path: Option<PathBuf>,
     activity_check_interval: Option<Duration>,
     inactivity_threshold: Option<Duration>,
 }
@@ -17,6 +18,11 @@ impl ModelManagerBuilder {
         self
     }
 
+    pub fn embedding_model_path(mut self, v: impl Into<PathBuf>) -> Self {
+        self.embedding_model_path = Some(v.into());
+        self
+    }
+
     pub fn activity_check_interval(mut self, v: Duration) -> Self {
         self.activity_check_interval = Some(v);
         self
@@ -32,7 +38,9 @@ impl ModelManagerBuilder {
 
         let manager = ModelManager {
             model_path: self.model_path.unwrap(),
+            embedding_model_path: self.embedding_model_path,
             model: Arc::new(tokio::sync::Mutex::new(None)),
+            embedding_model: Arc::new(tokio::sync::Mutex::new(None)),
             last_activity: Arc::new(tokio::sync::Mutex::new(None)),
             activity_check_interval: self
                 .activity_check_interval
@@ -51,7 +59,9 @@ impl ModelManagerBuilder {
 #[derive(Clone)]
 pub struct ModelManager {
     model_path: PathBuf,
+    embedding_model_path: Option<PathBuf>,
     model: Arc<Mutex<Option<Arc<hypr_llama::Llama>>>>,
+    embedding_model: Arc<Mutex<Option<Arc<hypr_llama::LlamaEmbedding>>>>,
     last_activity: Arc<Mutex<Option<tokio::time::Instant>>>,
     activity_check_interval: Duration,
     inactivity_threshold: Duration,
@@ -92,6 +102,40 @@ impl ModelManager {
         }
     }
 
+    pub fn model_path(&self) -> &Path {
+        &self.model_path
+    }
+
+    pub fn embedding_model_path(&self) -> Option<&Path> {
+        self.embedding_model_path.as_deref()
+    }
+
+    pub async fn get_embedding_model(
+        &self,
+    ) -> Result<Arc<hypr_llama::LlamaEmbedding>, crate::Error> {
+        self.update_activity().await;
+
+        let model_path = self
+            .embedding_model_path
+            .as_ref()
+            .ok_or(crate::Error::EmbeddingModelNotConfigured)?;
+
+        let mut guard = self.embedding_model.lock().await;
+
+        match guard.as_ref() {
+            Some(model) => Ok(model.clone()),
+            None => {
+                if !model_path.exists() {
+                    return Err(crate::Error::ModelNotDownloaded);
+                }
+
+                let model = Arc::new(hypr_llama::LlamaEmbedding::new(model_path)?);
+                *guard = Some(model.clone());
+                Ok(model)
+            }
+        }
+    }
+
     pub async fn update_activity(&self) {
         *self.last_activity.lock().await = Some(tokio::time::Instant::now());
     }
@@ -101,6 +145,7 @@ impl ModelManager {
         let inactivity_threshold = self.inactivity_threshold;
 
         let model = self.model.clone();
+        let embedding_model = self.embedding_model.clone();
         let last_activity = self.last_activity.clone();
 
         let _handle = tokio::spawn(async move {
@@ -115,15 +160,14 @@ impl ModelManager {
                         break;
                     },
                     _ = interval.tick() => {
-                        let should_unload = match *last_activity.lock().await {
-                            Some(last_time) if last_time.elapsed() > inactivity_threshold => {
-                                model.lock().await.is_some()
-                            },
-                            _ => false
-                        };
+                        let should_unload = matches!(
+                            *last_activity.lock().await,
+                            Some(last_time) if last_time.elapsed() > inactivity_threshold
+                        );
 
                         if should_unload {
                             *model.lock().await = None;
+                            *embedding_model.lock().await = None;
                         }
                     }
                 }
@@ -7,6 +7,8 @@ pub enum Error {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
     #[error(transparent)]
+    HyprGbnfError(#[from] hypr_gbnf::Error),
+    #[error(transparent)]
     HyprLlamaError(#[from] hypr_llama::Error),
     #[error(transparent)]
     HyprLlmInterfaceError(#[from] hypr_llm_interface::Error),
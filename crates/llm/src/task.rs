@@ -22,7 +22,7 @@ pub async fn generate_title(
             },
         ],
         max_tokens: Some(30),
-        grammar: Some(Grammar::Title.build()),
+        grammar: Some(Grammar::Title.build()?),
         ..Default::default()
     })?;
 
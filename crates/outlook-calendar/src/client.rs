@@ -0,0 +1,411 @@
+use hypr_http::HttpClient;
+
+use crate::error::Error;
+use crate::types::{
+    CreateEventRequest, ListCalendarsResponse, ListEventsRequest, ListEventsResponse, OutlookEvent,
+};
+
+const GRAPH_ORIGIN: &str = "https://graph.microsoft.com";
+const CALENDARS_PATH: &str = "/v1.0/me/calendars/";
+
+pub struct OutlookCalendarClient<C> {
+    http: C,
+}
+
+impl<C: HttpClient> OutlookCalendarClient<C> {
+    pub fn new(http: C) -> Self {
+        Self { http }
+    }
+
+    pub async fn list_calendars(&self) -> Result<ListCalendarsResponse, Error> {
+        let bytes = self
+            .http
+            .get("/v1.0/me/calendars")
+            .await
+            .map_err(Error::Http)?;
+        let response: ListCalendarsResponse = serde_json::from_slice(&bytes)?;
+        Ok(response)
+    }
+
+    pub async fn list_events(&self, req: ListEventsRequest) -> Result<ListEventsResponse, Error> {
+        let full_path = match req.next_link {
+            Some(ref next_link) => next_link_path(next_link)?.to_string(),
+            None => events_path(&req),
+        };
+
+        let bytes = self.http.get(&full_path).await.map_err(Error::Http)?;
+        let response: ListEventsResponse = serde_json::from_slice(&bytes)?;
+        Ok(response)
+    }
+
+    pub async fn create_event(&self, req: CreateEventRequest) -> Result<OutlookEvent, Error> {
+        let calendar_id = urlencoding::encode(&req.calendar_id);
+        let path = format!("/v1.0/me/calendars/{calendar_id}/events");
+
+        let body = serde_json::to_vec(&req.event)?;
+        let bytes = self.http.post(&path, body).await.map_err(Error::Http)?;
+        let event: OutlookEvent = serde_json::from_slice(&bytes)?;
+        Ok(event)
+    }
+}
+
+// `nextLink` comes back from the server and is sent with our token, so only follow
+// it while it stays on Graph and under the calendars we asked for.
+fn next_link_path(next_link: &str) -> Result<&str, Error> {
+    let invalid = || Error::InvalidNextLink(next_link.to_string());
+
+    let path = next_link.strip_prefix(GRAPH_ORIGIN).ok_or_else(invalid)?;
+    let (path_only, _) = path.split_once('?').unwrap_or((path, ""));
+
+    if !path_only.starts_with(CALENDARS_PATH)
+        || path_only
+            .split('/')
+            .any(|segment| segment == ".." || segment == ".")
+    {
+        return Err(invalid());
+    }
+
+    Ok(path)
+}
+
+fn events_path(req: &ListEventsRequest) -> String {
+    let calendar_id = urlencoding::encode(&req.calendar_id);
+    let mut query_parts: Vec<String> = Vec::new();
+
+    let path = match (req.start_date_time, req.end_date_time) {
+        (Some(start), Some(end)) => {
+            query_parts.push(format!(
+                "startDateTime={}",
+                urlencoding::encode(&start.to_rfc3339())
+            ));
+            query_parts.push(format!(
+                "endDateTime={}",
+                urlencoding::encode(&end.to_rfc3339())
+            ));
+            format!("/v1.0/me/calendars/{calendar_id}/calendarView")
+        }
+        (start, end) => {
+            let mut filters = Vec::new();
+            if let Some(start) = start {
+                filters.push(format!("end/dateTime ge '{}'", graph_datetime(start)));
+            }
+            if let Some(end) = end {
+                filters.push(format!("start/dateTime lt '{}'", graph_datetime(end)));
+            }
+            if !filters.is_empty() {
+                query_parts.push(format!(
+                    "$filter={}",
+                    urlencoding::encode(&filters.join(" and "))
+                ));
+            }
+            format!("/v1.0/me/calendars/{calendar_id}/events")
+        }
+    };
+
+    if let Some(top) = req.top {
+        query_parts.push(format!("$top={top}"));
+    }
+    if let Some(ref order_by) = req.order_by {
+        query_parts.push(format!("$orderby={}", urlencoding::encode(order_by)));
+    }
+
+    if query_parts.is_empty() {
+        path
+    } else {
+        format!("{}?{}", path, query_parts.join("&"))
+    }
+}
+
+// `$filter` compares against the offset-less `dateTime` strings Graph stores in UTC.
+fn graph_datetime(dt: chrono::DateTime<chrono::Utc>) -> String {
+    dt.format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Attendee, CreateEventBody, DateTimeTimeZone, EmailAddress};
+    use std::sync::Mutex;
+
+    // Serves responses recorded from Graph and keeps the requests it saw.
+    #[derive(Default)]
+    struct FixtureHttpClient {
+        fixtures: Vec<(&'static str, &'static str)>,
+        requests: Mutex<Vec<(String, Option<serde_json::Value>)>>,
+    }
+
+    impl FixtureHttpClient {
+        fn new(fixtures: Vec<(&'static str, &'static str)>) -> Self {
+            Self {
+                fixtures,
+                ..Default::default()
+            }
+        }
+
+        fn respond(
+            &self,
+            path: &str,
+            body: Option<Vec<u8>>,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            let body = body.map(|b| serde_json::from_slice(&b).unwrap());
+            self.requests.lock().unwrap().push((path.to_string(), body));
+
+            self.fixtures
+                .iter()
+                .find(|(prefix, _)| path.starts_with(prefix))
+                .map(|(_, fixture)| fixture.as_bytes().to_vec())
+                .ok_or_else(|| format!("no fixture for {path}").into())
+        }
+
+        fn requests(&self) -> Vec<(String, Option<serde_json::Value>)> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl HttpClient for FixtureHttpClient {
+        async fn get(
+            &self,
+            path: &str,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            self.respond(path, None)
+        }
+
+        async fn post(
+            &self,
+            path: &str,
+            body: Vec<u8>,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            self.respond(path, Some(body))
+        }
+
+        async fn put(
+            &self,
+            path: &str,
+            body: Vec<u8>,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            self.respond(path, Some(body))
+        }
+
+        async fn patch(
+            &self,
+            path: &str,
+            body: Vec<u8>,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            self.respond(path, Some(body))
+        }
+
+        async fn delete(
+            &self,
+            path: &str,
+        ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            self.respond(path, None)
+        }
+    }
+
+    const CALENDARS: &str = include_str!("../tests/fixtures/list_calendars.json");
+    const CALENDAR_VIEW: &str = include_str!("../tests/fixtures/calendar_view.json");
+    const CALENDAR_VIEW_PAGE_2: &str = include_str!("../tests/fixtures/calendar_view_page_2.json");
+    const CREATE_EVENT: &str = include_str!("../tests/fixtures/create_event.json");
+
+    fn dt(s: &str) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[tokio::test]
+    async fn test_list_calendars() {
+        let client = OutlookCalendarClient::new(FixtureHttpClient::new(vec![(
+            "/v1.0/me/calendars",
+            CALENDARS,
+        )]));
+
+        let response = client.list_calendars().await.unwrap();
+        assert_eq!(response.value.len(), 2);
+        assert!(response.next_link.is_none());
+
+        let primary = &response.value[0];
+        assert_eq!(primary.name.as_deref(), Some("Calendar"));
+        assert_eq!(primary.is_default_calendar, Some(true));
+        assert_eq!(
+            primary.owner.as_ref().and_then(|o| o.address.as_deref()),
+            Some("adele@contoso.com")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_events_calendar_view() {
+        let client = OutlookCalendarClient::new(FixtureHttpClient::new(vec![
+            (
+                "/v1.0/me/calendars/AAMkAGI2TGuLAAA%3D/calendarView",
+                CALENDAR_VIEW,
+            ),
+            (
+                "/v1.0/me/calendars/AAMkAGI2TGuLAAA=/calendarView?",
+                CALENDAR_VIEW_PAGE_2,
+            ),
+        ]));
+
+        let response = client
+            .list_events(ListEventsRequest {
+                calendar_id: "AAMkAGI2TGuLAAA=".to_string(),
+                start_date_time: Some(dt("2025-03-03T00:00:00Z")),
+                end_date_time: Some(dt("2025-03-08T00:00:00Z")),
+                top: Some(2),
+                order_by: Some("start/dateTime".to_string()),
+                next_link: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            client.http.requests()[0].0,
+            "/v1.0/me/calendars/AAMkAGI2TGuLAAA%3D/calendarView?startDateTime=2025-03-03T00%3A00%3A00%2B00%3A00&endDateTime=2025-03-08T00%3A00%3A00%2B00%3A00&$top=2&$orderby=start%2FdateTime"
+        );
+
+        assert_eq!(response.value.len(), 2);
+
+        let standup = &response.value[0];
+        assert_eq!(standup.subject.as_deref(), Some("Weekly standup"));
+        assert_eq!(standup.event_type.as_deref(), Some("occurrence"));
+        assert!(standup.series_master_id.is_some());
+        assert_eq!(
+            standup.join_url(),
+            Some("https://teams.microsoft.com/l/meetup-join/19%3ameeting_standup%40thread.v2/0")
+        );
+        let start = standup.start.as_ref().unwrap();
+        assert_eq!(start.date_time, "2025-03-04T14:30:00.0000000");
+        assert_eq!(start.time_zone, "UTC");
+        let attendees = standup.attendees.as_ref().unwrap();
+        assert_eq!(attendees.len(), 2);
+        assert_eq!(
+            attendees[1]
+                .status
+                .as_ref()
+                .and_then(|s| s.response.as_deref()),
+            Some("declined")
+        );
+
+        let lunch = &response.value[1];
+        assert_eq!(lunch.is_online_meeting, Some(false));
+        assert_eq!(lunch.join_url(), None);
+
+        let next_link = response.next_link.unwrap();
+        let page_2 = client
+            .list_events(ListEventsRequest {
+                calendar_id: "ignored".to_string(),
+                start_date_time: None,
+                end_date_time: None,
+                top: None,
+                order_by: None,
+                next_link: Some(next_link),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            client.http.requests()[1].0,
+            "/v1.0/me/calendars/AAMkAGI2TGuLAAA=/calendarView?startDateTime=2025-03-03T00%3a00%3a00%2b00%3a00&endDateTime=2025-03-08T00%3a00%3a00%2b00%3a00&%24top=2&%24skip=2"
+        );
+        assert_eq!(page_2.value.len(), 1);
+        assert!(page_2.next_link.is_none());
+        assert_eq!(
+            page_2.value[0].join_url(),
+            Some("https://contoso.zoom.us/j/5551234567")
+        );
+    }
+
+    #[test]
+    fn test_next_link_path() {
+        assert_eq!(
+            next_link_path("https://graph.microsoft.com/v1.0/me/calendars/cal/events?$skip=10")
+                .unwrap(),
+            "/v1.0/me/calendars/cal/events?$skip=10"
+        );
+
+        for next_link in [
+            "/v1.0/me/calendars/cal/events?$skip=10",
+            "https://evil.example.com/v1.0/me/calendars/cal/events",
+            "https://graph.microsoft.com.evil.example.com/v1.0/me/calendars/cal/events",
+            "https://graph.microsoft.com/v1.0/me/messages",
+            "https://graph.microsoft.com/v1.0/me/calendars/../messages",
+            "https://graph.microsoft.com/beta/me/calendars/cal/events",
+        ] {
+            assert!(
+                matches!(next_link_path(next_link), Err(Error::InvalidNextLink(_))),
+                "{next_link}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_events_path_filter() {
+        let path = events_path(&ListEventsRequest {
+            calendar_id: "cal".to_string(),
+            start_date_time: Some(dt("2025-03-03T00:00:00Z")),
+            end_date_time: None,
+            top: None,
+            order_by: None,
+            next_link: None,
+        });
+
+        assert_eq!(
+            path,
+            "/v1.0/me/calendars/cal/events?$filter=end%2FdateTime%20ge%20%272025-03-03T00%3A00%3A00%27"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_event() {
+        let client = OutlookCalendarClient::new(FixtureHttpClient::new(vec![(
+            "/v1.0/me/calendars/cal/events",
+            CREATE_EVENT,
+        )]));
+
+        let event = client
+            .create_event(CreateEventRequest {
+                calendar_id: "cal".to_string(),
+                event: CreateEventBody {
+                    subject: "Roadmap sync".to_string(),
+                    start: DateTimeTimeZone {
+                        date_time: "2025-03-10T16:00:00".to_string(),
+                        time_zone: "UTC".to_string(),
+                    },
+                    end: DateTimeTimeZone {
+                        date_time: "2025-03-10T16:30:00".to_string(),
+                        time_zone: "UTC".to_string(),
+                    },
+                    body: None,
+                    location: None,
+                    attendees: Some(vec![Attendee {
+                        email_address: EmailAddress {
+                            name: Some("Alex Wilber".to_string()),
+                            address: Some("alexw@contoso.com".to_string()),
+                        },
+                        attendee_type: Some("required".to_string()),
+                        status: None,
+                    }]),
+                    is_all_day: None,
+                },
+            })
+            .await
+            .unwrap();
+
+        let (path, body) = client.http.requests().remove(0);
+        assert_eq!(path, "/v1.0/me/calendars/cal/events");
+        assert_eq!(
+            body.unwrap(),
+            serde_json::json!({
+                "subject": "Roadmap sync",
+                "start": { "dateTime": "2025-03-10T16:00:00", "timeZone": "UTC" },
+                "end": { "dateTime": "2025-03-10T16:30:00", "timeZone": "UTC" },
+                "attendees": [{
+                    "emailAddress": { "name": "Alex Wilber", "address": "alexw@contoso.com" },
+                    "type": "required"
+                }]
+            })
+        );
+
+        assert_eq!(event.subject.as_deref(), Some("Roadmap sync"));
+        assert_eq!(event.is_organizer, Some(true));
+        assert!(event.web_link.is_some());
+    }
+}
@@ -0,0 +1,7 @@
+mod client;
+mod error;
+mod types;
+
+pub use client::OutlookCalendarClient;
+pub use error::Error;
+pub use types::*;
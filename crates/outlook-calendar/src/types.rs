@@ -0,0 +1,203 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlookCalendar {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub hex_color: Option<String>,
+    #[serde(default)]
+    pub is_default_calendar: Option<bool>,
+    #[serde(default)]
+    pub can_edit: Option<bool>,
+    #[serde(default)]
+    pub owner: Option<EmailAddress>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListCalendarsResponse {
+    #[serde(default, rename = "@odata.nextLink")]
+    pub next_link: Option<String>,
+    #[serde(default)]
+    pub value: Vec<OutlookCalendar>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEventRequest {
+    pub calendar_id: String,
+    pub event: CreateEventBody,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEventBody {
+    pub subject: String,
+    pub start: DateTimeTimeZone,
+    pub end: DateTimeTimeZone,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attendees: Option<Vec<Attendee>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_all_day: Option<bool>,
+}
+
+/// With both `start_date_time` and `end_date_time` set, events are read from
+/// `calendarView`, which expands recurring series into their instances.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListEventsRequest {
+    pub calendar_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<String>,
+    /// `@odata.nextLink` from a previous page. When set, the other fields are ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListEventsResponse {
+    #[serde(default, rename = "@odata.nextLink")]
+    pub next_link: Option<String>,
+    #[serde(default)]
+    pub value: Vec<OutlookEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlookEvent {
+    pub id: String,
+    #[serde(default)]
+    pub subject: Option<String>,
+    #[serde(default)]
+    pub body_preview: Option<String>,
+    #[serde(default)]
+    pub body: Option<ItemBody>,
+    #[serde(default)]
+    pub start: Option<DateTimeTimeZone>,
+    #[serde(default)]
+    pub end: Option<DateTimeTimeZone>,
+    #[serde(default)]
+    pub location: Option<Location>,
+    #[serde(default)]
+    pub organizer: Option<Recipient>,
+    #[serde(default)]
+    pub attendees: Option<Vec<Attendee>>,
+    #[serde(default)]
+    pub is_all_day: Option<bool>,
+    #[serde(default)]
+    pub is_cancelled: Option<bool>,
+    #[serde(default)]
+    pub is_organizer: Option<bool>,
+    #[serde(default)]
+    pub is_online_meeting: Option<bool>,
+    #[serde(default)]
+    pub online_meeting_provider: Option<String>,
+    #[serde(default)]
+    pub online_meeting: Option<OnlineMeetingInfo>,
+    #[serde(default)]
+    pub online_meeting_url: Option<String>,
+    #[serde(default)]
+    pub web_link: Option<String>,
+    #[serde(default, rename = "type")]
+    pub event_type: Option<String>,
+    #[serde(default)]
+    pub series_master_id: Option<String>,
+    #[serde(default)]
+    pub recurrence: Option<serde_json::Value>,
+    #[serde(rename = "iCalUId")]
+    #[serde(default)]
+    pub ical_uid: Option<String>,
+}
+
+impl OutlookEvent {
+    /// Teams and Skype meetings carry their link in `onlineMeeting`; `onlineMeetingUrl`
+    /// is only filled for older Skype for Business meetings.
+    pub fn join_url(&self) -> Option<&str> {
+        self.online_meeting
+            .as_ref()
+            .and_then(|m| m.join_url.as_deref())
+            .or(self.online_meeting_url.as_deref())
+            .filter(|url| !url.is_empty())
+    }
+}
+
+/// Graph returns `dateTime` without an offset, in `timeZone` (UTC unless the
+/// request asked for another zone).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateTimeTimeZone {
+    pub date_time: String,
+    pub time_zone: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemBody {
+    pub content_type: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(default)]
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailAddress {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recipient {
+    pub email_address: EmailAddress,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attendee {
+    pub email_address: EmailAddress,
+    /// `required`, `optional`, or `resource`.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub attendee_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ResponseStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseStatus {
+    #[serde(default)]
+    pub response: Option<String>,
+    #[serde(default)]
+    pub time: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnlineMeetingInfo {
+    #[serde(default)]
+    pub join_url: Option<String>,
+    #[serde(default)]
+    pub conference_id: Option<String>,
+    #[serde(default)]
+    pub toll_number: Option<String>,
+}
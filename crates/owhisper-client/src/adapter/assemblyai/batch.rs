@@ -11,7 +11,10 @@ use serde::{Deserialize, Serialize};
 use super::AssemblyAIAdapter;
 use super::language::BATCH_LANGUAGES;
 use crate::adapter::http::ensure_success;
-use crate::adapter::{BatchFuture, BatchSttAdapter, ClientWithMiddleware};
+use crate::adapter::{
+    AsyncBatchSttAdapter, BatchFuture, BatchSttAdapter, ClientWithMiddleware, JobFuture, JobStatus,
+    JobStatusFuture,
+};
 use crate::error::Error;
 use crate::polling::{PollingConfig, PollingResult, poll_until};
 
@@ -45,6 +48,35 @@ impl BatchSttAdapter for AssemblyAIAdapter {
     }
 }
 
+impl AsyncBatchSttAdapter for AssemblyAIAdapter {
+    fn submit_job<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        params: &'a ListenParams,
+        audio_url: &'a str,
+    ) -> JobFuture<'a> {
+        Box::pin(Self::submit(
+            client,
+            api_base,
+            api_key,
+            params,
+            audio_url.to_string(),
+        ))
+    }
+
+    fn job_status<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        job_id: &'a str,
+    ) -> JobStatusFuture<'a> {
+        Box::pin(Self::status(client, api_base, api_key, job_id))
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct TranscriptRequest {
     audio_url: String,
@@ -147,6 +179,33 @@ impl AssemblyAIAdapter {
         let upload_response = ensure_success(upload_response).await?;
         let upload_result: UploadResponse = upload_response.json().await?;
 
+        let transcript_id =
+            Self::submit(client, api_base, api_key, params, upload_result.upload_url).await?;
+
+        let config = PollingConfig::default()
+            .with_interval(Duration::from_secs(3))
+            .with_timeout_error("transcription timed out".to_string());
+
+        poll_until(
+            || async {
+                Self::status(client, api_base, api_key, &transcript_id)
+                    .await
+                    .map(PollingResult::from)
+            },
+            config,
+        )
+        .await
+    }
+
+    async fn submit(
+        client: &ClientWithMiddleware,
+        api_base: &str,
+        api_key: &str,
+        params: &ListenParams,
+        audio_url: String,
+    ) -> Result<String, Error> {
+        let base_url = Self::batch_api_url(api_base);
+
         let language_code = params
             .languages
             .first()
@@ -158,7 +217,7 @@ impl AssemblyAIAdapter {
         };
 
         let transcript_request = TranscriptRequest {
-            audio_url: upload_result.upload_url,
+            audio_url,
             language_code,
             language_detection,
             speaker_labels: Some(true),
@@ -177,42 +236,38 @@ impl AssemblyAIAdapter {
 
         let create_response = ensure_success(create_response).await?;
         let create_result: TranscriptResponse = create_response.json().await?;
-        let transcript_id = create_result.id;
+        Ok(create_result.id)
+    }
 
-        let poll_url = format!("{}/transcript/{}", base_url, transcript_id);
+    async fn status(
+        client: &ClientWithMiddleware,
+        api_base: &str,
+        api_key: &str,
+        transcript_id: &str,
+    ) -> Result<JobStatus, Error> {
+        let poll_url = format!(
+            "{}/transcript/{}",
+            Self::batch_api_url(api_base),
+            transcript_id
+        );
 
-        let config = PollingConfig::default()
-            .with_interval(Duration::from_secs(3))
-            .with_timeout_error("transcription timed out".to_string());
+        let poll_response = client
+            .get(&poll_url)
+            .header("Authorization", api_key)
+            .send()
+            .await?;
 
-        poll_until(
-            || async {
-                let poll_response = client
-                    .get(&poll_url)
-                    .header("Authorization", api_key)
-                    .send()
-                    .await?;
-
-                let poll_response = ensure_success(poll_response).await?;
-                let result: TranscriptResponse = poll_response.json().await?;
-
-                match result.status.as_str() {
-                    "completed" => Ok(PollingResult::Complete(Self::convert_to_batch_response(
-                        result,
-                    ))),
-                    "error" => {
-                        let error_msg = result.error.unwrap_or_else(|| "unknown error".to_string());
-                        Ok(PollingResult::Failed(format!(
-                            "transcription failed: {}",
-                            error_msg
-                        )))
-                    }
-                    _ => Ok(PollingResult::Continue),
-                }
-            },
-            config,
-        )
-        .await
+        let poll_response = ensure_success(poll_response).await?;
+        let result: TranscriptResponse = poll_response.json().await?;
+
+        Ok(match result.status.as_str() {
+            "completed" => JobStatus::Completed(Self::convert_to_batch_response(result)),
+            "error" => {
+                let error_msg = result.error.unwrap_or_else(|| "unknown error".to_string());
+                JobStatus::Failed(format!("transcription failed: {}", error_msg))
+            }
+            _ => JobStatus::Pending,
+        })
     }
 
     fn convert_to_batch_response(response: TranscriptResponse) -> BatchResponse {
@@ -276,6 +276,7 @@ impl AssemblyAIAdapter {
                 words,
                 confidence: turn.end_of_turn_confidence,
                 languages: turn.language_code.map(|l| vec![l]).unwrap_or_default(),
+                translation: None,
             }],
         };
 
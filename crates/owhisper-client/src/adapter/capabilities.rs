@@ -0,0 +1,316 @@
+use serde::Serialize;
+
+use super::AdapterKind;
+
+// Rates we resample to before streaming. Providers that take raw PCM at any
+// rate list all of them.
+const ANY_SAMPLE_RATE: &[u32] = &[8000, 11025, 16000, 22050, 24000, 32000, 44100, 48000];
+
+const HOUR_SECS: u64 = 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AudioEncoding {
+    Linear16,
+    Mulaw,
+    Alaw,
+    Opus,
+    Flac,
+}
+
+/// What a provider can do on the realtime path, as driven by its adapter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    pub diarization: bool,
+    pub native_multichannel: bool,
+    pub keyword_boosting: bool,
+    /// `None` when the provider documents no limit.
+    pub max_keywords: Option<usize>,
+    /// Whether transcripts come back punctuated, cased and with numbers
+    /// written out. Others go through [`super::TranscriptFormatter`].
+    pub punctuation: bool,
+    pub word_timestamps: bool,
+    pub translation: bool,
+    /// `None` when sessions are not capped.
+    pub max_session_secs: Option<u64>,
+    pub sample_rates: &'static [u32],
+    pub encodings: &'static [AudioEncoding],
+}
+
+/// Features a session asks for. Everything defaults to "not needed".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CapabilityRequirements {
+    pub diarization: bool,
+    pub multichannel: bool,
+    pub keywords: usize,
+    pub translation: bool,
+    pub sample_rate: Option<u32>,
+    pub encoding: Option<AudioEncoding>,
+}
+
+impl Capabilities {
+    const BASELINE: Self = Self {
+        diarization: false,
+        native_multichannel: false,
+        keyword_boosting: false,
+        max_keywords: None,
+        punctuation: true,
+        word_timestamps: true,
+        translation: false,
+        max_session_secs: None,
+        sample_rates: ANY_SAMPLE_RATE,
+        encodings: &[AudioEncoding::Linear16],
+    };
+
+    pub fn supports_keywords(&self, count: usize) -> bool {
+        count == 0 || (self.keyword_boosting && self.max_keywords.is_none_or(|max| count <= max))
+    }
+
+    pub fn satisfies(&self, requirements: &CapabilityRequirements) -> bool {
+        (!requirements.diarization || self.diarization)
+            && (!requirements.multichannel || self.native_multichannel)
+            && (!requirements.translation || self.translation)
+            && self.supports_keywords(requirements.keywords)
+            && requirements
+                .sample_rate
+                .is_none_or(|rate| self.sample_rates.contains(&rate))
+            && requirements
+                .encoding
+                .is_none_or(|encoding| self.encodings.contains(&encoding))
+    }
+}
+
+impl AdapterKind {
+    pub fn capabilities(&self, model: Option<&str>) -> Capabilities {
+        let base = Capabilities::BASELINE;
+
+        match self {
+            // Local whisper and moonshine emit raw, lowercase text.
+            Self::Argmax => Capabilities {
+                keyword_boosting: true,
+                punctuation: false,
+                sample_rates: &[16000],
+                ..base
+            },
+            // https://developers.deepgram.com/docs/keywords#keyword-limits
+            // https://github.com/deepgram/deepgram-python-sdk/issues/503
+            Self::Deepgram => Capabilities {
+                diarization: true,
+                native_multichannel: true,
+                keyword_boosting: true,
+                max_keywords: Some(if uses_deepgram_keywords(model) {
+                    99
+                } else {
+                    50
+                }),
+                encodings: &[
+                    AudioEncoding::Linear16,
+                    AudioEncoding::Mulaw,
+                    AudioEncoding::Alaw,
+                    AudioEncoding::Opus,
+                    AudioEncoding::Flac,
+                ],
+                ..base
+            },
+            // https://www.assemblyai.com/docs/universal-streaming
+            Self::AssemblyAI => Capabilities {
+                keyword_boosting: true,
+                max_keywords: Some(100),
+                max_session_secs: Some(3 * HOUR_SECS),
+                encodings: &[AudioEncoding::Linear16, AudioEncoding::Mulaw],
+                ..base
+            },
+            // https://soniox.com/docs/stt/rt/limits-and-quotas
+            Self::Soniox => Capabilities {
+                diarization: true,
+                keyword_boosting: true,
+                translation: true,
+                max_session_secs: Some(5 * HOUR_SECS),
+                encodings: &[
+                    AudioEncoding::Linear16,
+                    AudioEncoding::Mulaw,
+                    AudioEncoding::Alaw,
+                    AudioEncoding::Opus,
+                    AudioEncoding::Flac,
+                ],
+                ..base
+            },
+            Self::Fireworks => Capabilities {
+                sample_rates: &[16000],
+                ..base
+            },
+            // Realtime transcription events carry no word timings. Transcription
+            // sessions only transcribe; translating needs a second model call.
+            Self::OpenAI => Capabilities {
+                word_timestamps: false,
+                max_session_secs: Some(HOUR_SECS),
+                sample_rates: &[24000],
+                encodings: &[
+                    AudioEncoding::Linear16,
+                    AudioEncoding::Mulaw,
+                    AudioEncoding::Alaw,
+                ],
+                ..base
+            },
+            Self::Gladia => Capabilities {
+                native_multichannel: true,
+                keyword_boosting: true,
+                translation: true,
+                sample_rates: &[8000, 16000, 32000, 44100, 48000],
+                encodings: &[
+                    AudioEncoding::Linear16,
+                    AudioEncoding::Mulaw,
+                    AudioEncoding::Alaw,
+                ],
+                ..base
+            },
+            Self::ElevenLabs => Capabilities {
+                sample_rates: &[8000, 16000, 22050, 24000, 44100, 48000],
+                encodings: &[AudioEncoding::Linear16, AudioEncoding::Mulaw],
+                ..base
+            },
+            // https://docs.speechmatics.com/speech-to-text/features/custom-dictionary
+            Self::Speechmatics => Capabilities {
+                diarization: true,
+                keyword_boosting: true,
+                max_keywords: Some(1000),
+                translation: true,
+                encodings: &[
+                    AudioEncoding::Linear16,
+                    AudioEncoding::Mulaw,
+                    AudioEncoding::Opus,
+                    AudioEncoding::Flac,
+                ],
+                ..base
+            },
+            // https://docs.rev.ai/api/streaming/
+            Self::RevAi => Capabilities {
+                max_session_secs: Some(3 * HOUR_SECS),
+                encodings: &[
+                    AudioEncoding::Linear16,
+                    AudioEncoding::Mulaw,
+                    AudioEncoding::Opus,
+                    AudioEncoding::Flac,
+                ],
+                ..base
+            },
+        }
+    }
+
+    pub fn satisfies(&self, model: Option<&str>, requirements: &CapabilityRequirements) -> bool {
+        self.capabilities(model).satisfies(requirements)
+    }
+}
+
+// nova-2 models take `keywords`; everything newer takes `keyterm`.
+pub(crate) fn uses_deepgram_keywords(model: Option<&str>) -> bool {
+    model.is_some_and(|m| m.contains("nova-2"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::*;
+
+    const ALL: [AdapterKind; 10] = [
+        AdapterKind::Argmax,
+        AdapterKind::Soniox,
+        AdapterKind::Fireworks,
+        AdapterKind::Deepgram,
+        AdapterKind::AssemblyAI,
+        AdapterKind::OpenAI,
+        AdapterKind::Gladia,
+        AdapterKind::ElevenLabs,
+        AdapterKind::Speechmatics,
+        AdapterKind::RevAi,
+    ];
+
+    fn native_multichannel(kind: AdapterKind) -> bool {
+        match kind {
+            AdapterKind::Argmax => ArgmaxAdapter.supports_native_multichannel(),
+            AdapterKind::Soniox => SonioxAdapter.supports_native_multichannel(),
+            AdapterKind::Fireworks => FireworksAdapter.supports_native_multichannel(),
+            AdapterKind::Deepgram => DeepgramAdapter.supports_native_multichannel(),
+            AdapterKind::AssemblyAI => AssemblyAIAdapter.supports_native_multichannel(),
+            AdapterKind::OpenAI => OpenAIAdapter.supports_native_multichannel(),
+            AdapterKind::Gladia => GladiaAdapter.supports_native_multichannel(),
+            AdapterKind::ElevenLabs => ElevenLabsAdapter.supports_native_multichannel(),
+            AdapterKind::Speechmatics => SpeechmaticsAdapter.supports_native_multichannel(),
+            AdapterKind::RevAi => RevAiAdapter.supports_native_multichannel(),
+        }
+    }
+
+    #[test]
+    fn test_multichannel_matches_adapters() {
+        for kind in ALL {
+            assert_eq!(
+                kind.capabilities(None).native_multichannel,
+                native_multichannel(kind),
+                "{kind}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_provider_takes_linear16() {
+        for kind in ALL {
+            let caps = kind.capabilities(None);
+            assert!(caps.encodings.contains(&AudioEncoding::Linear16), "{kind}");
+            assert!(!caps.sample_rates.is_empty(), "{kind}");
+        }
+    }
+
+    #[test]
+    fn test_deepgram_keyword_limit_depends_on_model() {
+        let kind = AdapterKind::Deepgram;
+        assert_eq!(kind.capabilities(None).max_keywords, Some(50));
+        assert_eq!(kind.capabilities(Some("nova-3")).max_keywords, Some(50));
+        assert_eq!(
+            kind.capabilities(Some("nova-2-meeting")).max_keywords,
+            Some(99)
+        );
+    }
+
+    #[test]
+    fn test_satisfies() {
+        let none = CapabilityRequirements::default();
+        for kind in ALL {
+            assert!(kind.satisfies(None, &none), "{kind}");
+        }
+
+        let diarized_stereo = CapabilityRequirements {
+            diarization: true,
+            multichannel: true,
+            ..Default::default()
+        };
+        let matching: Vec<_> = ALL
+            .into_iter()
+            .filter(|k| k.satisfies(None, &diarized_stereo))
+            .collect();
+        assert_eq!(matching, vec![AdapterKind::Deepgram]);
+
+        let many_keywords = CapabilityRequirements {
+            keywords: 80,
+            ..Default::default()
+        };
+        assert!(!AdapterKind::Deepgram.satisfies(Some("nova-3"), &many_keywords));
+        assert!(AdapterKind::Deepgram.satisfies(Some("nova-2"), &many_keywords));
+        assert!(AdapterKind::Soniox.satisfies(None, &many_keywords));
+        assert!(!AdapterKind::RevAi.satisfies(None, &many_keywords));
+
+        let openai_rate = CapabilityRequirements {
+            sample_rate: Some(16000),
+            ..Default::default()
+        };
+        assert!(!AdapterKind::OpenAI.satisfies(None, &openai_rate));
+        assert!(AdapterKind::Deepgram.satisfies(None, &openai_rate));
+
+        let opus = CapabilityRequirements {
+            encoding: Some(AudioEncoding::Opus),
+            ..Default::default()
+        };
+        assert!(AdapterKind::Deepgram.satisfies(None, &opus));
+        assert!(!AdapterKind::AssemblyAI.satisfies(None, &opus));
+    }
+}
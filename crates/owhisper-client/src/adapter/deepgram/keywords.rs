@@ -1,5 +1,7 @@
 use owhisper_interface::ListenParams;
 
+use crate::adapter::AdapterKind;
+use crate::adapter::capabilities::uses_deepgram_keywords;
 use crate::adapter::deepgram_compat::{KeywordQueryStrategy, Serializer, UrlQuery};
 
 pub struct DeepgramKeywordStrategy;
@@ -14,20 +16,16 @@ impl KeywordQueryStrategy for DeepgramKeywordStrategy {
             return;
         }
 
-        let use_keywords = params
-            .model
-            .as_ref()
-            .map(|model| model.contains("nova-2"))
-            .unwrap_or(false);
-
-        let param_name = if use_keywords { "keywords" } else { "keyterm" };
-        let max_keywords = if use_keywords {
-            // https://developers.deepgram.com/docs/keywords#keyword-limits
-            99
+        let model = params.model.as_deref();
+        let param_name = if uses_deepgram_keywords(model) {
+            "keywords"
         } else {
-            // https://github.com/deepgram/deepgram-python-sdk/issues/503
-            50
+            "keyterm"
         };
+        let max_keywords = AdapterKind::Deepgram
+            .capabilities(model)
+            .max_keywords
+            .unwrap_or(usize::MAX);
 
         for keyword in params.keywords.iter().take(max_keywords) {
             query_pairs.append_pair(param_name, keyword);
@@ -214,6 +214,7 @@ impl ElevenLabsAdapter {
                 words: parsed_words,
                 confidence: 1.0,
                 languages: vec![],
+                translation: None,
             }],
         };
 
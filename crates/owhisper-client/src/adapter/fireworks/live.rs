@@ -125,6 +125,7 @@ impl RealtimeSttAdapter for FireworksAdapter {
                         words,
                         confidence: 1.0,
                         languages: vec![],
+                        translation: None,
                     }],
                 };
 
@@ -170,6 +171,7 @@ impl RealtimeSttAdapter for FireworksAdapter {
                     words,
                     confidence: 1.0,
                     languages: vec![],
+                    translation: None,
                 }],
             };
 
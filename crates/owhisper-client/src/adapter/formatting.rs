@@ -0,0 +1,174 @@
+use hypr_language::Language;
+use hypr_transcript_postprocess::Formatter;
+use owhisper_interface::stream::{StreamResponse, Word};
+
+use super::AdapterKind;
+
+impl AdapterKind {
+    /// Terminal punctuation, casing and English number formatting for
+    /// providers that return raw text. `None` when the provider formats
+    /// transcripts itself.
+    pub fn transcript_formatter(
+        &self,
+        model: Option<&str>,
+        languages: &[Language],
+    ) -> Option<TranscriptFormatter> {
+        if self.capabilities(model).punctuation {
+            return None;
+        }
+
+        Some(TranscriptFormatter(Formatter::new(languages)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TranscriptFormatter(Formatter);
+
+impl TranscriptFormatter {
+    /// Formats every alternative in place. Terminal punctuation is only added
+    /// to final results, so partials don't flicker a trailing period.
+    pub fn format(&self, response: &mut StreamResponse) {
+        let StreamResponse::TranscriptResponse {
+            is_final, channel, ..
+        } = response
+        else {
+            return;
+        };
+
+        for alternative in &mut channel.alternatives {
+            alternative.transcript = self.0.format_text(&alternative.transcript, *is_final);
+            alternative.words =
+                self.format_words(std::mem::take(&mut alternative.words), *is_final);
+        }
+    }
+
+    // Like vocabulary corrections, a phrase written as one token ("25%")
+    // collapses into a single word covering its time range.
+    fn format_words(&self, words: Vec<Word>, end_of_segment: bool) -> Vec<Word> {
+        let tokens: Vec<&str> = words
+            .iter()
+            .map(|w| w.punctuated_word.as_deref().unwrap_or(&w.word))
+            .collect();
+        let spans = self.0.format(&tokens, end_of_segment);
+
+        spans
+            .into_iter()
+            .map(|span| {
+                let group = &words[span.start..span.start + span.len];
+                let first = &group[0];
+                let word = if span.len == 1 {
+                    first.word.clone()
+                } else {
+                    span.text
+                        .trim_end_matches(|c: char| c.is_ascii_punctuation() && c != '%')
+                        .to_lowercase()
+                };
+
+                Word {
+                    word,
+                    start: first.start,
+                    end: group[group.len() - 1].end,
+                    confidence: group
+                        .iter()
+                        .map(|w| w.confidence)
+                        .fold(f64::INFINITY, f64::min),
+                    speaker: first.speaker,
+                    punctuated_word: Some(span.text),
+                    language: first.language.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_language::ISO639;
+    use owhisper_interface::stream::{Alternatives, Channel, Metadata};
+
+    fn word(text: &str, start: f64) -> Word {
+        Word {
+            word: text.to_string(),
+            start,
+            end: start + 0.2,
+            confidence: 0.9,
+            speaker: None,
+            punctuated_word: None,
+            language: None,
+        }
+    }
+
+    fn response(is_final: bool, texts: &[&str]) -> StreamResponse {
+        StreamResponse::TranscriptResponse {
+            start: 0.0,
+            duration: 1.0,
+            is_final,
+            speech_final: is_final,
+            from_finalize: false,
+            channel: Channel {
+                alternatives: vec![Alternatives {
+                    transcript: texts.join(" "),
+                    words: texts
+                        .iter()
+                        .enumerate()
+                        .map(|(i, text)| word(text, i as f64 * 0.2))
+                        .collect(),
+                    confidence: 0.9,
+                    languages: vec![],
+                    translation: None,
+                }],
+            },
+            metadata: Metadata::default(),
+            channel_index: vec![0, 1],
+        }
+    }
+
+    #[test]
+    fn test_formatter_only_for_unpunctuated_providers() {
+        let languages = [Language::new(ISO639::En)];
+
+        assert!(
+            AdapterKind::Deepgram
+                .transcript_formatter(None, &languages)
+                .is_none()
+        );
+        assert!(
+            AdapterKind::Argmax
+                .transcript_formatter(None, &languages)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_format_response() {
+        let formatter = AdapterKind::Argmax
+            .transcript_formatter(None, &[Language::new(ISO639::En)])
+            .unwrap();
+
+        let mut partial = response(false, &["i", "got", "twenty", "five", "percent"]);
+        formatter.format(&mut partial);
+        let StreamResponse::TranscriptResponse { channel, .. } = &partial else {
+            unreachable!();
+        };
+        assert_eq!(channel.alternatives[0].transcript, "I got 25%");
+
+        let mut response = response(true, &["i", "got", "twenty", "five", "percent"]);
+        formatter.format(&mut response);
+        let StreamResponse::TranscriptResponse { channel, .. } = &response else {
+            unreachable!();
+        };
+        let alternative = &channel.alternatives[0];
+        assert_eq!(alternative.transcript, "I got 25%.");
+        assert_eq!(alternative.words.len(), 3);
+        assert_eq!(alternative.words[0].word, "i");
+        assert_eq!(alternative.words[0].punctuated_word.as_deref(), Some("I"));
+        assert_eq!(alternative.words[2].word, "25%");
+        assert_eq!(
+            alternative.words[2].punctuated_word.as_deref(),
+            Some("25%.")
+        );
+        assert!((alternative.words[2].start - 0.4).abs() < 1e-9);
+        assert!((alternative.words[2].end - 1.0).abs() < 1e-9);
+    }
+}
@@ -11,7 +11,10 @@ use owhisper_interface::batch::{
 use serde::{Deserialize, Serialize};
 
 use super::GladiaAdapter;
-use crate::adapter::{BatchFuture, BatchSttAdapter, ClientWithMiddleware};
+use crate::adapter::{
+    AsyncBatchSttAdapter, BatchFuture, BatchSttAdapter, ClientWithMiddleware, JobFuture, JobStatus,
+    JobStatusFuture,
+};
 use crate::error::Error;
 use crate::polling::{PollingConfig, PollingResult, poll_until};
 
@@ -39,6 +42,35 @@ impl BatchSttAdapter for GladiaAdapter {
     }
 }
 
+impl AsyncBatchSttAdapter for GladiaAdapter {
+    fn submit_job<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        params: &'a ListenParams,
+        audio_url: &'a str,
+    ) -> JobFuture<'a> {
+        Box::pin(Self::submit(
+            client,
+            api_base,
+            api_key,
+            params,
+            audio_url.to_string(),
+        ))
+    }
+
+    fn job_status<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        job_id: &'a str,
+    ) -> JobStatusFuture<'a> {
+        Box::pin(Self::status(client, api_base, api_key, job_id))
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct TranscriptRequest<'a> {
     audio_url: String,
@@ -196,6 +228,33 @@ impl GladiaAdapter {
 
         let upload_result: UploadResponse = upload_response.json().await?;
 
+        let transcript_id =
+            Self::submit(client, api_base, api_key, params, upload_result.audio_url).await?;
+
+        let config = PollingConfig::default()
+            .with_interval(Duration::from_secs(3))
+            .with_timeout_error("transcription timed out".to_string());
+
+        poll_until(
+            || async {
+                Self::status(client, api_base, api_key, &transcript_id)
+                    .await
+                    .map(PollingResult::from)
+            },
+            config,
+        )
+        .await
+    }
+
+    async fn submit(
+        client: &ClientWithMiddleware,
+        api_base: &str,
+        api_key: &str,
+        params: &ListenParams,
+        audio_url: String,
+    ) -> Result<String, Error> {
+        let base_url = Self::batch_api_url(api_base);
+
         let languages: Vec<String> = params
             .languages
             .iter()
@@ -217,7 +276,7 @@ impl GladiaAdapter {
         };
 
         let transcript_request = TranscriptRequest {
-            audio_url: upload_result.audio_url,
+            audio_url,
             model,
             language_config,
             diarization: Some(true),
@@ -243,51 +302,47 @@ impl GladiaAdapter {
         }
 
         let create_result: InitResponse = create_response.json().await?;
-        let transcript_id = create_result.id;
+        Ok(create_result.id)
+    }
 
-        let poll_url = format!("{}/pre-recorded/{}", base_url, transcript_id);
+    async fn status(
+        client: &ClientWithMiddleware,
+        api_base: &str,
+        api_key: &str,
+        transcript_id: &str,
+    ) -> Result<JobStatus, Error> {
+        let poll_url = format!(
+            "{}/pre-recorded/{}",
+            Self::batch_api_url(api_base),
+            transcript_id
+        );
 
-        let config = PollingConfig::default()
-            .with_interval(Duration::from_secs(3))
-            .with_timeout_error("transcription timed out".to_string());
+        let poll_response = client
+            .get(&poll_url)
+            .header("x-gladia-key", api_key)
+            .send()
+            .await?;
 
-        poll_until(
-            || async {
-                let poll_response = client
-                    .get(&poll_url)
-                    .header("x-gladia-key", api_key)
-                    .send()
-                    .await?;
-
-                let poll_status = poll_response.status();
-                if !poll_status.is_success() {
-                    return Err(Error::UnexpectedStatus {
-                        status: poll_status,
-                        body: poll_response.text().await.unwrap_or_default(),
-                    });
-                }
-
-                let result: TranscriptResponse = poll_response.json().await?;
-
-                match result.status.as_str() {
-                    "done" => Ok(PollingResult::Complete(Self::convert_to_batch_response(
-                        result,
-                    ))),
-                    "error" => {
-                        let error_msg = result
-                            .error_code
-                            .unwrap_or_else(|| "unknown error".to_string());
-                        Ok(PollingResult::Failed(format!(
-                            "transcription failed: {}",
-                            error_msg
-                        )))
-                    }
-                    _ => Ok(PollingResult::Continue),
-                }
-            },
-            config,
-        )
-        .await
+        let poll_status = poll_response.status();
+        if !poll_status.is_success() {
+            return Err(Error::UnexpectedStatus {
+                status: poll_status,
+                body: poll_response.text().await.unwrap_or_default(),
+            });
+        }
+
+        let result: TranscriptResponse = poll_response.json().await?;
+
+        Ok(match result.status.as_str() {
+            "done" => JobStatus::Completed(Self::convert_to_batch_response(result)),
+            "error" => {
+                let error_msg = result
+                    .error_code
+                    .unwrap_or_else(|| "unknown error".to_string());
+                JobStatus::Failed(format!("transcription failed: {}", error_msg))
+            }
+            _ => JobStatus::Pending,
+        })
     }
 
     fn convert_to_batch_response(response: TranscriptResponse) -> BatchResponse {
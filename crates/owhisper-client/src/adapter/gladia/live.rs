@@ -5,7 +5,7 @@ use std::sync::{Mutex, OnceLock};
 
 use hypr_ws_client::client::Message;
 use owhisper_interface::ListenParams;
-use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse};
+use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse, Translation};
 use serde::{Deserialize, Serialize};
 
 use super::GladiaAdapter;
@@ -123,6 +123,10 @@ impl RealtimeSttAdapter for GladiaAdapter {
                 None => None,
             };
 
+            let translation_config = params.translation.as_ref().map(|t| TranslationConfig {
+                target_languages: vec![t.target_language.iso639().code().to_string()],
+            });
+
             let has_keywords = !params.keywords.is_empty();
             let custom_vocabulary_config = has_keywords.then(|| CustomVocabularyConfig {
                 vocabulary: params
@@ -152,6 +156,8 @@ impl RealtimeSttAdapter for GladiaAdapter {
                     words_accurate_timestamps: true,
                     custom_vocabulary: has_keywords,
                     custom_vocabulary_config,
+                    translation: translation_config.is_some(),
+                    translation_config,
                 }),
             };
 
@@ -225,6 +231,7 @@ impl RealtimeSttAdapter for GladiaAdapter {
 
         match msg {
             GladiaMessage::Transcript(transcript) => Self::parse_transcript(transcript),
+            GladiaMessage::Translation(translation) => Self::parse_translation(translation),
             GladiaMessage::StartSession { id } => {
                 tracing::debug!(session_id = %id, "gladia_session_started");
                 vec![]
@@ -326,6 +333,15 @@ struct RealtimeProcessing {
     custom_vocabulary: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     custom_vocabulary_config: Option<CustomVocabularyConfig>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    translation: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translation_config: Option<TranslationConfig>,
+}
+
+#[derive(Serialize)]
+struct TranslationConfig {
+    target_languages: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -371,6 +387,8 @@ enum InitResponse {
 enum GladiaMessage {
     #[serde(rename = "transcript")]
     Transcript(TranscriptMessage),
+    #[serde(rename = "translation")]
+    Translation(TranslationMessage),
     #[serde(rename = "start_session")]
     StartSession { id: String },
     #[serde(rename = "end_session")]
@@ -422,6 +440,20 @@ struct TranscriptData {
     utterance: Utterance,
 }
 
+// https://docs.gladia.io/api-reference/v2/live/message/translation
+#[derive(Debug, Deserialize)]
+struct TranslationMessage {
+    #[serde(default)]
+    session_id: String,
+    data: TranslationData,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslationData {
+    target_language: String,
+    translated_utterance: Utterance,
+}
+
 #[derive(Debug, Deserialize)]
 struct Utterance {
     #[serde(default)]
@@ -486,6 +518,7 @@ impl GladiaAdapter {
                 words,
                 confidence: 1.0,
                 languages: utterance.language.map(|l| vec![l]).unwrap_or_default(),
+                translation: None,
             }],
         };
 
@@ -505,12 +538,53 @@ impl GladiaAdapter {
     }
 }
 
+impl GladiaAdapter {
+    // Translations arrive after the utterance they belong to has already been
+    // emitted as final, so they are sent on their own without words.
+    fn parse_translation(msg: TranslationMessage) -> Vec<StreamResponse> {
+        let data = msg.data;
+        let utterance = data.translated_utterance;
+
+        if utterance.text.is_empty() {
+            return vec![];
+        }
+
+        let channel = Channel {
+            alternatives: vec![Alternatives {
+                transcript: String::new(),
+                words: vec![],
+                confidence: 1.0,
+                languages: vec![],
+                translation: Some(Translation {
+                    language: data.target_language,
+                    text: utterance.text,
+                }),
+            }],
+        };
+
+        let channel_idx = utterance.channel.unwrap_or(0);
+        let total_channels = SessionChannels::get_or_infer(&msg.session_id, channel_idx);
+
+        vec![StreamResponse::TranscriptResponse {
+            is_final: true,
+            speech_final: false,
+            from_finalize: false,
+            start: utterance.start,
+            duration: utterance.end - utterance.start,
+            channel,
+            metadata: Metadata::default(),
+            channel_index: vec![channel_idx, total_channels as i32],
+        }]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use hypr_language::ISO639;
 
     use super::{GladiaAdapter, LanguageConfig};
     use crate::ListenClient;
+    use crate::adapter::RealtimeSttAdapter;
     use crate::test_utils::{UrlTestCase, run_dual_test, run_single_test, run_url_test_cases};
 
     const API_BASE: &str = "https://api.gladia.io";
@@ -609,6 +683,30 @@ mod tests {
         assert!(json.contains("\"languages\":[\"en\",\"fr\"]"));
     }
 
+    #[test]
+    fn test_parse_translation_message() {
+        let raw = r#"{
+            "session_id": "translation-session",
+            "type": "translation",
+            "data": {
+                "utterance_id": "00-00000001",
+                "original_language": "en",
+                "target_language": "fr",
+                "utterance": {"text": "Hello world", "start": 1.0, "end": 2.0, "channel": 0, "words": []},
+                "translated_utterance": {"text": "Bonjour le monde", "language": "fr", "start": 1.0, "end": 2.0, "channel": 0, "words": []}
+            }
+        }"#;
+
+        let responses = GladiaAdapter.parse_response(raw);
+        assert_eq!(responses.len(), 1);
+
+        let response = &responses[0];
+        assert_eq!(response.text(), Some(""));
+        let translation = response.translation().unwrap();
+        assert_eq!(translation.language, "fr");
+        assert_eq!(translation.text, "Bonjour le monde");
+    }
+
     macro_rules! single_test {
         ($name:ident, $params:expr) => {
             #[tokio::test]
@@ -46,6 +46,13 @@ impl RealtimeSttAdapter for HyprnoteAdapter {
                 query.append_pair("keyword", keyword);
             }
 
+            if let Some(translation) = &params.translation {
+                query.append_pair(
+                    "translation_language",
+                    translation.target_language.to_string().as_str(),
+                );
+            }
+
             if let Some(custom) = &params.custom_query {
                 for (key, value) in custom {
                     query.append_pair(key, value);
@@ -132,6 +139,24 @@ mod tests {
         assert!(url_str.contains("keyword=transcription"));
     }
 
+    #[test]
+    fn test_url_with_translation() {
+        let adapter = HyprnoteAdapter::default();
+        let params = owhisper_interface::ListenParams {
+            languages: vec![ISO639::En.into()],
+            translation: Some(owhisper_interface::TranslationParams {
+                target_language: ISO639::Es.into(),
+            }),
+            ..Default::default()
+        };
+
+        let url = adapter.build_ws_url(API_BASE, &params, 1);
+        assert!(url.as_str().contains("translation_language=es"));
+
+        let url = adapter.build_ws_url(API_BASE, &Default::default(), 1);
+        assert!(!url.as_str().contains("translation_language"));
+    }
+
     #[test]
     fn test_url_with_custom_query() {
         let adapter = HyprnoteAdapter::default();
@@ -2,10 +2,12 @@ mod argmax;
 pub(crate) mod assemblyai;
 #[cfg(feature = "argmax")]
 pub mod audio;
+pub(crate) mod capabilities;
 pub mod deepgram;
 mod deepgram_compat;
 pub(crate) mod elevenlabs;
 mod fireworks;
+pub(crate) mod formatting;
 mod gladia;
 pub mod http;
 mod hyprnote;
@@ -13,19 +15,27 @@ mod language;
 mod openai;
 mod owhisper;
 pub mod parsing;
+pub(crate) mod revai;
 pub(crate) mod soniox;
+pub(crate) mod speechmatics;
 mod url_builder;
+pub(crate) mod vocabulary;
 
 pub use argmax::*;
 pub use assemblyai::*;
+pub use capabilities::{AudioEncoding, Capabilities, CapabilityRequirements};
 pub use deepgram::*;
 pub use elevenlabs::*;
 pub use fireworks::*;
+pub use formatting::TranscriptFormatter;
 pub use gladia::*;
 pub use hyprnote::*;
 pub use language::{LanguageQuality, LanguageSupport};
 pub use openai::*;
+pub use revai::*;
 pub use soniox::*;
+pub use speechmatics::*;
+pub use vocabulary::VocabularyCorrector;
 
 use std::collections::{BTreeSet, HashSet};
 use std::future::Future;
@@ -42,6 +52,8 @@ use crate::error::Error;
 pub use reqwest_middleware::ClientWithMiddleware;
 
 pub type BatchFuture<'a> = Pin<Box<dyn Future<Output = Result<BatchResponse, Error>> + Send + 'a>>;
+pub type JobFuture<'a> = Pin<Box<dyn Future<Output = Result<String, Error>> + Send + 'a>>;
+pub type JobStatusFuture<'a> = Pin<Box<dyn Future<Output = Result<JobStatus, Error>> + Send + 'a>>;
 
 pub fn documented_language_codes_live() -> Vec<String> {
     let mut set: BTreeSet<&'static str> = BTreeSet::new();
@@ -51,6 +63,8 @@ pub fn documented_language_codes_live() -> Vec<String> {
     set.extend(gladia::documented_language_codes().iter().copied());
     set.extend(assemblyai::documented_language_codes_live().iter().copied());
     set.extend(elevenlabs::documented_language_codes());
+    set.extend(speechmatics::documented_language_codes().iter().copied());
+    set.extend(revai::documented_language_codes_live().iter().copied());
     set.extend(argmax::PARAKEET_V3_LANGS.iter().copied());
 
     set.into_iter().map(str::to_string).collect()
@@ -68,6 +82,8 @@ pub fn documented_language_codes_batch() -> Vec<String> {
             .copied(),
     );
     set.extend(elevenlabs::documented_language_codes());
+    set.extend(speechmatics::documented_language_codes().iter().copied());
+    set.extend(revai::documented_language_codes_batch().iter().copied());
     set.extend(argmax::PARAKEET_V3_LANGS.iter().copied());
 
     set.into_iter().map(str::to_string).collect()
@@ -136,6 +152,36 @@ pub trait BatchSttAdapter: Clone + Default + Send + Sync + 'static {
     ) -> BatchFuture<'a>;
 }
 
+#[derive(Debug, Clone)]
+pub enum JobStatus {
+    Pending,
+    Completed(BatchResponse),
+    Failed(String),
+}
+
+/// Batch transcription of audio the provider fetches from a URL, submitted
+/// as a job and collected later. Large recordings go through this path so
+/// the desktop never streams hours of audio to the provider in one request.
+pub trait AsyncBatchSttAdapter: BatchSttAdapter {
+    /// Submits `audio_url` and returns the provider's job id.
+    fn submit_job<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        params: &'a ListenParams,
+        audio_url: &'a str,
+    ) -> JobFuture<'a>;
+
+    fn job_status<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        job_id: &'a str,
+    ) -> JobStatusFuture<'a>;
+}
+
 pub fn set_scheme_from_host(url: &mut url::Url) {
     if let Some(host) = url.host_str() {
         if is_local_host(host) {
@@ -260,6 +306,10 @@ pub enum AdapterKind {
     Gladia,
     #[strum(serialize = "elevenlabs")]
     ElevenLabs,
+    #[strum(serialize = "speechmatics")]
+    Speechmatics,
+    #[strum(serialize = "revai")]
+    RevAi,
 }
 
 impl AdapterKind {
@@ -303,6 +353,8 @@ impl AdapterKind {
             Self::OpenAI => OpenAIAdapter::language_support_live(languages),
             Self::Fireworks => FireworksAdapter::language_support_live(languages),
             Self::ElevenLabs => ElevenLabsAdapter::language_support_live(languages),
+            Self::Speechmatics => SpeechmaticsAdapter::language_support_live(languages),
+            Self::RevAi => RevAiAdapter::language_support_live(languages),
             Self::Argmax => ArgmaxAdapter::language_support_live(languages, model),
         }
     }
@@ -323,6 +375,8 @@ impl AdapterKind {
             Self::OpenAI => OpenAIAdapter::language_support_batch(languages),
             Self::Fireworks => FireworksAdapter::language_support_batch(languages),
             Self::ElevenLabs => ElevenLabsAdapter::language_support_batch(languages),
+            Self::Speechmatics => SpeechmaticsAdapter::language_support_batch(languages),
+            Self::RevAi => RevAiAdapter::language_support_batch(languages),
             Self::Argmax => ArgmaxAdapter::language_support_batch(languages, model),
         }
     }
@@ -365,6 +419,8 @@ impl From<crate::providers::Provider> for AdapterKind {
             Provider::OpenAI => Self::OpenAI,
             Provider::Gladia => Self::Gladia,
             Provider::ElevenLabs => Self::ElevenLabs,
+            Provider::Speechmatics => Self::Speechmatics,
+            Provider::RevAi => Self::RevAi,
         }
     }
 }
@@ -358,6 +358,9 @@ impl OpenAIAdapter {
                 words,
                 confidence: 1.0,
                 languages: vec![],
+                // `transcription` sessions have no translation output, so
+                // `params.translation` is ignored for OpenAI.
+                translation: None,
             }],
         };
 
@@ -78,6 +78,11 @@ impl WordBuilder {
         self
     }
 
+    pub fn punctuated_word(mut self, punctuated_word: impl Into<String>) -> Self {
+        self.punctuated_word = Some(punctuated_word.into());
+        self
+    }
+
     pub fn language(mut self, language: Option<String>) -> Self {
         self.language = language;
         self
@@ -0,0 +1,310 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use owhisper_interface::ListenParams;
+use owhisper_interface::batch::{
+    Alternatives as BatchAlternatives, Channel as BatchChannel, Response as BatchResponse,
+    Results as BatchResults, Word as BatchWord,
+};
+use serde::{Deserialize, Serialize};
+
+use super::language::language_code;
+use super::{RevAiAdapter, RevAiElement, collect_words, elements_to_text};
+use crate::adapter::http::ensure_success;
+use crate::adapter::{BatchFuture, BatchSttAdapter, ClientWithMiddleware};
+use crate::error::Error;
+use crate::polling::{PollingConfig, PollingResult, poll_until};
+use crate::providers::Provider;
+
+const TRANSCRIPT_CONTENT_TYPE: &str = "application/vnd.rev.transcript.v1.0+json";
+
+// https://docs.rev.ai/api/asynchronous/reference/
+impl BatchSttAdapter for RevAiAdapter {
+    fn is_supported_languages(
+        &self,
+        languages: &[hypr_language::Language],
+        _model: Option<&str>,
+    ) -> bool {
+        RevAiAdapter::is_supported_languages_batch(languages)
+    }
+
+    fn transcribe_file<'a, P: AsRef<Path> + Send + 'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        params: &'a ListenParams,
+        file_path: P,
+    ) -> BatchFuture<'a> {
+        let path = file_path.as_ref().to_path_buf();
+        Box::pin(Self::do_transcribe_file(
+            client, api_base, api_key, params, path,
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JobOptions<'a> {
+    transcriber: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    custom_vocabularies: Vec<CustomVocabulary<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct CustomVocabulary<'a> {
+    phrases: &'a [String],
+}
+
+#[derive(Debug, Deserialize)]
+struct JobResponse {
+    id: String,
+    status: String,
+    #[serde(default)]
+    failure_detail: Option<String>,
+    #[serde(default)]
+    duration_seconds: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptResponse {
+    #[serde(default)]
+    monologues: Vec<Monologue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Monologue {
+    #[serde(default)]
+    speaker: Option<usize>,
+    #[serde(default)]
+    elements: Vec<RevAiElement>,
+}
+
+impl RevAiAdapter {
+    async fn do_transcribe_file(
+        client: &ClientWithMiddleware,
+        api_base: &str,
+        api_key: &str,
+        params: &ListenParams,
+        file_path: PathBuf,
+    ) -> Result<BatchResponse, Error> {
+        let base_url = Self::batch_api_url(api_base);
+        let auth = format!("Bearer {}", api_key);
+
+        let file_name = file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("audio.wav")
+            .to_string();
+
+        let file_bytes = tokio::fs::read(&file_path).await.map_err(|e| {
+            Error::AudioProcessing(format!(
+                "failed to read file {}: {}",
+                file_path.display(),
+                e
+            ))
+        })?;
+
+        let default = Provider::RevAi.default_batch_model();
+        let transcriber = match params.model.as_deref() {
+            Some(m) if crate::providers::is_meta_model(m) => default,
+            Some(m) => m,
+            None => default,
+        };
+
+        let options = JobOptions {
+            transcriber,
+            language: params.languages.first().map(language_code),
+            custom_vocabularies: if params.keywords.is_empty() {
+                vec![]
+            } else {
+                vec![CustomVocabulary {
+                    phrases: &params.keywords,
+                }]
+            },
+        };
+        let options_json = serde_json::to_string(&options)
+            .map_err(|e| Error::AudioProcessing(format!("failed to encode options: {}", e)))?;
+
+        let form = reqwest::multipart::Form::new()
+            .part(
+                "media",
+                reqwest::multipart::Part::bytes(file_bytes).file_name(file_name),
+            )
+            .text("options", options_json);
+
+        let create_response = client
+            .post(format!("{}/jobs", base_url))
+            .header("Authorization", &auth)
+            .multipart(form)
+            .send()
+            .await?;
+
+        let create_response = ensure_success(create_response).await?;
+        let job_id = create_response.json::<JobResponse>().await?.id;
+
+        let job_url = format!("{}/jobs/{}", base_url, job_id);
+
+        let config = PollingConfig::default()
+            .with_interval(Duration::from_secs(3))
+            .with_timeout_error("transcription timed out".to_string());
+
+        let job = poll_until(
+            || async {
+                let poll_response = client
+                    .get(&job_url)
+                    .header("Authorization", &auth)
+                    .send()
+                    .await?;
+
+                let poll_response = ensure_success(poll_response).await?;
+                let result: JobResponse = poll_response.json().await?;
+
+                match result.status.as_str() {
+                    "transcribed" => Ok(PollingResult::Complete(result)),
+                    "failed" => {
+                        let error_msg = result
+                            .failure_detail
+                            .unwrap_or_else(|| "unknown error".to_string());
+                        Ok(PollingResult::Failed(format!(
+                            "transcription failed: {}",
+                            error_msg
+                        )))
+                    }
+                    _ => Ok(PollingResult::Continue),
+                }
+            },
+            config,
+        )
+        .await?;
+
+        let transcript_response = client
+            .get(format!("{}/transcript", job_url))
+            .header("Authorization", &auth)
+            .header("Accept", TRANSCRIPT_CONTENT_TYPE)
+            .send()
+            .await?;
+
+        let transcript_response = ensure_success(transcript_response).await?;
+        let transcript: TranscriptResponse = transcript_response.json().await?;
+
+        Ok(Self::convert_to_batch_response(
+            transcript,
+            job.duration_seconds,
+        ))
+    }
+
+    fn convert_to_batch_response(
+        response: TranscriptResponse,
+        duration_seconds: Option<f64>,
+    ) -> BatchResponse {
+        let mut words: Vec<BatchWord> = Vec::new();
+        let mut paragraphs: Vec<String> = Vec::new();
+
+        for monologue in &response.monologues {
+            let text = elements_to_text(&monologue.elements);
+            if !text.is_empty() {
+                paragraphs.push(text);
+            }
+
+            words.extend(
+                collect_words(&monologue.elements)
+                    .into_iter()
+                    .map(|w| BatchWord {
+                        word: w.word,
+                        start: w.start,
+                        end: w.end,
+                        confidence: w.confidence,
+                        speaker: monologue.speaker,
+                        punctuated_word: Some(w.punctuated_word),
+                    }),
+            );
+        }
+
+        let confidence = if words.is_empty() {
+            1.0
+        } else {
+            words.iter().map(|w| w.confidence).sum::<f64>() / words.len() as f64
+        };
+
+        let channel = BatchChannel {
+            alternatives: vec![BatchAlternatives {
+                transcript: paragraphs.join(" "),
+                confidence,
+                words,
+            }],
+        };
+
+        BatchResponse {
+            metadata: serde_json::json!({
+                "audio_duration": duration_seconds,
+            }),
+            results: BatchResults {
+                channels: vec![channel],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_client::create_client;
+
+    #[test]
+    fn test_convert_to_batch_response() {
+        let response: TranscriptResponse = serde_json::from_str(
+            r#"{
+                "monologues": [
+                    {"speaker": 0, "elements": [
+                        {"type": "text", "value": "Hi", "ts": 0.3, "end_ts": 0.6, "confidence": 1.0},
+                        {"type": "punct", "value": "."}
+                    ]},
+                    {"speaker": 1, "elements": [
+                        {"type": "text", "value": "Hello", "ts": 1.0, "end_ts": 1.4, "confidence": 0.8},
+                        {"type": "punct", "value": " "},
+                        {"type": "text", "value": "there", "ts": 1.5, "end_ts": 1.9, "confidence": 0.9},
+                        {"type": "punct", "value": "!"}
+                    ]}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let batch = RevAiAdapter::convert_to_batch_response(response, Some(2.5));
+        let alt = &batch.results.channels[0].alternatives[0];
+        assert_eq!(alt.transcript, "Hi. Hello there!");
+        assert_eq!(alt.words.len(), 3);
+        assert_eq!(alt.words[0].speaker, Some(0));
+        assert_eq!(alt.words[2].speaker, Some(1));
+        assert_eq!(alt.words[2].punctuated_word.as_deref(), Some("there!"));
+        assert!((alt.confidence - 0.9).abs() < 1e-9);
+        assert_eq!(batch.metadata["audio_duration"], 2.5);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_revai_batch_transcription() {
+        let api_key = std::env::var("REVAI_API_KEY").expect("REVAI_API_KEY not set");
+        let client = create_client();
+        let adapter = RevAiAdapter::default();
+        let params = ListenParams::default();
+
+        let audio_path = std::path::PathBuf::from(hypr_data::english_1::AUDIO_PATH);
+
+        let result = adapter
+            .transcribe_file(&client, "", &api_key, &params, &audio_path)
+            .await
+            .expect("transcription failed");
+
+        assert!(!result.results.channels.is_empty());
+        assert!(!result.results.channels[0].alternatives.is_empty());
+        assert!(
+            !result.results.channels[0].alternatives[0]
+                .transcript
+                .is_empty()
+        );
+        assert!(!result.results.channels[0].alternatives[0].words.is_empty());
+    }
+}
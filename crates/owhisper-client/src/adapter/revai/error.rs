@@ -0,0 +1,139 @@
+// https://docs.rev.ai/api/asynchronous/errors/
+// https://docs.rev.ai/api/streaming/responses/#close-codes
+//
+// Streaming failures are reported as close codes rather than messages, so
+// they are handled by `detect_close_error`; `detect_error` covers the JSON
+// problem details returned by the HTTP API.
+
+use serde::Deserialize;
+
+use crate::error_detection::ProviderError;
+
+const ERROR_TYPE_PREFIX: &str = "https://www.rev.ai/api/v1/errors/";
+
+#[derive(Deserialize)]
+struct RevAiError<'a> {
+    #[serde(borrow, rename = "type")]
+    error_type: Option<&'a str>,
+    #[serde(borrow)]
+    title: Option<&'a str>,
+    #[serde(borrow)]
+    detail: Option<&'a str>,
+    status: Option<u16>,
+}
+
+pub fn detect_error(data: &[u8]) -> Option<ProviderError> {
+    let text = std::str::from_utf8(data).ok()?;
+    let parsed: RevAiError = serde_json::from_str(text).ok()?;
+
+    let provider_code = parsed.error_type?.strip_prefix(ERROR_TYPE_PREFIX)?;
+    let code = parsed
+        .status
+        .unwrap_or_else(|| map_error_type(provider_code));
+    let message = parsed
+        .detail
+        .or(parsed.title)
+        .unwrap_or("Unknown error")
+        .to_string();
+
+    Some(ProviderError::new(code, message).with_provider_code(provider_code))
+}
+
+pub fn detect_close_error(code: u16, reason: &str) -> Option<ProviderError> {
+    let http_code = match code {
+        4001 => 401,
+        4002 => 400,
+        4003 => 402,
+        4013 | 4029 => 429,
+        _ => return None,
+    };
+    let message = if reason.is_empty() {
+        "Unknown error"
+    } else {
+        reason
+    };
+
+    Some(ProviderError::new(http_code, message).with_provider_code(code.to_string()))
+}
+
+fn map_error_type(error_type: &str) -> u16 {
+    match error_type {
+        "unauthorized" => 401,
+        "insufficient-credits" => 402,
+        "forbidden" => 403,
+        "job-not-found" => 404,
+        "too-many-requests" | "rate-limit-exceeded" => 429,
+        "invalid-parameters" | "invalid-job-state" | "invalid-media" => 400,
+        _ => 500,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unauthorized() {
+        let data = br#"{"type": "https://www.rev.ai/api/v1/errors/unauthorized", "title": "Authorization has been denied for this request", "status": 401}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 401);
+        assert_eq!(
+            err.message,
+            "Authorization has been denied for this request"
+        );
+        assert_eq!(err.provider_code, Some("unauthorized".to_string()));
+        assert_eq!(err.to_ws_close_code(), 4401);
+    }
+
+    #[test]
+    fn test_invalid_parameters_prefers_detail() {
+        let data = br#"{"type": "https://www.rev.ai/api/v1/errors/invalid-parameters", "title": "Your request parameters didn't validate", "detail": "language is not supported", "status": 400}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 400);
+        assert_eq!(err.message, "language is not supported");
+        assert_eq!(err.to_ws_close_code(), 4400);
+    }
+
+    #[test]
+    fn test_status_from_type() {
+        let data =
+            br#"{"type": "https://www.rev.ai/api/v1/errors/insufficient-credits", "title": "Out of credits"}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 402);
+        assert_eq!(err.to_ws_close_code(), 4402);
+    }
+
+    #[test]
+    fn test_close_codes() {
+        let err = detect_close_error(4001, "Unauthorized").unwrap();
+        assert_eq!(err.http_code, 401);
+        assert_eq!(err.message, "Unauthorized");
+        assert_eq!(err.provider_code, Some("4001".to_string()));
+        assert_eq!(err.to_ws_close_code(), 4401);
+
+        assert_eq!(
+            detect_close_error(4003, "").unwrap().to_ws_close_code(),
+            4402
+        );
+        assert_eq!(
+            detect_close_error(4029, "").unwrap().to_ws_close_code(),
+            4429
+        );
+        assert!(detect_close_error(1000, "End of input").is_none());
+        assert!(detect_close_error(1011, "").is_none());
+    }
+
+    #[test]
+    fn test_transcript_is_not_error() {
+        let data = br#"{"type": "final", "ts": 0.0, "end_ts": 1.0, "elements": []}"#;
+        assert!(detect_error(data).is_none());
+        assert!(detect_error(br#"{"type": "connected", "id": "abc"}"#).is_none());
+    }
+
+    #[test]
+    fn test_other_provider_messages_are_not_errors() {
+        assert!(detect_error(br#"{"type": "Error", "error": "bad"}"#).is_none());
+        assert!(detect_error(br#"{"message": "Error", "type": "not_authorised"}"#).is_none());
+        assert!(detect_error(b"not json").is_none());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::adapter::{LanguageQuality, LanguageSupport};
+
+// https://docs.rev.ai/api/streaming/#supported-languages
+pub(super) const STREAMING_LANGUAGES: &[&str] =
+    &["en", "es", "fr", "de", "it", "ja", "ko", "pt", "zh"];
+
+// https://docs.rev.ai/api/asynchronous/reference/#operation/SubmitTranscriptionJob
+pub(super) const BATCH_LANGUAGES: &[&str] = &[
+    "ar", "bg", "ca", "zh", "cs", "da", "de", "el", "en", "es", "fi", "fr", "hi", "hr", "hu", "it",
+    "ja", "ko", "lt", "lv", "ms", "nl", "no", "pl", "pt", "ro", "ru", "sk", "sl", "sv", "tr",
+];
+
+pub(super) fn single_language_support_live(language: &hypr_language::Language) -> LanguageSupport {
+    support_in(STREAMING_LANGUAGES, language)
+}
+
+pub(super) fn single_language_support_batch(language: &hypr_language::Language) -> LanguageSupport {
+    support_in(BATCH_LANGUAGES, language)
+}
+
+fn support_in(codes: &[&str], language: &hypr_language::Language) -> LanguageSupport {
+    if codes.contains(&language.iso639().code()) {
+        LanguageSupport::Supported {
+            quality: LanguageQuality::NoData,
+        }
+    } else {
+        LanguageSupport::NotSupported
+    }
+}
+
+// Rev.ai uses "cmn" for Mandarin; every other language keeps its ISO 639-1 code.
+pub(super) fn language_code(language: &hypr_language::Language) -> &'static str {
+    match language.iso639().code() {
+        "zh" => "cmn",
+        code => code,
+    }
+}
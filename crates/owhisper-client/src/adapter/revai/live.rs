@@ -0,0 +1,390 @@
+use hypr_ws_client::client::Message;
+use owhisper_interface::ListenParams;
+use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse};
+use serde::Deserialize;
+
+use super::language::language_code;
+use super::{RevAiAdapter, RevAiElement, collect_words, elements_to_text};
+use crate::adapter::RealtimeSttAdapter;
+use crate::adapter::parsing::{WordBuilder, calculate_time_span};
+use crate::providers::Provider;
+
+// https://docs.rev.ai/api/streaming/requests/
+impl RealtimeSttAdapter for RevAiAdapter {
+    fn provider_name(&self) -> &'static str {
+        "revai"
+    }
+
+    fn is_supported_languages(
+        &self,
+        languages: &[hypr_language::Language],
+        _model: Option<&str>,
+    ) -> bool {
+        RevAiAdapter::is_supported_languages_live(languages)
+    }
+
+    fn supports_native_multichannel(&self) -> bool {
+        false
+    }
+
+    fn build_ws_url(&self, api_base: &str, params: &ListenParams, channels: u8) -> url::Url {
+        let (mut url, existing_params) = Self::build_ws_url_from_base(api_base);
+
+        {
+            let mut query_pairs = url.query_pairs_mut();
+
+            for (key, value) in &existing_params {
+                query_pairs.append_pair(key, value);
+            }
+
+            let content_type = format!(
+                "audio/x-raw;layout=interleaved;rate={};format=S16LE;channels={}",
+                params.sample_rate,
+                channels.max(1)
+            );
+            query_pairs.append_pair("content_type", &content_type);
+
+            let default = Provider::RevAi.default_live_model();
+            let transcriber = match params.model.as_deref() {
+                Some(m) if crate::providers::is_meta_model(m) => default,
+                Some(m) => m,
+                None => default,
+            };
+            query_pairs.append_pair("transcriber", transcriber);
+
+            query_pairs.append_pair("detailed_partials", "true");
+
+            if let Some(lang) = params.languages.first() {
+                query_pairs.append_pair("language", language_code(lang));
+            }
+        }
+
+        url
+    }
+
+    // Rev.ai only accepts the key as an `access_token` query parameter, so it
+    // has to go into the URL itself. The proxy adds it on its side.
+    fn build_ws_url_with_api_key(
+        &self,
+        api_base: &str,
+        params: &ListenParams,
+        channels: u8,
+        api_key: Option<&str>,
+    ) -> impl std::future::Future<Output = Option<url::Url>> + Send {
+        let mut url = self.build_ws_url(api_base, params, channels);
+
+        let is_proxy = crate::adapter::build_proxy_ws_url(api_base).is_some();
+        if let Some((name, value)) = api_key
+            .filter(|_| !is_proxy)
+            .and_then(|key| Provider::RevAi.build_auth_query_param(key))
+        {
+            url.query_pairs_mut().append_pair(name, &value);
+        }
+
+        async move { Some(url) }
+    }
+
+    fn build_auth_header(&self, _api_key: Option<&str>) -> Option<(&'static str, String)> {
+        None
+    }
+
+    fn keep_alive_message(&self) -> Option<Message> {
+        None
+    }
+
+    fn finalize_message(&self) -> Message {
+        Message::Text("EOS".into())
+    }
+
+    fn parse_response(&self, raw: &str) -> Vec<StreamResponse> {
+        let msg: RevAiMessage = match serde_json::from_str(raw) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!(error = ?e, raw = raw, "revai_json_parse_failed");
+                return vec![];
+            }
+        };
+
+        match msg {
+            RevAiMessage::Connected { id } => {
+                tracing::debug!(session_id = %id, "revai_session_started");
+                vec![]
+            }
+            RevAiMessage::Partial {
+                ts,
+                end_ts,
+                elements,
+            } => Self::build_response(ts, end_ts, &elements, false)
+                .into_iter()
+                .collect(),
+            RevAiMessage::Final {
+                ts,
+                end_ts,
+                elements,
+            } => Self::build_response(ts, end_ts, &elements, true)
+                .into_iter()
+                .collect(),
+            RevAiMessage::Unknown => {
+                tracing::debug!(raw = raw, "revai_unknown_message");
+                vec![]
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum RevAiMessage {
+    Connected {
+        #[serde(default)]
+        id: String,
+    },
+    Partial {
+        #[serde(default)]
+        ts: f64,
+        #[serde(default)]
+        end_ts: f64,
+        #[serde(default)]
+        elements: Vec<RevAiElement>,
+    },
+    Final {
+        #[serde(default)]
+        ts: f64,
+        #[serde(default)]
+        end_ts: f64,
+        #[serde(default)]
+        elements: Vec<RevAiElement>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl RevAiAdapter {
+    fn build_response(
+        ts: f64,
+        end_ts: f64,
+        elements: &[RevAiElement],
+        is_final: bool,
+    ) -> Option<StreamResponse> {
+        let transcript = elements_to_text(elements);
+        if transcript.is_empty() {
+            return None;
+        }
+
+        let words: Vec<_> = collect_words(elements)
+            .into_iter()
+            .map(|w| {
+                WordBuilder::new(w.word)
+                    .punctuated_word(w.punctuated_word)
+                    .start(w.start)
+                    .end(w.end)
+                    .confidence(w.confidence)
+                    .build()
+            })
+            .collect();
+
+        // Partials without timestamps fall back to the hypothesis span.
+        let (start, duration) = match calculate_time_span(&words) {
+            (_, duration) if duration <= 0.0 => (ts, end_ts - ts),
+            span => span,
+        };
+
+        let channel = Channel {
+            alternatives: vec![Alternatives {
+                transcript,
+                words,
+                confidence: 1.0,
+                languages: vec![],
+                translation: None,
+            }],
+        };
+
+        Some(StreamResponse::TranscriptResponse {
+            is_final,
+            speech_final: is_final,
+            from_finalize: false,
+            start,
+            duration,
+            channel,
+            metadata: Metadata::default(),
+            channel_index: vec![0, 1],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hypr_language::ISO639;
+    use owhisper_interface::stream::StreamResponse;
+
+    use super::RevAiAdapter;
+    use crate::ListenClient;
+    use crate::adapter::RealtimeSttAdapter;
+    use crate::test_utils::{UrlTestCase, run_dual_test, run_single_test, run_url_test_cases};
+
+    const API_BASE: &str = "https://api.rev.ai";
+
+    #[test]
+    fn test_default_params() {
+        run_url_test_cases(
+            &RevAiAdapter::default(),
+            API_BASE,
+            &[UrlTestCase {
+                name: "default_params",
+                model: None,
+                languages: &[ISO639::En],
+                contains: &[
+                    "wss://api.rev.ai/speechtotext/v1/stream",
+                    "content_type=audio%2Fx-raw%3Blayout%3Dinterleaved%3Brate%3D16000%3Bformat%3DS16LE%3Bchannels%3D1",
+                    "transcriber=machine",
+                    "detailed_partials=true",
+                    "language=en",
+                ],
+                not_contains: &["access_token"],
+            }],
+        );
+    }
+
+    #[test]
+    fn test_language_urls() {
+        run_url_test_cases(
+            &RevAiAdapter::default(),
+            API_BASE,
+            &[
+                UrlTestCase {
+                    name: "mandarin",
+                    model: None,
+                    languages: &[ISO639::Zh],
+                    contains: &["language=cmn"],
+                    not_contains: &["language=zh"],
+                },
+                UrlTestCase {
+                    name: "empty_languages",
+                    model: None,
+                    languages: &[],
+                    contains: &["transcriber=machine"],
+                    not_contains: &["language="],
+                },
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_api_key_goes_into_url() {
+        let adapter = RevAiAdapter::default();
+        let params = owhisper_interface::ListenParams::default();
+
+        let url = adapter
+            .build_ws_url_with_api_key(API_BASE, &params, 1, Some("secret"))
+            .await
+            .unwrap();
+        assert!(url.as_str().contains("access_token=secret"));
+
+        let url = adapter
+            .build_ws_url_with_api_key(
+                "https://api.hyprnote.com?provider=revai",
+                &params,
+                1,
+                Some("secret"),
+            )
+            .await
+            .unwrap();
+        assert!(!url.as_str().contains("access_token"));
+    }
+
+    #[test]
+    fn test_parse_responses() {
+        let adapter = RevAiAdapter::default();
+
+        assert!(
+            adapter
+                .parse_response(r#"{"type":"connected","id":"s1d24ax2fd21"}"#)
+                .is_empty()
+        );
+
+        let partial = adapter.parse_response(
+            r#"{"type":"partial","ts":0.5,"end_ts":1.2,"elements":[{"type":"text","value":"hello","ts":0.5,"end_ts":0.8,"confidence":0.6},{"type":"text","value":"world"}]}"#,
+        );
+        match &partial[..] {
+            [
+                StreamResponse::TranscriptResponse {
+                    is_final, channel, ..
+                },
+            ] => {
+                assert!(!is_final);
+                assert_eq!(channel.alternatives[0].transcript, "hello world");
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+
+        let final_ = adapter.parse_response(
+            r#"{"type":"final","ts":0.5,"end_ts":1.4,"elements":[{"type":"text","value":"Hello","ts":0.5,"end_ts":0.9,"confidence":0.98},{"type":"punct","value":" "},{"type":"text","value":"world","ts":1.0,"end_ts":1.4,"confidence":0.97},{"type":"punct","value":"."}]}"#,
+        );
+        match &final_[..] {
+            [
+                StreamResponse::TranscriptResponse {
+                    is_final,
+                    start,
+                    channel,
+                    ..
+                },
+            ] => {
+                assert!(is_final);
+                assert_eq!(*start, 0.5);
+                assert_eq!(channel.alternatives[0].transcript, "Hello world.");
+                let words = &channel.alternatives[0].words;
+                assert_eq!(words.len(), 2);
+                assert_eq!(words[1].punctuated_word.as_deref(), Some("world."));
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+
+        assert!(
+            adapter
+                .parse_response(r#"{"type":"final","ts":0.0,"end_ts":0.0,"elements":[]}"#)
+                .is_empty()
+        );
+    }
+
+    macro_rules! single_test {
+        ($name:ident, $params:expr) => {
+            #[tokio::test]
+            #[ignore]
+            async fn $name() {
+                let client = ListenClient::builder()
+                    .adapter::<RevAiAdapter>()
+                    .api_base(API_BASE)
+                    .api_key(std::env::var("REVAI_API_KEY").expect("REVAI_API_KEY not set"))
+                    .params($params)
+                    .build_single()
+                    .await;
+                run_single_test(client, "revai").await;
+            }
+        };
+    }
+
+    single_test!(
+        test_build_single,
+        owhisper_interface::ListenParams {
+            languages: vec![hypr_language::ISO639::En.into()],
+            ..Default::default()
+        }
+    );
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_build_dual() {
+        let client = ListenClient::builder()
+            .adapter::<RevAiAdapter>()
+            .api_base(API_BASE)
+            .api_key(std::env::var("REVAI_API_KEY").expect("REVAI_API_KEY not set"))
+            .params(owhisper_interface::ListenParams {
+                languages: vec![hypr_language::ISO639::En.into()],
+                ..Default::default()
+            })
+            .build_dual()
+            .await;
+
+        run_dual_test(client, "revai").await;
+    }
+}
@@ -0,0 +1,243 @@
+mod batch;
+pub mod error;
+mod language;
+mod live;
+
+use crate::providers::Provider;
+use serde::Deserialize;
+
+use super::LanguageSupport;
+
+#[derive(Clone, Default)]
+pub struct RevAiAdapter;
+
+impl RevAiAdapter {
+    pub fn language_support_live(languages: &[hypr_language::Language]) -> LanguageSupport {
+        LanguageSupport::min(languages.iter().map(language::single_language_support_live))
+    }
+
+    pub fn language_support_batch(languages: &[hypr_language::Language]) -> LanguageSupport {
+        LanguageSupport::min(
+            languages
+                .iter()
+                .map(language::single_language_support_batch),
+        )
+    }
+
+    pub fn is_supported_languages_live(languages: &[hypr_language::Language]) -> bool {
+        Self::language_support_live(languages).is_supported()
+    }
+
+    pub fn is_supported_languages_batch(languages: &[hypr_language::Language]) -> bool {
+        Self::language_support_batch(languages).is_supported()
+    }
+
+    pub(crate) fn build_ws_url_from_base(api_base: &str) -> (url::Url, Vec<(String, String)>) {
+        if api_base.is_empty() {
+            return (Self::default_ws_url(), Vec::new());
+        }
+
+        if let Some(proxy_result) = super::build_proxy_ws_url(api_base) {
+            return proxy_result;
+        }
+
+        let parsed: url::Url = api_base.parse().expect("invalid_api_base");
+        let existing_params = super::extract_query_params(&parsed);
+        let url = Self::build_url_with_scheme(&parsed, Provider::RevAi.ws_path(), true);
+        (url, existing_params)
+    }
+
+    fn build_url_with_scheme(parsed: &url::Url, path: &str, use_ws: bool) -> url::Url {
+        let host = parsed
+            .host_str()
+            .unwrap_or(Provider::RevAi.default_api_host());
+        let is_local = super::is_local_host(host);
+        let scheme = match (use_ws, is_local) {
+            (true, true) => "ws",
+            (true, false) => "wss",
+            (false, true) => "http",
+            (false, false) => "https",
+        };
+        let host_with_port = match parsed.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+        format!("{scheme}://{host_with_port}{path}")
+            .parse()
+            .expect("invalid_url")
+    }
+
+    fn default_ws_url() -> url::Url {
+        Provider::RevAi
+            .default_ws_url()
+            .parse()
+            .expect("invalid_default_ws_url")
+    }
+
+    pub(crate) fn batch_api_url(api_base: &str) -> String {
+        if api_base.is_empty() {
+            return Provider::RevAi.default_api_base().to_string();
+        }
+
+        let parsed: url::Url = api_base.parse().expect("invalid_api_base");
+        Self::build_url_with_scheme(&parsed, "/speechtotext/v1", false).to_string()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct RevAiElement {
+    #[serde(rename = "type")]
+    pub element_type: String,
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub ts: Option<f64>,
+    #[serde(default)]
+    pub end_ts: Option<f64>,
+    #[serde(default)]
+    pub confidence: Option<f64>,
+}
+
+pub(crate) struct RevAiWord {
+    pub word: String,
+    pub punctuated_word: String,
+    pub start: f64,
+    pub end: f64,
+    pub confidence: f64,
+}
+
+// Punctuation (including the spaces between words) arrives as separate
+// "punct" elements; visible marks are folded into the preceding word.
+pub(crate) fn collect_words(elements: &[RevAiElement]) -> Vec<RevAiWord> {
+    let mut words: Vec<RevAiWord> = Vec::new();
+
+    for element in elements {
+        match element.element_type.as_str() {
+            "text" => words.push(RevAiWord {
+                word: element.value.clone(),
+                punctuated_word: element.value.clone(),
+                start: element.ts.unwrap_or_default(),
+                end: element.end_ts.unwrap_or_default(),
+                confidence: element.confidence.unwrap_or(1.0),
+            }),
+            "punct" => {
+                let mark = element.value.trim();
+                if let Some(last) = words.last_mut().filter(|_| !mark.is_empty()) {
+                    last.punctuated_word.push_str(mark);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    words
+}
+
+// Final hypotheses carry their own spacing in punct elements; partials only
+// have text elements and are joined with spaces.
+pub(crate) fn elements_to_text(elements: &[RevAiElement]) -> String {
+    let has_punct = elements.iter().any(|e| e.element_type == "punct");
+    if has_punct {
+        elements
+            .iter()
+            .map(|e| e.value.as_str())
+            .collect::<String>()
+            .trim()
+            .to_string()
+    } else {
+        elements
+            .iter()
+            .filter(|e| e.element_type == "text")
+            .map(|e| e.value.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+pub(super) fn documented_language_codes_live() -> &'static [&'static str] {
+    language::STREAMING_LANGUAGES
+}
+
+pub(super) fn documented_language_codes_batch() -> &'static [&'static str] {
+    language::BATCH_LANGUAGES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_ws_url_from_base() {
+        let cases = [
+            ("", "wss://api.rev.ai/speechtotext/v1/stream", vec![]),
+            (
+                "https://api.rev.ai/speechtotext/v1",
+                "wss://api.rev.ai/speechtotext/v1/stream",
+                vec![],
+            ),
+            (
+                "https://ec1.api.rev.ai",
+                "wss://ec1.api.rev.ai/speechtotext/v1/stream",
+                vec![],
+            ),
+            (
+                "https://api.hyprnote.com?provider=revai",
+                "wss://api.hyprnote.com/listen",
+                vec![("provider", "revai")],
+            ),
+        ];
+
+        for (input, expected_url, expected_params) in cases {
+            let (url, params) = RevAiAdapter::build_ws_url_from_base(input);
+            assert_eq!(url.as_str(), expected_url, "input: {}", input);
+            assert_eq!(
+                params,
+                expected_params
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<Vec<_>>(),
+                "input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_api_url() {
+        assert_eq!(
+            RevAiAdapter::batch_api_url(""),
+            "https://api.rev.ai/speechtotext/v1"
+        );
+        assert_eq!(
+            RevAiAdapter::batch_api_url("https://ec1.api.rev.ai"),
+            "https://ec1.api.rev.ai/speechtotext/v1"
+        );
+    }
+
+    #[test]
+    fn test_collect_words_and_text() {
+        let elements: Vec<RevAiElement> = serde_json::from_str(
+            r#"[
+                {"type": "text", "value": "Hello", "ts": 0.5, "end_ts": 0.9, "confidence": 0.95},
+                {"type": "punct", "value": ","},
+                {"type": "punct", "value": " "},
+                {"type": "text", "value": "world", "ts": 1.0, "end_ts": 1.4, "confidence": 0.9},
+                {"type": "punct", "value": "."}
+            ]"#,
+        )
+        .unwrap();
+
+        let words = collect_words(&elements);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].punctuated_word, "Hello,");
+        assert_eq!(words[1].punctuated_word, "world.");
+        assert_eq!(words[1].start, 1.0);
+        assert_eq!(elements_to_text(&elements), "Hello, world.");
+
+        let partial: Vec<RevAiElement> = serde_json::from_str(
+            r#"[{"type": "text", "value": "hello"}, {"type": "text", "value": "world"}]"#,
+        )
+        .unwrap();
+        assert_eq!(elements_to_text(&partial), "hello world");
+    }
+}
@@ -8,10 +8,20 @@ use owhisper_interface::batch::{
 use serde::{Deserialize, Serialize};
 
 use super::SonioxAdapter;
-use crate::adapter::{BatchFuture, BatchSttAdapter, ClientWithMiddleware};
+use crate::adapter::{
+    AsyncBatchSttAdapter, BatchFuture, BatchSttAdapter, ClientWithMiddleware, JobFuture, JobStatus,
+    JobStatusFuture,
+};
 use crate::error::Error;
 use crate::polling::{PollingConfig, PollingResult, poll_until};
 
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AudioSource<'a> {
+    FileId(&'a str),
+    AudioUrl(&'a str),
+}
+
 impl SonioxAdapter {
     async fn upload_file(
         client: &ClientWithMiddleware,
@@ -91,7 +101,7 @@ impl SonioxAdapter {
         api_base: &str,
         api_key: &str,
         params: &ListenParams,
-        file_id: &str,
+        source: AudioSource<'_>,
     ) -> Result<String, Error> {
         #[derive(Serialize)]
         struct Context {
@@ -102,7 +112,8 @@ impl SonioxAdapter {
         #[derive(Serialize)]
         struct CreateTranscriptionRequest<'a> {
             model: &'a str,
-            file_id: &'a str,
+            #[serde(flatten)]
+            source: AudioSource<'a>,
             #[serde(skip_serializing_if = "Vec::is_empty")]
             language_hints: Vec<String>,
             enable_speaker_diarization: bool,
@@ -135,7 +146,7 @@ impl SonioxAdapter {
 
         let request = CreateTranscriptionRequest {
             model,
-            file_id,
+            source,
             language_hints,
             enable_speaker_diarization: true,
             enable_language_identification: true,
@@ -165,12 +176,12 @@ impl SonioxAdapter {
         Ok(transcription.id)
     }
 
-    async fn poll_transcription(
+    async fn transcription_status(
         client: &ClientWithMiddleware,
         api_base: &str,
         api_key: &str,
         transcription_id: &str,
-    ) -> Result<(), Error> {
+    ) -> Result<JobStatus, Error> {
         #[derive(Deserialize)]
         struct TranscriptionResponse {
             status: String,
@@ -184,46 +195,42 @@ impl SonioxAdapter {
             transcription_id
         );
 
-        let config =
-            PollingConfig::default().with_timeout_error("transcription timed out".to_string());
+        let response = client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", api_key))
+            .send()
+            .await?;
 
-        poll_until(
-            || async {
-                let response = client
-                    .get(&url)
-                    .header("Authorization", format!("Bearer {}", api_key))
-                    .send()
-                    .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::UnexpectedStatus { status, body });
+        }
 
-                let status = response.status();
-                if !status.is_success() {
-                    let body = response.text().await.unwrap_or_default();
-                    return Err(Error::UnexpectedStatus { status, body });
-                }
+        let transcription: TranscriptionResponse = response.json().await?;
 
-                let transcription: TranscriptionResponse = response.json().await?;
-
-                match transcription.status.as_str() {
-                    "completed" => Ok(PollingResult::Complete(())),
-                    "error" => {
-                        let error_msg = transcription
-                            .error_message
-                            .unwrap_or_else(|| "unknown error".to_string());
-                        Ok(PollingResult::Failed(format!(
-                            "transcription failed: {}",
-                            error_msg
-                        )))
-                    }
-                    "queued" | "processing" => Ok(PollingResult::Continue),
-                    unknown => Ok(PollingResult::Failed(format!(
-                        "unexpected transcription status: {}",
-                        unknown
-                    ))),
-                }
-            },
-            config,
-        )
-        .await
+        match transcription.status.as_str() {
+            "completed" => {
+                tracing::info!(transcription_id = %transcription_id, "transcription completed, fetching transcript");
+                let response =
+                    Self::get_transcript(client, api_base, api_key, transcription_id).await?;
+                Ok(JobStatus::Completed(response))
+            }
+            "error" => {
+                let error_msg = transcription
+                    .error_message
+                    .unwrap_or_else(|| "unknown error".to_string());
+                Ok(JobStatus::Failed(format!(
+                    "transcription failed: {}",
+                    error_msg
+                )))
+            }
+            "queued" | "processing" => Ok(JobStatus::Pending),
+            unknown => Ok(JobStatus::Failed(format!(
+                "unexpected transcription status: {}",
+                unknown
+            ))),
+        }
     }
 
     async fn get_transcript(
@@ -348,14 +355,28 @@ impl SonioxAdapter {
         params: &ListenParams,
         file_id: &str,
     ) -> Result<BatchResponse, Error> {
-        let transcription_id =
-            Self::create_transcription(client, api_base, api_key, params, file_id).await?;
+        let transcription_id = Self::create_transcription(
+            client,
+            api_base,
+            api_key,
+            params,
+            AudioSource::FileId(file_id),
+        )
+        .await?;
         tracing::info!(transcription_id = %transcription_id, "transcription created, polling for completion");
 
-        Self::poll_transcription(client, api_base, api_key, &transcription_id).await?;
-        tracing::info!(transcription_id = %transcription_id, "transcription completed, fetching transcript");
+        let config =
+            PollingConfig::default().with_timeout_error("transcription timed out".to_string());
 
-        let response = Self::get_transcript(client, api_base, api_key, &transcription_id).await?;
+        let response = poll_until(
+            || async {
+                Self::transcription_status(client, api_base, api_key, &transcription_id)
+                    .await
+                    .map(PollingResult::from)
+            },
+            config,
+        )
+        .await?;
         tracing::info!("transcript fetched successfully");
 
         Ok(response)
@@ -386,6 +407,37 @@ impl BatchSttAdapter for SonioxAdapter {
     }
 }
 
+impl AsyncBatchSttAdapter for SonioxAdapter {
+    fn submit_job<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        params: &'a ListenParams,
+        audio_url: &'a str,
+    ) -> JobFuture<'a> {
+        Box::pin(Self::create_transcription(
+            client,
+            api_base,
+            api_key,
+            params,
+            AudioSource::AudioUrl(audio_url),
+        ))
+    }
+
+    fn job_status<'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        job_id: &'a str,
+    ) -> JobStatusFuture<'a> {
+        Box::pin(Self::transcription_status(
+            client, api_base, api_key, job_id,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,6 @@
 use hypr_ws_client::client::Message;
 use owhisper_interface::ListenParams;
-use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse};
+use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse, Translation};
 use serde::{Deserialize, Serialize};
 
 use super::SonioxAdapter;
@@ -90,6 +90,12 @@ impl RealtimeSttAdapter for SonioxAdapter {
             enable_endpoint_detection: true,
             enable_speaker_diarization: true,
             context,
+            translation: params
+                .translation
+                .as_ref()
+                .map(|t| TranslationConfig::OneWay {
+                    target_language: t.target_language.iso639().code().to_string(),
+                }),
         };
 
         let json = serde_json::to_string(&cfg).unwrap();
@@ -201,6 +207,15 @@ struct SonioxConfig<'a> {
     enable_speaker_diarization: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     context: Option<Context>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translation: Option<TranslationConfig>,
+}
+
+// https://soniox.com/docs/stt/rt/real-time-translation
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TranslationConfig {
+    OneWay { target_language: String },
 }
 
 #[derive(Debug, Deserialize)]
@@ -216,6 +231,10 @@ struct Token {
     is_final: Option<bool>,
     #[serde(default)]
     speaker: Option<SpeakerId>,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    translation_status: Option<String>,
 }
 
 impl Token {
@@ -223,6 +242,12 @@ impl Token {
     fn is_fin_marker(&self) -> bool {
         self.text == "<fin>" && self.is_final == Some(true)
     }
+
+    // Translated tokens carry no timestamps and arrive interleaved with the
+    // spoken ones.
+    fn is_translation(&self) -> bool {
+        self.translation_status.as_deref() == Some("translation")
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -263,10 +288,13 @@ impl SonioxAdapter {
         speech_final: bool,
         from_finalize: bool,
     ) -> StreamResponse {
+        let (translated, tokens): (Vec<&Token>, Vec<&Token>) =
+            tokens.iter().copied().partition(|t| t.is_translation());
+
         let mut words = Vec::with_capacity(tokens.len());
         let mut transcript = String::new();
 
-        for t in tokens {
+        for t in &tokens {
             if t.text.trim().is_empty() {
                 transcript.push_str(&t.text);
                 continue;
@@ -302,6 +330,7 @@ impl SonioxAdapter {
                 words,
                 confidence: 1.0,
                 languages: vec![],
+                translation: Self::build_translation(&translated),
             }],
         };
 
@@ -316,6 +345,15 @@ impl SonioxAdapter {
             channel_index: vec![0, 1],
         }
     }
+
+    fn build_translation(tokens: &[&Token]) -> Option<Translation> {
+        let first = tokens.first()?;
+
+        Some(Translation {
+            language: first.language.clone().unwrap_or_default(),
+            text: tokens.iter().map(|t| t.text.as_str()).collect(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -438,6 +476,69 @@ mod tests {
         assert_eq!(json["language_hints_strict"].as_bool().unwrap(), true);
     }
 
+    #[test]
+    fn test_initial_message_translation() {
+        let adapter = SonioxAdapter::default();
+        let params = owhisper_interface::ListenParams {
+            languages: vec![hypr_language::ISO639::En.into()],
+            translation: Some(owhisper_interface::TranslationParams {
+                target_language: hypr_language::ISO639::Es.into(),
+            }),
+            ..Default::default()
+        };
+
+        let json = extract_initial_message_json(&adapter, &params);
+
+        assert_eq!(json["translation"]["type"], "one_way");
+        assert_eq!(json["translation"]["target_language"], "es");
+        assert!(
+            extract_initial_message_json(&adapter, &Default::default())
+                .get("translation")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_translation_tokens() {
+        let adapter = SonioxAdapter::default();
+        let raw = r#"{"tokens":[
+            {"text":"Hello","start_ms":0,"end_ms":400,"is_final":true,"language":"en","translation_status":"original"},
+            {"text":" world","start_ms":400,"end_ms":800,"is_final":true,"language":"en","translation_status":"original"},
+            {"text":"Hola","is_final":true,"language":"es","translation_status":"translation"},
+            {"text":" mundo","is_final":true,"language":"es","translation_status":"translation"}
+        ]}"#;
+
+        let responses = adapter.parse_response(raw);
+        assert_eq!(responses.len(), 1);
+
+        let response = &responses[0];
+        assert_eq!(response.text(), Some("Hello world"));
+        let translation = response.translation().unwrap();
+        assert_eq!(translation.language, "es");
+        assert_eq!(translation.text, "Hola mundo");
+
+        let owhisper_interface::stream::StreamResponse::TranscriptResponse {
+            channel,
+            duration,
+            ..
+        } = response
+        else {
+            panic!("expected transcript response");
+        };
+        assert_eq!(channel.alternatives[0].words.len(), 2);
+        assert!((duration - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_without_translation() {
+        let adapter = SonioxAdapter::default();
+        let raw = r#"{"tokens":[{"text":"Hello","start_ms":0,"end_ms":400,"is_final":true}]}"#;
+
+        let responses = adapter.parse_response(raw);
+        assert_eq!(responses.len(), 1);
+        assert!(responses[0].translation().is_none());
+    }
+
     macro_rules! single_test {
         ($name:ident, $params:expr) => {
             #[tokio::test]
@@ -0,0 +1,306 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use owhisper_interface::ListenParams;
+use owhisper_interface::batch::{
+    Alternatives as BatchAlternatives, Channel as BatchChannel, Response as BatchResponse,
+    Results as BatchResults, Word as BatchWord,
+};
+use serde::{Deserialize, Serialize};
+
+use super::language::language_code;
+use super::{SpeechmaticsAdapter, SpeechmaticsResult, TranscriptionConfig, collect_words};
+use crate::adapter::http::ensure_success;
+use crate::adapter::{BatchFuture, BatchSttAdapter, ClientWithMiddleware};
+use crate::error::Error;
+use crate::polling::{PollingConfig, PollingResult, poll_until};
+
+// https://docs.speechmatics.com/jobsapi
+impl BatchSttAdapter for SpeechmaticsAdapter {
+    fn is_supported_languages(
+        &self,
+        languages: &[hypr_language::Language],
+        _model: Option<&str>,
+    ) -> bool {
+        SpeechmaticsAdapter::is_supported_languages_batch(languages)
+    }
+
+    fn transcribe_file<'a, P: AsRef<Path> + Send + 'a>(
+        &'a self,
+        client: &'a ClientWithMiddleware,
+        api_base: &'a str,
+        api_key: &'a str,
+        params: &'a ListenParams,
+        file_path: P,
+    ) -> BatchFuture<'a> {
+        let path = file_path.as_ref().to_path_buf();
+        Box::pin(Self::do_transcribe_file(
+            client, api_base, api_key, params, path,
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JobConfig<'a> {
+    #[serde(rename = "type")]
+    job_type: &'a str,
+    transcription_config: TranscriptionConfig<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_identification_config: Option<LanguageIdentificationConfig>,
+}
+
+#[derive(Debug, Serialize)]
+struct LanguageIdentificationConfig {
+    expected_languages: Vec<&'static str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateJobResponse {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobStatusResponse {
+    job: JobDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobDetails {
+    status: String,
+    #[serde(default)]
+    errors: Vec<JobError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JobError {
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptResponse {
+    #[serde(default)]
+    job: Option<serde_json::Value>,
+    #[serde(default)]
+    results: Vec<SpeechmaticsResult>,
+}
+
+impl SpeechmaticsAdapter {
+    async fn do_transcribe_file(
+        client: &ClientWithMiddleware,
+        api_base: &str,
+        api_key: &str,
+        params: &ListenParams,
+        file_path: PathBuf,
+    ) -> Result<BatchResponse, Error> {
+        let base_url = Self::batch_api_url(api_base);
+        let auth = format!("Bearer {}", api_key);
+
+        let file_name = file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("audio.wav")
+            .to_string();
+
+        let file_bytes = tokio::fs::read(&file_path).await.map_err(|e| {
+            Error::AudioProcessing(format!(
+                "failed to read file {}: {}",
+                file_path.display(),
+                e
+            ))
+        })?;
+
+        // A single language is transcribed as-is; otherwise the job detects
+        // the language, restricted to the requested ones when there are several.
+        let (language, language_identification_config) = match params.languages.as_slice() {
+            [single] => (language_code(single), None),
+            [] => ("auto", None),
+            many => (
+                "auto",
+                Some(LanguageIdentificationConfig {
+                    expected_languages: many.iter().map(language_code).collect(),
+                }),
+            ),
+        };
+
+        let config = JobConfig {
+            job_type: "transcription",
+            transcription_config: TranscriptionConfig::new(params, language),
+            language_identification_config,
+        };
+        let config_json = serde_json::to_string(&config)
+            .map_err(|e| Error::AudioProcessing(format!("failed to encode config: {}", e)))?;
+
+        let form = reqwest::multipart::Form::new()
+            .part(
+                "data_file",
+                reqwest::multipart::Part::bytes(file_bytes).file_name(file_name),
+            )
+            .text("config", config_json);
+
+        let create_response = client
+            .post(format!("{}/jobs", base_url))
+            .header("Authorization", &auth)
+            .multipart(form)
+            .send()
+            .await?;
+
+        let create_response = ensure_success(create_response).await?;
+        let job_id = create_response.json::<CreateJobResponse>().await?.id;
+
+        let status_url = format!("{}/jobs/{}", base_url, job_id);
+
+        let config = PollingConfig::default()
+            .with_interval(Duration::from_secs(3))
+            .with_timeout_error("transcription timed out".to_string());
+
+        poll_until(
+            || async {
+                let poll_response = client
+                    .get(&status_url)
+                    .header("Authorization", &auth)
+                    .send()
+                    .await?;
+
+                let poll_response = ensure_success(poll_response).await?;
+                let result: JobStatusResponse = poll_response.json().await?;
+
+                match result.job.status.as_str() {
+                    "done" => Ok(PollingResult::Complete(())),
+                    "rejected" | "deleted" | "expired" => {
+                        let error_msg = result
+                            .job
+                            .errors
+                            .into_iter()
+                            .map(|e| e.message)
+                            .find(|m| !m.is_empty())
+                            .unwrap_or_else(|| result.job.status.clone());
+                        Ok(PollingResult::Failed(format!(
+                            "transcription failed: {}",
+                            error_msg
+                        )))
+                    }
+                    _ => Ok(PollingResult::Continue),
+                }
+            },
+            config,
+        )
+        .await?;
+
+        let transcript_response = client
+            .get(format!("{}/transcript?format=json-v2", status_url))
+            .header("Authorization", &auth)
+            .send()
+            .await?;
+
+        let transcript_response = ensure_success(transcript_response).await?;
+        let transcript: TranscriptResponse = transcript_response.json().await?;
+
+        Ok(Self::convert_to_batch_response(transcript))
+    }
+
+    fn convert_to_batch_response(response: TranscriptResponse) -> BatchResponse {
+        let words: Vec<BatchWord> = collect_words(&response.results)
+            .into_iter()
+            .map(|w| BatchWord {
+                word: w.word,
+                start: w.start,
+                end: w.end,
+                confidence: w.confidence,
+                speaker: w.speaker,
+                punctuated_word: Some(w.punctuated_word),
+            })
+            .collect();
+
+        let transcript = words
+            .iter()
+            .filter_map(|w| w.punctuated_word.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let confidence = if words.is_empty() {
+            1.0
+        } else {
+            words.iter().map(|w| w.confidence).sum::<f64>() / words.len() as f64
+        };
+
+        let channel = BatchChannel {
+            alternatives: vec![BatchAlternatives {
+                transcript,
+                confidence,
+                words,
+            }],
+        };
+
+        let audio_duration = response
+            .job
+            .as_ref()
+            .and_then(|job| job.get("duration"))
+            .cloned()
+            .unwrap_or(serde_json::Value::Null);
+
+        BatchResponse {
+            metadata: serde_json::json!({
+                "audio_duration": audio_duration,
+            }),
+            results: BatchResults {
+                channels: vec![channel],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_client::create_client;
+
+    #[test]
+    fn test_convert_to_batch_response() {
+        let response: TranscriptResponse = serde_json::from_str(
+            r#"{
+                "format": "2.9",
+                "job": {"id": "abc", "duration": 4},
+                "results": [
+                    {"type": "word", "start_time": 0.2, "end_time": 0.6, "alternatives": [{"content": "Hello", "confidence": 1.0, "speaker": "S1"}]},
+                    {"type": "word", "start_time": 0.7, "end_time": 1.1, "alternatives": [{"content": "there", "confidence": 0.8, "speaker": "S2"}]},
+                    {"type": "punctuation", "start_time": 1.1, "end_time": 1.1, "attaches_to": "previous", "alternatives": [{"content": "."}]}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let batch = SpeechmaticsAdapter::convert_to_batch_response(response);
+        let alt = &batch.results.channels[0].alternatives[0];
+        assert_eq!(alt.transcript, "Hello there.");
+        assert_eq!(alt.words.len(), 2);
+        assert_eq!(alt.words[1].speaker, Some(1));
+        assert!((alt.confidence - 0.9).abs() < 1e-9);
+        assert_eq!(batch.metadata["audio_duration"], 4);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_speechmatics_batch_transcription() {
+        let api_key = std::env::var("SPEECHMATICS_API_KEY").expect("SPEECHMATICS_API_KEY not set");
+        let client = create_client();
+        let adapter = SpeechmaticsAdapter::default();
+        let params = ListenParams::default();
+
+        let audio_path = std::path::PathBuf::from(hypr_data::english_1::AUDIO_PATH);
+
+        let result = adapter
+            .transcribe_file(&client, "", &api_key, &params, &audio_path)
+            .await
+            .expect("transcription failed");
+
+        assert!(!result.results.channels.is_empty());
+        assert!(!result.results.channels[0].alternatives.is_empty());
+        assert!(
+            !result.results.channels[0].alternatives[0]
+                .transcript
+                .is_empty()
+        );
+        assert!(!result.results.channels[0].alternatives[0].words.is_empty());
+    }
+}
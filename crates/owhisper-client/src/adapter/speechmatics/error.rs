@@ -0,0 +1,125 @@
+// https://docs.speechmatics.com/rt-api-ref#error-messages
+
+use serde::Deserialize;
+
+use crate::error_detection::ProviderError;
+
+#[derive(Deserialize)]
+struct SpeechmaticsError<'a> {
+    #[serde(borrow)]
+    message: Option<&'a str>,
+    #[serde(borrow, rename = "type")]
+    error_type: Option<&'a str>,
+    #[serde(borrow)]
+    reason: Option<&'a str>,
+}
+
+pub fn detect_error(data: &[u8]) -> Option<ProviderError> {
+    let text = std::str::from_utf8(data).ok()?;
+    let parsed: SpeechmaticsError = serde_json::from_str(text).ok()?;
+
+    if parsed.message != Some("Error") {
+        return None;
+    }
+
+    let error_type = parsed.error_type.unwrap_or("unknown_error");
+    let code = map_error_type(error_type);
+    let message = parsed.reason.unwrap_or("Unknown error").to_string();
+
+    Some(ProviderError::new(code, message).with_provider_code(error_type))
+}
+
+fn map_error_type(error_type: &str) -> u16 {
+    match error_type {
+        "not_authorised" => 401,
+        "insufficient_funds" => 402,
+        "not_allowed" => 403,
+        "invalid_message"
+        | "invalid_model"
+        | "invalid_config"
+        | "invalid_audio_type"
+        | "invalid_output_format"
+        | "protocol_error"
+        | "data_error" => 400,
+        "timelimit_exceeded" | "idle_timeout" | "session_timeout" => 408,
+        "quota_exceeded" | "buffer_error" => 429,
+        "job_error" | "internal_error" | "unknown_error" => 500,
+        _ => 500,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_authorised() {
+        let data =
+            br#"{"message": "Error", "type": "not_authorised", "reason": "Permission denied"}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 401);
+        assert_eq!(err.message, "Permission denied");
+        assert_eq!(err.provider_code, Some("not_authorised".to_string()));
+        assert_eq!(err.to_ws_close_code(), 4401);
+    }
+
+    #[test]
+    fn test_quota_exceeded() {
+        let data = br#"{"message": "Error", "type": "quota_exceeded", "reason": "Quota for concurrent Real Time sessions exceeded"}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 429);
+        assert_eq!(err.to_ws_close_code(), 4429);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let data = br#"{"message": "Error", "type": "invalid_config", "reason": "lang pack not supported"}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 400);
+        assert_eq!(err.to_ws_close_code(), 4400);
+    }
+
+    #[test]
+    fn test_insufficient_funds() {
+        let data = br#"{"message": "Error", "type": "insufficient_funds", "reason": "Not enough credits"}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 402);
+        assert_eq!(err.to_ws_close_code(), 4402);
+    }
+
+    #[test]
+    fn test_timelimit_exceeded() {
+        let data = br#"{"message": "Error", "type": "timelimit_exceeded", "reason": "Session exceeded the maximum duration"}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 408);
+        assert_eq!(err.to_ws_close_code(), 4000);
+    }
+
+    #[test]
+    fn test_error_without_reason() {
+        let data = br#"{"message": "Error", "type": "job_error"}"#;
+        let err = detect_error(data).unwrap();
+        assert_eq!(err.http_code, 500);
+        assert_eq!(err.message, "Unknown error");
+    }
+
+    #[test]
+    fn test_warning_is_not_error() {
+        let data = br#"{"message": "Warning", "type": "duration_limit_exceeded", "reason": "Close to limit"}"#;
+        assert!(detect_error(data).is_none());
+    }
+
+    #[test]
+    fn test_transcript_is_not_error() {
+        let data =
+            br#"{"message": "AddTranscript", "metadata": {"transcript": "hello"}, "results": []}"#;
+        assert!(detect_error(data).is_none());
+    }
+
+    #[test]
+    fn test_other_provider_messages_are_not_errors() {
+        assert!(detect_error(br#"{"message_type": "auth_error", "error": "Invalid"}"#).is_none());
+        assert!(detect_error(br#"{"error": "Unauthorized"}"#).is_none());
+        assert!(detect_error(b"not json").is_none());
+    }
+}
@@ -0,0 +1,28 @@
+use crate::adapter::{LanguageQuality, LanguageSupport};
+
+// https://docs.speechmatics.com/introduction/supported-languages
+pub(super) const SUPPORTED_LANGUAGES: &[&str] = &[
+    "ar", "ba", "eu", "be", "bn", "bg", "ca", "hr", "cs", "da", "nl", "en", "eo", "et", "fi", "fr",
+    "gl", "de", "el", "he", "hi", "hu", "id", "ia", "ga", "it", "ja", "ko", "lv", "lt", "ms", "mt",
+    "zh", "mr", "mn", "no", "fa", "pl", "pt", "ro", "ru", "sk", "sl", "es", "sw", "sv", "ta", "th",
+    "tr", "uk", "ur", "ug", "vi", "cy",
+];
+
+pub(super) fn single_language_support(language: &hypr_language::Language) -> LanguageSupport {
+    let code = language.iso639().code();
+    if SUPPORTED_LANGUAGES.contains(&code) {
+        LanguageSupport::Supported {
+            quality: LanguageQuality::NoData,
+        }
+    } else {
+        LanguageSupport::NotSupported
+    }
+}
+
+// Speechmatics uses "cmn" for Mandarin; every other language keeps its ISO 639-1 code.
+pub(super) fn language_code(language: &hypr_language::Language) -> &'static str {
+    match language.iso639().code() {
+        "zh" => "cmn",
+        code => code,
+    }
+}
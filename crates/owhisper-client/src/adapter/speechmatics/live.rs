@@ -0,0 +1,601 @@
+use hypr_ws_client::client::Message;
+use owhisper_interface::ListenParams;
+use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse, Translation};
+use serde::{Deserialize, Serialize};
+
+use super::language::language_code;
+use super::{SpeechmaticsAdapter, SpeechmaticsResult, TranscriptionConfig, collect_words};
+use crate::adapter::RealtimeSttAdapter;
+use crate::adapter::parsing::WordBuilder;
+
+// https://docs.speechmatics.com/rt-api-ref
+impl RealtimeSttAdapter for SpeechmaticsAdapter {
+    fn provider_name(&self) -> &'static str {
+        "speechmatics"
+    }
+
+    fn is_supported_languages(
+        &self,
+        languages: &[hypr_language::Language],
+        _model: Option<&str>,
+    ) -> bool {
+        SpeechmaticsAdapter::is_supported_languages_live(languages)
+    }
+
+    fn supports_native_multichannel(&self) -> bool {
+        false
+    }
+
+    fn build_ws_url(&self, api_base: &str, _params: &ListenParams, _channels: u8) -> url::Url {
+        let (mut url, existing_params) = Self::build_ws_url_from_base(api_base);
+
+        if !existing_params.is_empty() {
+            let mut query_pairs = url.query_pairs_mut();
+            for (key, value) in &existing_params {
+                query_pairs.append_pair(key, value);
+            }
+        }
+
+        url
+    }
+
+    fn build_auth_header(&self, api_key: Option<&str>) -> Option<(&'static str, String)> {
+        api_key.and_then(|k| crate::providers::Provider::Speechmatics.build_auth_header(k))
+    }
+
+    fn keep_alive_message(&self) -> Option<Message> {
+        None
+    }
+
+    fn initial_message(
+        &self,
+        _api_key: Option<&str>,
+        params: &ListenParams,
+        _channels: u8,
+    ) -> Option<Message> {
+        // Realtime sessions need a single language; there is no auto-detection.
+        let language = params.languages.first().map(language_code).unwrap_or("en");
+
+        let msg = StartRecognition {
+            message: "StartRecognition",
+            audio_format: AudioFormat {
+                format_type: "raw",
+                encoding: "pcm_s16le",
+                sample_rate: params.sample_rate,
+            },
+            transcription_config: TranscriptionConfig {
+                enable_partials: Some(true),
+                max_delay: Some(2.0),
+                ..TranscriptionConfig::new(params, language)
+            },
+            translation_config: params.translation.as_ref().map(|t| TranslationConfig {
+                target_languages: vec![language_code(&t.target_language)],
+                enable_partials: true,
+            }),
+        };
+
+        let json = serde_json::to_string(&msg).unwrap();
+        Some(Message::Text(json.into()))
+    }
+
+    fn finalize_message(&self) -> Message {
+        Message::Text(r#"{"message":"ForceEndOfUtterance"}"#.into())
+    }
+
+    fn parse_response(&self, raw: &str) -> Vec<StreamResponse> {
+        let msg: SpeechmaticsMessage = match serde_json::from_str(raw) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!(error = ?e, raw = raw, "speechmatics_json_parse_failed");
+                return vec![];
+            }
+        };
+
+        match msg {
+            SpeechmaticsMessage::RecognitionStarted { id } => {
+                tracing::debug!(session_id = ?id, "speechmatics_session_started");
+                vec![]
+            }
+            SpeechmaticsMessage::AddPartialTranscript { metadata, results } => {
+                Self::build_response(&metadata, &results, false)
+                    .into_iter()
+                    .collect()
+            }
+            SpeechmaticsMessage::AddTranscript { metadata, results } => {
+                Self::build_response(&metadata, &results, true)
+                    .into_iter()
+                    .collect()
+            }
+            SpeechmaticsMessage::AddPartialTranslation { language, results } => {
+                Self::build_translation_response(language, &results, false)
+                    .into_iter()
+                    .collect()
+            }
+            SpeechmaticsMessage::AddTranslation { language, results } => {
+                Self::build_translation_response(language, &results, true)
+                    .into_iter()
+                    .collect()
+            }
+            SpeechmaticsMessage::EndOfUtterance { metadata } => {
+                vec![StreamResponse::UtteranceEndResponse {
+                    channel: vec![0, 1],
+                    last_word_end: metadata.end_time,
+                }]
+            }
+            SpeechmaticsMessage::Error { error_type, reason } => {
+                tracing::error!(error_type = %error_type, reason = %reason, "speechmatics_error");
+                vec![StreamResponse::ErrorResponse {
+                    error_code: None,
+                    error_message: format!("{}: {}", error_type, reason),
+                    provider: "speechmatics".to_string(),
+                }]
+            }
+            SpeechmaticsMessage::Warning {
+                warning_type,
+                reason,
+            } => {
+                tracing::warn!(warning_type = %warning_type, reason = %reason, "speechmatics_warning");
+                vec![]
+            }
+            SpeechmaticsMessage::Unknown => {
+                tracing::debug!(raw = raw, "speechmatics_unknown_message");
+                vec![]
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct StartRecognition<'a> {
+    message: &'a str,
+    audio_format: AudioFormat<'a>,
+    transcription_config: TranscriptionConfig<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translation_config: Option<TranslationConfig<'a>>,
+}
+
+// https://docs.speechmatics.com/speech-to-text/features/translation
+#[derive(Serialize)]
+struct TranslationConfig<'a> {
+    target_languages: Vec<&'a str>,
+    enable_partials: bool,
+}
+
+#[derive(Serialize)]
+struct AudioFormat<'a> {
+    #[serde(rename = "type")]
+    format_type: &'a str,
+    encoding: &'a str,
+    sample_rate: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "message")]
+enum SpeechmaticsMessage {
+    RecognitionStarted {
+        #[serde(default)]
+        id: Option<String>,
+    },
+    AddPartialTranscript {
+        metadata: TranscriptMetadata,
+        #[serde(default)]
+        results: Vec<SpeechmaticsResult>,
+    },
+    AddTranscript {
+        metadata: TranscriptMetadata,
+        #[serde(default)]
+        results: Vec<SpeechmaticsResult>,
+    },
+    AddPartialTranslation {
+        language: String,
+        #[serde(default)]
+        results: Vec<TranslationResult>,
+    },
+    AddTranslation {
+        language: String,
+        #[serde(default)]
+        results: Vec<TranslationResult>,
+    },
+    EndOfUtterance {
+        metadata: TranscriptMetadata,
+    },
+    Error {
+        #[serde(default, rename = "type")]
+        error_type: String,
+        #[serde(default)]
+        reason: String,
+    },
+    Warning {
+        #[serde(default, rename = "type")]
+        warning_type: String,
+        #[serde(default)]
+        reason: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptMetadata {
+    #[serde(default)]
+    start_time: f64,
+    #[serde(default)]
+    end_time: f64,
+    #[serde(default)]
+    transcript: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslationResult {
+    #[serde(default)]
+    start_time: f64,
+    #[serde(default)]
+    end_time: f64,
+    #[serde(default)]
+    content: String,
+}
+
+impl SpeechmaticsAdapter {
+    fn build_response(
+        metadata: &TranscriptMetadata,
+        results: &[SpeechmaticsResult],
+        is_final: bool,
+    ) -> Option<StreamResponse> {
+        let transcript = metadata.transcript.trim();
+        if transcript.is_empty() && results.is_empty() {
+            return None;
+        }
+
+        let words: Vec<_> = collect_words(results)
+            .into_iter()
+            .map(|w| {
+                WordBuilder::new(w.word)
+                    .punctuated_word(w.punctuated_word)
+                    .start(w.start)
+                    .end(w.end)
+                    .confidence(w.confidence)
+                    .speaker(w.speaker.map(|s| s as i32))
+                    .language(w.language)
+                    .build()
+            })
+            .collect();
+
+        let languages = words
+            .iter()
+            .find_map(|w| w.language.clone())
+            .into_iter()
+            .collect();
+
+        let channel = Channel {
+            alternatives: vec![Alternatives {
+                transcript: transcript.to_string(),
+                words,
+                confidence: 1.0,
+                languages,
+                translation: None,
+            }],
+        };
+
+        Some(StreamResponse::TranscriptResponse {
+            is_final,
+            speech_final: is_final,
+            from_finalize: false,
+            start: metadata.start_time,
+            duration: metadata.end_time - metadata.start_time,
+            channel,
+            metadata: Metadata::default(),
+            channel_index: vec![0, 1],
+        })
+    }
+
+    // Translations are sentence-level and trail the transcript, so they are
+    // emitted on their own without words.
+    fn build_translation_response(
+        language: String,
+        results: &[TranslationResult],
+        is_final: bool,
+    ) -> Option<StreamResponse> {
+        let (first, last) = (results.first()?, results.last()?);
+
+        let text = results
+            .iter()
+            .map(|r| r.content.trim())
+            .filter(|c| !c.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.is_empty() {
+            return None;
+        }
+
+        let channel = Channel {
+            alternatives: vec![Alternatives {
+                transcript: String::new(),
+                words: vec![],
+                confidence: 1.0,
+                languages: vec![],
+                translation: Some(Translation { language, text }),
+            }],
+        };
+
+        Some(StreamResponse::TranscriptResponse {
+            is_final,
+            speech_final: false,
+            from_finalize: false,
+            start: first.start_time,
+            duration: last.end_time - first.start_time,
+            channel,
+            metadata: Metadata::default(),
+            channel_index: vec![0, 1],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hypr_language::ISO639;
+    use hypr_ws_client::client::Message;
+    use owhisper_interface::stream::StreamResponse;
+
+    use super::SpeechmaticsAdapter;
+    use crate::ListenClient;
+    use crate::adapter::RealtimeSttAdapter;
+    use crate::test_utils::{UrlTestCase, run_dual_test, run_single_test, run_url_test_cases};
+
+    const API_BASE: &str = "https://asr.api.speechmatics.com/v2";
+
+    #[test]
+    fn test_base_url() {
+        run_url_test_cases(
+            &SpeechmaticsAdapter::default(),
+            API_BASE,
+            &[UrlTestCase {
+                name: "base_url_structure",
+                model: None,
+                languages: &[ISO639::En],
+                contains: &["wss://eu2.rt.speechmatics.com/v2"],
+                not_contains: &["language"],
+            }],
+        );
+    }
+
+    fn extract_initial_message_json(
+        params: &owhisper_interface::ListenParams,
+    ) -> serde_json::Value {
+        let msg = SpeechmaticsAdapter::default()
+            .initial_message(Some("test_key"), params, 1)
+            .unwrap();
+        match msg {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            _ => panic!("Expected text message"),
+        }
+    }
+
+    #[test]
+    fn test_initial_message() {
+        let params = owhisper_interface::ListenParams {
+            languages: vec![ISO639::De.into()],
+            keywords: vec!["Hyprnote".to_string()],
+            ..Default::default()
+        };
+
+        let json = extract_initial_message_json(&params);
+        assert_eq!(json["message"], "StartRecognition");
+        assert_eq!(json["audio_format"]["encoding"], "pcm_s16le");
+        assert_eq!(json["audio_format"]["sample_rate"], 16000);
+        assert_eq!(json["transcription_config"]["language"], "de");
+        assert_eq!(json["transcription_config"]["operating_point"], "enhanced");
+        assert_eq!(json["transcription_config"]["enable_partials"], true);
+        assert_eq!(
+            json["transcription_config"]["additional_vocab"][0]["content"],
+            "Hyprnote"
+        );
+    }
+
+    #[test]
+    fn test_initial_message_language_codes() {
+        let params = owhisper_interface::ListenParams {
+            languages: vec![ISO639::Zh.into(), ISO639::En.into()],
+            ..Default::default()
+        };
+        let json = extract_initial_message_json(&params);
+        assert_eq!(json["transcription_config"]["language"], "cmn");
+
+        let json = extract_initial_message_json(&Default::default());
+        assert_eq!(json["transcription_config"]["language"], "en");
+        assert!(
+            json["transcription_config"]
+                .get("additional_vocab")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_transcripts() {
+        let adapter = SpeechmaticsAdapter::default();
+
+        let partial = adapter.parse_response(
+            r#"{"message":"AddPartialTranscript","metadata":{"start_time":0.5,"end_time":0.9,"transcript":"Hello"},"results":[{"type":"word","start_time":0.5,"end_time":0.9,"alternatives":[{"content":"Hello","confidence":0.8,"language":"en","speaker":"S1"}]}]}"#,
+        );
+        match &partial[..] {
+            [
+                StreamResponse::TranscriptResponse {
+                    is_final, channel, ..
+                },
+            ] => {
+                assert!(!is_final);
+                assert_eq!(channel.alternatives[0].transcript, "Hello");
+                assert_eq!(channel.alternatives[0].languages, vec!["en".to_string()]);
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+
+        let final_ = adapter.parse_response(
+            r#"{"message":"AddTranscript","metadata":{"start_time":0.5,"end_time":1.4,"transcript":"Hello world."},"results":[{"type":"word","start_time":0.5,"end_time":0.9,"alternatives":[{"content":"Hello","confidence":0.99,"speaker":"S1"}]},{"type":"word","start_time":1.0,"end_time":1.4,"alternatives":[{"content":"world","confidence":0.97,"speaker":"S1"}]},{"type":"punctuation","start_time":1.4,"end_time":1.4,"attaches_to":"previous","alternatives":[{"content":".","confidence":1.0,"speaker":"S1"}]}]}"#,
+        );
+        match &final_[..] {
+            [
+                StreamResponse::TranscriptResponse {
+                    is_final,
+                    start,
+                    duration,
+                    channel,
+                    ..
+                },
+            ] => {
+                assert!(is_final);
+                assert_eq!(*start, 0.5);
+                assert!((duration - 0.9).abs() < 1e-9);
+                let words = &channel.alternatives[0].words;
+                assert_eq!(words.len(), 2);
+                assert_eq!(words[1].punctuated_word.as_deref(), Some("world."));
+                assert_eq!(words[1].speaker, Some(0));
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_initial_message_translation() {
+        let params = owhisper_interface::ListenParams {
+            languages: vec![ISO639::En.into()],
+            translation: Some(owhisper_interface::TranslationParams {
+                target_language: ISO639::De.into(),
+            }),
+            ..Default::default()
+        };
+
+        let json = extract_initial_message_json(&params);
+        assert_eq!(json["translation_config"]["target_languages"][0], "de");
+        assert_eq!(json["translation_config"]["enable_partials"], true);
+        assert!(
+            extract_initial_message_json(&Default::default())
+                .get("translation_config")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_translations() {
+        let adapter = SpeechmaticsAdapter::default();
+
+        let responses = adapter.parse_response(
+            r#"{"message":"AddTranslation","format":"2.9","language":"de","results":[{"start_time":0.5,"end_time":1.4,"content":"Hallo Welt.","speaker":"S1"}]}"#,
+        );
+        assert_eq!(responses.len(), 1);
+        let translation = responses[0].translation().unwrap();
+        assert_eq!(translation.language, "de");
+        assert_eq!(translation.text, "Hallo Welt.");
+        assert!(matches!(
+            responses[0],
+            StreamResponse::TranscriptResponse { is_final: true, .. }
+        ));
+
+        let partial = adapter.parse_response(
+            r#"{"message":"AddPartialTranslation","language":"de","results":[{"start_time":0.5,"end_time":0.9,"content":"Hallo"}]}"#,
+        );
+        assert!(matches!(
+            partial[..],
+            [StreamResponse::TranscriptResponse {
+                is_final: false,
+                ..
+            }]
+        ));
+
+        assert!(
+            adapter
+                .parse_response(r#"{"message":"AddTranslation","language":"de","results":[]}"#)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parse_control_messages() {
+        let adapter = SpeechmaticsAdapter::default();
+
+        assert!(
+            adapter
+                .parse_response(r#"{"message":"RecognitionStarted","id":"abc"}"#)
+                .is_empty()
+        );
+        assert!(
+            adapter
+                .parse_response(r#"{"message":"AudioAdded","seq_no":3}"#)
+                .is_empty()
+        );
+        assert!(
+            adapter
+                .parse_response(r#"{"message":"AddTranscript","metadata":{"start_time":0.0,"end_time":1.0,"transcript":""},"results":[]}"#)
+                .is_empty()
+        );
+
+        let end = adapter.parse_response(
+            r#"{"message":"EndOfUtterance","metadata":{"start_time":1.4,"end_time":1.4}}"#,
+        );
+        assert!(matches!(
+            end[..],
+            [StreamResponse::UtteranceEndResponse { last_word_end, .. }] if last_word_end == 1.4
+        ));
+
+        let error = adapter.parse_response(
+            r#"{"message":"Error","type":"not_authorised","reason":"Permission denied"}"#,
+        );
+        assert!(matches!(
+            &error[..],
+            [StreamResponse::ErrorResponse { error_message, .. }] if error_message == "not_authorised: Permission denied"
+        ));
+    }
+
+    macro_rules! single_test {
+        ($name:ident, $params:expr) => {
+            #[tokio::test]
+            #[ignore]
+            async fn $name() {
+                let client = ListenClient::builder()
+                    .adapter::<SpeechmaticsAdapter>()
+                    .api_base(API_BASE)
+                    .api_key(
+                        std::env::var("SPEECHMATICS_API_KEY")
+                            .expect("SPEECHMATICS_API_KEY not set"),
+                    )
+                    .params($params)
+                    .build_single()
+                    .await;
+                run_single_test(client, "speechmatics").await;
+            }
+        };
+    }
+
+    single_test!(
+        test_build_single,
+        owhisper_interface::ListenParams {
+            languages: vec![hypr_language::ISO639::En.into()],
+            ..Default::default()
+        }
+    );
+
+    single_test!(
+        test_single_with_keywords,
+        owhisper_interface::ListenParams {
+            languages: vec![hypr_language::ISO639::En.into()],
+            keywords: vec!["Hyprnote".to_string(), "transcription".to_string()],
+            ..Default::default()
+        }
+    );
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_build_dual() {
+        let client = ListenClient::builder()
+            .adapter::<SpeechmaticsAdapter>()
+            .api_base(API_BASE)
+            .api_key(std::env::var("SPEECHMATICS_API_KEY").expect("SPEECHMATICS_API_KEY not set"))
+            .params(owhisper_interface::ListenParams {
+                languages: vec![hypr_language::ISO639::En.into()],
+                ..Default::default()
+            })
+            .build_dual()
+            .await;
+
+        run_dual_test(client, "speechmatics").await;
+    }
+}
@@ -0,0 +1,332 @@
+mod batch;
+pub mod error;
+mod language;
+mod live;
+
+use crate::providers::Provider;
+use owhisper_interface::ListenParams;
+use serde::{Deserialize, Serialize};
+
+use super::LanguageSupport;
+
+#[derive(Clone, Default)]
+pub struct SpeechmaticsAdapter;
+
+impl SpeechmaticsAdapter {
+    pub fn language_support_live(languages: &[hypr_language::Language]) -> LanguageSupport {
+        LanguageSupport::min(languages.iter().map(language::single_language_support))
+    }
+
+    pub fn language_support_batch(languages: &[hypr_language::Language]) -> LanguageSupport {
+        Self::language_support_live(languages)
+    }
+
+    pub fn is_supported_languages_live(languages: &[hypr_language::Language]) -> bool {
+        Self::language_support_live(languages).is_supported()
+    }
+
+    pub fn is_supported_languages_batch(languages: &[hypr_language::Language]) -> bool {
+        Self::language_support_batch(languages).is_supported()
+    }
+
+    pub(crate) fn build_ws_url_from_base(api_base: &str) -> (url::Url, Vec<(String, String)>) {
+        if api_base.is_empty() {
+            return (Self::default_ws_url(), Vec::new());
+        }
+
+        if let Some(proxy_result) = super::build_proxy_ws_url(api_base) {
+            return proxy_result;
+        }
+
+        let parsed: url::Url = api_base.parse().expect("invalid_api_base");
+        let existing_params = super::extract_query_params(&parsed);
+        let host = parsed
+            .host_str()
+            .unwrap_or(Provider::Speechmatics.default_ws_host());
+
+        // The batch API and the realtime API live on different hosts, so a
+        // batch base like `https://us1.asr.api.speechmatics.com/v2` is mapped
+        // to the realtime host of the same region.
+        let url = if Provider::Speechmatics.is_host(host) && !host.contains(".rt.") {
+            let rt_host = if host.starts_with("us") {
+                "us2.rt.speechmatics.com"
+            } else {
+                Provider::Speechmatics.default_ws_host()
+            };
+            format!("wss://{}{}", rt_host, Provider::Speechmatics.ws_path())
+                .parse()
+                .expect("invalid_url")
+        } else {
+            Self::build_url_with_scheme(&parsed, Provider::Speechmatics.ws_path(), true)
+        };
+        (url, existing_params)
+    }
+
+    fn build_url_with_scheme(parsed: &url::Url, path: &str, use_ws: bool) -> url::Url {
+        let host = parsed
+            .host_str()
+            .unwrap_or(Provider::Speechmatics.default_api_host());
+        let is_local = super::is_local_host(host);
+        let scheme = match (use_ws, is_local) {
+            (true, true) => "ws",
+            (true, false) => "wss",
+            (false, true) => "http",
+            (false, false) => "https",
+        };
+        let host_with_port = match parsed.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+        format!("{scheme}://{host_with_port}{path}")
+            .parse()
+            .expect("invalid_url")
+    }
+
+    fn default_ws_url() -> url::Url {
+        Provider::Speechmatics
+            .default_ws_url()
+            .parse()
+            .expect("invalid_default_ws_url")
+    }
+
+    pub(crate) fn batch_api_url(api_base: &str) -> String {
+        if api_base.is_empty() {
+            return Provider::Speechmatics.default_api_base().to_string();
+        }
+
+        let parsed: url::Url = api_base.parse().expect("invalid_api_base");
+        let host = parsed.host_str().unwrap_or_default();
+        if host.contains(".rt.") && Provider::Speechmatics.is_host(host) {
+            return if host.starts_with("us") {
+                "https://us1.asr.api.speechmatics.com/v2".to_string()
+            } else {
+                Provider::Speechmatics.default_api_base().to_string()
+            };
+        }
+
+        Self::build_url_with_scheme(&parsed, "/v2", false).to_string()
+    }
+
+    pub(crate) fn operating_point(model: Option<&str>) -> &'static str {
+        match model {
+            Some("standard") => "standard",
+            _ => Provider::Speechmatics.default_live_model(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct TranscriptionConfig<'a> {
+    pub language: &'a str,
+    pub operating_point: &'a str,
+    pub diarization: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub additional_vocab: Vec<AdditionalVocab<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_partials: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delay: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct AdditionalVocab<'a> {
+    pub content: &'a str,
+}
+
+impl<'a> TranscriptionConfig<'a> {
+    pub fn new(params: &'a ListenParams, language: &'a str) -> Self {
+        Self {
+            language,
+            operating_point: SpeechmaticsAdapter::operating_point(params.model.as_deref()),
+            diarization: "speaker",
+            additional_vocab: params
+                .keywords
+                .iter()
+                .map(|k| AdditionalVocab { content: k })
+                .collect(),
+            enable_partials: None,
+            max_delay: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SpeechmaticsResult {
+    #[serde(rename = "type")]
+    pub result_type: String,
+    #[serde(default)]
+    pub start_time: f64,
+    #[serde(default)]
+    pub end_time: f64,
+    #[serde(default)]
+    pub attaches_to: Option<String>,
+    #[serde(default)]
+    pub alternatives: Vec<SpeechmaticsAlternative>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SpeechmaticsAlternative {
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub confidence: f64,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub speaker: Option<String>,
+}
+
+pub(crate) struct SpeechmaticsWord {
+    pub word: String,
+    pub punctuated_word: String,
+    pub start: f64,
+    pub end: f64,
+    pub confidence: f64,
+    pub speaker: Option<usize>,
+    pub language: Option<String>,
+}
+
+// Punctuation arrives as its own result; it is folded into the word it
+// attaches to so that words carry their punctuated form.
+pub(crate) fn collect_words(results: &[SpeechmaticsResult]) -> Vec<SpeechmaticsWord> {
+    let mut words: Vec<SpeechmaticsWord> = Vec::new();
+    let mut pending_prefix = String::new();
+
+    for result in results {
+        let Some(alt) = result.alternatives.first() else {
+            continue;
+        };
+
+        if result.result_type == "punctuation" {
+            match (result.attaches_to.as_deref(), words.last_mut()) {
+                (Some("next"), _) => pending_prefix.push_str(&alt.content),
+                (_, Some(last)) => last.punctuated_word.push_str(&alt.content),
+                (_, None) => pending_prefix.push_str(&alt.content),
+            }
+            continue;
+        }
+
+        if result.result_type != "word" {
+            continue;
+        }
+
+        words.push(SpeechmaticsWord {
+            word: alt.content.clone(),
+            punctuated_word: format!("{}{}", std::mem::take(&mut pending_prefix), alt.content),
+            start: result.start_time,
+            end: result.end_time,
+            confidence: alt.confidence,
+            speaker: alt.speaker.as_deref().and_then(speaker_index),
+            language: alt.language.clone(),
+        });
+    }
+
+    words
+}
+
+// Speakers are labelled "S1", "S2", ...; "UU" marks an unknown speaker.
+fn speaker_index(label: &str) -> Option<usize> {
+    label
+        .strip_prefix('S')
+        .and_then(|n| n.parse::<usize>().ok())
+        .and_then(|n| n.checked_sub(1))
+}
+
+pub(super) fn documented_language_codes() -> &'static [&'static str] {
+    language::SUPPORTED_LANGUAGES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_ws_url_from_base() {
+        let cases = [
+            ("", "wss://eu2.rt.speechmatics.com/v2", vec![]),
+            (
+                "https://asr.api.speechmatics.com/v2",
+                "wss://eu2.rt.speechmatics.com/v2",
+                vec![],
+            ),
+            (
+                "https://us1.asr.api.speechmatics.com/v2",
+                "wss://us2.rt.speechmatics.com/v2",
+                vec![],
+            ),
+            (
+                "wss://eu2.rt.speechmatics.com/v2",
+                "wss://eu2.rt.speechmatics.com/v2",
+                vec![],
+            ),
+            (
+                "https://api.hyprnote.com?provider=speechmatics",
+                "wss://api.hyprnote.com/listen",
+                vec![("provider", "speechmatics")],
+            ),
+            (
+                "https://asr.internal.example.com",
+                "wss://asr.internal.example.com/v2",
+                vec![],
+            ),
+        ];
+
+        for (input, expected_url, expected_params) in cases {
+            let (url, params) = SpeechmaticsAdapter::build_ws_url_from_base(input);
+            assert_eq!(url.as_str(), expected_url, "input: {}", input);
+            assert_eq!(
+                params,
+                expected_params
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<Vec<_>>(),
+                "input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_api_url() {
+        assert_eq!(
+            SpeechmaticsAdapter::batch_api_url(""),
+            "https://asr.api.speechmatics.com/v2"
+        );
+        assert_eq!(
+            SpeechmaticsAdapter::batch_api_url("https://us1.asr.api.speechmatics.com"),
+            "https://us1.asr.api.speechmatics.com/v2"
+        );
+        assert_eq!(
+            SpeechmaticsAdapter::batch_api_url("wss://us2.rt.speechmatics.com/v2"),
+            "https://us1.asr.api.speechmatics.com/v2"
+        );
+    }
+
+    #[test]
+    fn test_collect_words_attaches_punctuation() {
+        let results: Vec<SpeechmaticsResult> = serde_json::from_str(
+            r#"[
+                {"type": "word", "start_time": 0.1, "end_time": 0.4, "alternatives": [{"content": "Hello", "confidence": 0.98, "speaker": "S1"}]},
+                {"type": "punctuation", "start_time": 0.4, "end_time": 0.4, "attaches_to": "previous", "alternatives": [{"content": ",", "confidence": 1.0, "speaker": "S1"}]},
+                {"type": "word", "start_time": 0.5, "end_time": 0.9, "alternatives": [{"content": "world", "confidence": 0.91, "speaker": "S2"}]},
+                {"type": "punctuation", "start_time": 0.9, "end_time": 0.9, "alternatives": [{"content": ".", "confidence": 1.0}]}
+            ]"#,
+        )
+        .unwrap();
+
+        let words = collect_words(&results);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].punctuated_word, "Hello,");
+        assert_eq!(words[0].speaker, Some(0));
+        assert_eq!(words[1].word, "world");
+        assert_eq!(words[1].punctuated_word, "world.");
+        assert_eq!(words[1].speaker, Some(1));
+    }
+
+    #[test]
+    fn test_speaker_index() {
+        assert_eq!(speaker_index("S1"), Some(0));
+        assert_eq!(speaker_index("S3"), Some(2));
+        assert_eq!(speaker_index("UU"), None);
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+
+use hypr_vocabulary::{Corrector, Vocabulary};
+use owhisper_interface::stream::{StreamResponse, Word};
+
+use super::AdapterKind;
+use super::capabilities::uses_deepgram_keywords;
+
+// https://www.assemblyai.com/docs/universal-streaming/keyterms-prompting
+const ASSEMBLYAI_MAX_KEYTERM_CHARS: usize = 50;
+
+impl AdapterKind {
+    /// `vocabulary` as the keyword list this provider boosts with, in priority
+    /// order and within its limit. Empty when the provider has no boosting.
+    pub fn boost_terms(&self, model: Option<&str>, vocabulary: &Vocabulary) -> Vec<String> {
+        let terms = vocabulary.terms();
+        self.boost_keywords(model, terms.iter().map(|term| term.text.as_str()))
+    }
+
+    /// [`Self::boost_terms`] for a plain keyword list already in priority
+    /// order, such as the untrimmed list the proxy receives.
+    pub fn boost_keywords<'a>(
+        &self,
+        model: Option<&str>,
+        keywords: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<String> {
+        let capabilities = self.capabilities(model);
+        if !capabilities.keyword_boosting {
+            return vec![];
+        }
+
+        let mut seen = HashSet::new();
+        let terms = keywords
+            .into_iter()
+            .flat_map(|text| self.format_term(model, text))
+            .filter(|term| seen.insert(term.to_lowercase()));
+
+        match capabilities.max_keywords {
+            Some(max) => terms.take(max).collect(),
+            None => terms.collect(),
+        }
+    }
+
+    fn format_term(&self, model: Option<&str>, text: &str) -> Vec<String> {
+        match self {
+            // `keywords` boosts single words; `keyterm` takes phrases.
+            Self::Deepgram if uses_deepgram_keywords(model) => {
+                text.split_whitespace().map(str::to_string).collect()
+            }
+            Self::AssemblyAI if text.chars().count() > ASSEMBLYAI_MAX_KEYTERM_CHARS => vec![],
+            _ => vec![text.to_string()],
+        }
+    }
+
+    /// Post-hoc correction for providers that can't be biased toward
+    /// `vocabulary`. `None` when the provider boosts keywords itself.
+    pub fn vocabulary_corrector(
+        &self,
+        model: Option<&str>,
+        vocabulary: &Vocabulary,
+    ) -> Option<VocabularyCorrector> {
+        if self.capabilities(model).keyword_boosting {
+            return None;
+        }
+
+        let corrector = Corrector::new(vocabulary);
+        (!corrector.is_empty()).then_some(VocabularyCorrector(corrector))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VocabularyCorrector(Corrector);
+
+impl VocabularyCorrector {
+    pub fn correct(&self, response: &mut StreamResponse) {
+        let StreamResponse::TranscriptResponse { channel, .. } = response else {
+            return;
+        };
+
+        for alternative in &mut channel.alternatives {
+            alternative.transcript = self.0.correct_text(&alternative.transcript);
+            alternative.words = self.correct_words(std::mem::take(&mut alternative.words));
+        }
+    }
+
+    // Corrected spans collapse into a single word covering their time range.
+    fn correct_words(&self, words: Vec<Word>) -> Vec<Word> {
+        let tokens: Vec<&str> = words
+            .iter()
+            .map(|w| w.punctuated_word.as_deref().unwrap_or(&w.word))
+            .collect();
+        let corrections = self.0.find(&tokens);
+        if corrections.is_empty() {
+            return words;
+        }
+
+        let mut out = Vec::with_capacity(words.len());
+        let mut next = 0;
+        for correction in &corrections {
+            out.extend_from_slice(&words[next..correction.start]);
+
+            let span = &words[correction.start..correction.start + correction.len];
+            let first = &span[0];
+            out.push(Word {
+                word: correction.text.clone(),
+                start: first.start,
+                end: span[span.len() - 1].end,
+                confidence: span
+                    .iter()
+                    .map(|w| w.confidence)
+                    .fold(f64::INFINITY, f64::min),
+                speaker: first.speaker,
+                punctuated_word: Some(correction.respell(&tokens)),
+                language: first.language.clone(),
+            });
+
+            next = correction.start + correction.len;
+        }
+        out.extend_from_slice(&words[next..]);
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_vocabulary::{Term, TermKind, TermSource};
+    use owhisper_interface::stream::{Alternatives, Channel, Metadata};
+
+    fn vocabulary() -> Vocabulary {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.extend_keywords(["roadmap"]);
+        vocabulary.extend_participants(["Jane Doe"]);
+        vocabulary.push(
+            Term::new("Hyprnote", TermKind::Product, TermSource::User)
+                .with_sounds_like(["hyper note"]),
+        );
+        vocabulary.push(Term::new(
+            "a product name that is far too long to be a keyterm",
+            TermKind::Other,
+            TermSource::User,
+        ));
+        vocabulary
+    }
+
+    fn word(text: &str, start: f64) -> Word {
+        Word {
+            word: text.trim_end_matches('.').to_lowercase(),
+            start,
+            end: start + 0.2,
+            confidence: 0.9,
+            speaker: None,
+            punctuated_word: Some(text.to_string()),
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_boost_terms_per_provider() {
+        let vocabulary = vocabulary();
+
+        assert_eq!(
+            AdapterKind::Deepgram.boost_terms(Some("nova-3"), &vocabulary),
+            vec![
+                "Hyprnote",
+                "a product name that is far too long to be a keyterm",
+                "Jane Doe",
+                "roadmap",
+            ]
+        );
+        assert_eq!(
+            AdapterKind::Deepgram
+                .boost_terms(Some("nova-2"), &vocabulary)
+                .len(),
+            15
+        );
+        assert_eq!(
+            AdapterKind::AssemblyAI.boost_terms(None, &vocabulary),
+            vec!["Hyprnote", "Jane Doe", "roadmap"]
+        );
+        assert!(
+            AdapterKind::OpenAI
+                .boost_terms(None, &vocabulary)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_boost_terms_respects_limit() {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.extend_keywords((0..200).map(|i| format!("term{i}")));
+
+        assert_eq!(
+            AdapterKind::Deepgram
+                .boost_terms(Some("nova-3"), &vocabulary)
+                .len(),
+            50
+        );
+        assert_eq!(
+            AdapterKind::Soniox.boost_terms(None, &vocabulary).len(),
+            200
+        );
+    }
+
+    #[test]
+    fn test_boost_keywords_maps_plain_list() {
+        let keywords: Vec<String> = (0..200).map(|i| format!("term {i}")).collect();
+        let keywords = || keywords.iter().map(String::as_str);
+
+        let nova_2 = AdapterKind::Deepgram.boost_keywords(Some("nova-2"), keywords());
+        assert_eq!(nova_2.len(), 99);
+        assert_eq!(nova_2[..3], ["term", "0", "1"]);
+
+        assert_eq!(
+            AdapterKind::Soniox.boost_keywords(None, keywords()).len(),
+            200
+        );
+        assert!(
+            AdapterKind::OpenAI
+                .boost_keywords(None, keywords())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_corrector_only_for_providers_without_boosting() {
+        let vocabulary = vocabulary();
+
+        assert!(
+            AdapterKind::Deepgram
+                .vocabulary_corrector(None, &vocabulary)
+                .is_none()
+        );
+        assert!(
+            AdapterKind::OpenAI
+                .vocabulary_corrector(None, &Vocabulary::new())
+                .is_none()
+        );
+
+        let corrector = AdapterKind::OpenAI
+            .vocabulary_corrector(None, &vocabulary)
+            .unwrap();
+
+        let mut response = StreamResponse::TranscriptResponse {
+            start: 0.0,
+            duration: 1.0,
+            is_final: true,
+            speech_final: true,
+            from_finalize: false,
+            channel: Channel {
+                alternatives: vec![Alternatives {
+                    transcript: "I like hyper note.".to_string(),
+                    words: vec![
+                        word("I", 0.0),
+                        word("like", 0.2),
+                        word("hyper", 0.4),
+                        word("note.", 0.6),
+                    ],
+                    confidence: 0.9,
+                    languages: vec![],
+                    translation: None,
+                }],
+            },
+            metadata: Metadata::default(),
+            channel_index: vec![0, 1],
+        };
+        corrector.correct(&mut response);
+
+        let StreamResponse::TranscriptResponse { channel, .. } = &response else {
+            unreachable!();
+        };
+        let alternative = &channel.alternatives[0];
+        assert_eq!(alternative.transcript, "I like Hyprnote.");
+        assert_eq!(alternative.words.len(), 3);
+        assert_eq!(alternative.words[2].word, "Hyprnote");
+        assert_eq!(
+            alternative.words[2].punctuated_word.as_deref(),
+            Some("Hyprnote.")
+        );
+        assert_eq!(alternative.words[2].start, 0.4);
+        assert!((alternative.words[2].end - 0.8).abs() < 1e-9);
+    }
+}
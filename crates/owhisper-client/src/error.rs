@@ -13,6 +13,10 @@ pub enum Error {
         status: reqwest::StatusCode,
         body: String,
     },
+    #[error("storage error: {0}")]
+    Storage(String),
+    #[error("authentication error: {0}")]
+    Auth(String),
     #[error("websocket error: {0}")]
     WebSocket(String),
 }
@@ -0,0 +1,399 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use owhisper_interface::ListenParams;
+use owhisper_interface::batch::Response as BatchResponse;
+use reqwest_middleware::ClientWithMiddleware;
+use serde::{Deserialize, Serialize};
+
+use crate::adapter::AsyncBatchSttAdapter;
+use crate::error::Error;
+use crate::http_client::create_client;
+use crate::polling::{PollingConfig, PollingResult, poll_until};
+use crate::upload::{DEFAULT_PART_SIZE, MultipartUpload, UploadTarget};
+
+// Multi-hour recordings take a while to transcribe.
+const MAX_WAIT: Duration = Duration::from_secs(6 * 60 * 60);
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A large-file transcription in progress. Callers persist it on every
+/// update and hand it back to [`LargeFileClient::resume`] after a restart.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LargeFileJob {
+    pub file_path: PathBuf,
+    pub upload: MultipartUpload,
+    /// Set once the provider has accepted the job.
+    pub job_id: Option<String>,
+}
+
+/// Transcribes files too large to send in one request: the file goes to
+/// S3-compatible storage in parts, and the provider fetches it from there
+/// as an async job.
+pub struct LargeFileClient<A: AsyncBatchSttAdapter, T: UploadTarget> {
+    client: ClientWithMiddleware,
+    adapter: A,
+    api_base: String,
+    api_key: String,
+    params: ListenParams,
+    target: T,
+    part_size: u64,
+}
+
+impl<A: AsyncBatchSttAdapter, T: UploadTarget> LargeFileClient<A, T> {
+    pub fn new(
+        api_base: impl Into<String>,
+        api_key: impl Into<String>,
+        params: ListenParams,
+        target: T,
+    ) -> Self {
+        Self {
+            client: create_client(),
+            adapter: A::default(),
+            api_base: api_base.into(),
+            api_key: api_key.into(),
+            params,
+            target,
+            part_size: DEFAULT_PART_SIZE,
+        }
+    }
+
+    pub fn with_part_size(mut self, part_size: u64) -> Self {
+        self.part_size = part_size;
+        self
+    }
+
+    /// Starts uploading `file_path` to `key` and returns the job to persist.
+    pub async fn start(
+        &self,
+        file_path: impl AsRef<Path>,
+        key: impl Into<String>,
+    ) -> Result<LargeFileJob, Error> {
+        let file_path = file_path.as_ref().to_path_buf();
+        let file_size = tokio::fs::metadata(&file_path)
+            .await
+            .map_err(|e| Error::AudioProcessing(format!("failed to read file: {}", e)))?
+            .len();
+
+        let upload = MultipartUpload::create(&self.target, key, file_size, self.part_size).await?;
+
+        Ok(LargeFileJob {
+            file_path,
+            upload,
+            job_id: None,
+        })
+    }
+
+    /// Runs `job` from wherever it stopped: uploads the remaining parts,
+    /// submits it, then waits for the transcript. `on_update` runs whenever
+    /// the job changes.
+    pub async fn resume(
+        &self,
+        job: &mut LargeFileJob,
+        mut on_update: impl FnMut(&LargeFileJob),
+    ) -> Result<BatchResponse, Error> {
+        if !job.upload.completed {
+            let file_path = job.file_path.clone();
+            job.upload
+                .resume(&self.client, &self.target, &file_path, |upload| {
+                    on_update(&LargeFileJob {
+                        file_path: file_path.clone(),
+                        upload: upload.clone(),
+                        job_id: None,
+                    })
+                })
+                .await?;
+        }
+
+        let job_id = match &job.job_id {
+            Some(job_id) => job_id.clone(),
+            None => {
+                let audio_url = self.target.download_url(&job.upload.key).await?;
+                let job_id = self
+                    .adapter
+                    .submit_job(
+                        &self.client,
+                        &self.api_base,
+                        &self.api_key,
+                        &self.params,
+                        &audio_url,
+                    )
+                    .await?;
+                tracing::info!(job_id = %job_id, "large file transcription submitted");
+
+                job.job_id = Some(job_id.clone());
+                on_update(job);
+                job_id
+            }
+        };
+
+        let response = poll_until(
+            || async {
+                self.adapter
+                    .job_status(&self.client, &self.api_base, &self.api_key, &job_id)
+                    .await
+                    .map(PollingResult::from)
+            },
+            self.polling_config(),
+        )
+        .await?;
+
+        if let Err(error) = self.target.delete(&job.upload.key).await {
+            tracing::warn!(key = %job.upload.key, ?error, "failed to delete uploaded file");
+        }
+
+        Ok(response)
+    }
+
+    /// Uploads and transcribes `file_path` without persisting progress.
+    pub async fn transcribe_file(
+        &self,
+        file_path: impl AsRef<Path>,
+        key: impl Into<String>,
+    ) -> Result<BatchResponse, Error> {
+        let mut job = self.start(file_path, key).await?;
+        self.resume(&mut job, |_| {}).await
+    }
+
+    fn polling_config(&self) -> PollingConfig {
+        PollingConfig::default()
+            .with_interval(POLL_INTERVAL)
+            .with_max_attempts((MAX_WAIT.as_secs() / POLL_INTERVAL.as_secs()) as usize)
+            .with_timeout_error("transcription timed out")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use owhisper_interface::batch::{Alternatives, Channel, Results};
+    use wiremock::matchers::{method, path, path_regex};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use super::*;
+    use crate::adapter::{BatchFuture, BatchSttAdapter, JobFuture, JobStatus, JobStatusFuture};
+
+    const PART_SIZE: u64 = 5 * 1024 * 1024;
+
+    struct MockTarget {
+        base_url: String,
+        completed: Mutex<Option<Vec<String>>>,
+        deleted: Mutex<Vec<String>>,
+    }
+
+    impl UploadTarget for MockTarget {
+        async fn create_upload(&self, _key: &str) -> Result<String, Error> {
+            Ok("upload".to_string())
+        }
+
+        async fn part_urls(
+            &self,
+            key: &str,
+            _upload_id: &str,
+            num_parts: usize,
+        ) -> Result<Vec<String>, Error> {
+            Ok((1..=num_parts)
+                .map(|n| format!("{}/{}/{}", self.base_url, key, n))
+                .collect())
+        }
+
+        async fn complete_upload(
+            &self,
+            _key: &str,
+            _upload_id: &str,
+            etags: Vec<String>,
+        ) -> Result<(), Error> {
+            *self.completed.lock().unwrap() = Some(etags);
+            Ok(())
+        }
+
+        async fn download_url(&self, key: &str) -> Result<String, Error> {
+            Ok(format!("{}/{}", self.base_url, key))
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), Error> {
+            self.deleted.lock().unwrap().push(key.to_string());
+            Ok(())
+        }
+    }
+
+    static STATUS_CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+    // Reports the job as pending on the first check.
+    #[derive(Clone, Default)]
+    struct MockAdapter;
+
+    impl BatchSttAdapter for MockAdapter {
+        fn is_supported_languages(
+            &self,
+            _languages: &[hypr_language::Language],
+            _model: Option<&str>,
+        ) -> bool {
+            true
+        }
+
+        fn transcribe_file<'a, P: AsRef<Path> + Send + 'a>(
+            &'a self,
+            _client: &'a ClientWithMiddleware,
+            _api_base: &'a str,
+            _api_key: &'a str,
+            _params: &'a ListenParams,
+            _file_path: P,
+        ) -> BatchFuture<'a> {
+            Box::pin(async { Err(Error::AudioProcessing("unsupported".to_string())) })
+        }
+    }
+
+    impl AsyncBatchSttAdapter for MockAdapter {
+        fn submit_job<'a>(
+            &'a self,
+            _client: &'a ClientWithMiddleware,
+            _api_base: &'a str,
+            _api_key: &'a str,
+            _params: &'a ListenParams,
+            audio_url: &'a str,
+        ) -> JobFuture<'a> {
+            Box::pin(async move { Ok(format!("job:{}", audio_url)) })
+        }
+
+        fn job_status<'a>(
+            &'a self,
+            _client: &'a ClientWithMiddleware,
+            _api_base: &'a str,
+            _api_key: &'a str,
+            job_id: &'a str,
+        ) -> JobStatusFuture<'a> {
+            Box::pin(async move {
+                if STATUS_CHECKS.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Ok(JobStatus::Pending);
+                }
+
+                Ok(JobStatus::Completed(BatchResponse {
+                    metadata: serde_json::json!({}),
+                    results: Results {
+                        channels: vec![Channel {
+                            alternatives: vec![Alternatives {
+                                transcript: job_id.to_string(),
+                                confidence: 1.0,
+                                words: vec![],
+                            }],
+                        }],
+                    },
+                }))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_after_interrupted_upload() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/audio.wav/2"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path_regex(r"^/audio.wav/\d+$"))
+            .respond_with(|request: &Request| {
+                let part = request.url.path().rsplit('/').next().unwrap();
+                ResponseTemplate::new(200).insert_header("ETag", format!("etag-{}", part))
+            })
+            .mount(&server)
+            .await;
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), vec![7u8; (PART_SIZE * 2 + 1024) as usize]).unwrap();
+
+        let target = MockTarget {
+            base_url: server.uri(),
+            completed: Mutex::new(None),
+            deleted: Mutex::new(vec![]),
+        };
+        let client =
+            LargeFileClient::<MockAdapter, _>::new("", "", ListenParams::default(), target)
+                .with_part_size(PART_SIZE);
+
+        let mut job = client.start(file.path(), "audio.wav").await.unwrap();
+        assert_eq!(job.upload.num_parts(), 3);
+
+        let mut saved = serde_json::to_string(&job).unwrap();
+        assert!(
+            client
+                .resume(&mut job, |job| saved = serde_json::to_string(job).unwrap())
+                .await
+                .is_err()
+        );
+
+        // As if the app restarted with only the persisted state.
+        let mut job: LargeFileJob = serde_json::from_str(&saved).unwrap();
+        assert_eq!(job.upload.uploaded_bytes(), PART_SIZE);
+        assert!(job.job_id.is_none());
+
+        let response = client.resume(&mut job, |_| {}).await.unwrap();
+
+        let transcript = &response.results.channels[0].alternatives[0].transcript;
+        assert_eq!(transcript, &format!("job:{}/audio.wav", server.uri()));
+        assert_eq!(job.job_id.as_deref(), Some(transcript.as_str()));
+        assert_eq!(
+            client.target.completed.lock().unwrap().as_deref(),
+            Some(
+                &[
+                    "etag-1".to_string(),
+                    "etag-2".to_string(),
+                    "etag-3".to_string()
+                ][..]
+            )
+        );
+        assert_eq!(*client.target.deleted.lock().unwrap(), vec!["audio.wav"]);
+
+        let first_part_uploads = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.url.path() == "/audio.wav/1")
+            .count();
+        assert_eq!(first_part_uploads, 1);
+    }
+
+    // Needs Docker. CI runs it in `.github/workflows/stt_upload_ci.yaml`.
+    #[cfg(feature = "s3")]
+    #[tokio::test]
+    #[ignore]
+    async fn test_upload_to_minio() {
+        use testcontainers_modules::{minio, testcontainers::runners::AsyncRunner};
+
+        let container = minio::MinIO::default().start().await.unwrap();
+        let port = container.get_host_port_ipv4(9000).await.unwrap();
+
+        let s3 = hypr_s3::Client::builder()
+            .endpoint_url(format!("http://127.0.0.1:{}", port))
+            .bucket("test")
+            .credentials("minioadmin", "minioadmin")
+            .build()
+            .await;
+        s3.create_bucket().await.unwrap();
+        let user_s3 = s3.for_user("test-user");
+
+        let data: Vec<u8> = (0..PART_SIZE * 2 + 1024).map(|i| i as u8).collect();
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), &data).unwrap();
+
+        let mut upload =
+            MultipartUpload::create(&user_s3, "audio.wav", data.len() as u64, PART_SIZE)
+                .await
+                .unwrap();
+        upload
+            .resume(&create_client(), &user_s3, file.path(), |_| {})
+            .await
+            .unwrap();
+        assert!(upload.completed);
+
+        let uploaded = user_s3.get("audio.wav").await.unwrap().into_bytes();
+        assert_eq!(uploaded.as_ref(), data.as_slice());
+    }
+}
@@ -3,9 +3,14 @@ mod batch;
 mod error;
 mod error_detection;
 mod http_client;
+mod large_file;
 mod live;
 pub(crate) mod polling;
 mod providers;
+mod reconnect;
+mod remote_upload;
+mod transport;
+mod upload;
 
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -17,9 +22,11 @@ use std::marker::PhantomData;
 
 pub use adapter::deepgram::DeepgramModel;
 pub use adapter::{
-    AdapterKind, ArgmaxAdapter, AssemblyAIAdapter, BatchSttAdapter, DeepgramAdapter,
-    ElevenLabsAdapter, FireworksAdapter, GladiaAdapter, HyprnoteAdapter, LanguageQuality,
-    LanguageSupport, OpenAIAdapter, RealtimeSttAdapter, SonioxAdapter, append_provider_param,
+    AdapterKind, ArgmaxAdapter, AssemblyAIAdapter, AsyncBatchSttAdapter, AudioEncoding,
+    BatchSttAdapter, Capabilities, CapabilityRequirements, DeepgramAdapter, ElevenLabsAdapter,
+    FireworksAdapter, GladiaAdapter, HyprnoteAdapter, JobStatus, LanguageQuality, LanguageSupport,
+    OpenAIAdapter, RealtimeSttAdapter, RevAiAdapter, SonioxAdapter, SpeechmaticsAdapter,
+    TranscriptFormatter, VocabularyCorrector, append_provider_param,
     documented_language_codes_batch, documented_language_codes_live, is_hyprnote_proxy,
     is_local_host, normalize_languages,
 };
@@ -29,13 +36,22 @@ pub use adapter::{StreamingBatchConfig, StreamingBatchEvent, StreamingBatchStrea
 pub use batch::{BatchClient, BatchClientBuilder};
 pub use error::Error;
 pub use hypr_ws_client;
+pub use large_file::{LargeFileClient, LargeFileJob};
 pub use live::{DualHandle, FinalizeHandle, ListenClient, ListenClientDual};
+pub use reconnect::ReconnectConfig;
+pub use remote_upload::{
+    CompleteUploadRequest, CreatedUpload, DownloadUrl, PartUrls, PartUrlsRequest,
+    RemoteUploadTarget, UploadKey,
+};
+pub use upload::{DEFAULT_PART_SIZE, MultipartUpload, UploadTarget};
 
 pub struct ListenClientBuilder<A: RealtimeSttAdapter = DeepgramAdapter> {
     api_base: Option<String>,
     api_key: Option<String>,
     params: Option<owhisper_interface::ListenParams>,
     extra_headers: Vec<(String, String)>,
+    reconnect: Option<ReconnectConfig>,
+    audio_encoding: AudioEncoding,
     _marker: PhantomData<A>,
 }
 
@@ -46,6 +62,8 @@ impl Default for ListenClientBuilder {
             api_key: None,
             params: None,
             extra_headers: Vec::new(),
+            reconnect: None,
+            audio_encoding: AudioEncoding::Linear16,
             _marker: PhantomData,
         }
     }
@@ -72,12 +90,29 @@ impl<A: RealtimeSttAdapter> ListenClientBuilder<A> {
         self
     }
 
+    /// Reopens dropped sockets and replays the audio that was not finalized yet.
+    /// Without it, the first socket error ends the stream.
+    pub fn reconnect(mut self, config: ReconnectConfig) -> Self {
+        self.reconnect = Some(config);
+        self
+    }
+
+    /// Requests compressed audio on the wire. Only honored when talking to the
+    /// Hyprnote proxy with an adapter that streams raw binary frames; every
+    /// other connection falls back to linear16.
+    pub fn audio_encoding(mut self, encoding: AudioEncoding) -> Self {
+        self.audio_encoding = encoding;
+        self
+    }
+
     pub fn adapter<B: RealtimeSttAdapter>(self) -> ListenClientBuilder<B> {
         ListenClientBuilder {
             api_base: self.api_base,
             api_key: self.api_key,
             params: self.params,
             extra_headers: self.extra_headers,
+            reconnect: self.reconnect,
+            audio_encoding: self.audio_encoding,
             _marker: PhantomData,
         }
     }
@@ -92,6 +127,15 @@ impl<A: RealtimeSttAdapter> ListenClientBuilder<A> {
         params
     }
 
+    fn get_audio_encoding(&self, adapter: &A) -> AudioEncoding {
+        transport::negotiate_encoding(
+            self.audio_encoding,
+            self.get_api_base(),
+            adapter,
+            self.get_params().sample_rate,
+        )
+    }
+
     async fn build_request(
         &self,
         adapter: &A,
@@ -100,10 +144,11 @@ impl<A: RealtimeSttAdapter> ListenClientBuilder<A> {
         let params = self.get_params();
         let original_api_base = self.get_api_base();
         let api_base = append_provider_param(original_api_base, adapter.provider_name());
-        let url = adapter
+        let mut url = adapter
             .build_ws_url_with_api_key(&api_base, &params, channels, self.api_key.as_deref())
             .await
             .unwrap_or_else(|| adapter.build_ws_url(&api_base, &params, channels));
+        transport::apply_encoding_param(&mut url, self.get_audio_encoding(adapter));
         let uri = url.to_string().parse().unwrap();
 
         let mut request = hypr_ws_client::client::ClientRequestBuilder::new(uri);
@@ -129,11 +174,16 @@ impl<A: RealtimeSttAdapter> ListenClientBuilder<A> {
         let params = self.get_params();
         let request = self.build_request(&adapter, channels).await;
         let initial_message = adapter.initial_message(self.api_key.as_deref(), &params, channels);
+        let audio_encoding = self.get_audio_encoding(&adapter);
 
         ListenClient {
             adapter,
             request,
             initial_message,
+            reconnect: self.reconnect,
+            channels,
+            sample_rate: params.sample_rate,
+            audio_encoding,
         }
     }
 
@@ -151,11 +201,15 @@ impl<A: RealtimeSttAdapter> ListenClientBuilder<A> {
         let params = self.get_params();
         let request = self.build_request(&adapter, channels).await;
         let initial_message = adapter.initial_message(self.api_key.as_deref(), &params, channels);
+        let audio_encoding = self.get_audio_encoding(&adapter);
 
         ListenClientDual {
             adapter,
             request,
             initial_message,
+            reconnect: self.reconnect,
+            sample_rate: params.sample_rate,
+            audio_encoding,
         }
     }
 }
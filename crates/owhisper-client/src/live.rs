@@ -4,12 +4,14 @@ use std::time::Duration;
 use futures_util::{Stream, StreamExt};
 
 use hypr_ws_client::client::{
-    ClientRequestBuilder, Message, Utf8Bytes, WebSocketClient, WebSocketHandle, WebSocketIO,
+    ClientRequestBuilder, Message, Utf8Bytes, WebSocketClient, WebSocketIO,
 };
 use owhisper_interface::stream::StreamResponse;
 use owhisper_interface::{ControlMessage, MixedMessage};
 
-use crate::{DeepgramAdapter, ListenClientBuilder, RealtimeSttAdapter};
+use crate::reconnect::{LiveHandle, LiveSocket, ReconnectConfig, TimedInput};
+use crate::transport::AudioTransport;
+use crate::{AudioEncoding, DeepgramAdapter, ListenClientBuilder, RealtimeSttAdapter};
 
 pub type ListenClientInput = MixedMessage<bytes::Bytes, ControlMessage>;
 pub type ListenClientDualInput = MixedMessage<(bytes::Bytes, bytes::Bytes), ControlMessage>;
@@ -19,6 +21,10 @@ pub struct ListenClient<A: RealtimeSttAdapter = DeepgramAdapter> {
     pub(crate) adapter: A,
     pub(crate) request: ClientRequestBuilder,
     pub(crate) initial_message: Option<Message>,
+    pub(crate) reconnect: Option<ReconnectConfig>,
+    pub(crate) channels: u8,
+    pub(crate) sample_rate: u32,
+    pub(crate) audio_encoding: AudioEncoding,
 }
 
 #[derive(Clone)]
@@ -26,21 +32,24 @@ pub struct ListenClientDual<A: RealtimeSttAdapter> {
     pub(crate) adapter: A,
     pub(crate) request: ClientRequestBuilder,
     pub(crate) initial_message: Option<Message>,
+    pub(crate) reconnect: Option<ReconnectConfig>,
+    pub(crate) sample_rate: u32,
+    pub(crate) audio_encoding: AudioEncoding,
 }
 
 pub struct SingleHandle {
-    inner: WebSocketHandle,
+    inner: LiveHandle,
     finalize_text: Utf8Bytes,
 }
 
 pub enum DualHandle {
     Native {
-        inner: WebSocketHandle,
+        inner: LiveHandle,
         finalize_text: Utf8Bytes,
     },
     Split {
-        mic: WebSocketHandle,
-        spk: WebSocketHandle,
+        mic: LiveHandle,
+        spk: LiveHandle,
         finalize_text: Utf8Bytes,
     },
 }
@@ -143,41 +152,6 @@ impl WebSocketIO for ListenClientIO {
     }
 }
 
-pub type TransformedDualInput = MixedMessage<(bytes::Bytes, bytes::Bytes, Message), ControlMessage>;
-
-pub struct ListenClientDualIO;
-
-impl WebSocketIO for ListenClientDualIO {
-    type Data = TransformedDualInput;
-    type Input = TransformedInput;
-    type Output = String;
-
-    fn to_input(data: Self::Data) -> Self::Input {
-        match data {
-            TransformedDualInput::Audio((_, _, transform_fn_result)) => {
-                TransformedInput::Audio(transform_fn_result)
-            }
-            TransformedDualInput::Control(control) => TransformedInput::Control(control),
-        }
-    }
-
-    fn to_message(input: Self::Input) -> Message {
-        match input {
-            TransformedInput::Audio(msg) => msg,
-            TransformedInput::Control(control) => {
-                Message::Text(serde_json::to_string(&control).unwrap().into())
-            }
-        }
-    }
-
-    fn from_message(msg: Message) -> Option<Self::Output> {
-        match msg {
-            Message::Text(text) => Some(text.to_string()),
-            _ => None,
-        }
-    }
-}
-
 impl ListenClient<DeepgramAdapter> {
     pub fn builder() -> ListenClientBuilder<DeepgramAdapter> {
         ListenClientBuilder::default()
@@ -197,36 +171,29 @@ impl<A: RealtimeSttAdapter> ListenClient<A> {
         hypr_ws_client::Error,
     > {
         let finalize_text = extract_finalize_text(&self.adapter);
-        let ws = websocket_client_with_keep_alive(&self.request, &self.adapter);
 
         // Transform audio stream to use adapter's audio_to_message method
         let adapter_for_transform = self.adapter.clone();
-        let transformed_stream = audio_stream.map(move |input| match input {
-            MixedMessage::Audio(data) => {
-                TransformedInput::Audio(adapter_for_transform.audio_to_message(data))
-            }
-            MixedMessage::Control(control) => TransformedInput::Control(control),
+        let mut transport =
+            AudioTransport::new(self.audio_encoding, self.sample_rate, self.channels);
+        let transformed_stream = audio_stream.flat_map(move |input| {
+            futures_util::stream::iter(transport.timed_inputs(&adapter_for_transform, input))
         });
 
-        let (raw_stream, inner) = ws
-            .from_audio::<ListenClientIO, _>(self.initial_message, Box::pin(transformed_stream))
-            .await?;
-
-        let adapter = self.adapter;
-        let mapped_stream = raw_stream.flat_map(move |result| {
-            let adapter = adapter.clone();
-            let responses: Vec<Result<StreamResponse, hypr_ws_client::Error>> = match result {
-                Ok(raw) => adapter.parse_response(&raw).into_iter().map(Ok).collect(),
-                Err(e) => vec![Err(e)],
-            };
-            futures_util::stream::iter(responses)
-        });
+        let socket = LiveSocket {
+            adapter: self.adapter,
+            request: self.request,
+            initial_message: self.initial_message,
+            reconnect: self.reconnect,
+            channels: self.channels as usize,
+        };
+        let (stream, inner) = socket.open(transformed_stream).await?;
 
         let handle = SingleHandle {
             inner,
             finalize_text,
         };
-        Ok((mapped_stream, handle))
+        Ok((stream, handle))
     }
 }
 
@@ -252,38 +219,34 @@ impl<A: RealtimeSttAdapter> ListenClientDual<A> {
         stream: impl Stream<Item = ListenClientDualInput> + Send + Unpin + 'static,
     ) -> Result<(DualOutputStream, DualHandle), hypr_ws_client::Error> {
         let finalize_text = extract_finalize_text(&self.adapter);
-        let ws = websocket_client_with_keep_alive(&self.request, &self.adapter);
 
         // Transform audio stream to use adapter's audio_to_message method
         let adapter_for_transform = self.adapter.clone();
-        let transformed_stream = stream.map(move |input| match input {
-            MixedMessage::Audio((mic, speaker)) => {
-                let interleaved = interleave_audio(&mic, &speaker);
-                let msg = adapter_for_transform.audio_to_message(interleaved.into());
-                TransformedDualInput::Audio((mic, speaker, msg))
-            }
-            MixedMessage::Control(control) => TransformedDualInput::Control(control),
-        });
-
-        let (raw_stream, inner) = ws
-            .from_audio::<ListenClientDualIO, _>(self.initial_message, Box::pin(transformed_stream))
-            .await?;
-
-        let adapter = self.adapter;
-        let mapped_stream = raw_stream.flat_map(move |result| {
-            let adapter = adapter.clone();
-            let responses: Vec<Result<StreamResponse, hypr_ws_client::Error>> = match result {
-                Ok(raw) => adapter.parse_response(&raw).into_iter().map(Ok).collect(),
-                Err(e) => vec![Err(e)],
+        let mut transport = AudioTransport::new(self.audio_encoding, self.sample_rate, 2);
+        let transformed_stream = stream.flat_map(move |input| {
+            let input = match input {
+                MixedMessage::Audio((mic, speaker)) => {
+                    MixedMessage::Audio(interleave_audio(&mic, &speaker).into())
+                }
+                MixedMessage::Control(control) => MixedMessage::Control(control),
             };
-            futures_util::stream::iter(responses)
+            futures_util::stream::iter(transport.timed_inputs(&adapter_for_transform, input))
         });
 
+        let socket = LiveSocket {
+            adapter: self.adapter,
+            request: self.request,
+            initial_message: self.initial_message,
+            reconnect: self.reconnect,
+            channels: 2,
+        };
+        let (stream, inner) = socket.open(transformed_stream).await?;
+
         let handle = DualHandle::Native {
             inner,
             finalize_text,
         };
-        Ok((Box::pin(mapped_stream), handle))
+        Ok((Box::pin(stream), handle))
     }
 
     #[allow(clippy::wrong_self_convention)]
@@ -292,55 +255,32 @@ impl<A: RealtimeSttAdapter> ListenClientDual<A> {
         stream: impl Stream<Item = ListenClientDualInput> + Send + Unpin + 'static,
     ) -> Result<(DualOutputStream, DualHandle), hypr_ws_client::Error> {
         let finalize_text = extract_finalize_text(&self.adapter);
-        let (mic_tx, mic_rx) = tokio::sync::mpsc::channel::<TransformedInput>(32);
-        let (spk_tx, spk_rx) = tokio::sync::mpsc::channel::<TransformedInput>(32);
-
-        let mic_ws = websocket_client_with_keep_alive(&self.request, &self.adapter);
-        let spk_ws = websocket_client_with_keep_alive(&self.request, &self.adapter);
+        let (mic_tx, mic_rx) = tokio::sync::mpsc::channel::<TimedInput>(32);
+        let (spk_tx, spk_rx) = tokio::sync::mpsc::channel::<TimedInput>(32);
+
+        let socket = || LiveSocket {
+            adapter: self.adapter.clone(),
+            request: self.request.clone(),
+            initial_message: self.initial_message.clone(),
+            reconnect: self.reconnect.clone(),
+            channels: 1,
+        };
 
         let mic_outbound = tokio_stream::wrappers::ReceiverStream::new(mic_rx);
         let spk_outbound = tokio_stream::wrappers::ReceiverStream::new(spk_rx);
 
-        let mic_connect =
-            mic_ws.from_audio::<ListenClientIO, _>(self.initial_message.clone(), mic_outbound);
-        let spk_connect =
-            spk_ws.from_audio::<ListenClientIO, _>(self.initial_message, spk_outbound);
-
-        let ((mic_raw, mic_handle), (spk_raw, spk_handle)) =
-            tokio::try_join!(mic_connect, spk_connect)?;
+        let ((mic_stream, mic_handle), (spk_stream, spk_handle)) =
+            tokio::try_join!(socket().open(mic_outbound), socket().open(spk_outbound))?;
 
         tokio::spawn(forward_dual_to_single(
             stream,
             mic_tx,
             spk_tx,
             self.adapter.clone(),
+            AudioTransport::new(self.audio_encoding, self.sample_rate, 1),
+            AudioTransport::new(self.audio_encoding, self.sample_rate, 1),
         ));
 
-        let adapter = self.adapter.clone();
-        let mic_stream = mic_raw.flat_map({
-            let adapter = adapter.clone();
-            move |result| {
-                let adapter = adapter.clone();
-                let responses: Vec<Result<StreamResponse, hypr_ws_client::Error>> = match result {
-                    Ok(raw) => adapter.parse_response(&raw).into_iter().map(Ok).collect(),
-                    Err(e) => vec![Err(e)],
-                };
-                futures_util::stream::iter(responses)
-            }
-        });
-
-        let spk_stream = spk_raw.flat_map({
-            let adapter = adapter.clone();
-            move |result| {
-                let adapter = adapter.clone();
-                let responses: Vec<Result<StreamResponse, hypr_ws_client::Error>> = match result {
-                    Ok(raw) => adapter.parse_response(&raw).into_iter().map(Ok).collect(),
-                    Err(e) => vec![Err(e)],
-                };
-                futures_util::stream::iter(responses)
-            }
-        });
-
         let merged_stream = merge_streams_with_channel_remap(mic_stream, spk_stream);
 
         Ok((
@@ -356,21 +296,32 @@ impl<A: RealtimeSttAdapter> ListenClientDual<A> {
 
 async fn forward_dual_to_single<A: RealtimeSttAdapter>(
     mut stream: impl Stream<Item = ListenClientDualInput> + Send + Unpin + 'static,
-    mic_tx: tokio::sync::mpsc::Sender<TransformedInput>,
-    spk_tx: tokio::sync::mpsc::Sender<TransformedInput>,
+    mic_tx: tokio::sync::mpsc::Sender<TimedInput>,
+    spk_tx: tokio::sync::mpsc::Sender<TimedInput>,
     adapter: A,
+    mut mic_transport: AudioTransport,
+    mut spk_transport: AudioTransport,
 ) {
     while let Some(msg) = stream.next().await {
         match msg {
             MixedMessage::Audio((mic, spk)) => {
-                let mic_msg = adapter.audio_to_message(mic);
-                let spk_msg = adapter.audio_to_message(spk);
-                let _ = mic_tx.try_send(MixedMessage::Audio(mic_msg));
-                let _ = spk_tx.try_send(MixedMessage::Audio(spk_msg));
+                for input in mic_transport.timed_inputs(&adapter, MixedMessage::Audio(mic)) {
+                    let _ = mic_tx.try_send(input);
+                }
+                for input in spk_transport.timed_inputs(&adapter, MixedMessage::Audio(spk)) {
+                    let _ = spk_tx.try_send(input);
+                }
             }
             MixedMessage::Control(ctrl) => {
-                let _ = mic_tx.send(MixedMessage::Control(ctrl.clone())).await;
-                let _ = spk_tx.send(MixedMessage::Control(ctrl)).await;
+                let mic_inputs =
+                    mic_transport.timed_inputs(&adapter, MixedMessage::Control(ctrl.clone()));
+                let spk_inputs = spk_transport.timed_inputs(&adapter, MixedMessage::Control(ctrl));
+                for input in mic_inputs {
+                    let _ = mic_tx.send(input).await;
+                }
+                for input in spk_inputs {
+                    let _ = spk_tx.send(input).await;
+                }
             }
         }
     }
@@ -401,7 +352,7 @@ where
     futures_util::stream::select(mic_mapped, spk_mapped)
 }
 
-fn websocket_client_with_keep_alive<A: RealtimeSttAdapter>(
+pub(crate) fn websocket_client_with_keep_alive<A: RealtimeSttAdapter>(
     request: &ClientRequestBuilder,
     adapter: &A,
 ) -> WebSocketClient {
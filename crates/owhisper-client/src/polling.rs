@@ -1,6 +1,9 @@
 use std::future::Future;
 use std::time::Duration;
 
+use owhisper_interface::batch::Response as BatchResponse;
+
+use crate::adapter::JobStatus;
 use crate::error::Error;
 
 pub struct PollingConfig {
@@ -25,6 +28,11 @@ impl PollingConfig {
         self
     }
 
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
     pub fn with_timeout_error(mut self, timeout_error: impl Into<String>) -> Self {
         self.timeout_error = timeout_error.into();
         self
@@ -37,6 +45,16 @@ pub enum PollingResult<T> {
     Failed(String),
 }
 
+impl From<JobStatus> for PollingResult<BatchResponse> {
+    fn from(status: JobStatus) -> Self {
+        match status {
+            JobStatus::Pending => PollingResult::Continue,
+            JobStatus::Completed(response) => PollingResult::Complete(response),
+            JobStatus::Failed(error) => PollingResult::Failed(error),
+        }
+    }
+}
+
 pub async fn poll_until<T, Fut, F>(poll_fn: F, config: PollingConfig) -> Result<T, Error>
 where
     F: Fn() -> Fut,
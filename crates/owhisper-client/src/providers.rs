@@ -1,7 +1,9 @@
 use crate::adapter::assemblyai;
 use crate::adapter::deepgram;
 use crate::adapter::elevenlabs;
+use crate::adapter::revai;
 use crate::adapter::soniox;
+use crate::adapter::speechmatics;
 use crate::error_detection::ProviderError;
 
 pub fn is_meta_model(model: &str) -> bool {
@@ -20,6 +22,9 @@ pub enum Auth {
     SessionInit {
         header_name: &'static str,
     },
+    QueryParam {
+        name: &'static str,
+    },
 }
 
 impl Auth {
@@ -32,7 +37,14 @@ impl Auth {
                 };
                 Some((name, value))
             }
-            Auth::FirstMessage { .. } | Auth::SessionInit { .. } => None,
+            Auth::FirstMessage { .. } | Auth::SessionInit { .. } | Auth::QueryParam { .. } => None,
+        }
+    }
+
+    pub fn build_query_param(&self, api_key: &str) -> Option<(&'static str, String)> {
+        match self {
+            Auth::QueryParam { name } => Some((name, api_key.to_string())),
+            _ => None,
         }
     }
 
@@ -59,7 +71,7 @@ impl Auth {
                     Err(_) => payload,
                 }
             }
-            Auth::Header { .. } | Auth::SessionInit { .. } => payload,
+            Auth::Header { .. } | Auth::SessionInit { .. } | Auth::QueryParam { .. } => payload,
         }
     }
 }
@@ -80,10 +92,14 @@ pub enum Provider {
     Gladia,
     #[strum(serialize = "elevenlabs")]
     ElevenLabs,
+    #[strum(serialize = "speechmatics")]
+    Speechmatics,
+    #[strum(serialize = "revai")]
+    RevAi,
 }
 
 impl Provider {
-    const ALL: [Provider; 7] = [
+    pub const ALL: [Provider; 9] = [
         Self::Deepgram,
         Self::AssemblyAI,
         Self::Soniox,
@@ -91,6 +107,8 @@ impl Provider {
         Self::OpenAI,
         Self::Gladia,
         Self::ElevenLabs,
+        Self::Speechmatics,
+        Self::RevAi,
     ];
 
     pub fn from_host(host: &str) -> Option<Self> {
@@ -125,6 +143,13 @@ impl Provider {
                 name: "xi-api-key",
                 prefix: None,
             },
+            Self::Speechmatics => Auth::Header {
+                name: "Authorization",
+                prefix: Some("Bearer "),
+            },
+            Self::RevAi => Auth::QueryParam {
+                name: "access_token",
+            },
         }
     }
 
@@ -132,6 +157,10 @@ impl Provider {
         self.auth().build_header(api_key)
     }
 
+    pub fn build_auth_query_param(&self, api_key: &str) -> Option<(&'static str, String)> {
+        self.auth().build_query_param(api_key)
+    }
+
     pub fn default_ws_url(&self) -> String {
         format!("wss://{}{}", self.default_ws_host(), self.ws_path())
     }
@@ -145,6 +174,8 @@ impl Provider {
             Self::OpenAI => "api.openai.com",
             Self::Gladia => "api.gladia.io",
             Self::ElevenLabs => "api.elevenlabs.io",
+            Self::Speechmatics => "asr.api.speechmatics.com",
+            Self::RevAi => "api.rev.ai",
         }
     }
 
@@ -157,6 +188,8 @@ impl Provider {
             Self::OpenAI => "api.openai.com",
             Self::Gladia => "api.gladia.io",
             Self::ElevenLabs => "api.elevenlabs.io",
+            Self::Speechmatics => "eu2.rt.speechmatics.com",
+            Self::RevAi => "api.rev.ai",
         }
     }
 
@@ -169,6 +202,8 @@ impl Provider {
             Self::OpenAI => "/v1/realtime",
             Self::Gladia => "/v2/live",
             Self::ElevenLabs => "/v1/speech-to-text/realtime",
+            Self::Speechmatics => "/v2",
+            Self::RevAi => "/speechtotext/v1/stream",
         }
     }
 
@@ -181,6 +216,8 @@ impl Provider {
             Self::OpenAI => None,
             Self::Gladia => Some("https://api.gladia.io/v2/live"),
             Self::ElevenLabs => Some("https://api.elevenlabs.io/v1"),
+            Self::Speechmatics => Some("https://asr.api.speechmatics.com/v2"),
+            Self::RevAi => Some("https://api.rev.ai/speechtotext/v1"),
         }
     }
 
@@ -193,6 +230,8 @@ impl Provider {
             Self::OpenAI => "https://api.openai.com/v1",
             Self::Gladia => "https://api.gladia.io/v2",
             Self::ElevenLabs => "https://api.elevenlabs.io",
+            Self::Speechmatics => "https://asr.api.speechmatics.com/v2",
+            Self::RevAi => "https://api.rev.ai/speechtotext/v1",
         }
     }
 
@@ -205,6 +244,8 @@ impl Provider {
             Self::OpenAI => "openai.com",
             Self::Gladia => "gladia.io",
             Self::ElevenLabs => "elevenlabs.io",
+            Self::Speechmatics => "speechmatics.com",
+            Self::RevAi => "rev.ai",
         }
     }
 
@@ -235,6 +276,8 @@ impl Provider {
             Self::OpenAI => "OPENAI_API_KEY",
             Self::Gladia => "GLADIA_API_KEY",
             Self::ElevenLabs => "ELEVENLABS_API_KEY",
+            Self::Speechmatics => "SPEECHMATICS_API_KEY",
+            Self::RevAi => "REVAI_API_KEY",
         }
     }
 
@@ -247,6 +290,8 @@ impl Provider {
             Self::OpenAI => "gpt-4o-transcribe",
             Self::Gladia => "solaria-1",
             Self::ElevenLabs => "scribe_v2_realtime",
+            Self::Speechmatics => "enhanced",
+            Self::RevAi => "machine",
         }
     }
 
@@ -267,6 +312,8 @@ impl Provider {
             Self::OpenAI => "whisper-1",
             Self::Gladia => "solaria-1",
             Self::ElevenLabs => "scribe_v2",
+            Self::Speechmatics => "enhanced",
+            Self::RevAi => "machine",
         }
     }
 
@@ -287,6 +334,8 @@ impl Provider {
             Self::OpenAI => &[],
             Self::Gladia => &[],
             Self::ElevenLabs => &["commit"],
+            Self::Speechmatics => &[],
+            Self::RevAi => &[],
         }
     }
 
@@ -315,6 +364,8 @@ impl Provider {
             Self::Soniox => soniox::error::detect_error(data),
             Self::ElevenLabs => elevenlabs::error::detect_error(data),
             Self::AssemblyAI => assemblyai::error::detect_error(data),
+            Self::Speechmatics => speechmatics::error::detect_error(data),
+            Self::RevAi => revai::error::detect_error(data),
             Self::Fireworks | Self::OpenAI | Self::Gladia => None,
         }
     }
@@ -322,4 +373,17 @@ impl Provider {
     pub fn detect_any_error(data: &[u8]) -> Option<ProviderError> {
         Self::ALL.iter().find_map(|p| p.detect_error(data))
     }
+
+    pub fn detect_close_error(&self, code: u16, reason: &str) -> Option<ProviderError> {
+        match self {
+            Self::RevAi => revai::error::detect_close_error(code, reason),
+            _ => None,
+        }
+    }
+
+    pub fn detect_any_close_error(code: u16, reason: &str) -> Option<ProviderError> {
+        Self::ALL
+            .iter()
+            .find_map(|p| p.detect_close_error(code, reason))
+    }
 }
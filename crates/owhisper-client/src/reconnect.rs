@@ -0,0 +1,683 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt};
+
+use hypr_ws_client::client::{ClientRequestBuilder, Message, Utf8Bytes, WebSocketHandle};
+use owhisper_interface::stream::StreamResponse;
+use owhisper_interface::{ControlMessage, MixedMessage};
+
+use crate::RealtimeSttAdapter;
+use crate::live::{ListenClientIO, TransformedInput, websocket_client_with_keep_alive};
+
+/// How a live connection recovers from dropped sockets.
+///
+/// Audio sent within the last `replay_window` is kept so that whatever the
+/// provider had not finalized yet can be sent again on the new socket.
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub replay_window: Duration,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            replay_window: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ReconnectConfig {
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+/// Audio message tagged with how much audio it carries.
+pub(crate) type TimedInput = MixedMessage<(Message, Duration), ControlMessage>;
+
+pub(crate) fn audio_duration(bytes: usize, channels: u8, sample_rate: u32) -> Duration {
+    let frames = bytes / 2 / channels.max(1) as usize;
+    Duration::from_secs_f64(frames as f64 / sample_rate.max(1) as f64)
+}
+
+struct BufferedAudio {
+    start: Duration,
+    end: Duration,
+    message: Message,
+}
+
+/// Recently sent audio, positioned on the session clock.
+pub(crate) struct ReplayBuffer {
+    window: Duration,
+    position: Duration,
+    chunks: VecDeque<BufferedAudio>,
+}
+
+impl ReplayBuffer {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            position: Duration::ZERO,
+            chunks: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, message: Message, duration: Duration) {
+        let start = self.position;
+        self.position += duration;
+
+        if !self.window.is_zero() {
+            self.chunks.push_back(BufferedAudio {
+                start,
+                end: self.position,
+                message,
+            });
+        }
+
+        while let Some(front) = self.chunks.front() {
+            if front.end + self.window >= self.position {
+                break;
+            }
+            self.chunks.pop_front();
+        }
+    }
+
+    /// Buffered audio that ends after `watermark`, together with the session
+    /// time at which it starts.
+    pub(crate) fn since(&self, watermark: f64) -> (f64, Vec<Message>) {
+        let pending: Vec<_> = self
+            .chunks
+            .iter()
+            .filter(|chunk| chunk.end.as_secs_f64() > watermark)
+            .collect();
+
+        let offset = pending
+            .first()
+            .map(|chunk| chunk.start)
+            .unwrap_or(self.position);
+        let messages = pending.iter().map(|chunk| chunk.message.clone()).collect();
+
+        (offset.as_secs_f64(), messages)
+    }
+}
+
+/// End of the last final transcript seen on each channel.
+pub(crate) struct Watermarks {
+    finals: Vec<f64>,
+    replayed: Option<Vec<f64>>,
+}
+
+impl Watermarks {
+    pub(crate) fn new(channels: usize) -> Self {
+        Self {
+            finals: vec![0.0; channels.max(1)],
+            replayed: None,
+        }
+    }
+
+    fn channel(&self, response: &StreamResponse) -> usize {
+        let index = match response {
+            StreamResponse::TranscriptResponse { channel_index, .. } => {
+                channel_index.first().copied().unwrap_or(0)
+            }
+            StreamResponse::SpeechStartedResponse { channel, .. }
+            | StreamResponse::UtteranceEndResponse { channel, .. } => {
+                channel.first().copied().unwrap_or(0) as i32
+            }
+            _ => 0,
+        };
+        (index.max(0) as usize).min(self.finals.len() - 1)
+    }
+
+    pub(crate) fn observe(&mut self, response: &StreamResponse) {
+        if let StreamResponse::TranscriptResponse {
+            start,
+            duration,
+            is_final: true,
+            ..
+        } = response
+        {
+            let channel = self.channel(response);
+            self.finals[channel] = self.finals[channel].max(start + duration);
+        }
+    }
+
+    /// Audio after this point has not been finalized on every channel yet.
+    pub(crate) fn replay_from(&self) -> f64 {
+        self.finals.iter().copied().fold(f64::INFINITY, f64::min)
+    }
+
+    /// Remembers what was already finalized before audio gets replayed.
+    pub(crate) fn mark_replay(&mut self) {
+        self.replayed = Some(self.finals.clone());
+    }
+
+    /// Drops the parts of a response that repeat what was finalized before the
+    /// last replay. Returns `false` if nothing is left.
+    pub(crate) fn trim(&self, response: &mut StreamResponse) -> bool {
+        const EPSILON: f64 = 1e-3;
+
+        let Some(replayed) = &self.replayed else {
+            return true;
+        };
+        let cutoff = replayed[self.channel(response)] + EPSILON;
+
+        match response {
+            StreamResponse::TranscriptResponse {
+                start,
+                duration,
+                channel,
+                ..
+            } => {
+                let end = *start + *duration;
+                if *start >= cutoff {
+                    return true;
+                }
+
+                let mut trimmed = false;
+                for alt in &mut channel.alternatives {
+                    let before = alt.words.len();
+                    alt.words.retain(|word| word.end > cutoff);
+                    if alt.words.len() != before {
+                        trimmed = true;
+                        alt.transcript = alt
+                            .words
+                            .iter()
+                            .map(|word| word.punctuated_word.as_ref().unwrap_or(&word.word))
+                            .cloned()
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                    }
+                }
+
+                let first_word = channel
+                    .alternatives
+                    .first()
+                    .and_then(|alt| alt.words.first());
+                match first_word {
+                    Some(word) => {
+                        if trimmed {
+                            *start = word.start;
+                            *duration = end - word.start;
+                        }
+                        true
+                    }
+                    None => end > cutoff && !trimmed,
+                }
+            }
+            StreamResponse::SpeechStartedResponse { timestamp, .. } => *timestamp >= cutoff,
+            StreamResponse::UtteranceEndResponse { last_word_end, .. } => *last_word_end > cutoff,
+            _ => true,
+        }
+    }
+}
+
+/// Finalizes whichever socket is currently live.
+#[derive(Clone)]
+pub struct LiveHandle {
+    current: Arc<Mutex<WebSocketHandle>>,
+    finalized: Arc<AtomicBool>,
+}
+
+impl LiveHandle {
+    fn new(handle: WebSocketHandle) -> Self {
+        Self {
+            current: Arc::new(Mutex::new(handle)),
+            finalized: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn replace(&self, handle: WebSocketHandle) {
+        *self.current.lock().unwrap() = handle;
+    }
+
+    fn is_finalized(&self) -> bool {
+        self.finalized.load(Ordering::SeqCst)
+    }
+
+    pub(crate) async fn finalize_with_text(&self, text: Utf8Bytes) {
+        self.finalized.store(true, Ordering::SeqCst);
+        let handle = self.current.lock().unwrap().clone();
+        handle.finalize_with_text(text).await
+    }
+}
+
+type RawStream = Pin<Box<dyn Stream<Item = Result<String, hypr_ws_client::Error>> + Send>>;
+
+pub(crate) type ResponseStream =
+    tokio_stream::wrappers::ReceiverStream<Result<StreamResponse, hypr_ws_client::Error>>;
+
+struct Outbound {
+    replay: ReplayBuffer,
+    tx: Option<tokio::sync::mpsc::UnboundedSender<TransformedInput>>,
+    input_closed: bool,
+}
+
+/// One provider socket that is reopened when it drops, with the unfinalized
+/// audio replayed and timestamps shifted back onto the session clock.
+pub(crate) struct LiveSocket<A: RealtimeSttAdapter> {
+    pub(crate) adapter: A,
+    pub(crate) request: ClientRequestBuilder,
+    pub(crate) initial_message: Option<Message>,
+    pub(crate) reconnect: Option<ReconnectConfig>,
+    pub(crate) channels: usize,
+}
+
+impl<A: RealtimeSttAdapter> LiveSocket<A> {
+    async fn connect(
+        &self,
+    ) -> Result<
+        (
+            RawStream,
+            WebSocketHandle,
+            tokio::sync::mpsc::UnboundedSender<TransformedInput>,
+        ),
+        hypr_ws_client::Error,
+    > {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let ws = websocket_client_with_keep_alive(&self.request, &self.adapter);
+        let (raw, handle) = ws
+            .from_audio::<ListenClientIO, _>(
+                self.initial_message.clone(),
+                tokio_stream::wrappers::UnboundedReceiverStream::new(rx),
+            )
+            .await?;
+        Ok((Box::pin(raw), handle, tx))
+    }
+
+    pub(crate) async fn open(
+        self,
+        input: impl Stream<Item = TimedInput> + Send + Unpin + 'static,
+    ) -> Result<(ResponseStream, LiveHandle), hypr_ws_client::Error> {
+        let (raw, handle, tx) = self.connect().await?;
+        let handle = LiveHandle::new(handle);
+
+        let window = self
+            .reconnect
+            .as_ref()
+            .map(|config| config.replay_window)
+            .unwrap_or_default();
+        let outbound = Arc::new(Mutex::new(Outbound {
+            replay: ReplayBuffer::new(window),
+            tx: Some(tx),
+            input_closed: false,
+        }));
+
+        tokio::spawn(forward_input(input, outbound.clone()));
+
+        let (out_tx, out_rx) = tokio::sync::mpsc::channel(64);
+        tokio::spawn(self.supervise(raw, outbound, handle.clone(), out_tx));
+
+        Ok((tokio_stream::wrappers::ReceiverStream::new(out_rx), handle))
+    }
+
+    async fn supervise(
+        self,
+        mut raw: RawStream,
+        outbound: Arc<Mutex<Outbound>>,
+        handle: LiveHandle,
+        out_tx: tokio::sync::mpsc::Sender<Result<StreamResponse, hypr_ws_client::Error>>,
+    ) {
+        let mut watermarks = Watermarks::new(self.channels);
+        let mut offset = 0.0;
+
+        loop {
+            while let Some(result) = raw.next().await {
+                match result {
+                    Ok(text) => {
+                        for mut response in self.adapter.parse_response(&text) {
+                            response.apply_offset(offset);
+                            if !watermarks.trim(&mut response) {
+                                continue;
+                            }
+                            watermarks.observe(&response);
+                            if out_tx.send(Ok(response)).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) if self.reconnect.is_none() || e.is_auth_error() => {
+                        let _ = out_tx.send(Err(e)).await;
+                        return;
+                    }
+                    Err(e) => {
+                        tracing::warn!("listen_ws_dropped: {:?}", e);
+                        break;
+                    }
+                }
+            }
+
+            let Some(config) = &self.reconnect else {
+                return;
+            };
+            outbound.lock().unwrap().tx = None;
+
+            match self
+                .reconnect(config, &outbound, &handle, &watermarks)
+                .await
+            {
+                Ok(Some((next, replay_offset))) => {
+                    raw = next;
+                    offset = replay_offset;
+                    watermarks.mark_replay();
+                }
+                Ok(None) => return,
+                Err(e) => {
+                    let _ = out_tx.send(Err(e)).await;
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn reconnect(
+        &self,
+        config: &ReconnectConfig,
+        outbound: &Mutex<Outbound>,
+        handle: &LiveHandle,
+        watermarks: &Watermarks,
+    ) -> Result<Option<(RawStream, f64)>, hypr_ws_client::Error> {
+        let mut last_error = None;
+
+        for attempt in 0..config.max_attempts {
+            if handle.is_finalized() || outbound.lock().unwrap().input_closed {
+                return Ok(None);
+            }
+
+            tokio::time::sleep(config.backoff(attempt)).await;
+            tracing::info!(attempt, "listen_ws_reconnecting");
+
+            match self.connect().await {
+                Ok((raw, next_handle, tx)) => {
+                    let mut outbound = outbound.lock().unwrap();
+                    let (offset, replay) = outbound.replay.since(watermarks.replay_from());
+                    for message in replay {
+                        let _ = tx.send(MixedMessage::Audio(message));
+                    }
+                    if !outbound.input_closed {
+                        outbound.tx = Some(tx);
+                    }
+                    handle.replace(next_handle);
+                    return Ok(Some((raw, offset)));
+                }
+                Err(e) if e.is_auth_error() => return Err(e),
+                Err(e) => {
+                    tracing::warn!(attempt, "listen_ws_reconnect_failed: {:?}", e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+}
+
+async fn forward_input(
+    mut input: impl Stream<Item = TimedInput> + Send + Unpin + 'static,
+    outbound: Arc<Mutex<Outbound>>,
+) {
+    while let Some(msg) = input.next().await {
+        let mut outbound = outbound.lock().unwrap();
+        let msg = match msg {
+            MixedMessage::Audio((message, duration)) => {
+                outbound.replay.push(message.clone(), duration);
+                MixedMessage::Audio(message)
+            }
+            MixedMessage::Control(control) => MixedMessage::Control(control),
+        };
+        if let Some(tx) = &outbound.tx {
+            let _ = tx.send(msg);
+        }
+    }
+
+    let mut outbound = outbound.lock().unwrap();
+    outbound.input_closed = true;
+    outbound.tx = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::parsing::WordBuilder;
+    use owhisper_interface::stream::{Alternatives, Channel, Metadata};
+
+    fn audio(n: u8) -> Message {
+        Message::Binary(vec![n].into())
+    }
+
+    fn transcript(words: &[(&str, f64, f64)], is_final: bool, channel: i32) -> StreamResponse {
+        let words: Vec<_> = words
+            .iter()
+            .map(|(word, start, end)| WordBuilder::new(*word).start(*start).end(*end).build())
+            .collect();
+        let start = words.first().map(|w| w.start).unwrap_or(0.0);
+        let end = words.last().map(|w| w.end).unwrap_or(0.0);
+
+        StreamResponse::TranscriptResponse {
+            start,
+            duration: end - start,
+            is_final,
+            speech_final: is_final,
+            from_finalize: false,
+            channel: Channel {
+                alternatives: vec![Alternatives {
+                    transcript: words
+                        .iter()
+                        .map(|w| w.word.clone())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    words,
+                    confidence: 1.0,
+                    languages: vec![],
+                    translation: None,
+                }],
+            },
+            metadata: Metadata::default(),
+            channel_index: vec![channel, 2],
+        }
+    }
+
+    // Serves one socket per connection. The first sends a final transcript for
+    // what it has heard after four chunks and then drops without a close frame.
+    async fn dropping_server() -> (
+        std::net::SocketAddr,
+        tokio::sync::mpsc::UnboundedReceiver<Vec<Message>>,
+    ) {
+        use futures_util::SinkExt;
+        use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (received_tx, received_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            for connection in 0.. {
+                let Ok((stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let received_tx = received_tx.clone();
+
+                tokio::spawn(async move {
+                    let mut audio = Vec::new();
+                    while let Some(Ok(msg)) = ws.next().await {
+                        let WsMessage::Binary(data) = msg else {
+                            continue;
+                        };
+                        audio.push(Message::Binary(data));
+                        let _ = received_tx.send(audio.clone());
+
+                        let response = match (connection, audio.len()) {
+                            (0, 4) => transcript(&[("one", 0.0, 0.5), ("two", 0.5, 1.8)], true, 0),
+                            // Repeats "two" from the replayed audio, which starts at 1s.
+                            (1, 3) => {
+                                transcript(&[("two", 0.0, 0.8), ("three", 0.9, 1.5)], true, 0)
+                            }
+                            _ => continue,
+                        };
+                        let text = serde_json::to_string(&response).unwrap();
+                        ws.send(WsMessage::Text(text.into())).await.unwrap();
+
+                        if connection == 0 {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        (addr, received_rx)
+    }
+
+    #[tokio::test]
+    async fn reconnects_and_replays_unfinalized_audio() {
+        let (addr, mut received) = dropping_server().await;
+
+        let socket = LiveSocket {
+            adapter: crate::DeepgramAdapter,
+            request: ClientRequestBuilder::new(format!("ws://{addr}").parse().unwrap()),
+            initial_message: None,
+            reconnect: Some(ReconnectConfig {
+                initial_backoff: Duration::from_millis(10),
+                ..Default::default()
+            }),
+            channels: 1,
+        };
+
+        let (input_tx, input_rx) = tokio::sync::mpsc::unbounded_channel::<TimedInput>();
+        let send = |n: u8| {
+            input_tx
+                .send(MixedMessage::Audio((audio(n), Duration::from_secs(1))))
+                .unwrap()
+        };
+        let (mut responses, _handle) = socket
+            .open(tokio_stream::wrappers::UnboundedReceiverStream::new(
+                input_rx,
+            ))
+            .await
+            .unwrap();
+
+        for n in 0..4 {
+            send(n);
+        }
+
+        let first = responses.next().await.unwrap().unwrap();
+        assert_eq!(first.text(), Some("one two"));
+
+        // Only "two" was finalized past 1s, so everything from [1s, 2s) on is sent again.
+        let second = responses.next().await.unwrap().unwrap();
+        assert_eq!(second.text(), Some("three"));
+        let StreamResponse::TranscriptResponse { start, .. } = second else {
+            unreachable!()
+        };
+        assert!((start - 1.9).abs() < 1e-9);
+
+        send(4);
+        let replayed = loop {
+            let chunks = received.recv().await.unwrap();
+            if chunks.len() == 4 && chunks[0] == audio(1) {
+                break chunks;
+            }
+        };
+        assert_eq!(replayed, [audio(1), audio(2), audio(3), audio(4)]);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let config = ReconnectConfig::default();
+        let delays: Vec<_> = (0..7).map(|n| config.backoff(n).as_millis()).collect();
+        assert_eq!(delays, [500, 1000, 2000, 4000, 8000, 8000, 8000]);
+    }
+
+    #[test]
+    fn audio_duration_counts_frames() {
+        assert_eq!(audio_duration(32000, 1, 16000), Duration::from_secs(1));
+        assert_eq!(audio_duration(32000, 2, 16000), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn replay_buffer_keeps_window_and_unfinalized_tail() {
+        let mut buffer = ReplayBuffer::new(Duration::from_secs(2));
+        for n in 0..5 {
+            buffer.push(audio(n), Duration::from_secs(1));
+        }
+
+        // [0,1) and [1,2) fell out of the window.
+        let (offset, messages) = buffer.since(0.0);
+        assert_eq!(offset, 2.0);
+        assert_eq!(messages, [audio(2), audio(3), audio(4)]);
+
+        let (offset, messages) = buffer.since(3.5);
+        assert_eq!(offset, 3.0);
+        assert_eq!(messages, [audio(3), audio(4)]);
+
+        let (offset, messages) = buffer.since(5.0);
+        assert_eq!(offset, 5.0);
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn disabled_buffer_only_tracks_position() {
+        let mut buffer = ReplayBuffer::new(Duration::ZERO);
+        buffer.push(audio(0), Duration::from_secs(1));
+        assert_eq!(buffer.since(0.0), (1.0, vec![]));
+    }
+
+    #[test]
+    fn watermark_is_the_least_finalized_channel() {
+        let mut watermarks = Watermarks::new(2);
+        watermarks.observe(&transcript(&[("a", 0.0, 4.0)], true, 0));
+        watermarks.observe(&transcript(&[("b", 0.0, 9.0)], false, 1));
+        assert_eq!(watermarks.replay_from(), 0.0);
+
+        watermarks.observe(&transcript(&[("c", 1.0, 2.5)], true, 1));
+        assert_eq!(watermarks.replay_from(), 2.5);
+    }
+
+    #[test]
+    fn trims_words_finalized_before_replay() {
+        let mut watermarks = Watermarks::new(1);
+        watermarks.observe(&transcript(
+            &[("hello", 1.0, 1.5), ("world", 1.5, 2.0)],
+            true,
+            0,
+        ));
+
+        let mut repeated = transcript(&[("world", 1.5, 2.0)], true, 0);
+        assert!(watermarks.trim(&mut repeated), "no replay yet");
+
+        watermarks.mark_replay();
+        assert!(!watermarks.trim(&mut repeated));
+
+        let mut overlapping = transcript(&[("world", 1.5, 2.0), ("again", 2.1, 2.6)], true, 0);
+        assert!(watermarks.trim(&mut overlapping));
+        assert_eq!(overlapping.text(), Some("again"));
+        let StreamResponse::TranscriptResponse {
+            start, duration, ..
+        } = overlapping
+        else {
+            unreachable!()
+        };
+        assert_eq!(start, 2.1);
+        assert!((duration - 0.5).abs() < 1e-9);
+
+        let mut later = transcript(&[("new", 3.0, 3.4)], false, 0);
+        assert!(watermarks.trim(&mut later));
+        assert_eq!(later.text(), Some("new"));
+    }
+}
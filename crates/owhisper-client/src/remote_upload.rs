@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use reqwest_middleware::ClientWithMiddleware;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::adapter::http::ensure_success;
+use crate::error::Error;
+use crate::http_client::create_client;
+use crate::upload::{UploadTarget, crc32_part_headers};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadKey {
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedUpload {
+    pub upload_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartUrlsRequest {
+    pub key: String,
+    pub upload_id: String,
+    pub num_parts: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartUrls {
+    pub urls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompleteUploadRequest {
+    pub key: String,
+    pub upload_id: String,
+    pub etags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadUrl {
+    pub url: String,
+}
+
+type AccessToken = Arc<dyn Fn() -> Result<String, Error> + Send + Sync>;
+
+/// Storage behind the transcription proxy's `/upload` routes. The proxy
+/// holds the storage credentials and scopes keys to the signed-in user, so
+/// the desktop only ever sees presigned URLs.
+#[derive(Clone)]
+pub struct RemoteUploadTarget {
+    client: ClientWithMiddleware,
+    api_base: String,
+    access_token: AccessToken,
+}
+
+impl RemoteUploadTarget {
+    /// `access_token` is called before every request, so a session that is
+    /// refreshed while a long upload runs keeps working.
+    pub fn new(
+        api_base: impl Into<String>,
+        access_token: impl Fn() -> Result<String, Error> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            client: create_client(),
+            api_base: api_base.into().trim_end_matches('/').to_string(),
+            access_token: Arc::new(access_token),
+        }
+    }
+
+    async fn post<B: Serialize>(&self, path: &str, body: &B) -> Result<reqwest::Response, Error> {
+        let response = self
+            .client
+            .post(format!("{}/upload{}", self.api_base, path))
+            .bearer_auth((self.access_token)()?)
+            .json(body)
+            .send()
+            .await?;
+
+        ensure_success(response).await
+    }
+
+    async fn post_json<B: Serialize, R: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<R, Error> {
+        Ok(self.post(path, body).await?.json().await?)
+    }
+}
+
+impl UploadTarget for RemoteUploadTarget {
+    async fn create_upload(&self, key: &str) -> Result<String, Error> {
+        let created: CreatedUpload = self
+            .post_json(
+                "",
+                &UploadKey {
+                    key: key.to_string(),
+                },
+            )
+            .await?;
+        Ok(created.upload_id)
+    }
+
+    async fn part_urls(
+        &self,
+        key: &str,
+        upload_id: &str,
+        num_parts: usize,
+    ) -> Result<Vec<String>, Error> {
+        let parts: PartUrls = self
+            .post_json(
+                "/parts",
+                &PartUrlsRequest {
+                    key: key.to_string(),
+                    upload_id: upload_id.to_string(),
+                    num_parts,
+                },
+            )
+            .await?;
+        Ok(parts.urls)
+    }
+
+    // The proxy signs part URLs the same way the S3 target does.
+    fn part_headers(&self, body: &[u8]) -> Vec<(&'static str, String)> {
+        crc32_part_headers(body)
+    }
+
+    async fn complete_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        etags: Vec<String>,
+    ) -> Result<(), Error> {
+        self.post(
+            "/complete",
+            &CompleteUploadRequest {
+                key: key.to_string(),
+                upload_id: upload_id.to_string(),
+                etags,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn download_url(&self, key: &str) -> Result<String, Error> {
+        let download: DownloadUrl = self
+            .post_json(
+                "/download",
+                &UploadKey {
+                    key: key.to_string(),
+                },
+            )
+            .await?;
+        Ok(download.url)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        self.post(
+            "/delete",
+            &UploadKey {
+                key: key.to_string(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use wiremock::matchers::{body_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_remote_upload_target_round_trip() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/stt/upload"))
+            .and(header("authorization", "Bearer token"))
+            .and(body_json(serde_json::json!({ "key": "audio.wav" })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "upload_id": "u1" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/stt/upload/parts"))
+            .and(body_json(serde_json::json!({
+                "key": "audio.wav",
+                "upload_id": "u1",
+                "num_parts": 2,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "urls": ["https://s3/part1", "https://s3/part2"] }),
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/stt/upload/complete"))
+            .and(body_json(serde_json::json!({
+                "key": "audio.wav",
+                "upload_id": "u1",
+                "etags": ["e1", "e2"],
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/stt/upload/download"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "url": "https://s3/audio.wav" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/stt/upload/delete"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let target =
+            RemoteUploadTarget::new(format!("{}/stt/", server.uri()), || Ok("token".into()));
+
+        assert_eq!(target.create_upload("audio.wav").await.unwrap(), "u1");
+        assert_eq!(
+            target.part_urls("audio.wav", "u1", 2).await.unwrap(),
+            vec!["https://s3/part1", "https://s3/part2"]
+        );
+        target
+            .complete_upload("audio.wav", "u1", vec!["e1".into(), "e2".into()])
+            .await
+            .unwrap();
+        assert_eq!(
+            target.download_url("audio.wav").await.unwrap(),
+            "https://s3/audio.wav"
+        );
+        assert!(matches!(
+            target.delete("audio.wav").await,
+            Err(Error::UnexpectedStatus { status, .. }) if status == 404
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remote_upload_target_fetches_token_per_request() {
+        let server = MockServer::start().await;
+
+        for token in ["first", "second"] {
+            Mock::given(method("POST"))
+                .and(path("/upload/delete"))
+                .and(header(
+                    "authorization",
+                    format!("Bearer {}", token).as_str(),
+                ))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let target = RemoteUploadTarget::new(server.uri(), {
+            let calls = calls.clone();
+            move || match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok("first".into()),
+                _ => Ok("second".into()),
+            }
+        });
+
+        target.delete("audio.wav").await.unwrap();
+        target.delete("audio.wav").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
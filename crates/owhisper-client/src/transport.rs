@@ -0,0 +1,222 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use hypr_audio_opus::{FRAME_DURATION_MS, OpusEncoder};
+use hypr_ws_client::client::Message;
+use owhisper_interface::{ControlMessage, MixedMessage};
+
+use crate::reconnect::{TimedInput, audio_duration};
+use crate::{AudioEncoding, RealtimeSttAdapter};
+
+const OPUS_FRAME_DURATION: Duration = Duration::from_millis(FRAME_DURATION_MS as u64);
+
+// Opus is only negotiated with our own proxy, which decodes it back to PCM
+// before it reaches the provider. Adapters that wrap audio in JSON can't carry
+// the packets, so they stay on PCM.
+pub(crate) fn negotiate_encoding<A: RealtimeSttAdapter>(
+    requested: AudioEncoding,
+    api_base: &str,
+    adapter: &A,
+    sample_rate: u32,
+) -> AudioEncoding {
+    let opus = requested == AudioEncoding::Opus
+        && crate::is_hyprnote_proxy(api_base)
+        && matches!(adapter.audio_to_message(Bytes::new()), Message::Binary(_))
+        && hypr_audio_opus::SUPPORTED_SAMPLE_RATES.contains(&sample_rate);
+
+    if opus {
+        AudioEncoding::Opus
+    } else {
+        AudioEncoding::Linear16
+    }
+}
+
+pub(crate) fn apply_encoding_param(url: &mut url::Url, encoding: AudioEncoding) {
+    if encoding != AudioEncoding::Opus {
+        return;
+    }
+
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(key, _)| key != "encoding")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("encoding", &encoding.to_string());
+}
+
+/// Turns outgoing PCM chunks into socket messages, compressing them into Opus
+/// packets when the session negotiated it.
+pub(crate) struct AudioTransport {
+    encoder: Option<OpusEncoder>,
+    channels: u8,
+    sample_rate: u32,
+}
+
+impl AudioTransport {
+    pub(crate) fn new(encoding: AudioEncoding, sample_rate: u32, channels: u8) -> Self {
+        let encoder = match encoding {
+            AudioEncoding::Opus => OpusEncoder::new(sample_rate, channels)
+                .map_err(|e| tracing::error!(error = %e, "opus_encoder_init_failed"))
+                .ok(),
+            _ => None,
+        };
+
+        Self {
+            encoder,
+            channels,
+            sample_rate,
+        }
+    }
+
+    pub(crate) fn timed_inputs<A: RealtimeSttAdapter>(
+        &mut self,
+        adapter: &A,
+        input: MixedMessage<Bytes, ControlMessage>,
+    ) -> Vec<TimedInput> {
+        match input {
+            MixedMessage::Audio(pcm) => self
+                .frames(pcm)
+                .into_iter()
+                .map(|(frame, duration)| {
+                    MixedMessage::Audio((adapter.audio_to_message(frame), duration))
+                })
+                .collect(),
+            // Don't hold back the tail of the audio behind a finalize.
+            MixedMessage::Control(control) => self
+                .flush()
+                .map(|(frame, duration)| {
+                    MixedMessage::Audio((adapter.audio_to_message(frame), duration))
+                })
+                .into_iter()
+                .chain(std::iter::once(MixedMessage::Control(control)))
+                .collect(),
+        }
+    }
+
+    fn frames(&mut self, pcm: Bytes) -> Vec<(Bytes, Duration)> {
+        let Some(encoder) = self.encoder.as_mut() else {
+            let duration = audio_duration(pcm.len(), self.channels, self.sample_rate);
+            return vec![(pcm, duration)];
+        };
+
+        match encoder.encode(&pcm) {
+            Ok(packets) => packets
+                .into_iter()
+                .map(|packet| (packet, OPUS_FRAME_DURATION))
+                .collect(),
+            Err(e) => {
+                tracing::warn!(error = %e, "opus_encode_failed");
+                vec![]
+            }
+        }
+    }
+
+    fn flush(&mut self) -> Option<(Bytes, Duration)> {
+        let encoder = self.encoder.as_mut()?;
+
+        match encoder.flush() {
+            Ok(packet) => packet.map(|packet| (packet, OPUS_FRAME_DURATION)),
+            Err(e) => {
+                tracing::warn!(error = %e, "opus_flush_failed");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DeepgramAdapter, OpenAIAdapter};
+
+    const PROXY: &str = "https://api.hyprnote.com/stt";
+
+    #[test]
+    fn test_negotiate_encoding() {
+        let opus = AudioEncoding::Opus;
+        let deepgram = DeepgramAdapter;
+
+        assert_eq!(
+            negotiate_encoding(opus, PROXY, &deepgram, 16000),
+            AudioEncoding::Opus
+        );
+        assert_eq!(
+            negotiate_encoding(AudioEncoding::Linear16, PROXY, &deepgram, 16000),
+            AudioEncoding::Linear16
+        );
+        assert_eq!(
+            negotiate_encoding(opus, "https://api.deepgram.com/v1", &deepgram, 16000),
+            AudioEncoding::Linear16
+        );
+        assert_eq!(
+            negotiate_encoding(opus, PROXY, &OpenAIAdapter, 16000),
+            AudioEncoding::Linear16
+        );
+        assert_eq!(
+            negotiate_encoding(opus, PROXY, &deepgram, 44100),
+            AudioEncoding::Linear16
+        );
+    }
+
+    #[test]
+    fn test_apply_encoding_param() {
+        let mut url: url::Url = "wss://api.hyprnote.com/stt/listen?model=nova-3&encoding=linear16"
+            .parse()
+            .unwrap();
+
+        apply_encoding_param(&mut url, AudioEncoding::Linear16);
+        assert_eq!(url.query(), Some("model=nova-3&encoding=linear16"));
+
+        apply_encoding_param(&mut url, AudioEncoding::Opus);
+        assert_eq!(url.query(), Some("model=nova-3&encoding=opus"));
+    }
+
+    #[test]
+    fn test_opus_frames() {
+        let adapter = DeepgramAdapter;
+        let mut transport = AudioTransport::new(AudioEncoding::Opus, 16000, 1);
+
+        // 50ms of silence: two full 20ms frames and a remainder.
+        let pcm = Bytes::from(vec![0u8; 800 * 2]);
+        let inputs = transport.timed_inputs(&adapter, MixedMessage::Audio(pcm));
+        assert_eq!(inputs.len(), 2);
+        for input in &inputs {
+            assert!(matches!(
+                input,
+                MixedMessage::Audio((Message::Binary(_), d)) if *d == OPUS_FRAME_DURATION
+            ));
+        }
+
+        let inputs =
+            transport.timed_inputs(&adapter, MixedMessage::Control(ControlMessage::Finalize));
+        assert!(matches!(
+            inputs[..],
+            [
+                MixedMessage::Audio(_),
+                MixedMessage::Control(ControlMessage::Finalize)
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_pcm_frames_pass_through() {
+        let adapter = DeepgramAdapter;
+        let mut transport = AudioTransport::new(AudioEncoding::Linear16, 16000, 2);
+
+        let pcm = Bytes::from(vec![0u8; 640 * 2 * 2]);
+        let inputs = transport.timed_inputs(&adapter, MixedMessage::Audio(pcm.clone()));
+        assert!(matches!(
+            &inputs[..],
+            [MixedMessage::Audio((Message::Binary(data), d))]
+                if *data == pcm && *d == Duration::from_millis(40)
+        ));
+
+        let inputs =
+            transport.timed_inputs(&adapter, MixedMessage::Control(ControlMessage::KeepAlive));
+        assert_eq!(inputs.len(), 1);
+    }
+}
@@ -0,0 +1,249 @@
+use std::future::Future;
+use std::path::Path;
+
+use reqwest_middleware::ClientWithMiddleware;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::adapter::http::ensure_success;
+use crate::error::Error;
+
+// S3 requires every part but the last to be at least 5 MiB.
+const MIN_PART_SIZE: u64 = 5 * 1024 * 1024;
+pub const DEFAULT_PART_SIZE: u64 = 64 * 1024 * 1024;
+
+/// S3-compatible storage that hands out presigned URLs, so the desktop can
+/// upload without holding storage credentials.
+pub trait UploadTarget: Send + Sync {
+    /// Starts a multipart upload to `key` and returns its upload id.
+    fn create_upload(&self, key: &str) -> impl Future<Output = Result<String, Error>> + Send;
+
+    /// Presigned `PUT` URLs for parts `1..=num_parts`.
+    fn part_urls(
+        &self,
+        key: &str,
+        upload_id: &str,
+        num_parts: usize,
+    ) -> impl Future<Output = Result<Vec<String>, Error>> + Send;
+
+    /// Extra headers a part upload must carry, e.g. a checksum the URL was
+    /// signed with.
+    fn part_headers(&self, _body: &[u8]) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    /// Completes the upload from the parts' ETags, in part order.
+    fn complete_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        etags: Vec<String>,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// A presigned `GET` URL providers can fetch the object from.
+    fn download_url(&self, key: &str) -> impl Future<Output = Result<String, Error>> + Send;
+
+    fn delete(&self, key: &str) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+/// Progress of a multipart upload. Serializable so an upload interrupted by
+/// an app restart picks up from the first missing part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultipartUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub file_size: u64,
+    pub part_size: u64,
+    /// ETag of each part, `None` until it is uploaded.
+    pub etags: Vec<Option<String>>,
+    pub completed: bool,
+}
+
+impl MultipartUpload {
+    pub async fn create<T: UploadTarget>(
+        target: &T,
+        key: impl Into<String>,
+        file_size: u64,
+        part_size: u64,
+    ) -> Result<Self, Error> {
+        let key = key.into();
+        let part_size = part_size.max(MIN_PART_SIZE);
+        let num_parts = file_size.div_ceil(part_size).max(1) as usize;
+        let upload_id = target.create_upload(&key).await?;
+
+        Ok(Self {
+            key,
+            upload_id,
+            file_size,
+            part_size,
+            etags: vec![None; num_parts],
+            completed: false,
+        })
+    }
+
+    pub fn num_parts(&self) -> usize {
+        self.etags.len()
+    }
+
+    pub fn uploaded_bytes(&self) -> u64 {
+        (0..self.num_parts())
+            .filter(|&i| self.etags[i].is_some())
+            .map(|i| self.part_range(i).1)
+            .sum()
+    }
+
+    // Offset and length of the zero-based part `index`.
+    fn part_range(&self, index: usize) -> (u64, u64) {
+        let start = index as u64 * self.part_size;
+        (start, self.part_size.min(self.file_size - start))
+    }
+
+    /// Uploads the parts still missing and completes the upload.
+    /// `on_progress` runs after every part, which is where callers persist
+    /// the state.
+    pub async fn resume<T: UploadTarget>(
+        &mut self,
+        client: &ClientWithMiddleware,
+        target: &T,
+        file_path: &Path,
+        mut on_progress: impl FnMut(&Self),
+    ) -> Result<(), Error> {
+        if self.completed {
+            return Ok(());
+        }
+
+        let mut file = tokio::fs::File::open(file_path)
+            .await
+            .map_err(|e| Error::AudioProcessing(format!("failed to open file: {}", e)))?;
+        let file_size = file
+            .metadata()
+            .await
+            .map_err(|e| Error::AudioProcessing(format!("failed to read file: {}", e)))?
+            .len();
+        if file_size != self.file_size {
+            return Err(Error::Storage(format!(
+                "{} changed since the upload started",
+                file_path.display()
+            )));
+        }
+
+        // Presigned URLs expire, so they are requested again on every resume.
+        let urls = target
+            .part_urls(&self.key, &self.upload_id, self.num_parts())
+            .await?;
+        if urls.len() != self.num_parts() {
+            return Err(Error::Storage(format!(
+                "expected {} part URLs, got {}",
+                self.num_parts(),
+                urls.len()
+            )));
+        }
+
+        for (index, url) in urls.iter().enumerate() {
+            if self.etags[index].is_some() {
+                continue;
+            }
+
+            let (start, len) = self.part_range(index);
+            let mut body = vec![0; len as usize];
+            let read = async {
+                file.seek(std::io::SeekFrom::Start(start)).await?;
+                file.read_exact(&mut body).await
+            };
+            read.await
+                .map_err(|e| Error::AudioProcessing(format!("failed to read file: {}", e)))?;
+
+            let mut request = client.put(url).header(reqwest::header::CONTENT_LENGTH, len);
+            for (name, value) in target.part_headers(&body) {
+                request = request.header(name, value);
+            }
+
+            let response = ensure_success(request.body(body).send().await?).await?;
+            let etag = response
+                .headers()
+                .get(reqwest::header::ETAG)
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| Error::Storage(format!("part {} has no ETag", index + 1)))?;
+
+            self.etags[index] = Some(etag.to_string());
+            on_progress(self);
+        }
+
+        let etags = self.etags.iter().flatten().cloned().collect();
+        target
+            .complete_upload(&self.key, &self.upload_id, etags)
+            .await?;
+        self.completed = true;
+        on_progress(self);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "s3")]
+impl UploadTarget for hypr_s3::UserClient<'_> {
+    async fn create_upload(&self, key: &str) -> Result<String, Error> {
+        self.create_multipart_upload(key)
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn part_urls(
+        &self,
+        key: &str,
+        upload_id: &str,
+        num_parts: usize,
+    ) -> Result<Vec<String>, Error> {
+        self.presigned_url_for_multipart_upload(key, upload_id, num_parts)
+            .await
+            .map_err(storage_error)
+    }
+
+    // Part URLs are signed for a CRC32 checksum.
+    fn part_headers(&self, body: &[u8]) -> Vec<(&'static str, String)> {
+        crc32_part_headers(body)
+    }
+
+    async fn complete_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        etags: Vec<String>,
+    ) -> Result<(), Error> {
+        self.complete_multipart_upload(key, upload_id, etags)
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn download_url(&self, key: &str) -> Result<String, Error> {
+        self.presigned_url_for_download(key)
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        hypr_s3::UserClient::delete(self, key)
+            .await
+            .map_err(storage_error)
+    }
+}
+
+/// Headers for a part URL signed with a CRC32 checksum, as
+/// `hypr_s3::UserClient` signs them.
+pub(crate) fn crc32_part_headers(body: &[u8]) -> Vec<(&'static str, String)> {
+    use base64::Engine as _;
+
+    let checksum = crc32fast::hash(body).to_be_bytes();
+    vec![
+        ("x-amz-checksum-algorithm", "CRC32".to_string()),
+        (
+            "x-amz-checksum-crc32",
+            base64::engine::general_purpose::STANDARD.encode(checksum),
+        ),
+    ]
+}
+
+#[cfg(feature = "s3")]
+fn storage_error(error: hypr_s3::ApiError) -> Error {
+    Error::Storage(error.to_string())
+}
@@ -31,6 +31,8 @@ common_derives! {
         WhisperCpp(WhisperCppModelConfig),
         #[serde(rename = "moonshine")]
         Moonshine(MoonshineModelConfig),
+        #[serde(rename = "replay")]
+        Replay(ReplayModelConfig),
     }
 }
 
@@ -41,6 +43,7 @@ impl ModelConfig {
             ModelConfig::Deepgram(config) => &config.id,
             ModelConfig::WhisperCpp(config) => &config.id,
             ModelConfig::Moonshine(config) => &config.id,
+            ModelConfig::Replay(config) => &config.id,
         }
     }
 }
@@ -115,6 +118,18 @@ common_derives! {
     }
 }
 
+common_derives! {
+    pub struct ReplayModelConfig {
+        pub id: String,
+        /// A `.jsonl` provider recording or a `.json` transcript with word timings.
+        pub path: String,
+        /// Provider the recording was captured from. Defaults to Deepgram.
+        pub provider: Option<String>,
+        /// Playback rate relative to real time. Defaults to 1.0.
+        pub speed: Option<f64>,
+    }
+}
+
 common_derives! {
     pub enum MoonshineModelSize {
         #[serde(rename = "tiny")]
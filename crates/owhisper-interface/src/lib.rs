@@ -141,6 +141,14 @@ common_derives! {
         pub keywords: Vec<String>,
         #[serde(default)]
         pub custom_query: Option<std::collections::HashMap<String, String>>,
+        #[serde(default)]
+        pub translation: Option<TranslationParams>,
+    }
+}
+
+common_derives! {
+    pub struct TranslationParams {
+        pub target_language: hypr_language::Language,
     }
 }
 
@@ -153,6 +161,7 @@ impl Default for ListenParams {
             languages: vec![],
             keywords: vec![],
             custom_query: None,
+            translation: None,
         }
     }
 }
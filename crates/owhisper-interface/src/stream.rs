@@ -24,6 +24,16 @@ common_derives! {
         pub confidence: f64,
         #[serde(default)]
         pub languages: Vec<String>,
+        #[serde(default)]
+        pub translation: Option<Translation>,
+    }
+}
+
+common_derives! {
+    #[specta(rename = "StreamTranslation")]
+    pub struct Translation {
+        pub language: String,
+        pub text: String,
     }
 }
 
@@ -153,6 +163,16 @@ impl StreamResponse {
         }
     }
 
+    pub fn translation(&self) -> Option<&Translation> {
+        match self {
+            StreamResponse::TranscriptResponse { channel, .. } => channel
+                .alternatives
+                .first()
+                .and_then(|alt| alt.translation.as_ref()),
+            _ => None,
+        }
+    }
+
     pub fn apply_offset(&mut self, offset_secs: f64) {
         match self {
             StreamResponse::TranscriptResponse { start, channel, .. } => {
@@ -165,7 +165,13 @@ impl<'a> UserClient<'a> {
             .set_parts(Some(
                 parts
                     .into_iter()
-                    .map(|p| aws_sdk_s3::types::CompletedPart::builder().e_tag(p).build())
+                    .enumerate()
+                    .map(|(i, p)| {
+                        aws_sdk_s3::types::CompletedPart::builder()
+                            .e_tag(p)
+                            .part_number(i as i32 + 1)
+                            .build()
+                    })
                     .collect(),
             ))
             .build();
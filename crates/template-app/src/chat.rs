@@ -1,4 +1,4 @@
-use crate::{Transcript, common_derives, filters};
+use crate::{PreviousMeeting, Transcript, common_derives, filters};
 
 common_derives! {
     pub struct ChatContext {
@@ -7,6 +7,7 @@ common_derives! {
         pub raw_content: Option<String>,
         pub enhanced_content: Option<String>,
         pub transcript: Option<Transcript>,
+        pub previous_meeting: Option<PreviousMeeting>,
     }
 }
 
@@ -24,7 +25,7 @@ common_derives! {
 mod tests {
     use super::*;
     use crate::Segment;
-    use hypr_askama_utils::tpl_snapshot_with_assert;
+    use hypr_askama_utils::{tpl_assert, tpl_snapshot_with_assert};
 
     tpl_snapshot_with_assert!(
         test_chat_system_with_context, 
@@ -54,6 +55,7 @@ mod tests {
                     started_at: Some(1715702400),
                     ended_at: Some(1715705400),
                 }),
+                previous_meeting: None,
             }),
         }, 
         |v| v.contains("English"),
@@ -95,4 +97,32 @@ mod tests {
 
     You should treat meeting transcript and enhanced meeting summary as the information with more weight than the original (manually written) note.
     "#);
+
+    tpl_assert!(
+        test_chat_system_with_previous_meeting,
+        ChatSystem {
+            language: None,
+            current_date: None,
+            context: Some(ChatContext {
+                title: Some("Weekly Standup".to_string()),
+                date: None,
+                raw_content: None,
+                enhanced_content: None,
+                transcript: None,
+                previous_meeting: Some(crate::PreviousMeeting {
+                    title: Some("Weekly Standup".to_string()),
+                    date: Some("2025-01-08".to_string()),
+                    summary: None,
+                    action_items: vec![crate::PreviousActionItem {
+                        text: "Fix the login bug".to_string(),
+                        owner: Some("Bob".to_string()),
+                        done: false,
+                    }],
+                }),
+            }),
+        },
+        |v| {
+            v.contains("Previous Meeting in This Series:\nMeeting: Weekly Standup\nDate: 2025-01-08\n\nAction items:\n- [ ] Fix the login bug (Bob)")
+        }
+    );
 }
@@ -0,0 +1,233 @@
+use crate::{Segment, Transcript};
+
+// Rough average for English text. Only used when the real tokenizer is not available.
+const CHARS_PER_TOKEN: usize = 4;
+
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Splits transcripts into chunks whose rendered text fits in `max_tokens`.
+///
+/// Chunks are only broken between speaker turns. A single turn that does not fit on its own is
+/// broken between segments, and a single oversized segment is broken between words.
+pub fn chunk_transcripts(
+    transcripts: &[Transcript],
+    max_tokens: usize,
+    count_tokens: impl Fn(&str) -> usize,
+) -> Vec<Transcript> {
+    let max_tokens = max_tokens.max(1);
+
+    let mut chunks = Vec::new();
+    let mut current: Vec<Segment> = Vec::new();
+    let mut current_tokens = 0;
+
+    for turn in transcripts.iter().flat_map(|t| speaker_turns(&t.segments)) {
+        let turn_tokens = count_tokens(&render(&turn));
+
+        if turn_tokens > max_tokens {
+            for segment in split_turn(turn, max_tokens, &count_tokens) {
+                let segment_tokens = count_tokens(&render(std::slice::from_ref(&segment)));
+
+                if current_tokens + segment_tokens > max_tokens && !current.is_empty() {
+                    chunks.push(std::mem::take(&mut current));
+                    current_tokens = 0;
+                }
+
+                current.push(segment);
+                current_tokens += segment_tokens;
+            }
+            continue;
+        }
+
+        if current_tokens + turn_tokens > max_tokens && !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+            current_tokens = 0;
+        }
+
+        current.extend(turn);
+        current_tokens += turn_tokens;
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+        .into_iter()
+        .map(|segments| Transcript {
+            segments,
+            started_at: None,
+            ended_at: None,
+        })
+        .collect()
+}
+
+fn speaker_turns(segments: &[Segment]) -> Vec<Vec<Segment>> {
+    let mut turns: Vec<Vec<Segment>> = Vec::new();
+
+    for segment in segments {
+        match turns.last_mut() {
+            Some(turn) if turn[0].speaker == segment.speaker => turn.push(segment.clone()),
+            _ => turns.push(vec![segment.clone()]),
+        }
+    }
+
+    turns
+}
+
+fn split_turn(
+    turn: Vec<Segment>,
+    max_tokens: usize,
+    count_tokens: &impl Fn(&str) -> usize,
+) -> Vec<Segment> {
+    let mut out = Vec::new();
+
+    for segment in turn {
+        if count_tokens(&render(std::slice::from_ref(&segment))) <= max_tokens {
+            out.push(segment);
+            continue;
+        }
+
+        let mut text = String::new();
+        for word in segment.text.split_whitespace() {
+            let candidate = if text.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", text, word)
+            };
+
+            if !text.is_empty()
+                && count_tokens(&format!("{}: {}", segment.speaker, candidate)) > max_tokens
+            {
+                out.push(Segment {
+                    text: std::mem::replace(&mut text, word.to_string()),
+                    speaker: segment.speaker.clone(),
+                });
+            } else {
+                text = candidate;
+            }
+        }
+
+        if !text.is_empty() {
+            out.push(Segment {
+                text,
+                speaker: segment.speaker,
+            });
+        }
+    }
+
+    out
+}
+
+// Mirrors the `transcript` macro in `_macros.jinja`.
+fn render(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .map(|s| format!("\n{}: {}", s.speaker, s.text))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, text: &str) -> Segment {
+        Segment {
+            speaker: speaker.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    fn transcript(segments: Vec<Segment>) -> Transcript {
+        Transcript {
+            segments,
+            started_at: None,
+            ended_at: None,
+        }
+    }
+
+    fn word_count(text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+
+    #[test]
+    fn test_fits_in_single_chunk() {
+        let transcripts = vec![transcript(vec![
+            segment("Alice", "hello there"),
+            segment("Bob", "hi"),
+        ])];
+
+        let chunks = chunk_transcripts(&transcripts, 100, word_count);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].segments.len(), 2);
+    }
+
+    #[test]
+    fn test_breaks_between_speaker_turns() {
+        let transcripts = vec![transcript(vec![
+            segment("Alice", "one two"),
+            segment("Alice", "three four"),
+            segment("Bob", "five six"),
+            segment("Alice", "seven eight"),
+        ])];
+
+        // Alice's first turn is 6 words once rendered, so it must stay together.
+        let chunks = chunk_transcripts(&transcripts, 7, word_count);
+        let speakers = chunks
+            .iter()
+            .map(|c| {
+                c.segments
+                    .iter()
+                    .map(|s| s.speaker.as_str())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(speakers, vec![vec!["Alice", "Alice"], vec!["Bob", "Alice"]]);
+    }
+
+    #[test]
+    fn test_turns_span_transcripts() {
+        let transcripts = vec![
+            transcript(vec![segment("Alice", "one")]),
+            transcript(vec![segment("Bob", "two")]),
+        ];
+
+        let chunks = chunk_transcripts(&transcripts, 100, word_count);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].segments.len(), 2);
+    }
+
+    #[test]
+    fn test_splits_oversized_segment() {
+        let text = (0..20).map(|i| i.to_string()).collect::<Vec<_>>().join(" ");
+        let transcripts = vec![transcript(vec![segment("Alice", &text)])];
+
+        let chunks = chunk_transcripts(&transcripts, 6, word_count);
+        assert!(chunks.len() > 1);
+
+        for chunk in &chunks {
+            assert!(word_count(&render(&chunk.segments)) <= 6);
+        }
+
+        let rejoined = chunks
+            .iter()
+            .flat_map(|c| c.segments.iter().map(|s| s.text.clone()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(rejoined, text);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(chunk_transcripts(&[], 10, word_count).is_empty());
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{EnhanceTemplate, Participant, Session, Transcript, common_derives, filters};
+use crate::{
+    EnhanceTemplate, Participant, PreviousMeeting, Session, Transcript, common_derives, filters,
+};
 
 common_derives! {
     #[derive(askama::Template)]
@@ -17,6 +19,40 @@ common_derives! {
         pub participants: Vec<Participant>,
         pub template: Option<EnhanceTemplate>,
         pub transcripts: Vec<Transcript>,
+        pub previous_meeting: Option<PreviousMeeting>,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "enhance-chunk.system.md.jinja")]
+    pub struct EnhanceChunkSystem {
+        pub language: Option<String>,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "enhance-chunk.user.md.jinja")]
+    pub struct EnhanceChunkUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub template: Option<EnhanceTemplate>,
+        pub chunk: Transcript,
+        pub index: usize,
+        pub total: usize,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "enhance-merge.user.md.jinja")]
+    pub struct EnhanceMergeUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub template: Option<EnhanceTemplate>,
+        pub summaries: Vec<String>,
+        pub previous_meeting: Option<PreviousMeeting>,
     }
 }
 
@@ -117,6 +153,7 @@ mod tests {
                 started_at: Some(1719859200),
                 ended_at: Some(1719862800),
             }],
+            previous_meeting: None,
         }, @"
     # Context
 
@@ -143,4 +180,173 @@ mod tests {
     1. Section 1 - Section 1 description
     2. Section 2 - Section 2 description
     ");
+
+    tpl_snapshot!(
+        test_enhance_chunk_user,
+        EnhanceChunkUser {
+            session: Session {
+                title: Some("Meeting".to_string()),
+                started_at: None,
+                ended_at: None,
+                event: None,
+            },
+            participants: vec![],
+            template: Some(EnhanceTemplate {
+                title: "Meeting".to_string(),
+                description: None,
+                sections: vec![
+                    TemplateSection {
+                        title: "Decisions".to_string(),
+                        description: None,
+                    },
+                    TemplateSection {
+                        title: "Action Items".to_string(),
+                        description: None,
+                    },
+                ],
+            }),
+            chunk: Transcript {
+                segments: vec![Segment {
+                    text: "Hello".to_string(),
+                    speaker: "John Doe".to_string(),
+                }],
+                started_at: None,
+                ended_at: None,
+            },
+            index: 1,
+            total: 3,
+        }, @"
+    # Context
+
+
+    Session: Meeting
+
+    # Transcript (Part 2 of 3)
+
+
+    John Doe: Hello
+
+    # Focus
+
+    The final summary will use these sections. Note anything relevant to them.
+
+    - Decisions
+    - Action Items
+    ");
+
+    tpl_snapshot!(
+        test_enhance_merge_user,
+        EnhanceMergeUser {
+            session: Session {
+                title: Some("Meeting".to_string()),
+                started_at: None,
+                ended_at: None,
+                event: None,
+            },
+            participants: vec![],
+            template: None,
+            summaries: vec![
+                "- First part".to_string(),
+                "- Second part".to_string(),
+            ],
+            previous_meeting: None,
+        }, @"
+    # Context
+
+
+    Session: Meeting
+
+    # Notes
+
+    The transcript was too long to read at once, so it was split into parts and noted separately. Combine the notes below into a single summary, removing duplicates.
+
+    ## Part 1
+
+    - First part
+
+    ## Part 2
+
+    - Second part
+
+    # Output Template
+
+    # Instructions
+
+    1. Analyze the content and decide the sections to use.
+    2. Generate a well-formatted markdown summary.
+    ");
+
+    tpl_snapshot!(
+        test_enhance_user_previous_meeting,
+        EnhanceUser {
+            session: Session {
+                title: Some("1:1 Alice / Bob".to_string()),
+                started_at: Some("2025-01-15 10:00".to_string()),
+                ended_at: Some("2025-01-15 10:30".to_string()),
+                event: Some(crate::Event {
+                    name: "1:1 Alice / Bob".to_string(),
+                }),
+            },
+            participants: vec![],
+            template: None,
+            transcripts: vec![Transcript {
+                segments: vec![Segment {
+                    text: "The deck went out on Monday.".to_string(),
+                    speaker: "Alice".to_string(),
+                }],
+                started_at: None,
+                ended_at: None,
+            }],
+            previous_meeting: Some(PreviousMeeting {
+                title: Some("1:1 Alice / Bob".to_string()),
+                date: Some("2025-01-08".to_string()),
+                summary: Some("# Hiring\n\n- Two offers out".to_string()),
+                action_items: vec![
+                    crate::PreviousActionItem {
+                        text: "Send the deck to investors".to_string(),
+                        owner: Some("Alice".to_string()),
+                        done: true,
+                    },
+                    crate::PreviousActionItem {
+                        text: "Book the offsite venue".to_string(),
+                        owner: None,
+                        done: false,
+                    },
+                ],
+            }),
+        }, @"
+    # Context
+
+
+    Meeting: 1:1 Alice / Bob
+    Time: 2025-01-15 10:00 - 2025-01-15 10:30
+
+    # Previous Meeting in This Series
+
+    Meeting: 1:1 Alice / Bob
+    Date: 2025-01-08
+
+    Summary:
+    # Hiring
+
+    - Two offers out
+
+    Action items:
+    - [x] Send the deck to investors (Alice)
+    - [ ] Book the offsite venue
+
+    Where this meeting follows up on the previous one, such as progress on its action items, say so.
+
+    # Transcript
+
+
+    Alice: The deck went out on Monday.
+
+    # Output Template
+
+    # Instructions
+
+    1. Analyze the content and decide the sections to use.
+    2. Generate a well-formatted markdown summary.
+    ");
 }
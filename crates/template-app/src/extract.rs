@@ -0,0 +1,152 @@
+use crate::{Participant, Session, common_derives, filters};
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "extract.system.md.jinja")]
+    pub struct ExtractSystem {
+        pub language: Option<String>,
+        pub current_date: Option<String>,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    // Extracted text is stored verbatim, so the transcript must not be HTML-escaped.
+    #[template(path = "extract.user.md.jinja", escape = "none")]
+    pub struct ExtractUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub lines: Vec<TranscriptLine>,
+    }
+}
+
+common_derives! {
+    pub struct TranscriptLine {
+        /// Shown to the model as-is, and copied back into `source_timestamp`.
+        pub timestamp: Option<String>,
+        pub speaker: String,
+        pub text: String,
+    }
+}
+
+// Field names follow the JSON the model is asked to produce, so no camelCase here.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, specta::Type)]
+pub struct Extraction {
+    pub action_items: Vec<ExtractedActionItem>,
+    pub decisions: Vec<ExtractedDecision>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, specta::Type)]
+pub struct ExtractedActionItem {
+    pub text: String,
+    pub owner: Option<String>,
+    pub due: Option<String>,
+    pub source_timestamp: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, specta::Type)]
+pub struct ExtractedDecision {
+    pub text: String,
+    pub source_timestamp: Option<String>,
+}
+
+impl Extraction {
+    /// Parses model output, tolerating code fences and text around the JSON object.
+    pub fn parse(text: &str) -> Result<Self, crate::Error> {
+        let json = match (text.find('{'), text.rfind('}')) {
+            (Some(start), Some(end)) if start < end => &text[start..=end],
+            _ => text,
+        };
+
+        let mut extraction: Extraction = serde_json::from_str(json)?;
+        extraction
+            .action_items
+            .retain(|item| !item.text.trim().is_empty());
+        extraction.decisions.retain(|d| !d.text.trim().is_empty());
+        Ok(extraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Event;
+    use hypr_askama_utils::{tpl_assert, tpl_snapshot};
+
+    tpl_assert!(
+        test_language_as_specified,
+        ExtractSystem {
+            language: Some("ko".to_string()),
+            current_date: None,
+        },
+        |v| v.contains("Korean")
+    );
+
+    tpl_snapshot!(
+        test_extract_user,
+        ExtractUser {
+            session: Session {
+                title: Some("Weekly Sync".to_string()),
+                started_at: None,
+                ended_at: None,
+                event: Some(Event { name: "Weekly Sync".to_string() }),
+            },
+            participants: vec![
+                Participant { name: "Alice".to_string(), job_title: None },
+                Participant { name: "Bob".to_string(), job_title: None },
+            ],
+            lines: vec![
+                TranscriptLine {
+                    timestamp: Some("00:10".to_string()),
+                    speaker: "Alice".to_string(),
+                    text: "Let's ship on Friday.".to_string(),
+                },
+                TranscriptLine {
+                    timestamp: None,
+                    speaker: "Bob".to_string(),
+                    text: "I'll update the docs.".to_string(),
+                },
+            ],
+        },
+        @"
+    # Context
+
+
+    Meeting: Weekly Sync
+    Participants:
+    - Alice
+      - Bob
+      
+
+    # Transcript
+
+    [00:10] Alice: Let's ship on Friday.
+    Bob: I'll update the docs.
+
+    Now, extract the action items and decisions from the transcript above.
+    "
+    );
+
+    #[test]
+    fn test_parse() {
+        let text = r#"```json
+{"action_items": [{"text": "Update the docs", "owner": "Bob", "due": null, "source_timestamp": "00:12"}, {"text": " ", "owner": null, "due": null, "source_timestamp": null}], "decisions": []}
+```"#;
+
+        let extraction = Extraction::parse(text).unwrap();
+        assert_eq!(
+            extraction,
+            Extraction {
+                action_items: vec![ExtractedActionItem {
+                    text: "Update the docs".to_string(),
+                    owner: Some("Bob".to_string()),
+                    due: None,
+                    source_timestamp: Some("00:12".to_string()),
+                }],
+                decisions: vec![],
+            }
+        );
+
+        assert!(Extraction::parse("no items").is_err());
+    }
+}
@@ -4,40 +4,48 @@ fn extract_iso639(code: &str) -> &str {
     code.split(['-', '_']).next().unwrap_or(code)
 }
 
-#[askama::filter_fn]
-pub fn current_date<T: ?Sized>(_value: &T, _env: &dyn askama::Values) -> askama::Result<String> {
+pub(crate) fn today() -> String {
     #[cfg(test)]
     {
-        Ok("2025-01-01".to_string())
+        "2025-01-01".to_string()
     }
     #[cfg(not(test))]
     {
-        Ok(chrono::Utc::now().format("%Y-%m-%d").to_string())
+        chrono::Utc::now().format("%Y-%m-%d").to_string()
     }
 }
 
-#[askama::filter_fn]
-pub fn language(value: &Option<String>, _env: &dyn askama::Values) -> askama::Result<String> {
-    let raw = value.as_deref().unwrap_or("").to_lowercase();
+pub(crate) fn language_name(value: Option<&str>) -> String {
+    let raw = value.unwrap_or("").to_lowercase();
     let v = extract_iso639(&raw);
     let lang = Language::from_639_1(v).unwrap_or(Language::from_639_1("en").unwrap());
-    Ok(lang.to_name().to_string())
+    lang.to_name().to_string()
+}
+
+pub(crate) fn language_is(value: Option<&str>, expected: Language) -> bool {
+    let raw = value.unwrap_or("en").to_lowercase();
+    let v = extract_iso639(&raw);
+    Language::from_639_1(v) == Some(expected)
+}
+
+#[askama::filter_fn]
+pub fn current_date<T: ?Sized>(_value: &T, _env: &dyn askama::Values) -> askama::Result<String> {
+    Ok(today())
+}
+
+#[askama::filter_fn]
+pub fn language(value: &Option<String>, _env: &dyn askama::Values) -> askama::Result<String> {
+    Ok(language_name(value.as_deref()))
 }
 
 #[askama::filter_fn]
 pub fn is_english(value: &Option<String>, _env: &dyn askama::Values) -> askama::Result<bool> {
-    let raw = value.as_deref().unwrap_or("en").to_lowercase();
-    let v = extract_iso639(&raw);
-    let lang = Language::from_639_1(v);
-    Ok(matches!(lang, Some(Language::Eng)))
+    Ok(language_is(value.as_deref(), Language::Eng))
 }
 
 #[askama::filter_fn]
 pub fn is_korean(value: &Option<String>, _env: &dyn askama::Values) -> askama::Result<bool> {
-    let raw = value.as_deref().unwrap_or("en").to_lowercase();
-    let v = extract_iso639(&raw);
-    let lang = Language::from_639_1(v);
-    Ok(matches!(lang, Some(Language::Kor)))
+    Ok(language_is(value.as_deref(), Language::Kor))
 }
 
 #[cfg(test)]
@@ -1,12 +1,18 @@
 mod chat;
+mod chunk;
 mod enhance;
+mod extract;
 mod filters;
+mod runtime;
 mod title;
 mod types;
 
 pub use chat::*;
+pub use chunk::*;
 pub use enhance::*;
+pub use extract::*;
 pub use filters::*;
+pub use runtime::*;
 pub use title::*;
 pub use types::*;
 
@@ -23,6 +29,11 @@ common_derives! {
     pub enum Template {
         EnhanceSystem(EnhanceSystem),
         EnhanceUser(EnhanceUser),
+        EnhanceChunkSystem(EnhanceChunkSystem),
+        EnhanceChunkUser(EnhanceChunkUser),
+        EnhanceMergeUser(EnhanceMergeUser),
+        ExtractSystem(ExtractSystem),
+        ExtractUser(ExtractUser),
         TitleSystem(TitleSystem),
         TitleUser(TitleUser),
         ChatSystem(ChatSystem),
@@ -33,12 +44,23 @@ common_derives! {
 pub enum Error {
     #[error(transparent)]
     AskamaError(#[from] askama::Error),
+    #[error(transparent)]
+    MinijinjaError(#[from] minijinja::Error),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error("invalid user template: {0}")]
+    InvalidUserTemplate(String),
 }
 
 pub fn render(t: Template) -> Result<String, Error> {
     let value = match t {
         Template::EnhanceSystem(t) => askama::Template::render(&t),
         Template::EnhanceUser(t) => askama::Template::render(&t),
+        Template::EnhanceChunkSystem(t) => askama::Template::render(&t),
+        Template::EnhanceChunkUser(t) => askama::Template::render(&t),
+        Template::EnhanceMergeUser(t) => askama::Template::render(&t),
+        Template::ExtractSystem(t) => askama::Template::render(&t),
+        Template::ExtractUser(t) => askama::Template::render(&t),
         Template::TitleSystem(t) => askama::Template::render(&t),
         Template::TitleUser(t) => askama::Template::render(&t),
         Template::ChatSystem(t) => askama::Template::render(&t),
@@ -0,0 +1,289 @@
+use isolang::Language;
+use minijinja::{Environment, UndefinedBehavior, Value};
+
+use crate::{
+    EnhanceSystem, EnhanceTemplate, EnhanceUser, Event, Participant, PreviousActionItem,
+    PreviousMeeting, Segment, Session, TemplateSection, Transcript, common_derives, filters,
+};
+
+common_derives! {
+    pub struct UserTemplate {
+        pub id: String,
+        pub name: String,
+        pub description: Option<String>,
+        /// Replaces `enhance.system.md.jinja` when set.
+        pub system: Option<String>,
+        /// Replaces `enhance.user.md.jinja` when set.
+        pub user: Option<String>,
+    }
+}
+
+common_derives! {
+    pub struct RenderedPrompts {
+        pub system: String,
+        pub user: String,
+    }
+}
+
+// Same macros as the built-in templates, so `{% import "_macros.jinja" as macros %}` works.
+const USER_MACROS: &str = include_str!("../assets/_macros.user.jinja");
+
+fn environment(strict: bool) -> Environment<'static> {
+    let mut env = Environment::new();
+    env.add_template("_macros.jinja", USER_MACROS)
+        .expect("user template macros must parse");
+
+    if strict {
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+    }
+
+    env.add_filter("current_date", |_value: Value| filters::today());
+    env.add_filter("language", |value: Option<String>| {
+        filters::language_name(value.as_deref())
+    });
+    env.add_filter("is_english", |value: Option<String>| {
+        filters::language_is(value.as_deref(), Language::Eng)
+    });
+    env.add_filter("is_korean", |value: Option<String>| {
+        filters::language_is(value.as_deref(), Language::Kor)
+    });
+
+    env
+}
+
+// Templates see the same snake_case field names as the built-in askama templates.
+fn to_context(value: &impl serde::Serialize) -> Result<serde_json::Value, crate::Error> {
+    fn snake_case(key: &str) -> String {
+        let mut out = String::with_capacity(key.len());
+        for c in key.chars() {
+            if c.is_ascii_uppercase() {
+                out.push('_');
+                out.push(c.to_ascii_lowercase());
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    fn convert(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => map
+                .into_iter()
+                .map(|(k, v)| (snake_case(&k), convert(v)))
+                .collect(),
+            serde_json::Value::Array(items) => items.into_iter().map(convert).collect(),
+            v => v,
+        }
+    }
+
+    Ok(convert(serde_json::to_value(value)?))
+}
+
+fn render_str(
+    source: &str,
+    ctx: &impl serde::Serialize,
+    strict: bool,
+) -> Result<String, crate::Error> {
+    let env = environment(strict);
+    let tpl = env.template_from_str(source)?;
+    Ok(tpl.render(to_context(ctx)?)?)
+}
+
+impl UserTemplate {
+    pub fn render_system(&self, ctx: EnhanceSystem) -> Result<String, crate::Error> {
+        match &self.system {
+            Some(source) => render_str(source, &ctx, false),
+            None => crate::render(crate::Template::EnhanceSystem(ctx)),
+        }
+    }
+
+    pub fn render_user(&self, ctx: EnhanceUser) -> Result<String, crate::Error> {
+        match &self.user {
+            Some(source) => render_str(source, &ctx, false),
+            None => crate::render(crate::Template::EnhanceUser(ctx)),
+        }
+    }
+
+    /// Checks syntax, and renders against a sample session with undefined variables as errors.
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        if self.name.trim().is_empty() {
+            return Err(crate::Error::InvalidUserTemplate(
+                "name must not be empty".to_string(),
+            ));
+        }
+
+        if let Some(source) = &self.system {
+            render_str(source, &sample_enhance_system(), true)?;
+        }
+        if let Some(source) = &self.user {
+            render_str(source, &sample_enhance_user(), true)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn preview(
+        &self,
+        system: Option<EnhanceSystem>,
+        user: Option<EnhanceUser>,
+    ) -> Result<RenderedPrompts, crate::Error> {
+        Ok(RenderedPrompts {
+            system: self.render_system(system.unwrap_or_else(sample_enhance_system))?,
+            user: self.render_user(user.unwrap_or_else(sample_enhance_user))?,
+        })
+    }
+}
+
+pub fn sample_enhance_system() -> EnhanceSystem {
+    EnhanceSystem {
+        language: Some("en".to_string()),
+        current_date: None,
+    }
+}
+
+pub fn sample_enhance_user() -> EnhanceUser {
+    EnhanceUser {
+        session: Session {
+            title: Some("Weekly Product Sync".to_string()),
+            started_at: Some("2025-01-01 10:00".to_string()),
+            ended_at: Some("2025-01-01 10:30".to_string()),
+            event: Some(Event {
+                name: "Weekly Product Sync".to_string(),
+            }),
+        },
+        participants: vec![
+            Participant {
+                name: "Alice".to_string(),
+                job_title: Some("Product Manager".to_string()),
+            },
+            Participant {
+                name: "Bob".to_string(),
+                job_title: None,
+            },
+        ],
+        template: Some(EnhanceTemplate {
+            title: "Meeting Notes".to_string(),
+            description: None,
+            sections: vec![
+                TemplateSection {
+                    title: "Decisions".to_string(),
+                    description: None,
+                },
+                TemplateSection {
+                    title: "Action Items".to_string(),
+                    description: Some("Who does what, by when".to_string()),
+                },
+            ],
+        }),
+        transcripts: vec![Transcript {
+            segments: vec![
+                Segment {
+                    speaker: "Alice".to_string(),
+                    text: "Let's ship the onboarding changes on Friday.".to_string(),
+                },
+                Segment {
+                    speaker: "Bob".to_string(),
+                    text: "Sounds good, I'll update the docs before then.".to_string(),
+                },
+            ],
+            started_at: None,
+            ended_at: None,
+        }],
+        previous_meeting: Some(PreviousMeeting {
+            title: Some("Weekly Product Sync".to_string()),
+            date: Some("2024-12-25".to_string()),
+            summary: Some("- Onboarding changes slipped a week.".to_string()),
+            action_items: vec![PreviousActionItem {
+                text: "Update the onboarding docs".to_string(),
+                owner: Some("Bob".to_string()),
+                done: false,
+            }],
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_template(system: Option<&str>, user: Option<&str>) -> UserTemplate {
+        UserTemplate {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            description: None,
+            system: system.map(String::from),
+            user: user.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_falls_back_to_builtin() {
+        let tpl = user_template(None, None);
+        let rendered = tpl.render_system(sample_enhance_system()).unwrap();
+        assert_eq!(
+            rendered,
+            crate::render(crate::Template::EnhanceSystem(sample_enhance_system())).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_filters_and_variables() {
+        let tpl = user_template(
+            Some(
+                "{{ \"\"|current_date }} {{ language|language }} {% if language|is_korean %}ko{% else %}other{% endif %}",
+            ),
+            Some(
+                "{{ session.title }}|{{ participants[0].job_title }}|{% for t in transcripts %}{% for s in t.segments %}{{ s.speaker }};{% endfor %}{% endfor %}",
+            ),
+        );
+
+        let prompts = tpl.preview(None, None).unwrap();
+        assert_eq!(prompts.system, "2025-01-01 English other");
+        assert_eq!(
+            prompts.user,
+            "Weekly Product Sync|Product Manager|Alice;Bob;"
+        );
+    }
+
+    #[test]
+    fn test_shared_macros() {
+        let tpl = user_template(
+            None,
+            Some(
+                "{% import \"_macros.jinja\" as macros %}{{ macros.session_context(session) }}{{ macros.transcripts(transcripts) }}{{ macros.template_numbered(template) }}{{ macros.previous_meeting(previous_meeting) }}",
+            ),
+        );
+        assert!(tpl.validate().is_ok());
+
+        let user = tpl.preview(None, None).unwrap().user;
+        assert!(user.contains("Meeting: Weekly Product Sync"));
+        assert!(user.contains("Alice: Let's ship the onboarding changes on Friday."));
+        assert!(user.contains("2. Action Items - Who does what, by when"));
+        assert!(user.contains("- [ ] Update the onboarding docs (Bob)"));
+    }
+
+    #[test]
+    fn test_validate_rejects_syntax_error() {
+        let tpl = user_template(None, Some("{% for x in transcripts %}"));
+        assert!(tpl.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_variable() {
+        let tpl = user_template(None, Some("{{ sesion.title }}"));
+        assert!(tpl.validate().is_err());
+
+        let tpl = user_template(None, Some("{{ session.title }}"));
+        assert!(tpl.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_name() {
+        let tpl = UserTemplate {
+            name: " ".to_string(),
+            ..user_template(None, None)
+        };
+        assert!(tpl.validate().is_err());
+    }
+}
@@ -52,6 +52,24 @@ common_derives! {
     }
 }
 
+common_derives! {
+    /// The latest earlier meeting of the same recurring series.
+    pub struct PreviousMeeting {
+        pub title: Option<String>,
+        pub date: Option<String>,
+        pub summary: Option<String>,
+        pub action_items: Vec<PreviousActionItem>,
+    }
+}
+
+common_derives! {
+    pub struct PreviousActionItem {
+        pub text: String,
+        pub owner: Option<String>,
+        pub done: bool,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,6 +105,7 @@ where
                     return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
                 }
             };
+            let opus = query_string.split('&').any(|pair| pair == "encoding=opus");
 
             let (mut parts, _body) = req.into_parts();
             let ws_upgrade = match WebSocketUpgrade::from_request_parts(&mut parts, &()).await {
@@ -119,6 +120,7 @@ where
                     handle_websocket_connection(
                         socket,
                         params,
+                        opus,
                         model_size,
                         tokenizer_path,
                         encoder_path,
@@ -134,6 +136,7 @@ where
 async fn handle_websocket_connection(
     socket: WebSocket,
     params: ListenParams,
+    opus: bool,
     model_size: MoonshineModelSize,
     tokenizer_path: String,
     encoder_path: String,
@@ -161,10 +164,10 @@ async fn handle_websocket_connection(
 
     match params.channels {
         1 => {
-            handle_single_channel(ws_sender, ws_receiver, model, redemption_time).await;
+            handle_single_channel(ws_sender, ws_receiver, opus, model, redemption_time).await;
         }
         _ => {
-            handle_dual_channel(ws_sender, ws_receiver, model, redemption_time).await;
+            handle_dual_channel(ws_sender, ws_receiver, opus, model, redemption_time).await;
         }
     }
 }
@@ -172,10 +175,21 @@ async fn handle_websocket_connection(
 async fn handle_single_channel(
     ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
     ws_receiver: futures_util::stream::SplitStream<WebSocket>,
+    opus: bool,
     model: Arc<Mutex<MoonshineOnnxModel>>,
     redemption_time: Duration,
 ) {
-    let audio_source = hypr_ws_utils::WebSocketAudioSource::new(ws_receiver, 16 * 1000);
+    let audio_source = if opus {
+        match hypr_ws_utils::WebSocketAudioSource::opus(ws_receiver, 16 * 1000) {
+            Ok(source) => source,
+            Err(e) => {
+                tracing::error!(error = %e, "opus_decoder_init_failed");
+                return;
+            }
+        }
+    } else {
+        hypr_ws_utils::WebSocketAudioSource::new(ws_receiver, 16 * 1000)
+    };
     let vad_chunks = audio_source.speech_chunks(redemption_time);
 
     let stream = process_vad_stream(vad_chunks, model, "mixed");
@@ -186,11 +200,21 @@ async fn handle_single_channel(
 async fn handle_dual_channel(
     ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
     ws_receiver: futures_util::stream::SplitStream<WebSocket>,
+    opus: bool,
     model: Arc<Mutex<MoonshineOnnxModel>>,
     redemption_time: Duration,
 ) {
-    let (mic_source, speaker_source) =
-        hypr_ws_utils::split_dual_audio_sources(ws_receiver, 16 * 1000);
+    let (mic_source, speaker_source) = if opus {
+        match hypr_ws_utils::split_dual_opus_audio_sources(ws_receiver, 16 * 1000) {
+            Ok(sources) => sources,
+            Err(e) => {
+                tracing::error!(error = %e, "opus_decoder_init_failed");
+                return;
+            }
+        }
+    } else {
+        hypr_ws_utils::split_dual_audio_sources(ws_receiver, 16 * 1000)
+    };
 
     let mic_stream = {
         let mic_vad_chunks = mic_source.speech_chunks(redemption_time);
@@ -290,6 +314,7 @@ where
                                 alternatives: vec![Alternatives {
                                     transcript: text.clone(),
                                     languages: vec![],
+                                    translation: None,
                                     words,
                                     confidence,
                                 }],
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use hypr_analytics::{AnalyticsClient, AnalyticsPayload};
+use owhisper_client::AudioEncoding;
 
 #[derive(Debug, Clone)]
 pub struct SttEvent {
@@ -8,6 +9,11 @@ pub struct SttEvent {
     pub user_id: Option<String>,
     pub provider: String,
     pub duration: Duration,
+    pub audio_encoding: AudioEncoding,
+    /// Audio received from the client, before any decoding.
+    pub client_audio_bytes: u64,
+    /// PCM forwarded to the provider.
+    pub upstream_audio_bytes: u64,
 }
 
 pub trait SttAnalyticsReporter: Send + Sync {
@@ -25,7 +31,10 @@ impl SttAnalyticsReporter for AnalyticsClient {
         Box::pin(async move {
             let payload = AnalyticsPayload::builder("$stt_request")
                 .with("$stt_provider", event.provider.clone())
-                .with("$stt_duration", event.duration.as_secs_f64());
+                .with("$stt_duration", event.duration.as_secs_f64())
+                .with("$stt_audio_encoding", event.audio_encoding.to_string())
+                .with("$stt_client_audio_bytes", event.client_audio_bytes)
+                .with("$stt_upstream_audio_bytes", event.upstream_audio_bytes);
 
             let payload = if let Some(user_id) = &event.user_id {
                 payload.with("user_id", user_id.clone())
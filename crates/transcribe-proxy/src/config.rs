@@ -19,6 +19,7 @@ pub struct SttProxyConfig {
     pub analytics: Option<Arc<dyn SttAnalyticsReporter>>,
     pub upstream_urls: HashMap<Provider, String>,
     pub hyprnote_routing: Option<HyprnoteRoutingConfig>,
+    pub storage: Option<hypr_s3::Client>,
 }
 
 impl SttProxyConfig {
@@ -30,6 +31,7 @@ impl SttProxyConfig {
             analytics: None,
             upstream_urls: HashMap::new(),
             hyprnote_routing: None,
+            storage: None,
         }
     }
 
@@ -58,6 +60,12 @@ impl SttProxyConfig {
         self
     }
 
+    /// Enables the `/upload` routes large-file transcriptions go through.
+    pub fn with_storage(mut self, storage: hypr_s3::Client) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
     pub fn provider_selector(&self) -> ProviderSelector {
         ProviderSelector::new(
             self.api_keys.clone(),
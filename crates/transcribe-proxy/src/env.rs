@@ -19,6 +19,36 @@ pub struct Env {
     pub gladia_api_key: Option<String>,
     #[serde(default)]
     pub elevenlabs_api_key: Option<String>,
+    #[serde(default)]
+    pub speechmatics_api_key: Option<String>,
+    #[serde(default)]
+    pub revai_api_key: Option<String>,
+
+    #[serde(default)]
+    pub stt_storage_endpoint_url: Option<String>,
+    #[serde(default)]
+    pub stt_storage_bucket: Option<String>,
+    #[serde(default)]
+    pub stt_storage_access_key_id: Option<String>,
+    #[serde(default)]
+    pub stt_storage_secret_access_key: Option<String>,
+}
+
+impl Env {
+    /// Storage for large-file uploads, when all of its settings are present.
+    pub fn storage(&self) -> Option<hypr_s3::ClientBuilder> {
+        let non_empty = |v: &Option<String>| v.clone().filter(|s| !s.is_empty());
+
+        Some(
+            hypr_s3::Client::builder()
+                .endpoint_url(non_empty(&self.stt_storage_endpoint_url)?)
+                .bucket(non_empty(&self.stt_storage_bucket)?)
+                .credentials(
+                    non_empty(&self.stt_storage_access_key_id)?,
+                    non_empty(&self.stt_storage_secret_access_key)?,
+                ),
+        )
+    }
 }
 
 pub struct ApiKeys(pub HashMap<Provider, String>);
@@ -63,6 +93,12 @@ impl From<&Env> for ApiKeys {
         if let Some(key) = env.elevenlabs_api_key.as_ref().filter(|s| !s.is_empty()) {
             map.insert(Provider::ElevenLabs, key.clone());
         }
+        if let Some(key) = env.speechmatics_api_key.as_ref().filter(|s| !s.is_empty()) {
+            map.insert(Provider::Speechmatics, key.clone());
+        }
+        if let Some(key) = env.revai_api_key.as_ref().filter(|s| !s.is_empty()) {
+            map.insert(Provider::RevAi, key.clone());
+        }
         Self(map)
     }
 }
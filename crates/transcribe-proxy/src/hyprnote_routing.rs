@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use hypr_language::Language;
-use owhisper_client::{AdapterKind, LanguageSupport, Provider};
+use owhisper_client::{AdapterKind, CapabilityRequirements, LanguageSupport, Provider};
 
 const DEFAULT_NUM_RETRIES: usize = 2;
 const DEFAULT_MAX_DELAY_SECS: u64 = 5;
@@ -36,6 +36,8 @@ impl Default for HyprnoteRoutingConfig {
                 Provider::AssemblyAI,
                 Provider::Gladia,
                 Provider::ElevenLabs,
+                Provider::Speechmatics,
+                Provider::RevAi,
                 Provider::Fireworks,
                 Provider::OpenAI,
             ],
@@ -60,9 +62,10 @@ impl HyprnoteRouter {
     pub fn select_provider(
         &self,
         languages: &[Language],
+        requirements: &CapabilityRequirements,
         available_providers: &HashSet<Provider>,
     ) -> Option<Provider> {
-        self.select_provider_chain(languages, available_providers)
+        self.select_provider_chain(languages, requirements, available_providers)
             .into_iter()
             .next()
     }
@@ -70,12 +73,24 @@ impl HyprnoteRouter {
     pub fn select_provider_chain(
         &self,
         languages: &[Language],
+        requirements: &CapabilityRequirements,
         available_providers: &HashSet<Provider>,
     ) -> Vec<Provider> {
+        // The desktop always streams mic and speaker as two channels. Providers
+        // without native multichannel still take that stream, so it must not
+        // narrow the chain. Keyword lists are trimmed to the chosen provider's
+        // limit, so only boosting itself is required.
+        let requirements = CapabilityRequirements {
+            multichannel: false,
+            keywords: requirements.keywords.min(1),
+            ..requirements.clone()
+        };
+
         let mut candidates: Vec<_> = self
             .priorities
             .iter()
             .copied()
+            .filter(|p| AdapterKind::from(*p).satisfies(None, &requirements))
             .filter_map(|p| {
                 let support = self.get_language_support(&p, languages, available_providers);
                 if support.is_supported() {
@@ -176,7 +191,7 @@ mod tests {
         let available = make_available_providers(&[Provider::Soniox, Provider::Deepgram]);
         let languages: Vec<Language> = vec!["en".parse().unwrap()];
 
-        let selected = router.select_provider(&languages, &available);
+        let selected = router.select_provider(&languages, &Default::default(), &available);
         assert_eq!(selected, Some(Provider::Deepgram));
     }
 
@@ -186,7 +201,7 @@ mod tests {
         let available = make_available_providers(&[Provider::Soniox, Provider::AssemblyAI]);
         let languages: Vec<Language> = vec!["en".parse().unwrap()];
 
-        let selected = router.select_provider(&languages, &available);
+        let selected = router.select_provider(&languages, &Default::default(), &available);
         assert_eq!(selected, Some(Provider::Soniox));
     }
 
@@ -196,7 +211,7 @@ mod tests {
         let available = HashSet::new();
         let languages: Vec<Language> = vec!["en".parse().unwrap()];
 
-        let selected = router.select_provider(&languages, &available);
+        let selected = router.select_provider(&languages, &Default::default(), &available);
         assert_eq!(selected, None);
     }
 
@@ -207,7 +222,7 @@ mod tests {
             make_available_providers(&[Provider::Deepgram, Provider::Soniox, Provider::AssemblyAI]);
 
         let ko_en: Vec<Language> = vec!["ko".parse().unwrap(), "en".parse().unwrap()];
-        let selected = router.select_provider(&ko_en, &available);
+        let selected = router.select_provider(&ko_en, &Default::default(), &available);
         assert_eq!(selected, Some(Provider::Soniox));
     }
 
@@ -218,13 +233,81 @@ mod tests {
             make_available_providers(&[Provider::Deepgram, Provider::Soniox, Provider::AssemblyAI]);
         let languages: Vec<Language> = vec!["en".parse().unwrap()];
 
-        let chain = router.select_provider_chain(&languages, &available);
+        let chain = router.select_provider_chain(&languages, &Default::default(), &available);
         assert_eq!(chain.len(), 3);
         assert_eq!(chain[0], Provider::Deepgram);
         assert_eq!(chain[1], Provider::Soniox);
         assert_eq!(chain[2], Provider::AssemblyAI);
     }
 
+    #[test]
+    fn test_select_provider_chain_filters_by_capabilities() {
+        let router = HyprnoteRouter::default();
+        let available = make_available_providers(&[
+            Provider::Deepgram,
+            Provider::Soniox,
+            Provider::AssemblyAI,
+            Provider::Gladia,
+        ]);
+        let languages: Vec<Language> = vec!["en".parse().unwrap()];
+
+        let stereo = CapabilityRequirements {
+            multichannel: true,
+            ..Default::default()
+        };
+        let chain = router.select_provider_chain(&languages, &stereo, &available);
+        assert_eq!(
+            chain,
+            vec![
+                Provider::Deepgram,
+                Provider::Soniox,
+                Provider::AssemblyAI,
+                Provider::Gladia
+            ]
+        );
+
+        let ko_en: Vec<Language> = vec!["ko".parse().unwrap(), "en".parse().unwrap()];
+        assert_eq!(
+            router.select_provider(&ko_en, &stereo, &available),
+            Some(Provider::Soniox)
+        );
+
+        let diarized = CapabilityRequirements {
+            diarization: true,
+            ..stereo
+        };
+        let chain = router.select_provider_chain(&languages, &diarized, &available);
+        assert_eq!(chain, vec![Provider::Deepgram, Provider::Soniox]);
+
+        let translated = CapabilityRequirements {
+            translation: true,
+            ..diarized.clone()
+        };
+        let chain = router.select_provider_chain(&languages, &translated, &available);
+        assert_eq!(chain, vec![Provider::Soniox]);
+
+        let many_keywords = CapabilityRequirements {
+            keywords: 500,
+            ..stereo.clone()
+        };
+        let chain = router.select_provider_chain(&languages, &many_keywords, &available);
+        assert_eq!(
+            chain,
+            vec![
+                Provider::Deepgram,
+                Provider::Soniox,
+                Provider::AssemblyAI,
+                Provider::Gladia
+            ]
+        );
+
+        let available = make_available_providers(&[Provider::AssemblyAI]);
+        assert_eq!(
+            router.select_provider(&languages, &diarized, &available),
+            None
+        );
+    }
+
     #[test]
     fn test_should_use_hyprnote_routing_explicit_hyprnote() {
         assert!(super::should_use_hyprnote_routing(Some("hyprnote")));
@@ -259,7 +342,7 @@ mod tests {
             make_available_providers(&[Provider::Deepgram, Provider::Soniox, Provider::ElevenLabs]);
 
         let ko: Vec<Language> = vec!["ko".parse().unwrap()];
-        let chain = router.select_provider_chain(&ko, &available);
+        let chain = router.select_provider_chain(&ko, &Default::default(), &available);
 
         assert_eq!(chain[0], Provider::Soniox);
         assert_eq!(chain[1], Provider::Deepgram);
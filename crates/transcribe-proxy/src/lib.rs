@@ -20,6 +20,9 @@ pub use hyprnote_routing::{
 };
 pub use openapi::openapi;
 pub use provider_selector::{ProviderSelector, SelectedProvider};
-pub use relay::{ClientRequestBuilder, UpstreamError, WebSocketProxy, detect_upstream_error};
+pub use relay::{
+    AudioBytes, ClientRequestBuilder, CloseStats, OpusInput, UpstreamError, WebSocketProxy,
+    detect_upstream_error,
+};
 pub use routes::{listen_router, router};
 pub use upstream_url::UpstreamUrlBuilder;
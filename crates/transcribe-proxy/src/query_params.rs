@@ -8,6 +8,8 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use hypr_language::Language;
+use owhisper_client::{AudioEncoding, CapabilityRequirements};
+use owhisper_interface::TranslationParams;
 
 #[derive(Debug, Clone)]
 pub enum QueryValue {
@@ -77,6 +79,52 @@ impl QueryParams {
             })
             .unwrap_or_default()
     }
+
+    pub fn get_keywords(&self) -> Vec<String> {
+        self.get("keyword")
+            .or_else(|| self.get("keywords"))
+            .map(|v| match v {
+                QueryValue::Single(s) => s.split(',').map(|k| k.trim().to_string()).collect(),
+                QueryValue::Multi(vec) => vec.iter().map(|k| k.trim().to_string()).collect(),
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_translation(&self) -> Option<TranslationParams> {
+        self.get_first("translation_language")
+            .and_then(|lang| Language::from_str(lang.trim()).ok())
+            .map(|target_language| TranslationParams { target_language })
+    }
+
+    /// Removes `encoding=opus`, which describes the client-to-proxy leg only.
+    /// Providers behind the proxy always receive decoded PCM.
+    pub fn take_opus_encoding(&mut self) -> bool {
+        let is_opus = self
+            .get_first("encoding")
+            .and_then(|e| AudioEncoding::from_str(e).ok())
+            == Some(AudioEncoding::Opus);
+
+        if is_opus {
+            self.remove("encoding");
+        }
+        is_opus
+    }
+
+    pub fn get_capability_requirements(&self) -> CapabilityRequirements {
+        CapabilityRequirements {
+            diarization: self.get_first("diarize") == Some("true"),
+            multichannel: self
+                .get_first("channels")
+                .and_then(|c| c.parse::<u8>().ok())
+                .is_some_and(|c| c > 1),
+            keywords: self.get_keywords().len(),
+            translation: self.get_translation().is_some(),
+            sample_rate: self.get_first("sample_rate").and_then(|r| r.parse().ok()),
+            encoding: self
+                .get_first("encoding")
+                .and_then(|e| AudioEncoding::from_str(e).ok()),
+        }
+    }
 }
 
 impl Deref for QueryParams {
@@ -257,6 +305,60 @@ mod tests {
         assert_eq!(languages[1].region(), Some("KR"));
     }
 
+    #[test]
+    fn get_keywords_single_and_multi() {
+        let params = parse_query("?keyword=Hyprnote,%20Owhisper");
+        assert_eq!(params.get_keywords(), vec!["Hyprnote", "Owhisper"]);
+
+        let params = parse_query("?keywords=a&keywords=b&keywords=c");
+        assert_eq!(params.get_keywords(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn get_capability_requirements() {
+        let params = parse_query(
+            "?channels=2&sample_rate=16000&diarize=true&keyword=a&keyword=b&encoding=linear16",
+        );
+        let req = params.get_capability_requirements();
+        assert!(req.multichannel);
+        assert!(req.diarization);
+        assert_eq!(req.keywords, 2);
+        assert_eq!(req.sample_rate, Some(16000));
+        assert_eq!(req.encoding, Some(AudioEncoding::Linear16));
+        assert!(!req.translation);
+
+        let req = parse_query("?translation_language=es").get_capability_requirements();
+        assert!(req.translation);
+
+        let req = parse_query("?channels=1").get_capability_requirements();
+        assert_eq!(req, CapabilityRequirements::default());
+    }
+
+    #[test]
+    fn take_opus_encoding() {
+        let mut params = parse_query("?encoding=opus&sample_rate=16000");
+        assert!(params.take_opus_encoding());
+        assert!(params.get("encoding").is_none());
+        assert_eq!(params.get_first("sample_rate"), Some("16000"));
+
+        let mut params = parse_query("?encoding=linear16");
+        assert!(!params.take_opus_encoding());
+        assert_eq!(params.get_first("encoding"), Some("linear16"));
+    }
+
+    #[test]
+    fn get_translation() {
+        let translation = parse_query("?translation_language=de").get_translation();
+        assert_eq!(translation.unwrap().target_language.iso639(), ISO639::De);
+
+        assert!(
+            parse_query("?translation_language=invalid")
+                .get_translation()
+                .is_none()
+        );
+        assert!(parse_query("?language=en").get_translation().is_none());
+    }
+
     #[test]
     fn get_languages_invalid_ignored() {
         let params = parse_query("?language=en,invalid,ko");
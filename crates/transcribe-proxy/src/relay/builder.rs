@@ -6,10 +6,13 @@ use owhisper_client::Auth;
 pub use tokio_tungstenite::tungstenite::ClientRequestBuilder;
 
 use super::handler::WebSocketProxy;
-use super::types::{FirstMessageTransformer, InitialMessage, OnCloseCallback, ResponseTransformer};
+use super::types::{
+    CloseStats, FirstMessageTransformer, InitialMessage, OnCloseCallback, OpusInput, RelayOptions,
+    ResponseTransformer,
+};
 use crate::config::DEFAULT_CONNECT_TIMEOUT_MS;
 use crate::provider_selector::SelectedProvider;
-use crate::query_params::QueryParams;
+use crate::query_params::{QueryParams, QueryValue};
 use crate::upstream_url::UpstreamUrlBuilder;
 
 pub struct NoUpstream;
@@ -40,6 +43,26 @@ impl HasHeaders for WithUrlComponents {
     }
 }
 
+pub(crate) trait HasQueryParams {
+    fn append_query_param(&mut self, key: &str, value: &str);
+}
+
+impl HasQueryParams for WithUrl {
+    fn append_query_param(&mut self, key: &str, value: &str) {
+        if let Ok(mut url) = url::Url::parse(&self.url) {
+            url.query_pairs_mut().append_pair(key, value);
+            self.url = url.to_string();
+        }
+    }
+}
+
+impl HasQueryParams for WithUrlComponents {
+    fn append_query_param(&mut self, key: &str, value: &str) {
+        self.client_params
+            .insert(key.to_string(), QueryValue::Single(value.to_string()));
+    }
+}
+
 pub struct WebSocketProxyBuilder<S = NoUpstream> {
     state: S,
     control_message_types: HashSet<&'static str>,
@@ -47,6 +70,7 @@ pub struct WebSocketProxyBuilder<S = NoUpstream> {
     initial_message: Option<InitialMessage>,
     response_transformer: Option<ResponseTransformer>,
     connect_timeout: Duration,
+    opus_input: Option<OpusInput>,
     on_close: Option<OnCloseCallback>,
 }
 
@@ -59,6 +83,7 @@ impl Default for WebSocketProxyBuilder<NoUpstream> {
             initial_message: None,
             response_transformer: None,
             connect_timeout: Duration::from_millis(DEFAULT_CONNECT_TIMEOUT_MS),
+            opus_input: None,
             on_close: None,
         }
     }
@@ -73,33 +98,29 @@ impl<S> WebSocketProxyBuilder<S> {
             initial_message: self.initial_message,
             response_transformer: self.response_transformer,
             connect_timeout: self.connect_timeout,
+            opus_input: self.opus_input,
             on_close: self.on_close,
         }
     }
 
-    fn build_from(
-        request: ClientRequestBuilder,
-        control_message_types: HashSet<&'static str>,
-        transform_first_message: Option<FirstMessageTransformer>,
-        initial_message: Option<InitialMessage>,
-        response_transformer: Option<ResponseTransformer>,
-        connect_timeout: Duration,
-        on_close: Option<OnCloseCallback>,
-    ) -> WebSocketProxy {
-        let control_message_types = if control_message_types.is_empty() {
+    fn build_from(self, request: ClientRequestBuilder) -> WebSocketProxy {
+        let control_message_types = if self.control_message_types.is_empty() {
             None
         } else {
-            Some(Arc::new(control_message_types))
+            Some(Arc::new(self.control_message_types))
         };
 
         WebSocketProxy::new(
             request,
-            control_message_types,
-            transform_first_message,
-            initial_message,
-            response_transformer,
-            connect_timeout,
-            on_close,
+            self.connect_timeout,
+            RelayOptions {
+                control_message_types,
+                transform_first_message: self.transform_first_message,
+                initial_message: self.initial_message,
+                response_transformer: self.response_transformer,
+                opus_input: self.opus_input,
+                on_close: self.on_close,
+            },
         )
     }
 
@@ -134,13 +155,19 @@ impl<S> WebSocketProxyBuilder<S> {
         self
     }
 
+    /// Decodes the client's binary frames from Opus before forwarding them.
+    pub fn opus_input(mut self, input: OpusInput) -> Self {
+        self.opus_input = Some(input);
+        self
+    }
+
     pub fn on_close<F, Fut>(mut self, callback: F) -> Self
     where
-        F: Fn(Duration) -> Fut + Send + Sync + 'static,
+        F: Fn(CloseStats) -> Fut + Send + Sync + 'static,
         Fut: std::future::Future<Output = ()> + Send + 'static,
     {
-        self.on_close = Some(Arc::new(move |duration| {
-            Box::pin(callback(duration))
+        self.on_close = Some(Arc::new(move |stats| {
+            Box::pin(callback(stats))
                 as std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>
         }));
         self
@@ -167,6 +194,14 @@ impl<S: HasHeaders> WebSocketProxyBuilder<S> {
         self.state.headers_mut().extend(new_headers);
         self
     }
+}
+
+#[allow(private_bounds)]
+impl<S: HasHeaders + HasQueryParams> WebSocketProxyBuilder<S> {
+    pub fn query_param(mut self, key: &str, value: &str) -> Self {
+        self.state.append_query_param(key, value);
+        self
+    }
 
     pub fn apply_auth(self, selected: &SelectedProvider) -> Self {
         let provider = selected.provider();
@@ -183,6 +218,10 @@ impl<S: HasHeaders> WebSocketProxyBuilder<S> {
                 self.transform_first_message(move |msg| auth.transform_first_message(msg, &api_key))
             }
             Auth::SessionInit { .. } => self,
+            Auth::QueryParam { .. } => match provider.build_auth_query_param(api_key) {
+                Some((name, value)) => self.query_param(name, &value),
+                None => self,
+            },
         }
     }
 }
@@ -196,25 +235,17 @@ impl WebSocketProxyBuilder<WithUrl> {
             .map_err(|e| crate::ProxyError::InvalidRequest(format!("{}", e)))?;
 
         let mut request = ClientRequestBuilder::new(uri);
-        for (key, value) in self.state.headers {
-            request = request.with_header(&key, &value);
+        for (key, value) in &self.state.headers {
+            request = request.with_header(key, value);
         }
 
-        Ok(Self::build_from(
-            request,
-            self.control_message_types,
-            self.transform_first_message,
-            self.initial_message,
-            self.response_transformer,
-            self.connect_timeout,
-            self.on_close,
-        ))
+        Ok(self.build_from(request))
     }
 }
 
 impl WebSocketProxyBuilder<WithUrlComponents> {
     pub fn build(self) -> Result<WebSocketProxy, crate::ProxyError> {
-        let url = UpstreamUrlBuilder::new(self.state.base_url)
+        let url = UpstreamUrlBuilder::new(self.state.base_url.clone())
             .default_params(&self.state.default_params)
             .client_params(&self.state.client_params)
             .build();
@@ -225,19 +256,11 @@ impl WebSocketProxyBuilder<WithUrlComponents> {
             .map_err(|e| crate::ProxyError::InvalidRequest(format!("{}", e)))?;
 
         let mut request = ClientRequestBuilder::new(uri);
-        for (key, value) in self.state.headers {
-            request = request.with_header(&key, &value);
+        for (key, value) in &self.state.headers {
+            request = request.with_header(key, value);
         }
 
-        Ok(Self::build_from(
-            request,
-            self.control_message_types,
-            self.transform_first_message,
-            self.initial_message,
-            self.response_transformer,
-            self.connect_timeout,
-            self.on_close,
-        ))
+        Ok(self.build_from(request))
     }
 }
 
@@ -255,6 +278,7 @@ mod tests {
             Duration::from_millis(DEFAULT_CONNECT_TIMEOUT_MS)
         );
         assert!(builder.on_close.is_none());
+        assert!(builder.opus_input.is_none());
     }
 
     #[test]
@@ -277,6 +301,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_param_with_url() {
+        let builder = WebSocketProxyBuilder::default()
+            .upstream_url("wss://api.example.com/listen?language=en")
+            .query_param("access_token", "secret");
+
+        assert_eq!(
+            builder.state.url,
+            "wss://api.example.com/listen?language=en&access_token=secret"
+        );
+        assert!(builder.state.headers.is_empty());
+    }
+
     #[test]
     fn test_multiple_headers() {
         let builder = WebSocketProxyBuilder::default()
@@ -537,4 +574,17 @@ mod tests {
         assert!(builder.response_transformer.is_some());
         assert_eq!(builder.connect_timeout, Duration::from_secs(10));
     }
+
+    #[test]
+    fn test_opus_input() {
+        let input = OpusInput {
+            sample_rate: 16000,
+            channels: 2,
+        };
+        let builder = WebSocketProxyBuilder::default()
+            .opus_input(input)
+            .upstream_url("wss://api.example.com/listen");
+
+        assert_eq!(builder.opus_input, Some(input));
+    }
 }
@@ -17,40 +17,28 @@ use owhisper_client::Provider;
 use super::builder::WebSocketProxyBuilder;
 use super::pending::{FlushError, PendingState, QueuedPayload};
 use super::types::{
-    ClientReceiver, ClientSender, ControlMessageTypes, DEFAULT_CLOSE_CODE, FirstMessageTransformer,
-    InitialMessage, OnCloseCallback, ResponseTransformer, UpstreamReceiver, UpstreamSender,
-    convert, is_control_message,
+    AudioBytes, ClientReceiver, ClientSender, CloseStats, ControlMessageTypes, DEFAULT_CLOSE_CODE,
+    RelayOptions, ResponseTransformer, UpstreamReceiver, UpstreamSender, convert,
+    is_control_message,
 };
 
 #[derive(Clone)]
 pub struct WebSocketProxy {
     upstream_request: ClientRequestBuilder,
-    control_message_types: Option<ControlMessageTypes>,
-    transform_first_message: Option<FirstMessageTransformer>,
-    initial_message: Option<InitialMessage>,
-    response_transformer: Option<ResponseTransformer>,
     connect_timeout: Duration,
-    on_close: Option<OnCloseCallback>,
+    options: RelayOptions,
 }
 
 impl WebSocketProxy {
     pub(crate) fn new(
         upstream_request: ClientRequestBuilder,
-        control_message_types: Option<ControlMessageTypes>,
-        transform_first_message: Option<FirstMessageTransformer>,
-        initial_message: Option<InitialMessage>,
-        response_transformer: Option<ResponseTransformer>,
         connect_timeout: Duration,
-        on_close: Option<OnCloseCallback>,
+        options: RelayOptions,
     ) -> Self {
         Self {
             upstream_request,
-            control_message_types,
-            transform_first_message,
-            initial_message,
-            response_transformer,
             connect_timeout,
-            on_close,
+            options,
         }
     }
 
@@ -81,16 +69,7 @@ impl WebSocketProxy {
     pub async fn handle(&self, client_socket: WebSocket) -> Result<(), crate::ProxyError> {
         let upstream_stream = self.connect_upstream().await?;
 
-        Self::run_proxy_loop(
-            client_socket,
-            upstream_stream,
-            self.control_message_types.clone(),
-            self.transform_first_message.clone(),
-            self.initial_message.clone(),
-            self.response_transformer.clone(),
-            self.on_close.clone(),
-        )
-        .await;
+        Self::run_proxy_loop(client_socket, upstream_stream, &self.options).await;
 
         Ok(())
     }
@@ -116,11 +95,7 @@ impl WebSocketProxy {
     async fn run_proxy_loop(
         client_socket: WebSocket,
         upstream_stream: WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>,
-        control_message_types: Option<ControlMessageTypes>,
-        transform_first_message: Option<FirstMessageTransformer>,
-        initial_message: Option<InitialMessage>,
-        response_transformer: Option<ResponseTransformer>,
-        on_close: Option<OnCloseCallback>,
+        options: &RelayOptions,
     ) {
         let start_time = Instant::now();
 
@@ -135,9 +110,7 @@ impl WebSocketProxy {
             upstream_sender,
             shutdown_tx.clone(),
             shutdown_rx,
-            control_message_types,
-            transform_first_message,
-            initial_message,
+            options,
         );
 
         let upstream_to_client = Self::run_upstream_to_client(
@@ -145,18 +118,24 @@ impl WebSocketProxy {
             client_sender,
             shutdown_tx.clone(),
             shutdown_rx2,
-            response_transformer,
+            options.response_transformer.clone(),
         );
 
-        let _ = tokio::join!(client_to_upstream, upstream_to_client);
+        let (audio_bytes, _) = tokio::join!(client_to_upstream, upstream_to_client);
 
         let duration = start_time.elapsed();
-        if let Some(on_close) = on_close {
-            on_close(duration).await;
+        if let Some(on_close) = &options.on_close {
+            on_close(CloseStats {
+                duration,
+                audio_bytes,
+            })
+            .await;
         }
 
         tracing::info!(
             duration_secs = %duration.as_secs_f64(),
+            client_audio_bytes = %audio_bytes.client,
+            upstream_audio_bytes = %audio_bytes.upstream,
             "websocket_proxy_connection_closed"
         );
     }
@@ -208,13 +187,29 @@ impl WebSocketProxy {
         mut upstream_sender: UpstreamSender,
         shutdown_tx: tokio::sync::broadcast::Sender<(u16, String)>,
         mut shutdown_rx: tokio::sync::broadcast::Receiver<(u16, String)>,
-        control_types: Option<ControlMessageTypes>,
-        mut first_msg_transformer: Option<FirstMessageTransformer>,
-        initial_message: Option<InitialMessage>,
-    ) {
+        options: &RelayOptions,
+    ) -> AudioBytes {
+        let control_types = &options.control_message_types;
+        let mut first_msg_transformer = options.transform_first_message.clone();
         let mut pending = PendingState::default();
+        let mut audio_bytes = AudioBytes::default();
+
+        let mut opus_decoder = match options.opus_input {
+            Some(input) => {
+                match hypr_audio_opus::OpusDecoder::new(input.sample_rate, input.channels) {
+                    Ok(decoder) => Some(decoder),
+                    Err(e) => {
+                        tracing::error!(error = %e, "opus_decoder_init_failed");
+                        let _ = shutdown_tx
+                            .send((DEFAULT_CLOSE_CODE, "unsupported_audio_format".to_string()));
+                        return audio_bytes;
+                    }
+                }
+            }
+            None => None,
+        };
 
-        if let Some(msg) = initial_message {
+        if let Some(msg) = &options.initial_message {
             if let Err(e) = upstream_sender
                 .send(TungsteniteMessage::Text(msg.as_str().into()))
                 .await
@@ -222,7 +217,7 @@ impl WebSocketProxy {
                 tracing::error!(error = ?e, "initial_message_send_failed");
                 let _ =
                     shutdown_tx.send((DEFAULT_CLOSE_CODE, "initial_message_failed".to_string()));
-                return;
+                return audio_bytes;
             }
             tracing::debug!("initial_message_sent");
         }
@@ -266,7 +261,7 @@ impl WebSocketProxy {
                             };
                             let data = text_str.into_bytes();
 
-                            if Self::process_data_message(&mut pending, data, true, &control_types, &shutdown_tx, &mut upstream_sender).await {
+                            if Self::process_data_message(&mut pending, data, true, control_types, &shutdown_tx, &mut upstream_sender).await {
                                 break;
                             }
                         }
@@ -277,9 +272,20 @@ impl WebSocketProxy {
                             if first_msg_transformer.is_some() {
                                 tracing::debug!("binary_message_received_before_text_transform");
                             }
-                            let data = bytes.to_vec();
+                            audio_bytes.client += bytes.len() as u64;
+                            let data = match opus_decoder.as_mut() {
+                                Some(decoder) => match decoder.decode(&bytes) {
+                                    Ok(pcm) => pcm.to_vec(),
+                                    Err(e) => {
+                                        tracing::warn!(error = %e, payload_size_bytes = %bytes.len(), "opus_decode_failed");
+                                        continue;
+                                    }
+                                },
+                                None => bytes.to_vec(),
+                            };
+                            audio_bytes.upstream += data.len() as u64;
 
-                            if Self::process_data_message(&mut pending, data, false, &control_types, &shutdown_tx, &mut upstream_sender).await {
+                            if Self::process_data_message(&mut pending, data, false, control_types, &shutdown_tx, &mut upstream_sender).await {
                                 break;
                             }
                         }
@@ -308,6 +314,8 @@ impl WebSocketProxy {
                 }
             }
         }
+
+        audio_bytes
     }
 
     async fn run_upstream_to_client(
@@ -405,9 +413,16 @@ impl WebSocketProxy {
                             }
                         }
                         TungsteniteMessage::Close(frame) => {
-                            let (code, reason) = pending_error.take().unwrap_or_else(|| {
-                                convert::extract_tungstenite_close(frame, "upstream_closed")
+                            let close_error = frame.as_ref().and_then(|f| {
+                                Provider::detect_any_close_error(f.code.into(), &f.reason)
                             });
+                            let (code, reason) = pending_error
+                                .take()
+                                .or_else(|| close_error.map(|e| (e.to_ws_close_code(), e.message)))
+                                .unwrap_or_else(|| {
+                                    convert::extract_tungstenite_close(frame, "upstream_closed")
+                                });
+                            let _ = client_sender.send(convert::to_axum_close(code, reason.clone())).await;
                             let _ = shutdown_tx.send((code, reason));
                             break;
                         }
@@ -6,4 +6,5 @@ mod upstream_error;
 
 pub use builder::ClientRequestBuilder;
 pub use handler::WebSocketProxy;
+pub use types::{AudioBytes, CloseStats, OpusInput};
 pub use upstream_error::{UpstreamError, detect_upstream_error};
@@ -11,7 +11,7 @@ use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 pub const DEFAULT_CLOSE_CODE: u16 = 1011;
 
 pub type OnCloseCallback =
-    Arc<dyn Fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+    Arc<dyn Fn(CloseStats) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
 pub type ControlMessageTypes = Arc<HashSet<&'static str>>;
 pub type FirstMessageTransformer = Arc<dyn Fn(String) -> String + Send + Sync>;
 pub type InitialMessage = Arc<String>;
@@ -25,6 +25,38 @@ pub type UpstreamReceiver = SplitStream<WebSocketStream<MaybeTlsStream<tokio::ne
 pub type ClientSender = SplitSink<WebSocket, axum::extract::ws::Message>;
 pub type ClientReceiver = SplitStream<WebSocket>;
 
+/// Format of the binary frames the client sends, when it isn't raw PCM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpusInput {
+    pub sample_rate: u32,
+    pub channels: u8,
+}
+
+/// What a connection does to the messages it relays, set up by the builder.
+#[derive(Clone, Default)]
+pub struct RelayOptions {
+    pub control_message_types: Option<ControlMessageTypes>,
+    pub transform_first_message: Option<FirstMessageTransformer>,
+    pub initial_message: Option<InitialMessage>,
+    pub response_transformer: Option<ResponseTransformer>,
+    pub opus_input: Option<OpusInput>,
+    pub on_close: Option<OnCloseCallback>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AudioBytes {
+    /// Audio payload as received from the client.
+    pub client: u64,
+    /// Audio payload as forwarded to the provider, after decoding.
+    pub upstream: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CloseStats {
+    pub duration: Duration,
+    pub audio_bytes: AudioBytes,
+}
+
 #[derive(serde::Deserialize)]
 struct TypeOnly<'a> {
     #[serde(borrow, rename = "type")]
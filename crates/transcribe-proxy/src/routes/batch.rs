@@ -12,7 +12,7 @@ use backon::{ExponentialBuilder, Retryable};
 
 use owhisper_client::{
     AssemblyAIAdapter, BatchClient, DeepgramAdapter, ElevenLabsAdapter, GladiaAdapter,
-    OpenAIAdapter, Provider, SonioxAdapter,
+    OpenAIAdapter, Provider, RevAiAdapter, SonioxAdapter, SpeechmaticsAdapter,
 };
 use owhisper_interface::ListenParams;
 use owhisper_interface::batch::Response as BatchResponse;
@@ -293,6 +293,24 @@ async fn transcribe_with_provider(
                 .transcribe_file(file_path)
                 .await
         }
+        Provider::Speechmatics => {
+            BatchClient::<SpeechmaticsAdapter>::builder()
+                .api_base(api_base)
+                .api_key(api_key)
+                .params(params)
+                .build()
+                .transcribe_file(file_path)
+                .await
+        }
+        Provider::RevAi => {
+            BatchClient::<RevAiAdapter>::builder()
+                .api_base(api_base)
+                .api_key(api_key)
+                .params(params)
+                .build()
+                .transcribe_file(file_path)
+                .await
+        }
         Provider::Fireworks => {
             return Err(format!(
                 "{:?} does not support batch transcription",
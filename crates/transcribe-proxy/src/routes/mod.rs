@@ -1,5 +1,6 @@
 mod batch;
 pub mod streaming;
+mod upload;
 
 use std::sync::Arc;
 
@@ -41,7 +42,7 @@ impl AppState {
                 Err(_) => {
                     return Err((
                         StatusCode::BAD_REQUEST,
-                        format!("Invalid provider: {}. Supported providers: deepgram, soniox, assemblyai, gladia, elevenlabs, fireworks, openai", s)
+                        format!("Invalid provider: {}. Supported providers: deepgram, soniox, assemblyai, gladia, elevenlabs, speechmatics, revai, fireworks, openai", s)
                     ).into_response());
                 }
             },
@@ -78,11 +79,14 @@ impl AppState {
         })?;
 
         let languages = params.get_languages();
+        let requirements = params.get_capability_requirements();
         let available_providers = self.selector.available_providers();
-        let routed_provider = router.select_provider(&languages, &available_providers);
+        let routed_provider =
+            router.select_provider(&languages, &requirements, &available_providers);
 
         tracing::debug!(
             languages = ?languages,
+            requirements = ?requirements,
             available_providers = ?available_providers,
             routed_provider = ?routed_provider,
             "hyprnote_routing"
@@ -104,10 +108,11 @@ impl AppState {
         };
 
         let languages = params.get_languages();
+        let requirements = params.get_capability_requirements();
         let available_providers = self.selector.available_providers();
 
         router
-            .select_provider_chain(&languages, &available_providers)
+            .select_provider_chain(&languages, &requirements, &available_providers)
             .into_iter()
             .filter_map(|p| self.selector.select(Some(p)).ok())
             .collect()
@@ -138,6 +143,11 @@ pub fn router(config: SttProxyConfig) -> Router {
             .route("/", post(batch::handler))
             .route("/listen", get(streaming::handler))
             .route("/listen", post(batch::handler))
+            .route("/upload", post(upload::create))
+            .route("/upload/parts", post(upload::parts))
+            .route("/upload/complete", post(upload::complete))
+            .route("/upload/download", post(upload::download))
+            .route("/upload/delete", post(upload::delete))
             .with_state(state),
     )
 }
@@ -1,7 +1,7 @@
 use crate::config::SttProxyConfig;
 use crate::provider_selector::SelectedProvider;
 use crate::query_params::QueryParams;
-use crate::relay::WebSocketProxy;
+use crate::relay::{OpusInput, WebSocketProxy};
 
 pub enum ProxyBuildError {
     SessionInitFailed(String),
@@ -30,17 +30,28 @@ macro_rules! finalize_proxy_builder {
             super::AnalyticsContext {
                 fingerprint: None,
                 user_id: None,
-            }
+            },
+            None
         )
     };
-    ($builder:expr, $provider:expr, $config:expr, $analytics_ctx:expr) => {
+    ($builder:expr, $provider:expr, $config:expr, $analytics_ctx:expr, $opus_input:expr) => {{
+        let opus_input: Option<$crate::relay::OpusInput> = $opus_input;
+        let builder = match opus_input {
+            Some(input) => $builder.opus_input(input),
+            None => $builder,
+        };
+        let audio_encoding = match opus_input {
+            Some(_) => owhisper_client::AudioEncoding::Opus,
+            None => owhisper_client::AudioEncoding::Linear16,
+        };
+
         match &$config.analytics {
             Some(analytics) => {
                 let analytics = analytics.clone();
                 let provider_name = format!("{:?}", $provider).to_lowercase();
                 let analytics_ctx: super::AnalyticsContext = $analytics_ctx;
-                $builder
-                    .on_close(move |duration| {
+                builder
+                    .on_close(move |stats| {
                         let analytics = analytics.clone();
                         let provider_name = provider_name.clone();
                         let fingerprint = analytics_ctx.fingerprint.clone();
@@ -51,16 +62,19 @@ macro_rules! finalize_proxy_builder {
                                     fingerprint,
                                     user_id,
                                     provider: provider_name,
-                                    duration,
+                                    duration: stats.duration,
+                                    audio_encoding,
+                                    client_audio_bytes: stats.audio_bytes.client,
+                                    upstream_audio_bytes: stats.audio_bytes.upstream,
                                 })
                                 .await;
                         }
                     })
                     .build()
             }
-            None => $builder.build(),
+            None => builder.build(),
         }
-    };
+    }};
 }
 
 pub(super) use finalize_proxy_builder;
@@ -70,6 +84,7 @@ pub fn build_proxy_with_url(
     upstream_url: &str,
     config: &SttProxyConfig,
     analytics_ctx: super::AnalyticsContext,
+    opus_input: Option<OpusInput>,
 ) -> Result<WebSocketProxy, crate::ProxyError> {
     let provider = selected.provider();
     let builder = WebSocketProxy::builder()
@@ -78,5 +93,5 @@ pub fn build_proxy_with_url(
         .control_message_types(provider.control_message_types())
         .apply_auth(selected);
 
-    finalize_proxy_builder!(builder, provider, config, analytics_ctx)
+    finalize_proxy_builder!(builder, provider, config, analytics_ctx, opus_input)
 }
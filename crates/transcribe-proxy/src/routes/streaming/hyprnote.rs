@@ -2,14 +2,15 @@ use std::str::FromStr;
 
 use owhisper_client::{
     AdapterKind, AssemblyAIAdapter, Auth, DeepgramAdapter, DeepgramModel, ElevenLabsAdapter,
-    FireworksAdapter, GladiaAdapter, OpenAIAdapter, Provider, RealtimeSttAdapter, SonioxAdapter,
+    FireworksAdapter, GladiaAdapter, OpenAIAdapter, Provider, RealtimeSttAdapter, RevAiAdapter,
+    SonioxAdapter, SpeechmaticsAdapter,
 };
 use owhisper_interface::ListenParams;
 
 use crate::config::SttProxyConfig;
 use crate::provider_selector::SelectedProvider;
-use crate::query_params::{QueryParams, QueryValue};
-use crate::relay::WebSocketProxy;
+use crate::query_params::QueryParams;
+use crate::relay::{OpusInput, WebSocketProxy};
 use crate::routes::AppState;
 
 use super::AnalyticsContext;
@@ -22,14 +23,7 @@ fn build_listen_params(params: &QueryParams) -> ListenParams {
     let sample_rate: u32 = parse_param(params, "sample_rate", 16000);
     let channels: u8 = parse_param(params, "channels", 1);
 
-    let keywords: Vec<String> = params
-        .get("keyword")
-        .or_else(|| params.get("keywords"))
-        .map(|v| match v {
-            QueryValue::Single(s) => s.split(',').map(|k| k.trim().to_string()).collect(),
-            QueryValue::Multi(vec) => vec.iter().map(|k| k.trim().to_string()).collect(),
-        })
-        .unwrap_or_default();
+    let keywords = params.get_keywords();
 
     ListenParams {
         model,
@@ -37,6 +31,7 @@ fn build_listen_params(params: &QueryParams) -> ListenParams {
         sample_rate,
         channels,
         keywords,
+        translation: params.get_translation(),
         ..Default::default()
     }
 }
@@ -55,6 +50,8 @@ fn build_upstream_url_with_adapter(
         Provider::OpenAI => OpenAIAdapter.build_ws_url(api_base, params, channels),
         Provider::Gladia => GladiaAdapter.build_ws_url(api_base, params, channels),
         Provider::ElevenLabs => ElevenLabsAdapter.build_ws_url(api_base, params, channels),
+        Provider::Speechmatics => SpeechmaticsAdapter.build_ws_url(api_base, params, channels),
+        Provider::RevAi => RevAiAdapter.build_ws_url(api_base, params, channels),
     }
 }
 
@@ -72,6 +69,8 @@ fn build_initial_message_with_adapter(
         Provider::OpenAI => OpenAIAdapter.initial_message(api_key, params, channels),
         Provider::Gladia => GladiaAdapter.initial_message(api_key, params, channels),
         Provider::ElevenLabs => ElevenLabsAdapter.initial_message(api_key, params, channels),
+        Provider::Speechmatics => SpeechmaticsAdapter.initial_message(api_key, params, channels),
+        Provider::RevAi => RevAiAdapter.initial_message(api_key, params, channels),
     };
 
     msg.and_then(|m| match m {
@@ -92,6 +91,8 @@ fn build_response_transformer(
             Provider::OpenAI => OpenAIAdapter.parse_response(raw),
             Provider::Gladia => GladiaAdapter.parse_response(raw),
             Provider::ElevenLabs => ElevenLabsAdapter.parse_response(raw),
+            Provider::Speechmatics => SpeechmaticsAdapter.parse_response(raw),
+            Provider::RevAi => RevAiAdapter.parse_response(raw),
         };
 
         if responses.is_empty() {
@@ -121,6 +122,7 @@ fn build_proxy_with_adapter(
     client_params: &QueryParams,
     config: &SttProxyConfig,
     analytics_ctx: AnalyticsContext,
+    opus_input: Option<OpusInput>,
 ) -> Result<WebSocketProxy, crate::ProxyError> {
     let provider = selected.provider();
     let mut listen_params = build_listen_params(client_params);
@@ -140,6 +142,11 @@ fn build_proxy_with_adapter(
         listen_params.model = Some(model.to_string());
     }
 
+    listen_params.keywords = AdapterKind::from(provider).boost_keywords(
+        listen_params.model.as_deref(),
+        listen_params.keywords.iter().map(String::as_str),
+    );
+
     let api_base = provider.default_api_base();
     let upstream_url =
         build_upstream_url_with_adapter(provider, api_base, &listen_params, channels);
@@ -162,7 +169,7 @@ fn build_proxy_with_adapter(
         builder = builder.initial_message(msg);
     }
 
-    finalize_proxy_builder!(builder, provider, config, analytics_ctx)
+    finalize_proxy_builder!(builder, provider, config, analytics_ctx, opus_input)
 }
 
 fn build_proxy_with_url_and_transformer(
@@ -170,6 +177,7 @@ fn build_proxy_with_url_and_transformer(
     upstream_url: &str,
     config: &SttProxyConfig,
     analytics_ctx: AnalyticsContext,
+    opus_input: Option<OpusInput>,
 ) -> Result<WebSocketProxy, crate::ProxyError> {
     let provider = selected.provider();
     let builder = WebSocketProxy::builder()
@@ -179,7 +187,7 @@ fn build_proxy_with_url_and_transformer(
         .response_transformer(build_response_transformer(provider))
         .apply_auth(selected);
 
-    finalize_proxy_builder!(builder, provider, config, analytics_ctx)
+    finalize_proxy_builder!(builder, provider, config, analytics_ctx, opus_input)
 }
 
 pub async fn build_proxy(
@@ -187,6 +195,7 @@ pub async fn build_proxy(
     selected: &SelectedProvider,
     params: &QueryParams,
     analytics_ctx: AnalyticsContext,
+    opus_input: Option<OpusInput>,
 ) -> Result<WebSocketProxy, ProxyBuildError> {
     let provider = selected.provider();
 
@@ -196,6 +205,7 @@ pub async fn build_proxy(
             custom_url,
             &state.config,
             analytics_ctx,
+            opus_input,
         )?);
     }
 
@@ -204,8 +214,13 @@ pub async fn build_proxy(
             let url = init_session(state, selected, header_name, params)
                 .await
                 .map_err(ProxyBuildError::SessionInitFailed)?;
-            let proxy =
-                build_proxy_with_url_and_transformer(selected, &url, &state.config, analytics_ctx)?;
+            let proxy = build_proxy_with_url_and_transformer(
+                selected,
+                &url,
+                &state.config,
+                analytics_ctx,
+                opus_input,
+            )?;
             Ok(proxy)
         }
         _ => Ok(build_proxy_with_adapter(
@@ -213,6 +228,7 @@ pub async fn build_proxy(
             params,
             &state.config,
             analytics_ctx,
+            opus_input,
         )?),
     }
 }
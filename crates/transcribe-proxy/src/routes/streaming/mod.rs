@@ -16,6 +16,7 @@ use axum::{
 
 use crate::hyprnote_routing::should_use_hyprnote_routing;
 use crate::query_params::{QueryParams, QueryValue};
+use crate::relay::OpusInput;
 
 use super::AppState;
 use common::{ProxyBuildError, parse_param};
@@ -57,6 +58,11 @@ pub async fn handler(
 ) -> Response {
     let is_hyprnote_routing = should_use_hyprnote_routing(params.get_first("provider"));
 
+    let opus_input = params.take_opus_encoding().then(|| OpusInput {
+        sample_rate: parse_param(&params, "sample_rate", 16000),
+        channels: parse_param(&params, "channels", 1),
+    });
+
     let selected = match state.resolve_provider(&mut params) {
         Ok(v) => v,
         Err(resp) => return resp,
@@ -105,13 +111,14 @@ pub async fn handler(
         ctx.insert("channels".into(), channels.into());
         ctx.insert("keywords_count".into(), keywords.into());
         ctx.insert("languages_count".into(), languages.len().into());
+        ctx.insert("opus".into(), opus_input.is_some().into());
         scope.set_context("stt_request", sentry::protocol::Context::Other(ctx));
     });
 
     let proxy = if is_hyprnote_routing {
-        hyprnote::build_proxy(&state, &selected, &params, analytics_ctx).await
+        hyprnote::build_proxy(&state, &selected, &params, analytics_ctx, opus_input).await
     } else {
-        passthrough::build_proxy(&state, &selected, &params, analytics_ctx).await
+        passthrough::build_proxy(&state, &selected, &params, analytics_ctx, opus_input).await
     };
 
     let proxy = match proxy {
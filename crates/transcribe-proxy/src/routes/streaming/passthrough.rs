@@ -1,20 +1,32 @@
-use owhisper_client::Auth;
+use owhisper_client::{Auth, Provider};
 
 use crate::config::SttProxyConfig;
 use crate::provider_selector::SelectedProvider;
 use crate::query_params::{QueryParams, QueryValue};
-use crate::relay::WebSocketProxy;
+use crate::relay::{OpusInput, WebSocketProxy};
 use crate::routes::AppState;
 
 use super::AnalyticsContext;
 use super::common::{ProxyBuildError, build_proxy_with_url, finalize_proxy_builder};
 use super::session::init_session;
 
+// Clients streaming Opus swap their `encoding` param for `opus`. The relay
+// forwards decoded PCM, so providers that read the format from the URL need
+// their PCM value back.
+fn pcm_encoding_param(provider: Provider) -> Option<&'static str> {
+    match provider {
+        Provider::Deepgram => Some("linear16"),
+        Provider::AssemblyAI => Some("pcm_s16le"),
+        _ => None,
+    }
+}
+
 fn build_relay(
     selected: &SelectedProvider,
     client_params: &QueryParams,
     config: &SttProxyConfig,
     analytics_ctx: AnalyticsContext,
+    opus_input: Option<OpusInput>,
 ) -> Result<WebSocketProxy, crate::ProxyError> {
     let provider = selected.provider();
     let ws_url = provider.default_ws_url();
@@ -37,6 +49,12 @@ fn build_relay(
                 }
             }
         }
+
+        if opus_input.is_some()
+            && let Some(encoding) = pcm_encoding_param(provider)
+        {
+            query.append_pair("encoding", encoding);
+        }
     }
 
     let builder = WebSocketProxy::builder()
@@ -45,7 +63,7 @@ fn build_relay(
         .control_message_types(provider.control_message_types())
         .apply_auth(selected);
 
-    finalize_proxy_builder!(builder, provider, config, analytics_ctx)
+    finalize_proxy_builder!(builder, provider, config, analytics_ctx, opus_input)
 }
 
 pub async fn build_proxy(
@@ -53,6 +71,7 @@ pub async fn build_proxy(
     selected: &SelectedProvider,
     params: &QueryParams,
     analytics_ctx: AnalyticsContext,
+    opus_input: Option<OpusInput>,
 ) -> Result<WebSocketProxy, ProxyBuildError> {
     let provider = selected.provider();
 
@@ -62,6 +81,7 @@ pub async fn build_proxy(
             custom_url,
             &state.config,
             analytics_ctx,
+            opus_input,
         )?);
     }
 
@@ -75,8 +95,27 @@ pub async fn build_proxy(
                 &url,
                 &state.config,
                 analytics_ctx,
+                opus_input,
             )?)
         }
-        _ => Ok(build_relay(selected, params, &state.config, analytics_ctx)?),
+        _ => Ok(build_relay(
+            selected,
+            params,
+            &state.config,
+            analytics_ctx,
+            opus_input,
+        )?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcm_encoding_param() {
+        assert_eq!(pcm_encoding_param(Provider::Deepgram), Some("linear16"));
+        assert_eq!(pcm_encoding_param(Provider::AssemblyAI), Some("pcm_s16le"));
+        assert_eq!(pcm_encoding_param(Provider::Soniox), None);
     }
 }
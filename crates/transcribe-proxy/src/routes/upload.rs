@@ -0,0 +1,130 @@
+use axum::{
+    Extension, Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use owhisper_client::{
+    CompleteUploadRequest, CreatedUpload, DownloadUrl, PartUrls, PartUrlsRequest, UploadKey,
+    UploadTarget,
+};
+
+use hypr_analytics::AuthenticatedUserId;
+
+use super::AppState;
+
+// Large-file uploads go straight from the desktop to storage through URLs
+// presigned here, scoped to the signed-in user's folder.
+fn user_storage<'a>(
+    state: &'a AppState,
+    user_id: Option<&AuthenticatedUserId>,
+) -> Result<hypr_s3::UserClient<'a>, (StatusCode, &'static str)> {
+    let storage = state.config.storage.as_ref().ok_or_else(|| {
+        tracing::warn!("upload_storage_not_configured");
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "upload storage is not configured",
+        )
+    })?;
+    let user_id = user_id.ok_or((StatusCode::UNAUTHORIZED, "sign-in required"))?;
+
+    Ok(storage.for_user(user_id.0.clone()))
+}
+
+fn storage_failed(error: owhisper_client::Error) -> Response {
+    tracing::error!(error = %error, "upload_storage_failed");
+    (
+        StatusCode::BAD_GATEWAY,
+        Json(serde_json::json!({
+            "error": "storage_failed",
+            "detail": error.to_string()
+        })),
+    )
+        .into_response()
+}
+
+pub async fn create(
+    State(state): State<AppState>,
+    user_id: Option<Extension<AuthenticatedUserId>>,
+    Json(body): Json<UploadKey>,
+) -> Response {
+    let storage = match user_storage(&state, user_id.as_deref()) {
+        Ok(storage) => storage,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    match storage.create_upload(&body.key).await {
+        Ok(upload_id) => Json(CreatedUpload { upload_id }).into_response(),
+        Err(e) => storage_failed(e),
+    }
+}
+
+pub async fn parts(
+    State(state): State<AppState>,
+    user_id: Option<Extension<AuthenticatedUserId>>,
+    Json(body): Json<PartUrlsRequest>,
+) -> Response {
+    let storage = match user_storage(&state, user_id.as_deref()) {
+        Ok(storage) => storage,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    match storage
+        .part_urls(&body.key, &body.upload_id, body.num_parts)
+        .await
+    {
+        Ok(urls) => Json(PartUrls { urls }).into_response(),
+        Err(e) => storage_failed(e),
+    }
+}
+
+pub async fn complete(
+    State(state): State<AppState>,
+    user_id: Option<Extension<AuthenticatedUserId>>,
+    Json(body): Json<CompleteUploadRequest>,
+) -> Response {
+    let storage = match user_storage(&state, user_id.as_deref()) {
+        Ok(storage) => storage,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    match storage
+        .complete_upload(&body.key, &body.upload_id, body.etags)
+        .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => storage_failed(e),
+    }
+}
+
+pub async fn download(
+    State(state): State<AppState>,
+    user_id: Option<Extension<AuthenticatedUserId>>,
+    Json(body): Json<UploadKey>,
+) -> Response {
+    let storage = match user_storage(&state, user_id.as_deref()) {
+        Ok(storage) => storage,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    match storage.download_url(&body.key).await {
+        Ok(url) => Json(DownloadUrl { url }).into_response(),
+        Err(e) => storage_failed(e),
+    }
+}
+
+pub async fn delete(
+    State(state): State<AppState>,
+    user_id: Option<Extension<AuthenticatedUserId>>,
+    Json(body): Json<UploadKey>,
+) -> Response {
+    let storage = match user_storage(&state, user_id.as_deref()) {
+        Ok(storage) => storage,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    match UploadTarget::delete(&storage, &body.key).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => storage_failed(e),
+    }
+}
@@ -77,6 +77,8 @@ pub fn env_with_provider(provider: Provider, api_key: String) -> transcribe_prox
         Provider::OpenAI => env.openai_api_key = Some(api_key),
         Provider::Gladia => env.gladia_api_key = Some(api_key),
         Provider::ElevenLabs => env.elevenlabs_api_key = Some(api_key),
+        Provider::Speechmatics => env.speechmatics_api_key = Some(api_key),
+        Provider::RevAi => env.revai_api_key = Some(api_key),
     }
     env
 }
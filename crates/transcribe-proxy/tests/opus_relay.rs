@@ -0,0 +1,97 @@
+mod common;
+use common::*;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use hypr_audio_opus::OpusEncoder;
+use owhisper_client::{AudioEncoding, Provider};
+use transcribe_proxy::SttProxyConfig;
+
+const SAMPLE_RATE: u32 = 16000;
+
+// Accepts a single upstream connection and reports the binary payload sizes it
+// received.
+async fn start_recording_upstream() -> (String, tokio::sync::oneshot::Receiver<Vec<usize>>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        let (_, mut receiver) = ws.split();
+        let mut sizes = Vec::new();
+        while let Some(Ok(msg)) = receiver.next().await {
+            match msg {
+                Message::Binary(data) => sizes.push(data.len()),
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+        let _ = tx.send(sizes);
+    });
+
+    (format!("ws://{}", addr), rx)
+}
+
+#[tokio::test]
+async fn test_opus_frames_are_decoded_before_upstream() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let (upstream_url, upstream_rx) = start_recording_upstream().await;
+
+    let mock_analytics = MockAnalytics::default();
+    let events = mock_analytics.events.clone();
+
+    let env = env_with_provider(Provider::Deepgram, "mock-api-key".to_string());
+    let config = SttProxyConfig::new(&env)
+        .with_default_provider(Provider::Deepgram)
+        .with_upstream_url(Provider::Deepgram, &upstream_url)
+        .with_analytics(Arc::new(mock_analytics));
+    let proxy_addr = start_server(config).await;
+
+    let url = format!(
+        "ws://{}/listen?provider=deepgram&model=nova-3&encoding=opus&sample_rate={}&channels=1",
+        proxy_addr, SAMPLE_RATE
+    );
+    let (ws_stream, _) = connect_async(&url).await.unwrap();
+    let (mut sender, _receiver) = ws_stream.split();
+
+    // 200ms of a quiet tone, in 20ms packets.
+    let pcm: Vec<u8> = (0..SAMPLE_RATE / 5)
+        .flat_map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            (((t * 440.0 * std::f32::consts::TAU).sin() * 3000.0) as i16).to_le_bytes()
+        })
+        .collect();
+    let mut encoder = OpusEncoder::new(SAMPLE_RATE, 1).unwrap();
+    let packets = encoder.encode(&pcm).unwrap();
+    assert_eq!(packets.len(), 10);
+
+    let client_bytes: usize = packets.iter().map(|p| p.len()).sum();
+    for packet in packets {
+        sender.send(Message::Binary(packet)).await.unwrap();
+    }
+    sender.send(Message::Close(None)).await.unwrap();
+
+    let sizes = tokio::time::timeout(Duration::from_secs(5), upstream_rx)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(sizes, vec![640; 10]);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let captured_events = events.lock().unwrap();
+    assert_eq!(captured_events.len(), 1);
+
+    let event = &captured_events[0];
+    assert_eq!(event.audio_encoding, AudioEncoding::Opus);
+    assert_eq!(event.client_audio_bytes, client_bytes as u64);
+    assert_eq!(event.upstream_audio_bytes, 6400);
+}
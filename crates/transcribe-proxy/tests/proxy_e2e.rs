@@ -258,6 +258,12 @@ mod passthrough {
             owhisper_client::ElevenLabsAdapter,
             Provider::ElevenLabs
         );
+        passthrough_live_test!(
+            speechmatics,
+            owhisper_client::SpeechmaticsAdapter,
+            Provider::Speechmatics
+        );
+        passthrough_live_test!(revai, owhisper_client::RevAiAdapter, Provider::RevAi);
     }
 
     pub mod batch {
@@ -270,6 +276,8 @@ mod passthrough {
         passthrough_batch_test!(fireworks, Provider::Fireworks);
         passthrough_batch_test!(openai, Provider::OpenAI);
         passthrough_batch_test!(elevenlabs, Provider::ElevenLabs);
+        passthrough_batch_test!(speechmatics, Provider::Speechmatics);
+        passthrough_batch_test!(revai, Provider::RevAi);
     }
 }
 
@@ -286,6 +294,8 @@ mod hyprnote {
         hyprnote_live_test!(fireworks, Provider::Fireworks);
         hyprnote_live_test!(openai, Provider::OpenAI);
         hyprnote_live_test!(elevenlabs, Provider::ElevenLabs);
+        hyprnote_live_test!(speechmatics, Provider::Speechmatics);
+        hyprnote_live_test!(revai, Provider::RevAi);
     }
 
     pub mod batch {
@@ -298,5 +308,7 @@ mod hyprnote {
         hyprnote_batch_test!(fireworks, Provider::Fireworks);
         hyprnote_batch_test!(openai, Provider::OpenAI);
         hyprnote_batch_test!(elevenlabs, Provider::ElevenLabs);
+        hyprnote_batch_test!(speechmatics, Provider::Speechmatics);
+        hyprnote_batch_test!(revai, Provider::RevAi);
     }
 }
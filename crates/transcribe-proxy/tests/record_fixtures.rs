@@ -138,4 +138,10 @@ mod record {
         owhisper_client::ElevenLabsAdapter,
         Provider::ElevenLabs
     );
+    record_fixture_test!(
+        speechmatics,
+        owhisper_client::SpeechmaticsAdapter,
+        Provider::Speechmatics
+    );
+    record_fixture_test!(revai, owhisper_client::RevAiAdapter, Provider::RevAi);
 }
@@ -7,7 +7,7 @@ use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::Message;
 
 use common::{
-    MessageKind, MockUpstreamConfig, load_fixture, start_mock_server_with_config,
+    MessageKind, MockUpstreamConfig, WsMessage, load_fixture, start_mock_server_with_config,
     start_server_with_upstream_url,
 };
 use owhisper_client::Provider;
@@ -203,6 +203,103 @@ async fn test_soniox_error_replay() {
     }
 }
 
+#[tokio::test]
+async fn test_speechmatics_normal_transcription_replay() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let recording = load_fixture("speechmatics_normal.jsonl");
+    let mock_handle = start_mock_server_with_config(recording, MockUpstreamConfig::default())
+        .await
+        .expect("Failed to start mock server");
+
+    let proxy_addr =
+        start_server_with_upstream_url(Provider::Speechmatics, &mock_handle.ws_url()).await;
+
+    let ws_stream = connect_to_proxy(proxy_addr, Provider::Speechmatics, "enhanced").await;
+    let (messages, close_info) = collect_messages(ws_stream, TEST_RESPONSE_TIMEOUT).await;
+
+    assert!(!messages.is_empty(), "Expected to receive messages");
+
+    let has_hello_world = messages.iter().any(|m| m.contains("Hello world."));
+    let has_speechmatics = messages.iter().any(|m| m.contains("Speechmatics test."));
+    assert!(has_hello_world, "Expected 'Hello world.' transcript");
+    assert!(has_speechmatics, "Expected 'Speechmatics test.' transcript");
+
+    if let Some((code, _reason)) = close_info {
+        assert_eq!(code, 1000, "Expected normal close code 1000");
+    }
+}
+
+#[tokio::test]
+async fn test_speechmatics_auth_error_replay() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let recording = load_fixture("speechmatics_error.jsonl");
+    let mock_handle = start_mock_server_with_config(recording, MockUpstreamConfig::default())
+        .await
+        .expect("Failed to start mock server");
+
+    let proxy_addr =
+        start_server_with_upstream_url(Provider::Speechmatics, &mock_handle.ws_url()).await;
+
+    let ws_stream = connect_to_proxy(proxy_addr, Provider::Speechmatics, "enhanced").await;
+    let (messages, close_info) = collect_messages(ws_stream, TEST_RESPONSE_TIMEOUT).await;
+
+    let has_auth_error = messages
+        .iter()
+        .any(|m| m.contains("not_authorised") || m.contains("Permission denied"));
+    assert!(has_auth_error, "Expected auth error message");
+
+    if let Some((code, _reason)) = close_info {
+        assert_eq!(code, 4401, "Expected close code 4401, got {}", code);
+    }
+}
+
+#[tokio::test]
+async fn test_revai_normal_transcription_replay() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let recording = load_fixture("revai_normal.jsonl");
+    let mock_handle = start_mock_server_with_config(recording, MockUpstreamConfig::default())
+        .await
+        .expect("Failed to start mock server");
+
+    let proxy_addr = start_server_with_upstream_url(Provider::RevAi, &mock_handle.ws_url()).await;
+
+    let ws_stream = connect_to_proxy(proxy_addr, Provider::RevAi, "machine").await;
+    let (messages, close_info) = collect_messages(ws_stream, TEST_RESPONSE_TIMEOUT).await;
+
+    assert!(!messages.is_empty(), "Expected to receive messages");
+
+    let has_hello = messages.iter().any(|m| m.contains("\"Hello\""));
+    let has_final = messages.iter().any(|m| m.contains("\"final\""));
+    assert!(has_hello, "Expected 'Hello' element");
+    assert!(has_final, "Expected a final hypothesis");
+
+    if let Some((code, _reason)) = close_info {
+        assert_eq!(code, 1000, "Expected normal close code 1000");
+    }
+}
+
+#[tokio::test]
+async fn test_revai_auth_error_replay() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let recording = load_fixture("revai_auth_error.jsonl");
+    let mock_handle = start_mock_server_with_config(recording, MockUpstreamConfig::default())
+        .await
+        .expect("Failed to start mock server");
+
+    let proxy_addr = start_server_with_upstream_url(Provider::RevAi, &mock_handle.ws_url()).await;
+
+    let ws_stream = connect_to_proxy(proxy_addr, Provider::RevAi, "machine").await;
+    let (_messages, close_info) = collect_messages(ws_stream, TEST_RESPONSE_TIMEOUT).await;
+
+    let (code, reason) = close_info.expect("Expected a close frame");
+    assert_eq!(code, 4401, "Expected close code 4401, got {}", code);
+    assert_eq!(reason, "Unauthorized");
+}
+
 #[tokio::test]
 async fn test_proxy_forwards_all_messages() {
     let _ = tracing_subscriber::fmt::try_init();
@@ -232,6 +329,55 @@ async fn test_proxy_forwards_all_messages() {
     );
 }
 
+#[tokio::test]
+async fn test_proxy_forwards_upstream_close_frame() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let recording = load_fixture("deepgram_normal.jsonl").transform(|m| match m.kind {
+        MessageKind::Close { .. } => {
+            WsMessage::close(m.direction, m.timestamp_ms, 1011, "internal server error")
+        }
+        _ => m,
+    });
+    let mock_handle = start_mock_server_with_config(recording, MockUpstreamConfig::default())
+        .await
+        .expect("Failed to start mock server");
+
+    let proxy_addr =
+        start_server_with_upstream_url(Provider::Deepgram, &mock_handle.ws_url()).await;
+
+    let ws_stream = connect_to_proxy(proxy_addr, Provider::Deepgram, "nova-3").await;
+    let (messages, close_info) = collect_messages(ws_stream, TEST_RESPONSE_TIMEOUT).await;
+
+    assert!(
+        !messages.is_empty(),
+        "Expected transcripts before the close"
+    );
+    let (code, reason) = close_info.expect("Expected a close frame");
+    assert_eq!(code, 1011);
+    assert_eq!(reason, "internal server error");
+}
+
+#[tokio::test]
+async fn test_proxy_close_prefers_detected_upstream_error() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let recording = load_fixture("deepgram_auth_error.jsonl");
+    let mock_handle = start_mock_server_with_config(recording, MockUpstreamConfig::default())
+        .await
+        .expect("Failed to start mock server");
+
+    let proxy_addr =
+        start_server_with_upstream_url(Provider::Deepgram, &mock_handle.ws_url()).await;
+
+    let ws_stream = connect_to_proxy(proxy_addr, Provider::Deepgram, "nova-3").await;
+    let (_messages, close_info) = collect_messages(ws_stream, TEST_RESPONSE_TIMEOUT).await;
+
+    let (code, reason) = close_info.expect("Expected a close frame");
+    assert_eq!(code, 4401);
+    assert_eq!(reason, "Invalid credentials.");
+}
+
 #[tokio::test]
 async fn test_proxy_handles_client_disconnect() {
     let _ = tracing_subscriber::fmt::try_init();
@@ -0,0 +1,43 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use tower::ServiceExt;
+
+use transcribe_proxy::{AuthenticatedUserId, Env, SttProxyConfig, router};
+
+fn create_upload_request(user_id: Option<&str>) -> Request<Body> {
+    let mut request = Request::post("/upload")
+        .header("content-type", "application/json")
+        .body(Body::from(r#"{"key":"audio.wav"}"#))
+        .unwrap();
+    if let Some(user_id) = user_id {
+        request
+            .extensions_mut()
+            .insert(AuthenticatedUserId(user_id.to_string()));
+    }
+    request
+}
+
+#[tokio::test]
+async fn test_upload_requires_storage() {
+    let app = router(SttProxyConfig::new(&Env::default()));
+
+    let response = app
+        .oneshot(create_upload_request(Some("user")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[tokio::test]
+async fn test_upload_requires_signed_in_user() {
+    let storage = hypr_s3::Client::builder()
+        .endpoint_url("http://127.0.0.1:9")
+        .bucket("test")
+        .credentials("key", "secret")
+        .build()
+        .await;
+    let app = router(SttProxyConfig::new(&Env::default()).with_storage(storage));
+
+    let response = app.oneshot(create_upload_request(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
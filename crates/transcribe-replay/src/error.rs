@@ -0,0 +1,14 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid provider: {0}")]
+    InvalidProvider(String),
+
+    #[error("invalid replay speed: {0}")]
+    InvalidSpeed(f64),
+}
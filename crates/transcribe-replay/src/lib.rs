@@ -0,0 +1,78 @@
+mod error;
+mod service;
+mod session;
+
+pub use error::*;
+pub use service::*;
+pub use session::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    use owhisper_interface::stream::StreamResponse;
+    use owhisper_interface::{ControlMessage, MixedMessage};
+
+    fn word(text: &str, start: f64, end: f64) -> TranscriptWord {
+        TranscriptWord {
+            word: text.to_lowercase(),
+            start,
+            end,
+            punctuated_word: Some(text.to_string()),
+            speaker: None,
+            channel: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_service_replays_transcript() -> Result<(), Box<dyn std::error::Error>> {
+        let session = ReplaySession::from_words(vec![
+            word("Hello", 0.0, 0.4),
+            word("world.", 0.5, 0.9),
+            word("Bye.", 2.0, 2.3),
+        ]);
+        let service = TranscribeService::builder()
+            .session(session)
+            .speed(100.0)
+            .build()?;
+
+        let app = axum::Router::new().route_service("/v1/listen", service);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        let server_handle = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                println!("Server error: {}", e);
+            }
+        });
+
+        let client = owhisper_client::ListenClient::builder()
+            .api_base(format!("http://{}/v1", addr))
+            .build_single()
+            .await;
+
+        let input = tokio_stream::iter(vec![MixedMessage::<bytes::Bytes, ControlMessage>::Audio(
+            bytes::Bytes::from(vec![0u8; 3200]),
+        )])
+        .chain(tokio_stream::pending());
+
+        let (stream, _handle) = client.from_realtime_audio(input).await?;
+        futures_util::pin_mut!(stream);
+
+        let mut transcripts = Vec::new();
+        while transcripts.len() < 2 {
+            let response = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next())
+                .await?
+                .expect("stream ended early")?;
+            if let StreamResponse::TranscriptResponse { .. } = &response {
+                transcripts.push(response.text().unwrap_or_default().to_string());
+            }
+        }
+        assert_eq!(transcripts, vec!["Hello world.", "Bye."]);
+
+        server_handle.abort();
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+//! Serves a recorded session for local development.
+//!
+//! Point the desktop's Custom STT provider at the printed base URL (any API
+//! key works) to run a listening session without a live provider. The `/stt`
+//! path makes the app treat the server like a local Hyprnote proxy, so mic and
+//! speaker share one multichannel socket instead of one socket each.
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+
+use clap::Parser;
+use owhisper_config::ReplayModelConfig;
+use transcribe_replay::TranscribeService;
+
+#[derive(Parser)]
+#[command(name = "transcribe-replay")]
+#[command(about = "Replay a recorded STT session as a Deepgram-compatible server")]
+struct Cli {
+    /// A `.jsonl` provider recording or a `.json` transcript with word timings.
+    path: PathBuf,
+
+    /// Provider the recording was captured from.
+    #[arg(long)]
+    provider: Option<String>,
+
+    /// Playback rate relative to real time.
+    #[arg(long)]
+    speed: Option<f64>,
+
+    #[arg(long, default_value_t = 0)]
+    port: u16,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let config = ReplayModelConfig {
+        id: "replay".to_string(),
+        path: cli.path.to_string_lossy().into_owned(),
+        provider: cli.provider,
+        speed: cli.speed,
+    };
+    let service = TranscribeService::from_config(&config)?;
+    let app = axum::Router::new()
+        .route_service("/v1/listen", service.clone())
+        .route_service("/stt/listen", service);
+
+    let listener =
+        tokio::net::TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, cli.port))).await?;
+    println!("http://{}/stt", listener.local_addr()?);
+
+    axum::serve(listener, app).await?;
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::{
+    extract::{
+        FromRequestParts,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+use futures_util::{SinkExt, StreamExt};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tower::Service;
+
+use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse};
+
+use crate::{ReplaySession, ScheduledResponse};
+
+#[derive(Clone)]
+pub struct TranscribeService {
+    session: Arc<ReplaySession>,
+    speed: f64,
+}
+
+impl TranscribeService {
+    pub fn builder() -> TranscribeServiceBuilder {
+        TranscribeServiceBuilder::default()
+    }
+
+    pub fn from_config(config: &owhisper_config::ReplayModelConfig) -> Result<Self, crate::Error> {
+        let session = ReplaySession::from_config(config)?;
+        let mut builder = Self::builder().session(session);
+        if let Some(speed) = config.speed {
+            builder = builder.speed(speed);
+        }
+        builder.build()
+    }
+}
+
+#[derive(Default)]
+pub struct TranscribeServiceBuilder {
+    session: Option<ReplaySession>,
+    speed: Option<f64>,
+}
+
+impl TranscribeServiceBuilder {
+    pub fn session(mut self, session: ReplaySession) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// Playback rate relative to real time. Clients can override it per
+    /// connection with the `replay_speed` query parameter.
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    pub fn build(self) -> Result<TranscribeService, crate::Error> {
+        let speed = self.speed.unwrap_or(1.0);
+        validate_speed(speed)?;
+
+        Ok(TranscribeService {
+            session: Arc::new(self.session.unwrap_or_default()),
+            speed,
+        })
+    }
+}
+
+fn validate_speed(speed: f64) -> Result<f64, crate::Error> {
+    if speed.is_finite() && speed > 0.0 {
+        Ok(speed)
+    } else {
+        Err(crate::Error::InvalidSpeed(speed))
+    }
+}
+
+#[derive(Default, serde::Deserialize)]
+struct ReplayQuery {
+    channels: Option<u8>,
+    replay_speed: Option<f64>,
+}
+
+impl<B> Service<Request<B>> for TranscribeService
+where
+    B: Send + 'static,
+{
+    type Response = Response;
+    type Error = std::convert::Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let session = self.session.clone();
+        let default_speed = self.speed;
+
+        Box::pin(async move {
+            let query_string = req.uri().query().unwrap_or("");
+
+            let query: ReplayQuery = match serde_qs::from_str(query_string) {
+                Ok(q) => q,
+                Err(e) => {
+                    return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                }
+            };
+
+            let speed = match validate_speed(query.replay_speed.unwrap_or(default_speed)) {
+                Ok(speed) => speed,
+                Err(e) => {
+                    return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                }
+            };
+            let channels = query.channels.unwrap_or(1).max(1);
+
+            let (mut parts, _body) = req.into_parts();
+            let ws_upgrade = match WebSocketUpgrade::from_request_parts(&mut parts, &()).await {
+                Ok(ws) => ws,
+                Err(e) => {
+                    return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                }
+            };
+
+            Ok(ws_upgrade
+                .on_upgrade(move |socket| async move {
+                    handle_websocket_connection(socket, session, speed, channels).await
+                })
+                .into_response())
+        })
+    }
+}
+
+enum ClientEvent {
+    Finalize,
+    CloseStream,
+}
+
+#[derive(serde::Deserialize)]
+struct ControlMessage<'a> {
+    #[serde(borrow, rename = "type")]
+    msg_type: &'a str,
+}
+
+async fn handle_websocket_connection(
+    socket: WebSocket,
+    session: Arc<ReplaySession>,
+    speed: f64,
+    channels: u8,
+) {
+    let (mut ws_sender, mut ws_receiver) = socket.split();
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel::<ClientEvent>();
+
+    // Audio is accepted and dropped; only control messages affect playback.
+    let reader = tokio::spawn(async move {
+        while let Some(Ok(message)) = ws_receiver.next().await {
+            let event = match &message {
+                Message::Text(text) => match serde_json::from_str::<ControlMessage>(text) {
+                    Ok(ControlMessage {
+                        msg_type: "Finalize",
+                    }) => ClientEvent::Finalize,
+                    Ok(ControlMessage {
+                        msg_type: "CloseStream",
+                    }) => ClientEvent::CloseStream,
+                    _ => continue,
+                },
+                Message::Close(_) => break,
+                _ => continue,
+            };
+
+            if event_tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+
+    let started = Instant::now();
+    let mut pending: VecDeque<ScheduledResponse> = session.responses().iter().cloned().collect();
+
+    loop {
+        let next_at = pending.front().map(|next| started + next.at.div_f64(speed));
+
+        tokio::select! {
+            _ = tokio::time::sleep_until(next_at.unwrap_or(started)), if next_at.is_some() => {
+                let Some(next) = pending.pop_front() else {
+                    continue;
+                };
+                if send_response(&mut ws_sender, next.response, channels).await.is_err() {
+                    break;
+                }
+            }
+            event = event_rx.recv() => match event {
+                Some(ClientEvent::Finalize) => {
+                    let mut responses: Vec<_> = pending.drain(..).map(|r| r.response).collect();
+                    if responses.is_empty() {
+                        responses.push(empty_final(channels));
+                    }
+                    if let Some(StreamResponse::TranscriptResponse { from_finalize, .. }) =
+                        responses.last_mut()
+                    {
+                        *from_finalize = true;
+                    }
+
+                    if send_all(&mut ws_sender, responses, channels).await.is_err() {
+                        break;
+                    }
+                }
+                Some(ClientEvent::CloseStream) => {
+                    let mut responses: Vec<_> = pending.drain(..).map(|r| r.response).collect();
+                    responses.push(StreamResponse::TerminalResponse {
+                        request_id: Metadata::default().request_id,
+                        created: String::new(),
+                        duration: session.duration().as_secs_f64(),
+                        channels: channels as u32,
+                    });
+
+                    let _ = send_all(&mut ws_sender, responses, channels).await;
+                    break;
+                }
+                None => break,
+            },
+        }
+    }
+
+    reader.abort();
+    let _ = ws_sender.close().await;
+}
+
+async fn send_all(
+    ws_sender: &mut futures_util::stream::SplitSink<WebSocket, Message>,
+    responses: Vec<StreamResponse>,
+    channels: u8,
+) -> Result<(), axum::Error> {
+    for response in responses {
+        send_response(ws_sender, response, channels).await?;
+    }
+    Ok(())
+}
+
+async fn send_response(
+    ws_sender: &mut futures_util::stream::SplitSink<WebSocket, Message>,
+    mut response: StreamResponse,
+    channels: u8,
+) -> Result<(), axum::Error> {
+    if let StreamResponse::TranscriptResponse { channel_index, .. } = &mut response {
+        let total = channels as i32;
+        let index = channel_index
+            .first()
+            .copied()
+            .unwrap_or(0)
+            .clamp(0, total - 1);
+        *channel_index = vec![index, total];
+    }
+
+    let msg = Message::Text(serde_json::to_string(&response).unwrap().into());
+    ws_sender.send(msg).await
+}
+
+fn empty_final(channels: u8) -> StreamResponse {
+    StreamResponse::TranscriptResponse {
+        start: 0.0,
+        duration: 0.0,
+        is_final: true,
+        speech_final: true,
+        from_finalize: false,
+        channel: Channel {
+            alternatives: vec![Alternatives {
+                transcript: String::new(),
+                words: vec![],
+                confidence: 1.0,
+                languages: vec![],
+                translation: None,
+            }],
+        },
+        metadata: Metadata::default(),
+        channel_index: vec![0, channels as i32],
+    }
+}
@@ -0,0 +1,342 @@
+use std::path::Path;
+use std::time::Duration;
+
+use owhisper_client::{
+    AssemblyAIAdapter, DeepgramAdapter, ElevenLabsAdapter, FireworksAdapter, GladiaAdapter,
+    OpenAIAdapter, Provider, RealtimeSttAdapter, RevAiAdapter, SonioxAdapter, SpeechmaticsAdapter,
+};
+use owhisper_interface::stream::{Alternatives, Channel, Metadata, StreamResponse, Word};
+
+// Transcript words are grouped into utterances that break on silence, on
+// sentence punctuation, or once they run this long.
+const MAX_UTTERANCE_GAP_SECS: f64 = 1.0;
+const MAX_UTTERANCE_SECS: f64 = 8.0;
+
+#[derive(Debug, Clone)]
+pub struct ScheduledResponse {
+    /// Offset from the start of the connection at which the response is sent.
+    pub at: Duration,
+    pub response: StreamResponse,
+}
+
+/// A pre-recorded sequence of responses, served by [`crate::TranscribeService`]
+/// in place of a live provider.
+#[derive(Debug, Clone, Default)]
+pub struct ReplaySession {
+    responses: Vec<ScheduledResponse>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Direction {
+    ServerToClient,
+    ClientToServer,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum MessageKind {
+    Text,
+    #[serde(other)]
+    Other,
+}
+
+// One line of a recorded provider session, as written by the proxy's fixture
+// recorder.
+#[derive(serde::Deserialize)]
+struct RecordedMessage {
+    direction: Direction,
+    timestamp_ms: u64,
+    kind: MessageKind,
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TranscriptWord {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+    #[serde(default)]
+    pub punctuated_word: Option<String>,
+    #[serde(default)]
+    pub speaker: Option<i32>,
+    #[serde(default)]
+    pub channel: i32,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Transcript {
+    pub words: Vec<TranscriptWord>,
+}
+
+impl ReplaySession {
+    /// Loads a `.jsonl` provider recording or a `.json` transcript.
+    pub fn from_file(path: impl AsRef<Path>, provider: Provider) -> Result<Self, crate::Error> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("jsonl") => Self::from_recording(&content, provider),
+            _ => Self::from_transcript(&content),
+        }
+    }
+
+    pub fn from_config(config: &owhisper_config::ReplayModelConfig) -> Result<Self, crate::Error> {
+        let provider = match &config.provider {
+            Some(name) => name
+                .parse::<Provider>()
+                .map_err(|_| crate::Error::InvalidProvider(name.clone()))?,
+            None => Provider::Deepgram,
+        };
+
+        Self::from_file(&config.path, provider)
+    }
+
+    /// Replays what the provider sent during a recorded session, translated to
+    /// Deepgram-compatible responses with the provider's adapter.
+    pub fn from_recording(jsonl: &str, provider: Provider) -> Result<Self, crate::Error> {
+        let mut responses = Vec::new();
+
+        for line in jsonl.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let message: RecordedMessage = serde_json::from_str(trimmed)?;
+            if !matches!(message.direction, Direction::ServerToClient)
+                || !matches!(message.kind, MessageKind::Text)
+            {
+                continue;
+            }
+
+            let at = Duration::from_millis(message.timestamp_ms);
+            responses.extend(
+                parse_provider_message(provider, &message.content)
+                    .into_iter()
+                    .filter(|response| !is_terminal(response))
+                    .map(|response| ScheduledResponse { at, response }),
+            );
+        }
+
+        Ok(Self { responses })
+    }
+
+    /// Replays a transcript with word timings, sending each utterance once its
+    /// last word has been spoken.
+    pub fn from_transcript(json: &str) -> Result<Self, crate::Error> {
+        let transcript: Transcript = serde_json::from_str(json)?;
+        Ok(Self::from_words(transcript.words))
+    }
+
+    pub fn from_words(mut words: Vec<TranscriptWord>) -> Self {
+        words.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        let mut responses = Vec::new();
+        let mut channels: Vec<i32> = words.iter().map(|w| w.channel).collect();
+        channels.sort_unstable();
+        channels.dedup();
+
+        for channel in channels {
+            let mut utterance: Vec<&TranscriptWord> = Vec::new();
+
+            for word in words.iter().filter(|w| w.channel == channel) {
+                if let (Some(first), Some(last)) = (utterance.first(), utterance.last())
+                    && (word.start - last.end > MAX_UTTERANCE_GAP_SECS
+                        || word.end - first.start > MAX_UTTERANCE_SECS)
+                {
+                    responses.push(utterance_response(&utterance));
+                    utterance.clear();
+                }
+
+                utterance.push(word);
+
+                if ends_sentence(word) {
+                    responses.push(utterance_response(&utterance));
+                    utterance.clear();
+                }
+            }
+
+            if !utterance.is_empty() {
+                responses.push(utterance_response(&utterance));
+            }
+        }
+
+        responses.sort_by_key(|r| r.at);
+        Self { responses }
+    }
+
+    pub fn responses(&self) -> &[ScheduledResponse] {
+        &self.responses
+    }
+
+    /// Audio length the session covers, judging by its last response.
+    pub fn duration(&self) -> Duration {
+        self.responses.last().map(|r| r.at).unwrap_or_default()
+    }
+}
+
+fn ends_sentence(word: &TranscriptWord) -> bool {
+    word.punctuated_word
+        .as_deref()
+        .unwrap_or(&word.word)
+        .ends_with(['.', '?', '!'])
+}
+
+fn utterance_response(words: &[&TranscriptWord]) -> ScheduledResponse {
+    let start = words.first().map(|w| w.start).unwrap_or_default();
+    let end = words.last().map(|w| w.end).unwrap_or_default();
+    let channel = words.first().map(|w| w.channel).unwrap_or_default();
+
+    let transcript = words
+        .iter()
+        .map(|w| w.punctuated_word.as_deref().unwrap_or(&w.word))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let words = words
+        .iter()
+        .map(|w| Word {
+            word: w.word.clone(),
+            start: w.start,
+            end: w.end,
+            confidence: 1.0,
+            speaker: w.speaker,
+            punctuated_word: w.punctuated_word.clone(),
+            language: None,
+        })
+        .collect();
+
+    ScheduledResponse {
+        at: Duration::from_secs_f64(end.max(0.0)),
+        response: StreamResponse::TranscriptResponse {
+            start,
+            duration: end - start,
+            is_final: true,
+            speech_final: true,
+            from_finalize: false,
+            channel: Channel {
+                alternatives: vec![Alternatives {
+                    transcript,
+                    words,
+                    confidence: 1.0,
+                    languages: vec![],
+                    translation: None,
+                }],
+            },
+            metadata: Metadata::default(),
+            channel_index: vec![channel, 1],
+        },
+    }
+}
+
+fn is_terminal(response: &StreamResponse) -> bool {
+    matches!(response, StreamResponse::TerminalResponse { .. })
+}
+
+fn parse_provider_message(provider: Provider, raw: &str) -> Vec<StreamResponse> {
+    match provider {
+        Provider::Deepgram => DeepgramAdapter.parse_response(raw),
+        Provider::AssemblyAI => AssemblyAIAdapter.parse_response(raw),
+        Provider::Soniox => SonioxAdapter.parse_response(raw),
+        Provider::Fireworks => FireworksAdapter.parse_response(raw),
+        Provider::OpenAI => OpenAIAdapter.parse_response(raw),
+        Provider::Gladia => GladiaAdapter.parse_response(raw),
+        Provider::ElevenLabs => ElevenLabsAdapter.parse_response(raw),
+        Provider::Speechmatics => SpeechmaticsAdapter.parse_response(raw),
+        Provider::RevAi => RevAiAdapter.parse_response(raw),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start: f64, end: f64) -> TranscriptWord {
+        TranscriptWord {
+            word: text.trim_end_matches('.').to_lowercase(),
+            start,
+            end,
+            punctuated_word: Some(text.to_string()),
+            speaker: None,
+            channel: 0,
+        }
+    }
+
+    #[test]
+    fn test_transcript_splits_utterances() {
+        let session = ReplaySession::from_words(vec![
+            word("Hello", 0.1, 0.4),
+            word("there.", 0.5, 0.8),
+            word("How", 1.0, 1.2),
+            word("are", 1.3, 1.4),
+            word("you", 3.0, 3.3),
+        ]);
+
+        let texts: Vec<_> = session
+            .responses()
+            .iter()
+            .map(|r| r.response.text().unwrap().to_string())
+            .collect();
+        assert_eq!(texts, vec!["Hello there.", "How are", "you"]);
+
+        let at: Vec<_> = session.responses().iter().map(|r| r.at).collect();
+        assert_eq!(
+            at,
+            vec![
+                Duration::from_millis(800),
+                Duration::from_millis(1400),
+                Duration::from_millis(3300),
+            ]
+        );
+        assert_eq!(session.duration(), Duration::from_millis(3300));
+    }
+
+    #[test]
+    fn test_transcript_keeps_channels_apart() {
+        let mut spk = word("Hi.", 0.2, 0.5);
+        spk.channel = 1;
+        let session = ReplaySession::from_words(vec![word("Hey.", 0.0, 0.3), spk]);
+
+        let indexes: Vec<_> = session
+            .responses()
+            .iter()
+            .map(|r| match &r.response {
+                StreamResponse::TranscriptResponse { channel_index, .. } => channel_index[0],
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(indexes, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_recording_replays_server_messages() {
+        let jsonl = r#"
+# recorded against deepgram
+{"direction":"client_to_server","timestamp_ms":0,"kind":{"type":"binary"},"content":"AAAA"}
+{"direction":"server_to_client","timestamp_ms":1200,"kind":{"type":"text"},"content":"{\"type\":\"Results\",\"channel_index\":[0,1],\"duration\":1.0,\"start\":0.0,\"is_final\":true,\"speech_final\":true,\"from_finalize\":false,\"channel\":{\"alternatives\":[{\"transcript\":\"hello world\",\"confidence\":0.9,\"words\":[]}]},\"metadata\":{\"request_id\":\"r\",\"model_info\":{\"name\":\"\",\"version\":\"\",\"arch\":\"\"},\"model_uuid\":\"m\"}}"}
+{"direction":"server_to_client","timestamp_ms":1500,"kind":{"type":"close","code":1000,"reason":""}}
+"#;
+
+        let session = ReplaySession::from_recording(jsonl, Provider::Deepgram).unwrap();
+        assert_eq!(session.responses().len(), 1);
+        assert_eq!(session.responses()[0].at, Duration::from_millis(1200));
+        assert_eq!(session.responses()[0].response.text(), Some("hello world"));
+    }
+
+    #[test]
+    fn test_from_config_rejects_unknown_provider() {
+        let config = owhisper_config::ReplayModelConfig {
+            id: "replay".to_string(),
+            path: "missing.jsonl".to_string(),
+            provider: Some("nope".to_string()),
+            speed: None,
+        };
+
+        assert!(matches!(
+            ReplaySession::from_config(&config),
+            Err(crate::Error::InvalidProvider(_))
+        ));
+    }
+}
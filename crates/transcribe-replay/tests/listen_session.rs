@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+
+use owhisper_client::{
+    AdapterKind, AudioEncoding, DeepgramAdapter, FinalizeHandle, ListenClient, ReconnectConfig,
+};
+use owhisper_config::ReplayModelConfig;
+use owhisper_interface::stream::StreamResponse;
+use owhisper_interface::{ListenParams, MixedMessage};
+use transcribe_replay::TranscribeService;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn start_replay_server(path: &str, speed: f64) -> std::net::SocketAddr {
+    let service = TranscribeService::from_config(&ReplayModelConfig {
+        id: "replay".to_string(),
+        path: format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), path),
+        provider: None,
+        speed: Some(speed),
+    })
+    .unwrap();
+    let app = axum::Router::new().route_service("/stt/listen", service);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    addr
+}
+
+// Mirrors how the listener plugin opens a mic + speaker session.
+#[tokio::test]
+async fn test_dual_listen_session_through_replay() {
+    let addr = start_replay_server("meeting.json", 20.0).await;
+    let base_url = format!("http://{}/stt", addr);
+
+    let languages = vec!["en".parse().unwrap()];
+    assert_eq!(
+        AdapterKind::from_url_and_languages(&base_url, &languages, Some("replay")),
+        AdapterKind::Deepgram
+    );
+
+    let client = ListenClient::builder()
+        .adapter::<DeepgramAdapter>()
+        .api_base(base_url)
+        .api_key("dev")
+        .params(ListenParams {
+            model: Some("replay".to_string()),
+            languages,
+            sample_rate: 16000,
+            ..Default::default()
+        })
+        .reconnect(ReconnectConfig::default())
+        .audio_encoding(AudioEncoding::Opus)
+        .build_dual()
+        .await;
+
+    let silence = bytes::Bytes::from(vec![0u8; 3200]);
+    let input = tokio_stream::iter(vec![MixedMessage::Audio((silence.clone(), silence))])
+        .chain(tokio_stream::pending());
+
+    let (stream, handle) = client.from_realtime_audio(Box::pin(input)).await.unwrap();
+    futures_util::pin_mut!(stream);
+
+    let mut transcripts = Vec::new();
+    while transcripts.len() < 3 {
+        let response = tokio::time::timeout(TIMEOUT, stream.next())
+            .await
+            .unwrap()
+            .expect("stream ended early")
+            .unwrap();
+        if let StreamResponse::TranscriptResponse { channel_index, .. } = &response {
+            transcripts.push((channel_index[0], response.text().unwrap().to_string()));
+        }
+    }
+    assert_eq!(
+        transcripts,
+        vec![
+            (0, "Thanks for joining.".to_string()),
+            (1, "Happy to be here.".to_string()),
+            (0, "Let's start.".to_string()),
+        ]
+    );
+
+    handle.finalize().await;
+    loop {
+        let response = tokio::time::timeout(TIMEOUT, stream.next())
+            .await
+            .unwrap()
+            .expect("stream ended before finalize")
+            .unwrap();
+        if let StreamResponse::TranscriptResponse { from_finalize, .. } = response {
+            assert!(from_finalize);
+            break;
+        }
+    }
+}
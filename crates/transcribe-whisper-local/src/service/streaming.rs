@@ -82,6 +82,7 @@ where
                     return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
                 }
             };
+            let opus = query_string.split('&').any(|pair| pair == "encoding=opus");
 
             let (mut parts, _body) = req.into_parts();
             let ws_upgrade = match WebSocketUpgrade::from_request_parts(&mut parts, &()).await {
@@ -117,7 +118,7 @@ where
 
             Ok(ws_upgrade
                 .on_upgrade(move |socket| async move {
-                    handle_websocket_connection(socket, params, model, guard).await;
+                    handle_websocket_connection(socket, params, opus, model, guard).await;
                 })
                 .into_response())
         })
@@ -127,6 +128,7 @@ where
 async fn handle_websocket_connection(
     socket: WebSocket,
     params: ListenParams,
+    opus: bool,
     model: hypr_whisper_local::Whisper,
     guard: ConnectionGuard,
 ) {
@@ -147,6 +149,7 @@ async fn handle_websocket_connection(
             handle_single_channel(
                 ws_sender,
                 ws_receiver,
+                opus,
                 model,
                 guard,
                 redemption_time,
@@ -158,6 +161,7 @@ async fn handle_websocket_connection(
             handle_dual_channel(
                 ws_sender,
                 ws_receiver,
+                opus,
                 model,
                 guard,
                 redemption_time,
@@ -171,12 +175,23 @@ async fn handle_websocket_connection(
 async fn handle_single_channel(
     ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
     ws_receiver: futures_util::stream::SplitStream<WebSocket>,
+    opus: bool,
     model: hypr_whisper_local::Whisper,
     guard: ConnectionGuard,
     redemption_time: Duration,
     global_timer: GlobalTimer,
 ) {
-    let audio_source = hypr_ws_utils::WebSocketAudioSource::new(ws_receiver, 16 * 1000);
+    let audio_source = if opus {
+        match hypr_ws_utils::WebSocketAudioSource::opus(ws_receiver, 16 * 1000) {
+            Ok(source) => source,
+            Err(e) => {
+                tracing::error!(error = %e, "opus_decoder_init_failed");
+                return;
+            }
+        }
+    } else {
+        hypr_ws_utils::WebSocketAudioSource::new(ws_receiver, 16 * 1000)
+    };
     let vad_chunks = audio_source.speech_chunks(redemption_time);
 
     let chunked = hypr_whisper_local::AudioChunkStream(process_vad_stream(vad_chunks, "mixed"));
@@ -188,13 +203,23 @@ async fn handle_single_channel(
 async fn handle_dual_channel(
     ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
     ws_receiver: futures_util::stream::SplitStream<WebSocket>,
+    opus: bool,
     model: hypr_whisper_local::Whisper,
     guard: ConnectionGuard,
     redemption_time: Duration,
     global_timer: GlobalTimer,
 ) {
-    let (mic_source, speaker_source) =
-        hypr_ws_utils::split_dual_audio_sources(ws_receiver, 16 * 1000);
+    let (mic_source, speaker_source) = if opus {
+        match hypr_ws_utils::split_dual_opus_audio_sources(ws_receiver, 16 * 1000) {
+            Ok(sources) => sources,
+            Err(e) => {
+                tracing::error!(error = %e, "opus_decoder_init_failed");
+                return;
+            }
+        }
+    } else {
+        hypr_ws_utils::split_dual_audio_sources(ws_receiver, 16 * 1000)
+    };
 
     let mic_chunked = {
         let mic_vad_chunks = mic_source.speech_chunks(redemption_time);
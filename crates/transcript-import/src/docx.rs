@@ -0,0 +1,87 @@
+use std::io::{Cursor, Read};
+
+use crate::{ParsedTranscript, Result, text};
+
+const WORDPROCESSING_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+/// DOCX exports hold the same layout as the TXT ones, one paragraph per line.
+pub fn parse(bytes: &[u8]) -> Result<ParsedTranscript> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))?;
+    let mut xml = String::new();
+    archive
+        .by_name("word/document.xml")?
+        .read_to_string(&mut xml)?;
+
+    Ok(text::parse(&paragraphs(&xml)?.join("\n")))
+}
+
+fn paragraphs(xml: &str) -> Result<Vec<String>> {
+    let doc = roxmltree::Document::parse(xml)?;
+
+    let paragraphs = doc
+        .descendants()
+        .filter(|n| n.has_tag_name((WORDPROCESSING_NS, "p")))
+        .map(|p| {
+            p.descendants()
+                .filter_map(|n| match n.tag_name() {
+                    name if name.namespace() != Some(WORDPROCESSING_NS) => None,
+                    name if name.name() == "t" => n.text(),
+                    name if name.name() == "tab" => Some("\t"),
+                    name if name.name() == "br" => Some("\n"),
+                    _ => None,
+                })
+                .collect::<String>()
+        })
+        .collect();
+
+    Ok(paragraphs)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+
+    pub(crate) fn docx(paragraphs: &[&str]) -> Vec<u8> {
+        let body: String = paragraphs
+            .iter()
+            .map(|p| {
+                format!(
+                    "<w:p><w:r><w:t xml:space=\"preserve\">{}</w:t></w:r></w:p>",
+                    p
+                )
+            })
+            .collect();
+        let xml = format!(
+            "<?xml version=\"1.0\"?><w:document xmlns:w=\"{}\"><w:body>{}</w:body></w:document>",
+            WORDPROCESSING_NS, body
+        );
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file(
+                "word/document.xml",
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.write_all(xml.as_bytes()).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_parse_docx() {
+        let parsed = parse(&docx(&[
+            "Speaker 1  0:00",
+            "Welcome to the weekly sync.",
+            "",
+            "Speaker 2  0:04",
+            "Thanks.",
+        ]))
+        .unwrap();
+
+        assert_eq!(parsed.segments.len(), 2);
+        assert_eq!(parsed.segments[0].text, "Welcome to the weekly sync.");
+        assert_eq!(parsed.segments[1].speaker.as_deref(), Some("Speaker 2"));
+        assert_eq!(parsed.segments[1].start_ms, Some(4000.0));
+    }
+}
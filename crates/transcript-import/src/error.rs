@@ -0,0 +1,22 @@
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("unsupported transcript format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("subtitle error: {0}")]
+    Subtitle(#[from] aspasia::Error),
+
+    #[error("docx error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error("docx error: {0}")]
+    Xml(#[from] roxmltree::Error),
+}
@@ -0,0 +1,325 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use hypr_importer_core::ir::{Collection, Human, Session, SessionParticipant, Transcript, Word};
+use regex::Regex;
+
+use crate::{Error, ParsedTranscript, parse_file};
+
+// Diarization labels like `Speaker 2` or `Unknown Speaker` aren't people.
+static GENERIC_SPEAKER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:unknown\s+)?(?:speaker|spk|participant)(?:[\s_-]*\d+)?$").unwrap()
+});
+
+/// A person already in the app. Speakers with the same name are linked to them
+/// instead of being imported again.
+#[derive(Debug, Clone)]
+pub struct KnownHuman {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct FileError {
+    pub path: PathBuf,
+    pub error: Error,
+}
+
+#[derive(Debug, Default)]
+pub struct ImportedFiles {
+    pub collection: Collection,
+    /// Files that could not be read. The rest are imported regardless.
+    pub errors: Vec<FileError>,
+}
+
+/// Imports every file into one collection. Sessions whose id is in
+/// `existing_session_ids`, or that repeat an earlier file, are skipped.
+pub fn import_all_from_paths(
+    paths: &[PathBuf],
+    existing_session_ids: &HashSet<String>,
+    known_humans: &[KnownHuman],
+) -> ImportedFiles {
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+
+    for path in paths {
+        match parse_file(path) {
+            Ok(transcript) => parsed.push(transcript),
+            Err(error) => errors.push(FileError {
+                path: path.clone(),
+                error,
+            }),
+        }
+    }
+
+    ImportedFiles {
+        collection: to_collection(parsed, existing_session_ids, known_humans),
+        errors,
+    }
+}
+
+pub fn to_collection(
+    transcripts: Vec<ParsedTranscript>,
+    existing_session_ids: &HashSet<String>,
+    known_humans: &[KnownHuman],
+) -> Collection {
+    let mut collection = Collection::default();
+    let mut seen = existing_session_ids.clone();
+    let mut humans: HashMap<String, Human> = HashMap::new();
+    let known: HashMap<String, &str> = known_humans
+        .iter()
+        .map(|h| (h.name.trim().to_lowercase(), h.id.as_str()))
+        .filter(|(name, _)| !name.is_empty())
+        .collect();
+
+    for parsed in transcripts {
+        if parsed.segments.is_empty() {
+            continue;
+        }
+
+        let session_id = session_id(&parsed);
+        if !seen.insert(session_id.clone()) {
+            continue;
+        }
+
+        let created_at = parsed
+            .started_at
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default();
+
+        let mut participants = HashSet::new();
+        for speaker in parsed.segments.iter().filter_map(|s| s.speaker.as_deref()) {
+            if GENERIC_SPEAKER.is_match(speaker) {
+                continue;
+            }
+
+            let human_id = match known.get(&speaker.trim().to_lowercase()) {
+                Some(id) => id.to_string(),
+                None => humans
+                    .entry(speaker.to_lowercase())
+                    .or_insert_with(|| Human {
+                        id: uuid::Uuid::new_v5(
+                            &uuid::Uuid::NAMESPACE_OID,
+                            speaker.to_lowercase().as_bytes(),
+                        )
+                        .to_string(),
+                        user_id: String::new(),
+                        created_at: created_at.clone(),
+                        name: speaker.to_string(),
+                        email: None,
+                        org_id: None,
+                        job_title: None,
+                        linkedin_username: None,
+                    })
+                    .id
+                    .clone(),
+            };
+
+            if participants.insert(human_id.clone()) {
+                collection.participants.push(SessionParticipant {
+                    id: format!("{}-{}", session_id, human_id),
+                    user_id: String::new(),
+                    session_id: session_id.clone(),
+                    human_id,
+                    source: "imported".to_string(),
+                });
+            }
+        }
+
+        collection
+            .transcripts
+            .push(to_transcript(&parsed, &session_id, &created_at));
+
+        collection.sessions.push(Session {
+            id: session_id,
+            user_id: String::new(),
+            created_at,
+            title: parsed
+                .title
+                .clone()
+                .unwrap_or_else(|| "Imported transcript".to_string()),
+            raw_md: None,
+            enhanced_content: None,
+            folder_id: None,
+            event_id: None,
+        });
+    }
+
+    let mut humans: Vec<Human> = humans.into_values().collect();
+    humans.sort_by(|a, b| a.name.cmp(&b.name));
+    collection.humans = humans;
+
+    collection
+}
+
+/// Derived from the spoken text only, so the same meeting exported twice, or
+/// in two formats with different file names, maps to one session.
+pub fn session_id(parsed: &ParsedTranscript) -> String {
+    let normalized = parsed
+        .segments
+        .iter()
+        .flat_map(|s| s.text.split_whitespace())
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    uuid::Uuid::new_v5(
+        &uuid::Uuid::NAMESPACE_OID,
+        format!("transcript-import:{}", normalized).as_bytes(),
+    )
+    .to_string()
+}
+
+fn to_transcript(parsed: &ParsedTranscript, session_id: &str, created_at: &str) -> Transcript {
+    let transcript_id = uuid::Uuid::new_v5(
+        &uuid::Uuid::NAMESPACE_OID,
+        format!("{}:transcript", session_id).as_bytes(),
+    )
+    .to_string();
+
+    let words = parsed
+        .segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| Word {
+            id: format!("{}-{}", transcript_id, i),
+            text: segment.text.clone(),
+            start_ms: segment.start_ms,
+            end_ms: segment.end_ms,
+            channel: 0,
+            speaker: segment.speaker.clone(),
+        })
+        .collect();
+
+    let start_ms = parsed.segments.iter().find_map(|s| s.start_ms);
+    let end_ms = parsed
+        .segments
+        .iter()
+        .rev()
+        .find_map(|s| s.end_ms.or(s.start_ms));
+
+    Transcript {
+        id: transcript_id,
+        user_id: String::new(),
+        created_at: created_at.to_string(),
+        session_id: session_id.to_string(),
+        title: parsed.title.clone().unwrap_or_default(),
+        started_at: parsed
+            .started_at
+            .map(|t| t.timestamp_millis() as f64)
+            .unwrap_or_default(),
+        ended_at: None,
+        start_ms,
+        end_ms,
+        words,
+        speaker_hints: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Format, parse_str};
+
+    const OTTER_TXT: &str =
+        "Jane Doe  0:00\nLet's go over the launch plan.\n\nSpeaker 2  0:06\nSounds good.\n";
+    const OTTER_SRT: &str = "1\n00:00:00,000 --> 00:00:06,000\nLet's go over the launch plan.\n\n2\n00:00:06,000 --> 00:00:08,000\nSounds good.\n";
+
+    #[test]
+    fn test_to_collection() {
+        let parsed = parse_str(Format::Txt, OTTER_TXT).unwrap();
+        let collection = to_collection(vec![parsed], &HashSet::new(), &[]);
+
+        assert_eq!(collection.sessions.len(), 1);
+        assert_eq!(collection.transcripts.len(), 1);
+        assert_eq!(collection.humans.len(), 1);
+        assert_eq!(collection.humans[0].name, "Jane Doe");
+        assert_eq!(collection.participants.len(), 1);
+        assert_eq!(
+            collection.participants[0].session_id,
+            collection.sessions[0].id
+        );
+
+        let transcript = &collection.transcripts[0];
+        assert_eq!(transcript.session_id, collection.sessions[0].id);
+        assert_eq!(transcript.words.len(), 2);
+        assert_eq!(transcript.words[1].speaker.as_deref(), Some("Speaker 2"));
+        assert_eq!(transcript.start_ms, Some(0.0));
+        assert_eq!(transcript.end_ms, Some(6000.0));
+    }
+
+    #[test]
+    fn test_duplicates_are_skipped() {
+        let txt = parse_str(Format::Txt, OTTER_TXT).unwrap();
+        let srt = parse_str(Format::Srt, OTTER_SRT).unwrap();
+        assert_eq!(session_id(&txt), session_id(&srt));
+
+        let collection = to_collection(vec![txt.clone(), srt], &HashSet::new(), &[]);
+        assert_eq!(collection.sessions.len(), 1);
+
+        let existing = HashSet::from([session_id(&txt)]);
+        let collection = to_collection(vec![txt], &existing, &[]);
+        assert!(collection.sessions.is_empty());
+        assert!(collection.humans.is_empty());
+    }
+
+    #[test]
+    fn test_known_humans() {
+        let parsed = parse_str(Format::Txt, OTTER_TXT).unwrap();
+        let known = [KnownHuman {
+            id: "jane".to_string(),
+            name: "jane doe ".to_string(),
+        }];
+        let collection = to_collection(vec![parsed], &HashSet::new(), &known);
+
+        assert!(collection.humans.is_empty());
+        assert_eq!(collection.participants.len(), 1);
+        assert_eq!(collection.participants[0].human_id, "jane");
+    }
+
+    #[test]
+    fn test_import_all_from_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let txt = dir.path().join("Launch sync.txt");
+        let docx = dir.path().join("Retro.docx");
+        std::fs::write(&txt, OTTER_TXT).unwrap();
+        std::fs::write(
+            &docx,
+            crate::docx::tests::docx(&["Alice - 00:00", "What went well?"]),
+        )
+        .unwrap();
+
+        let unsupported = dir.path().join("notes.pdf");
+        std::fs::write(&unsupported, "").unwrap();
+
+        let ImportedFiles { collection, errors } = import_all_from_paths(
+            &[txt.clone(), unsupported.clone(), docx],
+            &HashSet::new(),
+            &[],
+        );
+        let titles: Vec<&str> = collection
+            .sessions
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Launch sync", "Retro"]);
+        assert_eq!(collection.humans.len(), 2);
+
+        // Neither export carries a date, so the file's modification time is used.
+        let modified = chrono::DateTime::<chrono::Utc>::from(
+            std::fs::metadata(&txt).unwrap().modified().unwrap(),
+        );
+        assert_eq!(collection.sessions[0].created_at, modified.to_rfc3339());
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, unsupported);
+        assert!(matches!(errors[0].error, Error::UnsupportedFormat(_)));
+    }
+}
@@ -0,0 +1,137 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{ParsedTranscript, Result, Segment};
+
+#[derive(Debug, Default, Deserialize)]
+struct JsonTranscript {
+    #[serde(default)]
+    title: Option<String>,
+    /// Fireflies sends epoch milliseconds; other tools send RFC 3339.
+    #[serde(default)]
+    date: Option<Value>,
+    #[serde(default, rename = "dateString")]
+    date_string: Option<String>,
+    #[serde(default, alias = "sentences", alias = "utterances")]
+    segments: Vec<JsonSegment>,
+}
+
+/// Times are seconds unless the field says `_ms`.
+#[derive(Debug, Deserialize)]
+struct JsonSegment {
+    #[serde(default, alias = "speaker_name", alias = "speakerName")]
+    speaker: Option<Value>,
+    #[serde(default, alias = "transcript", alias = "content")]
+    text: String,
+    #[serde(default, alias = "start_time", alias = "startTime")]
+    start: Option<f64>,
+    #[serde(default, alias = "end_time", alias = "endTime")]
+    end: Option<f64>,
+    #[serde(default, alias = "startMs")]
+    start_ms: Option<f64>,
+    #[serde(default, alias = "endMs")]
+    end_ms: Option<f64>,
+}
+
+/// Accepts the Fireflies transcript object (also wrapped in its GraphQL
+/// `data.transcript` envelope) and bare segment arrays.
+pub fn parse(content: &str) -> Result<ParsedTranscript> {
+    let value: Value = serde_json::from_str(content)?;
+
+    let transcript = match value {
+        Value::Array(_) => JsonTranscript {
+            segments: serde_json::from_value(value)?,
+            ..Default::default()
+        },
+        value => {
+            let inner = value.pointer("/data/transcript").cloned().unwrap_or(value);
+            serde_json::from_value(inner)?
+        }
+    };
+
+    let started_at = transcript
+        .date
+        .as_ref()
+        .and_then(parse_date)
+        .or_else(|| transcript.date_string.as_deref().and_then(parse_rfc3339));
+
+    let segments = transcript
+        .segments
+        .into_iter()
+        .filter(|s| !s.text.trim().is_empty())
+        .map(|s| Segment {
+            speaker: s.speaker.and_then(|speaker| match speaker {
+                Value::String(name) if !name.trim().is_empty() => Some(name.trim().to_string()),
+                Value::Number(n) => Some(format!("Speaker {}", n)),
+                _ => None,
+            }),
+            start_ms: s.start_ms.or(s.start.map(|secs| secs * 1000.0)),
+            end_ms: s.end_ms.or(s.end.map(|secs| secs * 1000.0)),
+            text: s.text.trim().to_string(),
+        })
+        .collect();
+
+    Ok(ParsedTranscript {
+        title: transcript.title.filter(|t| !t.trim().is_empty()),
+        started_at,
+        segments,
+    })
+}
+
+fn parse_date(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::Number(n) => DateTime::from_timestamp_millis(n.as_i64()?),
+        Value::String(s) => parse_rfc3339(s),
+        _ => None,
+    }
+}
+
+fn parse_rfc3339(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fireflies_json() {
+        let parsed = parse(
+            r#"{"data":{"transcript":{
+                "title":"Roadmap review",
+                "date":1714560000000,
+                "sentences":[
+                    {"index":0,"speaker_id":0,"speaker_name":"Alice","text":"Let's begin.","raw_text":"lets begin","start_time":0.5,"end_time":1.8},
+                    {"index":1,"speaker_id":1,"speaker_name":null,"text":"Okay.","raw_text":"okay","start_time":2.0,"end_time":2.4}
+                ]
+            }}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(parsed.title.as_deref(), Some("Roadmap review"));
+        assert_eq!(
+            parsed.started_at,
+            Some("2024-05-01T10:40:00Z".parse().unwrap())
+        );
+        assert_eq!(parsed.segments.len(), 2);
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!(parsed.segments[0].start_ms, Some(500.0));
+        assert_eq!(parsed.segments[0].end_ms, Some(1800.0));
+        assert_eq!(parsed.segments[1].speaker, None);
+    }
+
+    #[test]
+    fn test_parse_segment_array() {
+        let parsed = parse(
+            r#"[{"speaker":2,"text":"Hello","start_ms":100,"end_ms":900},{"speaker":"Dana","text":" "}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(parsed.segments.len(), 1);
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Speaker 2"));
+        assert_eq!(parsed.segments[0].start_ms, Some(100.0));
+    }
+}
@@ -0,0 +1,92 @@
+mod docx;
+mod error;
+mod import;
+mod json;
+mod subtitle;
+mod text;
+
+pub use error::*;
+pub use import::*;
+
+use chrono::{DateTime, Utc};
+use std::path::Path;
+
+/// Export formats offered by Otter.ai and Fireflies, which other tools share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Txt,
+    Docx,
+    Srt,
+    Vtt,
+    Json,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "txt" | "md" => Some(Self::Txt),
+            "docx" => Some(Self::Docx),
+            "srt" => Some(Self::Srt),
+            "vtt" => Some(Self::Vtt),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Segment {
+    pub speaker: Option<String>,
+    pub start_ms: Option<f64>,
+    pub end_ms: Option<f64>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedTranscript {
+    pub title: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub segments: Vec<Segment>,
+}
+
+/// Falls back to the file name for the title and to the modification time for
+/// the start, since most exports carry neither.
+pub fn parse_file(path: &Path) -> Result<ParsedTranscript> {
+    let format = Format::from_path(path)
+        .ok_or_else(|| Error::UnsupportedFormat(path.display().to_string()))?;
+
+    let mut parsed = match format {
+        Format::Docx => docx::parse(&std::fs::read(path)?)?,
+        _ => parse_str(format, &std::fs::read_to_string(path)?)?,
+    };
+
+    if parsed.title.is_none() {
+        parsed.title = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().trim().to_string())
+            .filter(|stem| !stem.is_empty());
+    }
+
+    if parsed.started_at.is_none() {
+        parsed.started_at = std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(DateTime::<Utc>::from);
+    }
+
+    Ok(parsed)
+}
+
+pub fn parse_str(format: Format, content: &str) -> Result<ParsedTranscript> {
+    let content = content.trim_start_matches('\u{feff}');
+
+    match format {
+        Format::Txt => Ok(text::parse(content)),
+        Format::Srt => subtitle::parse_srt(content),
+        Format::Vtt => subtitle::parse_vtt(content),
+        Format::Json => json::parse(content),
+        Format::Docx => Err(Error::UnsupportedFormat("docx from text".to_string())),
+    }
+}
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use aspasia::{SubRipSubtitle, Subtitle, WebVttSubtitle};
+use regex::Regex;
+
+use crate::{ParsedTranscript, Result, Segment, text};
+
+// `<v Alice>` or `<v.loud Alice>` voice spans in WebVTT cues.
+static VOICE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<v(?:\.[^ >]+)*\s+(?P<speaker>[^>]+)>").unwrap());
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]+>|\{\\[^}]*\}").unwrap());
+
+pub fn parse_srt(content: &str) -> Result<ParsedTranscript> {
+    let srt = SubRipSubtitle::from_str(content)?;
+
+    let segments = srt
+        .events()
+        .iter()
+        .filter_map(|event| {
+            cue_segment(
+                None,
+                &event.text,
+                i64::from(event.start),
+                i64::from(event.end),
+            )
+        })
+        .collect();
+
+    Ok(ParsedTranscript {
+        segments,
+        ..Default::default()
+    })
+}
+
+/// Cue identifiers are only taken as speakers when they aren't plain counters,
+/// which is how our own VTT export stores them.
+pub fn parse_vtt(content: &str) -> Result<ParsedTranscript> {
+    let vtt = WebVttSubtitle::from_str(content)?;
+
+    let segments = vtt
+        .events()
+        .iter()
+        .filter_map(|cue| {
+            let identifier = cue
+                .identifier
+                .as_deref()
+                .map(str::trim)
+                .filter(|id| !id.is_empty() && !id.chars().all(|c| c.is_ascii_digit()));
+
+            cue_segment(
+                identifier,
+                &cue.text,
+                i64::from(cue.start),
+                i64::from(cue.end),
+            )
+        })
+        .collect();
+
+    Ok(ParsedTranscript {
+        segments,
+        ..Default::default()
+    })
+}
+
+fn cue_segment(identifier: Option<&str>, raw: &str, start: i64, end: i64) -> Option<Segment> {
+    let voice = VOICE
+        .captures(raw)
+        .map(|caps| caps["speaker"].trim().to_string());
+
+    let stripped = TAG.replace_all(raw, "");
+    let line = stripped.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let (speaker, text) = match voice.or_else(|| identifier.map(str::to_string)) {
+        Some(speaker) => (Some(speaker), line),
+        None => text::split_speaker(&line),
+    };
+
+    if text.is_empty() {
+        return None;
+    }
+
+    Some(Segment {
+        speaker,
+        start_ms: Some(start as f64),
+        end_ms: Some(end as f64),
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_srt() {
+        let parsed = parse_srt(
+            "1\n00:00:01,000 --> 00:00:03,500\nAlice: Good morning.\n\n2\n00:00:04,000 --> 00:00:06,000\n<i>Morning!</i>\n",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.segments.len(), 2);
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!(parsed.segments[0].text, "Good morning.");
+        assert_eq!(parsed.segments[0].start_ms, Some(1000.0));
+        assert_eq!(parsed.segments[0].end_ms, Some(3500.0));
+        assert_eq!(parsed.segments[1].speaker, None);
+        assert_eq!(parsed.segments[1].text, "Morning!");
+    }
+
+    #[test]
+    fn test_parse_vtt() {
+        let parsed = parse_vtt(
+            "WEBVTT\n\n1\n00:00:01.000 --> 00:00:02.000\n<v Bob Jones>Hi all.</v>\n\nCarol\n00:00:02.500 --> 00:00:04.000\nQuick update from me.\n",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.segments.len(), 2);
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Bob Jones"));
+        assert_eq!(parsed.segments[0].text, "Hi all.");
+        assert_eq!(parsed.segments[1].speaker.as_deref(), Some("Carol"));
+        assert_eq!(parsed.segments[1].start_ms, Some(2500.0));
+    }
+}
@@ -0,0 +1,317 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{ParsedTranscript, Segment};
+
+const TIMESTAMP: &str = r"(?:\d{1,2}:)?\d{1,2}:\d{2}(?:[.,]\d{1,3})?";
+
+// `[00:01:02] Alice: Hello` or `00:01 - Alice: Hello`
+static TIMESTAMP_FIRST: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"^\[?(?P<ts>{TIMESTAMP})\]?\s*(?:[-|–]\s*)?(?P<rest>.*)$"
+    ))
+    .unwrap()
+});
+
+// `Alice (00:01): Hello` or `Alice [00:01]`
+static TIMESTAMP_BRACKETED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"^(?P<speaker>[^\[\]():]+?)\s*[(\[](?P<ts>{TIMESTAMP})[)\]]\s*:?\s*(?P<text>.*)$"
+    ))
+    .unwrap()
+});
+
+// Otter's `Speaker 1  0:03` and Fireflies' `Alice - 00:03`.
+static TIMESTAMP_LAST: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"^(?P<speaker>.+?)\s*(?:[-|–]\s*|\s)(?P<ts>{TIMESTAMP})$"
+    ))
+    .unwrap()
+});
+
+static SPEAKER_PREFIX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?P<speaker>[^:]{1,60}):\s+(?P<text>.+)$").unwrap());
+
+enum Line {
+    Blank,
+    Header {
+        speaker: Option<String>,
+        start_ms: Option<f64>,
+    },
+    Utterance {
+        speaker: Option<String>,
+        start_ms: Option<f64>,
+        text: String,
+    },
+    Text(String),
+}
+
+/// Plain-text exports put either a `speaker timestamp` header above each
+/// paragraph, or the speaker and timestamp in front of every line.
+pub fn parse(content: &str) -> ParsedTranscript {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut open = false;
+
+    for line in content.lines() {
+        match classify(line) {
+            Line::Blank => open = false,
+            Line::Header { speaker, start_ms } => {
+                segments.push(Segment {
+                    speaker,
+                    start_ms,
+                    end_ms: None,
+                    text: String::new(),
+                });
+                open = true;
+            }
+            Line::Utterance {
+                speaker,
+                start_ms,
+                text,
+            } => {
+                segments.push(Segment {
+                    speaker,
+                    start_ms,
+                    end_ms: None,
+                    text,
+                });
+                open = false;
+            }
+            Line::Text(text) => match segments.last_mut() {
+                Some(last) if open || last.text.is_empty() => {
+                    if !last.text.is_empty() {
+                        last.text.push(' ');
+                    }
+                    last.text.push_str(&text);
+                    open = true;
+                }
+                // A paragraph after a blank line keeps the previous speaker.
+                Some(last) => {
+                    let speaker = last.speaker.clone();
+                    segments.push(Segment {
+                        speaker,
+                        text,
+                        ..Default::default()
+                    });
+                    open = true;
+                }
+                None => {
+                    segments.push(Segment {
+                        text,
+                        ..Default::default()
+                    });
+                    open = true;
+                }
+            },
+        }
+    }
+
+    segments.retain(|s| !s.text.is_empty());
+    fill_end_times(&mut segments);
+
+    ParsedTranscript {
+        segments,
+        ..Default::default()
+    }
+}
+
+fn classify(line: &str) -> Line {
+    let line = line.trim();
+    if line.is_empty() {
+        return Line::Blank;
+    }
+
+    if let Some(caps) = TIMESTAMP_FIRST.captures(line) {
+        let start_ms = parse_timestamp(&caps["ts"]);
+        let rest = caps["rest"].trim();
+
+        if rest.is_empty() {
+            return Line::Header {
+                speaker: None,
+                start_ms,
+            };
+        }
+
+        let (speaker, text) = split_speaker(rest);
+        return match (speaker, text.is_empty()) {
+            (Some(speaker), false) => Line::Utterance {
+                speaker: Some(speaker),
+                start_ms,
+                text,
+            },
+            _ if is_speaker_like(rest) => Line::Header {
+                speaker: Some(rest.to_string()),
+                start_ms,
+            },
+            _ => Line::Utterance {
+                speaker: None,
+                start_ms,
+                text: rest.to_string(),
+            },
+        };
+    }
+
+    if let Some(caps) = TIMESTAMP_BRACKETED.captures(line) {
+        let speaker = caps["speaker"].trim();
+        if is_speaker_like(speaker) {
+            let start_ms = parse_timestamp(&caps["ts"]);
+            let text = caps["text"].trim();
+
+            return if text.is_empty() {
+                Line::Header {
+                    speaker: Some(speaker.to_string()),
+                    start_ms,
+                }
+            } else {
+                Line::Utterance {
+                    speaker: Some(speaker.to_string()),
+                    start_ms,
+                    text: text.to_string(),
+                }
+            };
+        }
+    }
+
+    if let Some(caps) = TIMESTAMP_LAST.captures(line) {
+        let speaker = caps["speaker"].trim();
+        if is_speaker_like(speaker) {
+            return Line::Header {
+                speaker: Some(speaker.to_string()),
+                start_ms: parse_timestamp(&caps["ts"]),
+            };
+        }
+    }
+
+    match split_speaker(line) {
+        (Some(speaker), text) => Line::Utterance {
+            speaker: Some(speaker),
+            start_ms: None,
+            text,
+        },
+        (None, text) => Line::Text(text),
+    }
+}
+
+/// Splits a `Name: text` prefix off a line, if the name looks like one.
+pub(crate) fn split_speaker(line: &str) -> (Option<String>, String) {
+    if let Some(caps) = SPEAKER_PREFIX.captures(line) {
+        let speaker = caps["speaker"].trim();
+        if is_speaker_like(speaker) {
+            return (Some(speaker.to_string()), caps["text"].trim().to_string());
+        }
+    }
+
+    (None, line.trim().to_string())
+}
+
+// Names are short and never end a sentence; anything else is transcript text.
+fn is_speaker_like(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars().count() <= 60
+        && s.split_whitespace().count() <= 5
+        && !s.ends_with(['.', '?', '!', ','])
+        && s.chars().next().is_some_and(|c| !c.is_lowercase())
+        && !s.contains("http")
+}
+
+/// `1:02:03.5`, `02:03`, and `02:03,500` are all accepted.
+pub(crate) fn parse_timestamp(s: &str) -> Option<f64> {
+    let (clock, fraction) = match s.split_once(['.', ',']) {
+        Some((clock, fraction)) => (clock, Some(fraction)),
+        None => (s, None),
+    };
+
+    let mut seconds = 0u64;
+    for part in clock.split(':') {
+        seconds = seconds * 60 + part.parse::<u64>().ok()?;
+    }
+
+    let millis = match fraction {
+        Some(f) => format!("{:0<3}", f).parse::<u64>().ok()?,
+        None => 0,
+    };
+
+    Some((seconds * 1000 + millis) as f64)
+}
+
+// Text exports only carry start times; each segment runs until the next one starts.
+fn fill_end_times(segments: &mut [Segment]) {
+    for i in 1..segments.len() {
+        if segments[i - 1].end_ms.is_none() {
+            segments[i - 1].end_ms = segments[i].start_ms;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speakers(parsed: &ParsedTranscript) -> Vec<Option<&str>> {
+        parsed
+            .segments
+            .iter()
+            .map(|s| s.speaker.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("0:03"), Some(3000.0));
+        assert_eq!(parse_timestamp("1:02:03"), Some(3723000.0));
+        assert_eq!(parse_timestamp("00:00:01,5"), Some(1500.0));
+        assert_eq!(parse_timestamp("00:00:01.250"), Some(1250.0));
+        assert_eq!(parse_timestamp("a:00"), None);
+    }
+
+    #[test]
+    fn test_parse_otter_txt() {
+        let parsed = parse(
+            "Speaker 1  0:00\nHello everyone, thanks for joining.\nLet's start.\n\nJane Doe  0:07\nThanks for having me.\n",
+        );
+
+        assert_eq!(speakers(&parsed), vec![Some("Speaker 1"), Some("Jane Doe")]);
+        assert_eq!(
+            parsed.segments[0].text,
+            "Hello everyone, thanks for joining. Let's start."
+        );
+        assert_eq!(parsed.segments[0].start_ms, Some(0.0));
+        assert_eq!(parsed.segments[0].end_ms, Some(7000.0));
+        assert_eq!(parsed.segments[1].end_ms, None);
+    }
+
+    #[test]
+    fn test_parse_fireflies_txt() {
+        let parsed = parse("Alice Smith - 00:00\nHi Bob.\nBob Jones - 00:05\nHey Alice.\n");
+
+        assert_eq!(
+            speakers(&parsed),
+            vec![Some("Alice Smith"), Some("Bob Jones")]
+        );
+        assert_eq!(parsed.segments[1].start_ms, Some(5000.0));
+    }
+
+    #[test]
+    fn test_parse_inline_lines() {
+        let parsed = parse(
+            "[00:00:01] Alice: Hi there.\n[00:00:04] Bob: Hello.\nBob (00:09): Anyway, let's meet at 10:30.\nAlice: Sure.\n",
+        );
+
+        assert_eq!(
+            speakers(&parsed),
+            vec![Some("Alice"), Some("Bob"), Some("Bob"), Some("Alice")]
+        );
+        assert_eq!(parsed.segments[2].text, "Anyway, let's meet at 10:30.");
+        assert_eq!(parsed.segments[2].start_ms, Some(9000.0));
+        assert_eq!(parsed.segments[3].start_ms, None);
+    }
+
+    #[test]
+    fn test_parse_plain_text() {
+        let parsed = parse("We should ship this on Friday.\n\nnote: remember the docs\n");
+
+        assert_eq!(speakers(&parsed), vec![None, None]);
+        assert_eq!(parsed.segments[1].text, "note: remember the docs");
+    }
+}
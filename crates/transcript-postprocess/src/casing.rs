@@ -0,0 +1,188 @@
+//! Sentence casing and terminal punctuation for recognizers that return raw,
+//! lowercase text.
+
+use hypr_language::ISO639;
+
+use crate::Span;
+use crate::itn::capitalize;
+
+const SENTENCE_END: &[char] = &['.', '?', '!', '…', '。', '？', '！'];
+
+const EN_WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+const EN_QUESTION_WORDS: [&str; 9] = [
+    "what", "why", "how", "who", "whom", "whose", "where", "when", "which",
+];
+
+// Open a question only when a subject follows ("is it", "can we"), since they
+// also start statements ("is fine", "did well").
+const EN_QUESTION_AUXILIARIES: [&str; 14] = [
+    "is", "are", "was", "were", "do", "does", "did", "can", "could", "would", "should", "will",
+    "have", "has",
+];
+
+const EN_SUBJECT_PRONOUNS: [&str; 10] = [
+    "i", "you", "he", "she", "it", "we", "they", "this", "that", "there",
+];
+
+/// Capitalizes sentence starts, plus words English always capitalizes.
+pub(crate) fn apply_casing(spans: &mut [Span], language: Option<ISO639>) {
+    let mut sentence_start = true;
+
+    for span in spans.iter_mut() {
+        if sentence_start {
+            span.text = capitalize_first_letter(&span.text);
+        }
+
+        if language == Some(ISO639::En) {
+            span.text = english_casing(&span.text);
+        }
+
+        let trimmed = span.text.trim_end_matches(['"', '\'', ')', ']']);
+        sentence_start = trimmed.ends_with(SENTENCE_END);
+    }
+}
+
+/// Ends an unpunctuated segment with a period, or a question mark when an
+/// English segment opens with a question word. Segments that already end in
+/// punctuation, including a comma, are left alone.
+pub(crate) fn terminate(spans: &mut [Span], language: Option<ISO639>) {
+    let Some(last) = spans.last() else {
+        return;
+    };
+    if !last
+        .text
+        .ends_with(|c: char| c.is_alphanumeric() || c == '%')
+    {
+        return;
+    }
+
+    let is_question = language == Some(ISO639::En) && is_english_question(spans);
+
+    let mark = match language {
+        _ if is_question => "?",
+        Some(ISO639::Zh | ISO639::Ja) => "。",
+        _ => ".",
+    };
+
+    if let Some(last) = spans.last_mut() {
+        last.text.push_str(mark);
+    }
+}
+
+fn is_english_question(spans: &[Span]) -> bool {
+    let mut words = spans.iter().take(2).map(|span| {
+        span.text
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    });
+    let Some(first) = words.next() else {
+        return false;
+    };
+
+    EN_QUESTION_WORDS.contains(&first.as_str())
+        || (EN_QUESTION_AUXILIARIES.contains(&first.as_str())
+            && words
+                .next()
+                .is_some_and(|second| EN_SUBJECT_PRONOUNS.contains(&second.as_str())))
+}
+
+fn capitalize_first_letter(text: &str) -> String {
+    match text.find(char::is_alphabetic) {
+        Some(i) => format!("{}{}", &text[..i], capitalize(&text[i..])),
+        None => text.to_string(),
+    }
+}
+
+// "i" and its contractions, and weekday names.
+fn english_casing(text: &str) -> String {
+    let start = text.len()
+        - text
+            .trim_start_matches(|c: char| !c.is_alphanumeric())
+            .len();
+    let end = text.trim_end_matches(|c: char| !c.is_alphanumeric()).len();
+    if start >= end {
+        return text.to_string();
+    }
+
+    let word = &text[start..end];
+    let lower = word.to_lowercase();
+    let is_pronoun =
+        lower == "i" || ["i'm", "i'll", "i've", "i'd"].contains(&lower.replace('’', "'").as_str());
+
+    if is_pronoun || EN_WEEKDAYS.contains(&lower.as_str()) {
+        format!("{}{}{}", &text[..start], capitalize(word), &text[end..])
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(text: &str) -> Vec<Span> {
+        text.split_whitespace()
+            .enumerate()
+            .map(|(start, token)| Span {
+                start,
+                len: 1,
+                text: token.to_string(),
+            })
+            .collect()
+    }
+
+    fn render(spans: &[Span]) -> String {
+        spans
+            .iter()
+            .map(|span| span.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_casing() {
+        let mut s = spans("i think so. see you on friday, i'm out");
+        apply_casing(&mut s, Some(ISO639::En));
+        assert_eq!(render(&s), "I think so. See you on Friday, I'm out");
+
+        let mut s = spans("ça va. oui");
+        apply_casing(&mut s, Some(ISO639::Fr));
+        assert_eq!(render(&s), "Ça va. Oui");
+    }
+
+    #[test]
+    fn test_terminate() {
+        let mut s = spans("what time is it");
+        terminate(&mut s, Some(ISO639::En));
+        assert_eq!(render(&s), "what time is it?");
+
+        let mut s = spans("it is late");
+        terminate(&mut s, Some(ISO639::En));
+        assert_eq!(render(&s), "it is late.");
+
+        let mut s = spans("can we start");
+        terminate(&mut s, Some(ISO639::En));
+        assert_eq!(render(&s), "can we start?");
+
+        let mut s = spans("did well on the exam");
+        terminate(&mut s, Some(ISO639::En));
+        assert_eq!(render(&s), "did well on the exam.");
+
+        let mut s = spans("and then,");
+        terminate(&mut s, Some(ISO639::En));
+        assert_eq!(render(&s), "and then,");
+
+        let mut s = spans("你好");
+        terminate(&mut s, Some(ISO639::Zh));
+        assert_eq!(render(&s), "你好。");
+    }
+}
@@ -0,0 +1,115 @@
+use hypr_language::{ISO639, Language};
+
+use crate::casing::{apply_casing, terminate};
+use crate::itn::normalize;
+
+/// A run of `len` input tokens starting at `start`, rewritten as `text`.
+/// Spans from [`Formatter::format`] cover every input token, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub len: usize,
+    pub text: String,
+}
+
+/// Turns raw recognizer output ("i paid twenty five dollars on monday") into
+/// readable text ("I paid $25 on Monday."). Only terminal punctuation is
+/// added, and number normalization is English-only; see the crate docs.
+#[derive(Debug, Clone, Default)]
+pub struct Formatter {
+    language: Option<ISO639>,
+}
+
+impl Formatter {
+    /// Formats for the first of `languages`, the one the recognizer was
+    /// primed with.
+    pub fn new(languages: &[Language]) -> Self {
+        Self {
+            language: languages.first().map(Language::iso639),
+        }
+    }
+
+    /// Formats `tokens`, merging tokens where a spoken phrase becomes a single
+    /// written one. `end_of_segment` adds terminal punctuation when the
+    /// recognizer didn't.
+    pub fn format(&self, tokens: &[&str], end_of_segment: bool) -> Vec<Span> {
+        let mut spans = if self.language == Some(ISO639::En) {
+            normalize(tokens)
+        } else {
+            tokens
+                .iter()
+                .enumerate()
+                .map(|(start, token)| Span {
+                    start,
+                    len: 1,
+                    text: token.to_string(),
+                })
+                .collect()
+        };
+
+        apply_casing(&mut spans, self.language);
+        if end_of_segment {
+            terminate(&mut spans, self.language);
+        }
+
+        spans
+    }
+
+    pub fn format_text(&self, text: &str, end_of_segment: bool) -> String {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        self.format(&tokens, end_of_segment)
+            .into_iter()
+            .map(|span| span.text)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_text() {
+        let formatter = Formatter::new(&[Language::new(ISO639::En)]);
+        assert_eq!(
+            formatter.format_text("i paid twenty five dollars on monday", true),
+            "I paid $25 on Monday."
+        );
+        assert_eq!(
+            formatter.format_text("how many were there", true),
+            "How many were there?"
+        );
+        assert_eq!(
+            formatter.format_text("the meeting is on march third", false),
+            "The meeting is on March 3"
+        );
+
+        let formatter = Formatter::new(&[Language::new(ISO639::De)]);
+        assert_eq!(
+            formatter.format_text("wir sehen uns um zwei", true),
+            "Wir sehen uns um zwei."
+        );
+    }
+
+    #[test]
+    fn test_format_spans() {
+        let formatter = Formatter::new(&[Language::new(ISO639::En)]);
+        let spans = formatter.format(&["about", "fifty", "percent"], true);
+        assert_eq!(
+            spans,
+            vec![
+                Span {
+                    start: 0,
+                    len: 1,
+                    text: "About".to_string(),
+                },
+                Span {
+                    start: 1,
+                    len: 2,
+                    text: "50%.".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,458 @@
+//! English inverse text normalization: spoken numbers, percentages, currency
+//! amounts and dates become their written form.
+
+use crate::Span;
+
+const ONES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const ORDINAL_ONES: [&str; 9] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+];
+const ORDINAL_TEENS: [&str; 10] = [
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Ones,
+    Teen,
+    Tens,
+    Compound,
+    Hundred,
+    Scale,
+    And,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Cardinal {
+    text: String,
+    value: u64,
+    consumed: usize,
+    spoken: bool,
+}
+
+/// Rewrites number phrases in `tokens`. Returned spans cover every token, in
+/// order.
+pub(crate) fn normalize(tokens: &[&str]) -> Vec<Span> {
+    let cores: Vec<String> = tokens.iter().map(|t| core(t).to_lowercase()).collect();
+    let mut spans = Vec::with_capacity(tokens.len());
+    let mut start = 0;
+
+    while start < tokens.len() {
+        // A phrase stops at the first token carrying trailing punctuation.
+        let end = (start..tokens.len())
+            .find(|&i| has_trailing_punct(tokens[i]))
+            .map_or(tokens.len(), |i| i + 1);
+        let words: Vec<&str> = cores[start..end].iter().map(String::as_str).collect();
+
+        match date(&words).or_else(|| amount(&words)) {
+            Some((len, text)) => {
+                spans.push(Span {
+                    start,
+                    len,
+                    text: respell(&tokens[start..start + len], &text),
+                });
+                start += len;
+            }
+            None => {
+                spans.push(Span {
+                    start,
+                    len: 1,
+                    text: tokens[start].to_string(),
+                });
+                start += 1;
+            }
+        }
+    }
+
+    spans
+}
+
+// "twenty five percent" -> "25%", "ten dollars and fifty cents" -> "$10.50",
+// "three hundred" -> "300". A lone digit word stays spelled out.
+fn amount(words: &[&str]) -> Option<(usize, String)> {
+    let number = cardinal(words)?;
+    let rest = &words[number.consumed..];
+
+    match rest {
+        ["percent", ..] => return Some((number.consumed + 1, format!("{}%", number.text))),
+        ["per", "cent", ..] => return Some((number.consumed + 2, format!("{}%", number.text))),
+        _ => {}
+    }
+
+    if let Some(symbol) = rest.first().and_then(|w| currency_symbol(w)) {
+        let mut consumed = number.consumed + 1;
+        let mut text = format!("{}{}", symbol, number.text);
+
+        if rest.get(1) == Some(&"and")
+            && !number.text.contains('.')
+            && let Some(cents) = cardinal(&rest[2..]).filter(|c| c.value < 100)
+            && matches!(rest.get(2 + cents.consumed), Some(&("cents" | "cent")))
+        {
+            consumed += 2 + cents.consumed;
+            text = format!("{}.{:02}", text, cents.value);
+        }
+
+        return Some((consumed, text));
+    }
+
+    let worth_rewriting = number.spoken && (number.consumed > 1 || number.value >= 10);
+    worth_rewriting.then_some((number.consumed, number.text))
+}
+
+// "march third" -> "March 3", "july fourth twenty twenty five" -> "July 4, 2025".
+fn date(words: &[&str]) -> Option<(usize, String)> {
+    let month = MONTHS.iter().position(|m| Some(m) == words.first())?;
+    // "we may second that": only trust "may" before a written day.
+    if MONTHS[month] == "may"
+        && !words
+            .get(1)
+            .is_some_and(|w| w.starts_with(|c: char| c.is_ascii_digit()))
+    {
+        return None;
+    }
+    let (day, day_len) = ordinal(&words[1..]).filter(|(day, _)| (1..=31).contains(day))?;
+    let mut consumed = 1 + day_len;
+    let mut text = format!("{} {}", capitalize(MONTHS[month]), day);
+
+    if let Some(year) = cardinal(&words[consumed..]).filter(|y| (1000..3000).contains(&y.value)) {
+        consumed += year.consumed;
+        text = format!("{}, {}", text, year.value);
+    }
+
+    Some((consumed, text))
+}
+
+fn ordinal(words: &[&str]) -> Option<(u64, usize)> {
+    let first = *words.first()?;
+
+    if let Some(value) = ordinal_word(first) {
+        return Some((value, 1));
+    }
+
+    if let Some(tens) = TENS.iter().position(|t| *t == first)
+        && let Some(ones) = words
+            .get(1)
+            .and_then(|w| ordinal_word(w))
+            .filter(|v| *v < 10)
+    {
+        return Some(((tens as u64 + 2) * 10 + ones, 2));
+    }
+
+    let digits = first.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let suffix = &first[digits.len()..];
+    if !digits.is_empty()
+        && digits.chars().all(|c| c.is_ascii_digit())
+        && matches!(suffix, "" | "st" | "nd" | "rd" | "th")
+    {
+        return Some((digits.parse().ok()?, 1));
+    }
+
+    None
+}
+
+fn ordinal_word(word: &str) -> Option<u64> {
+    if let Some((tens, ones)) = word.split_once('-') {
+        let tens = TENS.iter().position(|t| *t == tens)? as u64;
+        let ones = ORDINAL_ONES.iter().position(|o| *o == ones)? as u64;
+        return Some((tens + 2) * 10 + ones + 1);
+    }
+
+    if let Some(i) = ORDINAL_ONES.iter().position(|o| *o == word) {
+        return Some(i as u64 + 1);
+    }
+    if let Some(i) = ORDINAL_TEENS.iter().position(|o| *o == word) {
+        return Some(i as u64 + 10);
+    }
+    match word {
+        "twentieth" => Some(20),
+        "thirtieth" => Some(30),
+        _ => None,
+    }
+}
+
+fn small_number(word: &str) -> Option<(u64, Part)> {
+    if let Some(i) = ONES.iter().position(|w| *w == word) {
+        return Some((i as u64, Part::Ones));
+    }
+    if let Some(i) = TEENS.iter().position(|w| *w == word) {
+        return Some((i as u64 + 10, Part::Teen));
+    }
+    if let Some(i) = TENS.iter().position(|w| *w == word) {
+        return Some(((i as u64 + 2) * 10, Part::Tens));
+    }
+
+    let (tens, ones) = word.split_once('-')?;
+    let tens = TENS.iter().position(|w| *w == tens)? as u64;
+    let ones = ONES.iter().position(|w| *w == ones).filter(|&i| i > 0)? as u64;
+    Some(((tens + 2) * 10 + ones, Part::Compound))
+}
+
+fn scale(word: &str) -> Option<u64> {
+    match word {
+        "thousand" => Some(1_000),
+        "million" => Some(1_000_000),
+        "billion" => Some(1_000_000_000),
+        _ => None,
+    }
+}
+
+fn currency_symbol(word: &str) -> Option<&'static str> {
+    match word {
+        "dollar" | "dollars" | "bucks" => Some("$"),
+        "euro" | "euros" => Some("€"),
+        _ => None,
+    }
+}
+
+// Reads the longest spoken number at the start of `words`, or a single token
+// that is already written in digits.
+fn cardinal(words: &[&str]) -> Option<Cardinal> {
+    let first = *words.first()?;
+    if is_written_number(first) {
+        return Some(Cardinal {
+            text: first.to_string(),
+            value: first.replace(',', "").split('.').next()?.parse().ok()?,
+            consumed: 1,
+            spoken: false,
+        });
+    }
+
+    let mut total = 0u64;
+    let mut current = 0u64;
+    let mut consumed = 0;
+    let mut last: Option<Part> = None;
+    let mut year = false;
+    let mut decimals = String::new();
+
+    while let Some(&word) = words.get(consumed) {
+        let number_parts = matches!(
+            last,
+            Some(Part::Ones | Part::Teen | Part::Tens | Part::Compound)
+        );
+
+        if let Some((value, part)) = small_number(word) {
+            match (last, part) {
+                (None, _) => current = value,
+                (Some(Part::Tens), Part::Ones) if value > 0 && current.is_multiple_of(10) => {
+                    current += value
+                }
+                (Some(Part::Hundred | Part::Scale | Part::And), _)
+                    if current.is_multiple_of(100) =>
+                {
+                    current += value
+                }
+                // Years read in pairs: "nineteen ninety", "twenty twenty".
+                (
+                    Some(Part::Teen | Part::Tens | Part::Compound),
+                    Part::Teen | Part::Tens | Part::Compound,
+                ) if !year && total == 0 && (11..100).contains(&current) => {
+                    current = current * 100 + value;
+                    year = true;
+                }
+                _ => break,
+            }
+            last = Some(part);
+        } else if word == "hundred" && number_parts && !year && (1..100).contains(&current) {
+            current *= 100;
+            last = Some(Part::Hundred);
+        } else if let Some(scale) = scale(word).filter(|_| !year && current > 0)
+            && matches!(last, Some(p) if p != Part::Scale && p != Part::And)
+        {
+            total += current * scale;
+            current = 0;
+            last = Some(Part::Scale);
+        } else if word == "and"
+            && matches!(last, Some(Part::Hundred | Part::Scale))
+            && words
+                .get(consumed + 1)
+                .is_some_and(|w| small_number(w).is_some())
+        {
+            last = Some(Part::And);
+        } else if word == "point" && last.is_some() && !year {
+            let digits: String = words[consumed + 1..]
+                .iter()
+                .map_while(|w| ONES.iter().position(|o| o == w))
+                .map(|d| char::from(b'0' + d as u8))
+                .collect();
+            if digits.is_empty() {
+                break;
+            }
+            consumed += 1 + digits.len();
+            decimals = digits;
+            break;
+        } else {
+            break;
+        }
+
+        consumed += 1;
+    }
+
+    last?;
+
+    let value = total + current;
+    let mut text = if value >= 10_000 && !year {
+        group_thousands(value)
+    } else {
+        value.to_string()
+    };
+    if !decimals.is_empty() {
+        text = format!("{}.{}", text, decimals);
+    }
+
+    Some(Cardinal {
+        text,
+        value,
+        consumed,
+        spoken: true,
+    })
+}
+
+fn is_written_number(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_ascii_digit())
+        && word
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ',' || c == '.')
+}
+
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+pub(crate) fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn is_edge_punct(c: char) -> bool {
+    !c.is_alphanumeric()
+}
+
+fn core(token: &str) -> &str {
+    token.trim_matches(|c: char| is_edge_punct(c) && c != '-')
+}
+
+fn has_trailing_punct(token: &str) -> bool {
+    token.ends_with(is_edge_punct)
+}
+
+// Keeps punctuation that surrounded the original words.
+fn respell(tokens: &[&str], text: &str) -> String {
+    let first = tokens.first().copied().unwrap_or_default();
+    let last = tokens.last().copied().unwrap_or_default();
+
+    let leading = &first[..first.len() - first.trim_start_matches(is_edge_punct).len()];
+    let trailing = &last[last.trim_end_matches(is_edge_punct).len()..];
+
+    format!("{leading}{text}{trailing}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(text: &str) -> String {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        normalize(&tokens)
+            .into_iter()
+            .map(|span| span.text)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(render("about twenty five people"), "about 25 people");
+        assert_eq!(render("one hundred and three days"), "103 days");
+        assert_eq!(render("two thousand four hundred fifty six"), "2456");
+        assert_eq!(render("twelve million users"), "12,000,000 users");
+        assert_eq!(render("three point one four"), "3.14");
+        assert_eq!(render("back in nineteen ninety nine"), "back in 1999");
+        assert_eq!(render("twenty-one pilots"), "21 pilots");
+    }
+
+    #[test]
+    fn test_small_numbers_stay_spelled_out() {
+        assert_eq!(render("I have one question"), "I have one question");
+        assert_eq!(render("the one and only"), "the one and only");
+        assert_eq!(render("one two three"), "one two three");
+    }
+
+    #[test]
+    fn test_percent_and_currency() {
+        assert_eq!(render("growth was twenty five percent."), "growth was 25%.");
+        assert_eq!(render("five per cent"), "5%");
+        assert_eq!(render("it costs 30 dollars"), "it costs $30");
+        assert_eq!(
+            render("ten dollars and fifty cents, please"),
+            "$10.50, please"
+        );
+        assert_eq!(render("two hundred euros"), "€200");
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(render("on march third we ship"), "on March 3 we ship");
+        assert_eq!(
+            render("by july twenty-first twenty twenty five."),
+            "by July 21, 2025."
+        );
+        assert_eq!(render("due may 5th"), "due May 5");
+        assert_eq!(render("we may second that"), "we may second that");
+    }
+
+    #[test]
+    fn test_stops_at_punctuation() {
+        assert_eq!(render("twenty, five"), "20, five");
+    }
+}
@@ -0,0 +1,18 @@
+//! Formatting for recognizers that return raw, lowercase text, such as the
+//! local models.
+//!
+//! Scope is deliberately narrow:
+//!
+//! - Punctuation is terminal only. A final segment gets a period, or a
+//!   question mark when it opens with an English question word; commas and
+//!   other inner punctuation are never inserted.
+//! - Casing capitalizes sentence starts in any language, plus English "I"
+//!   and weekdays.
+//! - Inverse text normalization (numbers, percentages, currency, dates) is
+//!   English-only. Other languages keep their spoken-form numbers.
+
+mod casing;
+mod formatter;
+mod itn;
+
+pub use formatter::*;
@@ -0,0 +1,219 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use crate::Vocabulary;
+
+// Recognizers tend to split unknown words, so a term can match up to this many
+// transcript tokens ("hyper note" for "Hyprnote").
+const MAX_SPAN_TOKENS: usize = 4;
+
+// Frequent English words, lowercase. A term spelled like one ("Will", "Design")
+// is only recapitalized on stronger evidence than a single matching word.
+static COMMON_WORDS: LazyLock<HashSet<&'static str>> =
+    LazyLock::new(|| include_str!("common_words.txt").lines().collect());
+
+/// Rewrites misrecognized vocabulary terms in finished transcripts, for
+/// providers that can't be biased toward them up front.
+#[derive(Debug, Clone, Default)]
+pub struct Corrector {
+    // Normalized spelling (lowercase alphanumerics, no spaces) to replacement.
+    spellings: HashMap<String, String>,
+}
+
+/// `len` tokens starting at `start` should read `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Correction {
+    pub start: usize,
+    pub len: usize,
+    pub text: String,
+}
+
+impl Corrector {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let mut spellings = HashMap::new();
+
+        // Lowest priority first, so higher-priority terms overwrite collisions.
+        for term in vocabulary.terms().into_iter().rev() {
+            for variant in std::iter::once(&term.text).chain(&term.sounds_like) {
+                let key = normalize(variant);
+                if !key.is_empty() {
+                    spellings.insert(key, term.text.clone());
+                }
+            }
+        }
+
+        Self { spellings }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spellings.is_empty()
+    }
+
+    /// Finds token spans that spell a term or one of its sounds-like hints,
+    /// preferring the longest span at each position. A lone common word that
+    /// differs from a term only in case is left alone.
+    pub fn find(&self, tokens: &[&str]) -> Vec<Correction> {
+        let mut corrections = Vec::new();
+        let mut start = 0;
+
+        while start < tokens.len() {
+            let max_len = MAX_SPAN_TOKENS.min(tokens.len() - start);
+
+            let found = (1..=max_len).rev().find_map(|len| {
+                let span = &tokens[start..start + len];
+                let parts: Vec<String> = span.iter().map(|t| normalize(t)).collect();
+                if parts.iter().any(String::is_empty) {
+                    return None;
+                }
+                self.spellings
+                    .get(&parts.concat())
+                    .map(|text| (len, text.clone()))
+            });
+
+            match found {
+                Some((len, text)) => {
+                    let span = &tokens[start..start + len];
+                    let original = core(span);
+                    if original != text && !is_common_word_recase(&original, &text) {
+                        corrections.push(Correction { start, len, text });
+                    }
+                    start += len;
+                }
+                None => start += 1,
+            }
+        }
+
+        corrections
+    }
+
+    pub fn correct_text(&self, text: &str) -> String {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let corrections = self.find(&tokens);
+        if corrections.is_empty() {
+            return text.to_string();
+        }
+
+        let mut out: Vec<String> = Vec::with_capacity(tokens.len());
+        let mut next = 0;
+        for correction in &corrections {
+            out.extend(tokens[next..correction.start].iter().map(|t| t.to_string()));
+            out.push(correction.respell(&tokens));
+            next = correction.start + correction.len;
+        }
+        out.extend(tokens[next..].iter().map(|t| t.to_string()));
+
+        out.join(" ")
+    }
+}
+
+impl Correction {
+    /// The replacement, keeping punctuation that surrounded the original span.
+    pub fn respell(&self, tokens: &[&str]) -> String {
+        let span = &tokens[self.start..self.start + self.len];
+        let first = span.first().copied().unwrap_or_default();
+        let last = span.last().copied().unwrap_or_default();
+
+        let leading = &first[..first.len() - first.trim_start_matches(is_edge_punct).len()];
+        let trailing = &last[last.trim_end_matches(is_edge_punct).len()..];
+
+        format!("{leading}{}{trailing}", self.text)
+    }
+}
+
+fn is_edge_punct(c: char) -> bool {
+    !c.is_alphanumeric()
+}
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_common_word_recase(original: &str, text: &str) -> bool {
+    let lower = original.to_lowercase();
+    !original.contains(' ') && lower == text.to_lowercase() && COMMON_WORDS.contains(lower.as_str())
+}
+
+fn core(span: &[&str]) -> String {
+    span.iter()
+        .map(|t| t.trim_matches(is_edge_punct))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Term, TermKind, TermSource};
+
+    fn corrector() -> Corrector {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.push(
+            Term::new("Hyprnote", TermKind::Product, TermSource::User)
+                .with_sounds_like(["hyper note", "hipper note"]),
+        );
+        vocabulary.push(Term::new("Kubernetes", TermKind::Product, TermSource::User));
+        vocabulary.extend_participants(["Yujong Lee"]);
+        Corrector::new(&vocabulary)
+    }
+
+    #[test]
+    fn test_correct_text() {
+        let corrector = corrector();
+
+        assert_eq!(
+            corrector.correct_text("I use hyper note, with kubernetes."),
+            "I use Hyprnote, with Kubernetes."
+        );
+        assert_eq!(
+            corrector.correct_text("thanks yujong lee!"),
+            "thanks Yujong Lee!"
+        );
+        assert_eq!(
+            corrector.correct_text("Hyprnote is great"),
+            "Hyprnote is great"
+        );
+        assert_eq!(corrector.correct_text(""), "");
+    }
+
+    #[test]
+    fn test_find_joins_split_tokens() {
+        let corrector = corrector();
+
+        assert_eq!(
+            corrector.find(&["the", "hypr", "note", "app"]),
+            vec![Correction {
+                start: 1,
+                len: 2,
+                text: "Hyprnote".to_string(),
+            }]
+        );
+        assert!(corrector.find(&["Hyprnote"]).is_empty());
+    }
+
+    #[test]
+    fn test_common_words_need_more_evidence() {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.extend_participants(["Will"]);
+        vocabulary.push(
+            Term::new("Design", TermKind::Product, TermSource::User).with_sounds_like(["dezign"]),
+        );
+        vocabulary.push(Term::new(
+            "Design Review",
+            TermKind::Other,
+            TermSource::User,
+        ));
+        let corrector = Corrector::new(&vocabulary);
+
+        assert_eq!(
+            corrector.correct_text("will you send the design?"),
+            "will you send the design?"
+        );
+        assert_eq!(
+            corrector.correct_text("the dezign is in the design review"),
+            "the Design is in the Design Review"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("invalid vocabulary settings: {0}")]
+    InvalidSettings(#[from] serde_json::Error),
+}
@@ -0,0 +1,129 @@
+use crate::TermKind;
+
+// Words that show up capitalized in meeting titles without being worth boosting.
+const TITLE_STOPWORDS: &[&str] = &[
+    "a",
+    "all",
+    "an",
+    "and",
+    "at",
+    "call",
+    "catch",
+    "check",
+    "chat",
+    "daily",
+    "demo",
+    "discussion",
+    "for",
+    "from",
+    "hands",
+    "in",
+    "interview",
+    "intro",
+    "kickoff",
+    "meeting",
+    "monthly",
+    "of",
+    "on",
+    "or",
+    "planning",
+    "quarterly",
+    "retro",
+    "review",
+    "session",
+    "standup",
+    "sync",
+    "team",
+    "the",
+    "to",
+    "up",
+    "update",
+    "vs",
+    "weekly",
+    "with",
+    "x",
+];
+
+const MAX_PHRASE_WORDS: usize = 3;
+
+/// Pulls names, product terms and acronyms out of a calendar event title.
+///
+/// Runs of capitalized words become one phrase ("Acme Corp"), all-caps tokens
+/// become acronyms ("OKR") and tokens with inner capitals become product terms
+/// ("GitHub"). Generic meeting words are skipped.
+pub(crate) fn title_terms(title: &str) -> Vec<(String, TermKind)> {
+    let mut terms = Vec::new();
+    let mut phrase: Vec<&str> = Vec::new();
+
+    let flush = |phrase: &mut Vec<&str>, terms: &mut Vec<(String, TermKind)>| {
+        if !phrase.is_empty() {
+            terms.push((phrase.join(" "), TermKind::Other));
+            phrase.clear();
+        }
+    };
+
+    for token in title
+        .split(|c: char| c.is_whitespace() || "/|:,;()[]<>&+\u{2013}\u{2014}".contains(c))
+        .map(|token| token.trim_matches(|c: char| !c.is_alphanumeric()))
+    {
+        if token.is_empty() || TITLE_STOPWORDS.contains(&token.to_lowercase().as_str()) {
+            flush(&mut phrase, &mut terms);
+            continue;
+        }
+
+        if is_acronym(token) {
+            flush(&mut phrase, &mut terms);
+            terms.push((token.to_string(), TermKind::Acronym));
+        } else if has_inner_capital(token) {
+            flush(&mut phrase, &mut terms);
+            terms.push((token.to_string(), TermKind::Product));
+        } else if token.chars().next().is_some_and(char::is_uppercase) {
+            phrase.push(token);
+            if phrase.len() == MAX_PHRASE_WORDS {
+                flush(&mut phrase, &mut terms);
+            }
+        } else {
+            flush(&mut phrase, &mut terms);
+        }
+    }
+    flush(&mut phrase, &mut terms);
+
+    terms
+}
+
+fn is_acronym(token: &str) -> bool {
+    let upper = token.chars().filter(|c| c.is_uppercase()).count();
+    upper >= 2
+        && token.chars().count() <= 6
+        && token
+            .chars()
+            .all(|c| c.is_uppercase() || c.is_ascii_digit())
+}
+
+fn has_inner_capital(token: &str) -> bool {
+    token.chars().skip(1).any(char::is_uppercase) && token.chars().any(char::is_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_terms() {
+        let terms = title_terms("Weekly Sync with Acme Corp: OKR review / GitHub migration");
+        assert_eq!(
+            terms,
+            vec![
+                ("Acme Corp".to_string(), TermKind::Other),
+                ("OKR".to_string(), TermKind::Acronym),
+                ("GitHub".to_string(), TermKind::Product),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_title_without_terms() {
+        assert!(title_terms("Daily standup").is_empty());
+        assert!(title_terms("1:1").is_empty());
+    }
+}
@@ -0,0 +1,10 @@
+mod correct;
+mod error;
+mod extract;
+mod term;
+mod vocabulary;
+
+pub use correct::*;
+pub use error::*;
+pub use term::*;
+pub use vocabulary::*;
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Term {
+    pub text: String,
+    /// How the term tends to come out of a recognizer that doesn't know it,
+    /// e.g. "hyper note" for "Hyprnote".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sounds_like: Vec<String>,
+    #[serde(default)]
+    pub kind: TermKind,
+    #[serde(default)]
+    pub source: TermSource,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermKind {
+    Name,
+    Product,
+    Acronym,
+    #[default]
+    Other,
+}
+
+/// Where a term came from. Declared in priority order: when a provider caps the
+/// number of keywords, earlier sources win.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TermSource {
+    #[default]
+    User,
+    Participant,
+    Calendar,
+    Note,
+}
+
+impl Term {
+    pub fn new(text: impl Into<String>, kind: TermKind, source: TermSource) -> Self {
+        Self {
+            text: text.into(),
+            sounds_like: vec![],
+            kind,
+            source,
+        }
+    }
+
+    pub fn with_sounds_like(
+        mut self,
+        sounds_like: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.sounds_like = sounds_like.into_iter().map(Into::into).collect();
+        self
+    }
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Term, TermKind, TermSource};
+
+/// Where the user's term list lives in the settings file, next to the other
+/// language settings.
+pub const SETTINGS_POINTER: &str = "/language/vocabulary";
+
+// Read when the list predates the settings editor and sits at the top level.
+const LEGACY_SETTINGS_KEY: &str = "vocabulary";
+
+const MAX_TERM_CHARS: usize = 100;
+
+/// Terms to bias transcription toward, deduplicated case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Vocabulary {
+    terms: Vec<Term>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the user-maintained list from the settings JSON. A missing key
+    /// yields an empty vocabulary.
+    pub fn from_settings(settings: &serde_json::Value) -> Result<Self, crate::Error> {
+        let mut vocabulary = Self::new();
+
+        let Some(value) = settings
+            .pointer(SETTINGS_POINTER)
+            .or_else(|| settings.get(LEGACY_SETTINGS_KEY))
+            .filter(|v| !v.is_null())
+        else {
+            return Ok(vocabulary);
+        };
+
+        for term in Vec::<Term>::deserialize(value)? {
+            vocabulary.push(Term {
+                source: TermSource::User,
+                ..term
+            });
+        }
+
+        Ok(vocabulary)
+    }
+
+    /// Adds a term, merging it into an existing entry with the same spelling.
+    /// The higher-priority source keeps its spelling and kind.
+    pub fn push(&mut self, mut term: Term) {
+        term.text = term.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if term.text.is_empty() || term.text.chars().count() > MAX_TERM_CHARS {
+            return;
+        }
+
+        let key = term.text.to_lowercase();
+        let Some(existing) = self.terms.iter_mut().find(|t| t.text.to_lowercase() == key) else {
+            self.terms.push(term);
+            return;
+        };
+
+        for hint in std::mem::take(&mut term.sounds_like) {
+            if !existing.sounds_like.contains(&hint) {
+                existing.sounds_like.push(hint);
+            }
+        }
+
+        if term.source < existing.source {
+            existing.text = term.text;
+            existing.kind = term.kind;
+            existing.source = term.source;
+        }
+    }
+
+    /// Adds meeting participants by display name. Email addresses are skipped.
+    pub fn extend_participants<S: AsRef<str>>(&mut self, names: impl IntoIterator<Item = S>) {
+        for name in names {
+            let name = name.as_ref();
+            if name.contains('@') {
+                continue;
+            }
+            self.push(Term::new(name, TermKind::Name, TermSource::Participant));
+        }
+    }
+
+    pub fn extend_event_title(&mut self, title: &str) {
+        for (text, kind) in crate::extract::title_terms(title) {
+            self.push(Term::new(text, kind, TermSource::Calendar));
+        }
+    }
+
+    /// Adds keywords extracted from the note itself.
+    pub fn extend_keywords<S: AsRef<str>>(&mut self, keywords: impl IntoIterator<Item = S>) {
+        for keyword in keywords {
+            self.push(Term::new(
+                keyword.as_ref(),
+                TermKind::Other,
+                TermSource::Note,
+            ));
+        }
+    }
+
+    /// Terms ordered by source priority, keeping insertion order within a
+    /// source.
+    pub fn terms(&self) -> Vec<&Term> {
+        let mut terms: Vec<&Term> = self.terms.iter().collect();
+        terms.sort_by_key(|t| t.source);
+        terms
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_settings() {
+        let settings = serde_json::json!({
+            "language": {
+                "vocabulary": [
+                    { "text": "Hyprnote", "sounds_like": ["hyper note"], "kind": "product" },
+                    { "text": "Yujong", "kind": "name", "source": "calendar" },
+                ],
+            },
+            "other": true,
+        });
+
+        let vocabulary = Vocabulary::from_settings(&settings).unwrap();
+        let terms = vocabulary.terms();
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms[0].sounds_like, vec!["hyper note"]);
+        assert_eq!(terms[0].kind, TermKind::Product);
+        assert!(terms.iter().all(|t| t.source == TermSource::User));
+
+        assert!(
+            Vocabulary::from_settings(&serde_json::json!({}))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            Vocabulary::from_settings(&serde_json::json!({ "language": { "vocabulary": 1 } }))
+                .is_err()
+        );
+
+        let legacy = serde_json::json!({ "vocabulary": [{ "text": "Hyprnote" }] });
+        assert_eq!(Vocabulary::from_settings(&legacy).unwrap().terms().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_prefers_higher_priority_source() {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.extend_keywords(["acme corp"]);
+        vocabulary.extend_event_title("Intro with Acme Corp");
+        vocabulary.push(
+            Term::new("ACME Corp", TermKind::Product, TermSource::User)
+                .with_sounds_like(["acne corp"]),
+        );
+
+        assert_eq!(vocabulary.len(), 1);
+        let term = vocabulary.terms()[0];
+        assert_eq!(term.text, "ACME Corp");
+        assert_eq!(term.kind, TermKind::Product);
+        assert_eq!(term.source, TermSource::User);
+        assert_eq!(term.sounds_like, vec!["acne corp"]);
+    }
+
+    #[test]
+    fn test_terms_are_ranked_by_source() {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.extend_keywords(["roadmap"]);
+        vocabulary.extend_event_title("OKR planning");
+        vocabulary.extend_participants(["Jane Doe", "john@example.com", "  "]);
+        vocabulary.push(Term::new("Hyprnote", TermKind::Product, TermSource::User));
+
+        let texts: Vec<_> = vocabulary.terms().iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["Hyprnote", "Jane Doe", "OKR", "roadmap"]);
+    }
+}
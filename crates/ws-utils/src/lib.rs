@@ -8,6 +8,7 @@ use axum::extract::ws::{Message, WebSocket};
 use futures_util::{Stream, StreamExt, stream::SplitStream};
 use tokio::sync::mpsc::{Receiver, channel};
 
+use hypr_audio_opus::OpusDecoder;
 use hypr_audio_utils::{bytes_to_f32_samples, mix_audio_f32};
 use owhisper_interface::ListenInputChunk;
 
@@ -35,13 +36,28 @@ fn deinterleave_audio(data: &[u8]) -> (Vec<f32>, Vec<f32>) {
     (mic, speaker)
 }
 
-fn process_ws_message(message: Message, channels: Option<u32>) -> AudioProcessResult {
+fn process_ws_message(
+    message: Message,
+    channels: Option<u32>,
+    decoder: Option<&mut OpusDecoder>,
+) -> AudioProcessResult {
     match message {
         Message::Binary(data) => {
             if data.is_empty() {
                 return AudioProcessResult::Empty;
             }
 
+            let data = match decoder {
+                Some(decoder) => match decoder.decode(&data) {
+                    Ok(pcm) => pcm,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "opus_decode_failed");
+                        return AudioProcessResult::Empty;
+                    }
+                },
+                None => data,
+            };
+
             match channels {
                 Some(2) => {
                     let (mic, speaker) = deinterleave_audio(&data);
@@ -73,6 +89,7 @@ fn process_ws_message(message: Message, channels: Option<u32>) -> AudioProcessRe
 pub struct WebSocketAudioSource {
     receiver: Option<SplitStream<WebSocket>>,
     sample_rate: u32,
+    decoder: Option<OpusDecoder>,
     buffer: Vec<f32>,
     buffer_idx: usize,
 }
@@ -82,10 +99,24 @@ impl WebSocketAudioSource {
         Self {
             receiver: Some(receiver),
             sample_rate,
+            decoder: None,
             buffer: Vec::new(),
             buffer_idx: 0,
         }
     }
+
+    /// Same as [`WebSocketAudioSource::new`], for clients sending mono Opus
+    /// packets (`encoding=opus`) instead of raw PCM.
+    pub fn opus(
+        receiver: SplitStream<WebSocket>,
+        sample_rate: u32,
+    ) -> Result<Self, hypr_audio_opus::Error> {
+        let decoder = OpusDecoder::new(sample_rate, 1)?;
+        Ok(Self {
+            decoder: Some(decoder),
+            ..Self::new(receiver, sample_rate)
+        })
+    }
 }
 
 impl Stream for WebSocketAudioSource {
@@ -102,30 +133,33 @@ impl Stream for WebSocketAudioSource {
             self.buffer.clear();
             self.buffer_idx = 0;
 
-            let Some(receiver) = self.receiver.as_mut() else {
+            let this = &mut *self;
+            let Some(receiver) = this.receiver.as_mut() else {
                 return Poll::Ready(None);
             };
 
             match Pin::new(receiver).poll_next(cx) {
-                Poll::Ready(Some(Ok(message))) => match process_ws_message(message, None) {
-                    AudioProcessResult::Samples(mut samples) => {
-                        if samples.is_empty() {
-                            continue;
+                Poll::Ready(Some(Ok(message))) => {
+                    match process_ws_message(message, None, this.decoder.as_mut()) {
+                        AudioProcessResult::Samples(mut samples) => {
+                            if samples.is_empty() {
+                                continue;
+                            }
+                            self.buffer.append(&mut samples);
+                            self.buffer_idx = 0;
                         }
-                        self.buffer.append(&mut samples);
-                        self.buffer_idx = 0;
-                    }
-                    AudioProcessResult::DualSamples { mic, speaker } => {
-                        let mut mixed = mix_audio_f32(&mic, &speaker);
-                        if mixed.is_empty() {
-                            continue;
+                        AudioProcessResult::DualSamples { mic, speaker } => {
+                            let mut mixed = mix_audio_f32(&mic, &speaker);
+                            if mixed.is_empty() {
+                                continue;
+                            }
+                            self.buffer.append(&mut mixed);
+                            self.buffer_idx = 0;
                         }
-                        self.buffer.append(&mut mixed);
-                        self.buffer_idx = 0;
+                        AudioProcessResult::Empty => continue,
+                        AudioProcessResult::End => return Poll::Ready(None),
                     }
-                    AudioProcessResult::Empty => continue,
-                    AudioProcessResult::End => return Poll::Ready(None),
-                },
+                }
                 Poll::Ready(Some(Err(_))) => return Poll::Ready(None),
                 Poll::Ready(None) => return Poll::Ready(None),
                 Poll::Pending => return Poll::Pending,
@@ -208,15 +242,37 @@ impl hypr_audio_interface::AsyncSource for ChannelAudioSource {
 }
 
 pub fn split_dual_audio_sources(
+    ws_receiver: SplitStream<WebSocket>,
+    sample_rate: u32,
+) -> (ChannelAudioSource, ChannelAudioSource) {
+    spawn_dual_audio_sources(ws_receiver, sample_rate, None)
+}
+
+/// Same as [`split_dual_audio_sources`], for clients sending interleaved
+/// stereo Opus packets (`encoding=opus`) instead of raw PCM.
+pub fn split_dual_opus_audio_sources(
+    ws_receiver: SplitStream<WebSocket>,
+    sample_rate: u32,
+) -> Result<(ChannelAudioSource, ChannelAudioSource), hypr_audio_opus::Error> {
+    let decoder = OpusDecoder::new(sample_rate, 2)?;
+    Ok(spawn_dual_audio_sources(
+        ws_receiver,
+        sample_rate,
+        Some(decoder),
+    ))
+}
+
+fn spawn_dual_audio_sources(
     mut ws_receiver: SplitStream<WebSocket>,
     sample_rate: u32,
+    mut decoder: Option<OpusDecoder>,
 ) -> (ChannelAudioSource, ChannelAudioSource) {
     let (mic_tx, mic_rx) = channel::<Vec<f32>>(AUDIO_CHANNEL_CAPACITY);
     let (speaker_tx, speaker_rx) = channel::<Vec<f32>>(AUDIO_CHANNEL_CAPACITY);
 
     tokio::spawn(async move {
         while let Some(Ok(message)) = ws_receiver.next().await {
-            match process_ws_message(message, Some(2)) {
+            match process_ws_message(message, Some(2), decoder.as_mut()) {
                 AudioProcessResult::Samples(samples) => {
                     if mic_tx.try_send(samples.clone()).is_err() {
                         tracing::warn!("mic_channel_full_dropping_audio");
@@ -1,4 +1,4 @@
 // This file is auto-generated by @hey-api/openapi-ts
 
 export { canStartTrial, createConnectSession, createEvent, listCalendars, listEvents, nangoWebhook, type Options, startTrial, submit } from './sdk.gen';
-export type { CanStartTrialData, CanStartTrialErrors, CanStartTrialReason, CanStartTrialResponse, CanStartTrialResponse2, CanStartTrialResponses, ClientOptions, ConnectSessionResponse, CreateConnectSessionData, CreateConnectSessionErrors, CreateConnectSessionResponse, CreateConnectSessionResponses, CreateEventData, CreateEventErrors, CreateEventRequest, CreateEventResponse, CreateEventResponse2, CreateEventResponses, DeviceInfo, EventAttendee, EventDateTime, FeedbackRequest, FeedbackResponse, FeedbackType, Interval, ListCalendarsData, ListCalendarsErrors, ListCalendarsRequest, ListCalendarsResponse, ListCalendarsResponse2, ListCalendarsResponses, ListEventsData, ListEventsErrors, ListEventsRequest, ListEventsResponse, ListEventsResponse2, ListEventsResponses, NangoWebhookData, NangoWebhookErrors, NangoWebhookResponse, NangoWebhookResponses, StartTrialData, StartTrialErrors, StartTrialReason, StartTrialResponse, StartTrialResponse2, StartTrialResponses, SubmitData, SubmitError, SubmitErrors, SubmitResponse, SubmitResponses, WebhookResponse } from './types.gen';
+export type { CalendarProvider, CanStartTrialData, CanStartTrialErrors, CanStartTrialReason, CanStartTrialResponse, CanStartTrialResponse2, CanStartTrialResponses, ClientOptions, ConnectSessionResponse, CreateConnectSessionData, CreateConnectSessionErrors, CreateConnectSessionResponse, CreateConnectSessionResponses, CreateEventData, CreateEventErrors, CreateEventRequest, CreateEventResponse, CreateEventResponse2, CreateEventResponses, DeviceInfo, EventAttendee, EventDateTime, FeedbackRequest, FeedbackResponse, FeedbackType, Interval, ListCalendarsData, ListCalendarsErrors, ListCalendarsRequest, ListCalendarsResponse, ListCalendarsResponse2, ListCalendarsResponses, ListEventsData, ListEventsErrors, ListEventsRequest, ListEventsResponse, ListEventsResponse2, ListEventsResponses, NangoWebhookData, NangoWebhookErrors, NangoWebhookResponse, NangoWebhookResponses, StartTrialData, StartTrialErrors, StartTrialReason, StartTrialResponse, StartTrialResponse2, StartTrialResponses, SubmitData, SubmitError, SubmitErrors, SubmitResponse, SubmitResponses, WebhookResponse } from './types.gen';
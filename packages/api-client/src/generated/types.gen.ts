@@ -4,6 +4,8 @@ export type ClientOptions = {
     baseUrl: `${string}://${string}` | (string & {});
 };
 
+export type CalendarProvider = 'google' | 'outlook';
+
 export type CanStartTrialReason = 'eligible' | 'not_eligible' | 'error';
 
 export type CanStartTrialResponse = {
@@ -23,6 +25,7 @@ export type CreateEventRequest = {
     description?: string | null;
     end: EventDateTime;
     location?: string | null;
+    provider?: CalendarProvider;
     start: EventDateTime;
     summary: string;
 };
@@ -69,6 +72,7 @@ export type Interval = 'monthly' | 'yearly';
 
 export type ListCalendarsRequest = {
     connection_id: string;
+    provider?: CalendarProvider;
 };
 
 export type ListCalendarsResponse = {
@@ -81,6 +85,7 @@ export type ListEventsRequest = {
     max_results?: number | null;
     order_by?: string | null;
     page_token?: string | null;
+    provider?: CalendarProvider;
     single_events?: boolean | null;
     time_max?: string | null;
     time_min?: string | null;
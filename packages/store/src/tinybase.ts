@@ -29,6 +29,7 @@ export const tableSchemaForTinybase = {
     event_id: { type: "string" },
     title: { type: "string" },
     raw_md: { type: "string" },
+    enhance_template_id: { type: "string" },
   } as const satisfies InferTinyBaseSchema<typeof sessionSchema>,
   transcripts: {
     user_id: { type: "string" },
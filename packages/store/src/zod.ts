@@ -41,7 +41,12 @@ export const eventSchema = z.object({
   is_all_day: z.preprocess((val) => val ?? undefined, z.boolean().optional()),
 });
 
-export const calendarProviderSchema = z.enum(["apple", "google", "outlook"]);
+export const calendarProviderSchema = z.enum([
+  "apple",
+  "google",
+  "outlook",
+  "caldav",
+]);
 export type CalendarProvider = z.infer<typeof calendarProviderSchema>;
 
 export const calendarSchema = z.object({
@@ -67,6 +72,10 @@ export const sessionSchema = z.object({
   event_id: z.preprocess((val) => val ?? undefined, z.string().optional()),
   title: z.string(),
   raw_md: z.string(),
+  enhance_template_id: z.preprocess(
+    (val) => val ?? undefined,
+    z.string().optional(),
+  ),
 });
 
 export const transcriptSchema = z.object({
@@ -199,6 +208,12 @@ export const providerSpeakerIndexSchema = z.object({
   channel: z.number().optional(),
 });
 
+export const vocabularyTermSchema = z.object({
+  text: z.string(),
+  sounds_like: z.array(z.string()).optional(),
+  kind: z.enum(["name", "product", "acronym", "other"]).optional(),
+});
+
 export const generalSchema = z.object({
   user_id: z.string(),
   autostart: z.boolean().default(false),
@@ -210,10 +225,14 @@ export const generalSchema = z.object({
   quit_intercept: z.boolean().default(false),
   ai_language: z.string().default("en"),
   spoken_languages: jsonObject(z.array(z.string()).default(["en"])),
+  translation_language: z.string().optional(),
+  vocabulary: jsonObject(z.array(vocabularyTermSchema).default([])),
+  transcript_formatting: z.boolean().default(true),
   ignored_platforms: jsonObject(z.array(z.string()).default([])),
   ignored_recurring_series: jsonObject(z.array(z.string()).default([])),
   current_llm_provider: z.string().optional(),
   current_llm_model: z.string().optional(),
+  extract_action_items: z.boolean().default(false),
   current_stt_provider: z.string().optional(),
   current_stt_model: z.string().optional(),
   timezone: z.string().optional(),
@@ -261,6 +280,7 @@ export type ChatShortcut = z.infer<typeof chatShortcutSchema>;
 export type EnhancedNote = z.infer<typeof enhancedNoteSchema>;
 export type Prompt = z.infer<typeof promptSchema>;
 export type AIProvider = z.infer<typeof aiProviderSchema>;
+export type VocabularyTerm = z.infer<typeof vocabularyTermSchema>;
 export type General = z.infer<typeof generalSchema>;
 
 export type SessionStorage = ToStorageType<typeof sessionSchema>;
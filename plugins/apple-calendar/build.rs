@@ -3,6 +3,12 @@ const COMMANDS: &[&str] = &[
     "list_calendars",
     "list_events",
     "create_event",
+    "list_remote_calendars",
+    "add_caldav_account",
+    "add_ics_subscription",
+    "set_remote_calendar_selected",
+    "sync_remote_calendars",
+    "list_remote_events",
 ];
 
 fn main() {
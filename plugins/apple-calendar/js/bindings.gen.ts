@@ -37,6 +37,54 @@ async createEvent(input: CreateEventInput) : Promise<Result<string, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async listRemoteCalendars(userId: string) : Promise<Result<RemoteCalendar[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|list_remote_calendars", { userId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async addCaldavAccount(userId: string, account: CalDavAccount) : Promise<Result<RemoteCalendar[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|add_caldav_account", { userId, account }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async addIcsSubscription(userId: string, url: string) : Promise<Result<RemoteCalendar[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|add_ics_subscription", { userId, url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setRemoteCalendarSelected(trackingId: string, selected: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|set_remote_calendar_selected", { trackingId, selected }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async syncRemoteCalendars(userId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|sync_remote_calendars", { userId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listRemoteEvents(filter: EventFilter) : Promise<Result<RemoteEvent[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|list_remote_events", { filter }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -60,6 +108,7 @@ export type AlarmProximity = "None" | "Enter" | "Leave"
 export type AlarmType = "Display" | "Audio" | "Procedure" | "Email"
 export type AppleCalendar = { id: string; title: string; calendar_type: CalendarType; color: CalendarColor | null; allows_content_modifications: boolean; is_immutable: boolean; is_subscribed: boolean; supported_event_availabilities: EventAvailability[]; allowed_entity_types: CalendarEntityType[]; source: CalendarSource }
 export type AppleEvent = { event_identifier: string; calendar_item_identifier: string; external_identifier: string; calendar: CalendarRef; title: string; location: string | null; url: string | null; notes: string | null; creation_date: string | null; last_modified_date: string | null; time_zone: string | null; start_date: string; end_date: string; is_all_day: boolean; availability: EventAvailability; status: EventStatus; has_alarms: boolean; has_attendees: boolean; has_notes: boolean; has_recurrence_rules: boolean; organizer: Participant | null; attendees: Participant[]; structured_location: StructuredLocation | null; recurrence: RecurrenceInfo | null; occurrence_date: string | null; is_detached: boolean; alarms: Alarm[]; birthday_contact_identifier: string | null; is_birthday: boolean }
+export type CalDavAccount = { server_url: string; username: string; password: string }
 export type CalendarChangedEvent = null
 export type CalendarColor = { red: number; green: number; blue: number; alpha: number }
 export type CalendarEntityType = "Event" | "Reminder"
@@ -84,6 +133,10 @@ export type RecurrenceFrequency = "Daily" | "Weekly" | "Monthly" | "Yearly"
 export type RecurrenceInfo = { series_identifier: string; has_recurrence_rules: boolean; occurrence: RecurrenceOccurrence | null; rules: RecurrenceRule[] }
 export type RecurrenceOccurrence = { original_start: string; is_detached: boolean }
 export type RecurrenceRule = { frequency: RecurrenceFrequency; interval: number; days_of_week: RecurrenceDayOfWeek[]; days_of_month: number[]; months_of_year: number[]; weeks_of_year: number[]; days_of_year: number[]; set_positions: number[]; first_day_of_week: Weekday | null; end: RecurrenceEnd | null }
+export type RemoteCalendar = { tracking_id: string; name: string; source: string | null; kind: RemoteCalendarKind; selected: boolean }
+export type RemoteCalendarKind = "CalDav" | "Ics"
+export type RemoteEvent = { tracking_id: string; calendar_tracking_id: string; title: string; notes: string | null; url: string | null; start_date: string; end_date: string; series_id: string | null; recurrence_id: string | null; participants: RemoteParticipant[] }
+export type RemoteParticipant = { name: string | null; email: string | null }
 export type StructuredLocation = { title: string; geo: GeoLocation | null; radius: number | null }
 export type Weekday = "Sunday" | "Monday" | "Tuesday" | "Wednesday" | "Thursday" | "Friday" | "Saturday"
 
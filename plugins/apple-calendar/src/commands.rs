@@ -1,5 +1,8 @@
 use crate::AppleCalendarPluginExt;
-use crate::types::{AppleCalendar, AppleEvent, CreateEventInput, EventFilter};
+use crate::types::{
+    AppleCalendar, AppleEvent, CalDavAccount, CreateEventInput, EventFilter, RemoteCalendar,
+    RemoteEvent,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -33,6 +36,81 @@ pub fn create_event<R: tauri::Runtime>(
     app.apple_calendar().create_event(input)
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn list_remote_calendars<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    user_id: String,
+) -> Result<Vec<RemoteCalendar>, String> {
+    app.apple_calendar()
+        .list_remote_calendars(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn add_caldav_account<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    user_id: String,
+    account: CalDavAccount,
+) -> Result<Vec<RemoteCalendar>, String> {
+    app.apple_calendar()
+        .add_caldav_account(user_id, account)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn add_ics_subscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    user_id: String,
+    url: String,
+) -> Result<Vec<RemoteCalendar>, String> {
+    app.apple_calendar()
+        .add_ics_subscription(user_id, url)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_remote_calendar_selected<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    tracking_id: String,
+    selected: bool,
+) -> Result<(), String> {
+    app.apple_calendar()
+        .set_remote_calendar_selected(tracking_id, selected)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_remote_calendars<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    user_id: String,
+) -> Result<(), String> {
+    app.apple_calendar()
+        .sync_remote_calendars(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_remote_events<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    filter: EventFilter,
+) -> Result<Vec<RemoteEvent>, String> {
+    app.apple_calendar()
+        .list_remote_events(filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[cfg(feature = "fixture")]
 #[derive(serde::Serialize, specta::Type)]
 pub struct FixtureInfo {
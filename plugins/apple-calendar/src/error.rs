@@ -26,6 +26,16 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error("serde error: {0}")]
     SerdeError(#[from] serde_json::Error),
+    #[error(transparent)]
+    CalDavError(#[from] hypr_caldav::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] hypr_db_user::Error),
+    #[error(transparent)]
+    Db2Error(#[from] tauri_plugin_db2::Error),
+    #[error(transparent)]
+    StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    KeyringError(#[from] keyring::Error),
 }
 
 impl Serialize for Error {
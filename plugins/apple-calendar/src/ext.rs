@@ -1,7 +1,17 @@
-use crate::types::{AppleCalendar, AppleEvent, CreateEventInput, EventFilter};
+use std::collections::HashMap;
+
+use hypr_caldav::SyncState;
+use tauri_plugin_db2::Database2PluginExt;
+use tauri_plugin_store2::{ScopedStore, Store2PluginExt};
+
+use crate::store::{StoreKey, StoredCalDavAccount};
+use crate::types::{
+    AppleCalendar, AppleEvent, CalDavAccount, CreateEventInput, EventFilter, RemoteCalendar,
+    RemoteEvent,
+};
+use crate::{keychain, remote};
 
 pub struct AppleCalendarExt<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
-    #[allow(dead_code)]
     manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
@@ -92,6 +102,169 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> AppleCalendarExt<'a, R, M> {
     }
 }
 
+// CalDAV accounts and `.ics` subscriptions work on every platform. Their events are
+// stored in the user database, the CalDAV accounts and sync tokens in this plugin's
+// store, and the CalDAV passwords in the OS keychain.
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> AppleCalendarExt<'a, R, M> {
+    fn store(&self) -> Result<ScopedStore<R, StoreKey>, crate::Error> {
+        Ok(self.manager.store2().scoped_store(crate::PLUGIN_NAME)?)
+    }
+
+    async fn user_db(&self) -> Result<hypr_db_user::UserDatabase, crate::Error> {
+        Ok(self.manager.db2().user_db().await?)
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn list_remote_calendars(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<RemoteCalendar>, crate::Error> {
+        let db = self.user_db().await?;
+
+        Ok(db
+            .list_calendars_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter_map(remote::to_remote_calendar)
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn add_caldav_account(
+        &self,
+        user_id: String,
+        account: CalDavAccount,
+    ) -> Result<Vec<RemoteCalendar>, crate::Error> {
+        // Rejects a wrong URL or password before anything is stored.
+        remote::caldav_client(&account)?.discover().await?;
+
+        let stored = StoredCalDavAccount {
+            server_url: account.server_url,
+            username: account.username,
+        };
+        keychain::set_password(&stored, &account.password)?;
+
+        let store = self.store()?;
+        let mut accounts: Vec<StoredCalDavAccount> =
+            store.get(StoreKey::CalDavAccounts)?.unwrap_or_default();
+        for replaced in accounts
+            .iter()
+            .filter(|a| a.server_url == stored.server_url)
+        {
+            if replaced.username != stored.username {
+                keychain::delete_password(replaced)?;
+            }
+        }
+        accounts.retain(|a| a.server_url != stored.server_url);
+        accounts.push(stored);
+        store.set(StoreKey::CalDavAccounts, accounts)?;
+        store.save()?;
+
+        self.sync_remote_calendars(user_id.clone()).await?;
+        self.list_remote_calendars(user_id).await
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn add_ics_subscription(
+        &self,
+        user_id: String,
+        url: String,
+    ) -> Result<Vec<RemoteCalendar>, crate::Error> {
+        let db = self.user_db().await?;
+        let window = remote::sync_window(chrono::Utc::now());
+        hypr_caldav::sync_ics_subscription(&db, &user_id, &reqwest::Client::new(), &url, window)
+            .await?;
+
+        let store = self.store()?;
+        let mut subscriptions: Vec<String> =
+            store.get(StoreKey::IcsSubscriptions)?.unwrap_or_default();
+        if !subscriptions.contains(&url) {
+            subscriptions.push(url);
+        }
+        store.set(StoreKey::IcsSubscriptions, subscriptions)?;
+        store.save()?;
+
+        self.list_remote_calendars(user_id).await
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn set_remote_calendar_selected(
+        &self,
+        tracking_id: String,
+        selected: bool,
+    ) -> Result<(), crate::Error> {
+        let db = self.user_db().await?;
+        db.set_calendar_selected(tracking_id, selected).await?;
+        Ok(())
+    }
+
+    /// Pulls every CalDAV account and `.ics` subscription into the user database.
+    /// A source that fails is logged and skipped, so it can't block the others.
+    #[tracing::instrument(skip_all)]
+    pub async fn sync_remote_calendars(&self, user_id: String) -> Result<(), crate::Error> {
+        let db = self.user_db().await?;
+        let store = self.store()?;
+        let window = remote::sync_window(chrono::Utc::now());
+
+        let accounts: Vec<StoredCalDavAccount> =
+            store.get(StoreKey::CalDavAccounts)?.unwrap_or_default();
+        let mut states: HashMap<String, SyncState> =
+            store.get(StoreKey::CalDavSyncStates)?.unwrap_or_default();
+
+        for stored in &accounts {
+            let password = match keychain::get_password(stored) {
+                Ok(password) => password,
+                Err(e) => {
+                    tracing::warn!("caldav_password_missing: {} {}", stored.server_url, e);
+                    continue;
+                }
+            };
+            let account = CalDavAccount {
+                server_url: stored.server_url.clone(),
+                username: stored.username.clone(),
+                password,
+            };
+
+            if let Err(e) =
+                remote::sync_caldav_account(&db, &user_id, &account, window, &mut states).await
+            {
+                tracing::warn!("caldav_sync_failed: {} {}", account.server_url, e);
+            }
+        }
+
+        store.set(StoreKey::CalDavSyncStates, states)?;
+        store.save()?;
+
+        let http = reqwest::Client::new();
+        let subscriptions: Vec<String> = store.get(StoreKey::IcsSubscriptions)?.unwrap_or_default();
+
+        for url in &subscriptions {
+            if let Err(e) =
+                hypr_caldav::sync_ics_subscription(&db, &user_id, &http, url, window).await
+            {
+                tracing::warn!("ics_sync_failed: {} {}", url, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn list_remote_events(
+        &self,
+        filter: EventFilter,
+    ) -> Result<Vec<RemoteEvent>, crate::Error> {
+        let db = self.user_db().await?;
+
+        Ok(db
+            .list_calendar_events(&filter.calendar_tracking_id, filter.from, filter.to)
+            .await?
+            .into_iter()
+            .map(|event| remote::to_remote_event(&filter.calendar_tracking_id, event))
+            .collect())
+    }
+}
+
 pub trait AppleCalendarPluginExt<R: tauri::Runtime> {
     fn apple_calendar(&self) -> AppleCalendarExt<'_, R, Self>
     where
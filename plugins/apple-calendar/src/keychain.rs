@@ -0,0 +1,27 @@
+use crate::store::StoredCalDavAccount;
+
+// One keychain entry per account, named after the username and server.
+const SERVICE: &str = "com.hyprnote.caldav";
+
+fn entry(account: &StoredCalDavAccount) -> Result<keyring::Entry, crate::Error> {
+    let user = format!("{}@{}", account.username, account.server_url);
+    Ok(keyring::Entry::new(SERVICE, &user)?)
+}
+
+pub(crate) fn set_password(
+    account: &StoredCalDavAccount,
+    password: &str,
+) -> Result<(), crate::Error> {
+    Ok(entry(account)?.set_password(password)?)
+}
+
+pub(crate) fn get_password(account: &StoredCalDavAccount) -> Result<String, crate::Error> {
+    Ok(entry(account)?.get_password()?)
+}
+
+pub(crate) fn delete_password(account: &StoredCalDavAccount) -> Result<(), crate::Error> {
+    match entry(account)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
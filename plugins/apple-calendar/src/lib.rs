@@ -8,6 +8,9 @@ mod commands;
 mod error;
 mod events;
 mod ext;
+mod keychain;
+mod remote;
+mod store;
 mod types;
 
 pub use error::{Error, Result};
@@ -26,6 +29,12 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_calendars::<tauri::Wry>,
             commands::list_events::<tauri::Wry>,
             commands::create_event::<tauri::Wry>,
+            commands::list_remote_calendars::<tauri::Wry>,
+            commands::add_caldav_account::<tauri::Wry>,
+            commands::add_ics_subscription::<tauri::Wry>,
+            commands::set_remote_calendar_selected::<tauri::Wry>,
+            commands::sync_remote_calendars::<tauri::Wry>,
+            commands::list_remote_events::<tauri::Wry>,
         ])
         .events(tauri_specta::collect_events![CalendarChangedEvent])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
@@ -40,6 +49,12 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_calendars::<tauri::Wry>,
             commands::list_events::<tauri::Wry>,
             commands::create_event::<tauri::Wry>,
+            commands::list_remote_calendars::<tauri::Wry>,
+            commands::add_caldav_account::<tauri::Wry>,
+            commands::add_ics_subscription::<tauri::Wry>,
+            commands::set_remote_calendar_selected::<tauri::Wry>,
+            commands::sync_remote_calendars::<tauri::Wry>,
+            commands::list_remote_events::<tauri::Wry>,
             commands::advance_fixture,
             commands::reset_fixture,
             commands::get_fixture_info,
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use hypr_caldav::{CalDavClient, Credentials, SyncState, SyncWindow};
+use hypr_db_user::{Calendar, Event, EventParticipant, Platform, UserDatabase};
+
+use crate::types::{
+    CalDavAccount, RemoteCalendar, RemoteCalendarKind, RemoteEvent, RemoteParticipant,
+};
+
+// Day-aligned, so the window (and with it a full CalDAV sync) changes once a day
+// rather than on every run. Covers the -7..+30 day range the app lists.
+pub(crate) fn sync_window(now: DateTime<Utc>) -> SyncWindow {
+    let today = now.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc();
+
+    SyncWindow {
+        from: today - Duration::days(7),
+        to: today + Duration::days(31),
+    }
+}
+
+pub(crate) fn caldav_client(account: &CalDavAccount) -> Result<CalDavClient, hypr_caldav::Error> {
+    CalDavClient::new(
+        &account.server_url,
+        Some(Credentials {
+            username: account.username.clone(),
+            password: account.password.clone(),
+        }),
+    )
+}
+
+/// Syncs every calendar of `account`, picking up from and updating `states`.
+pub(crate) async fn sync_caldav_account(
+    db: &UserDatabase,
+    user_id: &str,
+    account: &CalDavAccount,
+    window: SyncWindow,
+    states: &mut HashMap<String, SyncState>,
+) -> Result<(), hypr_caldav::Error> {
+    let client = caldav_client(account)?;
+
+    for calendar in client.discover().await? {
+        let state = hypr_caldav::sync_caldav_calendar(
+            db,
+            user_id,
+            &client,
+            &calendar,
+            states.get(&calendar.url),
+            window,
+        )
+        .await?;
+
+        match state {
+            Some(state) => states.insert(calendar.url, state),
+            None => states.remove(&calendar.url),
+        };
+    }
+
+    Ok(())
+}
+
+pub(crate) fn to_remote_calendar(calendar: Calendar) -> Option<RemoteCalendar> {
+    let kind = match calendar.platform {
+        Platform::CalDav => RemoteCalendarKind::CalDav,
+        Platform::Ics => RemoteCalendarKind::Ics,
+        _ => return None,
+    };
+
+    Some(RemoteCalendar {
+        tracking_id: calendar.tracking_id,
+        name: calendar.name,
+        source: calendar.source,
+        kind,
+        selected: calendar.selected,
+    })
+}
+
+pub(crate) fn to_remote_event(calendar_tracking_id: &str, event: Event) -> RemoteEvent {
+    let participants = event
+        .participants
+        .as_deref()
+        .and_then(|json| serde_json::from_str::<Vec<EventParticipant>>(json).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|p| RemoteParticipant {
+            name: p.name,
+            email: p.email,
+        })
+        .collect();
+
+    RemoteEvent {
+        tracking_id: event.tracking_id,
+        calendar_tracking_id: calendar_tracking_id.to_string(),
+        title: event.name,
+        notes: (!event.note.is_empty()).then_some(event.note),
+        url: event.google_event_url,
+        start_date: event.start_date,
+        end_date: event.end_date,
+        series_id: event.series_id,
+        recurrence_id: event.recurrence_id,
+        participants,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_window() {
+        let window = sync_window("2025-03-10T18:30:00Z".parse().unwrap());
+        assert_eq!(
+            window.from,
+            "2025-03-03T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            window.to,
+            "2025-04-10T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        // Stays the same for the rest of the day, so stored sync tokens stay usable.
+        assert_eq!(window, sync_window("2025-03-10T23:59:00Z".parse().unwrap()));
+    }
+}
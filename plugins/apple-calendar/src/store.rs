@@ -0,0 +1,18 @@
+use tauri_plugin_store2::ScopedStoreKey;
+
+#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+pub enum StoreKey {
+    CalDavAccounts,
+    IcsSubscriptions,
+    // CalDAV `SyncState` by calendar URL.
+    CalDavSyncStates,
+}
+
+impl ScopedStoreKey for StoreKey {}
+
+/// A CalDAV account as kept in the store. Its password is in the OS keychain.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StoredCalDavAccount {
+    pub server_url: String,
+    pub username: String,
+}
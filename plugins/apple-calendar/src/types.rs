@@ -21,6 +21,53 @@ pub struct CreateEventInput {
     pub url: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct CalDavAccount {
+    pub server_url: String,
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub enum RemoteCalendarKind {
+    CalDav,
+    Ics,
+}
+
+/// A calendar synced from a CalDAV account or an `.ics` subscription.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct RemoteCalendar {
+    pub tracking_id: String,
+    pub name: String,
+    pub source: Option<String>,
+    pub kind: RemoteCalendarKind,
+    pub selected: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct RemoteParticipant {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+/// One occurrence of an event from a `RemoteCalendar`. Recurring events are
+/// already expanded, so every instance has its own `tracking_id`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct RemoteEvent {
+    pub tracking_id: String,
+    pub calendar_tracking_id: String,
+    pub title: String,
+    pub notes: Option<String>,
+    pub url: Option<String>,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub series_id: Option<String>,
+    /// Original start of this instance within its series, so a moved exception
+    /// can be matched to the occurrence it replaces.
+    pub recurrence_id: Option<DateTime<Utc>>,
+    pub participants: Vec<RemoteParticipant>,
+}
+
 macro_rules! common_derives {
     ($item:item) => {
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type, schemars::JsonSchema)]
@@ -5,6 +5,7 @@ pub trait AuthPluginExt<R: tauri::Runtime> {
     fn set_item(&self, key: String, value: String) -> Result<(), crate::Error>;
     fn remove_item(&self, key: String) -> Result<(), crate::Error>;
     fn clear_auth(&self) -> Result<(), crate::Error>;
+    fn access_token(&self) -> Result<Option<String>, crate::Error>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AuthPluginExt<R> for T {
@@ -33,4 +34,29 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AuthPluginExt<R> for T {
         store.save()?;
         Ok(())
     }
+
+    // The frontend's Supabase client keeps the session under its
+    // `sb-<project>-auth-token` key and refreshes it before it expires.
+    fn access_token(&self) -> Result<Option<String>, crate::Error> {
+        #[derive(serde::Deserialize)]
+        struct Session {
+            access_token: String,
+        }
+
+        let store = self.store2().store()?;
+        let Some(items) = store.get(crate::PLUGIN_NAME) else {
+            return Ok(None);
+        };
+        let Some(items) = items.as_str() else {
+            return Ok(None);
+        };
+        let items: serde_json::Map<String, serde_json::Value> = serde_json::from_str(items)?;
+
+        Ok(items
+            .iter()
+            .filter(|(key, _)| key.ends_with("-auth-token"))
+            .filter_map(|(_, value)| value.as_str())
+            .find_map(|value| serde_json::from_str::<Session>(value).ok())
+            .map(|session| session.access_token))
+    }
 }
@@ -1,4 +1,11 @@
-const COMMANDS: &[&str] = &["execute_local", "execute_cloud"];
+const COMMANDS: &[&str] = &[
+    "execute_local",
+    "execute_cloud",
+    "save_extraction",
+    "delete_extraction",
+    "list_open_action_items",
+    "set_action_item_done",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
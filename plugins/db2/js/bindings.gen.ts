@@ -21,6 +21,38 @@ async executeCloud(sql: string, args: string[]) : Promise<Result<JsonValue[], st
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async saveExtraction(sessionId: string, text: string, participants: ExtractionParticipant[]) : Promise<Result<ActionItem[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|save_extraction", { sessionId, text, participants }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteExtraction(sessionId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|delete_extraction", { sessionId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listOpenActionItems(filter: ListActionItemFilter | null) : Promise<Result<ActionItem[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|list_open_action_items", { filter }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setActionItemDone(id: string, done: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|set_action_item_done", { id, done }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -34,7 +66,17 @@ async executeCloud(sql: string, args: string[]) : Promise<Result<JsonValue[], st
 
 /** user-defined types **/
 
+export type ActionItem = { id: string; session_id: string; text: string; owner_id: string | null; 
+/**
+ * Owner as written in the transcript. Kept even when it could not be linked to a human.
+ */
+owner_name: string | null; due: string | null; source_timestamp: string | null; done: boolean; created_at: string }
+/**
+ * A session participant that an extracted owner can be linked to.
+ */
+export type ExtractionParticipant = { id: string; name: string; email: string | null }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+export type ListActionItemFilter = { owner_id: string | null; session_id: string | null; limit: number | null }
 
 /** tauri-specta globals **/
 
@@ -25,3 +25,54 @@ pub(crate) async fn execute_cloud<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn save_extraction<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    text: String,
+    participants: Vec<crate::ExtractionParticipant>,
+) -> Result<Vec<hypr_db_user::ActionItem>, String> {
+    app.db2()
+        .save_extraction(session_id, text, participants)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn delete_extraction<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<(), String> {
+    app.db2()
+        .delete_extraction(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_open_action_items<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    filter: Option<hypr_db_user::ListActionItemFilter>,
+) -> Result<Vec<hypr_db_user::ActionItem>, String> {
+    app.db2()
+        .list_open_action_items(filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_action_item_done<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    done: bool,
+) -> Result<(), String> {
+    app.db2()
+        .set_action_item_done(id, done)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -7,6 +7,10 @@ pub enum Error {
     #[error(transparent)]
     HyprDbError(#[from] hypr_db_core::Error),
     #[error(transparent)]
+    HyprTemplateAppError(#[from] hypr_template_app::Error),
+    #[error("local database is not initialized")]
+    LocalDbNotInitialized,
+    #[error(transparent)]
     TauriError(#[from] tauri::Error),
     #[error(transparent)]
     IoError(#[from] std::io::Error),
@@ -24,10 +24,16 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Database2<'a, R, M> {
                     .unwrap()
             }
         };
-        {
-            let state = self.manager.state::<crate::ManagedState>();
-            let mut guard = state.lock().await;
-            guard.local_db = Some(db);
+        let user_db = hypr_db_user::UserDatabase::from(db.clone());
+
+        let state = self.manager.state::<crate::ManagedState>();
+        let mut guard = state.lock().await;
+        guard.local_db = Some(db);
+
+        // Extraction storage is optional; `execute_local` keeps working without it.
+        match hypr_db_user::migrate_extraction(&user_db).await {
+            Ok(()) => guard.user_db = Some(user_db),
+            Err(e) => tracing::error!("extraction_migration_failed: {:?}", e),
         }
         Ok(())
     }
@@ -170,6 +176,54 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Database2<'a, R, M> {
 
         Ok(items)
     }
+
+    pub async fn user_db(&self) -> Result<hypr_db_user::UserDatabase, crate::Error> {
+        let state = self.manager.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+        guard
+            .user_db
+            .clone()
+            .ok_or(crate::Error::LocalDbNotInitialized)
+    }
+
+    /// Stores the action items and decisions in model output from the extract task,
+    /// replacing what was extracted for the session before.
+    pub async fn save_extraction(
+        &self,
+        session_id: String,
+        text: String,
+        participants: Vec<crate::ExtractionParticipant>,
+    ) -> Result<Vec<hypr_db_user::ActionItem>, crate::Error> {
+        let extraction = hypr_template_app::Extraction::parse(&text)?;
+        let (action_items, decisions) =
+            crate::extraction::to_rows(&session_id, extraction, &participants);
+
+        let db = self.user_db().await?;
+        db.replace_session_decisions(&session_id, decisions).await?;
+        Ok(db
+            .replace_session_action_items(&session_id, action_items)
+            .await?)
+    }
+
+    pub async fn delete_extraction(&self, session_id: String) -> Result<(), crate::Error> {
+        let db = self.user_db().await?;
+        db.replace_session_decisions(&session_id, vec![]).await?;
+        db.replace_session_action_items(&session_id, vec![]).await?;
+        Ok(())
+    }
+
+    pub async fn list_open_action_items(
+        &self,
+        filter: Option<hypr_db_user::ListActionItemFilter>,
+    ) -> Result<Vec<hypr_db_user::ActionItem>, crate::Error> {
+        let db = self.user_db().await?;
+        Ok(db.list_open_action_items(filter).await?)
+    }
+
+    pub async fn set_action_item_done(&self, id: String, done: bool) -> Result<(), crate::Error> {
+        let db = self.user_db().await?;
+        Ok(db.set_action_item_done(id, done).await?)
+    }
 }
 
 pub trait Database2PluginExt<R: tauri::Runtime> {
@@ -0,0 +1,93 @@
+use hypr_db_user::{ActionItem, Decision, Human, match_owner};
+use hypr_template_app::Extraction;
+
+/// A session participant that an extracted owner can be linked to.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ExtractionParticipant {
+    pub id: String,
+    pub name: String,
+    pub email: Option<String>,
+}
+
+// Sessions live outside the user database, so owners are linked against the
+// participants the caller passes in rather than `session_participants`.
+pub(crate) fn to_rows(
+    session_id: &str,
+    extraction: Extraction,
+    participants: &[ExtractionParticipant],
+) -> (Vec<ActionItem>, Vec<Decision>) {
+    let humans = participants
+        .iter()
+        .map(|p| Human {
+            id: p.id.clone(),
+            full_name: Some(p.name.clone()),
+            email: p.email.clone(),
+            ..Human::default()
+        })
+        .collect::<Vec<_>>();
+    let created_at = chrono::Utc::now();
+
+    let action_items = extraction
+        .action_items
+        .into_iter()
+        .map(|item| ActionItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session_id.to_string(),
+            owner_id: item
+                .owner
+                .as_deref()
+                .and_then(|owner| match_owner(&humans, owner)),
+            text: item.text,
+            owner_name: item.owner,
+            due: item.due,
+            source_timestamp: item.source_timestamp,
+            done: false,
+            created_at,
+        })
+        .collect();
+
+    let decisions = extraction
+        .decisions
+        .into_iter()
+        .map(|decision| Decision {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session_id.to_string(),
+            text: decision.text,
+            source_timestamp: decision.source_timestamp,
+            created_at,
+        })
+        .collect();
+
+    (action_items, decisions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_rows() {
+        let extraction = Extraction::parse(
+            r#"{"action_items":[{"text":"Update the docs","owner":"Alice","due":"Friday","source_timestamp":"00:12"},{"text":"Book a room","owner":null,"due":null,"source_timestamp":null}],"decisions":[{"text":"Ship on Friday","source_timestamp":"00:10"}]}"#,
+        )
+        .unwrap();
+        let participants = vec![ExtractionParticipant {
+            id: "alice".to_string(),
+            name: "Alice Kim".to_string(),
+            email: None,
+        }];
+
+        let (items, decisions) = to_rows("session", extraction, &participants);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].owner_id.as_deref(), Some("alice"));
+        assert_eq!(items[0].owner_name.as_deref(), Some("Alice"));
+        assert_eq!(items[0].due.as_deref(), Some("Friday"));
+        assert_eq!(items[1].owner_id, None);
+        assert!(items.iter().all(|i| i.session_id == "session" && !i.done));
+
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].text, "Ship on Friday");
+        assert_eq!(decisions[0].source_timestamp.as_deref(), Some("00:10"));
+    }
+}
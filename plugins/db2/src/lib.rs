@@ -3,9 +3,11 @@ use tokio::sync::Mutex;
 mod commands;
 mod error;
 mod ext;
+mod extraction;
 
 pub use error::*;
 pub use ext::*;
+pub use extraction::*;
 use tauri::Manager;
 
 const PLUGIN_NAME: &str = "db2";
@@ -15,6 +17,7 @@ pub type ManagedState = Mutex<State>;
 #[derive(Default)]
 pub struct State {
     pub local_db: Option<hypr_db_core::Database>,
+    pub user_db: Option<hypr_db_user::UserDatabase>,
     pub cloud_db: Option<tokio_postgres::Client>,
 }
 
@@ -24,6 +27,10 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::execute_local::<tauri::Wry>,
             commands::execute_cloud::<tauri::Wry>,
+            commands::save_extraction::<tauri::Wry>,
+            commands::delete_extraction::<tauri::Wry>,
+            commands::list_open_action_items::<tauri::Wry>,
+            commands::set_action_item_done::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -26,8 +26,52 @@ deepLinkEvent: "plugin:deeplink2:deep-link-event"
 
 export type AuthCallbackSearch = { access_token: string; refresh_token: string }
 export type BillingRefreshSearch = Record<string, never>
-export type DeepLink = { to: "/auth/callback"; search: AuthCallbackSearch } | { to: "/billing/refresh"; search: BillingRefreshSearch }
+export type DeepLink = { to: "/auth/callback"; search: AuthCallbackSearch } | { to: "/billing/refresh"; search: BillingRefreshSearch } | { to: "/listen/start"; search: ListenStartSearch } | { to: "/listen/stop"; search: ListenStopSearch } | { to: "/session/open"; search: SessionOpenSearch } | { to: "/search"; search: SearchSearch } | { to: "/import"; search: ImportSearch }
 export type DeepLinkEvent = DeepLink
+/**
+ * Imports a transcript file as a new session.
+ */
+export type ImportSearch = { 
+/**
+ * Absolute path of a TXT, DOCX, SRT, VTT or JSON transcript.
+ */
+path: string }
+/**
+ * Starts listening, in a new session unless one already belongs to the event.
+ */
+export type ListenStartSearch = { 
+/**
+ * Title of the new session.
+ */
+title: string | null; 
+/**
+ * Calendar event to attach the session to.
+ */
+event_id: string | null }
+/**
+ * Stops the running listening session, if any.
+ */
+export type ListenStopSearch = Record<string, never>
+/**
+ * Opens search with a query.
+ */
+export type SearchSearch = { 
+/**
+ * Text to search for.
+ */
+q: string }
+/**
+ * Opens a session, optionally at a point in its recording.
+ */
+export type SessionOpenSearch = { 
+/**
+ * ID of the session.
+ */
+id: string; 
+/**
+ * Offset into the recording in seconds. Accepts `90`, `90s` or `1m30s`.
+ */
+t: number | null }
 
 /** tauri-specta globals **/
 
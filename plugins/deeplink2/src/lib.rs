@@ -10,6 +10,7 @@ pub use types::{DeepLink, DeepLinkEvent};
 use std::str::FromStr;
 
 use tauri_plugin_deep_link::DeepLinkExt;
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
 use tauri_specta::Event;
 
 const PLUGIN_NAME: &str = "deeplink2";
@@ -26,6 +27,35 @@ fn redact_url(url_str: &str) -> String {
     }
 }
 
+fn emit<R: tauri::Runtime>(app: &tauri::AppHandle<R>, deep_link: DeepLink) {
+    if let Err(e) = DeepLinkEvent(deep_link).emit(app) {
+        tracing::error!(error = ?e, "deeplink_event_emit_failed");
+    }
+}
+
+fn dispatch<R: tauri::Runtime>(app: &tauri::AppHandle<R>, deep_link: DeepLink) {
+    let Some(prompt) = deep_link.confirmation_prompt() else {
+        emit(app, deep_link);
+        return;
+    };
+
+    let app_for_dialog = app.clone();
+    app.dialog()
+        .message(prompt)
+        .title("Allow this action?")
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Allow".to_string(),
+            "Deny".to_string(),
+        ))
+        .show(move |allowed| {
+            if allowed {
+                emit(&app_for_dialog, deep_link);
+            } else {
+                tracing::info!(path = deep_link.path(), "deeplink_denied");
+            }
+        });
+}
+
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
@@ -54,9 +84,7 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
                     match DeepLink::from_str(url_str) {
                         Ok(deep_link) => {
                             tracing::info!(path = deep_link.path(), "deeplink_parsed");
-                            if let Err(e) = DeepLinkEvent(deep_link).emit(&app_handle) {
-                                tracing::error!(error = ?e, "deeplink_event_emit_failed");
-                            }
+                            dispatch(&app_handle, deep_link);
                         }
                         Err(e) => {
                             tracing::debug!(error = ?e, url = %redacted, "deeplink_parse_failed");
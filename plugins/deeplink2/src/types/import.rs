@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::required;
+
+const IMPORT_EXTENSIONS: &[&str] = &["txt", "docx", "srt", "vtt", "json"];
+
+/// Imports a transcript file as a new session.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ImportSearch {
+    /// Absolute path of a TXT, DOCX, SRT, VTT or JSON transcript.
+    pub path: String,
+}
+
+impl ImportSearch {
+    pub fn from_query_params(
+        query_params: &std::collections::HashMap<String, String>,
+    ) -> crate::Result<Self> {
+        let path = required(query_params, "path")?;
+        let invalid = |reason: &str| crate::Error::InvalidQueryParam {
+            name: "path".to_string(),
+            reason: reason.to_string(),
+        };
+
+        let p = Path::new(&path);
+        if !p.is_absolute() {
+            return Err(invalid("must be absolute"));
+        }
+
+        let extension = p
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        if !extension.is_some_and(|ext| IMPORT_EXTENSIONS.contains(&ext.as_str())) {
+            return Err(invalid("must be a .txt, .docx, .srt, .vtt or .json file"));
+        }
+
+        Ok(Self { path })
+    }
+}
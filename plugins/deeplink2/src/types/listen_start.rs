@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::{non_empty, optional};
+
+/// Starts listening, in a new session unless one already belongs to the event.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ListenStartSearch {
+    /// Title of the new session.
+    pub title: Option<String>,
+    /// Calendar event to attach the session to.
+    pub event_id: Option<String>,
+}
+
+impl ListenStartSearch {
+    pub fn from_query_params(
+        query_params: &std::collections::HashMap<String, String>,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            title: optional(query_params, "title"),
+            event_id: query_params
+                .get("event_id")
+                .map(|id| non_empty("event_id", id))
+                .transpose()?,
+        })
+    }
+}
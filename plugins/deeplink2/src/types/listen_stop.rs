@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Stops the running listening session, if any.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ListenStopSearch {}
+
+impl ListenStopSearch {
+    pub fn from_query_params(_: &std::collections::HashMap<String, String>) -> crate::Result<Self> {
+        Ok(Self {})
+    }
+}
@@ -1,8 +1,18 @@
 mod auth_callback;
 mod billing_refresh;
+mod import;
+mod listen_start;
+mod listen_stop;
+mod search;
+mod session_open;
 
 pub use auth_callback::*;
 pub use billing_refresh::*;
+pub use import::*;
+pub use listen_start::*;
+pub use listen_stop::*;
+pub use search::*;
+pub use session_open::*;
 
 use serde::{Deserialize, Serialize};
 use specta::Type;
@@ -19,6 +29,16 @@ pub enum DeepLink {
     AuthCallback(AuthCallbackSearch),
     #[serde(rename = "/billing/refresh")]
     BillingRefresh(BillingRefreshSearch),
+    #[serde(rename = "/listen/start")]
+    ListenStart(ListenStartSearch),
+    #[serde(rename = "/listen/stop")]
+    ListenStop(ListenStopSearch),
+    #[serde(rename = "/session/open")]
+    SessionOpen(SessionOpenSearch),
+    #[serde(rename = "/search")]
+    Search(SearchSearch),
+    #[serde(rename = "/import")]
+    Import(ImportSearch),
 }
 
 impl DeepLink {
@@ -26,10 +46,63 @@ impl DeepLink {
         match self {
             DeepLink::AuthCallback(_) => "/auth/callback",
             DeepLink::BillingRefresh(_) => "/billing/refresh",
+            DeepLink::ListenStart(_) => "/listen/start",
+            DeepLink::ListenStop(_) => "/listen/stop",
+            DeepLink::SessionOpen(_) => "/session/open",
+            DeepLink::Search(_) => "/search",
+            DeepLink::Import(_) => "/import",
+        }
+    }
+
+    /// Links that change app state on behalf of another program need the
+    /// user's consent; this is the question to ask them.
+    pub fn confirmation_prompt(&self) -> Option<String> {
+        match self {
+            DeepLink::ListenStart(search) => Some(match &search.title {
+                Some(title) => format!("Another app wants to start recording \"{}\".", title),
+                None => "Another app wants to start recording.".to_string(),
+            }),
+            DeepLink::ListenStop(_) => Some("Another app wants to stop recording.".to_string()),
+            DeepLink::Import(search) => Some(format!(
+                "Another app wants to import \"{}\" as a new session.",
+                search.path
+            )),
+            DeepLink::AuthCallback(_)
+            | DeepLink::BillingRefresh(_)
+            | DeepLink::SessionOpen(_)
+            | DeepLink::Search(_) => None,
         }
     }
 }
 
+pub(crate) fn required(
+    query_params: &HashMap<String, String>,
+    name: &str,
+) -> crate::Result<String> {
+    let value = query_params
+        .get(name)
+        .ok_or(crate::Error::MissingQueryParam(name.to_string()))?;
+    non_empty(name, value)
+}
+
+pub(crate) fn non_empty(name: &str, value: &str) -> crate::Result<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(crate::Error::InvalidQueryParam {
+            name: name.to_string(),
+            reason: "must not be empty".to_string(),
+        });
+    }
+    Ok(value.to_string())
+}
+
+pub(crate) fn optional(query_params: &HashMap<String, String>, name: &str) -> Option<String> {
+    query_params
+        .get(name)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
 impl FromStr for DeepLink {
     type Err = crate::Error;
 
@@ -53,7 +126,102 @@ impl FromStr for DeepLink {
             "billing/refresh" => Ok(DeepLink::BillingRefresh(
                 BillingRefreshSearch::from_query_params(&query_params)?,
             )),
+            "listen/start" => Ok(DeepLink::ListenStart(ListenStartSearch::from_query_params(
+                &query_params,
+            )?)),
+            "listen/stop" => Ok(DeepLink::ListenStop(ListenStopSearch::from_query_params(
+                &query_params,
+            )?)),
+            "session/open" => Ok(DeepLink::SessionOpen(SessionOpenSearch::from_query_params(
+                &query_params,
+            )?)),
+            "search" => Ok(DeepLink::Search(SearchSearch::from_query_params(
+                &query_params,
+            )?)),
+            "import" => Ok(DeepLink::Import(ImportSearch::from_query_params(
+                &query_params,
+            )?)),
             _ => Err(crate::Error::UnknownPath(full_path)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(url: &str) -> crate::Result<DeepLink> {
+        DeepLink::from_str(url)
+    }
+
+    #[test]
+    fn listen_links() {
+        let DeepLink::ListenStart(search) =
+            parse("hyprnote://listen/start?title=Weekly%20sync&event_id=ev1").unwrap()
+        else {
+            panic!("expected listen/start");
+        };
+        assert_eq!(search.title.as_deref(), Some("Weekly sync"));
+        assert_eq!(search.event_id.as_deref(), Some("ev1"));
+
+        let DeepLink::ListenStart(search) = parse("hyprnote://listen/start?title=%20").unwrap()
+        else {
+            panic!("expected listen/start");
+        };
+        assert!(search.title.is_none());
+        assert!(parse("hyprnote://listen/start?event_id=").is_err());
+
+        assert!(matches!(
+            parse("hyprnote://listen/stop").unwrap(),
+            DeepLink::ListenStop(_)
+        ));
+    }
+
+    #[test]
+    fn session_open() {
+        let DeepLink::SessionOpen(search) =
+            parse("hyprnote://session/open?id=abc-123&t=2m3s").unwrap()
+        else {
+            panic!("expected session/open");
+        };
+        assert_eq!(search.id, "abc-123");
+        assert_eq!(search.t, Some(123));
+
+        assert!(matches!(
+            parse("hyprnote://session/open"),
+            Err(crate::Error::MissingQueryParam(_))
+        ));
+        assert!(parse("hyprnote://session/open?id=../etc").is_err());
+        assert!(parse("hyprnote://session/open?id=a&t=soon").is_err());
+    }
+
+    #[test]
+    fn search_and_import() {
+        let DeepLink::Search(search) = parse("hyprnote://search?q=roadmap+q3").unwrap() else {
+            panic!("expected search");
+        };
+        assert_eq!(search.q, "roadmap q3");
+        assert!(parse("hyprnote://search?q=").is_err());
+        assert!(parse(&format!("hyprnote://search?q={}", "a".repeat(501))).is_err());
+
+        #[cfg(unix)]
+        {
+            assert!(parse("hyprnote://import?path=/tmp/call.VTT").is_ok());
+            assert!(parse("hyprnote://import?path=call.vtt").is_err());
+            assert!(parse("hyprnote://import?path=/tmp/call.exe").is_err());
+        }
+    }
+
+    #[test]
+    fn only_state_changes_need_confirmation() {
+        let needs_prompt = |url: &str| parse(url).unwrap().confirmation_prompt().is_some();
+
+        assert!(needs_prompt("hyprnote://listen/start"));
+        assert!(needs_prompt("hyprnote://listen/stop"));
+        #[cfg(unix)]
+        assert!(needs_prompt("hyprnote://import?path=/tmp/a.txt"));
+        assert!(!needs_prompt("hyprnote://session/open?id=a"));
+        assert!(!needs_prompt("hyprnote://search?q=a"));
+        assert!(!needs_prompt("hyprnote://billing/refresh"));
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::required;
+
+const MAX_QUERY_LEN: usize = 500;
+
+/// Opens search with a query.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SearchSearch {
+    /// Text to search for.
+    pub q: String,
+}
+
+impl SearchSearch {
+    pub fn from_query_params(
+        query_params: &std::collections::HashMap<String, String>,
+    ) -> crate::Result<Self> {
+        let q = required(query_params, "q")?;
+        if q.chars().count() > MAX_QUERY_LEN {
+            return Err(crate::Error::InvalidQueryParam {
+                name: "q".to_string(),
+                reason: format!("must be at most {} characters", MAX_QUERY_LEN),
+            });
+        }
+
+        Ok(Self { q })
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::required;
+
+/// Opens a session, optionally at a point in its recording.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SessionOpenSearch {
+    /// ID of the session.
+    pub id: String,
+    /// Offset into the recording in seconds. Accepts `90`, `90s` or `1m30s`.
+    pub t: Option<u32>,
+}
+
+impl SessionOpenSearch {
+    pub fn from_query_params(
+        query_params: &std::collections::HashMap<String, String>,
+    ) -> crate::Result<Self> {
+        let id = required(query_params, "id")?;
+        if !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(crate::Error::InvalidQueryParam {
+                name: "id".to_string(),
+                reason: "must only contain letters, digits, `-` and `_`".to_string(),
+            });
+        }
+
+        let t = query_params
+            .get("t")
+            .map(|t| {
+                parse_offset(t).ok_or_else(|| crate::Error::InvalidQueryParam {
+                    name: "t".to_string(),
+                    reason: format!("`{}` is not a time offset", t),
+                })
+            })
+            .transpose()?;
+
+        Ok(Self { id, t })
+    }
+}
+
+fn parse_offset(s: &str) -> Option<u32> {
+    if let Ok(secs) = s.parse() {
+        return Some(secs);
+    }
+
+    let mut total: u32 = 0;
+    let mut digits = String::new();
+    let mut last_unit = None;
+
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+
+        let (unit, rank) = match c {
+            'h' => (3600, 0),
+            'm' => (60, 1),
+            's' => (1, 2),
+            _ => return None,
+        };
+        // Units must be given at most once and from largest to smallest.
+        if digits.is_empty() || last_unit.is_some_and(|last| last >= rank) {
+            return None;
+        }
+        last_unit = Some(rank);
+
+        let value: u32 = digits.parse().ok()?;
+        total = total.checked_add(value.checked_mul(unit)?)?;
+        digits.clear();
+    }
+
+    (digits.is_empty() && last_unit.is_some()).then_some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets() {
+        assert_eq!(parse_offset("123"), Some(123));
+        assert_eq!(parse_offset("123s"), Some(123));
+        assert_eq!(parse_offset("2m3s"), Some(123));
+        assert_eq!(parse_offset("1h"), Some(3600));
+        assert_eq!(parse_offset("1h0m5s"), Some(3605));
+
+        assert_eq!(parse_offset(""), None);
+        assert_eq!(parse_offset("s"), None);
+        assert_eq!(parse_offset("3s2m"), None);
+        assert_eq!(parse_offset("1m1m"), None);
+        assert_eq!(parse_offset("12x"), None);
+        assert_eq!(parse_offset("1m30"), None);
+        assert_eq!(parse_offset("-5"), None);
+    }
+}
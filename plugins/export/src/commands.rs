@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+use crate::ExportPluginExt;
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn export_sessions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: PathBuf,
+    input: crate::ExportInput,
+) -> Result<crate::ExportSummary, String> {
+    app.export()
+        .export_sessions(&path, input)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use hypr_importer_core::ir::Collection;
+use tauri_plugin_fs_sync::FsSyncPluginExt;
+
+pub struct Export<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
+    manager: &'a M,
+    _runtime: std::marker::PhantomData<fn() -> R>,
+}
+
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Export<'a, R, M> {
+    pub fn export_sessions(
+        &self,
+        path: impl AsRef<Path>,
+        input: crate::ExportInput,
+    ) -> Result<crate::ExportSummary, crate::Error> {
+        let collection: Collection = serde_json::from_value(input.data)?;
+        let collection = match &input.session_ids {
+            Some(ids) => hypr_export::select_sessions(&collection, ids),
+            None => collection,
+        };
+
+        let mut audio = HashMap::new();
+        if input.include_audio {
+            for session in &collection.sessions {
+                if let Some(path) = self.manager.fs_sync().audio_path(&session.id)? {
+                    audio.insert(session.id.clone(), path);
+                }
+            }
+        }
+
+        let options = hypr_export::ExportOptions {
+            session_ids: None,
+            transcript_formats: input.transcript_formats,
+            audio,
+            zip: input.zip,
+        };
+
+        Ok(hypr_export::export_sessions(
+            &collection,
+            &options,
+            path.as_ref(),
+        )?)
+    }
+}
+
+pub trait ExportPluginExt<R: tauri::Runtime> {
+    fn export(&self) -> Export<'_, R, Self>
+    where
+        Self: tauri::Manager<R> + Sized;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> ExportPluginExt<R> for T {
+    fn export(&self) -> Export<'_, R, Self>
+    where
+        Self: Sized,
+    {
+        Export {
+            manager: self,
+            _runtime: std::marker::PhantomData,
+        }
+    }
+}
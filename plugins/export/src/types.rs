@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+pub use hypr_export::{ExportSummary, TranscriptFormat};
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportInput {
+    /// Store rows in the shape the `AsIs` importer reads: `sessions`,
+    /// `transcripts`, `humans`, `participants`, `enhanced_notes`, and so on.
+    pub data: serde_json::Value,
+    /// Sessions to export; `null` exports everything in `data`.
+    pub session_ids: Option<Vec<String>>,
+    pub transcript_formats: Vec<TranscriptFormat>,
+    pub include_audio: bool,
+    pub zip: bool,
+}
@@ -19,6 +19,11 @@ const COMMANDS: &[&str] = &[
     "chat_dir",
     "entity_dir",
     "attachment_save",
+    "attachment_list",
+    "attachment_remove",
+    "obsidian_sync",
+    "obsidian_watch",
+    "obsidian_unwatch",
 ];
 
 fn main() {
@@ -181,6 +181,30 @@ async attachmentRemove(sessionId: string, attachmentId: string) : Promise<Result
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async obsidianSync(target: string, sessionIds: string[] | null) : Promise<Result<ObsidianSyncResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|obsidian_sync", { target, sessionIds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async obsidianWatch(target: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|obsidian_watch", { target }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async obsidianUnwatch(target: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|obsidian_unwatch", { target }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -200,6 +224,20 @@ export type CleanupTarget = { type: "files"; subdir: string; extension: string }
 export type FolderInfo = { name: string; parent_folder_id: string | null }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type ListFoldersResult = { folders: Partial<{ [key in string]: FolderInfo }>; session_folder_map: Partial<{ [key in string]: string }> }
+export type ObsidianConflict = { session_id: string; note_path: string; 
+/**
+ * Copy of the note as edited in Obsidian, next to the note.
+ */
+conflict_path: string }
+export type ObsidianSyncResult = { 
+/**
+ * Notes created or rewritten in the target folder, relative to it.
+ */
+written: string[]; 
+/**
+ * Sessions whose `_memo.md` was updated from an edit made in Obsidian.
+ */
+reconciled: string[]; conflicts: ObsidianConflict[] }
 export type ParsedDocument = { frontmatter: Partial<{ [key in string]: JsonValue }>; content: string }
 export type ScanResult = { files: Partial<{ [key in string]: string }>; dirs: string[] }
 
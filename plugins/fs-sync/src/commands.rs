@@ -351,3 +351,39 @@ pub(crate) async fn attachment_remove<R: tauri::Runtime>(
             .map_err(|e| e.to_string())
     })
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn obsidian_sync<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    target: String,
+    session_ids: Option<Vec<String>>,
+) -> Result<crate::ObsidianSyncResult, String> {
+    spawn_blocking!({
+        app.fs_sync()
+            .obsidian_sync(std::path::Path::new(&target), session_ids.as_deref())
+            .map_err(|e| e.to_string())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn obsidian_watch<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    target: String,
+) -> Result<(), String> {
+    app.notify()
+        .watch_external(std::path::Path::new(&target))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn obsidian_unwatch<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    target: String,
+) -> Result<(), String> {
+    app.notify()
+        .unwatch_external(std::path::Path::new(&target))
+        .map_err(|e| e.to_string())
+}
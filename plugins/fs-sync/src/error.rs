@@ -12,6 +12,8 @@ pub enum Error {
     Path(String),
     #[error(transparent)]
     Frontmatter(#[from] hypr_frontmatter::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
     #[error("Markdown error: {0}")]
     Markdown(String),
 }
@@ -1,10 +1,12 @@
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use tauri_plugin_notify::NotifyPluginExt;
 use tauri_plugin_settings::SettingsPluginExt;
 
 use crate::cleanup::{cleanup_dirs_recursive, cleanup_files_in_dir, cleanup_files_recursive};
 use crate::folder::scan_directory_recursive;
+use crate::obsidian::{self, ObsidianSyncResult};
 use crate::path::is_uuid;
 use crate::session::find_session_dir;
 use crate::types::CleanupTarget;
@@ -309,6 +311,34 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> FsSync<'a, R, M> {
         Ok(())
     }
 
+    /// The session's recording, if this device has one.
+    pub fn audio_path(&self, session_id: &str) -> Result<Option<PathBuf>, crate::Error> {
+        let session_dir = self.resolve_session_dir(session_id)?;
+        Ok(crate::audio::path(&session_dir))
+    }
+
+    /// Syncs sessions into the Obsidian folder at `target`. The notes written
+    /// there are marked as own writes, so they don't echo back as edits.
+    pub fn obsidian_sync(
+        &self,
+        target: &Path,
+        session_ids: Option<&[String]>,
+    ) -> Result<ObsidianSyncResult, crate::Error> {
+        let result = obsidian::sync(&self.base_dir()?, target, session_ids)?;
+
+        let written: Vec<String> = result
+            .written
+            .iter()
+            .chain(result.conflicts.iter().map(|c| &c.conflict_path))
+            .map(String::as_str)
+            .chain([obsidian::STATE_FILE])
+            .map(|p| target.join(p).to_string_lossy().to_string())
+            .collect();
+        self.manager.app_handle().notify().mark_own_writes(&written);
+
+        Ok(result)
+    }
+
     fn resolve_session_dir(&self, session_id: &str) -> Result<PathBuf, crate::Error> {
         let sessions_dir = self.sessions_dir()?;
         Ok(find_session_dir(&sessions_dir, session_id))
@@ -9,6 +9,7 @@ mod ext;
 mod folder;
 mod frontmatter;
 mod json;
+mod obsidian;
 mod path;
 mod scan;
 mod session;
@@ -18,6 +19,7 @@ pub use types::*;
 
 pub use error::{Error, Result};
 pub use ext::*;
+pub use obsidian::{ObsidianConflict, ObsidianSyncResult};
 pub use path::is_uuid;
 pub use session::find_session_dir;
 
@@ -49,6 +51,9 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::attachment_save::<tauri::Wry>,
             commands::attachment_list::<tauri::Wry>,
             commands::attachment_remove::<tauri::Wry>,
+            commands::obsidian_sync::<tauri::Wry>,
+            commands::obsidian_watch::<tauri::Wry>,
+            commands::obsidian_unwatch::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -0,0 +1,802 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::folder::scan_directory_recursive;
+use crate::frontmatter::ParsedDocument;
+use crate::types::ListFoldersResult;
+
+/// Everything after this line in a synced note is regenerated on every sync;
+/// only the text above it is read back into `_memo.md`.
+pub const GENERATED_MARKER: &str = "<!-- hyprnote:generated -->";
+
+// Kept inside the target folder, so pointing the sync at another folder starts over.
+pub(crate) const STATE_FILE: &str = ".hyprnote-sync.json";
+const STATE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct ObsidianSyncResult {
+    /// Notes created or rewritten in the target folder, relative to it.
+    pub written: Vec<String>,
+    /// Sessions whose `_memo.md` was updated from an edit made in Obsidian.
+    pub reconciled: Vec<String>,
+    pub conflicts: Vec<ObsidianConflict>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ObsidianConflict {
+    pub session_id: String,
+    pub note_path: String,
+    /// Copy of the note as edited in Obsidian, next to the note.
+    pub conflict_path: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncState {
+    version: u32,
+    #[serde(default)]
+    notes: HashMap<String, NoteState>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NoteState {
+    path: String,
+    /// The memo both sides agreed on after the last sync.
+    memo: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SessionMeta {
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    participants: Vec<Participant>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Participant {
+    human_id: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TranscriptFile {
+    #[serde(default)]
+    transcripts: Vec<TranscriptData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptData {
+    #[serde(default)]
+    started_at: f64,
+    #[serde(default)]
+    words: Vec<WordData>,
+    #[serde(default)]
+    speaker_hints: Vec<SpeakerHintData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WordData {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    start_ms: f64,
+    #[serde(default)]
+    channel: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpeakerHintData {
+    word_id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    value: serde_json::Value,
+}
+
+#[derive(Serialize)]
+struct NoteFrontmatter<'a> {
+    hyprnote_id: &'a str,
+    title: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<&'a str>,
+    participants: Vec<String>,
+    tags: Vec<String>,
+}
+
+struct Session {
+    id: String,
+    dir: PathBuf,
+    folder: String,
+    meta: SessionMeta,
+    memo: Option<ParsedDocument>,
+}
+
+/// Mirrors every session (or only `session_ids`) of the vault at `vault_base`
+/// into `target` as one note per session, and reads memo edits made in
+/// Obsidian back into `_memo.md`.
+///
+/// The memo is compared against what both sides held after the previous sync:
+/// a change on one side wins, a change on both sides is a conflict. On a
+/// conflict the Hyprnote memo is kept and the edited note is saved as a copy.
+pub fn sync(
+    vault_base: &Path,
+    target: &Path,
+    session_ids: Option<&[String]>,
+) -> Result<ObsidianSyncResult, crate::Error> {
+    std::fs::create_dir_all(target)?;
+
+    let mut state = read_state(target);
+    let mut result = ObsidianSyncResult::default();
+
+    let humans = human_names(&vault_base.join("humans"));
+
+    for session in list_sessions(&vault_base.join("sessions"), session_ids) {
+        sync_session(&session, &humans, target, &mut state, &mut result)?;
+    }
+
+    state.version = STATE_VERSION;
+    std::fs::write(
+        target.join(STATE_FILE),
+        serde_json::to_string_pretty(&state)?,
+    )?;
+
+    Ok(result)
+}
+
+fn sync_session(
+    session: &Session,
+    humans: &HashMap<String, String>,
+    target: &Path,
+    state: &mut SyncState,
+    result: &mut ObsidianSyncResult,
+) -> Result<(), crate::Error> {
+    let hyprnote_memo = session
+        .memo
+        .as_ref()
+        .map(|doc| doc.content.trim().to_string())
+        .unwrap_or_default();
+
+    let previous = state.notes.get(&session.id).cloned();
+    let note_path = note_path(session, previous.as_ref(), target);
+    let note_file = target.join(&note_path);
+
+    // Follow a session rename, unless the new name is already taken.
+    if let Some(previous) = &previous {
+        let previous_file = target.join(&previous.path);
+        if previous.path != note_path && previous_file.exists() && !note_file.exists() {
+            if let Some(parent) = note_file.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::rename(&previous_file, &note_file)?;
+        }
+    }
+
+    let existing = std::fs::read_to_string(&note_file).ok();
+    let obsidian_memo = existing.as_deref().map(editable_memo);
+    let base = previous.as_ref().map(|p| p.memo.as_str());
+
+    let memo = match obsidian_memo {
+        None => hyprnote_memo,
+        Some(obsidian_memo) if obsidian_memo == hyprnote_memo => hyprnote_memo,
+        Some(obsidian_memo) if Some(obsidian_memo.as_str()) == base => hyprnote_memo,
+        Some(obsidian_memo) if Some(hyprnote_memo.as_str()) == base => {
+            write_memo(session, &obsidian_memo)?;
+            result.reconciled.push(session.id.clone());
+            obsidian_memo
+        }
+        Some(_) => {
+            let conflict_path = conflict_path(&note_path);
+            std::fs::write(
+                target.join(&conflict_path),
+                existing.as_deref().unwrap_or_default(),
+            )?;
+            result.conflicts.push(ObsidianConflict {
+                session_id: session.id.clone(),
+                note_path: note_path.clone(),
+                conflict_path,
+            });
+            hyprnote_memo
+        }
+    };
+
+    let rendered = render_note(session, &memo, humans)?;
+    if existing.as_deref() != Some(rendered.as_str()) {
+        if let Some(parent) = note_file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&note_file, &rendered)?;
+        result.written.push(note_path.clone());
+    }
+
+    state.notes.insert(
+        session.id.clone(),
+        NoteState {
+            path: note_path,
+            memo,
+        },
+    );
+
+    Ok(())
+}
+
+fn read_state(target: &Path) -> SyncState {
+    std::fs::read_to_string(target.join(STATE_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn list_sessions(sessions_dir: &Path, session_ids: Option<&[String]>) -> Vec<Session> {
+    let mut folders = ListFoldersResult {
+        folders: HashMap::new(),
+        session_folder_map: HashMap::new(),
+    };
+    scan_directory_recursive(sessions_dir, "", &mut folders);
+
+    let mut sessions: Vec<Session> = folders
+        .session_folder_map
+        .into_iter()
+        .filter(|(id, _)| session_ids.is_none_or(|ids| ids.contains(id)))
+        .map(|(id, folder)| {
+            let dir = if folder.is_empty() {
+                sessions_dir.join(&id)
+            } else {
+                sessions_dir.join(&folder).join(&id)
+            };
+
+            let meta = std::fs::read_to_string(dir.join("_meta.json"))
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok())
+                .unwrap_or_default();
+            let memo = std::fs::read_to_string(dir.join("_memo.md"))
+                .ok()
+                .and_then(|content| ParsedDocument::from_str(&content).ok());
+
+            Session {
+                id,
+                dir,
+                folder,
+                meta,
+                memo,
+            }
+        })
+        .collect();
+
+    sessions.sort_by(|a, b| a.id.cmp(&b.id));
+    sessions
+}
+
+fn human_names(humans_dir: &Path) -> HashMap<String, String> {
+    let Ok(entries) = std::fs::read_dir(humans_dir) else {
+        return HashMap::new();
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("md") {
+                return None;
+            }
+            let id = path.file_stem()?.to_str()?.to_string();
+            let doc = ParsedDocument::from_str(&std::fs::read_to_string(&path).ok()?).ok()?;
+            let name = doc.frontmatter.get("name")?.as_str()?.trim().to_string();
+            (!name.is_empty()).then_some((id, name))
+        })
+        .collect()
+}
+
+// `<folder>/2025-03-04 Weekly sync.md`, mirroring the session's folder.
+fn note_path(session: &Session, previous: Option<&NoteState>, target: &Path) -> String {
+    let title = sanitize(&session.meta.title);
+    let stem = match session_date(session) {
+        Some(date) => format!("{} {}", date, title),
+        None => title,
+    };
+    let dir = if session.folder.is_empty() {
+        String::new()
+    } else {
+        format!("{}/", session.folder)
+    };
+
+    let mut candidate = format!("{}{}.md", dir, stem);
+    let mut n = 2;
+    // Two sessions with the same date and title each get their own note.
+    while previous.is_none_or(|p| p.path != candidate) && is_other_note(target, &candidate, session)
+    {
+        candidate = format!("{}{} ({}).md", dir, stem, n);
+        n += 1;
+    }
+    candidate
+}
+
+fn is_other_note(target: &Path, relative: &str, session: &Session) -> bool {
+    let Ok(content) = std::fs::read_to_string(target.join(relative)) else {
+        return false;
+    };
+    ParsedDocument::from_str(&content)
+        .ok()
+        .and_then(|doc| {
+            doc.frontmatter
+                .get("hyprnote_id")
+                .and_then(|v| v.as_str())
+                .map(|id| id != session.id)
+        })
+        .unwrap_or(true)
+}
+
+fn session_date(session: &Session) -> Option<&str> {
+    session
+        .meta
+        .created_at
+        .get(..10)
+        .filter(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").is_ok())
+}
+
+fn sanitize(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' | '#' | '^' | '[' | ']' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .take(80)
+        .collect();
+
+    let trimmed = cleaned.trim().trim_matches('.').trim();
+    if trimmed.is_empty() {
+        "Untitled".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn conflict_path(note_path: &str) -> String {
+    let stem = note_path.strip_suffix(".md").unwrap_or(note_path);
+    format!(
+        "{} (conflict {}).md",
+        stem,
+        chrono::Local::now().format("%Y-%m-%d %H%M%S")
+    )
+}
+
+/// The part of a synced note the user owns: the body above the marker. Without
+/// the marker, the whole body is taken as the memo.
+fn editable_memo(note: &str) -> String {
+    let body = ParsedDocument::from_str(note)
+        .map(|doc| doc.content)
+        .unwrap_or_else(|_| note.to_string());
+
+    match body.split_once(GENERATED_MARKER) {
+        Some((memo, _)) => memo.trim().to_string(),
+        None => body.trim().to_string(),
+    }
+}
+
+fn write_memo(session: &Session, memo: &str) -> Result<(), crate::Error> {
+    let mut doc = session.memo.clone().unwrap_or_else(|| ParsedDocument {
+        frontmatter: HashMap::from([
+            ("id".to_string(), session.id.clone().into()),
+            ("session_id".to_string(), session.id.clone().into()),
+        ]),
+        content: String::new(),
+    });
+    doc.content = memo.to_string();
+
+    std::fs::create_dir_all(&session.dir)?;
+    std::fs::write(session.dir.join("_memo.md"), doc.render()?)?;
+    Ok(())
+}
+
+fn render_note(
+    session: &Session,
+    memo: &str,
+    humans: &HashMap<String, String>,
+) -> Result<String, crate::Error> {
+    let participants = session
+        .meta
+        .participants
+        .iter()
+        .filter_map(|p| humans.get(&p.human_id))
+        .map(|name| format!("[[{}]]", name))
+        .collect();
+
+    // Obsidian tags cannot contain spaces.
+    let tags = session
+        .meta
+        .tags
+        .iter()
+        .map(|tag| tag.trim().replace(char::is_whitespace, "-"))
+        .filter(|tag| !tag.is_empty())
+        .collect();
+
+    let frontmatter = NoteFrontmatter {
+        hyprnote_id: &session.id,
+        title: &session.meta.title,
+        date: session_date(session),
+        participants,
+        tags,
+    };
+
+    let mut body = String::new();
+    if !memo.is_empty() {
+        body.push_str(memo);
+        body.push_str("\n\n");
+    }
+    body.push_str(GENERATED_MARKER);
+    body.push('\n');
+
+    let summaries = enhanced_notes(&session.dir);
+    if !summaries.is_empty() {
+        body.push_str("\n## Summary\n");
+        let titled = summaries.len() > 1;
+        for (title, content) in summaries {
+            body.push('\n');
+            if titled {
+                body.push_str(&format!("### {}\n\n", title));
+            }
+            body.push_str(content.trim());
+            body.push('\n');
+        }
+    }
+
+    let transcript = transcript_lines(&session.dir, humans);
+    if !transcript.is_empty() {
+        body.push_str("\n> [!note]- Transcript\n");
+        for line in transcript {
+            body.push_str("> ");
+            body.push_str(&line);
+            body.push_str("\n>\n");
+        }
+    }
+
+    Ok(hypr_frontmatter::Document::new(frontmatter, body).render()?)
+}
+
+/// Every enhanced note of the session as `(title, markdown)`, in order.
+fn enhanced_notes(session_dir: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = std::fs::read_dir(session_dir) else {
+        return vec![];
+    };
+
+    let mut notes: Vec<(i64, String, String)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("md"))
+        .filter(|path| path.file_name().and_then(|n| n.to_str()) != Some("_memo.md"))
+        .filter_map(|path| {
+            let doc = ParsedDocument::from_str(&std::fs::read_to_string(&path).ok()?).ok()?;
+            let position = doc
+                .frontmatter
+                .get("position")
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0);
+            let title = doc
+                .frontmatter
+                .get("title")
+                .and_then(|v| v.as_str())
+                .filter(|t| !t.trim().is_empty())
+                .map(str::to_string)
+                .or_else(|| {
+                    let stem = path.file_stem()?.to_str()?.trim_start_matches('_');
+                    Some(stem.to_string())
+                })
+                .unwrap_or_default();
+            Some((position, title, doc.content))
+        })
+        .filter(|(_, _, content)| !content.trim().is_empty())
+        .collect();
+
+    notes.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+    notes
+        .into_iter()
+        .map(|(_, title, content)| (title, content))
+        .collect()
+}
+
+/// One `**Speaker** [mm:ss] text` line per speaker turn.
+fn transcript_lines(session_dir: &Path, humans: &HashMap<String, String>) -> Vec<String> {
+    let file: TranscriptFile = std::fs::read_to_string(session_dir.join("transcript.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+
+    let mut transcripts = file.transcripts;
+    transcripts.sort_by(|a, b| a.started_at.total_cmp(&b.started_at));
+    let first_started_at = transcripts.first().map(|t| t.started_at).unwrap_or(0.0);
+
+    let mut words = vec![];
+    for transcript in &transcripts {
+        let offset = if transcript.started_at > 0.0 && first_started_at > 0.0 {
+            transcript.started_at - first_started_at
+        } else {
+            0.0
+        };
+        let speakers = speaker_names(&transcript.speaker_hints, humans);
+
+        for word in &transcript.words {
+            let text = word.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let speaker = speakers
+                .get(&word.id)
+                .cloned()
+                .unwrap_or_else(|| format!("Speaker {}", word.channel + 1));
+            words.push((speaker, word.start_ms + offset, text));
+        }
+    }
+    words.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let mut turns: Vec<(String, f64, String)> = vec![];
+    for (speaker, start, text) in words {
+        match turns.last_mut() {
+            Some((last, _, line)) if *last == speaker => {
+                line.push(' ');
+                line.push_str(text);
+            }
+            _ => turns.push((speaker, start, text.to_string())),
+        }
+    }
+
+    turns
+        .into_iter()
+        .map(|(speaker, start, text)| {
+            let seconds = (start / 1000.0) as u64;
+            format!(
+                "**{}** [{:02}:{:02}] {}",
+                speaker,
+                seconds / 60,
+                seconds % 60,
+                text
+            )
+        })
+        .collect()
+}
+
+fn speaker_names(
+    hints: &[SpeakerHintData],
+    humans: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    hints
+        .iter()
+        .filter_map(|hint| {
+            // Stored either as an object or as its JSON string.
+            let value = match &hint.value {
+                serde_json::Value::String(s) => serde_json::from_str(s).ok()?,
+                value => value.clone(),
+            };
+
+            let name = match hint.kind.as_str() {
+                "user_speaker_assignment" => humans.get(value.get("human_id")?.as_str()?)?.clone(),
+                "provider_speaker_index" => {
+                    format!("Speaker {}", value.get("speaker_index")?.as_i64()? + 1)
+                }
+                _ => return None,
+            };
+            Some((hint.word_id.clone(), name))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{UUID_1, UUID_2, md_with_frontmatter};
+    use assert_fs::TempDir;
+    use assert_fs::prelude::*;
+
+    fn vault() -> TempDir {
+        let temp = TempDir::new().unwrap();
+
+        let session = temp.child("sessions/work").child(UUID_1);
+        session
+            .child("_meta.json")
+            .write_str(&format!(
+                r#"{{"id":"{UUID_1}","created_at":"2025-03-04T10:00:00Z","title":"Weekly: sync","participants":[{{"id":"p1","human_id":"h1","session_id":"{UUID_1}","source":"manual"}}],"tags":["team updates"]}}"#
+            ))
+            .unwrap();
+        session
+            .child("_memo.md")
+            .write_str(&md_with_frontmatter(
+                &format!("id: {UUID_1}\nsession_id: {UUID_1}"),
+                "- agenda",
+            ))
+            .unwrap();
+        session
+            .child("_summary.md")
+            .write_str(&md_with_frontmatter(
+                &format!("id: e1\nsession_id: {UUID_1}\nposition: 0"),
+                "## Decisions\n\n- ship it",
+            ))
+            .unwrap();
+        session
+            .child("transcript.json")
+            .write_str(
+                r#"{"transcripts":[{"started_at":0,"words":[
+                    {"id":"w1","text":" Let's","start_ms":1000,"channel":0},
+                    {"id":"w2","text":" start.","start_ms":1500,"channel":0},
+                    {"id":"w3","text":" Sure.","start_ms":65000,"channel":1}
+                ],"speaker_hints":[
+                    {"word_id":"w1","type":"user_speaker_assignment","value":"{\"human_id\":\"h1\"}"},
+                    {"word_id":"w2","type":"user_speaker_assignment","value":{"human_id":"h1"}}
+                ]}]}"#,
+            )
+            .unwrap();
+
+        temp.child("sessions")
+            .child(UUID_2)
+            .child("_meta.json")
+            .write_str(r#"{"title":""}"#)
+            .unwrap();
+
+        temp.child("humans/h1.md")
+            .write_str(&md_with_frontmatter("name: Alice", ""))
+            .unwrap();
+
+        temp
+    }
+
+    fn memo(vault: &TempDir) -> String {
+        let content = std::fs::read_to_string(
+            vault
+                .path()
+                .join("sessions/work")
+                .join(UUID_1)
+                .join("_memo.md"),
+        )
+        .unwrap();
+        ParsedDocument::from_str(&content).unwrap().content
+    }
+
+    fn edit_note(note: &Path, memo: &str) {
+        let content = std::fs::read_to_string(note).unwrap();
+        let (head, tail) = content.split_once("- agenda").unwrap();
+        std::fs::write(note, format!("{}{}{}", head, memo, tail)).unwrap();
+    }
+
+    #[test]
+    fn test_render_note() {
+        let vault = vault();
+        let target = TempDir::new().unwrap();
+
+        let result = sync(vault.path(), target.path(), None).unwrap();
+        assert_eq!(
+            result.written,
+            vec![
+                "work/2025-03-04 Weekly_ sync.md".to_string(),
+                "Untitled.md".to_string()
+            ]
+        );
+
+        let note =
+            std::fs::read_to_string(target.path().join("work/2025-03-04 Weekly_ sync.md")).unwrap();
+        assert!(note.contains(&format!("hyprnote_id: {UUID_1}")));
+        assert!(note.contains("date: 2025-03-04"));
+        assert!(note.contains("participants:\n- '[[Alice]]'"));
+        assert!(note.contains("tags:\n- team-updates"));
+        assert!(note.contains(&format!(
+            "- agenda\n\n{GENERATED_MARKER}\n\n## Summary\n\n## Decisions\n\n- ship it\n"
+        )));
+        assert!(note.contains(
+            "> [!note]- Transcript\n> **Alice** [00:01] Let's start.\n>\n> **Speaker 2** [01:05] Sure.\n"
+        ));
+
+        // Nothing changed, nothing written.
+        let result = sync(vault.path(), target.path(), None).unwrap();
+        assert!(result.written.is_empty());
+    }
+
+    #[test]
+    fn test_reconcile_obsidian_edit() {
+        let vault = vault();
+        let target = TempDir::new().unwrap();
+        let note = target.path().join("work/2025-03-04 Weekly_ sync.md");
+
+        sync(vault.path(), target.path(), None).unwrap();
+        edit_note(&note, "- agenda\n- edited in obsidian");
+
+        let result = sync(vault.path(), target.path(), None).unwrap();
+        assert_eq!(result.reconciled, vec![UUID_1.to_string()]);
+        assert!(result.conflicts.is_empty());
+        assert_eq!(memo(&vault), "- agenda\n- edited in obsidian");
+
+        let content = std::fs::read_to_string(
+            vault
+                .path()
+                .join("sessions/work")
+                .join(UUID_1)
+                .join("_memo.md"),
+        )
+        .unwrap();
+        assert!(content.contains(&format!("session_id: {UUID_1}")));
+    }
+
+    #[test]
+    fn test_hyprnote_edit_rewrites_note() {
+        let vault = vault();
+        let target = TempDir::new().unwrap();
+
+        sync(vault.path(), target.path(), None).unwrap();
+        vault
+            .child("sessions/work")
+            .child(UUID_1)
+            .child("_memo.md")
+            .write_str(&md_with_frontmatter(
+                &format!("id: {UUID_1}"),
+                "- agenda\n- edited in hyprnote",
+            ))
+            .unwrap();
+
+        let result = sync(vault.path(), target.path(), None).unwrap();
+        assert!(result.reconciled.is_empty());
+        assert_eq!(
+            result.written,
+            vec!["work/2025-03-04 Weekly_ sync.md".to_string()]
+        );
+
+        let note =
+            std::fs::read_to_string(target.path().join("work/2025-03-04 Weekly_ sync.md")).unwrap();
+        assert!(note.contains("- edited in hyprnote"));
+    }
+
+    #[test]
+    fn test_concurrent_edit_conflict() {
+        let vault = vault();
+        let target = TempDir::new().unwrap();
+        let note = target.path().join("work/2025-03-04 Weekly_ sync.md");
+
+        sync(vault.path(), target.path(), None).unwrap();
+        edit_note(&note, "- from obsidian");
+        vault
+            .child("sessions/work")
+            .child(UUID_1)
+            .child("_memo.md")
+            .write_str(&md_with_frontmatter(
+                &format!("id: {UUID_1}"),
+                "- from hyprnote",
+            ))
+            .unwrap();
+
+        let result = sync(vault.path(), target.path(), None).unwrap();
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(memo(&vault), "- from hyprnote");
+
+        let conflict = &result.conflicts[0];
+        assert!(
+            conflict
+                .conflict_path
+                .starts_with("work/2025-03-04 Weekly_ sync (conflict ")
+        );
+        let saved = std::fs::read_to_string(target.path().join(&conflict.conflict_path)).unwrap();
+        assert!(saved.contains("- from obsidian"));
+        assert!(
+            std::fs::read_to_string(&note)
+                .unwrap()
+                .contains("- from hyprnote")
+        );
+
+        // Resolved: the next sync has nothing to report.
+        let result = sync(vault.path(), target.path(), None).unwrap();
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_editable_memo() {
+        let note = md_with_frontmatter(
+            "title: x",
+            &format!("memo text\n\n{GENERATED_MARKER}\n## Summary"),
+        );
+        assert_eq!(editable_memo(&note), "memo text");
+        assert_eq!(editable_memo("---\ntitle: x\n---\nno marker"), "no marker");
+    }
+}
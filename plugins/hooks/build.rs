@@ -1,4 +1,9 @@
-const COMMANDS: &[&str] = &["run_event_hooks"];
+const COMMANDS: &[&str] = &[
+    "run_event_hooks",
+    "run_transform_hooks",
+    "list_hook_runs",
+    "clear_hook_runs",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -13,6 +13,30 @@ async runEventHooks(event: HookEvent) : Promise<Result<HookResult[], string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async runTransformHooks(event: TransformEvent) : Promise<Result<TransformOutcome, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:hooks|run_transform_hooks", { event }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listHookRuns(event: string | null, limit: number | null) : Promise<Result<HookRun[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:hooks|list_hook_runs", { event, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearHookRuns() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:hooks|clear_hook_runs") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -26,6 +50,22 @@ async runEventHooks(event: HookEvent) : Promise<Result<HookResult[], string>> {
 
 /** user-defined types **/
 
+/**
+ * Arguments passed to transform hooks run on a freshly generated enhanced note.
+ */
+export type AfterEnhanceArgs = { 
+/**
+ * ID of the enhanced session.
+ */
+session_id: string; 
+/**
+ * ID of the generated note.
+ */
+note_id: string; 
+/**
+ * Optional template the note was generated from.
+ */
+template_id?: string | null }
 /**
  * Arguments passed to hooks triggered after listening stops.
  */
@@ -42,6 +82,14 @@ app_hyprnote: string;
  * Optional meeting-specific data.
  */
 app_meeting?: string | null }
+/**
+ * Arguments passed to transform hooks run on the transcript before it is sent for enhancement.
+ */
+export type BeforeEnhanceArgs = { 
+/**
+ * ID of the session being enhanced.
+ */
+session_id: string }
 /**
  * Arguments passed to hooks triggered before listening starts.
  */
@@ -58,6 +106,50 @@ app_hyprnote: string;
  * Optional meeting-specific data.
  */
 app_meeting?: string | null }
+/**
+ * Arguments passed to hooks triggered a few minutes before a calendar event starts.
+ */
+export type CalendarEventStartingArgs = { 
+/**
+ * ID of the calendar event.
+ */
+event_id: string; 
+/**
+ * Title of the calendar event.
+ */
+title: string; 
+/**
+ * Start time, in RFC 3339.
+ */
+started_at: string; 
+/**
+ * Optional meeting link of the event.
+ */
+meeting_link?: string | null; 
+/**
+ * Optional session already linked to the event.
+ */
+session_id?: string | null }
+/**
+ * Arguments passed to hooks triggered when an enhanced note finishes generating.
+ */
+export type EnhancementCompletedArgs = { 
+/**
+ * Path to the session directory.
+ */
+resource_dir: string; 
+/**
+ * ID of the enhanced session.
+ */
+session_id: string; 
+/**
+ * ID of the generated note.
+ */
+note_id: string; 
+/**
+ * Optional template the note was generated from.
+ */
+template_id?: string | null }
 /**
  * Defines a single hook to be executed on an event.
  */
@@ -65,9 +157,52 @@ export type HookDefinition = {
 /**
  * Shell command to execute when the hook is triggered.
  */
-command: string }
-export type HookEvent = { afterListeningStopped: { args: AfterListeningStoppedArgs } } | { beforeListeningStarted: { args: BeforeListeningStartedArgs } }
-export type HookResult = { command: string; success: boolean; exit_code: number | null; stdout: string; stderr: string }
+command: string; 
+/**
+ * Whether the hook only observes the event or rewrites its data.
+ */
+kind?: HookKind; 
+/**
+ * Position of a transform hook in its event's chain; lower runs first.
+ */
+order?: number; 
+/**
+ * What happens to the chain when this transform hook fails.
+ */
+on_failure?: TransformFailure; 
+/**
+ * Seconds to wait before the hook is killed. Defaults to 5.
+ */
+timeout_secs?: number | null; 
+/**
+ * Working directory of the command.
+ */
+cwd?: string | null; 
+/**
+ * Extra environment variables for the command.
+ */
+env?: Partial<{ [key in string]: string }> }
+export type HookEvent = { afterListeningStopped: { args: AfterListeningStoppedArgs } } | { beforeListeningStarted: { args: BeforeListeningStartedArgs } } | { enhancementCompleted: { args: EnhancementCompletedArgs } } | { transcriptFinalized: { args: TranscriptFinalizedArgs } } | { sessionCreated: { args: SessionCreatedArgs } } | { sessionDeleted: { args: SessionDeletedArgs } } | { calendarEventStarting: { args: CalendarEventStartingArgs } }
+/**
+ * How a hook takes part in an event.
+ */
+export type HookKind = 
+/**
+ * Runs alongside other hooks; its output is only recorded.
+ */
+"notify" | 
+/**
+ * Receives the event data on stdin and prints the replacement as JSON on stdout.
+ */
+"transform"
+export type HookResult = { command: string; success: boolean; exit_code: number | null; stdout: string; stderr: string; duration_ms: number }
+export type HookRun = { 
+event: string; 
+/**
+ * When the event was dispatched, in RFC 3339.
+ */
+started_at: string; 
+result: HookResult }
 /**
  * Configuration for hook execution.
  */
@@ -80,6 +215,93 @@ version: number;
  * Map of event names to their associated hook definitions.
  */
 on?: Partial<{ [key in string]: HookDefinition[] }> }
+/**
+ * Markdown of a generated note.
+ */
+export type NoteData = { markdown: string }
+/**
+ * Arguments passed to hooks triggered when a session is created.
+ */
+export type SessionCreatedArgs = { 
+/**
+ * Path to the session directory.
+ */
+resource_dir: string; 
+/**
+ * ID of the new session.
+ */
+session_id: string; 
+/**
+ * Optional title the session was created with.
+ */
+title?: string | null; 
+/**
+ * Optional calendar event the session belongs to.
+ */
+event_id?: string | null }
+/**
+ * Arguments passed to hooks triggered when a session is deleted.
+ */
+export type SessionDeletedArgs = { 
+/**
+ * ID of the deleted session.
+ */
+session_id: string }
+/**
+ * Transcript as it is handed to the enhancement prompt.
+ */
+export type TranscriptData = { segments: TranscriptSegment[] }
+/**
+ * Arguments passed to hooks triggered once a transcript will no longer change.
+ */
+export type TranscriptFinalizedArgs = { 
+/**
+ * Path to the session directory.
+ */
+resource_dir: string; 
+/**
+ * ID of the transcribed session.
+ */
+session_id: string; 
+/**
+ * How the transcript was produced: `live` or `batch`.
+ */
+source: string }
+export type TranscriptSegment = { speaker: string; text: string; 
+/**
+ * Milliseconds from the start of the session, when the transcript has timing.
+ */
+start_ms?: number | null; end_ms?: number | null }
+/**
+ * An event whose data can be rewritten by a chain of transform hooks.
+ */
+export type TransformEvent = { beforeEnhance: { args: BeforeEnhanceArgs; data: TranscriptData } } | { afterEnhance: { args: AfterEnhanceArgs; data: NoteData } }
+/**
+ * What a transform chain does when one of its hooks fails or returns invalid data.
+ */
+export type TransformFailure = 
+/**
+ * Pass the data on unchanged to the next hook.
+ */
+"skip" | 
+/**
+ * Stop the chain and fail the whole transform.
+ */
+"abort"
+export type TransformHookResult = (HookResult) & { 
+/**
+ * Whether the hook's output replaced the data.
+ */
+applied: boolean; 
+/**
+ * Why the output was rejected, if it was.
+ */
+rejected: string | null }
+export type TransformOutcome = { 
+/**
+ * The event carrying the data left at the end of the chain.
+ */
+event: TransformEvent; results: TransformHookResult[] }
 
 /** tauri-specta globals **/
 
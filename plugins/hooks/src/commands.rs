@@ -1,4 +1,10 @@
-use crate::{HooksPluginExt, event::HookEvent, runner::HookResult};
+use crate::{
+    HooksPluginExt,
+    event::HookEvent,
+    history::HookRun,
+    runner::HookResult,
+    transform::{TransformEvent, TransformOutcome},
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -11,3 +17,36 @@ pub(crate) async fn run_event_hooks<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn run_transform_hooks<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    event: TransformEvent,
+) -> Result<TransformOutcome, String> {
+    app.hooks()
+        .transform(event)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_hook_runs<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    event: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<HookRun>, String> {
+    Ok(app
+        .hooks()
+        .runs(event.as_deref(), limit.map(|l| l as usize)))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn clear_hook_runs<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.hooks().clear_runs();
+    Ok(())
+}
@@ -10,9 +10,53 @@ pub struct HooksConfig {
     pub on: HashMap<String, Vec<HookDefinition>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
 pub struct HookDefinition {
     pub command: String,
+    /// Whether the hook only observes the event or rewrites its data.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub kind: HookKind,
+    /// Position of a transform hook in its event's chain; lower runs first.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub order: i32,
+    /// What happens to the chain when this transform hook fails.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub on_failure: TransformFailure,
+    /// Seconds to wait before the hook is killed. Defaults to 5.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    /// Working directory of the command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Extra environment variables for the command.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+}
+
+/// How a hook takes part in an event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum HookKind {
+    /// Runs alongside other hooks; its output is only recorded.
+    #[default]
+    Notify,
+    /// Receives the event data on stdin and prints the replacement as JSON on stdout.
+    Transform,
+}
+
+/// What a transform chain does when one of its hooks fails or returns invalid data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum TransformFailure {
+    /// Pass the data on unchanged to the next hook.
+    #[default]
+    Skip,
+    /// Stop the chain and fail the whole transform.
+    Abort,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
 }
 
 impl HooksConfig {
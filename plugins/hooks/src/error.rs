@@ -12,6 +12,8 @@ pub enum Error {
     UnsupportedVersion(u8),
     #[error("hook execution failed: {0}")]
     HookExecution(String),
+    #[error("transform hook `{command}` failed: {reason}")]
+    TransformAborted { command: String, reason: String },
 }
 
 impl Serialize for Error {
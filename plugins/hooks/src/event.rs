@@ -9,6 +9,21 @@ pub enum HookEvent {
     #[serde(rename = "beforeListeningStarted")]
     #[specta(rename = "beforeListeningStarted")]
     BeforeListeningStarted { args: BeforeListeningStartedArgs },
+    #[serde(rename = "enhancementCompleted")]
+    #[specta(rename = "enhancementCompleted")]
+    EnhancementCompleted { args: EnhancementCompletedArgs },
+    #[serde(rename = "transcriptFinalized")]
+    #[specta(rename = "transcriptFinalized")]
+    TranscriptFinalized { args: TranscriptFinalizedArgs },
+    #[serde(rename = "sessionCreated")]
+    #[specta(rename = "sessionCreated")]
+    SessionCreated { args: SessionCreatedArgs },
+    #[serde(rename = "sessionDeleted")]
+    #[specta(rename = "sessionDeleted")]
+    SessionDeleted { args: SessionDeletedArgs },
+    #[serde(rename = "calendarEventStarting")]
+    #[specta(rename = "calendarEventStarting")]
+    CalendarEventStarting { args: CalendarEventStartingArgs },
 }
 
 impl HookEvent {
@@ -16,6 +31,11 @@ impl HookEvent {
         match self {
             HookEvent::AfterListeningStopped { .. } => "afterListeningStopped",
             HookEvent::BeforeListeningStarted { .. } => "beforeListeningStarted",
+            HookEvent::EnhancementCompleted { .. } => "enhancementCompleted",
+            HookEvent::TranscriptFinalized { .. } => "transcriptFinalized",
+            HookEvent::SessionCreated { .. } => "sessionCreated",
+            HookEvent::SessionDeleted { .. } => "sessionDeleted",
+            HookEvent::CalendarEventStarting { .. } => "calendarEventStarting",
         }
     }
 
@@ -23,8 +43,31 @@ impl HookEvent {
         match self {
             HookEvent::AfterListeningStopped { args } => args.to_cli_args(),
             HookEvent::BeforeListeningStarted { args } => args.to_cli_args(),
+            HookEvent::EnhancementCompleted { args } => args.to_cli_args(),
+            HookEvent::TranscriptFinalized { args } => args.to_cli_args(),
+            HookEvent::SessionCreated { args } => args.to_cli_args(),
+            HookEvent::SessionDeleted { args } => args.to_cli_args(),
+            HookEvent::CalendarEventStarting { args } => args.to_cli_args(),
         }
     }
+
+    /// What a hook reads on stdin: `{"event": "<name>", "args": {...}}`.
+    pub fn payload(&self) -> serde_json::Value {
+        let args = match self {
+            HookEvent::AfterListeningStopped { args } => serde_json::to_value(args),
+            HookEvent::BeforeListeningStarted { args } => serde_json::to_value(args),
+            HookEvent::EnhancementCompleted { args } => serde_json::to_value(args),
+            HookEvent::TranscriptFinalized { args } => serde_json::to_value(args),
+            HookEvent::SessionCreated { args } => serde_json::to_value(args),
+            HookEvent::SessionDeleted { args } => serde_json::to_value(args),
+            HookEvent::CalendarEventStarting { args } => serde_json::to_value(args),
+        };
+
+        serde_json::json!({
+            "event": self.condition_key(),
+            "args": args.unwrap_or_default(),
+        })
+    }
 }
 
 pub trait HookArgs {
@@ -87,3 +130,135 @@ impl HookArgs for BeforeListeningStartedArgs {
         args
     }
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+/// Arguments passed to hooks triggered when an enhanced note finishes generating.
+pub struct EnhancementCompletedArgs {
+    /// Path to the session directory.
+    pub resource_dir: String,
+    /// ID of the enhanced session.
+    pub session_id: String,
+    /// ID of the generated note.
+    pub note_id: String,
+    /// Optional template the note was generated from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+}
+
+impl HookArgs for EnhancementCompletedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(8);
+        push_cli_arg(&mut args, stringify!(resource_dir), &self.resource_dir);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+        push_cli_arg(&mut args, stringify!(note_id), &self.note_id);
+
+        if let Some(template_id) = &self.template_id {
+            push_cli_arg(&mut args, stringify!(template_id), template_id);
+        }
+
+        args
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+/// Arguments passed to hooks triggered once a transcript will no longer change.
+pub struct TranscriptFinalizedArgs {
+    /// Path to the session directory.
+    pub resource_dir: String,
+    /// ID of the transcribed session.
+    pub session_id: String,
+    /// How the transcript was produced: `live` or `batch`.
+    pub source: String,
+}
+
+impl HookArgs for TranscriptFinalizedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(6);
+        push_cli_arg(&mut args, stringify!(resource_dir), &self.resource_dir);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+        push_cli_arg(&mut args, stringify!(source), &self.source);
+        args
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+/// Arguments passed to hooks triggered when a session is created.
+pub struct SessionCreatedArgs {
+    /// Path to the session directory.
+    pub resource_dir: String,
+    /// ID of the new session.
+    pub session_id: String,
+    /// Optional title the session was created with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional calendar event the session belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+}
+
+impl HookArgs for SessionCreatedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(8);
+        push_cli_arg(&mut args, stringify!(resource_dir), &self.resource_dir);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+
+        if let Some(title) = &self.title {
+            push_cli_arg(&mut args, stringify!(title), title);
+        }
+        if let Some(event_id) = &self.event_id {
+            push_cli_arg(&mut args, stringify!(event_id), event_id);
+        }
+
+        args
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+/// Arguments passed to hooks triggered when a session is deleted.
+pub struct SessionDeletedArgs {
+    /// ID of the deleted session.
+    pub session_id: String,
+}
+
+impl HookArgs for SessionDeletedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(2);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+        args
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+/// Arguments passed to hooks triggered a few minutes before a calendar event starts.
+pub struct CalendarEventStartingArgs {
+    /// ID of the calendar event.
+    pub event_id: String,
+    /// Title of the calendar event.
+    pub title: String,
+    /// Start time, in RFC 3339.
+    pub started_at: String,
+    /// Optional meeting link of the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting_link: Option<String>,
+    /// Optional session already linked to the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+impl HookArgs for CalendarEventStartingArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(10);
+        push_cli_arg(&mut args, stringify!(event_id), &self.event_id);
+        push_cli_arg(&mut args, stringify!(title), &self.title);
+        push_cli_arg(&mut args, stringify!(started_at), &self.started_at);
+
+        if let Some(meeting_link) = &self.meeting_link {
+            push_cli_arg(&mut args, stringify!(meeting_link), meeting_link);
+        }
+        if let Some(session_id) = &self.session_id {
+            push_cli_arg(&mut args, stringify!(session_id), session_id);
+        }
+
+        args
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
+    config::HooksConfig,
     event::HookEvent,
+    history::{HookHistory, HookRun},
     runner::{HookResult, run_hooks_for_event},
+    transform::{TransformEvent, TransformOutcome, run_transform_chain},
 };
 
 pub struct Hooks<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
@@ -10,7 +13,55 @@ pub struct Hooks<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
 
 impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Hooks<'a, R, M> {
     pub async fn handle_event(&self, event: HookEvent) -> crate::Result<Vec<HookResult>> {
-        run_hooks_for_event(self.manager, event).await
+        let event_name = event.condition_key();
+        let started_at = chrono::Utc::now().to_rfc3339();
+
+        let results = run_hooks_for_event(self.manager, event).await?;
+
+        self.manager
+            .state::<HookHistory>()
+            .record(results.iter().map(|result| HookRun {
+                event: event_name.to_string(),
+                started_at: started_at.clone(),
+                result: result.clone(),
+            }));
+
+        Ok(results)
+    }
+
+    /// Runs the event's transform hooks as a chain and returns the event
+    /// with the data left by the last hook that changed it.
+    pub async fn transform(&self, event: TransformEvent) -> crate::Result<TransformOutcome> {
+        let config = HooksConfig::load(self.manager).await?;
+        let event_name = event.condition_key();
+        let started_at = chrono::Utc::now().to_rfc3339();
+
+        let Some(hooks) = config.on.get(event_name) else {
+            return Ok(TransformOutcome {
+                event,
+                results: vec![],
+            });
+        };
+
+        let outcome = run_transform_chain(hooks, event).await?;
+
+        self.manager
+            .state::<HookHistory>()
+            .record(outcome.results.iter().map(|result| HookRun {
+                event: event_name.to_string(),
+                started_at: started_at.clone(),
+                result: result.result.clone(),
+            }));
+
+        Ok(outcome)
+    }
+
+    pub fn runs(&self, event: Option<&str>, limit: Option<usize>) -> Vec<HookRun> {
+        self.manager.state::<HookHistory>().list(event, limit)
+    }
+
+    pub fn clear_runs(&self) {
+        self.manager.state::<HookHistory>().clear();
     }
 }
 
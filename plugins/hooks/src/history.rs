@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::runner::HookResult;
+
+const MAX_HOOK_RUNS: usize = 200;
+
+pub const FILENAME: &str = "hook-runs.json";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct HookRun {
+    pub event: String,
+    /// When the event was dispatched, in RFC 3339.
+    pub started_at: String,
+    pub result: HookResult,
+}
+
+/// The most recent hook runs, oldest first. With a path, they are kept in that
+/// file so they survive restarts.
+#[derive(Default)]
+pub struct HookHistory {
+    path: Option<PathBuf>,
+    runs: Mutex<VecDeque<HookRun>>,
+}
+
+impl HookHistory {
+    /// A missing or unreadable file starts an empty history.
+    pub fn load(path: PathBuf) -> Self {
+        let mut runs: VecDeque<HookRun> = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        while runs.len() > MAX_HOOK_RUNS {
+            runs.pop_front();
+        }
+
+        Self {
+            path: Some(path),
+            runs: Mutex::new(runs),
+        }
+    }
+
+    pub fn record(&self, runs: impl IntoIterator<Item = HookRun>) {
+        let mut guard = self.runs.lock().unwrap();
+        for run in runs {
+            if guard.len() == MAX_HOOK_RUNS {
+                guard.pop_front();
+            }
+            guard.push_back(run);
+        }
+        self.persist(&guard);
+    }
+
+    /// Newest first, optionally only the runs of one event.
+    pub fn list(&self, event: Option<&str>, limit: Option<usize>) -> Vec<HookRun> {
+        self.runs
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|run| event.is_none_or(|event| run.event == event))
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        let mut guard = self.runs.lock().unwrap();
+        guard.clear();
+        self.persist(&guard);
+    }
+
+    // Written to a sibling file and renamed, so a crash never leaves half a file.
+    fn persist(&self, runs: &VecDeque<HookRun>) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let Ok(content) = serde_json::to_string(runs) else {
+            return;
+        };
+
+        let tmp = path.with_extension("json.tmp");
+        if std::fs::write(&tmp, content).is_ok() {
+            let _ = std::fs::rename(&tmp, path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(event: &str, command: &str) -> HookRun {
+        HookRun {
+            event: event.to_string(),
+            started_at: String::new(),
+            result: HookResult {
+                command: command.to_string(),
+                success: true,
+                exit_code: Some(0),
+                stdout: String::new(),
+                stderr: String::new(),
+                duration_ms: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn lists_newest_first_and_filters() {
+        let history = HookHistory::default();
+        history.record([run("sessionCreated", "a"), run("sessionDeleted", "b")]);
+        history.record([run("sessionCreated", "c")]);
+
+        let commands = |runs: Vec<HookRun>| -> Vec<String> {
+            runs.into_iter().map(|r| r.result.command).collect()
+        };
+
+        assert_eq!(commands(history.list(None, None)), ["c", "b", "a"]);
+        assert_eq!(
+            commands(history.list(Some("sessionCreated"), None)),
+            ["c", "a"]
+        );
+        assert_eq!(commands(history.list(None, Some(1))), ["c"]);
+
+        history.clear();
+        assert!(history.list(None, None).is_empty());
+    }
+
+    #[test]
+    fn keeps_most_recent_runs() {
+        let history = HookHistory::default();
+        history.record((0..MAX_HOOK_RUNS + 10).map(|i| run("e", &i.to_string())));
+
+        let runs = history.list(None, None);
+        assert_eq!(runs.len(), MAX_HOOK_RUNS);
+        assert_eq!(runs[0].result.command, (MAX_HOOK_RUNS + 9).to_string());
+        assert_eq!(runs.last().unwrap().result.command, "10");
+    }
+
+    #[test]
+    fn persists_across_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(FILENAME);
+
+        let history = HookHistory::load(path.clone());
+        history.record([run("sessionCreated", "a"), run("sessionCreated", "b")]);
+
+        let reloaded = HookHistory::load(path.clone());
+        let commands: Vec<_> = reloaded
+            .list(None, None)
+            .into_iter()
+            .map(|r| r.result.command)
+            .collect();
+        assert_eq!(commands, ["b", "a"]);
+
+        reloaded.clear();
+        assert!(HookHistory::load(path).list(None, None).is_empty());
+    }
+}
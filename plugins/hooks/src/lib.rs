@@ -3,15 +3,22 @@ mod config;
 mod error;
 mod event;
 mod ext;
+mod history;
 mod naming;
 mod runner;
+mod transform;
 
 #[cfg(test)]
 mod docs;
 
 pub use error::*;
 pub use ext::*;
+pub use history::HookRun;
 pub use runner::*;
+pub use transform::*;
+
+use tauri::Manager;
+use tauri_plugin_settings::SettingsPluginExt;
 
 const PLUGIN_NAME: &str = "hooks";
 
@@ -20,6 +27,9 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .plugin_name(PLUGIN_NAME)
         .commands(tauri_specta::collect_commands![
             commands::run_event_hooks::<tauri::Wry>,
+            commands::run_transform_hooks::<tauri::Wry>,
+            commands::list_hook_runs::<tauri::Wry>,
+            commands::clear_hook_runs::<tauri::Wry>,
         ])
         .typ::<config::HooksConfig>()
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
@@ -30,7 +40,14 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
-        .setup(|_app, _api| Ok(()))
+        .setup(|app, _api| {
+            let history = match app.settings().global_base() {
+                Ok(base) => history::HookHistory::load(base.join(history::FILENAME)),
+                Err(_) => history::HookHistory::default(),
+            };
+            app.manage(history);
+            Ok(())
+        })
         .build()
 }
 
@@ -1,7 +1,12 @@
 use std::ffi::OsString;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crate::{config::HooksConfig, event::HookEvent};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+
+use crate::{
+    config::{HookDefinition, HookKind, HooksConfig},
+    event::HookEvent,
+};
 
 const HOOK_TIMEOUT: Duration = Duration::from_secs(5);
 
@@ -12,6 +17,7 @@ pub struct HookResult {
     pub exit_code: Option<i32>,
     pub stdout: String,
     pub stderr: String,
+    pub duration_ms: u64,
 }
 
 pub async fn run_hooks_for_event<R: tauri::Runtime>(
@@ -19,111 +25,192 @@ pub async fn run_hooks_for_event<R: tauri::Runtime>(
     event: HookEvent,
 ) -> crate::Result<Vec<HookResult>> {
     let config = HooksConfig::load(app).await?;
-    let condition_key = event.condition_key();
-    let cli_args = event.cli_args();
 
-    let Some(hooks) = config.on.get(condition_key) else {
+    let Some(hooks) = config.on.get(event.condition_key()) else {
         return Ok(vec![]);
     };
 
+    Ok(run_hooks(hooks, &event).await)
+}
+
+pub(crate) async fn run_hooks(hooks: &[HookDefinition], event: &HookEvent) -> Vec<HookResult> {
+    let cli_args = event.cli_args();
+    let payload = serde_json::to_vec(&event.payload()).unwrap_or_default();
+
     let futures: Vec<_> = hooks
         .iter()
-        .map(|hook_def| {
-            let command = hook_def.command.clone();
-            let args = cli_args.clone();
-            async move { execute_hook(&command, &args).await }
-        })
+        .filter(|hook| hook.kind == HookKind::Notify)
+        .map(|hook| execute_hook(hook, event.condition_key(), &cli_args, &payload))
         .collect();
 
-    let results = futures_util::future::join_all(futures).await;
-    Ok(results)
+    futures_util::future::join_all(futures).await
 }
 
-async fn execute_hook(command: &str, args: &[OsString]) -> HookResult {
-    let expanded = shellexpand::full(command)
-        .map(|s| s.into_owned())
-        .unwrap_or_else(|_| command.to_string());
+/// Splits a command like a POSIX shell would. `~` and environment variables
+/// are expanded outside quotes and `$VAR` inside double quotes; single quotes
+/// and backslashes keep text literal. Unset variables expand to nothing.
+fn parse_command(command: &str) -> Result<Vec<String>, String> {
+    // Words are collected in `shellexpand` syntax, where `$$` is a literal `$`.
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = command.chars().peekable();
 
-    let parts: Vec<&str> = expanded.split_whitespace().collect();
-
-    if parts.is_empty() {
-        return HookResult {
-            command: command.to_string(),
-            success: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: "empty command".to_string(),
-        };
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => words.extend(word.take()),
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some('$') => word.push_str("$$"),
+                        Some(c) => word.push(c),
+                        None => return Err("unbalanced quotes in command".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            match chars.next_if(|c| matches!(c, '"' | '\\' | '$' | '`')) {
+                                Some('$') => word.push_str("$$"),
+                                Some(c) => word.push(c),
+                                None => word.push('\\'),
+                            }
+                        }
+                        Some(c) => word.push(c),
+                        None => return Err("unbalanced quotes in command".to_string()),
+                    }
+                }
+            }
+            '\\' => {
+                let word = word.get_or_insert_with(String::new);
+                match chars.next() {
+                    Some('$') => word.push_str("$$"),
+                    Some(c) => word.push(c),
+                    None => {}
+                }
+            }
+            '~' if word.is_none()
+                && chars.peek().is_none_or(|c| *c == '/' || c.is_whitespace()) =>
+            {
+                let home = dirs::home_dir().map(|home| home.to_string_lossy().replace('$', "$$"));
+                word = Some(home.unwrap_or_else(|| "~".to_string()));
+            }
+            c => word.get_or_insert_with(String::new).push(c),
+        }
     }
+    words.extend(word);
+
+    Ok(words
+        .iter()
+        .map(|word| {
+            shellexpand::env_with_context_no_errors(word, |var| {
+                Some(std::env::var(var).unwrap_or_default())
+            })
+            .into_owned()
+        })
+        .collect())
+}
+
+fn expand(s: &str) -> String {
+    shellexpand::full(s)
+        .map(|s| s.into_owned())
+        .unwrap_or_else(|_| s.to_string())
+}
 
-    let mut cmd = tokio::process::Command::new(parts[0]);
+async fn read_all(reader: Option<impl AsyncRead + Unpin>) -> String {
+    let Some(mut reader) = reader else {
+        return String::new();
+    };
+    let mut buf = Vec::new();
+    let _ = reader.read_to_end(&mut buf).await;
+    String::from_utf8_lossy(&buf).to_string()
+}
 
-    if parts.len() > 1 {
-        cmd.args(&parts[1..]);
-    }
+pub(crate) async fn execute_hook(
+    hook: &HookDefinition,
+    event: &str,
+    args: &[OsString],
+    payload: &[u8],
+) -> HookResult {
+    let started = Instant::now();
+    let failure = |stderr: String| HookResult {
+        command: hook.command.clone(),
+        success: false,
+        exit_code: None,
+        stdout: String::new(),
+        stderr,
+        duration_ms: started.elapsed().as_millis() as u64,
+    };
 
-    cmd.args(args);
+    let parts = match parse_command(&hook.command) {
+        Ok(parts) if !parts.is_empty() => parts,
+        Ok(_) => return failure("empty command".to_string()),
+        Err(e) => return failure(e),
+    };
 
-    let mut child = match cmd
+    let mut cmd = tokio::process::Command::new(&parts[0]);
+    cmd.args(&parts[1..])
+        .args(args)
+        .env("HYPRNOTE_HOOK_EVENT", event)
+        .envs(hook.env.iter().map(|(k, v)| (k, expand(v))))
+        .stdin(std::process::Stdio::piped())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
-        .spawn()
-    {
+        .kill_on_drop(true);
+
+    if let Some(cwd) = &hook.cwd {
+        cmd.current_dir(expand(cwd));
+    }
+
+    let mut child = match cmd.spawn() {
         Ok(child) => child,
-        Err(e) => {
-            return HookResult {
-                command: command.to_string(),
-                success: false,
-                exit_code: None,
-                stdout: String::new(),
-                stderr: format!("failed to spawn command: {}", e),
-            };
-        }
+        Err(e) => return failure(format!("failed to spawn command: {}", e)),
     };
 
-    match tokio::time::timeout(HOOK_TIMEOUT, child.wait()).await {
-        Ok(Ok(status)) => {
-            let stdout = match child.stdout.take() {
-                Some(mut stdout) => {
-                    let mut buf = Vec::new();
-                    let _ = tokio::io::AsyncReadExt::read_to_end(&mut stdout, &mut buf).await;
-                    String::from_utf8_lossy(&buf).to_string()
-                }
-                None => String::new(),
-            };
-            let stderr = match child.stderr.take() {
-                Some(mut stderr) => {
-                    let mut buf = Vec::new();
-                    let _ = tokio::io::AsyncReadExt::read_to_end(&mut stderr, &mut buf).await;
-                    String::from_utf8_lossy(&buf).to_string()
-                }
-                None => String::new(),
-            };
-            HookResult {
-                command: command.to_string(),
-                success: status.success(),
-                exit_code: status.code(),
-                stdout,
-                stderr,
-            }
+    let stdin = child.stdin.take();
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    // Hooks that ignore stdin close it early; a broken pipe is not a failure.
+    let write_payload = async move {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(payload).await;
         }
-        Ok(Err(e)) => HookResult {
-            command: command.to_string(),
-            success: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: format!("failed to wait for command: {}", e),
+    };
+
+    let timeout = hook.timeout_secs.map_or(HOOK_TIMEOUT, Duration::from_secs);
+    let outcome = tokio::time::timeout(timeout, async {
+        tokio::join!(
+            write_payload,
+            read_all(stdout),
+            read_all(stderr),
+            child.wait()
+        )
+    })
+    .await;
+
+    match outcome {
+        Ok((_, stdout, stderr, Ok(status))) => HookResult {
+            command: hook.command.clone(),
+            success: status.success(),
+            exit_code: status.code(),
+            stdout,
+            stderr,
+            duration_ms: started.elapsed().as_millis() as u64,
         },
+        Ok((_, _, _, Err(e))) => failure(format!("failed to wait for command: {}", e)),
         Err(_) => {
             let _ = child.kill().await;
             let _ = child.wait().await;
-            HookResult {
-                command: command.to_string(),
-                success: false,
-                exit_code: None,
-                stdout: String::new(),
-                stderr: format!("hook timed out after {} seconds", HOOK_TIMEOUT.as_secs()),
-            }
+            failure(format!(
+                "hook timed out after {} seconds",
+                timeout.as_secs()
+            ))
         }
     }
 }
@@ -132,9 +219,20 @@ async fn execute_hook(command: &str, args: &[OsString]) -> HookResult {
 mod tests {
     use super::*;
 
+    fn hook(command: &str) -> HookDefinition {
+        HookDefinition {
+            command: command.to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn run(command: &str, args: &[OsString]) -> HookResult {
+        execute_hook(&hook(command), "test", args, b"{}").await
+    }
+
     #[tokio::test]
     async fn empty_command() {
-        let result = execute_hook("", &[]).await;
+        let result = run("", &[]).await;
         assert!(!result.success);
         assert_eq!(result.stderr, "empty command");
     }
@@ -142,7 +240,7 @@ mod tests {
     #[tokio::test]
     #[cfg(unix)]
     async fn successful_command() {
-        let result = execute_hook("echo hello", &[]).await;
+        let result = run("echo hello", &[]).await;
         assert!(result.success);
         assert_eq!(result.exit_code, Some(0));
         assert_eq!(result.stdout.trim(), "hello");
@@ -151,7 +249,7 @@ mod tests {
     #[tokio::test]
     #[cfg(unix)]
     async fn failed_command() {
-        let result = execute_hook("false", &[]).await;
+        let result = run("false", &[]).await;
         assert!(!result.success);
         assert_eq!(result.exit_code, Some(1));
     }
@@ -160,7 +258,7 @@ mod tests {
     #[cfg(unix)]
     async fn with_cli_args() {
         let args = vec![OsString::from("world")];
-        let result = execute_hook("echo", &args).await;
+        let result = run("echo", &args).await;
         assert!(result.success);
         assert_eq!(result.stdout.trim(), "world");
     }
@@ -169,7 +267,7 @@ mod tests {
     #[cfg(unix)]
     async fn expands_home_env_var() {
         let home = std::env::var("HOME").unwrap();
-        let result = execute_hook("echo $HOME", &[]).await;
+        let result = run("echo $HOME", &[]).await;
         assert!(result.success);
         assert_eq!(result.stdout.trim(), home);
     }
@@ -177,15 +275,103 @@ mod tests {
     #[tokio::test]
     #[cfg(unix)]
     async fn expands_tilde_in_command_path() {
-        let result = execute_hook("~/../../bin/echo tilde_works", &[]).await;
+        let result = run("~/../../bin/echo tilde_works", &[]).await;
         assert!(result.success);
         assert_eq!(result.stdout.trim(), "tilde_works");
     }
 
+    #[test]
+    fn parse_command_quoting() {
+        let home = std::env::var("HOME").unwrap();
+        let parse = |command: &str| parse_command(command).unwrap();
+
+        assert_eq!(
+            parse("echo '$HOME' \"$HOME\" $HOME"),
+            ["echo", "$HOME", &home, &home]
+        );
+        assert_eq!(
+            parse(r#"echo \$HOME "\$HOME" "a\b""#),
+            ["echo", "$HOME", "$HOME", "a\\b"]
+        );
+        assert_eq!(
+            parse("cat '~/notes' ~/notes a~"),
+            ["cat", "~/notes", &format!("{home}/notes"), "a~"]
+        );
+        assert_eq!(parse("printf '' \"x\"'y'z  "), ["printf", "", "xyz"]);
+        assert_eq!(
+            parse("echo $HYPRNOTE_UNSET_VAR a${HYPRNOTE_UNSET_VAR}b"),
+            ["echo", "", "ab"]
+        );
+        assert!(parse_command("echo 'open").is_err());
+    }
+
     #[tokio::test]
     async fn nonexistent_command() {
-        let result = execute_hook("nonexistent_command_12345", &[]).await;
+        let result = run("nonexistent_command_12345", &[]).await;
         assert!(!result.success);
         assert!(result.stderr.contains("failed to spawn command"));
     }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn quoted_arguments() {
+        let result = run(r#"printf '%s|' "two words" 'and more'"#, &[]).await;
+        assert!(result.success);
+        assert_eq!(result.stdout, "two words|and more|");
+
+        let result = run(r#"echo "unbalanced"#, &[]).await;
+        assert!(!result.success);
+        assert_eq!(result.stderr, "unbalanced quotes in command");
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn json_payload_on_stdin() {
+        let event = HookEvent::SessionDeleted {
+            args: crate::event::SessionDeletedArgs {
+                session_id: "s1".to_string(),
+            },
+        };
+        // `sh -c` takes the CLI flags as positional parameters and ignores them.
+        let results = run_hooks(&[hook("sh -c cat")], &event).await;
+
+        let payload: serde_json::Value = serde_json::from_str(&results[0].stdout).unwrap();
+        assert_eq!(payload["event"], "sessionDeleted");
+        assert_eq!(payload["args"]["session_id"], "s1");
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn cwd_and_env() {
+        let dir = std::env::temp_dir();
+        let hook = HookDefinition {
+            command: "sh -c 'pwd; echo $GREETING $HYPRNOTE_HOOK_EVENT'".to_string(),
+            cwd: Some(dir.to_string_lossy().to_string()),
+            env: [("GREETING".to_string(), "hi".to_string())].into(),
+            ..Default::default()
+        };
+
+        let result = execute_hook(&hook, "sessionCreated", &[], b"").await;
+        let lines: Vec<_> = result.stdout.lines().collect();
+        assert_eq!(
+            std::fs::canonicalize(lines[0]).unwrap(),
+            std::fs::canonicalize(&dir).unwrap()
+        );
+        assert_eq!(lines[1], "hi sessionCreated");
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn per_hook_timeout() {
+        let hook = HookDefinition {
+            command: "sleep 5".to_string(),
+            timeout_secs: Some(1),
+            ..Default::default()
+        };
+
+        let result = execute_hook(&hook, "test", &[], b"").await;
+        assert!(!result.success);
+        assert_eq!(result.stderr, "hook timed out after 1 seconds");
+        assert!(result.duration_ms < 3_000);
+    }
 }
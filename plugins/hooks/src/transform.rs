@@ -0,0 +1,468 @@
+use std::ffi::OsString;
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    config::{HookDefinition, HookKind, TransformFailure},
+    event::HookArgs,
+    naming::cli_flag,
+    runner::{HookResult, execute_hook},
+};
+
+/// An event whose data can be rewritten by a chain of transform hooks.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum TransformEvent {
+    #[serde(rename = "beforeEnhance")]
+    #[specta(rename = "beforeEnhance")]
+    BeforeEnhance {
+        args: BeforeEnhanceArgs,
+        data: TranscriptData,
+    },
+    #[serde(rename = "afterEnhance")]
+    #[specta(rename = "afterEnhance")]
+    AfterEnhance {
+        args: AfterEnhanceArgs,
+        data: NoteData,
+    },
+}
+
+impl TransformEvent {
+    pub fn condition_key(&self) -> &'static str {
+        match self {
+            TransformEvent::BeforeEnhance { .. } => "beforeEnhance",
+            TransformEvent::AfterEnhance { .. } => "afterEnhance",
+        }
+    }
+}
+
+/// Arguments passed to transform hooks run on the transcript before it is sent for enhancement.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct BeforeEnhanceArgs {
+    /// ID of the session being enhanced.
+    pub session_id: String,
+}
+
+impl HookArgs for BeforeEnhanceArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        vec![
+            OsString::from(cli_flag(stringify!(session_id))),
+            OsString::from(&self.session_id),
+        ]
+    }
+}
+
+/// Arguments passed to transform hooks run on a freshly generated enhanced note.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct AfterEnhanceArgs {
+    /// ID of the enhanced session.
+    pub session_id: String,
+    /// ID of the generated note.
+    pub note_id: String,
+    /// Optional template the note was generated from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+}
+
+impl HookArgs for AfterEnhanceArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = vec![
+            OsString::from(cli_flag(stringify!(session_id))),
+            OsString::from(&self.session_id),
+            OsString::from(cli_flag(stringify!(note_id))),
+            OsString::from(&self.note_id),
+        ];
+        if let Some(template_id) = &self.template_id {
+            args.push(OsString::from(cli_flag(stringify!(template_id))));
+            args.push(OsString::from(template_id));
+        }
+        args
+    }
+}
+
+/// Transcript as it is handed to the enhancement prompt.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(deny_unknown_fields)]
+pub struct TranscriptData {
+    pub segments: Vec<TranscriptSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(deny_unknown_fields)]
+pub struct TranscriptSegment {
+    pub speaker: String,
+    pub text: String,
+    /// Milliseconds from the start of the session, when the transcript has timing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_ms: Option<i64>,
+}
+
+/// Markdown of a generated note.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(deny_unknown_fields)]
+pub struct NoteData {
+    pub markdown: String,
+}
+
+/// Data a transform hook may replace. Whatever a hook prints must
+/// deserialize into the same type and pass [`TransformData::validate`]
+/// against the data the hook was handed.
+pub(crate) trait TransformData: Serialize + DeserializeOwned {
+    fn validate(&self, input: &Self) -> Result<(), String>;
+}
+
+/// Hooks may rewrite words, e.g. to redact them, but not add, drop or
+/// reorder them, so the transcript still lines up with the recording.
+impl TransformData for TranscriptData {
+    fn validate(&self, input: &Self) -> Result<(), String> {
+        if self.segments.len() != input.segments.len() {
+            return Err(format!(
+                "expected {} segments, got {}",
+                input.segments.len(),
+                self.segments.len()
+            ));
+        }
+
+        for (i, (segment, original)) in self.segments.iter().zip(&input.segments).enumerate() {
+            let words = segment.text.split_whitespace().count();
+            let expected = original.text.split_whitespace().count();
+            if words != expected {
+                return Err(format!(
+                    "segment {i} has {words} words instead of {expected}"
+                ));
+            }
+        }
+
+        let range = input
+            .segments
+            .iter()
+            .filter_map(|s| Some((s.start_ms?, s.end_ms?)))
+            .reduce(|(start, end), (s, e)| (start.min(s), end.max(e)));
+        let mut previous_start = i64::MIN;
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            let (start, end) = match (segment.start_ms, segment.end_ms) {
+                (Some(start), Some(end)) => (start, end),
+                (None, None) => continue,
+                _ => return Err(format!("segment {i} has only one of start_ms and end_ms")),
+            };
+            let Some((min, max)) = range else {
+                return Err(format!("segment {i} has timestamps the input did not"));
+            };
+
+            if start > end {
+                return Err(format!("segment {i} ends before it starts"));
+            }
+            if start < previous_start {
+                return Err(format!("segment {i} starts before the one before it"));
+            }
+            if start < min || end > max {
+                return Err(format!(
+                    "segment {i} is outside the transcript's {min}..{max} ms"
+                ));
+            }
+            previous_start = start;
+        }
+
+        Ok(())
+    }
+}
+
+impl TransformData for NoteData {
+    fn validate(&self, _input: &Self) -> Result<(), String> {
+        if self.markdown.trim().is_empty() {
+            return Err("markdown must not be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct TransformHookResult {
+    #[serde(flatten)]
+    pub result: HookResult,
+    /// Whether the hook's output replaced the data.
+    pub applied: bool,
+    /// Why the output was rejected, if it was.
+    pub rejected: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct TransformOutcome {
+    /// The event carrying the data left at the end of the chain.
+    pub event: TransformEvent,
+    pub results: Vec<TransformHookResult>,
+}
+
+/// Transform hooks of an event, in chain order. Ties keep config order.
+pub(crate) fn transform_chain(hooks: &[HookDefinition]) -> Vec<&HookDefinition> {
+    let mut chain: Vec<_> = hooks
+        .iter()
+        .filter(|hook| hook.kind == HookKind::Transform)
+        .collect();
+    chain.sort_by_key(|hook| hook.order);
+    chain
+}
+
+pub(crate) async fn run_transform_chain(
+    hooks: &[HookDefinition],
+    event: TransformEvent,
+) -> crate::Result<TransformOutcome> {
+    let chain = transform_chain(hooks);
+    let key = event.condition_key();
+
+    match event {
+        TransformEvent::BeforeEnhance { args, data } => {
+            let (data, results) = run_chain(&chain, key, &args, data).await?;
+            Ok(TransformOutcome {
+                event: TransformEvent::BeforeEnhance { args, data },
+                results,
+            })
+        }
+        TransformEvent::AfterEnhance { args, data } => {
+            let (data, results) = run_chain(&chain, key, &args, data).await?;
+            Ok(TransformOutcome {
+                event: TransformEvent::AfterEnhance { args, data },
+                results,
+            })
+        }
+    }
+}
+
+async fn run_chain<A, D>(
+    chain: &[&HookDefinition],
+    event: &str,
+    args: &A,
+    mut data: D,
+) -> crate::Result<(D, Vec<TransformHookResult>)>
+where
+    A: HookArgs + Serialize,
+    D: TransformData,
+{
+    let cli_args = args.to_cli_args();
+    let mut results = Vec::with_capacity(chain.len());
+
+    for hook in chain {
+        let payload = serde_json::to_vec(&serde_json::json!({
+            "event": event,
+            "args": args,
+            "data": &data,
+        }))
+        .unwrap_or_default();
+
+        let result = execute_hook(hook, event, &cli_args, &payload).await;
+        let output = if result.success {
+            parse_output(&result.stdout, &data)
+        } else {
+            Err(format!("hook exited with {:?}", result.exit_code))
+        };
+
+        let (applied, rejected) = match output {
+            Ok(Some(next)) => {
+                data = next;
+                (true, None)
+            }
+            Ok(None) => (false, None),
+            Err(reason) if hook.on_failure == TransformFailure::Abort => {
+                return Err(crate::Error::TransformAborted {
+                    command: hook.command.clone(),
+                    reason,
+                });
+            }
+            Err(reason) => (false, Some(reason)),
+        };
+
+        results.push(TransformHookResult {
+            result,
+            applied,
+            rejected,
+        });
+    }
+
+    Ok((data, results))
+}
+
+/// Blank output leaves the data as it is.
+fn parse_output<D: TransformData>(stdout: &str, input: &D) -> Result<Option<D>, String> {
+    if stdout.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let data: D =
+        serde_json::from_str(stdout).map_err(|e| format!("invalid transform output: {}", e))?;
+    data.validate(input)?;
+    Ok(Some(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(command: &str, order: i32) -> HookDefinition {
+        HookDefinition {
+            command: command.to_string(),
+            kind: HookKind::Transform,
+            order,
+            ..Default::default()
+        }
+    }
+
+    fn note(markdown: &str) -> TransformEvent {
+        TransformEvent::AfterEnhance {
+            args: AfterEnhanceArgs {
+                session_id: "s1".to_string(),
+                note_id: "n1".to_string(),
+                template_id: None,
+            },
+            data: NoteData {
+                markdown: markdown.to_string(),
+            },
+        }
+    }
+
+    fn markdown(outcome: &TransformOutcome) -> &str {
+        match &outcome.event {
+            TransformEvent::AfterEnhance { data, .. } => &data.markdown,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn chain_skips_notify_hooks_and_sorts_by_order() {
+        let hooks = [
+            transform("b", 1),
+            HookDefinition {
+                command: "notify".to_string(),
+                ..Default::default()
+            },
+            transform("a", -1),
+            transform("c", 1),
+        ];
+
+        let commands: Vec<_> = transform_chain(&hooks)
+            .iter()
+            .map(|hook| hook.command.as_str())
+            .collect();
+        assert_eq!(commands, ["a", "b", "c"]);
+    }
+
+    fn segment(text: &str, timing: Option<(i64, i64)>) -> TranscriptSegment {
+        TranscriptSegment {
+            speaker: "A".to_string(),
+            text: text.to_string(),
+            start_ms: timing.map(|(start, _)| start),
+            end_ms: timing.map(|(_, end)| end),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_output() {
+        let note = NoteData {
+            markdown: "original".to_string(),
+        };
+        assert!(parse_output("  \n", &note).unwrap().is_none());
+        assert!(parse_output(r#"{"markdown": "ok"}"#, &note).is_ok());
+        assert!(parse_output(r#"{"markdown": " "}"#, &note).is_err());
+        assert!(parse_output(r#"{"markdown": "ok", "extra": 1}"#, &note).is_err());
+
+        let transcript = TranscriptData {
+            segments: vec![segment("hi", None)],
+        };
+        assert!(parse_output(r#"{"segments": [{"text": "hi"}]}"#, &transcript).is_err());
+    }
+
+    #[test]
+    fn transcript_keeps_words_and_timing() {
+        let input = TranscriptData {
+            segments: vec![
+                segment("call John Smith", Some((0, 1_000))),
+                segment("at noon", Some((1_000, 2_000))),
+            ],
+        };
+        let check = |segments: Vec<TranscriptSegment>| TranscriptData { segments }.validate(&input);
+
+        assert!(
+            check(vec![
+                segment("call [NAME] [NAME]", Some((0, 1_000))),
+                segment("at noon", Some((1_000, 2_000))),
+            ])
+            .is_ok()
+        );
+        // Dropped segment, and a dropped word.
+        assert!(check(vec![segment("call John Smith", Some((0, 1_000)))]).is_err());
+        assert!(
+            check(vec![
+                segment("call [NAME]", Some((0, 1_000))),
+                segment("at noon", Some((1_000, 2_000))),
+            ])
+            .is_err()
+        );
+        // Reordered, and past the end of the transcript.
+        assert!(
+            check(vec![
+                segment("call John Smith", Some((1_000, 2_000))),
+                segment("at noon", Some((0, 1_000))),
+            ])
+            .is_err()
+        );
+        assert!(
+            check(vec![
+                segment("call John Smith", Some((0, 1_000))),
+                segment("at noon", Some((1_000, 5_000))),
+            ])
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn applies_outputs_in_chain_order() {
+        let hooks = [
+            transform(r#"sh -c 'printf "{\"markdown\": \"second\"}"'"#, 2),
+            transform(r#"sh -c 'printf "{\"markdown\": \"first\"}"'"#, 1),
+            // Passes the data through by printing nothing.
+            transform("true", 3),
+        ];
+
+        let outcome = run_transform_chain(&hooks, note("original")).await.unwrap();
+        assert_eq!(markdown(&outcome), "second");
+        let applied: Vec<_> = outcome.results.iter().map(|r| r.applied).collect();
+        assert_eq!(applied, [true, true, false]);
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn hooks_receive_the_previous_output() {
+        let hooks = [
+            transform(r#"sh -c 'printf "{\"markdown\": \"redacted\"}"'"#, 0),
+            // Only succeeds if it is handed the first hook's output.
+            transform(
+                r#"sh -c 'grep -q redacted && printf "{\"markdown\": \"seen\"}"'"#,
+                1,
+            ),
+        ];
+
+        let outcome = run_transform_chain(&hooks, note("secret")).await.unwrap();
+        assert_eq!(markdown(&outcome), "seen");
+        assert!(outcome.results[1].applied);
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn failures_skip_or_abort() {
+        let hooks = [transform("sh -c 'echo not json'", 0)];
+        let outcome = run_transform_chain(&hooks, note("original")).await.unwrap();
+        assert_eq!(markdown(&outcome), "original");
+        assert!(outcome.results[0].rejected.is_some());
+
+        let hooks = [HookDefinition {
+            on_failure: TransformFailure::Abort,
+            ..transform("false", 0)
+        }];
+        let err = run_transform_chain(&hooks, note("original"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::TransformAborted { .. }));
+    }
+}
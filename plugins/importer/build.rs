@@ -1,4 +1,9 @@
-const COMMANDS: &[&str] = &["list_available_sources", "run_import", "run_import_dry"];
+const COMMANDS: &[&str] = &[
+    "list_available_sources",
+    "run_import",
+    "run_import_dry",
+    "run_import_files",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -14,17 +14,25 @@ async listAvailableSources() : Promise<Result<ImportSourceInfo[], string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async runImport(source: ImportSourceKind, userId: string) : Promise<Result<ImportDataResult, string>> {
+async runImport(source: ImportSourceKind, userId: string, path: string | null) : Promise<Result<ImportDataResult, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import", { source, userId }) };
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import", { source, userId, path }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async runImportDry(source: ImportSourceKind) : Promise<Result<ImportStats, string>> {
+async runImportDry(source: ImportSourceKind, path: string | null) : Promise<Result<ImportStats, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_dry", { source }) };
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_dry", { source, path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async runImportFiles(paths: string[], userId: string, existingSessionIds: string[], existingHumans: ExistingHuman[]) : Promise<Result<ImportFilesResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_files", { paths, userId, existingSessionIds, existingHumans }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -42,12 +50,18 @@ async runImportDry(source: ImportSourceKind) : Promise<Result<ImportStats, strin
 
 /** user-defined types **/
 
+/**
+ * A person already in the app, so imported speakers with the same name link to them.
+ */
+export type ExistingHuman = { id: string; name: string }
 export type ImportDataResult = { stats: ImportStats; data: JsonValue }
+export type ImportFileError = { path: string; error: string }
+export type ImportFilesResult = { stats: ImportStats; data: JsonValue; errors: ImportFileError[] }
 export type ImportSourceInfo = { kind: ImportSourceKind | null; transform: TransformKind; name: string; path: string; revealPath: string }
-export type ImportSourceKind = "granola" | "hyprnote_v0_stable" | "hyprnote_v0_nightly" | "as_is"
-export type ImportStats = { sessionsCount: number; transcriptsCount: number; humansCount: number; organizationsCount: number; participantsCount: number; templatesCount: number; enhancedNotesCount: number }
+export type ImportSourceKind = "granola" | "hyprnote_v0_stable" | "hyprnote_v0_nightly" | "apple_notes" | "as_is"
+export type ImportStats = { sessionsCount: number; transcriptsCount: number; humansCount: number; organizationsCount: number; participantsCount: number; templatesCount: number; enhancedNotesCount: number; skippedCount: number }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
-export type TransformKind = "hyprnote_v0" | "granola" | "as_is"
+export type TransformKind = "hyprnote_v0" | "granola" | "apple_notes" | "as_is"
 
 /** tauri-specta globals **/
 
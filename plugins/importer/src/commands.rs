@@ -1,5 +1,8 @@
 use crate::ext::ImporterPluginExt;
-use crate::types::{ImportDataResult, ImportSourceInfo, ImportSourceKind, ImportStats};
+use crate::types::{
+    ExistingHuman, ImportDataResult, ImportFilesResult, ImportSourceInfo, ImportSourceKind,
+    ImportStats,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -16,9 +19,10 @@ pub async fn run_import<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     source: ImportSourceKind,
     user_id: String,
+    path: Option<String>,
 ) -> Result<ImportDataResult, String> {
     app.importer()
-        .run_import(source, user_id)
+        .run_import(source, user_id, path)
         .await
         .map_err(|e| e.to_string())
 }
@@ -28,9 +32,28 @@ pub async fn run_import<R: tauri::Runtime>(
 pub async fn run_import_dry<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     source: ImportSourceKind,
+    path: Option<String>,
 ) -> Result<ImportStats, String> {
     app.importer()
-        .run_import_dry(source)
+        .run_import_dry(source, path)
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_import_files<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    paths: Vec<String>,
+    user_id: String,
+    existing_session_ids: Vec<String>,
+    existing_humans: Vec<ExistingHuman>,
+) -> Result<ImportFilesResult, String> {
+    let importer = app.importer();
+    // Reading and parsing the files is blocking work.
+    tokio::task::spawn_blocking(move || {
+        importer.run_import_files(paths, user_id, existing_session_ids, existing_humans)
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
@@ -3,6 +3,12 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum Error {
+    #[error("apple notes error: {0}")]
+    AppleNote(#[from] hypr_apple_note::Error),
+
+    #[error("export archive error: {0}")]
+    Export(#[from] hypr_export::Error),
+
     #[error("granola error: {0}")]
     Granola(#[from] hypr_granola::error::Error),
 
@@ -12,6 +18,9 @@ pub enum Error {
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("transcript import error: {0}")]
+    TranscriptImport(#[from] hypr_transcript_import::Error),
+
     #[error("db parser error: {0}")]
     DbParser(#[from] hypr_db_parser::Error),
 
@@ -1,5 +1,6 @@
 use crate::types::{
-    ImportDataResult, ImportSource, ImportSourceInfo, ImportSourceKind, ImportStats,
+    ExistingHuman, ImportDataResult, ImportFileError, ImportFilesResult, ImportSource,
+    ImportSourceInfo, ImportSourceKind, ImportStats,
 };
 use hypr_importer_core::output::to_tinybase_json;
 
@@ -16,8 +17,9 @@ impl<R: tauri::Runtime> Importer<R> {
         &self,
         source_kind: ImportSourceKind,
         user_id: String,
+        path: Option<String>,
     ) -> Result<ImportDataResult, crate::Error> {
-        let source = ImportSource::from(source_kind.clone());
+        let source = ImportSource::from_kind(source_kind, path.map(Into::into));
         self.run_import_from_source(&source, user_id).await
     }
 
@@ -31,8 +33,8 @@ impl<R: tauri::Runtime> Importer<R> {
         }
 
         let data = crate::sources::import_all(source).await?;
-        let stats = ImportStats::from_data(&data);
-        let tinybase_json = to_tinybase_json(&data, &user_id);
+        let stats = ImportStats::from_source(&data);
+        let tinybase_json = to_tinybase_json(&data.collection, &user_id);
 
         Ok(ImportDataResult {
             stats,
@@ -43,8 +45,9 @@ impl<R: tauri::Runtime> Importer<R> {
     pub async fn run_import_dry(
         &self,
         source_kind: ImportSourceKind,
+        path: Option<String>,
     ) -> Result<ImportStats, crate::Error> {
-        let source = ImportSource::from(source_kind.clone());
+        let source = ImportSource::from_kind(source_kind, path.map(Into::into));
         self.run_import_dry_from_source(&source).await
     }
 
@@ -57,7 +60,46 @@ impl<R: tauri::Runtime> Importer<R> {
         }
 
         let data = crate::sources::import_all(source).await?;
-        Ok(ImportStats::from_data(&data))
+        Ok(ImportStats::from_source(&data))
+    }
+
+    /// Transcript exports from Otter.ai, Fireflies and similar tools, picked by
+    /// the user. Meetings already in `existing_session_ids` are left out, and
+    /// files that can't be read are reported instead of failing the import.
+    pub fn run_import_files(
+        &self,
+        paths: Vec<String>,
+        user_id: String,
+        existing_session_ids: Vec<String>,
+        existing_humans: Vec<ExistingHuman>,
+    ) -> ImportFilesResult {
+        let paths: Vec<std::path::PathBuf> = paths.into_iter().map(Into::into).collect();
+        let existing = existing_session_ids.into_iter().collect();
+        let known_humans: Vec<_> = existing_humans
+            .into_iter()
+            .map(|h| hypr_transcript_import::KnownHuman {
+                id: h.id,
+                name: h.name,
+            })
+            .collect();
+
+        let imported =
+            hypr_transcript_import::import_all_from_paths(&paths, &existing, &known_humans);
+        let stats = ImportStats::from_data(&imported.collection);
+        let tinybase_json = to_tinybase_json(&imported.collection, &user_id);
+
+        ImportFilesResult {
+            stats,
+            data: tinybase_json,
+            errors: imported
+                .errors
+                .into_iter()
+                .map(|e| ImportFileError {
+                    path: e.path.to_string_lossy().to_string(),
+                    error: e.error.to_string(),
+                })
+                .collect(),
+        }
     }
 }
 
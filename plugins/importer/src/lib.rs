@@ -20,6 +20,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_available_sources::<Wry>,
             commands::run_import::<Wry>,
             commands::run_import_dry::<Wry>,
+            commands::run_import_files::<Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -71,7 +72,7 @@ mod test {
         println!(
             "{:?}",
             importer
-                .run_import_dry(ImportSourceKind::HyprnoteV0Stable)
+                .run_import_dry(ImportSourceKind::HyprnoteV0Stable, None)
                 .await
                 .unwrap()
         );
@@ -79,7 +80,7 @@ mod test {
         println!(
             "{:?}",
             importer
-                .run_import_dry(ImportSourceKind::HyprnoteV0Nightly)
+                .run_import_dry(ImportSourceKind::HyprnoteV0Nightly, None)
                 .await
                 .unwrap()
         );
@@ -0,0 +1,10 @@
+use crate::types::SourceData;
+use std::path::Path;
+
+pub async fn import_all_from_path(path: &Path) -> Result<SourceData, crate::Error> {
+    let imported = hypr_apple_note::importer::import_all_from_path(path).await?;
+    Ok(SourceData {
+        collection: imported.collection,
+        skipped_count: imported.skipped,
+    })
+}
@@ -27,10 +27,14 @@ pub struct AsIsData {
     pub tag_mappings: Vec<TagMapping>,
 }
 
+/// Also reads archives written by the bulk exporter, as a directory or a zip.
 pub fn load_data(path: &Path) -> Result<Collection, crate::Error> {
     if !path.exists() {
         return Ok(Collection::default());
     }
+    if path.is_dir() || hypr_export::is_zip(path) {
+        return Ok(hypr_export::read_manifest(path)?.collection);
+    }
     let content = std::fs::read_to_string(path)?;
     let data: AsIsData = serde_json::from_str(&content)?;
     Ok(Collection {
@@ -1,16 +1,22 @@
+mod apple_notes;
 mod as_is;
 mod granola;
 mod hyprnote;
 
 pub use as_is::AsIsData;
 
-use crate::types::{Collection, ImportSource, ImportSourceInfo, TransformKind};
+use crate::types::{ImportSource, ImportSourceInfo, SourceData, TransformKind};
 
-pub async fn import_all(source: &ImportSource) -> Result<Collection, crate::Error> {
+pub async fn import_all(source: &ImportSource) -> Result<SourceData, crate::Error> {
     match source.transform {
-        TransformKind::HyprnoteV0 => hyprnote::v0::import_all_from_path(&source.path).await,
-        TransformKind::Granola => granola::import_all_from_path(&source.path).await,
-        TransformKind::AsIs => as_is::load_data(&source.path),
+        TransformKind::HyprnoteV0 => hyprnote::v0::import_all_from_path(&source.path)
+            .await
+            .map(Into::into),
+        TransformKind::Granola => granola::import_all_from_path(&source.path)
+            .await
+            .map(Into::into),
+        TransformKind::AppleNotes => apple_notes::import_all_from_path(&source.path).await,
+        TransformKind::AsIs => as_is::load_data(&source.path).map(Into::into),
     }
 }
 
@@ -18,6 +24,7 @@ pub fn all_sources() -> Vec<ImportSource> {
     [
         ImportSource::hyprnote_stable(),
         ImportSource::hyprnote_nightly(),
+        ImportSource::apple_notes(),
     ]
     .into_iter()
     .flatten()
@@ -11,6 +11,7 @@ pub use hypr_importer_core::ir::{
 pub enum TransformKind {
     HyprnoteV0,
     Granola,
+    AppleNotes,
     AsIs,
 }
 
@@ -20,6 +21,7 @@ pub enum ImportSourceKind {
     Granola,
     HyprnoteV0Stable,
     HyprnoteV0Nightly,
+    AppleNotes,
     AsIs,
 }
 
@@ -75,6 +77,41 @@ impl ImportSource {
         })
     }
 
+    pub fn apple_notes() -> Option<Self> {
+        let path = hypr_apple_note::importer::default_note_store_path();
+        Some(Self::apple_notes_at(path))
+    }
+
+    /// A `NoteStore.sqlite` picked by the user, e.g. copied over from a Mac.
+    pub fn apple_notes_at(path: PathBuf) -> Self {
+        Self {
+            kind: Some(ImportSourceKind::AppleNotes),
+            transform: TransformKind::AppleNotes,
+            path,
+            name: "Apple Notes".to_string(),
+        }
+    }
+
+    /// An archive written by the bulk exporter, as a directory or a zip.
+    pub fn as_is_at(path: PathBuf) -> Self {
+        Self {
+            kind: Some(ImportSourceKind::AsIs),
+            transform: TransformKind::AsIs,
+            path,
+            name: "Hyprnote export".to_string(),
+        }
+    }
+
+    /// `path` overrides where the source is read from, for sources that can live
+    /// anywhere. Other sources ignore it.
+    pub fn from_kind(kind: ImportSourceKind, path: Option<PathBuf>) -> Self {
+        match (kind, path) {
+            (ImportSourceKind::AppleNotes, Some(path)) => Self::apple_notes_at(path),
+            (ImportSourceKind::AsIs, Some(path)) => Self::as_is_at(path),
+            (kind, _) => Self::from(kind),
+        }
+    }
+
     pub fn is_available(&self) -> bool {
         self.path.exists()
     }
@@ -113,6 +150,7 @@ impl From<ImportSourceKind> for ImportSource {
             ImportSourceKind::HyprnoteV0Stable => Self::hyprnote_stable().unwrap(),
             ImportSourceKind::HyprnoteV0Nightly => Self::hyprnote_nightly().unwrap(),
             ImportSourceKind::Granola => Self::granola().unwrap(),
+            ImportSourceKind::AppleNotes => Self::apple_notes().unwrap(),
             ImportSourceKind::AsIs => Self {
                 kind: Some(ImportSourceKind::AsIs),
                 transform: TransformKind::AsIs,
@@ -143,9 +181,18 @@ pub struct ImportStats {
     pub participants_count: usize,
     pub templates_count: usize,
     pub enhanced_notes_count: usize,
+    /// Items the source has but that could not be read, e.g. corrupt notes.
+    pub skipped_count: usize,
 }
 
 impl ImportStats {
+    pub fn from_source(data: &SourceData) -> Self {
+        Self {
+            skipped_count: data.skipped_count,
+            ..Self::from_data(&data.collection)
+        }
+    }
+
     pub fn from_data(data: &Collection) -> Self {
         Self {
             sessions_count: data.sessions.len(),
@@ -155,6 +202,23 @@ impl ImportStats {
             participants_count: data.participants.len(),
             templates_count: data.templates.len(),
             enhanced_notes_count: data.enhanced_notes.len(),
+            skipped_count: 0,
+        }
+    }
+}
+
+/// What a source yielded when read.
+#[derive(Debug, Clone, Default)]
+pub struct SourceData {
+    pub collection: Collection,
+    pub skipped_count: usize,
+}
+
+impl From<Collection> for SourceData {
+    fn from(collection: Collection) -> Self {
+        Self {
+            collection,
+            skipped_count: 0,
         }
     }
 }
@@ -165,3 +229,24 @@ pub struct ImportDataResult {
     pub stats: ImportStats,
     pub data: serde_json::Value,
 }
+
+/// A person already in the app, so imported speakers with the same name link to them.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ExistingHuman {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ImportFileError {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFilesResult {
+    pub stats: ImportStats,
+    pub data: serde_json::Value,
+    pub errors: Vec<ImportFileError>,
+}
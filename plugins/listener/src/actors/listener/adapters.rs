@@ -6,7 +6,8 @@ use tauri_specta::Event;
 
 use owhisper_client::{
     AdapterKind, ArgmaxAdapter, AssemblyAIAdapter, DeepgramAdapter, ElevenLabsAdapter,
-    FireworksAdapter, GladiaAdapter, OpenAIAdapter, RealtimeSttAdapter, SonioxAdapter,
+    FireworksAdapter, GladiaAdapter, OpenAIAdapter, RealtimeSttAdapter, RevAiAdapter,
+    SonioxAdapter, SpeechmaticsAdapter,
 };
 use owhisper_interface::stream::Extra;
 use owhisper_interface::{ControlMessage, MixedMessage};
@@ -26,7 +27,7 @@ pub(super) async fn spawn_rx_task(
         ChannelSender,
         tokio::task::JoinHandle<()>,
         tokio::sync::oneshot::Sender<()>,
-        String,
+        AdapterKind,
     ),
     ActorProcessingErr,
 > {
@@ -83,18 +84,43 @@ pub(super) async fn spawn_rx_task(
         (AdapterKind::ElevenLabs, true) => {
             spawn_rx_task_dual_with_adapter::<ElevenLabsAdapter>(args, myself).await
         }
+        (AdapterKind::Speechmatics, false) => {
+            spawn_rx_task_single_with_adapter::<SpeechmaticsAdapter>(args, myself).await
+        }
+        (AdapterKind::Speechmatics, true) => {
+            spawn_rx_task_dual_with_adapter::<SpeechmaticsAdapter>(args, myself).await
+        }
+        (AdapterKind::RevAi, false) => {
+            spawn_rx_task_single_with_adapter::<RevAiAdapter>(args, myself).await
+        }
+        (AdapterKind::RevAi, true) => {
+            spawn_rx_task_dual_with_adapter::<RevAiAdapter>(args, myself).await
+        }
     }?;
 
-    Ok((result.0, result.1, result.2, adapter_kind.to_string()))
+    Ok((result.0, result.1, result.2, adapter_kind))
 }
 
 fn build_listen_params(args: &ListenerArgs) -> owhisper_interface::ListenParams {
+    // The proxy picks the upstream provider, so it gets the whole list and
+    // trims it for that provider itself.
+    let keywords = if owhisper_client::is_hyprnote_proxy(&args.base_url) {
+        let terms = args.vocabulary.terms();
+        terms.iter().map(|term| term.text.clone()).collect()
+    } else {
+        AdapterKind::from_url_and_languages(&args.base_url, &args.languages, Some(&args.model))
+            .boost_terms(Some(&args.model), &args.vocabulary)
+    };
     let redemption_time_ms = if args.onboarding { "60" } else { "400" };
     owhisper_interface::ListenParams {
         model: Some(args.model.clone()),
         languages: args.languages.clone(),
         sample_rate: super::super::SAMPLE_RATE,
-        keywords: args.keywords.clone(),
+        keywords,
+        translation: args
+            .translation_language
+            .clone()
+            .map(|target_language| owhisper_interface::TranslationParams { target_language }),
         custom_query: Some(std::collections::HashMap::from([(
             "redemption_time_ms".to_string(),
             redemption_time_ms.to_string(),
@@ -141,6 +167,8 @@ async fn spawn_rx_task_single_with_adapter<A: RealtimeSttAdapter>(
         .api_key(args.api_key.clone())
         .params(build_listen_params(&args))
         .extra_header(DEVICE_FINGERPRINT_HEADER, hypr_host::fingerprint())
+        .reconnect(owhisper_client::ReconnectConfig::default())
+        .audio_encoding(owhisper_client::AudioEncoding::Opus)
         .build_single()
         .await;
 
@@ -213,6 +241,8 @@ async fn spawn_rx_task_dual_with_adapter<A: RealtimeSttAdapter>(
         .api_key(args.api_key.clone())
         .params(build_listen_params(&args))
         .extra_header(DEVICE_FINGERPRINT_HEADER, hypr_host::fingerprint())
+        .reconnect(owhisper_client::ReconnectConfig::default())
+        .audio_encoding(owhisper_client::AudioEncoding::Opus)
         .build_dual()
         .await;
 
@@ -38,7 +38,9 @@ pub struct ListenerArgs {
     pub model: String,
     pub base_url: String,
     pub api_key: String,
-    pub keywords: Vec<String>,
+    pub vocabulary: hypr_vocabulary::Vocabulary,
+    pub translation_language: Option<hypr_language::Language>,
+    pub transcript_formatting: bool,
     pub mode: crate::actors::ChannelMode,
     pub session_started_at: Instant,
     pub session_started_at_unix: SystemTime,
@@ -48,6 +50,8 @@ pub struct ListenerArgs {
 pub struct ListenerState {
     pub args: ListenerArgs,
     tx: ChannelSender,
+    formatter: Option<owhisper_client::TranscriptFormatter>,
+    corrector: Option<owhisper_client::VocabularyCorrector>,
     rx_task: tokio::task::JoinHandle<()>,
     shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
 }
@@ -103,21 +107,30 @@ impl Actor for ListenerActor {
                 tracing::error!(?error, "failed_to_emit_connecting");
             }
 
-            let (tx, rx_task, shutdown_tx, adapter_name) =
+            let (tx, rx_task, shutdown_tx, adapter_kind) =
                 spawn_rx_task(args.clone(), myself).await?;
 
             if let Err(error) = (SessionProgressEvent::Connected {
                 session_id: session_id.clone(),
-                adapter: adapter_name,
+                adapter: adapter_kind.to_string(),
             })
             .emit(&args.app)
             {
                 tracing::error!(?error, "failed_to_emit_connected");
             }
 
+            let formatter = if args.transcript_formatting {
+                adapter_kind.transcript_formatter(Some(&args.model), &args.languages)
+            } else {
+                None
+            };
+            let corrector = adapter_kind.vocabulary_corrector(Some(&args.model), &args.vocabulary);
+
             let state = ListenerState {
                 args,
                 tx,
+                formatter,
+                corrector,
                 rx_task,
                 shutdown_tx: Some(shutdown_tx),
             };
@@ -191,6 +204,15 @@ impl Actor for ListenerActor {
                     return Ok(());
                 }
 
+                // Format first so corrections match against the same
+                // casing and spelling cloud providers return.
+                if let Some(formatter) = &state.formatter {
+                    formatter.format(&mut response);
+                }
+                if let Some(corrector) = &state.corrector {
+                    corrector.correct(&mut response);
+                }
+
                 match state.args.mode {
                     crate::actors::ChannelMode::MicOnly => {
                         response.remap_channel_index(0, 2);
@@ -6,6 +6,7 @@ use ractor::{Actor, ActorCell, ActorProcessingErr};
 use ractor_supervisor::SupervisorStrategy;
 use ractor_supervisor::core::{ChildBackoffFn, ChildSpec, Restart, SpawnFn};
 use ractor_supervisor::supervisor::{Supervisor, SupervisorArguments, SupervisorOptions};
+use tauri_plugin_settings::SettingsPluginExt;
 
 use crate::actors::{
     ChannelMode, ListenerActor, ListenerArgs, RecArgs, RecorderActor, SourceActor, SourceArgs,
@@ -23,6 +24,18 @@ pub struct SessionParams {
     pub base_url: String,
     pub api_key: String,
     pub keywords: Vec<String>,
+    #[serde(default)]
+    pub translation_language: Option<hypr_language::Language>,
+    #[serde(default = "default_transcript_formatting")]
+    pub transcript_formatting: bool,
+    #[serde(default)]
+    pub participants: Vec<String>,
+    #[serde(default)]
+    pub event_title: Option<String>,
+}
+
+fn default_transcript_formatting() -> bool {
+    true
 }
 
 #[derive(Clone)]
@@ -57,6 +70,30 @@ fn make_listener_backoff() -> ChildBackoffFn {
     })
 }
 
+// The user's term list from settings, plus what we know about this meeting.
+async fn load_vocabulary(ctx: &SessionContext) -> hypr_vocabulary::Vocabulary {
+    let mut vocabulary = match ctx.app.settings().load().await {
+        Ok(settings) => {
+            hypr_vocabulary::Vocabulary::from_settings(&settings).unwrap_or_else(|error| {
+                tracing::warn!(?error, "invalid_vocabulary_settings");
+                Default::default()
+            })
+        }
+        Err(error) => {
+            tracing::warn!(?error, "failed_to_load_settings");
+            Default::default()
+        }
+    };
+
+    vocabulary.extend_participants(&ctx.params.participants);
+    if let Some(title) = &ctx.params.event_title {
+        vocabulary.extend_event_title(title);
+    }
+    vocabulary.extend_keywords(&ctx.params.keywords);
+
+    vocabulary
+}
+
 pub async fn spawn_session_supervisor(
     ctx: SessionContext,
 ) -> Result<(ActorCell, tokio::task::JoinHandle<()>), ActorProcessingErr> {
@@ -98,6 +135,7 @@ pub async fn spawn_session_supervisor(
             let ctx = ctx_listener.clone();
             async move {
                 let mode = ChannelMode::determine(ctx.params.onboarding);
+                let vocabulary = load_vocabulary(&ctx).await;
 
                 let (actor_ref, _) = Actor::spawn_linked(
                     Some(ListenerActor::name()),
@@ -109,7 +147,9 @@ pub async fn spawn_session_supervisor(
                         model: ctx.params.model.clone(),
                         base_url: ctx.params.base_url.clone(),
                         api_key: ctx.params.api_key.clone(),
-                        keywords: ctx.params.keywords.clone(),
+                        vocabulary,
+                        translation_language: ctx.params.translation_language.clone(),
+                        transcript_formatting: ctx.params.transcript_formatting,
                         mode,
                         session_started_at: ctx.started_at_instant,
                         session_started_at_unix: ctx.started_at_system,
@@ -1,7 +1,7 @@
 use owhisper_client::AdapterKind;
 use std::str::FromStr;
 
-use crate::{ListenerPluginExt, actors::SessionParams};
+use crate::{ListenerPluginExt, ProviderCapabilities, actors::SessionParams};
 
 #[tauri::command]
 #[specta::specta]
@@ -110,6 +110,8 @@ pub async fn suggest_providers_for_languages_live<R: tauri::Runtime>(
         AdapterKind::OpenAI,
         AdapterKind::Gladia,
         AdapterKind::ElevenLabs,
+        AdapterKind::Speechmatics,
+        AdapterKind::RevAi,
     ];
 
     let mut with_support: Vec<_> = all_providers
@@ -138,3 +140,21 @@ pub async fn list_documented_language_codes_live<R: tauri::Runtime>(
 ) -> Result<Vec<String>, String> {
     Ok(owhisper_client::documented_language_codes_live())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_provider_capabilities<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    provider: String,
+    model: Option<String>,
+) -> Result<Option<ProviderCapabilities>, String> {
+    // Routed providers depend on the request, so there is nothing fixed to report.
+    if provider == "custom" || provider == "hyprnote" {
+        return Ok(None);
+    }
+
+    let adapter_kind =
+        AdapterKind::from_str(&provider).map_err(|_| format!("unknown_provider: {}", provider))?;
+
+    Ok(Some(adapter_kind.capabilities(model.as_deref()).into()))
+}
@@ -24,6 +24,37 @@ pub enum State {
     Finalizing,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, specta::Type, serde::Serialize, serde::Deserialize)]
+pub struct ProviderCapabilities {
+    pub diarization: bool,
+    pub native_multichannel: bool,
+    pub keyword_boosting: bool,
+    pub max_keywords: Option<u32>,
+    pub punctuation: bool,
+    pub word_timestamps: bool,
+    pub translation: bool,
+    pub max_session_secs: Option<u32>,
+    pub sample_rates: Vec<u32>,
+    pub encodings: Vec<String>,
+}
+
+impl From<owhisper_client::Capabilities> for ProviderCapabilities {
+    fn from(caps: owhisper_client::Capabilities) -> Self {
+        Self {
+            diarization: caps.diarization,
+            native_multichannel: caps.native_multichannel,
+            keyword_boosting: caps.keyword_boosting,
+            max_keywords: caps.max_keywords.map(|n| n as u32),
+            punctuation: caps.punctuation,
+            word_timestamps: caps.word_timestamps,
+            translation: caps.translation,
+            max_session_secs: caps.max_session_secs.map(|s| s as u32),
+            sample_rates: caps.sample_rates.to_vec(),
+            encodings: caps.encodings.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+}
+
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
@@ -38,6 +69,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::is_supported_languages_live::<tauri::Wry>,
             commands::suggest_providers_for_languages_live::<tauri::Wry>,
             commands::list_documented_language_codes_live::<tauri::Wry>,
+            commands::get_provider_capabilities::<tauri::Wry>,
         ])
         .events(tauri_specta::collect_events![
             SessionLifecycleEvent,
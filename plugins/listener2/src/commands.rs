@@ -78,6 +78,8 @@ pub async fn suggest_providers_for_languages_batch<R: tauri::Runtime>(
         AdapterKind::OpenAI,
         AdapterKind::Gladia,
         AdapterKind::ElevenLabs,
+        AdapterKind::Speechmatics,
+        AdapterKind::RevAi,
     ];
 
     let mut with_support: Vec<_> = all_providers
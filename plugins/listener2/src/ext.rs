@@ -1,11 +1,13 @@
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
-use owhisper_client::BatchSttAdapter;
+use owhisper_client::{AsyncBatchSttAdapter, BatchSttAdapter};
 use tauri_specta::Event;
 use tracing::Instrument;
 
 use crate::BatchEvent;
 use crate::batch::{BatchArgs, spawn_batch_actor};
+use crate::large_file;
 
 /// Creates a tracing span with session context that child events will inherit
 fn session_span(session_id: &str) -> tracing::Span {
@@ -34,6 +36,15 @@ pub struct BatchParams {
     pub languages: Vec<hypr_language::Language>,
     #[serde(default)]
     pub keywords: Vec<String>,
+    #[serde(default)]
+    pub upload: Option<UploadParams>,
+}
+
+/// Storage for files too large to send in one request. Without it, every
+/// file is sent directly. Requests are signed with the signed-in session.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct UploadParams {
+    pub api_base: String,
 }
 
 pub struct Listener2<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
@@ -63,6 +74,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener2<'a, R, M> {
             languages: params.languages.clone(),
             keywords: params.keywords.clone(),
             custom_query: None,
+            translation: None,
         };
 
         let state = self.manager.state::<crate::SharedState>();
@@ -81,11 +93,15 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener2<'a, R, M> {
                 .await
             }
             BatchProvider::Soniox => {
-                run_batch_with_adapter::<owhisper_client::SonioxAdapter>(app, params, listen_params)
-                    .await
+                run_batch_with_async_adapter::<owhisper_client::SonioxAdapter>(
+                    app,
+                    params,
+                    listen_params,
+                )
+                .await
             }
             BatchProvider::AssemblyAI => {
-                run_batch_with_adapter::<owhisper_client::AssemblyAIAdapter>(
+                run_batch_with_async_adapter::<owhisper_client::AssemblyAIAdapter>(
                     app,
                     params,
                     listen_params,
@@ -205,6 +221,67 @@ async fn run_batch_with_adapter<A: BatchSttAdapter>(
     .await
 }
 
+// Large files go through storage and an async job. An interrupted job for
+// the same file is resumed instead of starting over.
+async fn run_batch_with_async_adapter<A: AsyncBatchSttAdapter>(
+    app: tauri::AppHandle,
+    params: BatchParams,
+    listen_params: owhisper_interface::ListenParams,
+) -> Result<(), crate::Error> {
+    let Some(upload) = params.upload.clone() else {
+        return run_batch_with_adapter::<A>(app, params, listen_params).await;
+    };
+
+    let file_path = PathBuf::from(&params.file_path);
+    let job_path = large_file::job_path(&app, &params.session_id)?;
+    let job = large_file::load_job(&job_path, &file_path);
+    if !large_file::should_use(job.as_ref(), &file_path) {
+        return run_batch_with_adapter::<A>(app, params, listen_params).await;
+    }
+
+    let span = session_span(&params.session_id);
+
+    async {
+        BatchEvent::BatchStarted {
+            session_id: params.session_id.clone(),
+        }
+        .emit(&app)
+        .map_err(|e| {
+            crate::Error::BatchStartFailed(format!("failed to emit BatchStarted event: {e}"))
+        })?;
+
+        let client = owhisper_client::LargeFileClient::<A, _>::new(
+            params.base_url.clone(),
+            params.api_key.clone(),
+            listen_params,
+            large_file::upload_target(&app, upload.api_base),
+        );
+        let file_name = file_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "audio".to_string());
+        let key = format!("batch/{}/{}", params.session_id, file_name);
+
+        tracing::debug!("transcribing large file: {}", params.file_path);
+        let response = large_file::run(client, job, &job_path, &file_path, key).await?;
+
+        tracing::info!("large file transcription completed");
+
+        BatchEvent::BatchResponse {
+            session_id: params.session_id.clone(),
+            response,
+        }
+        .emit(&app)
+        .map_err(|e| {
+            crate::Error::BatchStartFailed(format!("failed to emit BatchResponse event: {e}"))
+        })?;
+
+        Ok(())
+    }
+    .instrument(span)
+    .await
+}
+
 async fn run_batch_am(
     app: tauri::AppHandle,
     params: BatchParams,
@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+
+use owhisper_client::{AsyncBatchSttAdapter, LargeFileClient, LargeFileJob, RemoteUploadTarget};
+use owhisper_interface::batch::Response as BatchResponse;
+use tauri_plugin_auth::AuthPluginExt;
+use tauri_plugin_fs_sync::find_session_dir;
+use tauri_plugin_settings::SettingsPluginExt;
+
+// Past this size a single request is likely to time out or hit a body limit.
+const LARGE_FILE_BYTES: u64 = 100 * 1024 * 1024;
+const JOB_FILE: &str = "large_file_job.json";
+
+/// Where the in-progress job for `session_id` is kept, next to its other
+/// session files.
+pub fn job_path<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    session_id: &str,
+) -> Result<PathBuf, crate::Error> {
+    let base = app
+        .settings()
+        .cached_vault_base()
+        .map_err(|e| crate::Error::BatchStartFailed(e.to_string()))?;
+    Ok(find_session_dir(&base.join("sessions"), session_id).join(JOB_FILE))
+}
+
+/// The proxy's upload storage, signed with the session's current access
+/// token on every request rather than the one the run started with.
+pub fn upload_target<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    api_base: String,
+) -> RemoteUploadTarget {
+    let app = app.clone();
+    RemoteUploadTarget::new(api_base, move || {
+        app.access_token()
+            .map_err(|e| owhisper_client::Error::Auth(e.to_string()))?
+            .ok_or_else(|| owhisper_client::Error::Auth("not signed in".to_string()))
+    })
+}
+
+/// The job left behind by an interrupted run on the same file, if any.
+pub fn load_job(job_path: &Path, file_path: &Path) -> Option<LargeFileJob> {
+    let content = std::fs::read(job_path).ok()?;
+    match serde_json::from_slice::<LargeFileJob>(&content) {
+        Ok(job) if job.file_path == file_path => Some(job),
+        Ok(_) => None,
+        Err(error) => {
+            tracing::warn!(?error, "invalid_large_file_job");
+            None
+        }
+    }
+}
+
+/// Whether `file_path` goes through upload and an async job rather than a
+/// single request.
+pub fn should_use(job: Option<&LargeFileJob>, file_path: &Path) -> bool {
+    job.is_some()
+        || std::fs::metadata(file_path).is_ok_and(|metadata| metadata.len() >= LARGE_FILE_BYTES)
+}
+
+fn save_job(job_path: &Path, job: &LargeFileJob) {
+    let result = serde_json::to_vec(job)
+        .map_err(std::io::Error::other)
+        .and_then(|content| {
+            if let Some(parent) = job_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(job_path, content)
+        });
+
+    if let Err(error) = result {
+        tracing::warn!(?error, "failed_to_save_large_file_job");
+    }
+}
+
+/// Uploads and transcribes the file, resuming `job` when there is one. The
+/// job is saved after every step and removed once the transcript is back,
+/// so a failed or interrupted run picks up where it stopped next time.
+pub async fn run<A: AsyncBatchSttAdapter>(
+    client: LargeFileClient<A, RemoteUploadTarget>,
+    job: Option<LargeFileJob>,
+    job_path: &Path,
+    file_path: &Path,
+    key: String,
+) -> Result<BatchResponse, crate::Error> {
+    let mut job = match job {
+        Some(job) => {
+            tracing::info!(job_id = ?job.job_id, "resuming_large_file_job");
+            job
+        }
+        None => {
+            let job = client.start(file_path, key).await?;
+            save_job(job_path, &job);
+            job
+        }
+    };
+
+    let response = client
+        .resume(&mut job, |job| save_job(job_path, job))
+        .await?;
+
+    if let Err(error) = std::fs::remove_file(job_path) {
+        tracing::warn!(?error, "failed_to_remove_large_file_job");
+    }
+
+    Ok(response)
+}
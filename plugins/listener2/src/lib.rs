@@ -7,6 +7,7 @@ mod commands;
 mod error;
 mod events;
 mod ext;
+mod large_file;
 mod subtitle;
 
 pub use error::{Error, Result};
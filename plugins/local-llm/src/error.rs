@@ -11,6 +11,8 @@ pub enum Error {
     #[error(transparent)]
     HyprLlamaError(#[from] hypr_llama::Error),
     #[error(transparent)]
+    HyprGbnfError(#[from] hypr_gbnf::Error),
+    #[error(transparent)]
     HyprFileError(#[from] hypr_file::Error),
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -206,6 +206,7 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
 
         let model_manager = crate::ModelManager::builder()
             .model_path(model_path)
+            .embedding_model_path(self.models_dir().join(crate::EMBEDDING_MODEL_FILE_NAME))
             .build();
         let state = self.state::<crate::SharedState>();
 
@@ -1,3 +1,6 @@
+/// Any GGUF embedding model placed in the models directory under this name is served on `/v1/embeddings`.
+pub const EMBEDDING_MODEL_FILE_NAME: &str = "embedding.gguf";
+
 pub static SUPPORTED_MODELS: &[SupportedModel] = &[
     SupportedModel::Llama3p2_3bQ4,
     SupportedModel::HyprLLM,
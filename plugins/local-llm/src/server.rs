@@ -6,7 +6,8 @@ use async_openai::types::{
     ChatChoice, ChatChoiceStream, ChatCompletionMessageToolCallChunk,
     ChatCompletionResponseMessage, ChatCompletionStreamResponseDelta, ChatCompletionToolType,
     CreateChatCompletionRequest, CreateChatCompletionResponse, CreateChatCompletionStreamResponse,
-    FunctionCallStream, Role,
+    CreateEmbeddingRequest, CreateEmbeddingResponse, Embedding, EmbeddingInput, EmbeddingUsage,
+    EncodingFormat, FunctionCallStream, ListModelResponse, Model, ResponseFormat, Role,
 };
 use axum::{
     Router,
@@ -81,6 +82,13 @@ pub async fn run_server(state: ServerState) -> Result<ServerHandle, crate::Error
         .route("/health", get(health))
         .route("/cancel", get(cancel))
         .route("/chat/completions", post(chat_completions))
+        // OpenAI-compatible paths, for clients configured with a `/v1` base URL.
+        .route("/v1/models", get(models))
+        .route("/v1/chat/completions", post(chat_completions))
+        .route("/v1/embeddings", post(embeddings))
+        // llama.cpp server paths, so clients can budget prompts against the real tokenizer.
+        .route("/tokenize", post(tokenize))
+        .route("/props", get(props))
         .with_state(state)
         .layer(
             CorsLayer::new()
@@ -142,9 +150,158 @@ async fn chat_completions(
         provider.chat_completions(request, &state).await
     };
 
-    response
-        .map(|r| r.into_response())
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    response.map(|r| r.into_response()).map_err(|e| match e {
+        crate::Error::HyprGbnfError(_) => (StatusCode::BAD_REQUEST, e.to_string()),
+        _ => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    })
+}
+
+async fn models(AxumState(state): AxumState<ServerState>) -> Json<ListModelResponse> {
+    let manager = &state.model_manager;
+
+    let data = std::iter::once(manager.model_path())
+        .chain(manager.embedding_model_path())
+        .filter(|path| path.is_file())
+        .map(|path| Model {
+            id: model_id(path),
+            object: "model".to_string(),
+            created: std::fs::metadata(path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs() as u32)
+                .unwrap_or_default(),
+            owned_by: "hyprnote".to_string(),
+        })
+        .collect();
+
+    Json(ListModelResponse {
+        object: "list".to_string(),
+        data,
+    })
+}
+
+fn model_id(path: &std::path::Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+async fn embeddings(
+    AxumState(state): AxumState<ServerState>,
+    Json(request): Json<CreateEmbeddingRequest>,
+) -> Result<Json<CreateEmbeddingResponse>, (StatusCode, String)> {
+    let inputs = match request.input {
+        EmbeddingInput::String(input) => vec![input],
+        EmbeddingInput::StringArray(inputs) => inputs,
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "token array inputs are not supported".to_string(),
+            ));
+        }
+    };
+
+    if matches!(request.encoding_format, Some(EncodingFormat::Base64)) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "base64 encoding_format is not supported".to_string(),
+        ));
+    }
+
+    let model = state
+        .model_manager
+        .get_embedding_model()
+        .await
+        .map_err(|e| (StatusCode::SERVICE_UNAVAILABLE, e.to_string()))?;
+
+    if let Some(dimensions) = request.dimensions
+        && dimensions as usize != model.dimensions()
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("model only supports {} dimensions", model.dimensions()),
+        ));
+    }
+
+    let embeddings = tokio::task::spawn_blocking(move || model.embed(&inputs))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let data = embeddings
+        .data
+        .into_iter()
+        .enumerate()
+        .map(|(index, embedding)| Embedding {
+            index: index as u32,
+            object: "embedding".to_string(),
+            embedding,
+        })
+        .collect();
+
+    let prompt_tokens = embeddings.prompt_tokens as u32;
+
+    Ok(Json(CreateEmbeddingResponse {
+        object: "list".to_string(),
+        model: request.model,
+        data,
+        usage: EmbeddingUsage {
+            prompt_tokens,
+            total_tokens: prompt_tokens,
+        },
+    }))
+}
+
+#[derive(serde::Deserialize)]
+struct TokenizeRequest {
+    content: String,
+}
+
+async fn tokenize(
+    AxumState(state): AxumState<ServerState>,
+    Json(request): Json<TokenizeRequest>,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let model = state
+        .model_manager
+        .get_model()
+        .await
+        .map_err(|e| (StatusCode::SERVICE_UNAVAILABLE, e.to_string()))?;
+
+    let tokens = model
+        .tokenize(&request.content)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    Ok(Json(serde_json::json!({ "tokens": tokens })))
+}
+
+async fn props(
+    AxumState(state): AxumState<ServerState>,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let model = state
+        .model_manager
+        .get_model()
+        .await
+        .map_err(|e| (StatusCode::SERVICE_UNAVAILABLE, e.to_string()))?;
+
+    Ok(Json(serde_json::json!({
+        "default_generation_settings": { "n_ctx": model.max_input_tokens() }
+    })))
+}
+
+fn response_format_grammar(
+    format: Option<&ResponseFormat>,
+) -> Result<Option<String>, crate::Error> {
+    let schema = match format {
+        None | Some(ResponseFormat::Text) => return Ok(None),
+        Some(ResponseFormat::JsonObject) => serde_json::json!({ "type": "object" }),
+        Some(ResponseFormat::JsonSchema { json_schema }) => json_schema
+            .schema
+            .clone()
+            .unwrap_or(serde_json::Value::Bool(true)),
+    };
+
+    Ok(Some(hypr_gbnf::from_json_schema(&schema)?))
 }
 
 struct LocalProvider {
@@ -200,16 +357,20 @@ impl LocalProvider {
             .and_then(|v| v.get("grammar"))
             .and_then(|v| serde_json::from_value::<hypr_gbnf::Grammar>(v.clone()).ok());
 
-        let grammar = match maybe_grammar {
-            None => None,
-            Some(g) => {
+        let grammar = match (
+            response_format_grammar(request.response_format.as_ref())?,
+            maybe_grammar,
+        ) {
+            (Some(grammar), _) => Some(grammar),
+            (None, None) => None,
+            (None, Some(g)) => {
                 if model.name == hypr_llama::ModelName::HyprLLM {
                     match &g {
                         hypr_gbnf::Grammar::Enhance { sections: None } => None,
-                        _ => Some(g.build()),
+                        _ => Some(g.build()?),
                     }
                 } else {
-                    Some(g.build())
+                    Some(g.build()?)
                 }
             }
         };
@@ -13,8 +13,10 @@ export const commands = {
 
 
 export const events = __makeEvents__<{
+externalFileChanged: ExternalFileChanged,
 fileChanged: FileChanged
 }>({
+externalFileChanged: "plugin:notify:external-file-changed",
 fileChanged: "plugin:notify:file-changed"
 })
 
@@ -24,6 +26,7 @@ fileChanged: "plugin:notify:file-changed"
 
 /** user-defined types **/
 
+export type ExternalFileChanged = { root: string; path: string }
 export type FileChanged = { path: string }
 
 /** tauri-specta globals **/
@@ -13,3 +13,10 @@ common_event_derives! {
         pub path: String,
     }
 }
+
+common_event_derives! {
+    pub struct ExternalFileChanged {
+        pub root: String,
+        pub path: String,
+    }
+}
@@ -1,3 +1,4 @@
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use notify::RecursiveMode;
@@ -6,7 +7,7 @@ use tauri_plugin_settings::SettingsPluginExt;
 use tauri_specta::Event;
 
 use crate::path::{should_skip_path, to_relative_path};
-use crate::{FileChanged, WatcherState};
+use crate::{ExternalFileChanged, FileChanged, WatcherState};
 
 const DEBOUNCE_DELAY_MS: u64 = 900;
 const OWN_WRITES_TTL_MS: u128 = (DEBOUNCE_DELAY_MS as u128) * 2 + 200;
@@ -29,6 +30,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Notify<'a, R, M> {
         let app_handle = self.manager.app_handle().clone();
         let base_for_closure = base.clone();
         let own_writes = state.own_writes.clone();
+        let external_roots = state.external_roots.clone();
 
         let mut debouncer = new_debouncer(
             Duration::from_millis(DEBOUNCE_DELAY_MS),
@@ -37,6 +39,8 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Notify<'a, R, M> {
                 if let Ok(events) = events {
                     let mut changed_paths: std::collections::HashSet<String> =
                         std::collections::HashSet::new();
+                    let mut external_paths: std::collections::HashSet<(PathBuf, PathBuf)> =
+                        std::collections::HashSet::new();
 
                     for event in events {
                         let should_emit = match &event.kind {
@@ -60,6 +64,20 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Notify<'a, R, M> {
                         }
 
                         for path in &event.paths {
+                            let external_root = external_roots
+                                .lock()
+                                .unwrap()
+                                .iter()
+                                .find(|root| path.starts_with(root))
+                                .cloned();
+
+                            if let Some(root) = external_root {
+                                if !should_skip_path(&to_relative_path(path, &root), path) {
+                                    external_paths.insert((root, path.clone()));
+                                }
+                                continue;
+                            }
+
                             let relative_path = to_relative_path(path, &base_for_closure);
 
                             if should_skip_path(&relative_path, path) {
@@ -87,11 +105,32 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Notify<'a, R, M> {
                         tracing::info!("file_changed: {:?}", path);
                         let _ = FileChanged { path }.emit(&app_handle);
                     }
+
+                    for (root, path) in external_paths {
+                        let skip = {
+                            let own = own_writes.lock().unwrap();
+                            own.contains_key(path.to_string_lossy().as_ref())
+                        };
+                        if skip {
+                            continue;
+                        }
+                        tracing::info!("external_file_changed: {:?}", path);
+                        let _ = ExternalFileChanged {
+                            root: root.to_string_lossy().to_string(),
+                            path: to_relative_path(&path, &root),
+                        }
+                        .emit(&app_handle);
+                    }
                 }
             },
         )?;
 
         debouncer.watch(&base, RecursiveMode::Recursive)?;
+        for root in state.external_roots.lock().unwrap().iter() {
+            if let Err(e) = debouncer.watch(root, RecursiveMode::Recursive) {
+                tracing::warn!("failed_to_watch_external: {:?} {}", root, e);
+            }
+        }
         *guard = Some(debouncer);
 
         Ok(())
@@ -104,6 +143,40 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Notify<'a, R, M> {
         Ok(())
     }
 
+    /// Watches a directory outside the vault. Changes under it are emitted as
+    /// [`ExternalFileChanged`], with paths relative to `root`; own writes are
+    /// marked by absolute path.
+    pub fn watch_external(&self, root: &Path) -> Result<(), crate::Error> {
+        let state = self.manager.state::<WatcherState>();
+        if !state
+            .external_roots
+            .lock()
+            .unwrap()
+            .insert(root.to_path_buf())
+        {
+            return Ok(());
+        }
+
+        if let Some(debouncer) = state.debouncer.lock().unwrap().as_mut() {
+            debouncer.watch(root, RecursiveMode::Recursive)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn unwatch_external(&self, root: &Path) -> Result<(), crate::Error> {
+        let state = self.manager.state::<WatcherState>();
+        if !state.external_roots.lock().unwrap().remove(root) {
+            return Ok(());
+        }
+
+        if let Some(debouncer) = state.debouncer.lock().unwrap().as_mut() {
+            debouncer.unwatch(root)?;
+        }
+
+        Ok(())
+    }
+
     pub fn mark_own_writes(&self, paths: &[String]) {
         let state = self.manager.state::<WatcherState>();
         let mut guard = state.own_writes.lock().unwrap();
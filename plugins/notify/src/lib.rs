@@ -3,7 +3,8 @@ mod events;
 mod ext;
 mod path;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
@@ -20,12 +21,16 @@ const PLUGIN_NAME: &str = "notify";
 pub struct WatcherState {
     pub(crate) debouncer: Mutex<Option<Debouncer<RecommendedWatcher, RecommendedCache>>>,
     pub(crate) own_writes: Arc<Mutex<HashMap<String, Instant>>>,
+    pub(crate) external_roots: Arc<Mutex<HashSet<PathBuf>>>,
 }
 
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
-        .events(tauri_specta::collect_events![FileChanged,])
+        .events(tauri_specta::collect_events![
+            FileChanged,
+            ExternalFileChanged,
+        ])
 }
 
 pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
@@ -39,6 +44,7 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
             app.manage(WatcherState {
                 debouncer: Mutex::new(None),
                 own_writes: Arc::new(Mutex::new(HashMap::new())),
+                external_roots: Arc::new(Mutex::new(HashSet::new())),
             });
 
             Ok(())
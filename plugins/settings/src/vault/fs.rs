@@ -3,7 +3,14 @@ use std::path::Path;
 use super::path::VAULT_PATH_KEY;
 use crate::fs::copy_dir_recursive;
 
-const VAULT_DIRECTORIES: &[&str] = &["sessions", "humans", "organizations", "chats", "prompts"];
+const VAULT_DIRECTORIES: &[&str] = &[
+    "sessions",
+    "humans",
+    "organizations",
+    "chats",
+    "prompts",
+    "enhance_templates",
+];
 
 const VAULT_FILES: &[&str] = &[
     "settings.json",
@@ -1,4 +1,13 @@
-const COMMANDS: &[&str] = &["render", "render_custom"];
+const COMMANDS: &[&str] = &[
+    "render",
+    "render_custom",
+    "chunk_transcripts",
+    "list_user_templates",
+    "save_user_template",
+    "delete_user_template",
+    "preview_user_template",
+    "render_enhance",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
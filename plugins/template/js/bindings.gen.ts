@@ -21,6 +21,54 @@ async renderCustom(templateContent: string, ctx: Partial<{ [key in string]: Json
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async chunkTranscripts(transcripts: Transcript[], maxTokens: number) : Promise<Result<Transcript[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|chunk_transcripts", { transcripts, maxTokens }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listUserTemplates() : Promise<Result<UserTemplate[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|list_user_templates") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async saveUserTemplate(tpl: UserTemplate) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|save_user_template", { tpl }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteUserTemplate(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|delete_user_template", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async previewUserTemplate(tpl: UserTemplate, system: EnhanceSystem | null, user: EnhanceUser | null) : Promise<Result<RenderedPrompts, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|preview_user_template", { tpl, system, user }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async renderEnhance(templateId: string | null, system: EnhanceSystem, user: EnhanceUser) : Promise<Result<RenderedPrompts, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|render_enhance", { templateId, system, user }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -34,22 +82,47 @@ async renderCustom(templateContent: string, ctx: Partial<{ [key in string]: Json
 
 /** user-defined types **/
 
-export type ChatContext = { title: string | null; date: string | null; rawContent: string | null; enhancedContent: string | null; transcript: Transcript | null }
+export type ChatContext = { title: string | null; date: string | null; rawContent: string | null; enhancedContent: string | null; transcript: Transcript | null; previousMeeting: PreviousMeeting | null }
 export type ChatSystem = { language: string | null; context: ChatContext | null }
+export type EnhanceChunkSystem = { language: string | null }
+export type EnhanceChunkUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; chunk: Transcript; index: number; total: number }
+export type EnhanceMergeUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; summaries: string[]; previousMeeting: PreviousMeeting | null }
 export type EnhanceSystem = { language: string | null }
 export type EnhanceTemplate = { title: string; description: string | null; sections: TemplateSection[] }
-export type EnhanceUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; transcripts: Transcript[] }
+export type EnhanceUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; transcripts: Transcript[]; previousMeeting: PreviousMeeting | null }
 export type Event = { name: string }
-export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" } | { task: "email-to-name" }
+export type ExtractSystem = { language: string | null; currentDate: string | null }
+export type ExtractUser = { session: Session; participants: Participant[]; lines: TranscriptLine[] }
+export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" } | { task: "email-to-name" } | { task: "json-schema"; schema: JsonValue }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type Participant = { name: string; jobTitle: string | null }
+export type PreviousActionItem = { text: string; owner: string | null; done: boolean }
+/**
+ * The latest earlier meeting of the same recurring series.
+ */
+export type PreviousMeeting = { title: string | null; date: string | null; summary: string | null; actionItems: PreviousActionItem[] }
+export type RenderedPrompts = { system: string; user: string }
 export type Segment = { text: string; speaker: string }
 export type Session = { title: string | null; startedAt: string | null; endedAt: string | null; event: Event | null }
-export type Template = { enhanceSystem: EnhanceSystem } | { enhanceUser: EnhanceUser } | { titleSystem: TitleSystem } | { titleUser: TitleUser } | { chatSystem: ChatSystem }
+export type Template = { enhanceSystem: EnhanceSystem } | { enhanceUser: EnhanceUser } | { enhanceChunkSystem: EnhanceChunkSystem } | { enhanceChunkUser: EnhanceChunkUser } | { enhanceMergeUser: EnhanceMergeUser } | { extractSystem: ExtractSystem } | { extractUser: ExtractUser } | { titleSystem: TitleSystem } | { titleUser: TitleUser } | { chatSystem: ChatSystem }
 export type TemplateSection = { title: string; description: string | null }
 export type TitleSystem = { language: string | null }
 export type TitleUser = { enhancedNote: string }
 export type Transcript = { segments: Segment[]; startedAt: number | null; endedAt: number | null }
+export type TranscriptLine = { 
+/**
+ * Shown to the model as-is, and copied back into `source_timestamp`.
+ */
+timestamp: string | null; speaker: string; text: string }
+export type UserTemplate = { id: string; name: string; description: string | null; 
+/**
+ * Replaces `enhance.system.md.jinja` when set.
+ */
+system: string | null; 
+/**
+ * Replaces `enhance.user.md.jinja` when set.
+ */
+user: string | null }
 
 /** tauri-specta globals **/
 
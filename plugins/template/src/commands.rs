@@ -1,3 +1,5 @@
+use hypr_template_app::{EnhanceSystem, EnhanceUser, RenderedPrompts, UserTemplate};
+
 use crate::TemplatePluginExt;
 
 #[tauri::command]
@@ -18,3 +20,61 @@ pub async fn render_custom<R: tauri::Runtime>(
 ) -> Result<String, String> {
     app.template().render_custom(&template_content, ctx)
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn chunk_transcripts<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    transcripts: Vec<hypr_template_app::Transcript>,
+    max_tokens: u32,
+) -> Result<Vec<hypr_template_app::Transcript>, String> {
+    Ok(app.template().chunk_transcripts(&transcripts, max_tokens))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_user_templates<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<UserTemplate>, String> {
+    app.template().list_user_templates()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn save_user_template<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    tpl: UserTemplate,
+) -> Result<(), String> {
+    app.template().save_user_template(tpl)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_user_template<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.template().delete_user_template(&id)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_user_template<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    tpl: UserTemplate,
+    system: Option<EnhanceSystem>,
+    user: Option<EnhanceUser>,
+) -> Result<RenderedPrompts, String> {
+    app.template().preview_user_template(tpl, system, user)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn render_enhance<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    template_id: Option<String>,
+    system: EnhanceSystem,
+    user: EnhanceUser,
+) -> Result<RenderedPrompts, String> {
+    app.template().render_enhance(template_id, system, user)
+}
@@ -1,5 +1,9 @@
+use hypr_template_app::{EnhanceSystem, EnhanceUser, RenderedPrompts, UserTemplate};
+use tauri_plugin_settings::SettingsPluginExt;
+
+use crate::user_templates;
+
 pub struct Template<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
-    #[allow(dead_code)]
     manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
@@ -20,6 +24,78 @@ impl<R: tauri::Runtime, M: tauri::Manager<R>> Template<'_, R, M> {
             .map(|s| s.trim().to_string())
             .map_err(|e| e.to_string())
     }
+
+    #[tracing::instrument(skip_all)]
+    pub fn chunk_transcripts(
+        &self,
+        transcripts: &[hypr_template_app::Transcript],
+        max_tokens: u32,
+    ) -> Vec<hypr_template_app::Transcript> {
+        hypr_template_app::chunk_transcripts(
+            transcripts,
+            max_tokens as usize,
+            hypr_template_app::estimate_tokens,
+        )
+    }
+
+    fn vault_base(&self) -> Result<std::path::PathBuf, String> {
+        self.manager
+            .settings()
+            .cached_vault_base()
+            .map_err(|e| e.to_string())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn list_user_templates(&self) -> Result<Vec<UserTemplate>, String> {
+        user_templates::list(&self.vault_base()?)
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn save_user_template(&self, tpl: UserTemplate) -> Result<(), String> {
+        user_templates::save(&self.vault_base()?, &tpl)
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn delete_user_template(&self, id: &str) -> Result<(), String> {
+        user_templates::delete(&self.vault_base()?, id)
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn preview_user_template(
+        &self,
+        tpl: UserTemplate,
+        system: Option<EnhanceSystem>,
+        user: Option<EnhanceUser>,
+    ) -> Result<RenderedPrompts, String> {
+        tpl.preview(system, user).map_err(|e| e.to_string())
+    }
+
+    /// Renders the enhance prompts with the given user template, or the built-in ones.
+    #[tracing::instrument(skip_all)]
+    pub fn render_enhance(
+        &self,
+        template_id: Option<String>,
+        system: EnhanceSystem,
+        user: EnhanceUser,
+    ) -> Result<RenderedPrompts, String> {
+        let tpl = match template_id {
+            Some(id) => user_templates::get(&self.vault_base()?, &id)?,
+            None => None,
+        };
+
+        // Both halves fall back to the built-in templates when not overridden,
+        // as does a template that has since been deleted.
+        let tpl = tpl.unwrap_or_else(|| UserTemplate {
+            id: String::new(),
+            name: String::new(),
+            description: None,
+            system: None,
+            user: None,
+        });
+
+        tpl.preview(Some(system), Some(user))
+            .map_err(|e| e.to_string())
+    }
 }
 
 pub trait TemplatePluginExt<R: tauri::Runtime> {
@@ -2,6 +2,7 @@ use tauri::Wry;
 
 mod commands;
 mod ext;
+mod user_templates;
 
 pub use ext::TemplatePluginExt;
 pub use hypr_template_app_legacy::Template;
@@ -14,6 +15,12 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::render::<Wry>,
             commands::render_custom::<Wry>,
+            commands::chunk_transcripts::<Wry>,
+            commands::list_user_templates::<Wry>,
+            commands::save_user_template::<Wry>,
+            commands::delete_user_template::<Wry>,
+            commands::preview_user_template::<Wry>,
+            commands::render_enhance::<Wry>,
         ])
         .typ::<hypr_gbnf::Grammar>()
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
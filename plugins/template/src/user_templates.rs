@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+
+use hypr_template_app::UserTemplate;
+
+const DIR_NAME: &str = "enhance_templates";
+
+pub fn dir(vault_base: &Path) -> PathBuf {
+    vault_base.join(DIR_NAME)
+}
+
+fn template_path(vault_base: &Path, id: &str) -> Result<PathBuf, String> {
+    if id.is_empty() || id.contains(['/', '\\', '.']) {
+        return Err(format!("invalid template id: {id}"));
+    }
+    Ok(dir(vault_base).join(format!("{id}.json")))
+}
+
+pub fn list(vault_base: &Path) -> Result<Vec<UserTemplate>, String> {
+    let entries = match std::fs::read_dir(dir(vault_base)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let mut templates = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+        .filter_map(|path| match load(&path) {
+            Ok(tpl) => Some(tpl),
+            Err(e) => {
+                tracing::warn!("invalid_user_template: {:?} {}", path, e);
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(templates)
+}
+
+pub fn get(vault_base: &Path, id: &str) -> Result<Option<UserTemplate>, String> {
+    let path = template_path(vault_base, id)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    load(&path).map(Some)
+}
+
+// Files can be dropped into the folder by hand, so they get the same checks as `save`.
+fn load(path: &Path) -> Result<UserTemplate, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let tpl = serde_json::from_str::<UserTemplate>(&content).map_err(|e| e.to_string())?;
+
+    if path.file_stem().and_then(|s| s.to_str()) != Some(tpl.id.as_str()) {
+        return Err(format!(
+            "template id {} does not match its file name",
+            tpl.id
+        ));
+    }
+    tpl.validate().map_err(|e| e.to_string())?;
+
+    Ok(tpl)
+}
+
+pub fn save(vault_base: &Path, tpl: &UserTemplate) -> Result<(), String> {
+    tpl.validate().map_err(|e| e.to_string())?;
+
+    let path = template_path(vault_base, &tpl.id)?;
+    let content = serde_json::to_string_pretty(tpl).map_err(|e| e.to_string())?;
+    write(&path, &content)
+}
+
+pub fn delete(vault_base: &Path, id: &str) -> Result<(), String> {
+    let path = template_path(vault_base, id)?;
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, content).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_template(id: &str, user: Option<&str>) -> UserTemplate {
+        UserTemplate {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            system: None,
+            user: user.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_save_list_delete() {
+        let temp = tempfile::tempdir().unwrap();
+        let base = temp.path();
+
+        assert!(list(base).unwrap().is_empty());
+
+        save(base, &user_template("b", Some("{{ session.title }}"))).unwrap();
+        save(base, &user_template("a", None)).unwrap();
+
+        let ids = list(base)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["a", "b"]);
+
+        delete(base, "b").unwrap();
+        assert!(get(base, "b").unwrap().is_none());
+        delete(base, "b").unwrap();
+    }
+
+    #[test]
+    fn test_load_validates_dropped_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let base = temp.path();
+
+        let dropped = |file: &str, tpl: &UserTemplate| {
+            write(&dir(base).join(file), &serde_json::to_string(tpl).unwrap()).unwrap();
+        };
+        dropped("a.json", &user_template("a", Some("{{ session.title }}")));
+        dropped("b.json", &user_template("b", Some("{{ unknown }}")));
+        dropped("c.json", &user_template("other", None));
+
+        let ids = list(base)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["a"]);
+        assert!(get(base, "b").is_err());
+        assert!(get(base, "c").is_err());
+    }
+
+    #[test]
+    fn test_save_rejects_invalid() {
+        let temp = tempfile::tempdir().unwrap();
+        let base = temp.path();
+
+        assert!(save(base, &user_template("a", Some("{{ unknown }}"))).is_err());
+        assert!(save(base, &user_template("../a", None)).is_err());
+        assert!(list(base).unwrap().is_empty());
+    }
+}